@@ -0,0 +1,183 @@
+use academy_config::Config;
+use academy_core_user_contracts::invite::{UserInviteCreateCommand, UserInviteService};
+use academy_di::Provide;
+use academy_models::invite::Invite;
+use academy_persistence_contracts::{invite::InviteRepository, Database as _, Transaction};
+use anyhow::{bail, Context};
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    cache, database, email,
+    environment::{
+        types::{self, Database},
+        ConfigProvider, Provider,
+    },
+};
+
+#[derive(Debug, Subcommand)]
+pub enum AdminInviteCommand {
+    /// List all invites
+    #[command(aliases(["l", "ls"]))]
+    List,
+    /// Create a new invite which is not owned by any user
+    #[command(aliases(["c", "new", "n", "+"]))]
+    Create {
+        /// The time at which the invite expires (RFC 3339, never expires if
+        /// not set)
+        #[arg(long)]
+        expires_at: Option<DateTime<Utc>>,
+        /// The maximum number of users that can register using the invite
+        /// (unlimited if not set)
+        #[arg(long)]
+        max_uses: Option<u32>,
+        /// Grant admin privileges to users registering with the invite
+        #[arg(long)]
+        admin: bool,
+        /// Tag to add to the profile of users registering with the invite
+        #[arg(long)]
+        cohort: Option<String>,
+    },
+    /// Delete an invite
+    #[command(aliases(["d", "remove", "rm", "-"]))]
+    Delete {
+        /// The id of the invite
+        invite: Uuid,
+    },
+}
+
+impl AdminInviteCommand {
+    pub async fn invoke(self, config: Config) -> anyhow::Result<()> {
+        match self {
+            AdminInviteCommand::List => list(config).await,
+            AdminInviteCommand::Create {
+                expires_at,
+                max_uses,
+                admin,
+                cohort,
+            } => create(config, expires_at, max_uses, admin, cohort).await,
+            AdminInviteCommand::Delete { invite } => delete(config, invite).await,
+        }
+    }
+}
+
+async fn list(config: Config) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let invite_repo: types::InviteRepo = provider.provide();
+    let invites = invite_repo
+        .list(&mut txn, None)
+        .await
+        .context("Failed to list invites")?;
+
+    let now = Utc::now();
+    for invite in &invites {
+        println!("{}", format_invite(invite, now));
+    }
+    println!("{} invites", invites.len());
+
+    Ok(())
+}
+
+async fn create(
+    config: Config,
+    expires_at: Option<DateTime<Utc>>,
+    max_uses: Option<u32>,
+    admin: bool,
+    cohort: Option<String>,
+) -> anyhow::Result<()> {
+    let cmd = UserInviteCreateCommand {
+        created_by: None,
+        expires_at,
+        max_uses: max_uses
+            .map(TryInto::try_into)
+            .transpose()
+            .context("Invalid max uses")?,
+        admin,
+        cohort: cohort
+            .map(TryInto::try_into)
+            .transpose()
+            .context("Invalid cohort")?,
+    };
+
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_invite: types::UserInvite = provider.provide();
+    let invite = user_invite
+        .create(&mut txn, cmd)
+        .await
+        .context("Failed to create invite")?;
+
+    txn.commit().await?;
+
+    info!("Invite has been created:\n{invite:#?}");
+
+    Ok(())
+}
+
+async fn delete(config: Config, invite_id: Uuid) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let invite_repo: types::InviteRepo = provider.provide();
+    if !invite_repo
+        .delete(&mut txn, invite_id.into())
+        .await
+        .context("Failed to delete invite")?
+    {
+        bail!("Invite {invite_id} not found");
+    }
+
+    txn.commit().await?;
+
+    info!("Invite {invite_id} has been deleted");
+
+    Ok(())
+}
+
+async fn provider(config: &Config) -> anyhow::Result<Provider> {
+    let database = database::connect(&config.database).await?;
+    let cache = cache::connect(&config.cache).await?;
+    let email_service = email::connect(&config.email).await?;
+    let config_provider = ConfigProvider::new(config)?;
+    Ok(Provider::new(
+        config_provider,
+        database,
+        cache,
+        email_service,
+    ))
+}
+
+fn format_invite(invite: &Invite, now: DateTime<Utc>) -> String {
+    let mut flags = Vec::new();
+    if !invite.is_valid(now) {
+        flags.push("invalid");
+    }
+    if invite.admin {
+        flags.push("admin");
+    }
+
+    format!(
+        "{}  {}  {:>4}/{:<4}  {:<25}  {:<32}  {}",
+        invite.id.hyphenated(),
+        invite.code.as_str(),
+        invite.uses,
+        invite
+            .max_uses
+            .map(|x| x.to_string())
+            .unwrap_or_else(|| "-".into()),
+        invite
+            .expires_at
+            .map(|x| x.to_rfc3339())
+            .unwrap_or_else(|| "-".into()),
+        invite.cohort.as_ref().map(|x| x.as_str()).unwrap_or("-"),
+        flags.join(",")
+    )
+}
@@ -1,7 +1,9 @@
 use academy_config::Config;
 use clap::Subcommand;
+use invite::AdminInviteCommand;
 use user::AdminUserCommand;
 
+mod invite;
 mod user;
 
 #[derive(Debug, Subcommand)]
@@ -12,12 +14,19 @@ pub enum AdminCommand {
         #[command(subcommand)]
         command: AdminUserCommand,
     },
+    /// Manage registration invites
+    #[command(aliases(["i"]))]
+    Invite {
+        #[command(subcommand)]
+        command: AdminInviteCommand,
+    },
 }
 
 impl AdminCommand {
     pub async fn invoke(self, config: Config) -> anyhow::Result<()> {
         match self {
             AdminCommand::User { command } => command.invoke(config).await,
+            AdminCommand::Invite { command } => command.invoke(config).await,
         }
     }
 }
@@ -1,10 +1,27 @@
 use academy_config::Config;
-use academy_core_user_contracts::user::{UserCreateCommand, UserService};
+use academy_core_mfa_contracts::disable::MfaDisableService;
+use academy_core_session_contracts::session::SessionService;
+use academy_core_user_contracts::{
+    avatar::UserAvatarService,
+    update::{
+        UserAccountUpdate, UserUpdateAccountError, UserUpdateNameRateLimitPolicy, UserUpdateService,
+    },
+    user::{UserCreateCommand, UserListQuery, UserListResult, UserService},
+};
 use academy_di::Provide;
-use academy_persistence_contracts::{Database as _, Transaction};
-use anyhow::Context;
-use clap::Subcommand;
+use academy_models::{
+    pagination::{PaginationSlice, SortDirection},
+    user::{
+        UserComposite, UserFilter, UserNameOrEmailAddress, UserPassword, UserSortField, UserSorting,
+    },
+};
+use academy_persistence_contracts::{user::UserRepository, Database as _, Transaction};
+use academy_utils::patch::PatchValue;
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, Utc};
+use clap::{Args, Subcommand, ValueEnum};
 use tracing::info;
+use uuid::Uuid;
 
 use crate::{
     cache, database, email,
@@ -16,6 +33,30 @@ use crate::{
 
 #[derive(Debug, Subcommand)]
 pub enum AdminUserCommand {
+    /// List all user accounts matching the given filters
+    #[command(aliases(["l", "ls"]))]
+    List {
+        #[command(flatten)]
+        filter: ListFilter,
+        /// The field to sort by
+        #[arg(long, default_value = "registration")]
+        sort_by: ListSortField,
+        /// Sort in descending order
+        #[arg(long)]
+        desc: bool,
+        /// The maximum number of users to list
+        #[arg(long, default_value = "100")]
+        limit: u64,
+        /// The number of users to skip
+        #[arg(long, default_value = "0")]
+        offset: u64,
+    },
+    /// Show a user account
+    #[command(aliases(["s", "get", "g"]))]
+    Show {
+        /// The id, name or email address of the user
+        user: String,
+    },
     /// Create a new user account
     #[command(aliases(["c", "new", "n", "+"]))]
     Create {
@@ -35,11 +76,122 @@ pub enum AdminUserCommand {
         /// The password of the new user
         password: String,
     },
+    /// Update a user account
+    #[command(aliases(["u", "edit", "e"]))]
+    Update {
+        /// The id, name or email address of the user
+        user: String,
+        /// Change the login name of the user
+        #[arg(long)]
+        name: Option<String>,
+        /// Change the email address of the user
+        #[arg(long)]
+        email: Option<String>,
+        /// Enable or disable the user account (disabling logs out the user)
+        #[arg(long)]
+        enabled: Option<bool>,
+        /// Grant or revoke admin privileges
+        #[arg(long)]
+        admin: Option<bool>,
+        /// Mark the email address of the user as verified or unverified
+        #[arg(long)]
+        verified: Option<bool>,
+    },
+    /// Delete a user account
+    #[command(aliases(["d", "remove", "rm", "-"]))]
+    Delete {
+        /// The id, name or email address of the user
+        user: String,
+    },
+    /// Set a new password for a user account and log out the user
+    ResetPassword {
+        /// The id, name or email address of the user
+        user: String,
+        /// The new password of the user
+        password: String,
+    },
+    /// Disable multi-factor authentication for a user account
+    DisableMfa {
+        /// The id, name or email address of the user
+        user: String,
+    },
+    /// Delete all sessions of a user account
+    Logout {
+        /// The id, name or email address of the user
+        user: String,
+    },
+    /// Create a new session for a user account and print its tokens
+    Impersonate {
+        /// The id, name or email address of the user
+        user: String,
+    },
+    /// List all invoice countries which could not be converted to a country
+    /// code
+    UnconvertedCountries,
+}
+
+#[derive(Debug, Args)]
+pub struct ListFilter {
+    /// Fuzzy search by name, display name, email address and invoice name
+    #[arg(long, short)]
+    query: Option<String>,
+    /// Filter by name and display name
+    #[arg(long)]
+    name: Option<String>,
+    /// Filter by email address
+    #[arg(long)]
+    email: Option<String>,
+    /// Filter by enabled status
+    #[arg(long)]
+    enabled: Option<bool>,
+    /// Filter by admin status
+    #[arg(long)]
+    admin: Option<bool>,
+    /// Filter by multi-factor authentication status
+    #[arg(long)]
+    mfa_enabled: Option<bool>,
+    /// Filter by email verification status
+    #[arg(long)]
+    verified: Option<bool>,
+    /// Filter by newsletter subscription status
+    #[arg(long)]
+    newsletter: Option<bool>,
+    /// Only include users registered at or after this time (RFC 3339)
+    #[arg(long)]
+    registered_after: Option<DateTime<Utc>>,
+    /// Only include users registered before this time (RFC 3339)
+    #[arg(long)]
+    registered_before: Option<DateTime<Utc>>,
+    /// Only include users whose last login was at or after this time (RFC
+    /// 3339)
+    #[arg(long)]
+    last_login_after: Option<DateTime<Utc>>,
+    /// Only include users whose last login was before this time (RFC 3339)
+    #[arg(long)]
+    last_login_before: Option<DateTime<Utc>>,
+    /// Only include users linked to this OAuth2 provider
+    #[arg(long)]
+    oauth2_provider: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ListSortField {
+    Registration,
+    LastLogin,
+    Name,
 }
 
 impl AdminUserCommand {
     pub async fn invoke(self, config: Config) -> anyhow::Result<()> {
         match self {
+            AdminUserCommand::List {
+                filter,
+                sort_by,
+                desc,
+                limit,
+                offset,
+            } => list(config, filter, sort_by, desc, limit, offset).await,
+            AdminUserCommand::Show { user } => show(config, user).await,
             AdminUserCommand::Create {
                 admin,
                 name,
@@ -48,10 +200,105 @@ impl AdminUserCommand {
                 disabled,
                 verified,
             } => create(config, name, email, password, admin, !disabled, verified).await,
+            AdminUserCommand::Update {
+                user,
+                name,
+                email,
+                enabled,
+                admin,
+                verified,
+            } => update(config, user, name, email, enabled, admin, verified).await,
+            AdminUserCommand::Delete { user } => delete(config, user).await,
+            AdminUserCommand::ResetPassword { user, password } => {
+                reset_password(config, user, password).await
+            }
+            AdminUserCommand::DisableMfa { user } => disable_mfa(config, user).await,
+            AdminUserCommand::Logout { user } => logout(config, user).await,
+            AdminUserCommand::Impersonate { user } => impersonate(config, user).await,
+            AdminUserCommand::UnconvertedCountries => unconverted_countries(config).await,
         }
     }
 }
 
+async fn list(
+    config: Config,
+    filter: ListFilter,
+    sort_by: ListSortField,
+    desc: bool,
+    limit: u64,
+    offset: u64,
+) -> anyhow::Result<()> {
+    let query = UserListQuery {
+        pagination: PaginationSlice {
+            limit: limit.try_into().context("Invalid limit")?,
+            offset,
+        }
+        .into(),
+        filter: UserFilter {
+            query: filter.query.map(TryInto::try_into).transpose()?,
+            name: filter.name.map(TryInto::try_into).transpose()?,
+            email: filter.email.map(TryInto::try_into).transpose()?,
+            enabled: filter.enabled,
+            admin: filter.admin,
+            mfa_enabled: filter.mfa_enabled,
+            email_verified: filter.verified,
+            newsletter: filter.newsletter,
+            created_after: filter.registered_after,
+            created_before: filter.registered_before,
+            last_login_after: filter.last_login_after,
+            last_login_before: filter.last_login_before,
+            oauth2_provider: filter.oauth2_provider.map(Into::into),
+        },
+        sorting: UserSorting {
+            field: match sort_by {
+                ListSortField::Registration => UserSortField::CreatedAt,
+                ListSortField::LastLogin => UserSortField::LastLogin,
+                ListSortField::Name => UserSortField::Name,
+            },
+            direction: if desc {
+                SortDirection::Desc
+            } else {
+                SortDirection::Asc
+            },
+        },
+    };
+
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_service: types::User = provider.provide();
+    let UserListResult {
+        total,
+        user_composites,
+        ..
+    } = user_service
+        .list(&mut txn, query)
+        .await
+        .context("Failed to list users")?;
+
+    for user_composite in &user_composites {
+        println!("{}", format_user(user_composite));
+    }
+    println!(
+        "showing {} of {total} users (offset {offset})",
+        user_composites.len()
+    );
+
+    Ok(())
+}
+
+async fn show(config: Config, user: String) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_composite = get_user(&mut provider, &mut txn, &user).await?;
+    println!("{user_composite:#?}");
+
+    Ok(())
+}
+
 async fn create(
     config: Config,
     name: String,
@@ -61,12 +308,7 @@ async fn create(
     enabled: bool,
     email_verified: bool,
 ) -> anyhow::Result<()> {
-    let database = database::connect(&config.database).await?;
-    let cache = cache::connect(&config.cache).await?;
-    let email_service = email::connect(&config.email).await?;
-    let config_provider = ConfigProvider::new(&config)?;
-    let mut provider = Provider::new(config_provider, database, cache, email_service);
-
+    let mut provider = provider(&config).await?;
     let db: Database = provider.provide();
     let mut txn = db.begin_transaction().await?;
 
@@ -83,6 +325,8 @@ async fn create(
                 enabled,
                 email_verified,
                 oauth2_registration: None,
+                tags: Default::default(),
+                locale: config.user.default_locale.clone(),
             },
         )
         .await
@@ -94,3 +338,274 @@ async fn create(
 
     Ok(())
 }
+
+async fn update(
+    config: Config,
+    user: String,
+    name: Option<String>,
+    email: Option<String>,
+    enabled: Option<bool>,
+    admin: Option<bool>,
+    verified: Option<bool>,
+) -> anyhow::Result<()> {
+    let update = UserAccountUpdate {
+        name: name.map(TryInto::try_into).transpose()?.into(),
+        email: email
+            .map(|email| email.parse().map(Some))
+            .transpose()?
+            .into(),
+        email_verified: verified.into(),
+        enabled: enabled.into(),
+        admin: admin.into(),
+        password: PatchValue::Unchanged,
+    };
+
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_composite = get_user(&mut provider, &mut txn, &user).await?;
+
+    let user_update: types::UserUpdate = provider.provide();
+    let user_composite = update_account(&user_update, &mut txn, user_composite, update).await?;
+
+    txn.commit().await?;
+
+    info!("User has been updated:\n{user_composite:#?}");
+
+    Ok(())
+}
+
+async fn delete(config: Config, user: String) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_id = get_user(&mut provider, &mut txn, &user).await?.user.id;
+
+    let user_service: types::User = provider.provide();
+    user_service
+        .delete(&mut txn, user_id)
+        .await
+        .context("Failed to delete user")?;
+
+    txn.commit().await?;
+
+    let user_avatar: types::UserAvatar = provider.provide();
+    user_avatar
+        .remove(user_id)
+        .await
+        .context("Failed to remove avatar")?;
+
+    info!("User {} has been deleted", *user_id);
+
+    Ok(())
+}
+
+async fn reset_password(config: Config, user: String, password: String) -> anyhow::Result<()> {
+    let update = UserAccountUpdate {
+        password: UserPassword::try_from(password)?.into(),
+        ..Default::default()
+    };
+
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_composite = get_user(&mut provider, &mut txn, &user).await?;
+    let user_id = user_composite.user.id;
+
+    let user_update: types::UserUpdate = provider.provide();
+    update_account(&user_update, &mut txn, user_composite, update).await?;
+
+    let session: types::Session = provider.provide();
+    session
+        .delete_by_user(&mut txn, user_id)
+        .await
+        .context("Failed to log out user")?;
+
+    txn.commit().await?;
+
+    info!("Password of user {} has been reset", *user_id);
+
+    Ok(())
+}
+
+async fn disable_mfa(config: Config, user: String) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_id = get_user(&mut provider, &mut txn, &user).await?.user.id;
+
+    let mfa_disable: types::MfaDisable = provider.provide();
+    mfa_disable
+        .disable(&mut txn, user_id)
+        .await
+        .context("Failed to disable MFA")?;
+
+    txn.commit().await?;
+
+    info!("MFA of user {} has been disabled", *user_id);
+
+    Ok(())
+}
+
+async fn logout(config: Config, user: String) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_id = get_user(&mut provider, &mut txn, &user).await?.user.id;
+
+    let session: types::Session = provider.provide();
+    session
+        .delete_by_user(&mut txn, user_id)
+        .await
+        .context("Failed to log out user")?;
+
+    txn.commit().await?;
+
+    info!("User {} has been logged out", *user_id);
+
+    Ok(())
+}
+
+async fn impersonate(config: Config, user: String) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_composite = get_user(&mut provider, &mut txn, &user).await?;
+
+    let session: types::Session = provider.provide();
+    let login = session
+        .create(&mut txn, user_composite, None, false)
+        .await
+        .context("Failed to create session")?;
+
+    txn.commit().await?;
+
+    info!("Session {} has been created", *login.session.id);
+    println!("access_token: {}", login.access_token.into_inner());
+    println!("refresh_token: {}", login.refresh_token.into_inner());
+
+    Ok(())
+}
+
+async fn unconverted_countries(config: Config) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let mut txn = db.begin_transaction().await?;
+
+    let user_repo: types::UserRepo = provider.provide();
+    let countries = user_repo
+        .list_unconverted_countries(&mut txn)
+        .await
+        .context("Failed to list unconverted countries")?;
+
+    for (user_id, country) in &countries {
+        println!("{}  {country:?}", user_id.hyphenated());
+    }
+    println!("{} unconverted countries", countries.len());
+
+    Ok(())
+}
+
+async fn provider(config: &Config) -> anyhow::Result<Provider> {
+    let database = database::connect(&config.database).await?;
+    let cache = cache::connect(&config.cache).await?;
+    let email_service = email::connect(&config.email).await?;
+    let config_provider = ConfigProvider::new(config)?;
+    Ok(Provider::new(
+        config_provider,
+        database,
+        cache,
+        email_service,
+    ))
+}
+
+/// Find a user by id, name or email address.
+async fn get_user(
+    provider: &mut Provider,
+    txn: &mut <Database as academy_persistence_contracts::Database>::Transaction,
+    user: &str,
+) -> anyhow::Result<UserComposite> {
+    let user_repo: types::UserRepo = provider.provide();
+
+    let user_composite = if let Ok(user_id) = user.parse::<Uuid>() {
+        user_repo.get_composite(txn, user_id.into()).await
+    } else {
+        let name_or_email = match user.parse() {
+            Ok(email) => UserNameOrEmailAddress::Email(email),
+            Err(_) => UserNameOrEmailAddress::Name(user.to_owned().try_into()?),
+        };
+        user_repo
+            .get_composite_by_name_or_email(txn, &name_or_email)
+            .await
+    };
+
+    user_composite
+        .context("Failed to get user from database")?
+        .with_context(|| format!("User {user} not found"))
+}
+
+/// Apply an update to a user account with the same rules as an admin using
+/// the REST API.
+async fn update_account(
+    user_update: &types::UserUpdate,
+    txn: &mut <Database as academy_persistence_contracts::Database>::Transaction,
+    user_composite: UserComposite,
+    update: UserAccountUpdate,
+) -> anyhow::Result<UserComposite> {
+    user_update
+        .update_account(
+            txn,
+            user_composite,
+            update,
+            UserUpdateNameRateLimitPolicy::Bypass,
+        )
+        .await
+        .map_err(|err| match err {
+            UserUpdateAccountError::NameConflict => anyhow!("A user with this name already exists"),
+            UserUpdateAccountError::EmailConflict => {
+                anyhow!("A user with this email address already exists")
+            }
+            UserUpdateAccountError::NameChangeRateLimit { until } => {
+                anyhow!("The user name cannot be changed until {until}")
+            }
+            UserUpdateAccountError::Other(err) => err.context("Failed to update user"),
+        })
+}
+
+fn format_user(
+    UserComposite {
+        user,
+        profile,
+        details,
+        ..
+    }: &UserComposite,
+) -> String {
+    let mut flags = Vec::new();
+    if user.admin {
+        flags.push("admin");
+    }
+    if !user.enabled {
+        flags.push("disabled");
+    }
+    if !user.email_verified {
+        flags.push("unverified");
+    }
+    if details.mfa_enabled {
+        flags.push("mfa");
+    }
+
+    format!(
+        "{}  {:<32}  {:<32}  {:<48}  {}",
+        user.id.hyphenated(),
+        user.name.as_str(),
+        profile.display_name.as_str(),
+        user.email.as_ref().map(|x| x.as_str()).unwrap_or("-"),
+        flags.join(",")
+    )
+}
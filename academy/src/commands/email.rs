@@ -1,9 +1,18 @@
+use std::path::PathBuf;
+
 use academy_config::Config;
-use academy_email_contracts::{ContentType, Email, EmailService};
-use academy_email_impl::EmailServiceImpl;
-use academy_models::email_address::EmailAddressWithName;
+use academy_di::Provide;
+use academy_email_contracts::{Email, EmailBody, EmailService};
+use academy_email_impl::{template::TemplateEmailServiceConfig, EmailServiceImpl};
+use academy_models::{email_address::EmailAddressWithName, locale::Locale};
+use academy_templates_contracts::TEMPLATES;
 use anyhow::{anyhow, Context};
-use clap::Subcommand;
+use clap::{builder::PossibleValuesParser, Subcommand};
+
+use crate::{
+    email,
+    environment::{types::Template, ConfigProvider},
+};
 
 #[derive(Debug, Subcommand)]
 pub enum EmailCommand {
@@ -12,33 +21,164 @@ pub enum EmailCommand {
         /// The address to which the test email should be sent
         recipient: EmailAddressWithName,
     },
+    /// Render an email template
+    Render {
+        /// The name of the template
+        #[arg(value_parser = template_names())]
+        template: String,
+        /// The JSON data to render the template with
+        #[arg(long, default_value = "{}")]
+        data: String,
+        /// The locale to render the template in
+        #[arg(long, default_value = Locale::FALLBACK)]
+        locale: String,
+        /// Render the plain text version of the template
+        #[arg(long)]
+        text: bool,
+        /// Write the rendered template to this file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Render an email template and send it to a test recipient
+    SendTemplate {
+        /// The name of the template
+        #[arg(value_parser = template_names())]
+        template: String,
+        /// The address to which the rendered template should be sent
+        recipient: EmailAddressWithName,
+        /// The JSON data to render the template with
+        #[arg(long, default_value = "{}")]
+        data: String,
+        /// The locale to render the template in
+        #[arg(long, default_value = Locale::FALLBACK)]
+        locale: String,
+        /// The subject of the email
+        #[arg(long)]
+        subject: Option<String>,
+    },
 }
 
 impl EmailCommand {
     pub async fn invoke(self, config: Config) -> anyhow::Result<()> {
         match self {
             EmailCommand::Test { recipient } => test(config, recipient).await,
+            EmailCommand::Render {
+                template,
+                data,
+                locale,
+                text,
+                output,
+            } => render(&config, &template, &data, locale, text, output),
+            EmailCommand::SendTemplate {
+                template,
+                recipient,
+                data,
+                locale,
+                subject,
+            } => send_template(config, template, recipient, &data, locale, subject).await,
         }
     }
 }
 
 async fn test(config: Config, recipient: EmailAddressWithName) -> anyhow::Result<()> {
-    let email_service = EmailServiceImpl::new(&config.email.smtp_url, config.email.from).await?;
+    let email_service = email::connect(&config.email).await?;
 
-    email_service
-        .send(Email {
+    send(
+        &email_service,
+        Email {
             recipient,
             subject: "Email Deliverability Test".into(),
-            body: "Email deliverability seems to be working!".into(),
-            content_type: ContentType::Text,
+            body: EmailBody::Text("Email deliverability seems to be working!".into()),
             reply_to: None,
-        })
+            headers: Vec::new(),
+            inline_images: Vec::new(),
+        },
+    )
+    .await
+}
+
+fn render(
+    config: &Config,
+    template: &str,
+    data: &str,
+    locale: String,
+    text: bool,
+    output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let mut provider = ConfigProvider::new(config)?;
+    let template_service: Template = provider.provide();
+
+    let data = parse_data(data)?;
+    let locale = locale.try_into()?;
+    let rendered = if text {
+        template_service.render_text_by_name(template, data, &locale)?
+    } else {
+        template_service.render_by_name(template, data, &locale)?
+    };
+
+    match output {
+        Some(path) => std::fs::write(&path, rendered)
+            .with_context(|| format!("Failed to write rendered template to {}", path.display()))?,
+        None => print!("{rendered}"),
+    }
+
+    Ok(())
+}
+
+async fn send_template(
+    config: Config,
+    template: String,
+    recipient: EmailAddressWithName,
+    data: &str,
+    locale: String,
+    subject: Option<String>,
+) -> anyhow::Result<()> {
+    let mut provider = ConfigProvider::new(&config)?;
+    let template_service: Template = provider.provide();
+    let template_email_config: TemplateEmailServiceConfig = provider.provide();
+
+    let data = parse_data(data)?;
+    let locale = locale.try_into()?;
+    let text = template_service.render_text_by_name(&template, data.clone(), &locale)?;
+    let html = template_service.render_by_name(&template, data, &locale)?;
+
+    let email_service = email::connect(&config.email).await?;
+
+    send(
+        &email_service,
+        Email {
+            recipient,
+            subject: subject.unwrap_or_else(|| format!("Template Preview: {template}")),
+            body: EmailBody::Alternative { text, html },
+            reply_to: None,
+            headers: Vec::new(),
+            inline_images: template_email_config.inline_images.to_vec(),
+        },
+    )
+    .await
+}
+
+async fn send(email_service: &EmailServiceImpl, email: Email) -> anyhow::Result<()> {
+    email_service
+        .send(email)
         .await
         .and_then(|r| {
             r.then_some(())
                 .ok_or_else(|| anyhow!("SMTP server returned a negative response"))
         })
-        .context("Failed to send email")?;
+        .context("Failed to send email")
+}
 
-    Ok(())
+fn parse_data(data: &str) -> anyhow::Result<serde_json::Value> {
+    serde_json::from_str(data).context("Failed to parse the template data as json")
+}
+
+/// Return a parser accepting the names of all registered templates.
+fn template_names() -> PossibleValuesParser {
+    let mut names = TEMPLATES
+        .iter()
+        .map(|&(_, name, _)| name)
+        .collect::<Vec<_>>();
+    names.dedup();
+    PossibleValuesParser::new(names)
 }
@@ -1,4 +1,5 @@
 use academy_models::{
+    country::Country,
     mfa::{MfaRecoveryCodeHash, TotpDevice, TotpSecret},
     oauth2::{OAuth2Link, OAuth2UserInfo},
     session::{Session, SessionRefreshTokenHash},
@@ -17,7 +18,7 @@ use academy_shared_contracts::hash::HashService;
 use academy_shared_impl::hash::HashServiceImpl;
 use chrono::NaiveDateTime;
 use indicatif::ProgressIterator;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use super::DbConnection;
@@ -76,6 +77,7 @@ pub async fn load(db: PostgresDatabase, auth: DbConnection) -> anyhow::Result<()
             enabled,
             admin,
             newsletter: newsletter.unwrap_or(false),
+            locale: "de".try_into()?,
         };
 
         let profile = UserProfile {
@@ -85,6 +87,7 @@ pub async fn load(db: PostgresDatabase, auth: DbConnection) -> anyhow::Result<()
                 .transpose()?
                 .unwrap_or_default(),
             tags: serde_json::from_str(tags.as_deref().unwrap_or("[]"))?,
+            avatar_url: None,
         };
 
         let invoice_info = UserInvoiceInfo {
@@ -94,8 +97,15 @@ pub async fn load(db: PostgresDatabase, auth: DbConnection) -> anyhow::Result<()
             street: street.map(TryInto::try_into).transpose()?,
             zip_code: zip_code.map(TryInto::try_into).transpose()?,
             city: city.map(TryInto::try_into).transpose()?,
-            country: country.map(TryInto::try_into).transpose()?,
+            country: country.and_then(|country| {
+                let result = Country::from_name(&country);
+                if result.is_none() {
+                    warn!("Failed to convert country {country:?} of user {}", *user.id);
+                }
+                result
+            }),
             vat_id: vat_id.map(TryInto::try_into).transpose()?,
+            vat_id_validation: None,
         };
 
         user_repo
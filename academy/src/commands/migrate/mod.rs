@@ -1,8 +1,12 @@
 use academy_config::Config;
 use academy_persistence_contracts::{Database, Transaction};
 use academy_persistence_postgres::{
-    mfa::PostgresMfaRepository, oauth2::PostgresOAuth2Repository,
-    session::PostgresSessionRepository, user::PostgresUserRepository, MigrationStatus,
+    checkout::PostgresCheckoutRepository, coin::PostgresCoinRepository,
+    contact::PostgresContactRepository, email_outbox::PostgresEmailOutboxRepository,
+    invite::PostgresInviteRepository, invoice::PostgresInvoiceRepository,
+    mfa::PostgresMfaRepository, newsletter::PostgresNewsletterRepository,
+    oauth2::PostgresOAuth2Repository, session::PostgresSessionRepository,
+    skill::PostgresSkillRepository, user::PostgresUserRepository, MigrationStatus,
     PostgresDatabase,
 };
 use anyhow::Context;
@@ -112,6 +116,14 @@ async fn demo(db: PostgresDatabase) -> anyhow::Result<()> {
         PostgresSessionRepository,
         PostgresMfaRepository,
         PostgresOAuth2Repository,
+        PostgresInviteRepository,
+        PostgresNewsletterRepository,
+        PostgresEmailOutboxRepository,
+        PostgresContactRepository,
+        PostgresCoinRepository,
+        PostgresCheckoutRepository,
+        PostgresInvoiceRepository,
+        PostgresSkillRepository,
     )
     .await
     .context("Failed to restore demo dataset")?;
@@ -36,6 +36,12 @@ pub async fn serve(config: Config) -> anyhow::Result<()> {
     let config_provider = ConfigProvider::new(&config)?;
     let mut provider = Provider::new(config_provider, database, cache, email);
 
+    info!("Starting email outbox worker");
+    tokio::spawn(email::run_outbox_worker(
+        provider.provide(),
+        config.email.outbox_poll_interval.into(),
+    ));
+
     let server: RestServer = provider.provide();
     server.serve().await
 }
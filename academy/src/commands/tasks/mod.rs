@@ -1,23 +1,55 @@
 use academy_config::Config;
-use academy_persistence_contracts::{session::SessionRepository, Database, Transaction};
-use academy_persistence_postgres::session::PostgresSessionRepository;
+use academy_core_checkout_contracts::invoice::CheckoutInvoiceService;
+use academy_core_newsletter_contracts::campaign::{
+    NewsletterCampaignService, NewsletterSendBatchResult,
+};
+use academy_core_user_contracts::vat::UserVatService;
+use academy_di::Provide;
+use academy_email_contracts::outbox::EmailOutboxDeliveryResult;
+use academy_models::{newsletter::NewsletterCampaignStatus, user::UserVatIdStatus};
+use academy_persistence_contracts::{
+    checkout::CheckoutRepository, email_outbox::EmailOutboxRepository,
+    newsletter::NewsletterRepository, session::SessionRepository, user::UserRepository,
+    Database as _, Transaction,
+};
+use academy_persistence_postgres::{
+    email_outbox::PostgresEmailOutboxRepository, session::PostgresSessionRepository,
+};
 use anyhow::Context;
 use chrono::Utc;
 use clap::Subcommand;
-use tracing::info;
+use tracing::{error, info, warn};
 
-use crate::database;
+use crate::{
+    cache, database, email,
+    environment::{
+        types::{self, Database},
+        ConfigProvider, Provider,
+    },
+};
 
 #[derive(Debug, Subcommand)]
 pub enum TaskCommand {
     /// Remove expired records from the database.
     PruneDatabase,
+    /// Deliver all queued emails whose next delivery attempt is due.
+    DeliverEmails,
+    /// Deliver all newsletter campaigns that are currently being sent.
+    SendNewsletters,
+    /// Validate stored VAT ids again and flag those that have become invalid.
+    RevalidateVatIds,
+    /// Issue the invoices of completed coin orders which do not have one yet.
+    CreateInvoices,
 }
 
 impl TaskCommand {
     pub async fn invoke(self, config: Config) -> anyhow::Result<()> {
         match self {
             TaskCommand::PruneDatabase => prune_database(config).await,
+            TaskCommand::DeliverEmails => deliver_emails(config).await,
+            TaskCommand::SendNewsletters => send_newsletters(config).await,
+            TaskCommand::RevalidateVatIds => revalidate_vat_ids(config).await,
+            TaskCommand::CreateInvoices => create_invoices(config).await,
         }
     }
 }
@@ -34,7 +66,183 @@ async fn prune_database(config: Config) -> anyhow::Result<()> {
         .context("Failed to prune sessions")?;
     info!("Pruned {pruned} expired sessions.");
 
+    let email_outbox_repo = PostgresEmailOutboxRepository;
+    let pruned = email_outbox_repo
+        .delete_sent_before(&mut txn, now - config.email.outbox_retention.0)
+        .await
+        .context("Failed to prune sent emails")?;
+    info!("Pruned {pruned} sent emails.");
+
     txn.commit().await?;
 
     Ok(())
 }
+
+async fn deliver_emails(config: Config) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let email_outbox: types::EmailOutbox = provider.provide();
+
+    let EmailOutboxDeliveryResult {
+        sent,
+        retried,
+        failed,
+    } = email::deliver_outbox(&email_outbox).await?;
+    info!("Sent {sent} emails ({retried} retried, {failed} failed)");
+
+    Ok(())
+}
+
+async fn send_newsletters(config: Config) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let newsletter_repo: types::NewsletterRepo = provider.provide();
+    let newsletter_campaign: types::NewsletterCampaign = provider.provide();
+
+    let campaigns = {
+        let mut txn = db.begin_transaction().await?;
+        newsletter_repo
+            .list_campaigns(&mut txn, Some(NewsletterCampaignStatus::Sending))
+            .await
+            .context("Failed to get campaigns from database")?
+    };
+
+    for campaign in campaigns {
+        info!(
+            "Sending campaign {} ({}) to {} pending recipients",
+            *campaign.id, *campaign.subject, campaign.progress.pending
+        );
+
+        loop {
+            let NewsletterSendBatchResult {
+                sent,
+                failed,
+                skipped,
+                completed,
+            } = newsletter_campaign
+                .send_batch(&campaign)
+                .await
+                .with_context(|| format!("Failed to send batch of campaign {}", *campaign.id))?;
+
+            info!("Sent {sent} emails ({failed} failed, {skipped} skipped)");
+
+            if completed {
+                info!("Campaign {} has been sent to all recipients", *campaign.id);
+                break;
+            }
+
+            if sent + failed + skipped == 0 {
+                // All remaining recipients are currently locked by another worker.
+                info!("Campaign {} is being sent by another worker", *campaign.id);
+                break;
+            }
+
+            tokio::time::sleep(config.newsletter.batch_interval.into()).await;
+        }
+    }
+
+    Ok(())
+}
+
+async fn revalidate_vat_ids(config: Config) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let user_repo: types::UserRepo = provider.provide();
+    let user_vat: types::UserVat = provider.provide();
+
+    let vat_ids = {
+        let mut txn = db.begin_transaction().await?;
+        user_repo
+            .list_vat_ids_to_revalidate(&mut txn, Utc::now() - config.vat.revalidate_after.0)
+            .await
+            .context("Failed to get VAT ids from database")?
+    };
+
+    let (mut valid, mut invalid, mut pending, mut failed) = (0, 0, 0, 0);
+    for (user_id, vat_id) in vat_ids {
+        let validation = match user_vat.revalidate(&vat_id).await {
+            Ok(validation) => validation,
+            Err(err) => {
+                error!("Failed to validate VAT id of user {}: {err:?}", *user_id);
+                failed += 1;
+                continue;
+            }
+        };
+
+        match validation.status {
+            UserVatIdStatus::Valid => valid += 1,
+            UserVatIdStatus::Invalid => {
+                warn!("VAT id {} of user {} is invalid", *vat_id, *user_id);
+                invalid += 1;
+            }
+            UserVatIdStatus::Pending => {
+                // Keep the previous result, the VAT id is checked again on
+                // the next run.
+                pending += 1;
+                continue;
+            }
+        }
+
+        let mut txn = db.begin_transaction().await?;
+        user_repo
+            .update_vat_id_validation(&mut txn, user_id, &vat_id, &validation)
+            .await
+            .context("Failed to save VAT id validation in database")?;
+        txn.commit().await?;
+    }
+
+    info!(
+        "Validated VAT ids ({valid} valid, {invalid} invalid, {pending} pending, {failed} failed)"
+    );
+
+    Ok(())
+}
+
+async fn create_invoices(config: Config) -> anyhow::Result<()> {
+    let mut provider = provider(&config).await?;
+    let db: Database = provider.provide();
+    let checkout_repo: types::CheckoutRepo = provider.provide();
+    let checkout_invoice: types::CheckoutInvoice = provider.provide();
+
+    let orders = {
+        let mut txn = db.begin_transaction().await?;
+        checkout_repo
+            .list_orders_without_invoice(&mut txn)
+            .await
+            .context("Failed to get coin orders from database")?
+    };
+
+    let (mut created, mut failed) = (0, 0);
+    for order in orders {
+        let mut txn = db.begin_transaction().await?;
+        match checkout_invoice.create(&mut txn, &order).await {
+            Ok(_) => {
+                txn.commit().await?;
+                created += 1;
+            }
+            Err(err) => {
+                error!(
+                    "Failed to issue invoice for coin order {}: {err:?}",
+                    *order.id
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    info!("Issued {created} invoices ({failed} failed)");
+
+    Ok(())
+}
+
+async fn provider(config: &Config) -> anyhow::Result<Provider> {
+    let database = database::connect(&config.database).await?;
+    let cache = cache::connect(&config.cache).await?;
+    let email_service = email::connect(&config.email).await?;
+    let config_provider = ConfigProvider::new(config)?;
+    Ok(Provider::new(
+        config_provider,
+        database,
+        cache,
+        email_service,
+    ))
+}
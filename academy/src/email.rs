@@ -1,10 +1,75 @@
+use std::time::Duration;
+
 use academy_config::EmailConfig;
+use academy_email_contracts::outbox::{EmailOutboxDeliveryResult, EmailOutboxService};
 use academy_email_impl::EmailServiceImpl;
 use anyhow::Context;
+use tracing::{error, info};
+
+use crate::environment::types::EmailOutbox;
 
 /// Connect to the SMTP server
 pub async fn connect(config: &EmailConfig) -> anyhow::Result<EmailServiceImpl> {
-    EmailServiceImpl::new(&config.smtp_url, config.from.clone())
+    let mut email_service = EmailServiceImpl::new(&config.smtp_url, config.from.clone())
         .await
-        .context("Failed to connect to SMTP server")
+        .context("Failed to connect to SMTP server")?;
+
+    if let Some(dkim) = &config.dkim {
+        let private_key = tokio::fs::read_to_string(&dkim.private_key_path)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to read DKIM private key from {}",
+                    dkim.private_key_path.display()
+                )
+            })?;
+        email_service = email_service.with_dkim(
+            dkim.selector.clone(),
+            dkim.domain.clone(),
+            &private_key,
+            &dkim.headers,
+        )?;
+    }
+
+    Ok(email_service)
+}
+
+/// Deliver batches of queued emails until no more emails are due.
+pub async fn deliver_outbox(
+    email_outbox: &EmailOutbox,
+) -> anyhow::Result<EmailOutboxDeliveryResult> {
+    let mut total = EmailOutboxDeliveryResult::default();
+    loop {
+        let result = email_outbox
+            .deliver_batch()
+            .await
+            .context("Failed to deliver batch of queued emails")?;
+
+        if result.total() == 0 {
+            return Ok(total);
+        }
+
+        total.sent += result.sent;
+        total.retried += result.retried;
+        total.failed += result.failed;
+    }
+}
+
+/// Continuously deliver queued emails, waiting `poll_interval` whenever the
+/// outbox is empty.
+pub async fn run_outbox_worker(email_outbox: EmailOutbox, poll_interval: Duration) {
+    loop {
+        match deliver_outbox(&email_outbox).await {
+            Ok(result) if result.total() > 0 => {
+                info!(
+                    "Delivered queued emails ({} sent, {} retried, {} failed)",
+                    result.sent, result.retried, result.failed
+                );
+            }
+            Ok(_) => {}
+            Err(err) => error!("Failed to deliver queued emails: {err:#}"),
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
 }
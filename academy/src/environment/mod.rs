@@ -1,24 +1,44 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    sync::Arc,
+};
 
 use academy_api_rest::{RestServerConfig, RestServerRealIpConfig};
 use academy_auth_impl::AuthServiceConfig;
 use academy_config::Config;
+use academy_core_checkout_impl::CheckoutFeatureConfig;
 use academy_core_contact_impl::ContactFeatureConfig;
 use academy_core_health_impl::HealthFeatureConfig;
+use academy_core_invoice_impl::invoice::InvoiceServiceConfig;
+use academy_core_mfa_impl::MfaFeatureConfig;
+use academy_core_newsletter_impl::campaign::NewsletterCampaignServiceConfig;
 use academy_core_oauth2_impl::OAuth2FeatureConfig;
 use academy_core_session_impl::SessionFeatureConfig;
-use academy_core_user_impl::UserFeatureConfig;
+use academy_core_user_impl::{
+    email_policy::{normalize_domain, parse_domain_list, DISPOSABLE_EMAIL_DOMAINS},
+    UserFeatureConfig,
+};
 use academy_di::provider;
+use academy_email_contracts::EmailInlineImage;
+use academy_email_impl::{outbox::EmailOutboxServiceConfig, template::TemplateEmailServiceConfig};
 use academy_extern_impl::{
-    internal::InternalApiServiceConfig, recaptcha::RecaptchaApiServiceConfig,
-    vat::VatApiServiceConfig,
+    dns::DnsResolverServiceConfig, hcaptcha::HcaptchaApiServiceConfig,
+    payment::PaymentApiServiceConfig, recaptcha::RecaptchaApiServiceConfig,
+    turnstile::TurnstileApiServiceConfig, vat::VatApiServiceConfig,
 };
-use academy_models::oauth2::OAuth2Provider;
+use academy_models::{checkout::CoinPackage, oauth2::OAuth2Provider};
 use academy_shared_impl::{
-    captcha::{CaptchaServiceConfig, RecaptchaCaptchaServiceConfig},
+    captcha::{
+        CaptchaServiceConfig, ProofOfWorkCaptchaServiceConfig, RecaptchaCaptchaServiceConfig,
+        SiteverifyCaptchaServiceConfig,
+    },
     jwt::JwtServiceConfig,
     totp::TotpServiceConfig,
 };
+use academy_storage_local::LocalStorageConfig;
+use academy_templates_impl::TemplateServiceConfig;
+use anyhow::{anyhow, Context};
 use types::{Cache, Database, Email};
 
 pub mod types;
@@ -34,10 +54,23 @@ provider! {
             RestServerConfig,
 
             // Extern
-            InternalApiServiceConfig,
+            DnsResolverServiceConfig,
+            HcaptchaApiServiceConfig,
+            PaymentApiServiceConfig,
             RecaptchaApiServiceConfig,
+            TurnstileApiServiceConfig,
             VatApiServiceConfig,
 
+            // Storage
+            LocalStorageConfig,
+
+            // Email
+            EmailOutboxServiceConfig,
+            TemplateEmailServiceConfig,
+
+            // Templates
+            TemplateServiceConfig,
+
             // Shared
             CaptchaServiceConfig,
             JwtServiceConfig,
@@ -48,8 +81,12 @@ provider! {
             AuthServiceConfig,
 
             // Core
+            CheckoutFeatureConfig,
             ContactFeatureConfig,
             HealthFeatureConfig,
+            InvoiceServiceConfig,
+            MfaFeatureConfig,
+            NewsletterCampaignServiceConfig,
             SessionFeatureConfig,
             UserFeatureConfig,
         }
@@ -75,10 +112,23 @@ provider! {
         rest_server_config: RestServerConfig,
 
         // Extern
-        internal_api_service_config: InternalApiServiceConfig,
+        dns_resolver_service_config: DnsResolverServiceConfig,
+        hcaptcha_api_service_config: HcaptchaApiServiceConfig,
+        payment_api_service_config: PaymentApiServiceConfig,
         recaptcha_api_service_config: RecaptchaApiServiceConfig,
+        turnstile_api_service_config: TurnstileApiServiceConfig,
         vat_api_service_config: VatApiServiceConfig,
 
+        // Storage
+        local_storage_config: LocalStorageConfig,
+
+        // Email
+        email_outbox_service_config: EmailOutboxServiceConfig,
+        template_email_service_config: TemplateEmailServiceConfig,
+
+        // Templates
+        template_service_config: TemplateServiceConfig,
+
         // Shared
         captcha_service_config: CaptchaServiceConfig,
         jwt_service_config: JwtServiceConfig,
@@ -89,8 +139,12 @@ provider! {
         auth_service_config: AuthServiceConfig,
 
         // Core
+        checkout_feature_config: CheckoutFeatureConfig,
         contact_feature_config: ContactFeatureConfig,
         health_feature_config: HealthFeatureConfig,
+        invoice_service_config: InvoiceServiceConfig,
+        mfa_feature_config: MfaFeatureConfig,
+        newsletter_campaign_service_config: NewsletterCampaignServiceConfig,
         session_feature_config: SessionFeatureConfig,
         user_feature_config: UserFeatureConfig,
     }
@@ -110,10 +164,6 @@ impl ConfigProvider {
         };
 
         // Extern
-        let internal_api_service_config = InternalApiServiceConfig {
-            shop_url: config.internal.shop_url.clone(),
-        };
-
         let recaptcha_api_service_config = RecaptchaApiServiceConfig::new(
             config
                 .recaptcha
@@ -121,17 +171,92 @@ impl ConfigProvider {
                 .and_then(|recaptcha| recaptcha.siteverify_endpoint_override.clone()),
         );
 
-        let vat_api_service_config =
-            VatApiServiceConfig::new(config.vat.validate_endpoint_override.clone());
+        let hcaptcha_api_service_config = HcaptchaApiServiceConfig::new(
+            config
+                .hcaptcha
+                .as_ref()
+                .and_then(|hcaptcha| hcaptcha.siteverify_endpoint_override.clone()),
+        );
+
+        let turnstile_api_service_config = TurnstileApiServiceConfig::new(
+            config
+                .turnstile
+                .as_ref()
+                .and_then(|turnstile| turnstile.siteverify_endpoint_override.clone()),
+        );
+
+        let vat_api_service_config = VatApiServiceConfig::new(
+            config.vat.validate_endpoint_override.clone(),
+            config.vat.requester_vat_id.as_deref(),
+        )?;
+
+        let payment_api_service_config = PaymentApiServiceConfig {
+            api_url: config.payment.api_url.clone().into(),
+            secret_key: config.payment.secret_key.as_str().into(),
+            webhook_secret: config.payment.webhook_secret.as_str().into(),
+        };
+
+        let dns_resolver_service_config = DnsResolverServiceConfig::new(
+            config.dns.nameserver_override,
+            config.dns.timeout.into(),
+        );
+
+        // Storage
+        let local_storage_config = LocalStorageConfig {
+            path: config.storage.path.as_path().into(),
+        };
+
+        // Email
+        let email_outbox_service_config = EmailOutboxServiceConfig {
+            batch_size: config.email.outbox_batch_size,
+            max_attempts: config.email.outbox_max_attempts,
+            retry_delay: config.email.outbox_retry_delay.into(),
+            max_retry_delay: config.email.outbox_max_retry_delay.into(),
+        };
+
+        let logo = config
+            .email
+            .logo_path
+            .as_deref()
+            .map(|path| load_inline_image("logo", path))
+            .transpose()?;
+
+        let template_email_service_config = TemplateEmailServiceConfig {
+            inline_images: logo.iter().cloned().collect(),
+        };
+
+        // Templates
+        let template_service_config = TemplateServiceConfig {
+            logo_src: match &logo {
+                Some(logo) => format!("cid:{}", logo.content_id).into(),
+                None => config.email.logo_url.as_str().into(),
+            },
+        };
 
         // Shared
-        let captcha_service_config = match config.recaptcha.as_ref() {
-            Some(recaptcha) => CaptchaServiceConfig::Recaptcha(RecaptchaCaptchaServiceConfig {
+        let captcha_service_config = if let Some(recaptcha) = &config.recaptcha {
+            CaptchaServiceConfig::Recaptcha(RecaptchaCaptchaServiceConfig {
                 sitekey: recaptcha.sitekey.clone().into(),
                 secret: recaptcha.secret.clone().into(),
                 min_score: recaptcha.min_score,
-            }),
-            None => CaptchaServiceConfig::Disabled,
+            })
+        } else if let Some(hcaptcha) = &config.hcaptcha {
+            CaptchaServiceConfig::Hcaptcha(SiteverifyCaptchaServiceConfig {
+                sitekey: hcaptcha.sitekey.clone().into(),
+                secret: hcaptcha.secret.clone().into(),
+            })
+        } else if let Some(turnstile) = &config.turnstile {
+            CaptchaServiceConfig::Turnstile(SiteverifyCaptchaServiceConfig {
+                sitekey: turnstile.sitekey.clone().into(),
+                secret: turnstile.secret.clone().into(),
+            })
+        } else if let Some(proof_of_work) = &config.proof_of_work {
+            CaptchaServiceConfig::ProofOfWork(ProofOfWorkCaptchaServiceConfig {
+                difficulty: proof_of_work.difficulty,
+                challenge_ttl: proof_of_work.challenge_ttl.into(),
+            })
+        } else {
+            CaptchaServiceConfig::Disabled
         };
 
         let jwt_service_config = JwtServiceConfig::new(&config.jwt.secret)?;
@@ -164,6 +289,7 @@ impl ConfigProvider {
                 })
                 .collect::<HashMap<_, _>>()
                 .into(),
+            account_recovery_url: config.user.account_recovery_url.clone().into(),
         };
 
         let totp_service_config = TotpServiceConfig {
@@ -179,8 +305,50 @@ impl ConfigProvider {
         };
 
         // Core
+        let checkout_feature_config =
+            CheckoutFeatureConfig {
+                packages: config
+                    .checkout
+                    .packages
+                    .iter()
+                    .map(|package| {
+                        Ok(CoinPackage {
+                            id: package.id.clone().try_into().with_context(|| {
+                                format!("Invalid coin package id {:?}", package.id)
+                            })?,
+                            coins: package.coins.try_into().with_context(|| {
+                                format!("Invalid number of coins in coin package {:?}", package.id)
+                            })?,
+                            price: package.price,
+                        })
+                    })
+                    .collect::<anyhow::Result<_>>()?,
+                success_url: config.checkout.success_url.as_str().into(),
+                cancel_url: config.checkout.cancel_url.as_str().into(),
+            };
+
         let contact_feature_config = ContactFeatureConfig {
             email: config.contact.email.clone().into(),
+            spam_reject_score: config.contact.spam_reject_score,
+            spam_quarantine_score: config.contact.spam_quarantine_score,
+            spam_link_score: config.contact.spam_link_score,
+            spam_keywords: config
+                .contact
+                .spam_keywords
+                .iter()
+                .map(|keyword| keyword.to_lowercase())
+                .collect(),
+            spam_keyword_score: config.contact.spam_keyword_score,
+            spam_domains: config
+                .contact
+                .spam_domains
+                .iter()
+                .map(|domain| domain.to_lowercase())
+                .collect(),
+            spam_domain_score: config.contact.spam_domain_score,
+            spam_repeat_window: config.contact.spam_repeat_window.into(),
+            spam_repeat_score: config.contact.spam_repeat_score,
+            spam_honeypot_score: config.contact.spam_honeypot_score,
         };
 
         let health_feature_config = HealthFeatureConfig {
@@ -189,22 +357,80 @@ impl ConfigProvider {
             email_cache_ttl: config.health.email_cache_ttl.into(),
         };
 
+        let invoice_service_config = InvoiceServiceConfig {
+            issuer: config.invoice.issuer.as_slice().into(),
+            issuer_vat_id: config.invoice.issuer_vat_id.as_deref().map(Into::into),
+            issuer_country: config.invoice.country,
+            number_prefix: config.invoice.number_prefix.as_str().into(),
+        };
+
+        let mfa_feature_config = MfaFeatureConfig {
+            account_recovery_url: config.user.account_recovery_url.clone().into(),
+        };
+
+        let newsletter_campaign_service_config = NewsletterCampaignServiceConfig {
+            batch_size: config.newsletter.batch_size,
+            locale: config.newsletter.locale.clone(),
+        };
+
         let session_feature_config = SessionFeatureConfig {
             login_fails_before_captcha: config.session.login_fails_before_captcha,
         };
 
+        let email_disposable_domains = match &config.user.email_disposable_list {
+            _ if !config.user.email_block_disposable => HashSet::new(),
+            Some(path) => parse_domain_list(&std::fs::read_to_string(path).with_context(|| {
+                format!(
+                    "Failed to read disposable email domain list at {}",
+                    path.display()
+                )
+            })?),
+            None => parse_domain_list(DISPOSABLE_EMAIL_DOMAINS),
+        };
+
         let user_feature_config = UserFeatureConfig {
             name_change_rate_limit: config.user.name_change_rate_limit.into(),
             verification_redirect_url: config.user.verification_redirect_url.clone().into(),
             verification_verification_code_ttl: config.user.verification_code_ttl.into(),
             password_reset_redirect_url: config.user.password_reset_redirect_url.clone().into(),
             password_reset_verification_code_ttl: config.user.password_reset_code_ttl.into(),
+            account_recovery_url: config.user.account_recovery_url.clone().into(),
             newsletter_subscription_redirect_url: config
                 .user
                 .newsletter_redirect_url
                 .clone()
                 .into(),
             newsletter_subscription_verification_code_ttl: config.user.newsletter_code_ttl.into(),
+            newsletter_unsubscribe_url: config.user.newsletter_unsubscribe_url.clone().into(),
+            newsletter_unsubscribe_redirect_url: config
+                .user
+                .newsletter_unsubscribe_redirect_url
+                .clone()
+                .into(),
+            newsletter_unsubscribe_token_ttl: config.user.newsletter_unsubscribe_token_ttl.into(),
+            avatar_max_upload_size: config.user.avatar_max_upload_size,
+            avatar_sizes: config.user.avatar_sizes.as_slice().into(),
+            avatar_url: config.user.avatar_url.clone().into(),
+            list_cursor_ttl: config.user.list_cursor_ttl.into(),
+            default_locale: config.user.default_locale.clone(),
+            registration_mode: config.user.registration_mode,
+            invite_limit: config.user.invite_limit,
+            invite_ttl: config.user.invite_ttl.into(),
+            email_allowlist: config
+                .user
+                .email_allowlist
+                .iter()
+                .map(|domain| normalize_domain(domain))
+                .collect(),
+            email_denylist: config
+                .user
+                .email_denylist
+                .iter()
+                .map(|domain| normalize_domain(domain))
+                .collect(),
+            email_disposable_domains: email_disposable_domains.into(),
+            email_require_mx: config.user.email_require_mx,
+            vat_cache_ttl: config.vat.cache_ttl.into(),
         };
 
         Ok(Self {
@@ -214,10 +440,23 @@ impl ConfigProvider {
             rest_server_config,
 
             // Extern
-            internal_api_service_config,
+            dns_resolver_service_config,
+            hcaptcha_api_service_config,
+            payment_api_service_config,
             recaptcha_api_service_config,
+            turnstile_api_service_config,
             vat_api_service_config,
 
+            // Storage
+            local_storage_config,
+
+            // Email
+            email_outbox_service_config,
+            template_email_service_config,
+
+            // Templates
+            template_service_config,
+
             // Shared
             jwt_service_config,
             totp_service_config,
@@ -228,14 +467,42 @@ impl ConfigProvider {
             auth_service_config,
 
             // Core
+            checkout_feature_config,
             contact_feature_config,
             health_feature_config,
+            invoice_service_config,
+            mfa_feature_config,
+            newsletter_campaign_service_config,
             session_feature_config,
             user_feature_config,
         })
     }
 }
 
+/// Load an image which can be embedded in html emails using `cid:<content_id>`.
+fn load_inline_image(content_id: &str, path: &Path) -> anyhow::Result<EmailInlineImage> {
+    let content_type = match path.extension().and_then(|x| x.to_str()) {
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        _ => {
+            return Err(anyhow!(
+                "Unsupported image format of {}, expected png, jpeg or gif",
+                path.display()
+            ))
+        }
+    };
+
+    let data = std::fs::read(path)
+        .with_context(|| format!("Failed to read image at {}", path.display()))?;
+
+    Ok(EmailInlineImage {
+        content_id: content_id.into(),
+        content_type: content_type.into(),
+        data,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use academy_cache_valkey::ValkeyCache;
@@ -5,40 +5,58 @@ use academy_auth_impl::{
     refresh_token::AuthRefreshTokenServiceImpl, AuthServiceImpl,
 };
 use academy_cache_valkey::ValkeyCache;
+use academy_core_checkout_impl::{invoice::CheckoutInvoiceServiceImpl, CheckoutFeatureServiceImpl};
+use academy_core_coin_impl::{coin::CoinServiceImpl, CoinFeatureServiceImpl};
 use academy_core_config_impl::ConfigFeatureServiceImpl;
-use academy_core_contact_impl::ContactFeatureServiceImpl;
+use academy_core_contact_impl::{spam::ContactSpamServiceImpl, ContactFeatureServiceImpl};
 use academy_core_health_impl::HealthFeatureServiceImpl;
 use academy_core_internal_impl::InternalServiceImpl;
+use academy_core_invoice_impl::{invoice::InvoiceServiceImpl, InvoiceFeatureServiceImpl};
 use academy_core_mfa_impl::{
     authenticate::MfaAuthenticateServiceImpl, disable::MfaDisableServiceImpl,
     recovery::MfaRecoveryServiceImpl, totp_device::MfaTotpDeviceServiceImpl, MfaFeatureServiceImpl,
 };
+use academy_core_newsletter_impl::{
+    campaign::NewsletterCampaignServiceImpl, NewsletterFeatureServiceImpl,
+};
 use academy_core_oauth2_impl::{
     link::OAuth2LinkServiceImpl, login::OAuth2LoginServiceImpl,
     registration::OAuth2RegistrationServiceImpl, OAuth2FeatureServiceImpl,
 };
+use academy_core_outbox_impl::OutboxFeatureServiceImpl;
 use academy_core_session_impl::{
     failed_auth_count::SessionFailedAuthCountServiceImpl, session::SessionServiceImpl,
     SessionFeatureServiceImpl,
 };
+use academy_core_skills_impl::{progress::SkillProgressServiceImpl, SkillsFeatureServiceImpl};
 use academy_core_user_impl::{
-    email_confirmation::UserEmailConfirmationServiceImpl, update::UserUpdateServiceImpl,
-    user::UserServiceImpl, UserFeatureServiceImpl,
+    avatar::UserAvatarServiceImpl, email_confirmation::UserEmailConfirmationServiceImpl,
+    email_policy::UserEmailPolicyServiceImpl, invite::UserInviteServiceImpl,
+    newsletter::UserNewsletterServiceImpl, update::UserUpdateServiceImpl, user::UserServiceImpl,
+    vat::UserVatServiceImpl, UserFeatureServiceImpl,
+};
+use academy_email_impl::{
+    outbox::EmailOutboxServiceImpl, template::TemplateEmailServiceImpl, EmailServiceImpl,
 };
-use academy_email_impl::{template::TemplateEmailServiceImpl, EmailServiceImpl};
 use academy_extern_impl::{
-    internal::InternalApiServiceImpl, oauth2::OAuth2ApiServiceImpl,
-    recaptcha::RecaptchaApiServiceImpl, vat::VatApiServiceImpl,
+    dns::DnsResolverServiceImpl, hcaptcha::HcaptchaApiServiceImpl, oauth2::OAuth2ApiServiceImpl,
+    payment::PaymentApiServiceImpl, recaptcha::RecaptchaApiServiceImpl,
+    turnstile::TurnstileApiServiceImpl, vat::VatApiServiceImpl,
 };
 use academy_persistence_postgres::{
-    mfa::PostgresMfaRepository, oauth2::PostgresOAuth2Repository,
-    session::PostgresSessionRepository, user::PostgresUserRepository, PostgresDatabase,
+    checkout::PostgresCheckoutRepository, coin::PostgresCoinRepository,
+    contact::PostgresContactRepository, email_outbox::PostgresEmailOutboxRepository,
+    invite::PostgresInviteRepository, invoice::PostgresInvoiceRepository,
+    mfa::PostgresMfaRepository, newsletter::PostgresNewsletterRepository,
+    oauth2::PostgresOAuth2Repository, session::PostgresSessionRepository,
+    skill::PostgresSkillRepository, user::PostgresUserRepository, PostgresDatabase,
 };
 use academy_shared_impl::{
-    captcha::CaptchaServiceImpl, hash::HashServiceImpl, id::IdServiceImpl, jwt::JwtServiceImpl,
-    password::PasswordServiceImpl, secret::SecretServiceImpl, time::TimeServiceImpl,
-    totp::TotpServiceImpl,
+    captcha::CaptchaServiceImpl, hash::HashServiceImpl, id::IdServiceImpl, image::ImageServiceImpl,
+    jwt::JwtServiceImpl, password::PasswordServiceImpl, pdf::PdfServiceImpl,
+    secret::SecretServiceImpl, time::TimeServiceImpl, totp::TotpServiceImpl,
 };
+use academy_storage_local::LocalStorage;
 use academy_templates_impl::TemplateServiceImpl;
 
 // API
@@ -49,7 +67,13 @@ pub type RestServer = academy_api_rest::RestServer<
     SessionFeature,
     ContactFeature,
     MfaFeature,
+    NewsletterFeature,
+    OutboxFeature,
     OAuth2Feature,
+    CoinFeature,
+    CheckoutFeature,
+    InvoiceFeature,
+    SkillsFeature,
     Internal,
 >;
 
@@ -59,25 +83,34 @@ pub type Database = PostgresDatabase;
 // Cache
 pub type Cache = ValkeyCache;
 
+// Storage
+pub type Storage = LocalStorage;
+
 // Email
 pub type Email = EmailServiceImpl;
-pub type TemplateEmail = TemplateEmailServiceImpl<Email, Template>;
+pub type EmailOutbox = EmailOutboxServiceImpl<Database, Id, Time, Email, EmailOutboxRepo>;
+pub type TemplateEmail = TemplateEmailServiceImpl<Email, Template, EmailOutbox>;
 
 // Extern
 pub type RecaptchaApi = RecaptchaApiServiceImpl;
+pub type HcaptchaApi = HcaptchaApiServiceImpl;
+pub type TurnstileApi = TurnstileApiServiceImpl;
 pub type OAuth2Api = OAuth2ApiServiceImpl;
-pub type InternalApi = InternalApiServiceImpl<AuthInternal>;
 pub type VatApi = VatApiServiceImpl;
+pub type PaymentApi = PaymentApiServiceImpl;
+pub type DnsResolver = DnsResolverServiceImpl;
 
 // Template
 pub type Template = TemplateServiceImpl;
 
 // Shared
-pub type Captcha = CaptchaServiceImpl<RecaptchaApi>;
+pub type Captcha = CaptchaServiceImpl<RecaptchaApi, HcaptchaApi, TurnstileApi, Secret, Cache>;
 pub type Hash = HashServiceImpl;
 pub type Id = IdServiceImpl;
+pub type Image = ImageServiceImpl;
 pub type Jwt = JwtServiceImpl<Time>;
 pub type Password = PasswordServiceImpl;
+pub type Pdf = PdfServiceImpl;
 pub type Secret = SecretServiceImpl;
 pub type Time = TimeServiceImpl;
 pub type Totp = TotpServiceImpl<Secret, Time, Hash, Cache>;
@@ -87,6 +120,14 @@ pub type SessionRepo = PostgresSessionRepository;
 pub type UserRepo = PostgresUserRepository;
 pub type MfaRepo = PostgresMfaRepository;
 pub type OAuth2Repo = PostgresOAuth2Repository;
+pub type InviteRepo = PostgresInviteRepository;
+pub type NewsletterRepo = PostgresNewsletterRepository;
+pub type EmailOutboxRepo = PostgresEmailOutboxRepository;
+pub type ContactRepo = PostgresContactRepository;
+pub type CoinRepo = PostgresCoinRepository;
+pub type CheckoutRepo = PostgresCheckoutRepository;
+pub type InvoiceRepo = PostgresInvoiceRepository;
+pub type SkillRepo = PostgresSkillRepository;
 
 // Auth
 pub type Auth =
@@ -104,19 +145,37 @@ pub type UserFeature = UserFeatureServiceImpl<
     Database,
     Auth,
     Captcha,
-    VatApi,
-    InternalApi,
+    Time,
+    Coin,
     User,
     UserEmailConfirmation,
+    UserEmailPolicy,
+    UserNewsletter,
     UserUpdate,
+    UserAvatar,
+    UserInvite,
+    UserVat,
     Session,
     OAuth2Registration,
     UserRepo,
+    InviteRepo,
+>;
+pub type User = UserServiceImpl<Id, Time, Auth, Password, Jwt, UserRepo, OAuth2Link>;
+pub type UserEmailConfirmation = UserEmailConfirmationServiceImpl<
+    Auth,
+    Secret,
+    TemplateEmail,
+    Cache,
+    UserNewsletter,
+    UserUpdate,
+    UserRepo,
 >;
-pub type User = UserServiceImpl<Id, Time, Password, UserRepo, OAuth2Link>;
-pub type UserEmailConfirmation =
-    UserEmailConfirmationServiceImpl<Auth, Secret, TemplateEmail, Cache, Password, UserRepo>;
-pub type UserUpdate = UserUpdateServiceImpl<Auth, Time, Password, Session, UserRepo>;
+pub type UserEmailPolicy = UserEmailPolicyServiceImpl<DnsResolver>;
+pub type UserNewsletter = UserNewsletterServiceImpl<Jwt>;
+pub type UserUpdate = UserUpdateServiceImpl<Auth, Time, Password, TemplateEmail, Session, UserRepo>;
+pub type UserAvatar = UserAvatarServiceImpl<Time, Image, Storage>;
+pub type UserInvite = UserInviteServiceImpl<Id, Time, Secret, InviteRepo>;
+pub type UserVat = UserVatServiceImpl<Time, Cache, VatApi>;
 
 pub type SessionFeature = SessionFeatureServiceImpl<
     Database,
@@ -131,11 +190,24 @@ pub type SessionFeature = SessionFeatureServiceImpl<
 pub type Session = SessionServiceImpl<Id, Time, Auth, AuthAccessToken, SessionRepo, UserRepo>;
 pub type SessionFailedAuthCount = SessionFailedAuthCountServiceImpl<Hash, Cache>;
 
-pub type ContactFeature = ContactFeatureServiceImpl<Captcha, Email>;
+pub type ContactFeature = ContactFeatureServiceImpl<
+    Database,
+    Auth,
+    Id,
+    Time,
+    Captcha,
+    Email,
+    EmailOutbox,
+    ContactSpam,
+    UserRepo,
+    ContactRepo,
+>;
+pub type ContactSpam = ContactSpamServiceImpl<Hash, Cache>;
 
 pub type MfaFeature = MfaFeatureServiceImpl<
     Database,
     Auth,
+    TemplateEmail,
     UserRepo,
     MfaRepo,
     MfaRecovery,
@@ -144,13 +216,27 @@ pub type MfaFeature = MfaFeatureServiceImpl<
 >;
 pub type MfaRecovery = MfaRecoveryServiceImpl<Secret, Hash, MfaRepo>;
 pub type MfaAuthenticate = MfaAuthenticateServiceImpl<Hash, Totp, MfaDisable, MfaRepo>;
-pub type MfaDisable = MfaDisableServiceImpl<MfaRepo>;
+pub type MfaDisable = MfaDisableServiceImpl<TemplateEmail, UserRepo, MfaRepo>;
 pub type MfaTotpDevice = MfaTotpDeviceServiceImpl<Id, Time, Totp, MfaRepo>;
 
+pub type NewsletterFeature =
+    NewsletterFeatureServiceImpl<Database, Auth, Id, Time, NewsletterCampaign, NewsletterRepo>;
+pub type NewsletterCampaign = NewsletterCampaignServiceImpl<
+    Database,
+    Time,
+    Template,
+    TemplateEmail,
+    UserNewsletter,
+    NewsletterRepo,
+>;
+
+pub type OutboxFeature = OutboxFeatureServiceImpl<Database, Auth, Time, EmailOutboxRepo>;
+
 pub type OAuth2Feature = OAuth2FeatureServiceImpl<
     Database,
     Auth,
     OAuth2Api,
+    TemplateEmail,
     UserRepo,
     OAuth2Repo,
     OAuth2Link,
@@ -162,4 +248,29 @@ pub type OAuth2Link = OAuth2LinkServiceImpl<Id, Time, OAuth2Repo>;
 pub type OAuth2Login = OAuth2LoginServiceImpl<OAuth2Api>;
 pub type OAuth2Registration = OAuth2RegistrationServiceImpl<Secret, Cache>;
 
+pub type CoinFeature =
+    CoinFeatureServiceImpl<Database, Auth, AuthInternal, Coin, UserRepo, CoinRepo>;
+pub type Coin = CoinServiceImpl<Id, Time, CoinRepo>;
+
+pub type CheckoutFeature = CheckoutFeatureServiceImpl<
+    Database,
+    Auth,
+    Id,
+    Time,
+    PaymentApi,
+    Coin,
+    CheckoutInvoice,
+    UserRepo,
+    CheckoutRepo,
+>;
+pub type CheckoutInvoice = CheckoutInvoiceServiceImpl<Invoice, UserRepo, CheckoutRepo>;
+
+pub type InvoiceFeature =
+    InvoiceFeatureServiceImpl<Database, Auth, AuthInternal, Invoice, UserRepo, InvoiceRepo>;
+pub type Invoice = InvoiceServiceImpl<Id, Time, Template, Pdf, Storage, InvoiceRepo>;
+
+pub type SkillsFeature =
+    SkillsFeatureServiceImpl<Database, Auth, Id, Time, SkillProgress, UserRepo, SkillRepo>;
+pub type SkillProgress = SkillProgressServiceImpl<SkillRepo>;
+
 pub type Internal = InternalServiceImpl<Database, AuthInternal, UserRepo>;
@@ -0,0 +1,29 @@
+use std::convert::Infallible;
+
+use academy_models::locale::Locale;
+use aide::OperationInput;
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header::ACCEPT_LANGUAGE, request::Parts},
+};
+
+/// Extract the preferred supported locale from the Accept-Language header
+pub struct AcceptLanguage(pub Option<Locale>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AcceptLanguage {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let locale = parts
+            .headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|header| header.to_str().ok())
+            .and_then(Locale::from_accept_language);
+
+        Ok(Self(locale))
+    }
+}
+
+impl OperationInput for AcceptLanguage {}
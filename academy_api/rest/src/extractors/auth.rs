@@ -11,6 +11,9 @@ use axum::{
 /// Extract Bearer API tokens from the Authorization header
 pub struct ApiToken<T: ApiTokenType = AccessToken>(pub T);
 
+/// Extract Bearer API tokens from the Authorization header if present
+pub struct OptionalApiToken<T: ApiTokenType = AccessToken>(pub Option<T>);
+
 pub trait ApiTokenType: for<'a> From<&'a str> + private::Sealed {
     const NAME: &str;
 }
@@ -53,3 +56,29 @@ impl<T: ApiTokenType> OperationInput for ApiToken<T> {
             .push([(T::NAME.into(), Vec::new())].into());
     }
 }
+
+#[async_trait]
+impl<S: Send + Sync, T: ApiTokenType> FromRequestParts<S> for OptionalApiToken<T> {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(
+            parts
+                .headers
+                .get(AUTHORIZATION)
+                .and_then(|x| x.to_str().ok())
+                .map(|x| x.strip_prefix("Bearer ").unwrap_or(x))
+                .filter(|x| !x.is_empty())
+                .map(Into::into),
+        ))
+    }
+}
+
+impl<T: ApiTokenType> OperationInput for OptionalApiToken<T> {
+    fn operation_input(_ctx: &mut GenContext, operation: &mut Operation) {
+        operation.security.push(Default::default());
+        operation
+            .security
+            .push([(T::NAME.into(), Vec::new())].into());
+    }
+}
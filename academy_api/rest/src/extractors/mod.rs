@@ -1,2 +1,4 @@
+pub mod accept_language;
 pub mod auth;
+pub mod payment_signature;
 pub mod user_agent;
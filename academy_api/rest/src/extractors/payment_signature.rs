@@ -0,0 +1,32 @@
+use std::convert::Infallible;
+
+use aide::OperationInput;
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{request::Parts, HeaderName},
+};
+
+const PAYMENT_SIGNATURE: HeaderName = HeaderName::from_static("payment-signature");
+
+/// Extract the signature of payment provider webhooks from the
+/// Payment-Signature header
+pub struct PaymentSignature(pub String);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for PaymentSignature {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(
+            parts
+                .headers
+                .get(PAYMENT_SIGNATURE)
+                .and_then(|x| x.to_str().ok())
+                .unwrap_or_default()
+                .into(),
+        ))
+    }
+}
+
+impl OperationInput for PaymentSignature {}
@@ -3,13 +3,19 @@ use std::{
     sync::Arc,
 };
 
+use academy_core_checkout_contracts::CheckoutFeatureService;
+use academy_core_coin_contracts::CoinFeatureService;
 use academy_core_config_contracts::ConfigFeatureService;
 use academy_core_contact_contracts::ContactFeatureService;
 use academy_core_health_contracts::HealthFeatureService;
 use academy_core_internal_contracts::InternalService;
+use academy_core_invoice_contracts::InvoiceFeatureService;
 use academy_core_mfa_contracts::MfaFeatureService;
+use academy_core_newsletter_contracts::NewsletterFeatureService;
 use academy_core_oauth2_contracts::OAuth2FeatureService;
+use academy_core_outbox_contracts::OutboxFeatureService;
 use academy_core_session_contracts::SessionFeatureService;
+use academy_core_skills_contracts::SkillsFeatureService;
 use academy_core_user_contracts::UserFeatureService;
 use academy_di::Build;
 use academy_models::auth::{AccessToken, InternalToken};
@@ -36,7 +42,22 @@ mod models;
 mod routes;
 
 #[derive(Debug, Clone, Build)]
-pub struct RestServer<Health, Config, User, Session, Contact, Mfa, OAuth2, Internal> {
+pub struct RestServer<
+    Health,
+    Config,
+    User,
+    Session,
+    Contact,
+    Mfa,
+    Newsletter,
+    Outbox,
+    OAuth2,
+    Coin,
+    Checkout,
+    Invoice,
+    Skills,
+    Internal,
+> {
     _config: RestServerConfig,
     health: Health,
     config: Config,
@@ -44,7 +65,13 @@ pub struct RestServer<Health, Config, User, Session, Contact, Mfa, OAuth2, Inter
     session: Session,
     contact: Contact,
     mfa: Mfa,
+    newsletter: Newsletter,
+    outbox: Outbox,
     oauth2: OAuth2,
+    coin: Coin,
+    checkout: Checkout,
+    invoice: Invoice,
+    skills: Skills,
     internal: Internal,
 }
 
@@ -60,8 +87,38 @@ pub struct RestServerRealIpConfig {
     pub set_from: IpAddr,
 }
 
-impl<Health, Config, User, Session, Contact, Mfa, OAuth2, Internal>
-    RestServer<Health, Config, User, Session, Contact, Mfa, OAuth2, Internal>
+impl<
+        Health,
+        Config,
+        User,
+        Session,
+        Contact,
+        Mfa,
+        Newsletter,
+        Outbox,
+        OAuth2,
+        Coin,
+        Checkout,
+        Invoice,
+        Skills,
+        Internal,
+    >
+    RestServer<
+        Health,
+        Config,
+        User,
+        Session,
+        Contact,
+        Mfa,
+        Newsletter,
+        Outbox,
+        OAuth2,
+        Coin,
+        Checkout,
+        Invoice,
+        Skills,
+        Internal,
+    >
 where
     Health: HealthFeatureService,
     Config: ConfigFeatureService,
@@ -69,7 +126,13 @@ where
     Session: SessionFeatureService,
     Contact: ContactFeatureService,
     Mfa: MfaFeatureService,
+    Newsletter: NewsletterFeatureService,
+    Outbox: OutboxFeatureService,
     OAuth2: OAuth2FeatureService,
+    Coin: CoinFeatureService,
+    Checkout: CheckoutFeatureService,
+    Invoice: InvoiceFeatureService,
+    Skills: SkillsFeatureService,
     Internal: InternalService,
 {
     pub async fn serve(self) -> anyhow::Result<()> {
@@ -93,7 +156,13 @@ where
                 routes::user::TAG,
                 routes::session::TAG,
                 routes::mfa::TAG,
+                routes::newsletter::TAG,
+                routes::outbox::TAG,
                 routes::oauth2::TAG,
+                routes::coin::TAG,
+                routes::checkout::TAG,
+                routes::invoice::TAG,
+                routes::skills::TAG,
                 routes::internal::TAG,
             ]
             .into_iter()
@@ -156,7 +225,13 @@ where
             .merge(routes::session::router(self.session.into()))
             .merge(routes::contact::router(self.contact.into()))
             .merge(routes::mfa::router(self.mfa.into()))
+            .merge(routes::newsletter::router(self.newsletter.into()))
+            .merge(routes::outbox::router(self.outbox.into()))
             .merge(routes::oauth2::router(self.oauth2.into()))
+            .merge(routes::coin::router(self.coin.into()))
+            .merge(routes::checkout::router(self.checkout.into()))
+            .merge(routes::invoice::router(self.invoice.into()))
+            .merge(routes::skills::router(self.skills.into()))
             .merge(routes::internal::router(self.internal.into()))
     }
 }
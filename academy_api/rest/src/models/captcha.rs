@@ -0,0 +1,61 @@
+use academy_models::captcha::{CaptchaChallenge, CaptchaConfig};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum ApiCaptchaConfig {
+    /// No captcha response is required.
+    Disabled,
+    /// A reCAPTCHA v3 response is required.
+    Recaptcha {
+        /// The public reCAPTCHA sitekey
+        sitekey: String,
+    },
+    /// An hCaptcha response is required.
+    Hcaptcha {
+        /// The public hCaptcha sitekey
+        sitekey: String,
+    },
+    /// A Cloudflare Turnstile response is required.
+    Turnstile {
+        /// The public Turnstile sitekey
+        sitekey: String,
+    },
+    /// The solution of a proof-of-work challenge is required.
+    ProofOfWork {
+        /// The number of leading zero bits of the SHA-256 hash of the
+        /// captcha response
+        difficulty: u8,
+    },
+}
+
+impl From<CaptchaConfig> for ApiCaptchaConfig {
+    fn from(value: CaptchaConfig) -> Self {
+        match value {
+            CaptchaConfig::Disabled => Self::Disabled,
+            CaptchaConfig::Recaptcha { sitekey } => Self::Recaptcha { sitekey },
+            CaptchaConfig::Hcaptcha { sitekey } => Self::Hcaptcha { sitekey },
+            CaptchaConfig::Turnstile { sitekey } => Self::Turnstile { sitekey },
+            CaptchaConfig::ProofOfWork { difficulty } => Self::ProofOfWork { difficulty },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ApiCaptchaChallenge {
+    /// The challenge to solve
+    pub challenge: String,
+    /// The number of leading zero bits of the SHA-256 hash of the captcha
+    /// response
+    pub difficulty: u8,
+}
+
+impl From<CaptchaChallenge> for ApiCaptchaChallenge {
+    fn from(value: CaptchaChallenge) -> Self {
+        Self {
+            challenge: value.challenge,
+            difficulty: value.difficulty,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use academy_models::{
+    checkout::{CoinOrder, CoinOrderId, CoinOrderStatus, CoinPackage, CoinPackageId},
+    coin::CoinAmount,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::user::ApiUserIdOrSelf;
+
+/// A package of coins which can be bought.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCoinPackage {
+    /// Package ID
+    pub id: CoinPackageId,
+    /// The number of coins in this package
+    pub coins: CoinAmount,
+    /// The gross price in euro cents
+    pub price: u64,
+}
+
+impl From<CoinPackage> for ApiCoinPackage {
+    fn from(value: CoinPackage) -> Self {
+        Self {
+            id: value.id,
+            coins: value.coins,
+            price: value.price,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCoinOrder {
+    /// Order ID
+    pub id: CoinOrderId,
+    /// ID of the ordered package
+    pub package: CoinPackageId,
+    /// The number of ordered coins
+    pub coins: CoinAmount,
+    /// The gross price in euro cents
+    pub price: u64,
+    pub status: CoinOrderStatus,
+    /// Timestamp at which the order has been created
+    pub created_at: i64,
+    /// Timestamp of the last status change
+    pub updated_at: i64,
+}
+
+impl From<CoinOrder> for ApiCoinOrder {
+    fn from(value: CoinOrder) -> Self {
+        Self {
+            id: value.id,
+            package: value.package,
+            coins: value.coins,
+            price: value.price,
+            status: value.status,
+            created_at: value.created_at.timestamp(),
+            updated_at: value.updated_at.timestamp(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathCoinOrderId {
+    pub user_id: ApiUserIdOrSelf,
+    pub order_id: CoinOrderId,
+}
@@ -0,0 +1,52 @@
+use academy_models::coin::{
+    CoinAmount, CoinBalance, CoinTransaction, CoinTransactionDescription, CoinTransactionId,
+    CoinTransactionKind, CoinTransactionReference,
+};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCoinBalance {
+    /// The number of coins the user can spend
+    pub coins: u64,
+    /// The number of coins which are withheld until the invoice info of the
+    /// user is complete
+    pub withheld_coins: u64,
+}
+
+impl From<CoinBalance> for ApiCoinBalance {
+    fn from(value: CoinBalance) -> Self {
+        Self {
+            coins: value.coins,
+            withheld_coins: value.withheld_coins,
+        }
+    }
+}
+
+/// An entry in the coin ledger of a user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCoinTransaction {
+    /// Transaction ID
+    pub id: CoinTransactionId,
+    pub kind: CoinTransactionKind,
+    /// The number of coins which have been moved by this transaction
+    pub coins: CoinAmount,
+    pub description: Option<CoinTransactionDescription>,
+    /// Idempotency key supplied when the transaction was created
+    pub reference: Option<CoinTransactionReference>,
+    /// Timestamp at which the transaction has been created
+    pub created_at: i64,
+}
+
+impl From<CoinTransaction> for ApiCoinTransaction {
+    fn from(value: CoinTransaction) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind,
+            coins: value.coins,
+            description: value.description,
+            reference: value.reference,
+            created_at: value.created_at.timestamp(),
+        }
+    }
+}
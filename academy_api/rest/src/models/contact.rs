@@ -1,12 +1,14 @@
 use academy_models::{
     contact::{
-        ContactMessage, ContactMessageAuthor, ContactMessageAuthorName, ContactMessageContent,
-        ContactMessageSubject,
+        ContactInboxMessage, ContactMessage, ContactMessageAuthor, ContactMessageAuthorName,
+        ContactMessageContent, ContactMessageId, ContactMessageReply, ContactMessageReplyId,
+        ContactMessageStatus, ContactMessageSubject,
     },
     email_address::EmailAddress,
+    user::UserId,
 };
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ApiContactMessage {
@@ -32,3 +34,68 @@ impl From<ApiContactMessage> for ContactMessage {
         }
     }
 }
+
+/// A message in the contact inbox.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiContactInboxMessage {
+    /// Message ID
+    pub id: ContactMessageId,
+    /// Full name of the author
+    pub name: ContactMessageAuthorName,
+    /// Email address of the author
+    pub email: EmailAddress,
+    /// Subject of the message
+    pub subject: ContactMessageSubject,
+    /// Content of the message
+    pub message: ContactMessageContent,
+    pub status: ContactMessageStatus,
+    /// ID of the admin responsible for answering the message
+    pub assignee: Option<UserId>,
+    /// Timestamp at which the message has been received
+    pub created_at: i64,
+}
+
+impl From<ContactInboxMessage> for ApiContactInboxMessage {
+    fn from(value: ContactInboxMessage) -> Self {
+        Self {
+            id: value.id,
+            name: value.message.author.name,
+            email: value.message.author.email,
+            subject: value.message.subject,
+            message: value.message.content,
+            status: value.status,
+            assignee: value.assignee,
+            created_at: value.created_at.timestamp(),
+        }
+    }
+}
+
+/// A reply which has been sent to the author of a contact message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiContactMessageReply {
+    /// Reply ID
+    pub id: ContactMessageReplyId,
+    /// ID of the admin who has written the reply (null if the user has been
+    /// deleted)
+    pub author: Option<UserId>,
+    /// Content of the reply
+    pub content: ContactMessageContent,
+    /// Timestamp at which the reply has been sent
+    pub created_at: i64,
+}
+
+impl From<ContactMessageReply> for ApiContactMessageReply {
+    fn from(value: ContactMessageReply) -> Self {
+        Self {
+            id: value.id,
+            author: value.author,
+            content: value.content,
+            created_at: value.created_at.timestamp(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathContactMessageId {
+    pub message_id: ContactMessageId,
+}
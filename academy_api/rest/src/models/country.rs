@@ -0,0 +1,28 @@
+use academy_models::{country::Country, locale::Locale};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ApiCountry {
+    /// ISO 3166-1 alpha-2 code of the country
+    pub code: Country,
+    /// Name of the country in the requested language
+    pub name: String,
+    /// Prefix of VAT IDs issued by this country (only for member states of
+    /// the European Union)
+    pub vat_prefix: Option<String>,
+    /// Regular expression which zip codes in this country must match after
+    /// conversion to uppercase (not set if zip codes are not validated)
+    pub zip_code_pattern: Option<String>,
+}
+
+impl ApiCountry {
+    pub fn new(country: Country, locale: &Locale) -> Self {
+        Self {
+            code: country,
+            name: country.name(locale).into(),
+            vat_prefix: country.vat_prefix().map(Into::into),
+            zip_code_pattern: country.zip_code_pattern().map(Into::into),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use academy_models::{
+    invite::{Invite, InviteCode, InviteId, InviteMaxUses},
+    user::{UserId, UserTag},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiInvite {
+    /// Invite ID
+    pub id: InviteId,
+    /// The code to enter during registration
+    pub code: InviteCode,
+    /// ID of the user who created the invite (null if the user has been
+    /// deleted or the invite has been created by an operator)
+    pub created_by: Option<UserId>,
+    /// Timestamp of creation
+    pub created_at: i64,
+    /// Timestamp after which the invite can no longer be used (null if it never
+    /// expires)
+    pub expires_at: Option<i64>,
+    /// The maximum number of users that can register using this invite (null
+    /// if unlimited)
+    pub max_uses: Option<InviteMaxUses>,
+    /// The number of users that have registered using this invite
+    pub uses: u32,
+    /// Whether users registering with this invite are granted admin privileges
+    pub admin: bool,
+    /// Tag added to the profile of users registering with this invite
+    pub cohort: Option<UserTag>,
+}
+
+impl From<Invite> for ApiInvite {
+    fn from(value: Invite) -> Self {
+        Self {
+            id: value.id,
+            code: value.code,
+            created_by: value.created_by,
+            created_at: value.created_at.timestamp(),
+            expires_at: value.expires_at.map(|x| x.timestamp()),
+            max_uses: value.max_uses,
+            uses: value.uses,
+            admin: value.admin,
+            cohort: value.cohort,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathInviteId {
+    pub invite_id: InviteId,
+}
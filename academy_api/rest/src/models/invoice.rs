@@ -0,0 +1,88 @@
+use academy_models::{
+    country::Country,
+    invoice::{
+        Invoice, InvoiceDescription, InvoiceId, InvoiceKind, InvoiceNumber, InvoiceRecipient,
+        InvoiceVatTreatment,
+    },
+    user::{UserCity, UserFirstName, UserLastName, UserStreet, UserVatId, UserZipCode},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::user::ApiUserIdOrSelf;
+
+/// An invoice for a purchase or a self-billed invoice for a payout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiInvoice {
+    /// Invoice ID
+    pub id: InvoiceId,
+    /// Sequential invoice number
+    pub number: InvoiceNumber,
+    pub kind: InvoiceKind,
+    /// Snapshot of the invoice info of the user at the time the invoice was
+    /// issued
+    pub recipient: ApiInvoiceRecipient,
+    pub description: InvoiceDescription,
+    /// The net amount in euro cents
+    pub net: u64,
+    /// The VAT in euro cents
+    pub vat: u64,
+    /// The gross amount in euro cents
+    pub gross: u64,
+    /// The VAT rate in basis points (1/100 of a percent)
+    pub vat_rate: u32,
+    pub vat_treatment: InvoiceVatTreatment,
+    /// Timestamp at which the invoice has been issued
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiInvoiceRecipient {
+    pub business: bool,
+    pub first_name: Option<UserFirstName>,
+    pub last_name: Option<UserLastName>,
+    pub street: Option<UserStreet>,
+    pub zip_code: Option<UserZipCode>,
+    pub city: Option<UserCity>,
+    pub country: Country,
+    pub vat_id: Option<UserVatId>,
+}
+
+impl From<Invoice> for ApiInvoice {
+    fn from(value: Invoice) -> Self {
+        Self {
+            id: value.id,
+            number: value.number,
+            kind: value.kind,
+            recipient: value.recipient.into(),
+            description: value.description,
+            net: value.net,
+            vat: value.vat,
+            gross: value.gross,
+            vat_rate: value.vat_rate,
+            vat_treatment: value.vat_treatment,
+            created_at: value.created_at.timestamp(),
+        }
+    }
+}
+
+impl From<InvoiceRecipient> for ApiInvoiceRecipient {
+    fn from(value: InvoiceRecipient) -> Self {
+        Self {
+            business: value.business,
+            first_name: value.first_name,
+            last_name: value.last_name,
+            street: value.street,
+            zip_code: value.zip_code,
+            city: value.city,
+            country: value.country,
+            vat_id: value.vat_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathInvoiceId {
+    pub user_id: ApiUserIdOrSelf,
+    pub invoice_id: InvoiceId,
+}
@@ -1,14 +1,24 @@
 use std::borrow::Cow;
 
-use academy_models::pagination::{PaginationLimit, PaginationSlice};
+use academy_models::pagination::{Pagination, PaginationCursor, PaginationLimit, PaginationSlice};
+use chrono::{DateTime, Utc};
 use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
-use serde::Deserialize;
+use serde::{de::Error, Deserialize};
 
 use crate::const_schema;
 
+pub mod captcha;
+pub mod checkout;
+pub mod coin;
 pub mod contact;
+pub mod country;
+pub mod invite;
+pub mod invoice;
+pub mod newsletter;
 pub mod oauth2;
+pub mod outbox;
 pub mod session;
+pub mod skill;
 pub mod user;
 
 const_schema! {
@@ -23,13 +33,23 @@ pub struct ApiPaginationSlice {
     /// The number of items to skip.
     #[serde(default)]
     pub offset: u64,
+    /// Return the items after this cursor instead of skipping `offset` items.
+    /// Cursors are returned as `next_cursor` by previous requests with the
+    /// same sorting.
+    pub cursor: Option<PaginationCursor>,
 }
 
-impl From<ApiPaginationSlice> for PaginationSlice {
+impl From<ApiPaginationSlice> for Pagination<PaginationCursor> {
     fn from(value: ApiPaginationSlice) -> Self {
-        Self {
-            limit: value.limit,
-            offset: value.offset,
+        match value.cursor {
+            Some(cursor) => Self::After {
+                limit: value.limit,
+                key: cursor,
+            },
+            None => Self::Offset(PaginationSlice {
+                limit: value.limit,
+                offset: value.offset,
+            }),
         }
     }
 }
@@ -94,6 +114,47 @@ impl<T> From<StringOption<T>> for Option<T> {
     }
 }
 
+/// Unix timestamp (in seconds) which fails to deserialize if it is out of
+/// range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApiTimestamp(pub DateTime<Utc>);
+
+impl<'de> Deserialize<'de> for ApiTimestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let timestamp = i64::deserialize(deserializer)?;
+        DateTime::from_timestamp(timestamp, 0)
+            .map(Self)
+            .ok_or_else(|| D::Error::custom(format!("timestamp out of range: {timestamp}")))
+    }
+}
+
+impl JsonSchema for ApiTimestamp {
+    fn schema_name() -> String {
+        i64::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        i64::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        i64::is_referenceable()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        i64::schema_id()
+    }
+}
+
+impl From<ApiTimestamp> for DateTime<Utc> {
+    fn from(value: ApiTimestamp) -> Self {
+        value.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use academy_models::user::UserPassword;
@@ -120,4 +181,17 @@ mod tests {
         );
         assert_eq!(y, None);
     }
+
+    #[test]
+    fn timestamp() {
+        let x = serde_json::Value::Number(1700000000.into());
+        let y = serde_json::from_value::<ApiTimestamp>(x).unwrap();
+        assert_eq!(y.0, DateTime::from_timestamp(1700000000, 0).unwrap());
+
+        let x = serde_json::Value::Number(i64::MAX.into());
+        serde_json::from_value::<ApiTimestamp>(x).unwrap_err();
+
+        let x = serde_json::Value::Number(i64::MIN.into());
+        serde_json::from_value::<ApiTimestamp>(x).unwrap_err();
+    }
 }
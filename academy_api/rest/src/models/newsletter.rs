@@ -0,0 +1,95 @@
+use academy_models::newsletter::{
+    NewsletterCampaign, NewsletterCampaignBody, NewsletterCampaignId, NewsletterCampaignPreview,
+    NewsletterCampaignProgress, NewsletterCampaignStatus, NewsletterCampaignSubject,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiNewsletterCampaign {
+    /// Campaign ID
+    pub id: NewsletterCampaignId,
+    /// Subject of the newsletter email
+    pub subject: NewsletterCampaignSubject,
+    /// HTML content of the newsletter
+    pub body_html: NewsletterCampaignBody,
+    /// Plain text content of the newsletter
+    pub body_text: NewsletterCampaignBody,
+    pub status: NewsletterCampaignStatus,
+    /// Timestamp of creation
+    pub created_at: i64,
+    /// Timestamp at which sending the campaign has been started
+    pub started_at: Option<i64>,
+    /// Timestamp at which the campaign has been sent to all recipients
+    pub completed_at: Option<i64>,
+    /// Number of recipients by delivery status
+    pub progress: ApiNewsletterCampaignProgress,
+}
+
+impl From<NewsletterCampaign> for ApiNewsletterCampaign {
+    fn from(value: NewsletterCampaign) -> Self {
+        Self {
+            id: value.id,
+            subject: value.subject,
+            body_html: value.body_html,
+            body_text: value.body_text,
+            status: value.status,
+            created_at: value.created_at.timestamp(),
+            started_at: value.started_at.map(|x| x.timestamp()),
+            completed_at: value.completed_at.map(|x| x.timestamp()),
+            progress: value.progress.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiNewsletterCampaignProgress {
+    /// Total number of recipients
+    pub total: u64,
+    /// Number of recipients the newsletter has not been sent to yet
+    pub pending: u64,
+    /// Number of recipients the newsletter has been sent to
+    pub sent: u64,
+    /// Number of recipients the newsletter could not be delivered to
+    pub failed: u64,
+    /// Number of recipients who unsubscribed before the newsletter was sent
+    /// to them
+    pub skipped: u64,
+}
+
+impl From<NewsletterCampaignProgress> for ApiNewsletterCampaignProgress {
+    fn from(value: NewsletterCampaignProgress) -> Self {
+        Self {
+            total: value.total(),
+            pending: value.pending,
+            sent: value.sent,
+            failed: value.failed,
+            skipped: value.skipped,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiNewsletterCampaignPreview {
+    /// Subject of the newsletter email
+    pub subject: NewsletterCampaignSubject,
+    /// Rendered HTML email
+    pub body_html: String,
+    /// Rendered plain text email
+    pub body_text: String,
+}
+
+impl From<NewsletterCampaignPreview> for ApiNewsletterCampaignPreview {
+    fn from(value: NewsletterCampaignPreview) -> Self {
+        Self {
+            subject: value.subject,
+            body_html: value.body_html,
+            body_text: value.body_text,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathNewsletterCampaignId {
+    pub campaign_id: NewsletterCampaignId,
+}
@@ -0,0 +1,50 @@
+use academy_models::{
+    email_address::EmailAddress,
+    email_outbox::{EmailOutboxEntry, EmailOutboxId, EmailOutboxStatus},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// An email in the outbox. The body is omitted because it may contain
+/// confidential data like verification codes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiOutboxEmail {
+    /// Email ID
+    pub id: EmailOutboxId,
+    /// Address of the recipient
+    pub recipient: EmailAddress,
+    /// Subject of the email
+    pub subject: String,
+    pub status: EmailOutboxStatus,
+    /// Number of failed delivery attempts
+    pub attempts: u32,
+    /// Timestamp at which the email has been queued
+    pub created_at: i64,
+    /// Timestamp of the next delivery attempt
+    pub next_attempt_at: Option<i64>,
+    /// Timestamp at which the email has been sent
+    pub sent_at: Option<i64>,
+    /// Error of the most recent failed delivery attempt
+    pub last_error: Option<String>,
+}
+
+impl From<EmailOutboxEntry> for ApiOutboxEmail {
+    fn from(value: EmailOutboxEntry) -> Self {
+        Self {
+            id: value.id,
+            recipient: value.email.recipient.into_email_address(),
+            subject: value.email.subject,
+            status: value.status,
+            attempts: value.attempts,
+            created_at: value.created_at.timestamp(),
+            next_attempt_at: value.next_attempt_at.map(|x| x.timestamp()),
+            sent_at: value.sent_at.map(|x| x.timestamp()),
+            last_error: value.last_error,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathEmailOutboxId {
+    pub email_id: EmailOutboxId,
+}
@@ -0,0 +1,258 @@
+use academy_core_skills_contracts::{CourseOutline, CourseOutlineSection, SkillTree};
+use academy_models::skill::{
+    Course, CourseDescription, CourseId, CourseProgress, CourseSection, CourseSectionId,
+    CourseSectionTitle, CourseTitle, LearningProgress, Lecture, LectureDescription, LectureId,
+    LectureTitle, Skill, SkillDescription, SkillId, SkillPrerequisite, SkillProgress, SkillTitle,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::user::ApiUserIdOrSelf;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiSkill {
+    /// Skill ID
+    pub id: SkillId,
+    pub title: SkillTitle,
+    pub description: SkillDescription,
+    /// Timestamp of creation
+    pub created_at: i64,
+}
+
+impl From<Skill> for ApiSkill {
+    fn from(value: Skill) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            description: value.description,
+            created_at: value.created_at.timestamp(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiSkillPrerequisite {
+    pub skill_id: SkillId,
+    /// The skill which has to be completed before `skill_id` is unlocked
+    pub prerequisite_id: SkillId,
+}
+
+impl From<SkillPrerequisite> for ApiSkillPrerequisite {
+    fn from(value: SkillPrerequisite) -> Self {
+        Self {
+            skill_id: value.skill_id,
+            prerequisite_id: value.prerequisite_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiSkillTree {
+    pub skills: Vec<ApiSkill>,
+    /// The edges of the skill tree
+    pub prerequisites: Vec<ApiSkillPrerequisite>,
+}
+
+impl From<SkillTree> for ApiSkillTree {
+    fn from(value: SkillTree) -> Self {
+        Self {
+            skills: value.skills.into_iter().map(Into::into).collect(),
+            prerequisites: value.prerequisites.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCourse {
+    /// Course ID
+    pub id: CourseId,
+    /// The skill which is learned by this course
+    pub skill_id: SkillId,
+    pub title: CourseTitle,
+    pub description: CourseDescription,
+    /// Timestamp of creation
+    pub created_at: i64,
+}
+
+impl From<Course> for ApiCourse {
+    fn from(value: Course) -> Self {
+        Self {
+            id: value.id,
+            skill_id: value.skill_id,
+            title: value.title,
+            description: value.description,
+            created_at: value.created_at.timestamp(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCourseSection {
+    /// Section ID
+    pub id: CourseSectionId,
+    pub course_id: CourseId,
+    pub title: CourseSectionTitle,
+    /// Position of the section within the course
+    pub position: u32,
+}
+
+impl From<CourseSection> for ApiCourseSection {
+    fn from(value: CourseSection) -> Self {
+        Self {
+            id: value.id,
+            course_id: value.course_id,
+            title: value.title,
+            position: value.position,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiLecture {
+    /// Lecture ID
+    pub id: LectureId,
+    pub section_id: CourseSectionId,
+    pub title: LectureTitle,
+    pub description: LectureDescription,
+    /// Position of the lecture within the section
+    pub position: u32,
+}
+
+impl From<Lecture> for ApiLecture {
+    fn from(value: Lecture) -> Self {
+        Self {
+            id: value.id,
+            section_id: value.section_id,
+            title: value.title,
+            description: value.description,
+            position: value.position,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCourseOutline {
+    pub course: ApiCourse,
+    /// The sections of the course in order
+    pub sections: Vec<ApiCourseOutlineSection>,
+}
+
+impl From<CourseOutline> for ApiCourseOutline {
+    fn from(value: CourseOutline) -> Self {
+        Self {
+            course: value.course.into(),
+            sections: value.sections.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCourseOutlineSection {
+    pub section: ApiCourseSection,
+    /// The lectures of the section in order
+    pub lectures: Vec<ApiLecture>,
+}
+
+impl From<CourseOutlineSection> for ApiCourseOutlineSection {
+    fn from(value: CourseOutlineSection) -> Self {
+        Self {
+            section: value.section.into(),
+            lectures: value.lectures.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiLearningProgress {
+    /// Number of completed lectures
+    pub completed_lectures: u64,
+    /// Total number of lectures
+    pub total_lectures: u64,
+    /// Percentage of completed lectures, rounded down
+    pub percent: u8,
+}
+
+impl From<LearningProgress> for ApiLearningProgress {
+    fn from(value: LearningProgress) -> Self {
+        Self {
+            completed_lectures: value.completed_lectures,
+            total_lectures: value.total_lectures,
+            percent: value.percent(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiSkillProgress {
+    pub skill_id: SkillId,
+    /// Combined progress in all courses of the skill
+    pub progress: ApiLearningProgress,
+    /// Whether all prerequisites of the skill have been completed
+    pub unlocked: bool,
+}
+
+impl From<SkillProgress> for ApiSkillProgress {
+    fn from(value: SkillProgress) -> Self {
+        Self {
+            skill_id: value.skill_id,
+            progress: value.progress.into(),
+            unlocked: value.unlocked,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ApiCourseProgress {
+    pub course_id: CourseId,
+    pub progress: ApiLearningProgress,
+    /// The lectures of the course which have been completed
+    pub completed_lectures: Vec<LectureId>,
+}
+
+impl From<CourseProgress> for ApiCourseProgress {
+    fn from(value: CourseProgress) -> Self {
+        Self {
+            course_id: value.course_id,
+            progress: value.progress.into(),
+            completed_lectures: value.completed_lectures,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathSkillId {
+    pub skill_id: SkillId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathSkillPrerequisite {
+    pub skill_id: SkillId,
+    pub prerequisite_id: SkillId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathCourseId {
+    pub course_id: CourseId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathCourseSectionId {
+    pub section_id: CourseSectionId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathLectureId {
+    pub lecture_id: LectureId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathUserCourseId {
+    pub user_id: ApiUserIdOrSelf,
+    pub course_id: CourseId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PathUserLectureId {
+    pub user_id: ApiUserIdOrSelf,
+    pub lecture_id: LectureId,
+}
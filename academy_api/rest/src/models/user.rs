@@ -1,10 +1,15 @@
 use academy_models::{
+    country::Country,
     email_address::EmailAddress,
+    locale::Locale,
+    oauth2::OAuth2ProviderId,
+    pagination::SortDirection,
     url::Url,
     user::{
-        UserBio, UserCity, UserComposite, UserCountry, UserDisplayName, UserFilter, UserFirstName,
-        UserId, UserIdOrSelf, UserLastName, UserName, UserPassword, UserStreet, UserTags,
-        UserVatId, UserZipCode,
+        UserBio, UserCity, UserComposite, UserDisplayName, UserFilter, UserFirstName, UserId,
+        UserIdOrSelf, UserLastName, UserName, UserPassword, UserPrivacy, UserProfileVisibility,
+        UserPublicProfile, UserSortField, UserSorting, UserStreet, UserTags, UserVatId,
+        UserVatIdStatus, UserZipCode,
     },
     SearchTerm,
 };
@@ -16,6 +21,7 @@ use schemars::{
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use super::ApiTimestamp;
 use crate::const_schema;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -52,6 +58,8 @@ pub struct ApiUser {
     pub tags: UserTags,
     /// Whether the user is subscribed to the newsletter
     pub newsletter: bool,
+    /// Preferred language of the user, e.g. for emails
+    pub locale: Locale,
     /// Whether the user represents a business instead of a private person
     pub business: Option<bool>,
     /// First name of the user
@@ -65,14 +73,18 @@ pub struct ApiUser {
     /// City of the user's address
     pub city: Option<UserCity>,
     /// Country of the user's address
-    pub country: Option<UserCountry>,
+    pub country: Option<Country>,
     /// Vat ID of the user
     pub vat_id: Option<UserVatId>,
+    /// Result of the last validation of the vat ID (`pending` if VIES was
+    /// unavailable)
+    pub vat_id_status: Option<UserVatIdStatus>,
     /// Whether the user can buy coins
     pub can_buy_coins: bool,
     /// Whether the user can receive coins
     pub can_receive_coins: bool,
-    /// URL of the user's avatar
+    /// URL of the user's avatar (falls back to Gravatar if the user has not
+    /// uploaded an avatar)
     pub avatar_url: Option<Url>,
 }
 
@@ -88,7 +100,9 @@ impl From<UserComposite> for ApiUser {
             invoice_info,
         } = user_composite;
 
-        let avatar_url = user.email.as_ref().map(get_avatar_url);
+        let avatar_url = profile
+            .avatar_url
+            .or_else(|| user.email.as_ref().map(get_avatar_url));
 
         Self {
             id: user.id,
@@ -101,6 +115,7 @@ impl From<UserComposite> for ApiUser {
             enabled: user.enabled,
             admin: user.admin,
             newsletter: user.newsletter,
+            locale: user.locale,
 
             display_name: profile.display_name,
             description: profile.bio,
@@ -117,6 +132,7 @@ impl From<UserComposite> for ApiUser {
             city: invoice_info.city,
             country: invoice_info.country,
             vat_id: invoice_info.vat_id,
+            vat_id_status: invoice_info.vat_id_validation.map(|x| x.status),
 
             avatar_url,
             can_buy_coins,
@@ -125,8 +141,71 @@ impl From<UserComposite> for ApiUser {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiUserPublicProfile {
+    /// User ID
+    pub id: UserId,
+    /// Unique user name
+    pub name: UserName,
+    /// Display name (null if hidden by the user's privacy settings)
+    pub display_name: Option<UserDisplayName>,
+    /// Bio of the user profile (null if hidden by the user's privacy settings)
+    pub description: Option<UserBio>,
+    /// Tags of the user profile (null if hidden by the user's privacy
+    /// settings)
+    pub tags: Option<UserTags>,
+    /// Timestamp of creation (null if hidden by the user's privacy settings)
+    pub registration: Option<i64>,
+    /// URL of the user's uploaded avatar (null if the user has not uploaded an
+    /// avatar or if it is hidden by the user's privacy settings)
+    pub avatar_url: Option<Url>,
+}
+
+impl From<UserPublicProfile> for ApiUserPublicProfile {
+    fn from(value: UserPublicProfile) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            display_name: value.display_name,
+            description: value.bio,
+            tags: value.tags,
+            registration: value.created_at.map(|x| x.timestamp()),
+            avatar_url: value.avatar_url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiUserPrivacy {
+    /// Visibility of the display name
+    pub display_name: UserProfileVisibility,
+    /// Visibility of the bio
+    pub description: UserProfileVisibility,
+    /// Visibility of the tags
+    pub tags: UserProfileVisibility,
+    /// Visibility of the registration timestamp
+    pub registration: UserProfileVisibility,
+    /// Visibility of the uploaded avatar
+    pub avatar: UserProfileVisibility,
+}
+
+impl From<UserPrivacy> for ApiUserPrivacy {
+    fn from(value: UserPrivacy) -> Self {
+        Self {
+            display_name: value.display_name,
+            description: value.bio,
+            tags: value.tags,
+            registration: value.registration,
+            avatar: value.avatar,
+        }
+    }
+}
+
 #[derive(Deserialize, JsonSchema)]
 pub struct ApiUserFilter {
+    /// Fuzzy search by `name`, `display_name`, `email`, `first_name` and
+    /// `last_name` (tolerates typos)
+    pub query: Option<SearchTerm>,
     /// Filter by `name` and `display_name`
     pub name: Option<SearchTerm>,
     /// Filter by `email`
@@ -141,11 +220,22 @@ pub struct ApiUserFilter {
     pub email_verified: Option<bool>,
     /// Filter by `newsletter`
     pub newsletter: Option<bool>,
+    /// Only include users registered at or after this timestamp
+    pub registered_after: Option<ApiTimestamp>,
+    /// Only include users registered before this timestamp
+    pub registered_before: Option<ApiTimestamp>,
+    /// Only include users whose last login was at or after this timestamp
+    pub last_login_after: Option<ApiTimestamp>,
+    /// Only include users whose last login was before this timestamp
+    pub last_login_before: Option<ApiTimestamp>,
+    /// Only include users linked to this OAuth2 provider
+    pub oauth2_provider: Option<OAuth2ProviderId>,
 }
 
 impl From<ApiUserFilter> for UserFilter {
     fn from(value: ApiUserFilter) -> Self {
         Self {
+            query: value.query,
             name: value.name,
             email: value.email,
             enabled: value.enabled,
@@ -153,6 +243,43 @@ impl From<ApiUserFilter> for UserFilter {
             mfa_enabled: value.mfa_enabled,
             email_verified: value.email_verified,
             newsletter: value.newsletter,
+            created_after: value.registered_after.map(Into::into),
+            created_before: value.registered_before.map(Into::into),
+            last_login_after: value.last_login_after.map(Into::into),
+            last_login_before: value.last_login_before.map(Into::into),
+            oauth2_provider: value.oauth2_provider,
+        }
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ApiUserSorting {
+    /// The field to sort by (defaults to `registration`)
+    #[serde(default)]
+    pub sort_by: ApiUserSortField,
+    /// The sort direction (defaults to `asc`)
+    #[serde(default)]
+    pub sort_direction: SortDirection,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiUserSortField {
+    #[default]
+    Registration,
+    LastLogin,
+    Name,
+}
+
+impl From<ApiUserSorting> for UserSorting {
+    fn from(value: ApiUserSorting) -> Self {
+        Self {
+            field: match value.sort_by {
+                ApiUserSortField::Registration => UserSortField::CreatedAt,
+                ApiUserSortField::LastLogin => UserSortField::LastLogin,
+                ApiUserSortField::Name => UserSortField::Name,
+            },
+            direction: value.sort_direction,
         }
     }
 }
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use academy_core_checkout_contracts::{
+    CheckoutCreateOrderError, CheckoutFeatureService, CheckoutGetOrderError,
+    CheckoutHandleWebhookError, CheckoutListOrdersError, CheckoutListOrdersResult, CheckoutResult,
+};
+use academy_models::{
+    checkout::CoinPackageId,
+    pagination::{PaginationLimit, PaginationSlice},
+    url::Url,
+};
+use aide::{
+    axum::{routing, ApiRouter},
+    transform::TransformOperation,
+};
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::user::UserNotFoundError;
+use crate::{
+    docs::TransformOperationExt,
+    error_code,
+    errors::{auth_error, auth_error_docs, internal_server_error, internal_server_error_docs},
+    extractors::{auth::ApiToken, payment_signature::PaymentSignature},
+    models::{
+        checkout::{ApiCoinOrder, ApiCoinPackage, PathCoinOrderId},
+        user::PathUserIdOrSelf,
+        OkResponse,
+    },
+};
+
+pub const TAG: &str = "Checkout";
+
+pub fn router(service: Arc<impl CheckoutFeatureService>) -> ApiRouter<()> {
+    ApiRouter::new()
+        .api_route(
+            "/auth/coins/packages",
+            routing::get_with(list_packages, list_packages_docs),
+        )
+        .api_route(
+            "/auth/coins/webhook",
+            routing::post_with(handle_webhook, handle_webhook_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/coins/orders",
+            routing::get_with(list_orders, list_orders_docs)
+                .post_with(create_order, create_order_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/coins/orders/:order_id",
+            routing::get_with(get_order, get_order_docs),
+        )
+        .with_state(service)
+        .with_path_items(|op| op.tag(TAG))
+}
+
+async fn list_packages(service: State<Arc<impl CheckoutFeatureService>>) -> Response {
+    Json(
+        service
+            .list_packages()
+            .into_iter()
+            .map(ApiCoinPackage::from)
+            .collect::<Vec<_>>(),
+    )
+    .into_response()
+}
+
+fn list_packages_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return all coin packages which can be bought.")
+        .add_response::<Vec<ApiCoinPackage>>(StatusCode::OK, None)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListOrdersQuery {
+    /// The number of items to select.
+    #[serde(default)]
+    limit: PaginationLimit,
+    /// The number of items to skip.
+    #[serde(default)]
+    offset: u64,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ListOrdersResult {
+    /// The total number of coin orders of the user
+    total: u64,
+    /// The paginated list of coin orders, most recent first
+    orders: Vec<ApiCoinOrder>,
+}
+
+async fn list_orders(
+    service: State<Arc<impl CheckoutFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+    Query(ListOrdersQuery { limit, offset }): Query<ListOrdersQuery>,
+) -> Response {
+    match service
+        .list_orders(&token.0, user_id.into(), PaginationSlice { limit, offset })
+        .await
+    {
+        Ok(CheckoutListOrdersResult { total, orders }) => Json(ListOrdersResult {
+            total,
+            orders: orders.into_iter().map(Into::into).collect(),
+        })
+        .into_response(),
+        Err(CheckoutListOrdersError::NotFound) => UserNotFoundError.into_response(),
+        Err(CheckoutListOrdersError::Auth(err)) => auth_error(err),
+        Err(CheckoutListOrdersError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_orders_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the coin orders of the given user.")
+        .add_response::<ListOrdersResult>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateOrderRequest {
+    /// ID of the coin package to buy
+    package: CoinPackageId,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CreateOrderResult {
+    /// The new coin order
+    order: ApiCoinOrder,
+    /// The url of the payment provider to which the user has to be
+    /// redirected to pay the order
+    redirect_url: Url,
+}
+
+async fn create_order(
+    service: State<Arc<impl CheckoutFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+    Json(CreateOrderRequest { package }): Json<CreateOrderRequest>,
+) -> Response {
+    match service
+        .create_order(&token.0, user_id.into(), package)
+        .await
+    {
+        Ok(CheckoutResult {
+            order,
+            redirect_url,
+        }) => Json(CreateOrderResult {
+            order: order.into(),
+            redirect_url,
+        })
+        .into_response(),
+        Err(CheckoutCreateOrderError::NotFound) => UserNotFoundError.into_response(),
+        Err(CheckoutCreateOrderError::PackageNotFound) => PackageNotFoundError.into_response(),
+        Err(CheckoutCreateOrderError::CannotBuyCoins) => CannotBuyCoinsError.into_response(),
+        Err(CheckoutCreateOrderError::Auth(err)) => auth_error(err),
+        Err(CheckoutCreateOrderError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn create_order_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Order a coin package.")
+        .description(
+            "The user has to be redirected to the returned url to pay the order. The coins are \
+             credited and an invoice is issued as soon as the payment provider reports the \
+             payment as successful.",
+        )
+        .add_response::<CreateOrderResult>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .add_error::<PackageNotFoundError>()
+        .add_error::<CannotBuyCoinsError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn get_order(
+    service: State<Arc<impl CheckoutFeatureService>>,
+    token: ApiToken,
+    Path(PathCoinOrderId { user_id, order_id }): Path<PathCoinOrderId>,
+) -> Response {
+    match service.get_order(&token.0, user_id.into(), order_id).await {
+        Ok(order) => Json(ApiCoinOrder::from(order)).into_response(),
+        Err(CheckoutGetOrderError::NotFound) => OrderNotFoundError.into_response(),
+        Err(CheckoutGetOrderError::Auth(err)) => auth_error(err),
+        Err(CheckoutGetOrderError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_order_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return a coin order of the given user.")
+        .add_response::<ApiCoinOrder>(StatusCode::OK, None)
+        .add_error::<OrderNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn handle_webhook(
+    service: State<Arc<impl CheckoutFeatureService>>,
+    signature: PaymentSignature,
+    payload: Bytes,
+) -> Response {
+    match service.handle_webhook(&signature.0, &payload).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(CheckoutHandleWebhookError::InvalidSignature) => InvalidSignatureError.into_response(),
+        Err(CheckoutHandleWebhookError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn handle_webhook_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Process a webhook request of the payment provider.")
+        .description(
+            "The request has to be signed by the payment provider using the `Payment-Signature` \
+             header.",
+        )
+        .add_response::<OkResponse>(StatusCode::OK, None)
+        .add_error::<InvalidSignatureError>()
+        .with(internal_server_error_docs)
+}
+
+error_code! {
+    /// The coin package does not exist.
+    PackageNotFoundError(NOT_FOUND, "Package not found");
+    /// The user is not allowed to buy coins.
+    CannotBuyCoinsError(FORBIDDEN, "Cannot buy coins");
+    /// The coin order does not exist.
+    OrderNotFoundError(NOT_FOUND, "Order not found");
+    /// The webhook signature is invalid.
+    InvalidSignatureError(UNAUTHORIZED, "Invalid signature");
+}
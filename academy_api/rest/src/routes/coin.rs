@@ -0,0 +1,271 @@
+use std::sync::Arc;
+
+use academy_core_coin_contracts::{
+    CoinFeatureService, CoinGetBalanceError, CoinInternalCreditError, CoinInternalDebitError,
+    CoinInternalGetBalanceError, CoinListTransactionsError, CoinListTransactionsResult,
+    CoinTransactionResult,
+};
+use academy_models::{
+    auth::InternalToken,
+    coin::{CoinAmount, CoinCreditKind, CoinTransactionDescription, CoinTransactionReference},
+    pagination::{PaginationLimit, PaginationSlice},
+};
+use aide::{
+    axum::{routing, ApiRouter},
+    transform::TransformOperation,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    internal::{internal_auth_error, internal_auth_error_docs},
+    user::UserNotFoundError,
+};
+use crate::{
+    docs::TransformOperationExt,
+    error_code,
+    errors::{auth_error, auth_error_docs, internal_server_error, internal_server_error_docs},
+    extractors::auth::ApiToken,
+    models::{
+        coin::{ApiCoinBalance, ApiCoinTransaction},
+        user::{PathUserId, PathUserIdOrSelf},
+    },
+};
+
+pub const TAG: &str = "Coins";
+
+pub fn router(service: Arc<impl CoinFeatureService>) -> ApiRouter<()> {
+    ApiRouter::new()
+        .api_route(
+            "/auth/users/:user_id/coins",
+            routing::get_with(get_balance, get_balance_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/coins/transactions",
+            routing::get_with(list_transactions, list_transactions_docs),
+        )
+        .api_route(
+            "/auth/_internal/coins/:user_id",
+            routing::get_with(internal_get_balance, internal_get_balance_docs),
+        )
+        .api_route(
+            "/auth/_internal/coins/:user_id/credit",
+            routing::post_with(internal_credit, internal_credit_docs),
+        )
+        .api_route(
+            "/auth/_internal/coins/:user_id/debit",
+            routing::post_with(internal_debit, internal_debit_docs),
+        )
+        .with_state(service)
+        .with_path_items(|op| op.tag(TAG))
+}
+
+async fn get_balance(
+    service: State<Arc<impl CoinFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+) -> Response {
+    match service.get_balance(&token.0, user_id.into()).await {
+        Ok(balance) => Json(ApiCoinBalance::from(balance)).into_response(),
+        Err(CoinGetBalanceError::NotFound) => UserNotFoundError.into_response(),
+        Err(CoinGetBalanceError::Auth(err)) => auth_error(err),
+        Err(CoinGetBalanceError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_balance_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the coin balance of the given user.")
+        .add_response::<ApiCoinBalance>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListTransactionsQuery {
+    /// The number of items to select.
+    #[serde(default)]
+    limit: PaginationLimit,
+    /// The number of items to skip.
+    #[serde(default)]
+    offset: u64,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ListTransactionsResult {
+    /// The total number of transactions of the user
+    total: u64,
+    /// The paginated list of transactions, most recent first
+    transactions: Vec<ApiCoinTransaction>,
+}
+
+async fn list_transactions(
+    service: State<Arc<impl CoinFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+    Query(ListTransactionsQuery { limit, offset }): Query<ListTransactionsQuery>,
+) -> Response {
+    match service
+        .list_transactions(&token.0, user_id.into(), PaginationSlice { limit, offset })
+        .await
+    {
+        Ok(CoinListTransactionsResult {
+            total,
+            transactions,
+        }) => Json(ListTransactionsResult {
+            total,
+            transactions: transactions.into_iter().map(Into::into).collect(),
+        })
+        .into_response(),
+        Err(CoinListTransactionsError::NotFound) => UserNotFoundError.into_response(),
+        Err(CoinListTransactionsError::Auth(err)) => auth_error(err),
+        Err(CoinListTransactionsError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_transactions_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the coin transactions of the given user.")
+        .add_response::<ListTransactionsResult>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn internal_get_balance(
+    service: State<Arc<impl CoinFeatureService>>,
+    token: ApiToken<InternalToken>,
+    Path(PathUserId { user_id }): Path<PathUserId>,
+) -> Response {
+    match service.internal_get_balance(&token.0, user_id).await {
+        Ok(balance) => Json(ApiCoinBalance::from(balance)).into_response(),
+        Err(CoinInternalGetBalanceError::NotFound) => UserNotFoundError.into_response(),
+        Err(CoinInternalGetBalanceError::Auth(err)) => internal_auth_error(err),
+        Err(CoinInternalGetBalanceError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn internal_get_balance_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the coin balance of the given user.")
+        .add_response::<ApiCoinBalance>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(internal_auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Serialize, JsonSchema)]
+struct TransactionResult {
+    /// The transaction which has been appended to the ledger
+    transaction: ApiCoinTransaction,
+    /// The new balance of the user
+    balance: ApiCoinBalance,
+}
+
+impl From<CoinTransactionResult> for TransactionResult {
+    fn from(value: CoinTransactionResult) -> Self {
+        Self {
+            transaction: value.transaction.into(),
+            balance: value.balance.into(),
+        }
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreditRequest {
+    kind: CoinCreditKind,
+    /// The number of coins to add
+    coins: CoinAmount,
+    description: Option<CoinTransactionDescription>,
+    /// Idempotency key, unique per user and kind of transaction
+    reference: Option<CoinTransactionReference>,
+}
+
+async fn internal_credit(
+    service: State<Arc<impl CoinFeatureService>>,
+    token: ApiToken<InternalToken>,
+    Path(PathUserId { user_id }): Path<PathUserId>,
+    Json(CreditRequest {
+        kind,
+        coins,
+        description,
+        reference,
+    }): Json<CreditRequest>,
+) -> Response {
+    match service
+        .internal_credit(&token.0, user_id, kind, coins, description, reference)
+        .await
+    {
+        Ok(result) => Json(TransactionResult::from(result)).into_response(),
+        Err(CoinInternalCreditError::NotFound) => UserNotFoundError.into_response(),
+        Err(CoinInternalCreditError::Auth(err)) => internal_auth_error(err),
+        Err(CoinInternalCreditError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn internal_credit_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Add coins to the wallet of the given user.")
+        .description(
+            "Earned coins are withheld if the invoice info of the user is incomplete. They are \
+             released automatically as soon as the user completes their invoice info.\n\nIf the \
+             user already has a transaction of the same kind with the given `reference`, this \
+             transaction is returned instead and no coins are added.",
+        )
+        .add_response::<TransactionResult>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(internal_auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct DebitRequest {
+    /// The number of coins to remove
+    coins: CoinAmount,
+    description: Option<CoinTransactionDescription>,
+    /// Idempotency key, unique per user
+    reference: Option<CoinTransactionReference>,
+}
+
+async fn internal_debit(
+    service: State<Arc<impl CoinFeatureService>>,
+    token: ApiToken<InternalToken>,
+    Path(PathUserId { user_id }): Path<PathUserId>,
+    Json(DebitRequest {
+        coins,
+        description,
+        reference,
+    }): Json<DebitRequest>,
+) -> Response {
+    match service
+        .internal_debit(&token.0, user_id, coins, description, reference)
+        .await
+    {
+        Ok(result) => Json(TransactionResult::from(result)).into_response(),
+        Err(CoinInternalDebitError::NotFound) => UserNotFoundError.into_response(),
+        Err(CoinInternalDebitError::NotEnoughCoins) => NotEnoughCoinsError.into_response(),
+        Err(CoinInternalDebitError::Auth(err)) => internal_auth_error(err),
+        Err(CoinInternalDebitError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn internal_debit_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Remove coins from the wallet of the given user.")
+        .description(
+            "If the user already has a debit transaction with the given `reference`, this \
+             transaction is returned instead and no coins are removed.",
+        )
+        .add_response::<TransactionResult>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .add_error::<NotEnoughCoinsError>()
+        .with(internal_auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+error_code! {
+    /// The user does not have enough coins.
+    NotEnoughCoinsError(PRECONDITION_FAILED, "Not enough coins");
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
-use academy_core_config_contracts::ConfigFeatureService;
+use academy_core_config_contracts::{ConfigCreateCaptchaChallengeError, ConfigFeatureService};
+use academy_models::{captcha::CaptchaConfig, locale::Locale};
 use aide::{
     axum::{routing, ApiRouter},
     transform::TransformOperation,
@@ -12,28 +13,123 @@ use axum::{
     Json,
 };
 
-use crate::docs::TransformOperationExt;
+use crate::{
+    docs::TransformOperationExt,
+    error_code,
+    errors::{internal_server_error, internal_server_error_docs},
+    extractors::accept_language::AcceptLanguage,
+    models::{
+        captcha::{ApiCaptchaChallenge, ApiCaptchaConfig},
+        country::ApiCountry,
+    },
+};
 
 pub const TAG: &str = "Config";
 
 pub fn router(service: Arc<impl ConfigFeatureService>) -> ApiRouter<()> {
     ApiRouter::new()
+        .api_route(
+            "/auth/captcha",
+            routing::get_with(get_captcha_config, get_captcha_config_docs),
+        )
+        .api_route(
+            "/auth/captcha/challenge",
+            routing::post_with(create_captcha_challenge, create_captcha_challenge_docs),
+        )
         .api_route(
             "/auth/recaptcha",
             routing::get_with(get_recaptcha_sitekey, get_recaptcha_sitekey_docs),
         )
+        .api_route(
+            "/auth/countries",
+            routing::get_with(list_countries, list_countries_docs),
+        )
         .with_state(service)
         .with_path_items(|op| op.tag(TAG))
 }
 
+async fn get_captcha_config(service: State<Arc<impl ConfigFeatureService>>) -> Response {
+    Json(ApiCaptchaConfig::from(service.get_captcha_config())).into_response()
+}
+
+fn get_captcha_config_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the public configuration of the captcha provider.")
+        .description(
+            "Endpoints which require a captcha expect the response of the configured provider in \
+             their `recaptcha_response` field.",
+        )
+        .add_response::<ApiCaptchaConfig>(StatusCode::OK, None)
+}
+
+async fn create_captcha_challenge(service: State<Arc<impl ConfigFeatureService>>) -> Response {
+    match service.create_captcha_challenge().await {
+        Ok(challenge) => Json(ApiCaptchaChallenge::from(challenge)).into_response(),
+        Err(ConfigCreateCaptchaChallengeError::NotAvailable) => {
+            ProofOfWorkDisabledError.into_response()
+        }
+        Err(ConfigCreateCaptchaChallengeError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn create_captcha_challenge_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Create a new proof-of-work captcha challenge.")
+        .description(
+            "The captcha response is `{challenge}:{nonce}` where `nonce` is an arbitrary string \
+             such that the SHA-256 hash of the response has at least `difficulty` leading zero \
+             bits. Every challenge expires after a few minutes and can only be used once.",
+        )
+        .add_response::<ApiCaptchaChallenge>(StatusCode::OK, None)
+        .add_error::<ProofOfWorkDisabledError>()
+        .with(internal_server_error_docs)
+}
+
 async fn get_recaptcha_sitekey(service: State<Arc<impl ConfigFeatureService>>) -> Response {
-    Json(service.get_recaptcha_sitekey()).into_response()
+    let sitekey = match service.get_captcha_config() {
+        CaptchaConfig::Recaptcha { sitekey } => Some(sitekey),
+        _ => None,
+    };
+    Json(sitekey).into_response()
 }
 
-fn get_recaptcha_sitekey_docs(op: TransformOperation) -> TransformOperation {
+fn get_recaptcha_sitekey_docs(mut op: TransformOperation) -> TransformOperation {
+    op.inner_mut().deprecated = true;
     op.summary("Return the public reCAPTCHA sitekey.")
-        .description("Returns `null` if reCAPTCHA is disabled.")
+        .description(
+            "Returns `null` if reCAPTCHA is disabled. Use `GET /auth/captcha` instead, which also \
+             supports the other captcha providers.",
+        )
         .add_response_with::<Option<&str>>(StatusCode::OK, None, |op| {
             op.example("recaptcha-sitekey")
         })
 }
+
+async fn list_countries(
+    service: State<Arc<impl ConfigFeatureService>>,
+    accept_language: AcceptLanguage,
+) -> Response {
+    let locale = accept_language
+        .0
+        .unwrap_or_else(|| Locale::FALLBACK.try_into().unwrap());
+    Json(
+        service
+            .list_countries()
+            .iter()
+            .map(|&country| ApiCountry::new(country, &locale))
+            .collect::<Vec<_>>(),
+    )
+    .into_response()
+}
+
+fn list_countries_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return all countries which can be used in invoice addresses.")
+        .description(
+            "Country names are translated to the language of the `Accept-Language` header (English \
+             if no translation is available).",
+        )
+        .add_response::<Vec<ApiCountry>>(StatusCode::OK, None)
+}
+
+error_code! {
+    /// The proof-of-work captcha is not enabled.
+    ProofOfWorkDisabledError(NOT_FOUND, "Proof of work disabled");
+}
@@ -1,25 +1,44 @@
 use std::sync::Arc;
 
-use academy_core_contact_contracts::{ContactFeatureService, ContactSendMessageError};
-use academy_models::RecaptchaResponse;
+use academy_core_contact_contracts::{
+    ContactAssignMessageError, ContactFeatureService, ContactGetMessageError,
+    ContactListMessagesError, ContactListQuery, ContactListRepliesError, ContactListResult,
+    ContactReplyToMessageError, ContactSendMessageError, ContactUpdateMessageStatusError,
+};
+use academy_models::{
+    contact::{ContactMessageContent, ContactMessageFilter, ContactMessageStatus},
+    pagination::{PaginationLimit, PaginationSlice},
+    user::UserId,
+    RecaptchaResponse,
+};
 use aide::{
     axum::{routing, ApiRouter},
     transform::TransformOperation,
 };
 use axum::{
-    extract::State,
+    extract::{Path, Query, State},
     http::StatusCode,
     response::{IntoResponse, Response},
-    Json,
+    Extension, Json,
 };
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     docs::TransformOperationExt,
     error_code,
-    errors::{internal_server_error, internal_server_error_docs, RecaptchaFailedError},
-    models::{contact::ApiContactMessage, OkResponse, StringOption},
+    errors::{
+        auth_error, auth_error_docs, internal_server_error, internal_server_error_docs,
+        RecaptchaFailedError,
+    },
+    extractors::auth::ApiToken,
+    middlewares::client_ip::ClientIp,
+    models::{
+        contact::{
+            ApiContactInboxMessage, ApiContactMessage, ApiContactMessageReply, PathContactMessageId,
+        },
+        OkResponse, StringOption,
+    },
 };
 
 pub const TAG: &str = "Contact";
@@ -30,6 +49,27 @@ pub fn router(service: Arc<impl ContactFeatureService>) -> ApiRouter<()> {
             "/auth/contact",
             routing::post_with(send_message, send_message_docs),
         )
+        .api_route(
+            "/auth/contact/messages",
+            routing::get_with(list_messages, list_messages_docs),
+        )
+        .api_route(
+            "/auth/contact/messages/:message_id",
+            routing::get_with(get_message, get_message_docs),
+        )
+        .api_route(
+            "/auth/contact/messages/:message_id/assignee",
+            routing::put_with(assign_message, assign_message_docs),
+        )
+        .api_route(
+            "/auth/contact/messages/:message_id/status",
+            routing::put_with(update_message_status, update_message_status_docs),
+        )
+        .api_route(
+            "/auth/contact/messages/:message_id/replies",
+            routing::get_with(list_replies, list_replies_docs)
+                .post_with(reply_to_message, reply_to_message_docs),
+        )
         .with_state(service)
         .with_path_items(|op| op.tag(TAG))
 }
@@ -41,36 +81,259 @@ struct SendMessageRequest {
     /// reCAPTCHA response. Required if reCAPTCHA is enabled.
     #[serde(default)]
     recaptcha_response: StringOption<RecaptchaResponse>,
+    /// Honeypot field which must be left empty. The frontend should render it
+    /// as a hidden input, so that it is only filled by spam bots.
+    #[serde(default)]
+    honeypot: Option<String>,
 }
 
 async fn send_message(
     service: State<Arc<impl ContactFeatureService>>,
+    Extension(ClientIp(client_ip)): Extension<ClientIp>,
     Json(SendMessageRequest {
         message,
         recaptcha_response,
+        honeypot,
     }): Json<SendMessageRequest>,
 ) -> Response {
     match service
-        .send_message(message.into(), recaptcha_response.into())
+        .send_message(
+            message.into(),
+            recaptcha_response.into(),
+            client_ip,
+            honeypot.is_some_and(|x| !x.is_empty()),
+        )
         .await
     {
         Ok(()) => Json(OkResponse).into_response(),
         Err(ContactSendMessageError::Recaptcha) => RecaptchaFailedError.into_response(),
-        Err(ContactSendMessageError::Send) => CouldNotSendMessageError.into_response(),
+        Err(ContactSendMessageError::Spam) => MessageRejectedError.into_response(),
         Err(ContactSendMessageError::Other(err)) => internal_server_error(err),
     }
 }
 
 fn send_message_docs(op: TransformOperation) -> TransformOperation {
     op.summary("Send a message to the support team.")
-        .description("A reCAPTCHA response is required if reCAPTCHA is enabled.")
+        .description(
+            "A reCAPTCHA response is required if reCAPTCHA is enabled. Messages which look like \
+             spam are rejected or quarantined.",
+        )
         .add_response::<OkResponse>(StatusCode::OK, "The message has been sent.")
         .add_error::<RecaptchaFailedError>()
-        .add_error::<CouldNotSendMessageError>()
+        .add_error::<MessageRejectedError>()
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListMessagesQuery {
+    /// Only return messages with this status
+    status: Option<ContactMessageStatus>,
+    /// Only return messages assigned to this user
+    assignee: Option<UserId>,
+    /// The number of items to select.
+    #[serde(default)]
+    limit: PaginationLimit,
+    /// The number of items to skip.
+    #[serde(default)]
+    offset: u64,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ListMessagesResult {
+    /// The total number of messages matching the given query
+    total: u64,
+    /// The paginated list of messages matching the given query, most recent
+    /// first
+    messages: Vec<ApiContactInboxMessage>,
+}
+
+async fn list_messages(
+    service: State<Arc<impl ContactFeatureService>>,
+    token: ApiToken,
+    Query(ListMessagesQuery {
+        status,
+        assignee,
+        limit,
+        offset,
+    }): Query<ListMessagesQuery>,
+) -> Response {
+    match service
+        .list_messages(
+            &token.0,
+            ContactListQuery {
+                filter: ContactMessageFilter { status, assignee },
+                pagination: PaginationSlice { limit, offset },
+            },
+        )
+        .await
+    {
+        Ok(ContactListResult { total, messages }) => Json(ListMessagesResult {
+            total,
+            messages: messages.into_iter().map(Into::into).collect(),
+        })
+        .into_response(),
+        Err(ContactListMessagesError::Auth(err)) => auth_error(err),
+        Err(ContactListMessagesError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_messages_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the messages in the contact inbox.")
+        .add_response::<ListMessagesResult>(StatusCode::OK, None)
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn get_message(
+    service: State<Arc<impl ContactFeatureService>>,
+    token: ApiToken,
+    Path(PathContactMessageId { message_id }): Path<PathContactMessageId>,
+) -> Response {
+    match service.get_message(&token.0, message_id).await {
+        Ok(message) => Json(ApiContactInboxMessage::from(message)).into_response(),
+        Err(ContactGetMessageError::NotFound) => MessageNotFoundError.into_response(),
+        Err(ContactGetMessageError::Auth(err)) => auth_error(err),
+        Err(ContactGetMessageError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_message_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the contact message with the given id.")
+        .add_response::<ApiContactInboxMessage>(StatusCode::OK, None)
+        .add_error::<MessageNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct AssignMessageRequest {
+    /// ID of the admin responsible for answering the message, or null to
+    /// remove the current assignee
+    assignee: Option<UserId>,
+}
+
+async fn assign_message(
+    service: State<Arc<impl ContactFeatureService>>,
+    token: ApiToken,
+    Path(PathContactMessageId { message_id }): Path<PathContactMessageId>,
+    Json(AssignMessageRequest { assignee }): Json<AssignMessageRequest>,
+) -> Response {
+    match service.assign_message(&token.0, message_id, assignee).await {
+        Ok(message) => Json(ApiContactInboxMessage::from(message)).into_response(),
+        Err(ContactAssignMessageError::NotFound) => MessageNotFoundError.into_response(),
+        Err(ContactAssignMessageError::InvalidAssignee) => InvalidAssigneeError.into_response(),
+        Err(ContactAssignMessageError::Auth(err)) => auth_error(err),
+        Err(ContactAssignMessageError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn assign_message_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Assign a contact message to an admin.")
+        .add_response::<ApiContactInboxMessage>(StatusCode::OK, None)
+        .add_error::<MessageNotFoundError>()
+        .add_error::<InvalidAssigneeError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdateMessageStatusRequest {
+    status: ContactMessageStatus,
+}
+
+async fn update_message_status(
+    service: State<Arc<impl ContactFeatureService>>,
+    token: ApiToken,
+    Path(PathContactMessageId { message_id }): Path<PathContactMessageId>,
+    Json(UpdateMessageStatusRequest { status }): Json<UpdateMessageStatusRequest>,
+) -> Response {
+    match service
+        .update_message_status(&token.0, message_id, status)
+        .await
+    {
+        Ok(message) => Json(ApiContactInboxMessage::from(message)).into_response(),
+        Err(ContactUpdateMessageStatusError::NotFound) => MessageNotFoundError.into_response(),
+        Err(ContactUpdateMessageStatusError::Auth(err)) => auth_error(err),
+        Err(ContactUpdateMessageStatusError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn update_message_status_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Mark a contact message as open or resolved.")
+        .add_response::<ApiContactInboxMessage>(StatusCode::OK, None)
+        .add_error::<MessageNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn list_replies(
+    service: State<Arc<impl ContactFeatureService>>,
+    token: ApiToken,
+    Path(PathContactMessageId { message_id }): Path<PathContactMessageId>,
+) -> Response {
+    match service.list_replies(&token.0, message_id).await {
+        Ok(replies) => Json(
+            replies
+                .into_iter()
+                .map(ApiContactMessageReply::from)
+                .collect::<Vec<_>>(),
+        )
+        .into_response(),
+        Err(ContactListRepliesError::NotFound) => MessageNotFoundError.into_response(),
+        Err(ContactListRepliesError::Auth(err)) => auth_error(err),
+        Err(ContactListRepliesError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_replies_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return all replies to a contact message.")
+        .description("The replies are ordered from oldest to newest.")
+        .add_response::<Vec<ApiContactMessageReply>>(StatusCode::OK, None)
+        .add_error::<MessageNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ReplyToMessageRequest {
+    /// Content of the reply
+    content: ContactMessageContent,
+}
+
+async fn reply_to_message(
+    service: State<Arc<impl ContactFeatureService>>,
+    token: ApiToken,
+    Path(PathContactMessageId { message_id }): Path<PathContactMessageId>,
+    Json(ReplyToMessageRequest { content }): Json<ReplyToMessageRequest>,
+) -> Response {
+    match service
+        .reply_to_message(&token.0, message_id, content)
+        .await
+    {
+        Ok(reply) => Json(ApiContactMessageReply::from(reply)).into_response(),
+        Err(ContactReplyToMessageError::NotFound) => MessageNotFoundError.into_response(),
+        Err(ContactReplyToMessageError::Auth(err)) => auth_error(err),
+        Err(ContactReplyToMessageError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn reply_to_message_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Send a reply to the author of a contact message.")
+        .description(
+            "The reply is queued for delivery via email and threaded with the original message and \
+             all previous replies.",
+        )
+        .add_response::<ApiContactMessageReply>(StatusCode::OK, None)
+        .add_error::<MessageNotFoundError>()
+        .with(auth_error_docs)
         .with(internal_server_error_docs)
 }
 
 error_code! {
-    /// The message could not be sent.
-    CouldNotSendMessageError(INTERNAL_SERVER_ERROR, "Could not send message");
+    /// The message has been classified as spam.
+    MessageRejectedError(FORBIDDEN, "Message rejected");
+    /// The contact message does not exist.
+    MessageNotFoundError(NOT_FOUND, "Message not found");
+    /// The assignee does not exist or is not an admin.
+    InvalidAssigneeError(UNPROCESSABLE_ENTITY, "Invalid assignee");
 }
@@ -90,13 +90,13 @@ fn get_user_by_email_docs(op: TransformOperation) -> TransformOperation {
         .with(internal_server_error_docs)
 }
 
-fn internal_auth_error(err: AuthInternalAuthenticateError) -> Response {
+pub(super) fn internal_auth_error(err: AuthInternalAuthenticateError) -> Response {
     match err {
         AuthInternalAuthenticateError::InvalidToken => InvalidTokenError.into_response(),
     }
 }
 
-fn internal_auth_error_docs(op: TransformOperation) -> TransformOperation {
+pub(super) fn internal_auth_error_docs(op: TransformOperation) -> TransformOperation {
     op.add_error::<InvalidTokenError>()
 }
 
@@ -0,0 +1,223 @@
+use std::{num::NonZeroU64, sync::Arc};
+
+use academy_core_invoice_contracts::{
+    InvoiceDocument, InvoiceFeatureService, InvoiceGetError, InvoiceInternalCreatePayoutError,
+    InvoiceListError, InvoiceListResult,
+};
+use academy_models::{
+    auth::InternalToken,
+    invoice::InvoiceDescription,
+    pagination::{PaginationLimit, PaginationSlice},
+};
+use aide::{
+    axum::{routing, ApiRouter},
+    transform::TransformOperation,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    internal::{internal_auth_error, internal_auth_error_docs},
+    user::UserNotFoundError,
+};
+use crate::{
+    docs::TransformOperationExt,
+    error_code,
+    errors::{auth_error, auth_error_docs, internal_server_error, internal_server_error_docs},
+    extractors::auth::ApiToken,
+    models::{
+        invoice::{ApiInvoice, PathInvoiceId},
+        user::{PathUserId, PathUserIdOrSelf},
+    },
+};
+
+pub const TAG: &str = "Invoices";
+
+pub fn router(service: Arc<impl InvoiceFeatureService>) -> ApiRouter<()> {
+    ApiRouter::new()
+        .api_route(
+            "/auth/users/:user_id/invoices",
+            routing::get_with(list_invoices, list_invoices_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/invoices/:invoice_id",
+            routing::get_with(get_invoice, get_invoice_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/invoices/:invoice_id/pdf",
+            routing::get_with(download_invoice, download_invoice_docs),
+        )
+        .api_route(
+            "/auth/_internal/invoices/:user_id/payout",
+            routing::post_with(
+                internal_create_payout_invoice,
+                internal_create_payout_invoice_docs,
+            ),
+        )
+        .with_state(service)
+        .with_path_items(|op| op.tag(TAG))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListInvoicesQuery {
+    /// The number of items to select.
+    #[serde(default)]
+    limit: PaginationLimit,
+    /// The number of items to skip.
+    #[serde(default)]
+    offset: u64,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ListInvoicesResult {
+    /// The total number of invoices of the user
+    total: u64,
+    /// The paginated list of invoices, most recent first
+    invoices: Vec<ApiInvoice>,
+}
+
+async fn list_invoices(
+    service: State<Arc<impl InvoiceFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+    Query(ListInvoicesQuery { limit, offset }): Query<ListInvoicesQuery>,
+) -> Response {
+    match service
+        .list_invoices(&token.0, user_id.into(), PaginationSlice { limit, offset })
+        .await
+    {
+        Ok(InvoiceListResult { total, invoices }) => Json(ListInvoicesResult {
+            total,
+            invoices: invoices.into_iter().map(Into::into).collect(),
+        })
+        .into_response(),
+        Err(InvoiceListError::NotFound) => UserNotFoundError.into_response(),
+        Err(InvoiceListError::Auth(err)) => auth_error(err),
+        Err(InvoiceListError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_invoices_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the invoices of the given user.")
+        .add_response::<ListInvoicesResult>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn get_invoice(
+    service: State<Arc<impl InvoiceFeatureService>>,
+    token: ApiToken,
+    Path(PathInvoiceId {
+        user_id,
+        invoice_id,
+    }): Path<PathInvoiceId>,
+) -> Response {
+    match service
+        .get_invoice(&token.0, user_id.into(), invoice_id)
+        .await
+    {
+        Ok(invoice) => Json(ApiInvoice::from(invoice)).into_response(),
+        Err(InvoiceGetError::NotFound) => InvoiceNotFoundError.into_response(),
+        Err(InvoiceGetError::Auth(err)) => auth_error(err),
+        Err(InvoiceGetError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_invoice_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return an invoice of the given user.")
+        .add_response::<ApiInvoice>(StatusCode::OK, None)
+        .add_error::<InvoiceNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn download_invoice(
+    service: State<Arc<impl InvoiceFeatureService>>,
+    token: ApiToken,
+    Path(PathInvoiceId {
+        user_id,
+        invoice_id,
+    }): Path<PathInvoiceId>,
+) -> Response {
+    match service
+        .download_invoice(&token.0, user_id.into(), invoice_id)
+        .await
+    {
+        Ok(InvoiceDocument { invoice, pdf }) => (
+            [
+                (header::CONTENT_TYPE, "application/pdf".to_owned()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}.pdf\"", *invoice.number),
+                ),
+            ],
+            pdf,
+        )
+            .into_response(),
+        Err(InvoiceGetError::NotFound) => InvoiceNotFoundError.into_response(),
+        Err(InvoiceGetError::Auth(err)) => auth_error(err),
+        Err(InvoiceGetError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn download_invoice_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Download an invoice of the given user as a PDF document.")
+        .response_with::<200, Vec<u8>, _>(|res| res.description("The PDF document."))
+        .add_error::<InvoiceNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreatePayoutInvoiceRequest {
+    description: InvoiceDescription,
+    /// The gross amount which is paid out in euro cents
+    amount: NonZeroU64,
+}
+
+async fn internal_create_payout_invoice(
+    service: State<Arc<impl InvoiceFeatureService>>,
+    token: ApiToken<InternalToken>,
+    Path(PathUserId { user_id }): Path<PathUserId>,
+    Json(CreatePayoutInvoiceRequest {
+        description,
+        amount,
+    }): Json<CreatePayoutInvoiceRequest>,
+) -> Response {
+    match service
+        .internal_create_payout_invoice(&token.0, user_id, description, amount.get())
+        .await
+    {
+        Ok(invoice) => Json(ApiInvoice::from(invoice)).into_response(),
+        Err(InvoiceInternalCreatePayoutError::NotFound) => UserNotFoundError.into_response(),
+        Err(InvoiceInternalCreatePayoutError::InvoiceInfoIncomplete) => {
+            InvoiceInfoIncompleteError.into_response()
+        }
+        Err(InvoiceInternalCreatePayoutError::Auth(err)) => internal_auth_error(err),
+        Err(InvoiceInternalCreatePayoutError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn internal_create_payout_invoice_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Issue a self-billed invoice for a payout to the given user.")
+        .description("VAT is determined by the country and business status of the user.")
+        .add_response::<ApiInvoice>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .add_error::<InvoiceInfoIncompleteError>()
+        .with(internal_auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+error_code! {
+    /// The invoice does not exist.
+    InvoiceNotFoundError(NOT_FOUND, "Invoice not found");
+    /// The invoice info of the user is incomplete.
+    InvoiceInfoIncompleteError(FORBIDDEN, "Invoice info incomplete");
+}
@@ -1,8 +1,14 @@
+pub mod checkout;
+pub mod coin;
 pub mod config;
 pub mod contact;
 pub mod health;
 pub mod internal;
+pub mod invoice;
 pub mod mfa;
+pub mod newsletter;
 pub mod oauth2;
+pub mod outbox;
 pub mod session;
+pub mod skills;
 pub mod user;
@@ -0,0 +1,328 @@
+use std::sync::Arc;
+
+use academy_core_newsletter_contracts::{
+    NewsletterCreateCampaignError, NewsletterCreateCampaignRequest, NewsletterDeleteCampaignError,
+    NewsletterFeatureService, NewsletterGetCampaignError, NewsletterListCampaignsError,
+    NewsletterPreviewCampaignError, NewsletterSendCampaignError, NewsletterSendTestEmailError,
+    NewsletterUpdateCampaignError,
+};
+use academy_models::{
+    email_address::EmailAddress,
+    newsletter::{
+        NewsletterCampaignBody, NewsletterCampaignPatch, NewsletterCampaignStatus,
+        NewsletterCampaignSubject,
+    },
+};
+use aide::{
+    axum::{routing, ApiRouter},
+    transform::TransformOperation,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    docs::TransformOperationExt,
+    error_code,
+    errors::{auth_error, auth_error_docs, internal_server_error, internal_server_error_docs},
+    extractors::auth::ApiToken,
+    models::{
+        newsletter::{
+            ApiNewsletterCampaign, ApiNewsletterCampaignPreview, PathNewsletterCampaignId,
+        },
+        OkResponse,
+    },
+};
+
+pub const TAG: &str = "Newsletter";
+
+pub fn router(service: Arc<impl NewsletterFeatureService>) -> ApiRouter<()> {
+    ApiRouter::new()
+        .api_route(
+            "/auth/newsletter/campaigns",
+            routing::get_with(list_campaigns, list_campaigns_docs)
+                .post_with(create_campaign, create_campaign_docs),
+        )
+        .api_route(
+            "/auth/newsletter/campaigns/:campaign_id",
+            routing::get_with(get_campaign, get_campaign_docs)
+                .patch_with(update_campaign, update_campaign_docs)
+                .delete_with(delete_campaign, delete_campaign_docs),
+        )
+        .api_route(
+            "/auth/newsletter/campaigns/:campaign_id/preview",
+            routing::get_with(preview_campaign, preview_campaign_docs),
+        )
+        .api_route(
+            "/auth/newsletter/campaigns/:campaign_id/test",
+            routing::post_with(send_test_email, send_test_email_docs),
+        )
+        .api_route(
+            "/auth/newsletter/campaigns/:campaign_id/send",
+            routing::post_with(send_campaign, send_campaign_docs),
+        )
+        .with_state(service)
+        .with_path_items(|op| op.tag(TAG))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListCampaignsQuery {
+    /// Only return campaigns with this status
+    status: Option<NewsletterCampaignStatus>,
+}
+
+async fn list_campaigns(
+    service: State<Arc<impl NewsletterFeatureService>>,
+    token: ApiToken,
+    Query(ListCampaignsQuery { status }): Query<ListCampaignsQuery>,
+) -> Response {
+    match service.list_campaigns(&token.0, status).await {
+        Ok(campaigns) => Json(
+            campaigns
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<ApiNewsletterCampaign>>(),
+        )
+        .into_response(),
+        Err(NewsletterListCampaignsError::Auth(err)) => auth_error(err),
+        Err(NewsletterListCampaignsError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_campaigns_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return all newsletter campaigns.")
+        .add_response::<Vec<ApiNewsletterCampaign>>(StatusCode::OK, None)
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateCampaignRequest {
+    subject: NewsletterCampaignSubject,
+    body_html: NewsletterCampaignBody,
+    body_text: NewsletterCampaignBody,
+}
+
+async fn create_campaign(
+    service: State<Arc<impl NewsletterFeatureService>>,
+    token: ApiToken,
+    Json(CreateCampaignRequest {
+        subject,
+        body_html,
+        body_text,
+    }): Json<CreateCampaignRequest>,
+) -> Response {
+    match service
+        .create_campaign(
+            &token.0,
+            NewsletterCreateCampaignRequest {
+                subject,
+                body_html,
+                body_text,
+            },
+        )
+        .await
+    {
+        Ok(campaign) => Json(ApiNewsletterCampaign::from(campaign)).into_response(),
+        Err(NewsletterCreateCampaignError::Auth(err)) => auth_error(err),
+        Err(NewsletterCreateCampaignError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn create_campaign_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Create a new draft newsletter campaign.")
+        .add_response::<ApiNewsletterCampaign>(StatusCode::OK, None)
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn get_campaign(
+    service: State<Arc<impl NewsletterFeatureService>>,
+    token: ApiToken,
+    Path(PathNewsletterCampaignId { campaign_id }): Path<PathNewsletterCampaignId>,
+) -> Response {
+    match service.get_campaign(&token.0, campaign_id).await {
+        Ok(campaign) => Json(ApiNewsletterCampaign::from(campaign)).into_response(),
+        Err(NewsletterGetCampaignError::NotFound) => CampaignNotFoundError.into_response(),
+        Err(NewsletterGetCampaignError::Auth(err)) => auth_error(err),
+        Err(NewsletterGetCampaignError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_campaign_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the newsletter campaign with the given id.")
+        .description("Includes the delivery progress of campaigns that are being sent.")
+        .add_response::<ApiNewsletterCampaign>(StatusCode::OK, None)
+        .add_error::<CampaignNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdateCampaignRequest {
+    subject: Option<NewsletterCampaignSubject>,
+    body_html: Option<NewsletterCampaignBody>,
+    body_text: Option<NewsletterCampaignBody>,
+}
+
+async fn update_campaign(
+    service: State<Arc<impl NewsletterFeatureService>>,
+    token: ApiToken,
+    Path(PathNewsletterCampaignId { campaign_id }): Path<PathNewsletterCampaignId>,
+    Json(UpdateCampaignRequest {
+        subject,
+        body_html,
+        body_text,
+    }): Json<UpdateCampaignRequest>,
+) -> Response {
+    match service
+        .update_campaign(
+            &token.0,
+            campaign_id,
+            NewsletterCampaignPatch {
+                subject: subject.into(),
+                body_html: body_html.into(),
+                body_text: body_text.into(),
+            },
+        )
+        .await
+    {
+        Ok(campaign) => Json(ApiNewsletterCampaign::from(campaign)).into_response(),
+        Err(NewsletterUpdateCampaignError::NotFound) => CampaignNotFoundError.into_response(),
+        Err(NewsletterUpdateCampaignError::NotDraft) => CampaignNotDraftError.into_response(),
+        Err(NewsletterUpdateCampaignError::Auth(err)) => auth_error(err),
+        Err(NewsletterUpdateCampaignError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn update_campaign_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Update the given draft newsletter campaign.")
+        .add_response::<ApiNewsletterCampaign>(StatusCode::OK, "The campaign has been updated.")
+        .add_error::<CampaignNotFoundError>()
+        .add_error::<CampaignNotDraftError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn delete_campaign(
+    service: State<Arc<impl NewsletterFeatureService>>,
+    token: ApiToken,
+    Path(PathNewsletterCampaignId { campaign_id }): Path<PathNewsletterCampaignId>,
+) -> Response {
+    match service.delete_campaign(&token.0, campaign_id).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(NewsletterDeleteCampaignError::NotFound) => CampaignNotFoundError.into_response(),
+        Err(NewsletterDeleteCampaignError::Sending) => CampaignSendingError.into_response(),
+        Err(NewsletterDeleteCampaignError::Auth(err)) => auth_error(err),
+        Err(NewsletterDeleteCampaignError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn delete_campaign_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Delete the given newsletter campaign.")
+        .add_response::<OkResponse>(StatusCode::OK, "The campaign has been deleted.")
+        .add_error::<CampaignNotFoundError>()
+        .add_error::<CampaignSendingError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn preview_campaign(
+    service: State<Arc<impl NewsletterFeatureService>>,
+    token: ApiToken,
+    Path(PathNewsletterCampaignId { campaign_id }): Path<PathNewsletterCampaignId>,
+) -> Response {
+    match service.preview_campaign(&token.0, campaign_id).await {
+        Ok(preview) => Json(ApiNewsletterCampaignPreview::from(preview)).into_response(),
+        Err(NewsletterPreviewCampaignError::NotFound) => CampaignNotFoundError.into_response(),
+        Err(NewsletterPreviewCampaignError::Auth(err)) => auth_error(err),
+        Err(NewsletterPreviewCampaignError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn preview_campaign_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Render the given newsletter campaign.")
+        .description(
+            "Returns the HTML and plain text emails as they would be received by the \
+             authenticated user.",
+        )
+        .add_response::<ApiNewsletterCampaignPreview>(StatusCode::OK, None)
+        .add_error::<CampaignNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct SendTestEmailRequest {
+    /// The address to which the test email should be sent
+    email: EmailAddress,
+}
+
+async fn send_test_email(
+    service: State<Arc<impl NewsletterFeatureService>>,
+    token: ApiToken,
+    Path(PathNewsletterCampaignId { campaign_id }): Path<PathNewsletterCampaignId>,
+    Json(SendTestEmailRequest { email }): Json<SendTestEmailRequest>,
+) -> Response {
+    match service.send_test_email(&token.0, campaign_id, email).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(NewsletterSendTestEmailError::NotFound) => CampaignNotFoundError.into_response(),
+        Err(NewsletterSendTestEmailError::Send) => SendTestEmailFailedError.into_response(),
+        Err(NewsletterSendTestEmailError::Auth(err)) => auth_error(err),
+        Err(NewsletterSendTestEmailError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn send_test_email_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Send the given newsletter campaign to a single test address.")
+        .add_response::<OkResponse>(StatusCode::OK, "The test email has been sent.")
+        .add_error::<CampaignNotFoundError>()
+        .add_error::<SendTestEmailFailedError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn send_campaign(
+    service: State<Arc<impl NewsletterFeatureService>>,
+    token: ApiToken,
+    Path(PathNewsletterCampaignId { campaign_id }): Path<PathNewsletterCampaignId>,
+) -> Response {
+    match service.send_campaign(&token.0, campaign_id).await {
+        Ok(campaign) => Json(ApiNewsletterCampaign::from(campaign)).into_response(),
+        Err(NewsletterSendCampaignError::NotFound) => CampaignNotFoundError.into_response(),
+        Err(NewsletterSendCampaignError::NotDraft) => CampaignNotDraftError.into_response(),
+        Err(NewsletterSendCampaignError::Auth(err)) => auth_error(err),
+        Err(NewsletterSendCampaignError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn send_campaign_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Send the given newsletter campaign to all subscribers.")
+        .description(
+            "All enabled users with a verified email address who are subscribed to the \
+             newsletter are added as recipients. The emails are then delivered in batches in \
+             the background; the progress can be monitored using the `progress` field of the \
+             campaign.",
+        )
+        .add_response::<ApiNewsletterCampaign>(StatusCode::OK, "Sending has been started.")
+        .add_error::<CampaignNotFoundError>()
+        .add_error::<CampaignNotDraftError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+error_code! {
+    /// The newsletter campaign does not exist.
+    CampaignNotFoundError(NOT_FOUND, "Campaign not found");
+    /// The newsletter campaign has already been sent.
+    CampaignNotDraftError(CONFLICT, "Campaign already sent");
+    /// The newsletter campaign is currently being sent.
+    CampaignSendingError(CONFLICT, "Campaign is being sent");
+    /// The test email could not be sent.
+    SendTestEmailFailedError(INTERNAL_SERVER_ERROR, "Failed to send test email");
+}
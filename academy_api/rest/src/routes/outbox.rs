@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use academy_core_outbox_contracts::{
+    OutboxFeatureService, OutboxGetEmailError, OutboxListEmailsError, OutboxListQuery,
+    OutboxListResult, OutboxRetryEmailError,
+};
+use academy_models::{
+    email_outbox::EmailOutboxStatus,
+    pagination::{PaginationLimit, PaginationSlice},
+};
+use aide::{
+    axum::{routing, ApiRouter},
+    transform::TransformOperation,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    docs::TransformOperationExt,
+    error_code,
+    errors::{auth_error, auth_error_docs, internal_server_error, internal_server_error_docs},
+    extractors::auth::ApiToken,
+    models::outbox::{ApiOutboxEmail, PathEmailOutboxId},
+};
+
+pub const TAG: &str = "Outbox";
+
+pub fn router(service: Arc<impl OutboxFeatureService>) -> ApiRouter<()> {
+    ApiRouter::new()
+        .api_route(
+            "/auth/outbox",
+            routing::get_with(list_emails, list_emails_docs),
+        )
+        .api_route(
+            "/auth/outbox/:email_id",
+            routing::get_with(get_email, get_email_docs),
+        )
+        .api_route(
+            "/auth/outbox/:email_id/retry",
+            routing::post_with(retry_email, retry_email_docs),
+        )
+        .with_state(service)
+        .with_path_items(|op| op.tag(TAG))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListEmailsQuery {
+    /// Only return emails with this status
+    status: Option<EmailOutboxStatus>,
+    /// The number of items to select.
+    #[serde(default)]
+    limit: PaginationLimit,
+    /// The number of items to skip.
+    #[serde(default)]
+    offset: u64,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ListEmailsResult {
+    /// The total number of emails matching the given query
+    total: u64,
+    /// The paginated list of emails matching the given query, most recent
+    /// first
+    emails: Vec<ApiOutboxEmail>,
+}
+
+async fn list_emails(
+    service: State<Arc<impl OutboxFeatureService>>,
+    token: ApiToken,
+    Query(ListEmailsQuery {
+        status,
+        limit,
+        offset,
+    }): Query<ListEmailsQuery>,
+) -> Response {
+    match service
+        .list_emails(
+            &token.0,
+            OutboxListQuery {
+                status,
+                pagination: PaginationSlice { limit, offset },
+            },
+        )
+        .await
+    {
+        Ok(OutboxListResult { total, emails }) => Json(ListEmailsResult {
+            total,
+            emails: emails.into_iter().map(Into::into).collect(),
+        })
+        .into_response(),
+        Err(OutboxListEmailsError::Auth(err)) => auth_error(err),
+        Err(OutboxListEmailsError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_emails_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the emails in the outbox.")
+        .add_response::<ListEmailsResult>(StatusCode::OK, None)
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn get_email(
+    service: State<Arc<impl OutboxFeatureService>>,
+    token: ApiToken,
+    Path(PathEmailOutboxId { email_id }): Path<PathEmailOutboxId>,
+) -> Response {
+    match service.get_email(&token.0, email_id).await {
+        Ok(email) => Json(ApiOutboxEmail::from(email)).into_response(),
+        Err(OutboxGetEmailError::NotFound) => EmailNotFoundError.into_response(),
+        Err(OutboxGetEmailError::Auth(err)) => auth_error(err),
+        Err(OutboxGetEmailError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_email_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the email with the given id.")
+        .add_response::<ApiOutboxEmail>(StatusCode::OK, None)
+        .add_error::<EmailNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn retry_email(
+    service: State<Arc<impl OutboxFeatureService>>,
+    token: ApiToken,
+    Path(PathEmailOutboxId { email_id }): Path<PathEmailOutboxId>,
+) -> Response {
+    match service.retry_email(&token.0, email_id).await {
+        Ok(email) => Json(ApiOutboxEmail::from(email)).into_response(),
+        Err(OutboxRetryEmailError::NotFound) => EmailNotFoundError.into_response(),
+        Err(OutboxRetryEmailError::AlreadySent) => EmailAlreadySentError.into_response(),
+        Err(OutboxRetryEmailError::Auth(err)) => auth_error(err),
+        Err(OutboxRetryEmailError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn retry_email_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Schedule an email for immediate delivery.")
+        .description("Resets the number of failed delivery attempts of the email.")
+        .add_response::<ApiOutboxEmail>(StatusCode::OK, None)
+        .add_error::<EmailNotFoundError>()
+        .add_error::<EmailAlreadySentError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+error_code! {
+    /// The email does not exist.
+    EmailNotFoundError(NOT_FOUND, "Email not found");
+    /// The email has already been sent.
+    EmailAlreadySentError(CONFLICT, "Email already sent");
+}
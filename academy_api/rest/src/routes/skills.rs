@@ -0,0 +1,732 @@
+use std::sync::Arc;
+
+use academy_core_skills_contracts::{
+    SkillsAddPrerequisiteError, SkillsCompleteLectureError, SkillsCreateCourseError,
+    SkillsCreateCourseRequest, SkillsCreateLectureError, SkillsCreateLectureRequest,
+    SkillsCreateSectionError, SkillsCreateSkillError, SkillsCreateSkillRequest,
+    SkillsDeleteCourseError, SkillsDeleteLectureError, SkillsDeleteSectionError,
+    SkillsDeleteSkillError, SkillsFeatureService, SkillsGetCourseError,
+    SkillsGetCourseProgressError, SkillsListCoursesError, SkillsListSkillProgressError,
+    SkillsListSkillsError, SkillsRemovePrerequisiteError, SkillsResetLectureError,
+    SkillsUpdateCourseError, SkillsUpdateLectureError, SkillsUpdateSectionError,
+    SkillsUpdateSkillError,
+};
+use academy_models::skill::{
+    CourseDescription, CoursePatch, CourseSectionPatch, CourseSectionTitle, CourseTitle,
+    LectureDescription, LecturePatch, LectureTitle, SkillDescription, SkillId, SkillPatch,
+    SkillPrerequisite, SkillTitle,
+};
+use aide::{
+    axum::{routing, ApiRouter},
+    transform::TransformOperation,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use super::user::UserNotFoundError;
+use crate::{
+    docs::TransformOperationExt,
+    error_code,
+    errors::{auth_error, auth_error_docs, internal_server_error, internal_server_error_docs},
+    extractors::auth::ApiToken,
+    models::{
+        skill::{
+            ApiCourse, ApiCourseOutline, ApiCourseProgress, ApiCourseSection, ApiLecture, ApiSkill,
+            ApiSkillProgress, ApiSkillTree, PathCourseId, PathCourseSectionId, PathLectureId,
+            PathSkillId, PathSkillPrerequisite, PathUserCourseId, PathUserLectureId,
+        },
+        user::PathUserIdOrSelf,
+        OkResponse,
+    },
+};
+
+pub const TAG: &str = "Skills";
+
+pub fn router(service: Arc<impl SkillsFeatureService>) -> ApiRouter<()> {
+    ApiRouter::new()
+        .api_route(
+            "/auth/skills",
+            routing::get_with(list_skills, list_skills_docs)
+                .post_with(create_skill, create_skill_docs),
+        )
+        .api_route(
+            "/auth/skills/:skill_id",
+            routing::patch_with(update_skill, update_skill_docs)
+                .delete_with(delete_skill, delete_skill_docs),
+        )
+        .api_route(
+            "/auth/skills/:skill_id/prerequisites/:prerequisite_id",
+            routing::put_with(add_skill_prerequisite, add_skill_prerequisite_docs)
+                .delete_with(remove_skill_prerequisite, remove_skill_prerequisite_docs),
+        )
+        .api_route(
+            "/auth/skills/:skill_id/courses",
+            routing::post_with(create_course, create_course_docs),
+        )
+        .api_route(
+            "/auth/courses",
+            routing::get_with(list_courses, list_courses_docs),
+        )
+        .api_route(
+            "/auth/courses/:course_id",
+            routing::get_with(get_course, get_course_docs)
+                .patch_with(update_course, update_course_docs)
+                .delete_with(delete_course, delete_course_docs),
+        )
+        .api_route(
+            "/auth/courses/:course_id/sections",
+            routing::post_with(create_section, create_section_docs),
+        )
+        .api_route(
+            "/auth/sections/:section_id",
+            routing::patch_with(update_section, update_section_docs)
+                .delete_with(delete_section, delete_section_docs),
+        )
+        .api_route(
+            "/auth/sections/:section_id/lectures",
+            routing::post_with(create_lecture, create_lecture_docs),
+        )
+        .api_route(
+            "/auth/lectures/:lecture_id",
+            routing::patch_with(update_lecture, update_lecture_docs)
+                .delete_with(delete_lecture, delete_lecture_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/skills/progress",
+            routing::get_with(list_skill_progress, list_skill_progress_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/courses/:course_id/progress",
+            routing::get_with(get_course_progress, get_course_progress_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/completed_lectures/:lecture_id",
+            routing::put_with(complete_lecture, complete_lecture_docs)
+                .delete_with(reset_lecture, reset_lecture_docs),
+        )
+        .with_state(service)
+        .with_path_items(|op| op.tag(TAG))
+}
+
+async fn list_skills(service: State<Arc<impl SkillsFeatureService>>) -> Response {
+    match service.list_skills().await {
+        Ok(tree) => Json(ApiSkillTree::from(tree)).into_response(),
+        Err(SkillsListSkillsError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_skills_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return all skills together with the edges of the skill tree.")
+        .add_response::<ApiSkillTree>(StatusCode::OK, None)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateSkillRequest {
+    title: SkillTitle,
+    description: SkillDescription,
+}
+
+async fn create_skill(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Json(CreateSkillRequest { title, description }): Json<CreateSkillRequest>,
+) -> Response {
+    match service
+        .create_skill(&token.0, SkillsCreateSkillRequest { title, description })
+        .await
+    {
+        Ok(skill) => Json(ApiSkill::from(skill)).into_response(),
+        Err(SkillsCreateSkillError::Auth(err)) => auth_error(err),
+        Err(SkillsCreateSkillError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn create_skill_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Create a new skill.")
+        .add_response::<ApiSkill>(StatusCode::OK, None)
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdateSkillRequest {
+    title: Option<SkillTitle>,
+    description: Option<SkillDescription>,
+}
+
+async fn update_skill(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathSkillId { skill_id }): Path<PathSkillId>,
+    Json(UpdateSkillRequest { title, description }): Json<UpdateSkillRequest>,
+) -> Response {
+    match service
+        .update_skill(
+            &token.0,
+            skill_id,
+            SkillPatch {
+                title: title.into(),
+                description: description.into(),
+            },
+        )
+        .await
+    {
+        Ok(skill) => Json(ApiSkill::from(skill)).into_response(),
+        Err(SkillsUpdateSkillError::NotFound) => SkillNotFoundError.into_response(),
+        Err(SkillsUpdateSkillError::Auth(err)) => auth_error(err),
+        Err(SkillsUpdateSkillError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn update_skill_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Update the given skill.")
+        .add_response::<ApiSkill>(StatusCode::OK, None)
+        .add_error::<SkillNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn delete_skill(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathSkillId { skill_id }): Path<PathSkillId>,
+) -> Response {
+    match service.delete_skill(&token.0, skill_id).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(SkillsDeleteSkillError::NotFound) => SkillNotFoundError.into_response(),
+        Err(SkillsDeleteSkillError::Auth(err)) => auth_error(err),
+        Err(SkillsDeleteSkillError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn delete_skill_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Delete the given skill together with all of its courses.")
+        .add_response::<OkResponse>(StatusCode::OK, "The skill has been deleted.")
+        .add_error::<SkillNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn add_skill_prerequisite(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathSkillPrerequisite {
+        skill_id,
+        prerequisite_id,
+    }): Path<PathSkillPrerequisite>,
+) -> Response {
+    match service
+        .add_skill_prerequisite(
+            &token.0,
+            SkillPrerequisite {
+                skill_id,
+                prerequisite_id,
+            },
+        )
+        .await
+    {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(SkillsAddPrerequisiteError::NotFound) => SkillNotFoundError.into_response(),
+        Err(SkillsAddPrerequisiteError::Cycle) => SkillPrerequisiteCycleError.into_response(),
+        Err(SkillsAddPrerequisiteError::Auth(err)) => auth_error(err),
+        Err(SkillsAddPrerequisiteError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn add_skill_prerequisite_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Require a skill to be completed before the given skill is unlocked.")
+        .add_response::<OkResponse>(StatusCode::OK, "The prerequisite has been added.")
+        .add_error::<SkillNotFoundError>()
+        .add_error::<SkillPrerequisiteCycleError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn remove_skill_prerequisite(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathSkillPrerequisite {
+        skill_id,
+        prerequisite_id,
+    }): Path<PathSkillPrerequisite>,
+) -> Response {
+    match service
+        .remove_skill_prerequisite(
+            &token.0,
+            SkillPrerequisite {
+                skill_id,
+                prerequisite_id,
+            },
+        )
+        .await
+    {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(SkillsRemovePrerequisiteError::NotFound) => {
+            SkillPrerequisiteNotFoundError.into_response()
+        }
+        Err(SkillsRemovePrerequisiteError::Auth(err)) => auth_error(err),
+        Err(SkillsRemovePrerequisiteError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn remove_skill_prerequisite_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Remove a prerequisite of the given skill.")
+        .add_response::<OkResponse>(StatusCode::OK, "The prerequisite has been removed.")
+        .add_error::<SkillPrerequisiteNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListCoursesQuery {
+    /// Only return courses of this skill
+    skill_id: Option<SkillId>,
+}
+
+async fn list_courses(
+    service: State<Arc<impl SkillsFeatureService>>,
+    Query(ListCoursesQuery { skill_id }): Query<ListCoursesQuery>,
+) -> Response {
+    match service.list_courses(skill_id).await {
+        Ok(courses) => Json(
+            courses
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<ApiCourse>>(),
+        )
+        .into_response(),
+        Err(SkillsListCoursesError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_courses_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return all courses.")
+        .add_response::<Vec<ApiCourse>>(StatusCode::OK, None)
+        .with(internal_server_error_docs)
+}
+
+async fn get_course(
+    service: State<Arc<impl SkillsFeatureService>>,
+    Path(PathCourseId { course_id }): Path<PathCourseId>,
+) -> Response {
+    match service.get_course(course_id).await {
+        Ok(outline) => Json(ApiCourseOutline::from(outline)).into_response(),
+        Err(SkillsGetCourseError::NotFound) => CourseNotFoundError.into_response(),
+        Err(SkillsGetCourseError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_course_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the given course together with its sections and lectures.")
+        .add_response::<ApiCourseOutline>(StatusCode::OK, None)
+        .add_error::<CourseNotFoundError>()
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateCourseRequest {
+    title: CourseTitle,
+    description: CourseDescription,
+}
+
+async fn create_course(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathSkillId { skill_id }): Path<PathSkillId>,
+    Json(CreateCourseRequest { title, description }): Json<CreateCourseRequest>,
+) -> Response {
+    match service
+        .create_course(
+            &token.0,
+            skill_id,
+            SkillsCreateCourseRequest { title, description },
+        )
+        .await
+    {
+        Ok(course) => Json(ApiCourse::from(course)).into_response(),
+        Err(SkillsCreateCourseError::NotFound) => SkillNotFoundError.into_response(),
+        Err(SkillsCreateCourseError::Auth(err)) => auth_error(err),
+        Err(SkillsCreateCourseError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn create_course_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Create a new course for the given skill.")
+        .add_response::<ApiCourse>(StatusCode::OK, None)
+        .add_error::<SkillNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdateCourseRequest {
+    title: Option<CourseTitle>,
+    description: Option<CourseDescription>,
+}
+
+async fn update_course(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathCourseId { course_id }): Path<PathCourseId>,
+    Json(UpdateCourseRequest { title, description }): Json<UpdateCourseRequest>,
+) -> Response {
+    match service
+        .update_course(
+            &token.0,
+            course_id,
+            CoursePatch {
+                title: title.into(),
+                description: description.into(),
+            },
+        )
+        .await
+    {
+        Ok(course) => Json(ApiCourse::from(course)).into_response(),
+        Err(SkillsUpdateCourseError::NotFound) => CourseNotFoundError.into_response(),
+        Err(SkillsUpdateCourseError::Auth(err)) => auth_error(err),
+        Err(SkillsUpdateCourseError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn update_course_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Update the given course.")
+        .add_response::<ApiCourse>(StatusCode::OK, None)
+        .add_error::<CourseNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn delete_course(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathCourseId { course_id }): Path<PathCourseId>,
+) -> Response {
+    match service.delete_course(&token.0, course_id).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(SkillsDeleteCourseError::NotFound) => CourseNotFoundError.into_response(),
+        Err(SkillsDeleteCourseError::Auth(err)) => auth_error(err),
+        Err(SkillsDeleteCourseError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn delete_course_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Delete the given course together with its sections and lectures.")
+        .add_response::<OkResponse>(StatusCode::OK, "The course has been deleted.")
+        .add_error::<CourseNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateSectionRequest {
+    title: CourseSectionTitle,
+}
+
+async fn create_section(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathCourseId { course_id }): Path<PathCourseId>,
+    Json(CreateSectionRequest { title }): Json<CreateSectionRequest>,
+) -> Response {
+    match service.create_section(&token.0, course_id, title).await {
+        Ok(section) => Json(ApiCourseSection::from(section)).into_response(),
+        Err(SkillsCreateSectionError::NotFound) => CourseNotFoundError.into_response(),
+        Err(SkillsCreateSectionError::Auth(err)) => auth_error(err),
+        Err(SkillsCreateSectionError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn create_section_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Append a new section to the given course.")
+        .add_response::<ApiCourseSection>(StatusCode::OK, None)
+        .add_error::<CourseNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdateSectionRequest {
+    title: Option<CourseSectionTitle>,
+}
+
+async fn update_section(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathCourseSectionId { section_id }): Path<PathCourseSectionId>,
+    Json(UpdateSectionRequest { title }): Json<UpdateSectionRequest>,
+) -> Response {
+    match service
+        .update_section(
+            &token.0,
+            section_id,
+            CourseSectionPatch {
+                title: title.into(),
+            },
+        )
+        .await
+    {
+        Ok(section) => Json(ApiCourseSection::from(section)).into_response(),
+        Err(SkillsUpdateSectionError::NotFound) => CourseSectionNotFoundError.into_response(),
+        Err(SkillsUpdateSectionError::Auth(err)) => auth_error(err),
+        Err(SkillsUpdateSectionError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn update_section_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Update the given section.")
+        .add_response::<ApiCourseSection>(StatusCode::OK, None)
+        .add_error::<CourseSectionNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn delete_section(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathCourseSectionId { section_id }): Path<PathCourseSectionId>,
+) -> Response {
+    match service.delete_section(&token.0, section_id).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(SkillsDeleteSectionError::NotFound) => CourseSectionNotFoundError.into_response(),
+        Err(SkillsDeleteSectionError::Auth(err)) => auth_error(err),
+        Err(SkillsDeleteSectionError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn delete_section_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Delete the given section together with its lectures.")
+        .add_response::<OkResponse>(StatusCode::OK, "The section has been deleted.")
+        .add_error::<CourseSectionNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateLectureRequest {
+    title: LectureTitle,
+    description: LectureDescription,
+}
+
+async fn create_lecture(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathCourseSectionId { section_id }): Path<PathCourseSectionId>,
+    Json(CreateLectureRequest { title, description }): Json<CreateLectureRequest>,
+) -> Response {
+    match service
+        .create_lecture(
+            &token.0,
+            section_id,
+            SkillsCreateLectureRequest { title, description },
+        )
+        .await
+    {
+        Ok(lecture) => Json(ApiLecture::from(lecture)).into_response(),
+        Err(SkillsCreateLectureError::NotFound) => CourseSectionNotFoundError.into_response(),
+        Err(SkillsCreateLectureError::Auth(err)) => auth_error(err),
+        Err(SkillsCreateLectureError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn create_lecture_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Append a new lecture to the given section.")
+        .add_response::<ApiLecture>(StatusCode::OK, None)
+        .add_error::<CourseSectionNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdateLectureRequest {
+    title: Option<LectureTitle>,
+    description: Option<LectureDescription>,
+}
+
+async fn update_lecture(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathLectureId { lecture_id }): Path<PathLectureId>,
+    Json(UpdateLectureRequest { title, description }): Json<UpdateLectureRequest>,
+) -> Response {
+    match service
+        .update_lecture(
+            &token.0,
+            lecture_id,
+            LecturePatch {
+                title: title.into(),
+                description: description.into(),
+            },
+        )
+        .await
+    {
+        Ok(lecture) => Json(ApiLecture::from(lecture)).into_response(),
+        Err(SkillsUpdateLectureError::NotFound) => LectureNotFoundError.into_response(),
+        Err(SkillsUpdateLectureError::Auth(err)) => auth_error(err),
+        Err(SkillsUpdateLectureError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn update_lecture_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Update the given lecture.")
+        .add_response::<ApiLecture>(StatusCode::OK, None)
+        .add_error::<LectureNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn delete_lecture(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathLectureId { lecture_id }): Path<PathLectureId>,
+) -> Response {
+    match service.delete_lecture(&token.0, lecture_id).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(SkillsDeleteLectureError::NotFound) => LectureNotFoundError.into_response(),
+        Err(SkillsDeleteLectureError::Auth(err)) => auth_error(err),
+        Err(SkillsDeleteLectureError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn delete_lecture_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Delete the given lecture.")
+        .add_response::<OkResponse>(StatusCode::OK, "The lecture has been deleted.")
+        .add_error::<LectureNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn list_skill_progress(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+) -> Response {
+    match service.list_skill_progress(&token.0, user_id.into()).await {
+        Ok(progress) => Json(
+            progress
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<ApiSkillProgress>>(),
+        )
+        .into_response(),
+        Err(SkillsListSkillProgressError::NotFound) => UserNotFoundError.into_response(),
+        Err(SkillsListSkillProgressError::Auth(err)) => auth_error(err),
+        Err(SkillsListSkillProgressError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_skill_progress_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the progress of the given user in all skills.")
+        .add_response::<Vec<ApiSkillProgress>>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn get_course_progress(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathUserCourseId { user_id, course_id }): Path<PathUserCourseId>,
+) -> Response {
+    match service
+        .get_course_progress(&token.0, user_id.into(), course_id)
+        .await
+    {
+        Ok(progress) => Json(ApiCourseProgress::from(progress)).into_response(),
+        Err(SkillsGetCourseProgressError::NotFound) => CourseNotFoundError.into_response(),
+        Err(SkillsGetCourseProgressError::Auth(err)) => auth_error(err),
+        Err(SkillsGetCourseProgressError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_course_progress_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the progress of the given user in a course.")
+        .add_response::<ApiCourseProgress>(StatusCode::OK, None)
+        .add_error::<CourseNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn complete_lecture(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathUserLectureId {
+        user_id,
+        lecture_id,
+    }): Path<PathUserLectureId>,
+) -> Response {
+    match service
+        .complete_lecture(&token.0, user_id.into(), lecture_id)
+        .await
+    {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(SkillsCompleteLectureError::UserNotFound) => UserNotFoundError.into_response(),
+        Err(SkillsCompleteLectureError::LectureNotFound) => LectureNotFoundError.into_response(),
+        Err(SkillsCompleteLectureError::SkillLocked) => SkillLockedError.into_response(),
+        Err(SkillsCompleteLectureError::Auth(err)) => auth_error(err),
+        Err(SkillsCompleteLectureError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn complete_lecture_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Mark a lecture as completed by the given user.")
+        .description("All prerequisites of the skill of the lecture have to be completed.")
+        .add_response::<OkResponse>(StatusCode::OK, "The lecture has been completed.")
+        .add_error::<UserNotFoundError>()
+        .add_error::<LectureNotFoundError>()
+        .add_error::<SkillLockedError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn reset_lecture(
+    service: State<Arc<impl SkillsFeatureService>>,
+    token: ApiToken,
+    Path(PathUserLectureId {
+        user_id,
+        lecture_id,
+    }): Path<PathUserLectureId>,
+) -> Response {
+    match service
+        .reset_lecture(&token.0, user_id.into(), lecture_id)
+        .await
+    {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(SkillsResetLectureError::NotFound) => LectureNotCompletedError.into_response(),
+        Err(SkillsResetLectureError::Auth(err)) => auth_error(err),
+        Err(SkillsResetLectureError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn reset_lecture_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Mark a lecture as not completed by the given user.")
+        .add_response::<OkResponse>(StatusCode::OK, "The lecture has been reset.")
+        .add_error::<LectureNotCompletedError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+error_code! {
+    /// The skill does not exist.
+    SkillNotFoundError(NOT_FOUND, "Skill not found");
+    /// The prerequisite does not exist.
+    SkillPrerequisiteNotFoundError(NOT_FOUND, "Skill prerequisite not found");
+    /// The prerequisite would make the skill depend on itself.
+    SkillPrerequisiteCycleError(CONFLICT, "Skill prerequisite cycle");
+    /// The prerequisites of the skill have not been completed.
+    SkillLockedError(FORBIDDEN, "Skill locked");
+    /// The course does not exist.
+    CourseNotFoundError(NOT_FOUND, "Course not found");
+    /// The section does not exist.
+    CourseSectionNotFoundError(NOT_FOUND, "Section not found");
+    /// The lecture does not exist.
+    LectureNotFoundError(NOT_FOUND, "Lecture not found");
+    /// The lecture has not been completed.
+    LectureNotCompletedError(NOT_FOUND, "Lecture not completed");
+}
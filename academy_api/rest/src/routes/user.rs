@@ -2,19 +2,26 @@ use std::sync::Arc;
 
 use academy_core_user_contracts::{
     user::{UserListQuery, UserListResult},
-    PasswordUpdate, UserCreateError, UserCreateRequest, UserDeleteError, UserFeatureService,
-    UserGetError, UserListError, UserRequestPasswordResetError, UserRequestVerificationEmailError,
-    UserResetPasswordError, UserUpdateError, UserUpdateRequest, UserUpdateUserRequest,
-    UserVerifyEmailError, UserVerifyNewsletterSubscriptionError,
+    PasswordUpdate, UserCreateError, UserCreateInviteError, UserCreateInviteRequest,
+    UserCreateRequest, UserDeleteAvatarError, UserDeleteError, UserDeleteInviteError,
+    UserFeatureService, UserGetAvatarError, UserGetError, UserGetPrivacyError,
+    UserGetPublicProfileError, UserListError, UserListInvitesError, UserRequestPasswordResetError,
+    UserRequestVerificationEmailError, UserResetPasswordError, UserUnsubscribeFromNewsletterError,
+    UserUpdateError, UserUpdatePrivacyError, UserUpdateRequest, UserUpdateUserRequest,
+    UserUploadAvatarError, UserVerifyEmailError, UserVerifyNewsletterSubscriptionError,
 };
 use academy_models::{
+    country::Country,
     email_address::EmailAddress,
+    invite::{InviteCode, InviteMaxUses},
+    locale::Locale,
     oauth2::OAuth2RegistrationToken,
+    pagination::PaginationCursor,
     session::DeviceName,
     user::{
-        UserBio, UserCity, UserCountry, UserDisplayName, UserFirstName, UserInvoiceInfo,
-        UserLastName, UserName, UserPassword, UserProfilePatch, UserStreet, UserTags, UserVatId,
-        UserZipCode,
+        NewsletterUnsubscribeToken, UserBio, UserCity, UserDisplayName, UserFirstName,
+        UserInvoiceInfo, UserLastName, UserName, UserPassword, UserPrivacyPatch, UserProfilePatch,
+        UserProfileVisibility, UserStreet, UserTag, UserTags, UserVatId, UserZipCode,
     },
     RecaptchaResponse, VerificationCode,
 };
@@ -23,8 +30,9 @@ use aide::{
     transform::TransformOperation,
 };
 use axum::{
+    body::Bytes,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
@@ -39,11 +47,19 @@ use crate::{
         auth_error, auth_error_docs, internal_server_error, internal_server_error_docs,
         PermissionDeniedError, RecaptchaFailedError,
     },
-    extractors::{auth::ApiToken, user_agent::UserAgent},
+    extractors::{
+        accept_language::AcceptLanguage,
+        auth::{ApiToken, OptionalApiToken},
+        user_agent::UserAgent,
+    },
     models::{
+        invite::{ApiInvite, PathInviteId},
         session::ApiLogin,
-        user::{ApiUser, ApiUserFilter, ApiUserIdOrSelf, ApiUserPasswordOrEmpty, PathUserIdOrSelf},
-        ApiPaginationSlice, OkResponse, StringOption,
+        user::{
+            ApiUser, ApiUserFilter, ApiUserIdOrSelf, ApiUserPasswordOrEmpty, ApiUserPrivacy,
+            ApiUserPublicProfile, ApiUserSorting, PathUserId, PathUserIdOrSelf,
+        },
+        ApiPaginationSlice, ApiTimestamp, OkResponse, StringOption,
     },
 };
 
@@ -73,6 +89,44 @@ pub fn router(service: Arc<impl UserFeatureService>) -> ApiRouter<()> {
                 verify_newsletter_subscription_docs,
             ),
         )
+        .api_route(
+            "/auth/newsletter/unsubscribe",
+            routing::post_with(
+                unsubscribe_from_newsletter,
+                unsubscribe_from_newsletter_docs,
+            ),
+        )
+        .api_route(
+            "/auth/users/:user_id/avatar",
+            routing::put_with(upload_avatar, upload_avatar_docs)
+                .delete_with(delete_avatar, delete_avatar_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/privacy",
+            routing::get_with(get_privacy, get_privacy_docs)
+                .patch_with(update_privacy, update_privacy_docs),
+        )
+        .api_route(
+            "/auth/users/by-name/:name/profile",
+            routing::get_with(get_public_profile, get_public_profile_docs),
+        )
+        .api_route(
+            "/auth/users/:user_id/invites",
+            routing::get_with(list_user_invites, list_user_invites_docs),
+        )
+        .api_route(
+            "/auth/invites",
+            routing::get_with(list_invites, list_invites_docs)
+                .post_with(create_invite, create_invite_docs),
+        )
+        .api_route(
+            "/auth/invites/:invite_id",
+            routing::delete_with(delete_invite, delete_invite_docs),
+        )
+        .api_route(
+            "/auth/avatars/:user_id",
+            routing::get_with(get_avatar, get_avatar_docs),
+        )
         .api_route(
             "/auth/password_reset",
             routing::post_with(request_password_reset, request_password_reset_docs)
@@ -88,6 +142,8 @@ struct ListResult {
     total: u64,
     /// The paginated list of users matching the given query
     users: Vec<ApiUser>,
+    /// Cursor for requesting the next page (null if this is the last page)
+    next_cursor: Option<PaginationCursor>,
 }
 
 async fn list(
@@ -95,12 +151,14 @@ async fn list(
     token: ApiToken,
     Query(pagination): Query<ApiPaginationSlice>,
     Query(filter): Query<ApiUserFilter>,
+    Query(sorting): Query<ApiUserSorting>,
 ) -> Response {
     match user_service
         .list_users(
             &token.0,
             UserListQuery {
                 filter: filter.into(),
+                sorting: sorting.into(),
                 pagination: pagination.into(),
             },
         )
@@ -109,11 +167,14 @@ async fn list(
         Ok(UserListResult {
             total,
             user_composites: users,
+            next_cursor,
         }) => Json(ListResult {
             total,
             users: users.into_iter().map(Into::into).collect(),
+            next_cursor,
         })
         .into_response(),
+        Err(UserListError::InvalidCursor) => InvalidCursorError.into_response(),
         Err(UserListError::Auth(err)) => auth_error(err),
         Err(UserListError::Other(err)) => internal_server_error(err),
     }
@@ -122,6 +183,7 @@ async fn list(
 fn list_docs(op: TransformOperation) -> TransformOperation {
     op.summary("Return all users matching the given query.")
         .add_response::<ListResult>(StatusCode::OK, None)
+        .add_error::<InvalidCursorError>()
         .with(auth_error_docs)
         .with(internal_server_error_docs)
 }
@@ -155,11 +217,19 @@ struct CreateRequest {
     password: StringOption<UserPassword>,
     oauth_register_token: StringOption<OAuth2RegistrationToken>,
     recaptcha_response: StringOption<RecaptchaResponse>,
+    /// Required if registration is restricted to invited users
+    #[serde(default)]
+    invite_code: StringOption<InviteCode>,
+    /// Preferred language of the user. Defaults to the most preferred
+    /// language of the `Accept-Language` header.
+    #[serde(default)]
+    locale: StringOption<Locale>,
 }
 
 async fn create(
     user_service: State<Arc<impl UserFeatureService>>,
     user_agent: UserAgent,
+    accept_language: AcceptLanguage,
     Json(CreateRequest {
         name,
         display_name,
@@ -167,6 +237,8 @@ async fn create(
         password,
         oauth_register_token,
         recaptcha_response,
+        invite_code,
+        locale,
     }): Json<CreateRequest>,
 ) -> Response {
     match user_service
@@ -177,6 +249,8 @@ async fn create(
                 email,
                 password: password.into(),
                 oauth2_registration_token: oauth_register_token.into(),
+                invite_code: invite_code.into(),
+                locale: Option::from(locale).or(accept_language.0),
             },
             user_agent.0.map(DeviceName::from_string_truncated),
             recaptcha_response.into(),
@@ -192,6 +266,10 @@ async fn create(
             InvalidOAuthTokenError.into_response()
         }
         Err(UserCreateError::RemoteAlreadyLinked) => RemoteAlreadyLinkedError.into_response(),
+        Err(UserCreateError::RegistrationClosed) => RegistrationClosedError.into_response(),
+        Err(UserCreateError::InviteCodeRequired) => InviteCodeRequiredError.into_response(),
+        Err(UserCreateError::InvalidInviteCode) => InvalidInviteCodeError.into_response(),
+        Err(UserCreateError::EmailNotAllowed) => EmailNotAllowedError.into_response(),
         Err(UserCreateError::Other(err)) => internal_server_error(err),
     }
 }
@@ -206,6 +284,10 @@ fn create_docs(op: TransformOperation) -> TransformOperation {
         .add_error::<NoLoginMethodError>()
         .add_error::<InvalidOAuthTokenError>()
         .add_error::<RemoteAlreadyLinkedError>()
+        .add_error::<RegistrationClosedError>()
+        .add_error::<InviteCodeRequiredError>()
+        .add_error::<InvalidInviteCodeError>()
+        .add_error::<EmailNotAllowedError>()
         .with(internal_server_error_docs)
 }
 
@@ -221,13 +303,14 @@ struct UpdateRequest {
     description: StringOption<UserBio>,
     tags: Option<UserTags>,
     newsletter: Option<bool>,
+    locale: StringOption<Locale>,
     business: Option<bool>,
     first_name: StringOption<UserFirstName>,
     last_name: StringOption<UserLastName>,
     street: StringOption<UserStreet>,
     zip_code: StringOption<UserZipCode>,
     city: StringOption<UserCity>,
-    country: StringOption<UserCountry>,
+    country: StringOption<Country>,
     vat_id: StringOption<UserVatId>,
 }
 
@@ -246,6 +329,7 @@ async fn update(
         description,
         tags,
         newsletter,
+        locale,
         business,
         first_name,
         last_name,
@@ -274,6 +358,7 @@ async fn update(
                     enabled: enabled.into(),
                     admin: admin.into(),
                     newsletter: newsletter.into(),
+                    locale: Option::from(locale).into(),
                 },
                 profile: UserProfilePatch {
                     display_name: Option::from(display_name).into(),
@@ -289,6 +374,7 @@ async fn update(
                     city: city.into(),
                     country: country.into(),
                     vat_id: vat_id.into(),
+                    vat_id_validation: None,
                 },
             },
         )
@@ -298,6 +384,7 @@ async fn update(
         Err(UserUpdateError::NotFound) => UserNotFoundError.into_response(),
         Err(UserUpdateError::NameConflict) => UserAlreadyExistsError.into_response(),
         Err(UserUpdateError::EmailConflict) => EmailAlreadyExistsError.into_response(),
+        Err(UserUpdateError::EmailNotAllowed) => EmailNotAllowedError.into_response(),
         Err(UserUpdateError::CannotRemovePassword) => {
             CannotDeleteLastLoginMethodError.into_response()
         }
@@ -308,6 +395,8 @@ async fn update(
         ) => PermissionDeniedError.into_response(),
         Err(UserUpdateError::NoEmail) => NoEmailError.into_response(),
         Err(UserUpdateError::InvalidVatId) => InvalidVatIdError.into_response(),
+        Err(UserUpdateError::InvalidZipCode) => InvalidZipCodeError.into_response(),
+        Err(UserUpdateError::VatIdCountryMismatch) => VatIdCountryMismatchError.into_response(),
         Err(UserUpdateError::Auth(err)) => auth_error(err),
         Err(UserUpdateError::Other(err)) => internal_server_error(err),
     }
@@ -319,10 +408,13 @@ fn update_docs(op: TransformOperation) -> TransformOperation {
         .add_error::<UserNotFoundError>()
         .add_error::<UserAlreadyExistsError>()
         .add_error::<EmailAlreadyExistsError>()
+        .add_error::<EmailNotAllowedError>()
         .add_error::<CannotDeleteLastLoginMethodError>()
         .add_error::<PermissionDeniedError>()
         .add_error::<NoEmailError>()
         .add_error::<InvalidVatIdError>()
+        .add_error::<InvalidZipCodeError>()
+        .add_error::<VatIdCountryMismatchError>()
         .with(auth_error_docs)
         .with(internal_server_error_docs)
 }
@@ -453,6 +545,40 @@ fn verify_newsletter_subscription_docs(op: TransformOperation) -> TransformOpera
         .with(internal_server_error_docs)
 }
 
+#[derive(Deserialize, JsonSchema)]
+struct UnsubscribeFromNewsletterQuery {
+    /// The signed token included in newsletter emails
+    token: NewsletterUnsubscribeToken,
+}
+
+async fn unsubscribe_from_newsletter(
+    service: State<Arc<impl UserFeatureService>>,
+    Query(UnsubscribeFromNewsletterQuery { token }): Query<UnsubscribeFromNewsletterQuery>,
+) -> Response {
+    match service.unsubscribe_from_newsletter(&token).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(UserUnsubscribeFromNewsletterError::InvalidToken) => {
+            InvalidUnsubscribeTokenError.into_response()
+        }
+        Err(UserUnsubscribeFromNewsletterError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn unsubscribe_from_newsletter_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Unsubscribe a user from the newsletter using a signed unsubscribe token.")
+        .description(
+            "This endpoint does not require authentication and supports [RFC 8058](https://www.rfc-editor.org/rfc/rfc8058) \
+             one-click unsubscribe requests. The url including the token is sent in the \
+             `List-Unsubscribe` header of newsletter emails.",
+        )
+        .add_response::<OkResponse>(
+            StatusCode::OK,
+            "The user has been unsubscribed from the newsletter.",
+        )
+        .add_error::<InvalidUnsubscribeTokenError>()
+        .with(internal_server_error_docs)
+}
+
 #[derive(Deserialize, JsonSchema)]
 struct RequestPasswordResetRequest {
     email: EmailAddress,
@@ -515,6 +641,343 @@ fn reset_password_docs(op: TransformOperation) -> TransformOperation {
         .with(internal_server_error_docs)
 }
 
+async fn upload_avatar(
+    service: State<Arc<impl UserFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+    data: Bytes,
+) -> Response {
+    match service
+        .upload_avatar(&token.0, user_id.into(), data.into())
+        .await
+    {
+        Ok(user) => Json(ApiUser::from(user)).into_response(),
+        Err(UserUploadAvatarError::NotFound) => UserNotFoundError.into_response(),
+        Err(UserUploadAvatarError::TooLarge) => AvatarTooLargeError.into_response(),
+        Err(UserUploadAvatarError::UnsupportedFormat) => {
+            UnsupportedAvatarFormatError.into_response()
+        }
+        Err(UserUploadAvatarError::InvalidImage) => InvalidAvatarError.into_response(),
+        Err(UserUploadAvatarError::Auth(err)) => auth_error(err),
+        Err(UserUploadAvatarError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn upload_avatar_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Upload a new avatar for the given user.")
+        .description(
+            "The request body must contain the raw PNG, JPEG or WebP encoded image. The image is \
+             cropped to a square and stored in multiple sizes.",
+        )
+        .add_response::<ApiUser>(StatusCode::OK, "The avatar has been uploaded.")
+        .add_error::<UserNotFoundError>()
+        .add_error::<AvatarTooLargeError>()
+        .add_error::<UnsupportedAvatarFormatError>()
+        .add_error::<InvalidAvatarError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn delete_avatar(
+    service: State<Arc<impl UserFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+) -> Response {
+    match service.delete_avatar(&token.0, user_id.into()).await {
+        Ok(user) => Json(ApiUser::from(user)).into_response(),
+        Err(UserDeleteAvatarError::NotFound) => UserNotFoundError.into_response(),
+        Err(UserDeleteAvatarError::Auth(err)) => auth_error(err),
+        Err(UserDeleteAvatarError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn delete_avatar_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Delete the avatar of the given user.")
+        .add_response::<ApiUser>(StatusCode::OK, "The avatar has been deleted.")
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GetAvatarQuery {
+    /// Minimum width and height of the avatar in pixels (defaults to the
+    /// largest available size)
+    size: Option<u32>,
+}
+
+async fn get_avatar(
+    service: State<Arc<impl UserFeatureService>>,
+    token: OptionalApiToken,
+    Path(PathUserId { user_id }): Path<PathUserId>,
+    Query(GetAvatarQuery { size }): Query<GetAvatarQuery>,
+) -> Response {
+    // Avatars which are only visible to some visitors must not be stored in
+    // shared caches.
+    let cache_control = match token.0 {
+        Some(_) => "private, max-age=86400",
+        None => "public, max-age=86400",
+    };
+
+    match service.get_avatar(token.0.as_ref(), user_id, size).await {
+        Ok(data) => (
+            [
+                (header::CONTENT_TYPE, "image/png"),
+                (header::CACHE_CONTROL, cache_control),
+            ],
+            data,
+        )
+            .into_response(),
+        Err(UserGetAvatarError::NotFound) => AvatarNotFoundError.into_response(),
+        Err(UserGetAvatarError::Auth(err)) => auth_error(err),
+        Err(UserGetAvatarError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_avatar_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the PNG encoded avatar of the given user.")
+        .description(
+            "Avatars hidden by the user's privacy settings are treated as if the user did not \
+             have an avatar.",
+        )
+        .response_with::<200, Vec<u8>, _>(|res| res.description("The avatar image."))
+        .add_error::<AvatarNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GetPublicProfilePath {
+    name: UserName,
+}
+
+async fn get_public_profile(
+    service: State<Arc<impl UserFeatureService>>,
+    token: OptionalApiToken,
+    Path(GetPublicProfilePath { name }): Path<GetPublicProfilePath>,
+) -> Response {
+    match service.get_public_profile(token.0.as_ref(), name).await {
+        Ok(profile) => Json(ApiUserPublicProfile::from(profile)).into_response(),
+        Err(UserGetPublicProfileError::NotFound) => UserNotFoundError.into_response(),
+        Err(UserGetPublicProfileError::Auth(err)) => auth_error(err),
+        Err(UserGetPublicProfileError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_public_profile_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the public profile of the user with the given name.")
+        .description(
+            "Fields hidden by the user's privacy settings are set to `null`. Disabled users are \
+             only visible to administrators.",
+        )
+        .add_response::<ApiUserPublicProfile>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn get_privacy(
+    service: State<Arc<impl UserFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+) -> Response {
+    match service.get_privacy(&token.0, user_id.into()).await {
+        Ok(privacy) => Json(ApiUserPrivacy::from(privacy)).into_response(),
+        Err(UserGetPrivacyError::NotFound) => UserNotFoundError.into_response(),
+        Err(UserGetPrivacyError::Auth(err)) => auth_error(err),
+        Err(UserGetPrivacyError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn get_privacy_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return the privacy settings of the given user.")
+        .add_response::<ApiUserPrivacy>(StatusCode::OK, None)
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdatePrivacyRequest {
+    display_name: Option<UserProfileVisibility>,
+    description: Option<UserProfileVisibility>,
+    tags: Option<UserProfileVisibility>,
+    registration: Option<UserProfileVisibility>,
+    avatar: Option<UserProfileVisibility>,
+}
+
+async fn update_privacy(
+    service: State<Arc<impl UserFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+    Json(UpdatePrivacyRequest {
+        display_name,
+        description,
+        tags,
+        registration,
+        avatar,
+    }): Json<UpdatePrivacyRequest>,
+) -> Response {
+    match service
+        .update_privacy(
+            &token.0,
+            user_id.into(),
+            UserPrivacyPatch {
+                display_name: display_name.into(),
+                bio: description.into(),
+                tags: tags.into(),
+                registration: registration.into(),
+                avatar: avatar.into(),
+            },
+        )
+        .await
+    {
+        Ok(privacy) => Json(ApiUserPrivacy::from(privacy)).into_response(),
+        Err(UserUpdatePrivacyError::NotFound) => UserNotFoundError.into_response(),
+        Err(UserUpdatePrivacyError::Auth(err)) => auth_error(err),
+        Err(UserUpdatePrivacyError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn update_privacy_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Update the privacy settings of the given user.")
+        .add_response::<ApiUserPrivacy>(StatusCode::OK, "The privacy settings have been updated.")
+        .add_error::<UserNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn list_user_invites(
+    user_service: State<Arc<impl UserFeatureService>>,
+    token: ApiToken,
+    Path(PathUserIdOrSelf { user_id }): Path<PathUserIdOrSelf>,
+) -> Response {
+    match user_service
+        .list_invites(&token.0, Some(user_id.into()))
+        .await
+    {
+        Ok(invites) => Json(
+            invites
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<ApiInvite>>(),
+        )
+        .into_response(),
+        Err(UserListInvitesError::Auth(err)) => auth_error(err),
+        Err(UserListInvitesError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_user_invites_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return all invites created by the given user.")
+        .add_response::<Vec<ApiInvite>>(StatusCode::OK, None)
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn list_invites(
+    user_service: State<Arc<impl UserFeatureService>>,
+    token: ApiToken,
+) -> Response {
+    match user_service.list_invites(&token.0, None).await {
+        Ok(invites) => Json(
+            invites
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<ApiInvite>>(),
+        )
+        .into_response(),
+        Err(UserListInvitesError::Auth(err)) => auth_error(err),
+        Err(UserListInvitesError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn list_invites_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Return all invites.")
+        .add_response::<Vec<ApiInvite>>(StatusCode::OK, None)
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateInviteRequest {
+    /// Timestamp after which the invite can no longer be used (admin only)
+    expires_at: Option<ApiTimestamp>,
+    /// The maximum number of users that can register using this invite (admin
+    /// only)
+    max_uses: Option<InviteMaxUses>,
+    /// Grant admin privileges to users registering with this invite (admin
+    /// only)
+    #[serde(default)]
+    admin: bool,
+    /// Tag to add to the profile of users registering with this invite (admin
+    /// only)
+    #[serde(default)]
+    cohort: StringOption<UserTag>,
+}
+
+async fn create_invite(
+    user_service: State<Arc<impl UserFeatureService>>,
+    token: ApiToken,
+    Json(CreateInviteRequest {
+        expires_at,
+        max_uses,
+        admin,
+        cohort,
+    }): Json<CreateInviteRequest>,
+) -> Response {
+    match user_service
+        .create_invite(
+            &token.0,
+            UserCreateInviteRequest {
+                expires_at: expires_at.map(Into::into),
+                max_uses,
+                admin,
+                cohort: cohort.into(),
+            },
+        )
+        .await
+    {
+        Ok(invite) => Json(ApiInvite::from(invite)).into_response(),
+        Err(UserCreateInviteError::TooManyInvites) => TooManyInvitesError.into_response(),
+        Err(UserCreateInviteError::Auth(err)) => auth_error(err),
+        Err(UserCreateInviteError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn create_invite_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Create a new invite.")
+        .description(
+            "Invites created by non-admin users can be used only once and expire after a fixed \
+             duration.",
+        )
+        .add_response::<ApiInvite>(StatusCode::OK, None)
+        .add_error::<TooManyInvitesError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
+async fn delete_invite(
+    user_service: State<Arc<impl UserFeatureService>>,
+    token: ApiToken,
+    Path(PathInviteId { invite_id }): Path<PathInviteId>,
+) -> Response {
+    match user_service.delete_invite(&token.0, invite_id).await {
+        Ok(()) => Json(OkResponse).into_response(),
+        Err(UserDeleteInviteError::NotFound) => InviteNotFoundError.into_response(),
+        Err(UserDeleteInviteError::Auth(err)) => auth_error(err),
+        Err(UserDeleteInviteError::Other(err)) => internal_server_error(err),
+    }
+}
+
+fn delete_invite_docs(op: TransformOperation) -> TransformOperation {
+    op.summary("Delete the given invite.")
+        .add_response::<OkResponse>(StatusCode::OK, "The invite has been deleted.")
+        .add_error::<InviteNotFoundError>()
+        .with(auth_error_docs)
+        .with(internal_server_error_docs)
+}
+
 error_code! {
     /// The user does not exist.
     pub UserNotFoundError(NOT_FOUND, "User not found");
@@ -532,18 +995,47 @@ error_code! {
     InvalidOAuthTokenError(UNAUTHORIZED, "Invalid OAuth token");
     /// The vat id is invalid.
     InvalidVatIdError(NOT_FOUND, "Invalid VAT ID");
+    /// The zip code is not valid in the user's country.
+    InvalidZipCodeError(BAD_REQUEST, "Invalid zip code");
+    /// The vat id has not been issued by the user's country.
+    VatIdCountryMismatchError(BAD_REQUEST, "VAT ID country mismatch");
     /// The email or password reset code is invalid or the reset code has expired.
     PasswordResetFailedError(UNAUTHORIZED, "Password reset failed");
     /// The verification code is invalid.
     InvalidVerificationCodeError(UNAUTHORIZED, "Invalid verification code");
     /// The user is already subscribed to the newsletter.
     NewsletterAlreadySubscribedError(CONFLICT, "Newsletter already subscribed");
+    /// The newsletter unsubscribe token is invalid or has expired.
+    InvalidUnsubscribeTokenError(UNAUTHORIZED, "Invalid unsubscribe token");
     /// The user does not have an email address.
     NoEmailError(FORBIDDEN, "No email");
     /// The user's email address has already been verified.
     EmailAlreadyVerifiedError(PRECONDITION_FAILED, "Email already verified");
     /// The email address is invalid.
     InvalidEmailError(BAD_REQUEST, "Invalid email");
+    /// The domain of the email address is not allowed, belongs to a disposable email provider or
+    /// cannot receive emails.
+    EmailNotAllowedError(FORBIDDEN, "Email not allowed");
+    /// The pagination cursor is invalid, has expired or was created for a different sorting.
+    InvalidCursorError(BAD_REQUEST, "Invalid cursor");
     /// Only the email address of the currently authenticated user can be verified.
     CanOnlyVerifyEmailForSelfError(BAD_REQUEST, "Can only verify email for self");
+    /// The uploaded avatar exceeds the maximum file size.
+    AvatarTooLargeError(PAYLOAD_TOO_LARGE, "Avatar too large");
+    /// The uploaded avatar is not a PNG, JPEG or WebP image.
+    UnsupportedAvatarFormatError(UNSUPPORTED_MEDIA_TYPE, "Unsupported avatar format");
+    /// The uploaded avatar could not be decoded.
+    InvalidAvatarError(UNPROCESSABLE_ENTITY, "Invalid avatar");
+    /// The user does not have an avatar.
+    AvatarNotFoundError(NOT_FOUND, "Avatar not found");
+    /// Registration is currently disabled.
+    RegistrationClosedError(FORBIDDEN, "Registration closed");
+    /// Registration is restricted to invited users, but no invite code was provided.
+    InviteCodeRequiredError(FORBIDDEN, "Invite code required");
+    /// The invite code is invalid, has expired or has already been used up.
+    InvalidInviteCodeError(FORBIDDEN, "Invalid invite code");
+    /// The user has reached the maximum number of valid invites.
+    TooManyInvitesError(FORBIDDEN, "Too many invites");
+    /// The invite does not exist.
+    InviteNotFoundError(NOT_FOUND, "Invite not found");
 }
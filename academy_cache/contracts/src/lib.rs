@@ -25,6 +25,27 @@ pub trait CacheService: Sized + Send + Sync + 'static {
     /// Does nothing if the cache item does not exist.
     fn remove(&self, key: &str) -> impl Future<Output = anyhow::Result<()>> + Send;
 
+    /// Read and remove a cache item in a single atomic operation.
+    ///
+    /// If multiple callers try to take the same item concurrently, only one of
+    /// them receives its value.
+    fn take<T: DeserializeOwned + Debug + 'static>(
+        &self,
+        key: &str,
+    ) -> impl Future<Output = anyhow::Result<Option<T>>> + Send;
+
+    /// Increment a counter by one in a single atomic operation and return its
+    /// new value.
+    ///
+    /// A counter which does not exist yet is created with a value of `1` and
+    /// is automatically removed after `ttl`. Subsequent increments do not
+    /// extend this timeout. Counters can only be read using this method.
+    fn increment(
+        &self,
+        key: &str,
+        ttl: Duration,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+
     /// Verify the connection to the cache.
     fn ping(&self) -> impl Future<Output = anyhow::Result<()>> + Send;
 }
@@ -67,4 +88,24 @@ impl MockCacheService {
             .return_once(|_| Box::pin(std::future::ready(Ok(()))));
         self
     }
+
+    pub fn with_take<T: DeserializeOwned + Debug + Send + 'static>(
+        mut self,
+        key: String,
+        result: Option<T>,
+    ) -> Self {
+        self.expect_take()
+            .once()
+            .with(mockall::predicate::eq(key))
+            .return_once(|_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_increment(mut self, key: String, ttl: Duration, result: u64) -> Self {
+        self.expect_increment()
+            .once()
+            .with(mockall::predicate::eq(key), mockall::predicate::eq(ttl))
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
 }
@@ -120,6 +120,53 @@ impl CacheService for ValkeyCache {
             .context("Failed to remove item from cache")
     }
 
+    #[trace_instrument(skip(self))]
+    async fn take<T: DeserializeOwned + Debug + 'static>(
+        &self,
+        key: &str,
+    ) -> anyhow::Result<Option<T>> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .context("Failed to acquire cache connection")?;
+
+        let result = conn
+            .get_del::<_, Option<Vec<u8>>>(key)
+            .await
+            .context("Failed to take value from cache")?;
+
+        result
+            .map(|data| rmp_serde::from_slice(&data))
+            .transpose()
+            .context("Failed to deserialize cached value")
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn increment(&self, key: &str, ttl: Duration) -> anyhow::Result<u64> {
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .context("Failed to acquire cache connection")?;
+
+        // `NX` only sets the expiry if the key does not have one yet, i.e. if it has
+        // just been created by `INCR`
+        let (count,) = redis::pipe()
+            .atomic()
+            .incr(key, 1)
+            .cmd("PEXPIRE")
+            .arg(key)
+            .arg(u64::try_from(ttl.as_millis())?)
+            .arg("NX")
+            .ignore()
+            .query_async::<(u64,)>(&mut *conn)
+            .await
+            .context("Failed to increment counter in cache")?;
+
+        Ok(count)
+    }
+
     #[trace_instrument(skip(self))]
     async fn ping(&self) -> anyhow::Result<()> {
         let mut conn = self
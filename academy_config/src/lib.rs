@@ -1,11 +1,14 @@
 use std::{
     collections::HashMap,
     net::{IpAddr, SocketAddr},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
-use academy_models::{email_address::EmailAddressWithName, mfa::TotpSecretLength, url::Url};
-use anyhow::Context;
+use academy_models::{
+    country::Country, email_address::EmailAddressWithName, invite::RegistrationMode,
+    locale::Locale, mfa::TotpSecretLength, url::Url,
+};
+use anyhow::{bail, Context};
 use config::{File, FileFormat};
 use duration::Duration;
 use serde::Deserialize;
@@ -60,6 +63,25 @@ fn load_paths(paths: &[impl AsRef<Path>], overrides: &[&str]) -> anyhow::Result<
     config
         .recaptcha
         .take_if(|recaptcha| recaptcha.enable == Some(false));
+    config
+        .hcaptcha
+        .take_if(|hcaptcha| hcaptcha.enable == Some(false));
+    config
+        .turnstile
+        .take_if(|turnstile| turnstile.enable == Some(false));
+    config
+        .proof_of_work
+        .take_if(|proof_of_work| proof_of_work.enable == Some(false));
+
+    let captcha_providers = [
+        config.recaptcha.is_some(),
+        config.hcaptcha.is_some(),
+        config.turnstile.is_some(),
+        config.proof_of_work.is_some(),
+    ];
+    if captcha_providers.into_iter().filter(|&x| x).count() > 1 {
+        bail!("Only one of recaptcha, hcaptcha, turnstile and proof_of_work may be enabled");
+    }
 
     config.sentry.take_if(|sentry| sentry.enable == Some(false));
 
@@ -79,15 +101,24 @@ pub struct Config {
     pub database: DatabaseConfig,
     pub cache: CacheConfig,
     pub email: EmailConfig,
+    pub storage: StorageConfig,
     pub jwt: JwtConfig,
     pub internal: InternalConfig,
     pub health: HealthConfig,
     pub user: UserConfig,
+    pub newsletter: NewsletterConfig,
     pub session: SessionConfig,
     pub totp: TotpConfig,
     pub contact: ContactConfig,
+    pub checkout: CheckoutConfig,
+    pub invoice: InvoiceConfig,
     pub recaptcha: Option<RecaptchaConfig>,
+    pub hcaptcha: Option<HcaptchaConfig>,
+    pub turnstile: Option<TurnstileConfig>,
+    pub proof_of_work: Option<ProofOfWorkConfig>,
     pub vat: VatConfig,
+    pub payment: PaymentConfig,
+    pub dns: DnsConfig,
     pub sentry: Option<SentryConfig>,
     pub oauth2: Option<OAuth2Config>,
 }
@@ -128,6 +159,28 @@ pub struct CacheConfig {
 pub struct EmailConfig {
     pub smtp_url: String,
     pub from: EmailAddressWithName,
+    pub outbox_batch_size: u64,
+    pub outbox_poll_interval: Duration,
+    pub outbox_max_attempts: u32,
+    pub outbox_retry_delay: Duration,
+    pub outbox_max_retry_delay: Duration,
+    pub outbox_retention: Duration,
+    pub logo_url: Url,
+    pub logo_path: Option<PathBuf>,
+    pub dkim: Option<EmailDkimConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EmailDkimConfig {
+    pub selector: String,
+    pub domain: String,
+    pub private_key_path: PathBuf,
+    pub headers: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StorageConfig {
+    pub path: PathBuf,
 }
 
 #[derive(Debug, Deserialize)]
@@ -138,7 +191,6 @@ pub struct JwtConfig {
 #[derive(Debug, Deserialize)]
 pub struct InternalConfig {
     pub jwt_ttl: Duration,
-    pub shop_url: Url,
 }
 
 #[derive(Debug, Deserialize)]
@@ -155,8 +207,32 @@ pub struct UserConfig {
     pub verification_redirect_url: String,
     pub password_reset_code_ttl: Duration,
     pub password_reset_redirect_url: String,
+    pub account_recovery_url: String,
     pub newsletter_code_ttl: Duration,
     pub newsletter_redirect_url: String,
+    pub newsletter_unsubscribe_url: Url,
+    pub newsletter_unsubscribe_redirect_url: String,
+    pub newsletter_unsubscribe_token_ttl: Duration,
+    pub avatar_max_upload_size: usize,
+    pub avatar_sizes: Vec<u32>,
+    pub avatar_url: Url,
+    pub list_cursor_ttl: Duration,
+    pub registration_mode: RegistrationMode,
+    pub invite_limit: u64,
+    pub invite_ttl: Duration,
+    pub email_allowlist: Vec<String>,
+    pub email_denylist: Vec<String>,
+    pub email_block_disposable: bool,
+    pub email_disposable_list: Option<PathBuf>,
+    pub email_require_mx: bool,
+    pub default_locale: Locale,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewsletterConfig {
+    pub batch_size: u64,
+    pub batch_interval: Duration,
+    pub locale: Locale,
 }
 
 #[derive(Debug, Deserialize)]
@@ -175,6 +251,38 @@ pub struct TotpConfig {
 #[derive(Debug, Deserialize)]
 pub struct ContactConfig {
     pub email: EmailAddressWithName,
+    pub spam_reject_score: u64,
+    pub spam_quarantine_score: u64,
+    pub spam_link_score: u64,
+    pub spam_keywords: Vec<String>,
+    pub spam_keyword_score: u64,
+    pub spam_domains: Vec<String>,
+    pub spam_domain_score: u64,
+    pub spam_repeat_window: Duration,
+    pub spam_repeat_score: u64,
+    pub spam_honeypot_score: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutConfig {
+    pub success_url: String,
+    pub cancel_url: String,
+    pub packages: Vec<CheckoutPackageConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutPackageConfig {
+    pub id: String,
+    pub coins: u64,
+    pub price: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InvoiceConfig {
+    pub issuer: Vec<String>,
+    pub issuer_vat_id: Option<String>,
+    pub country: Country,
+    pub number_prefix: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -186,9 +294,48 @@ pub struct RecaptchaConfig {
     pub min_score: f64,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct HcaptchaConfig {
+    pub enable: Option<bool>,
+    pub siteverify_endpoint_override: Option<Url>,
+    pub sitekey: String,
+    pub secret: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TurnstileConfig {
+    pub enable: Option<bool>,
+    pub siteverify_endpoint_override: Option<Url>,
+    pub sitekey: String,
+    pub secret: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProofOfWorkConfig {
+    pub enable: Option<bool>,
+    pub difficulty: u8,
+    pub challenge_ttl: Duration,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct VatConfig {
     pub validate_endpoint_override: Option<Url>,
+    pub requester_vat_id: Option<String>,
+    pub cache_ttl: Duration,
+    pub revalidate_after: Duration,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaymentConfig {
+    pub api_url: Url,
+    pub secret_key: String,
+    pub webhook_secret: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DnsConfig {
+    pub nameserver_override: Option<SocketAddr>,
+    pub timeout: Duration,
 }
 
 #[derive(Debug, Deserialize)]
@@ -0,0 +1,39 @@
+use std::future::Future;
+
+use academy_models::{checkout::CoinOrder, invoice::Invoice};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait CheckoutInvoiceService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Issue the invoice for a completed coin order and link it to the order.
+    ///
+    /// Fails if the user of the order does not exist anymore or their
+    /// invoice info is incomplete.
+    fn create(
+        &self,
+        txn: &mut Txn,
+        order: &CoinOrder,
+    ) -> impl Future<Output = anyhow::Result<Invoice>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockCheckoutInvoiceService<Txn> {
+    pub fn with_create(mut self, order: CoinOrder, result: Invoice) -> Self {
+        self.expect_create()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(order))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_error(mut self, order: CoinOrder) -> Self {
+        self.expect_create()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(order))
+            .return_once(|_, _| {
+                Box::pin(std::future::ready(Err(anyhow::anyhow!(
+                    "The country of the invoice recipient is unknown"
+                ))))
+            });
+        self
+    }
+}
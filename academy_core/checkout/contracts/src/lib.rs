@@ -0,0 +1,121 @@
+use std::future::Future;
+
+use academy_models::{
+    auth::{AccessToken, AuthError},
+    checkout::{CoinOrder, CoinOrderId, CoinPackage, CoinPackageId},
+    pagination::PaginationSlice,
+    url::Url,
+    user::UserIdOrSelf,
+};
+use thiserror::Error;
+
+pub mod invoice;
+
+pub trait CheckoutFeatureService: Send + Sync + 'static {
+    /// Return all coin packages which can be bought.
+    fn list_packages(&self) -> Vec<CoinPackage>;
+
+    /// Order a coin package.
+    ///
+    /// The user has to be redirected to the returned url to pay the order.
+    /// The coins are credited and an invoice is issued as soon as the payment
+    /// provider reports the payment as successful.
+    ///
+    /// Can only be used by administrators, if not used on the authenticated
+    /// user.
+    fn create_order(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        package: CoinPackageId,
+    ) -> impl Future<Output = Result<CheckoutResult, CheckoutCreateOrderError>> + Send;
+
+    /// Return the most recent coin orders of a user.
+    ///
+    /// Can only be used by administrators, if not used on the authenticated
+    /// user.
+    fn list_orders(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        pagination: PaginationSlice,
+    ) -> impl Future<Output = Result<CheckoutListOrdersResult, CheckoutListOrdersError>> + Send;
+
+    /// Return a coin order of a user.
+    ///
+    /// Can only be used by administrators, if not used on the authenticated
+    /// user.
+    fn get_order(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        order_id: CoinOrderId,
+    ) -> impl Future<Output = Result<CoinOrder, CheckoutGetOrderError>> + Send;
+
+    /// Process a webhook request of the payment provider.
+    ///
+    /// Repeated deliveries of the same event are ignored, so the coins of an
+    /// order are credited at most once. The invoice of a completed order is
+    /// issued after the coins have been credited. If this fails, the invoice
+    /// is issued later by the `create-invoices` task.
+    fn handle_webhook(
+        &self,
+        signature: &str,
+        payload: &[u8],
+    ) -> impl Future<Output = Result<(), CheckoutHandleWebhookError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckoutResult {
+    pub order: CoinOrder,
+    /// The url of the payment page
+    pub redirect_url: Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckoutListOrdersResult {
+    pub total: u64,
+    pub orders: Vec<CoinOrder>,
+}
+
+#[derive(Debug, Error)]
+pub enum CheckoutCreateOrderError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error("The coin package does not exist.")]
+    PackageNotFound,
+    #[error("The user cannot buy coins.")]
+    CannotBuyCoins,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CheckoutListOrdersError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CheckoutGetOrderError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The order does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CheckoutHandleWebhookError {
+    #[error("The signature of the webhook request is invalid.")]
+    InvalidSignature,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
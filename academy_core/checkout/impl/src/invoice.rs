@@ -0,0 +1,185 @@
+use academy_core_checkout_contracts::invoice::CheckoutInvoiceService;
+use academy_core_invoice_contracts::invoice::InvoiceService;
+use academy_di::Build;
+use academy_models::{
+    checkout::CoinOrder,
+    invoice::{Invoice, InvoiceKind},
+};
+use academy_persistence_contracts::{checkout::CheckoutRepository, user::UserRepository};
+use academy_utils::trace_instrument;
+use anyhow::{anyhow, Context};
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct CheckoutInvoiceServiceImpl<InvoiceS, UserRepo, CheckoutRepo> {
+    invoice: InvoiceS,
+    user_repo: UserRepo,
+    checkout_repo: CheckoutRepo,
+}
+
+impl<Txn, InvoiceS, UserRepo, CheckoutRepo> CheckoutInvoiceService<Txn>
+    for CheckoutInvoiceServiceImpl<InvoiceS, UserRepo, CheckoutRepo>
+where
+    Txn: Send + Sync + 'static,
+    InvoiceS: InvoiceService<Txn>,
+    UserRepo: UserRepository<Txn>,
+    CheckoutRepo: CheckoutRepository<Txn>,
+{
+    #[trace_instrument(skip(self, txn))]
+    async fn create(&self, txn: &mut Txn, order: &CoinOrder) -> anyhow::Result<Invoice> {
+        let user_composite = self
+            .user_repo
+            .get_composite(txn, order.user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or_else(|| anyhow!("The user of the coin order does not exist"))?;
+
+        let description = format!("{} Coins", *order.coins)
+            .try_into()
+            .context("Failed to build invoice description")?;
+        let invoice = self
+            .invoice
+            .create(
+                txn,
+                &user_composite,
+                InvoiceKind::Purchase,
+                description,
+                order.price,
+            )
+            .await
+            .context("Failed to create invoice for coin order")?;
+
+        if !self
+            .checkout_repo
+            .set_order_invoice(txn, order.id, invoice.id)
+            .await
+            .context("Failed to link invoice to coin order in database")?
+        {
+            return Err(anyhow!(
+                "An invoice has already been issued for the coin order"
+            ));
+        }
+
+        Ok(invoice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_core_invoice_contracts::invoice::MockInvoiceService;
+    use academy_demo::{checkout::FOO_ORDER_PENDING, invoice::FOO_INVOICE_PURCHASE, user::FOO};
+    use academy_models::{checkout::CoinOrderStatus, invoice::InvoiceDescription};
+    use academy_persistence_contracts::{
+        checkout::MockCheckoutRepository, user::MockUserRepository,
+    };
+    use academy_utils::assert_matches;
+
+    use super::*;
+
+    type Sut = CheckoutInvoiceServiceImpl<
+        MockInvoiceService<()>,
+        MockUserRepository<()>,
+        MockCheckoutRepository<()>,
+    >;
+
+    #[tokio::test]
+    async fn ok() {
+        // Arrange
+        let order = completed_order();
+        let expected = make_invoice(&order);
+
+        let user_repo =
+            MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+        let invoice = MockInvoiceService::new().with_create(
+            FOO.clone(),
+            InvoiceKind::Purchase,
+            expected.description.clone(),
+            order.price,
+            expected.clone(),
+        );
+
+        let checkout_repo =
+            MockCheckoutRepository::new().with_set_order_invoice(order.id, expected.id, true);
+
+        let sut = CheckoutInvoiceServiceImpl {
+            invoice,
+            user_repo,
+            checkout_repo,
+        };
+
+        // Act
+        let result = sut.create(&mut (), &order).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn already_issued() {
+        // Arrange
+        let order = completed_order();
+        let expected = make_invoice(&order);
+
+        let user_repo =
+            MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+        let invoice = MockInvoiceService::new().with_create(
+            FOO.clone(),
+            InvoiceKind::Purchase,
+            expected.description.clone(),
+            order.price,
+            expected.clone(),
+        );
+
+        let checkout_repo =
+            MockCheckoutRepository::new().with_set_order_invoice(order.id, expected.id, false);
+
+        let sut = CheckoutInvoiceServiceImpl {
+            invoice,
+            user_repo,
+            checkout_repo,
+        };
+
+        // Act
+        let result = sut.create(&mut (), &order).await;
+
+        // Assert
+        assert_matches!(result, Err(_));
+    }
+
+    #[tokio::test]
+    async fn user_not_found() {
+        // Arrange
+        let order = completed_order();
+
+        let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
+
+        let sut = CheckoutInvoiceServiceImpl {
+            user_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.create(&mut (), &order).await;
+
+        // Assert
+        assert_matches!(result, Err(_));
+    }
+
+    fn completed_order() -> CoinOrder {
+        CoinOrder {
+            status: CoinOrderStatus::Completed,
+            ..FOO_ORDER_PENDING.clone()
+        }
+    }
+
+    fn make_invoice(order: &CoinOrder) -> Invoice {
+        let description: InvoiceDescription = format!("{} Coins", *order.coins).try_into().unwrap();
+        Invoice {
+            description,
+            gross: order.price,
+            ..FOO_INVOICE_PURCHASE.clone()
+        }
+    }
+}
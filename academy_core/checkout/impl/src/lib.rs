@@ -0,0 +1,318 @@
+use std::sync::Arc;
+
+use academy_auth_contracts::{AuthResultExt, AuthService};
+use academy_core_checkout_contracts::{
+    invoice::CheckoutInvoiceService, CheckoutCreateOrderError, CheckoutFeatureService,
+    CheckoutGetOrderError, CheckoutHandleWebhookError, CheckoutListOrdersError,
+    CheckoutListOrdersResult, CheckoutResult,
+};
+use academy_core_coin_contracts::coin::CoinService;
+use academy_di::Build;
+use academy_extern_contracts::payment::{PaymentApiService, PaymentCheckoutRequest, PaymentStatus};
+use academy_models::{
+    auth::AccessToken,
+    checkout::{CoinOrder, CoinOrderId, CoinOrderStatus, CoinPackage, CoinPackageId},
+    pagination::PaginationSlice,
+    url::Url,
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{
+    checkout::CheckoutRepository, user::UserRepository, Database, Transaction,
+};
+use academy_shared_contracts::{id::IdService, time::TimeService};
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use tracing::{error, trace, warn};
+
+pub mod invoice;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct CheckoutFeatureServiceImpl<
+    Db,
+    Auth,
+    Id,
+    Time,
+    Payment,
+    Coin,
+    CheckoutInvoice,
+    UserRepo,
+    CheckoutRepo,
+> {
+    db: Db,
+    auth: Auth,
+    id: Id,
+    time: Time,
+    payment: Payment,
+    coin: Coin,
+    checkout_invoice: CheckoutInvoice,
+    user_repo: UserRepo,
+    checkout_repo: CheckoutRepo,
+    config: CheckoutFeatureConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckoutFeatureConfig {
+    pub packages: Arc<[CoinPackage]>,
+    /// The url to redirect users to after a successful payment. `{order_id}`
+    /// is replaced by the id of the order.
+    pub success_url: Arc<str>,
+    /// The url to redirect users to if the payment has been cancelled.
+    /// `{order_id}` is replaced by the id of the order.
+    pub cancel_url: Arc<str>,
+}
+
+impl<Db, Auth, Id, Time, Payment, Coin, CheckoutInvoice, UserRepo, CheckoutRepo>
+    CheckoutFeatureService
+    for CheckoutFeatureServiceImpl<
+        Db,
+        Auth,
+        Id,
+        Time,
+        Payment,
+        Coin,
+        CheckoutInvoice,
+        UserRepo,
+        CheckoutRepo,
+    >
+where
+    Db: Database,
+    Auth: AuthService<Db::Transaction>,
+    Id: IdService,
+    Time: TimeService,
+    Payment: PaymentApiService,
+    Coin: CoinService<Db::Transaction>,
+    CheckoutInvoice: CheckoutInvoiceService<Db::Transaction>,
+    UserRepo: UserRepository<Db::Transaction>,
+    CheckoutRepo: CheckoutRepository<Db::Transaction>,
+{
+    fn list_packages(&self) -> Vec<CoinPackage> {
+        self.config.packages.to_vec()
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn create_order(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        package: CoinPackageId,
+    ) -> Result<CheckoutResult, CheckoutCreateOrderError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let package = self
+            .config
+            .packages
+            .iter()
+            .find(|p| p.id == package)
+            .ok_or(CheckoutCreateOrderError::PackageNotFound)?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let user_composite = self
+            .user_repo
+            .get_composite(&mut txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or(CheckoutCreateOrderError::NotFound)?;
+
+        if !user_composite.can_buy_coins() {
+            return Err(CheckoutCreateOrderError::CannotBuyCoins);
+        }
+
+        let order_id: CoinOrderId = self.id.generate();
+        let checkout = self
+            .payment
+            .create_checkout(PaymentCheckoutRequest {
+                reference: (*order_id).to_string(),
+                amount: package.price,
+                description: format!("{} Coins", *package.coins),
+                success_url: self.make_redirect_url(&self.config.success_url, order_id)?,
+                cancel_url: self.make_redirect_url(&self.config.cancel_url, order_id)?,
+            })
+            .await
+            .context("Failed to create checkout at payment provider")?;
+
+        let now = self.time.now();
+        let order = CoinOrder {
+            id: order_id,
+            user_id,
+            package: package.id.clone(),
+            coins: package.coins,
+            price: package.price,
+            status: CoinOrderStatus::Pending,
+            payment_id: checkout.payment_id,
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.checkout_repo
+            .create_order(&mut txn, &order)
+            .await
+            .context("Failed to create coin order in database")?;
+
+        txn.commit().await?;
+
+        Ok(CheckoutResult {
+            order,
+            redirect_url: checkout.redirect_url,
+        })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn list_orders(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        pagination: PaginationSlice,
+    ) -> Result<CheckoutListOrdersResult, CheckoutListOrdersError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .user_repo
+            .exists(&mut txn, user_id)
+            .await
+            .context("Failed to check user existence")?
+        {
+            return Err(CheckoutListOrdersError::NotFound);
+        }
+
+        let total = self
+            .checkout_repo
+            .count_orders(&mut txn, user_id)
+            .await
+            .context("Failed to count coin orders in database")?;
+
+        let orders = self
+            .checkout_repo
+            .list_orders(&mut txn, user_id, pagination)
+            .await
+            .context("Failed to list coin orders from database")?;
+
+        Ok(CheckoutListOrdersResult { total, orders })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_order(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        order_id: CoinOrderId,
+    ) -> Result<CoinOrder, CheckoutGetOrderError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.checkout_repo
+            .get_order(&mut txn, order_id)
+            .await
+            .context("Failed to get coin order from database")?
+            .filter(|order| order.user_id == user_id)
+            .ok_or(CheckoutGetOrderError::NotFound)
+    }
+
+    #[trace_instrument(skip(self, payload))]
+    async fn handle_webhook(
+        &self,
+        signature: &str,
+        payload: &[u8],
+    ) -> Result<(), CheckoutHandleWebhookError> {
+        let event = self
+            .payment
+            .parse_webhook(signature, payload)
+            .context("Failed to parse webhook payload")?
+            .ok_or(CheckoutHandleWebhookError::InvalidSignature)?;
+
+        let status = match event.status {
+            PaymentStatus::Succeeded => CoinOrderStatus::Completed,
+            PaymentStatus::Failed => CoinOrderStatus::Failed,
+            PaymentStatus::Cancelled => CoinOrderStatus::Cancelled,
+        };
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let Some(order) = self
+            .checkout_repo
+            .get_order_by_payment_id(&mut txn, &event.payment_id)
+            .await
+            .context("Failed to get coin order from database")?
+        else {
+            warn!(payment_id = ?event.payment_id, "received webhook for unknown payment");
+            return Ok(());
+        };
+
+        // the payment provider may deliver the same event more than once
+        if !order.status.can_transition_to(status) {
+            trace!(?order.status, ?status, "ignore webhook for processed order");
+            return Ok(());
+        }
+
+        if !self
+            .checkout_repo
+            .update_order_status(&mut txn, order.id, order.status, status, self.time.now())
+            .await
+            .context("Failed to update coin order status in database")?
+        {
+            trace!("order has been processed concurrently");
+            return Ok(());
+        }
+
+        if status == CoinOrderStatus::Completed {
+            let description = format!("Bought coin package {}", *order.package)
+                .try_into()
+                .context("Failed to build coin transaction description")?;
+            self.coin
+                .purchase(&mut txn, order.user_id, order.coins, Some(description))
+                .await
+                .context("Failed to credit purchased coins")?;
+        }
+
+        txn.commit().await?;
+
+        if status == CoinOrderStatus::Completed {
+            // The invoice is issued separately, so that the coins are credited even if the invoice
+            // cannot be issued right now. Orders without invoice are picked up again by the
+            // `create-invoices` task.
+            let mut txn = self.db.begin_transaction().await?;
+            match self.checkout_invoice.create(&mut txn, &order).await {
+                Ok(_) => txn.commit().await?,
+                Err(err) => {
+                    error!(order_id = %*order.id, "Failed to issue invoice for coin order: {err:#}")
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Db, Auth, Id, Time, Payment, Coin, CheckoutInvoice, UserRepo, CheckoutRepo>
+    CheckoutFeatureServiceImpl<
+        Db,
+        Auth,
+        Id,
+        Time,
+        Payment,
+        Coin,
+        CheckoutInvoice,
+        UserRepo,
+        CheckoutRepo,
+    >
+{
+    fn make_redirect_url(&self, template: &str, order_id: CoinOrderId) -> anyhow::Result<Url> {
+        template
+            .replace("{order_id}", &(*order_id).to_string())
+            .parse()
+            .context("Failed to build checkout redirect url")
+    }
+}
@@ -0,0 +1,205 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_checkout_contracts::{
+    CheckoutCreateOrderError, CheckoutFeatureService, CheckoutResult,
+};
+use academy_demo::{
+    session::{BAR_1, FOO_1},
+    user::{BAR, FOO},
+    UUID1,
+};
+use academy_extern_contracts::payment::{
+    MockPaymentApiService, PaymentCheckout, PaymentCheckoutRequest,
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    checkout::{CoinOrder, CoinOrderStatus},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{
+    checkout::MockCheckoutRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CheckoutFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = CheckoutResult {
+        order: CoinOrder {
+            id: UUID1.into(),
+            user_id: FOO.user.id,
+            package: "large".try_into().unwrap(),
+            coins: 1000.try_into().unwrap(),
+            price: 900,
+            status: CoinOrderStatus::Pending,
+            payment_id: "cs_42".try_into().unwrap(),
+            created_at: FOO.user.created_at,
+            updated_at: FOO.user.created_at,
+        },
+        redirect_url: "https://payment.provider/checkout/cs_42".parse().unwrap(),
+    };
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(expected.order.id);
+
+    let time = MockTimeService::new().with_now(expected.order.created_at);
+
+    let payment = MockPaymentApiService::new().with_create_checkout(
+        PaymentCheckoutRequest {
+            reference: UUID1.to_string(),
+            amount: 900,
+            description: "1000 Coins".into(),
+            success_url: format!("https://bootstrap.academy/shop/orders/{UUID1}")
+                .parse()
+                .unwrap(),
+            cancel_url: format!("https://bootstrap.academy/shop?cancelled={UUID1}")
+                .parse()
+                .unwrap(),
+        },
+        PaymentCheckout {
+            payment_id: expected.order.payment_id.clone(),
+            redirect_url: expected.redirect_url.clone(),
+        },
+    );
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+    let checkout_repo = MockCheckoutRepository::new().with_create_order(expected.order.clone());
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        auth,
+        id,
+        time,
+        payment,
+        user_repo,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_order(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            "large".try_into().unwrap(),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = CheckoutFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_order(
+            &"token".into(),
+            FOO.user.id.into(),
+            "large".try_into().unwrap(),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CheckoutCreateOrderError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn package_not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = CheckoutFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_order(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            "medium".try_into().unwrap(),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CheckoutCreateOrderError::PackageNotFound));
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        auth,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_order(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            "small".try_into().unwrap(),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CheckoutCreateOrderError::NotFound));
+}
+
+#[tokio::test]
+async fn cannot_buy_coins() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, Some(BAR.clone()));
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        auth,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_order(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            "small".try_into().unwrap(),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CheckoutCreateOrderError::CannotBuyCoins));
+}
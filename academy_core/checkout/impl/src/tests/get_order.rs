@@ -0,0 +1,116 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_checkout_contracts::{CheckoutFeatureService, CheckoutGetOrderError};
+use academy_demo::{
+    checkout::FOO_ORDER_PENDING,
+    session::{BAR_1, FOO_1},
+    user::{BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{checkout::MockCheckoutRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CheckoutFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_get_order(FOO_ORDER_PENDING.id, Some(FOO_ORDER_PENDING.clone()));
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        auth,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_order(&"token".into(), UserIdOrSelf::Slf, FOO_ORDER_PENDING.id)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *FOO_ORDER_PENDING);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = CheckoutFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_order(&"token".into(), FOO.user.id.into(), FOO_ORDER_PENDING.id)
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CheckoutGetOrderError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let checkout_repo = MockCheckoutRepository::new().with_get_order(FOO_ORDER_PENDING.id, None);
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        auth,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_order(&"token".into(), UserIdOrSelf::Slf, FOO_ORDER_PENDING.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CheckoutGetOrderError::NotFound));
+}
+
+#[tokio::test]
+async fn order_of_other_user() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_get_order(FOO_ORDER_PENDING.id, Some(FOO_ORDER_PENDING.clone()));
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        auth,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_order(&"token".into(), UserIdOrSelf::Slf, FOO_ORDER_PENDING.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CheckoutGetOrderError::NotFound));
+}
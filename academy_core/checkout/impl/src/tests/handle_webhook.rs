@@ -0,0 +1,379 @@
+use academy_core_checkout_contracts::{
+    invoice::MockCheckoutInvoiceService, CheckoutFeatureService, CheckoutHandleWebhookError,
+};
+use academy_core_coin_contracts::{coin::MockCoinService, CoinTransactionResult};
+use academy_demo::{
+    checkout::{FOO_ORDER_CANCELLED, FOO_ORDER_PENDING},
+    coin::FOO_BALANCE,
+    invoice::FOO_INVOICE_PURCHASE,
+    UUID1,
+};
+use academy_extern_contracts::payment::{
+    MockPaymentApiService, PaymentStatus, PaymentWebhookEvent,
+};
+use academy_models::{
+    checkout::{CoinOrderStatus, PaymentId},
+    coin::{CoinBalance, CoinTransaction, CoinTransactionKind},
+    invoice::Invoice,
+};
+use academy_persistence_contracts::{checkout::MockCheckoutRepository, MockDatabase};
+use academy_shared_contracts::time::MockTimeService;
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CheckoutFeatureServiceImpl};
+
+const SIGNATURE: &str = "the signature";
+const PAYLOAD: &[u8] = b"the payload";
+
+#[tokio::test]
+async fn succeeded() {
+    // Arrange
+    let order = &*FOO_ORDER_PENDING;
+    let now = order.created_at + std::time::Duration::from_secs(60);
+    let description = Some("Bought coin package large".try_into().unwrap());
+
+    let payment = make_payment(order.payment_id.clone(), PaymentStatus::Succeeded);
+
+    let db = MockDatabase::build_many(&[true, true]);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_get_order_by_payment_id(order.payment_id.clone(), Some(order.clone()))
+        .with_update_order_status(
+            order.id,
+            CoinOrderStatus::Pending,
+            CoinOrderStatus::Completed,
+            now,
+            true,
+        );
+
+    let coin = MockCoinService::new().with_purchase(
+        order.user_id,
+        order.coins,
+        description.clone(),
+        CoinTransactionResult {
+            transaction: CoinTransaction {
+                id: UUID1.into(),
+                user_id: order.user_id,
+                kind: CoinTransactionKind::Purchase,
+                coins: order.coins,
+                description,
+                reference: None,
+                created_at: now,
+            },
+            balance: CoinBalance {
+                coins: FOO_BALANCE.coins + *order.coins,
+                ..FOO_BALANCE
+            },
+        },
+    );
+
+    let checkout_invoice = MockCheckoutInvoiceService::new().with_create(
+        order.clone(),
+        Invoice {
+            gross: order.price,
+            ..FOO_INVOICE_PURCHASE.clone()
+        },
+    );
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        time,
+        payment,
+        coin,
+        checkout_invoice,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.handle_webhook(SIGNATURE, PAYLOAD).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn succeeded_after_cancelled() {
+    // Arrange
+    let order = &*FOO_ORDER_CANCELLED;
+    let now = order.created_at + std::time::Duration::from_secs(60);
+    let description = Some("Bought coin package small".try_into().unwrap());
+
+    let payment = make_payment(order.payment_id.clone(), PaymentStatus::Succeeded);
+
+    let db = MockDatabase::build_many(&[true, true]);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_get_order_by_payment_id(order.payment_id.clone(), Some(order.clone()))
+        .with_update_order_status(
+            order.id,
+            CoinOrderStatus::Cancelled,
+            CoinOrderStatus::Completed,
+            now,
+            true,
+        );
+
+    let coin = MockCoinService::new().with_purchase(
+        order.user_id,
+        order.coins,
+        description.clone(),
+        CoinTransactionResult {
+            transaction: CoinTransaction {
+                id: UUID1.into(),
+                user_id: order.user_id,
+                kind: CoinTransactionKind::Purchase,
+                coins: order.coins,
+                description,
+                reference: None,
+                created_at: now,
+            },
+            balance: CoinBalance {
+                coins: FOO_BALANCE.coins + *order.coins,
+                ..FOO_BALANCE
+            },
+        },
+    );
+
+    let checkout_invoice = MockCheckoutInvoiceService::new().with_create(
+        order.clone(),
+        Invoice {
+            gross: order.price,
+            ..FOO_INVOICE_PURCHASE.clone()
+        },
+    );
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        time,
+        payment,
+        coin,
+        checkout_invoice,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.handle_webhook(SIGNATURE, PAYLOAD).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn invoice_failed() {
+    // Arrange
+    let order = &*FOO_ORDER_PENDING;
+    let now = order.created_at + std::time::Duration::from_secs(60);
+    let description = Some("Bought coin package large".try_into().unwrap());
+
+    let payment = make_payment(order.payment_id.clone(), PaymentStatus::Succeeded);
+
+    let db = MockDatabase::build_many(&[true, false]);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_get_order_by_payment_id(order.payment_id.clone(), Some(order.clone()))
+        .with_update_order_status(
+            order.id,
+            CoinOrderStatus::Pending,
+            CoinOrderStatus::Completed,
+            now,
+            true,
+        );
+
+    let coin = MockCoinService::new().with_purchase(
+        order.user_id,
+        order.coins,
+        description.clone(),
+        CoinTransactionResult {
+            transaction: CoinTransaction {
+                id: UUID1.into(),
+                user_id: order.user_id,
+                kind: CoinTransactionKind::Purchase,
+                coins: order.coins,
+                description,
+                reference: None,
+                created_at: now,
+            },
+            balance: CoinBalance {
+                coins: FOO_BALANCE.coins + *order.coins,
+                ..FOO_BALANCE
+            },
+        },
+    );
+
+    let checkout_invoice = MockCheckoutInvoiceService::new().with_create_error(order.clone());
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        time,
+        payment,
+        coin,
+        checkout_invoice,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.handle_webhook(SIGNATURE, PAYLOAD).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn failed() {
+    // Arrange
+    let order = &*FOO_ORDER_PENDING;
+    let now = order.created_at + std::time::Duration::from_secs(60);
+
+    let payment = make_payment(order.payment_id.clone(), PaymentStatus::Failed);
+
+    let db = MockDatabase::build(true);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_get_order_by_payment_id(order.payment_id.clone(), Some(order.clone()))
+        .with_update_order_status(
+            order.id,
+            CoinOrderStatus::Pending,
+            CoinOrderStatus::Failed,
+            now,
+            true,
+        );
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        time,
+        payment,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.handle_webhook(SIGNATURE, PAYLOAD).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn already_processed() {
+    // Arrange
+    let order = &*FOO_ORDER_CANCELLED;
+
+    let payment = make_payment(order.payment_id.clone(), PaymentStatus::Failed);
+
+    let db = MockDatabase::build(false);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_get_order_by_payment_id(order.payment_id.clone(), Some(order.clone()));
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        payment,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.handle_webhook(SIGNATURE, PAYLOAD).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn processed_concurrently() {
+    // Arrange
+    let order = &*FOO_ORDER_PENDING;
+    let now = order.created_at + std::time::Duration::from_secs(60);
+
+    let payment = make_payment(order.payment_id.clone(), PaymentStatus::Succeeded);
+
+    let db = MockDatabase::build(false);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_get_order_by_payment_id(order.payment_id.clone(), Some(order.clone()))
+        .with_update_order_status(
+            order.id,
+            CoinOrderStatus::Pending,
+            CoinOrderStatus::Completed,
+            now,
+            false,
+        );
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        time,
+        payment,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.handle_webhook(SIGNATURE, PAYLOAD).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn unknown_payment() {
+    // Arrange
+    let payment_id: PaymentId = "cs_unknown".try_into().unwrap();
+
+    let payment = make_payment(payment_id.clone(), PaymentStatus::Succeeded);
+
+    let db = MockDatabase::build(false);
+
+    let checkout_repo =
+        MockCheckoutRepository::new().with_get_order_by_payment_id(payment_id, None);
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        payment,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.handle_webhook(SIGNATURE, PAYLOAD).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn invalid_signature() {
+    // Arrange
+    let payment =
+        MockPaymentApiService::new().with_parse_webhook(SIGNATURE.into(), PAYLOAD.into(), None);
+
+    let sut = CheckoutFeatureServiceImpl {
+        payment,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.handle_webhook(SIGNATURE, PAYLOAD).await;
+
+    // Assert
+    assert_matches!(result, Err(CheckoutHandleWebhookError::InvalidSignature));
+}
+
+fn make_payment(payment_id: PaymentId, status: PaymentStatus) -> MockPaymentApiService {
+    MockPaymentApiService::new().with_parse_webhook(
+        SIGNATURE.into(),
+        PAYLOAD.into(),
+        Some(PaymentWebhookEvent { payment_id, status }),
+    )
+}
@@ -0,0 +1,109 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_checkout_contracts::{
+    CheckoutFeatureService, CheckoutListOrdersError, CheckoutListOrdersResult,
+};
+use academy_demo::{
+    checkout::{FOO_ORDER_CANCELLED, FOO_ORDER_PENDING},
+    session::{ADMIN_1, BAR_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    pagination::PaginationSlice,
+};
+use academy_persistence_contracts::{
+    checkout::MockCheckoutRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CheckoutFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let pagination = PaginationSlice {
+        limit: 10.try_into().unwrap(),
+        offset: 0,
+    };
+    let expected = CheckoutListOrdersResult {
+        total: 2,
+        orders: vec![FOO_ORDER_PENDING.clone(), FOO_ORDER_CANCELLED.clone()],
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let checkout_repo = MockCheckoutRepository::new()
+        .with_count_orders(FOO.user.id, expected.total)
+        .with_list_orders(FOO.user.id, pagination, expected.orders.clone());
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        auth,
+        user_repo,
+        checkout_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_orders(&"token".into(), FOO.user.id.into(), pagination)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = CheckoutFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_orders(&"token".into(), FOO.user.id.into(), Default::default())
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CheckoutListOrdersError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+
+    let sut = CheckoutFeatureServiceImpl {
+        db,
+        auth,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_orders(&"token".into(), FOO.user.id.into(), Default::default())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CheckoutListOrdersError::NotFound));
+}
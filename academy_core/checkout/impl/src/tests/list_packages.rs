@@ -0,0 +1,15 @@
+use academy_core_checkout_contracts::CheckoutFeatureService;
+
+use crate::{tests::Sut, CheckoutFeatureConfig};
+
+#[test]
+fn ok() {
+    // Arrange
+    let sut = Sut::default();
+
+    // Act
+    let result = sut.list_packages();
+
+    // Assert
+    assert_eq!(result, *CheckoutFeatureConfig::default().packages);
+}
@@ -0,0 +1,51 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_checkout_contracts::invoice::MockCheckoutInvoiceService;
+use academy_core_coin_contracts::coin::MockCoinService;
+use academy_extern_contracts::payment::MockPaymentApiService;
+use academy_models::checkout::CoinPackage;
+use academy_persistence_contracts::{
+    checkout::MockCheckoutRepository, user::MockUserRepository, MockDatabase, MockTransaction,
+};
+use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+
+use crate::{CheckoutFeatureConfig, CheckoutFeatureServiceImpl};
+
+mod create_order;
+mod get_order;
+mod handle_webhook;
+mod list_orders;
+mod list_packages;
+
+type Sut = CheckoutFeatureServiceImpl<
+    MockDatabase,
+    MockAuthService<MockTransaction>,
+    MockIdService,
+    MockTimeService,
+    MockPaymentApiService,
+    MockCoinService<MockTransaction>,
+    MockCheckoutInvoiceService<MockTransaction>,
+    MockUserRepository<MockTransaction>,
+    MockCheckoutRepository<MockTransaction>,
+>;
+
+impl Default for CheckoutFeatureConfig {
+    fn default() -> Self {
+        Self {
+            packages: [
+                CoinPackage {
+                    id: "small".try_into().unwrap(),
+                    coins: 100.try_into().unwrap(),
+                    price: 100,
+                },
+                CoinPackage {
+                    id: "large".try_into().unwrap(),
+                    coins: 1000.try_into().unwrap(),
+                    price: 900,
+                },
+            ]
+            .into(),
+            success_url: "https://bootstrap.academy/shop/orders/{order_id}".into(),
+            cancel_url: "https://bootstrap.academy/shop?cancelled={order_id}".into(),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::future::Future;
+
+use academy_models::{
+    coin::{
+        CoinAmount, CoinCreditKind, CoinTransaction, CoinTransactionDescription,
+        CoinTransactionReference,
+    },
+    user::{UserComposite, UserId},
+};
+use thiserror::Error;
+
+use crate::CoinTransactionResult;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait CoinService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Add coins to the wallet of a user.
+    ///
+    /// Earned coins are withheld if the user cannot receive coins because
+    /// their invoice info is incomplete. If the user already has a
+    /// transaction of the same kind with the given `reference`, this
+    /// transaction is returned instead.
+    fn credit(
+        &self,
+        txn: &mut Txn,
+        user_composite: &UserComposite,
+        kind: CoinCreditKind,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> impl Future<Output = anyhow::Result<CoinTransactionResult>> + Send;
+
+    /// Remove coins from the wallet of a user.
+    ///
+    /// If the user already has a debit transaction with the given
+    /// `reference`, this transaction is returned instead.
+    fn debit(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> impl Future<Output = Result<CoinTransactionResult, CoinDebitError>> + Send;
+
+    /// Add bought coins to the wallet of a user.
+    fn purchase(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+    ) -> impl Future<Output = anyhow::Result<CoinTransactionResult>> + Send;
+
+    /// Release all withheld coins of a user.
+    ///
+    /// Returns `None` if no coins have been withheld.
+    fn release_withheld(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<Option<CoinTransaction>>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum CoinDebitError {
+    #[error("The user does not have enough coins.")]
+    NotEnoughCoins,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockCoinService<Txn> {
+    pub fn with_credit(
+        mut self,
+        user_composite: UserComposite,
+        kind: CoinCreditKind,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+        result: CoinTransactionResult,
+    ) -> Self {
+        self.expect_credit()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_composite),
+                mockall::predicate::eq(kind),
+                mockall::predicate::eq(coins),
+                mockall::predicate::eq(description),
+                mockall::predicate::eq(reference),
+            )
+            .return_once(|_, _, _, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_debit(
+        mut self,
+        user_id: UserId,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+        result: Result<CoinTransactionResult, CoinDebitError>,
+    ) -> Self {
+        self.expect_debit()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(coins),
+                mockall::predicate::eq(description),
+                mockall::predicate::eq(reference),
+            )
+            .return_once(|_, _, _, _, _| Box::pin(std::future::ready(result)));
+        self
+    }
+
+    pub fn with_purchase(
+        mut self,
+        user_id: UserId,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        result: CoinTransactionResult,
+    ) -> Self {
+        self.expect_purchase()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(coins),
+                mockall::predicate::eq(description),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_release_withheld(
+        mut self,
+        user_id: UserId,
+        result: Option<CoinTransaction>,
+    ) -> Self {
+        self.expect_release_withheld()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
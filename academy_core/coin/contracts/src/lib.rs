@@ -0,0 +1,143 @@
+use std::future::Future;
+
+use academy_auth_contracts::internal::AuthInternalAuthenticateError;
+use academy_models::{
+    auth::{AccessToken, AuthError, InternalToken},
+    coin::{
+        CoinAmount, CoinBalance, CoinCreditKind, CoinTransaction, CoinTransactionDescription,
+        CoinTransactionReference,
+    },
+    pagination::PaginationSlice,
+    user::{UserId, UserIdOrSelf},
+};
+use thiserror::Error;
+
+pub mod coin;
+
+pub trait CoinFeatureService: Send + Sync + 'static {
+    /// Return the coin balance of a user.
+    ///
+    /// Can only be used by administrators, if not used on the authenticated
+    /// user.
+    fn get_balance(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+    ) -> impl Future<Output = Result<CoinBalance, CoinGetBalanceError>> + Send;
+
+    /// Return the most recent transactions of a user.
+    ///
+    /// Can only be used by administrators, if not used on the authenticated
+    /// user.
+    fn list_transactions(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        pagination: PaginationSlice,
+    ) -> impl Future<Output = Result<CoinListTransactionsResult, CoinListTransactionsError>> + Send;
+
+    /// Return the coin balance of a user.
+    fn internal_get_balance(
+        &self,
+        token: &InternalToken,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<CoinBalance, CoinInternalGetBalanceError>> + Send;
+
+    /// Add coins to the wallet of a user.
+    ///
+    /// Earned coins are withheld if the user cannot receive coins because
+    /// their invoice info is incomplete.
+    ///
+    /// If the user already has a transaction of the same kind with the given
+    /// `reference`, this transaction is returned together with the current
+    /// balance of the user and no coins are added.
+    fn internal_credit(
+        &self,
+        token: &InternalToken,
+        user_id: UserId,
+        kind: CoinCreditKind,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> impl Future<Output = Result<CoinTransactionResult, CoinInternalCreditError>> + Send;
+
+    /// Remove coins from the wallet of a user.
+    ///
+    /// If the user already has a debit transaction with the given
+    /// `reference`, this transaction is returned together with the current
+    /// balance of the user and no coins are removed.
+    fn internal_debit(
+        &self,
+        token: &InternalToken,
+        user_id: UserId,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> impl Future<Output = Result<CoinTransactionResult, CoinInternalDebitError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinListTransactionsResult {
+    pub total: u64,
+    pub transactions: Vec<CoinTransaction>,
+}
+
+/// A transaction which has been appended to the ledger together with the
+/// resulting balance of its user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinTransactionResult {
+    pub transaction: CoinTransaction,
+    pub balance: CoinBalance,
+}
+
+#[derive(Debug, Error)]
+pub enum CoinGetBalanceError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CoinListTransactionsError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CoinInternalGetBalanceError {
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Auth(#[from] AuthInternalAuthenticateError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CoinInternalCreditError {
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Auth(#[from] AuthInternalAuthenticateError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum CoinInternalDebitError {
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error("The user does not have enough coins.")]
+    NotEnoughCoins,
+    #[error(transparent)]
+    Auth(#[from] AuthInternalAuthenticateError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
@@ -0,0 +1,519 @@
+use academy_core_coin_contracts::{
+    coin::{CoinDebitError, CoinService},
+    CoinTransactionResult,
+};
+use academy_di::Build;
+use academy_models::{
+    coin::{
+        CoinAmount, CoinCreditKind, CoinTransaction, CoinTransactionDescription,
+        CoinTransactionKind, CoinTransactionReference,
+    },
+    user::{UserComposite, UserId},
+};
+use academy_persistence_contracts::coin::{CoinRepository, CoinTransactionCreateResult};
+use academy_shared_contracts::{id::IdService, time::TimeService};
+use academy_utils::trace_instrument;
+use anyhow::{anyhow, Context};
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct CoinServiceImpl<Id, Time, CoinRepo> {
+    id: Id,
+    time: Time,
+    coin_repo: CoinRepo,
+}
+
+impl<Txn, Id, Time, CoinRepo> CoinService<Txn> for CoinServiceImpl<Id, Time, CoinRepo>
+where
+    Txn: Send + Sync + 'static,
+    Id: IdService,
+    Time: TimeService,
+    CoinRepo: CoinRepository<Txn>,
+{
+    #[trace_instrument(skip(self, txn))]
+    async fn credit(
+        &self,
+        txn: &mut Txn,
+        user_composite: &UserComposite,
+        kind: CoinCreditKind,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> anyhow::Result<CoinTransactionResult> {
+        let kind = match kind {
+            CoinCreditKind::Earn if user_composite.can_receive_coins() => CoinTransactionKind::Earn,
+            CoinCreditKind::Earn => CoinTransactionKind::Withheld,
+            CoinCreditKind::Refund => CoinTransactionKind::Refund,
+        };
+
+        let transaction =
+            self.make_transaction(user_composite.user.id, kind, coins, description, reference);
+        match self.create_transaction(txn, transaction).await? {
+            Some(result) => Ok(result),
+            None => Err(anyhow!("Coin balance overflow")),
+        }
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn debit(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> Result<CoinTransactionResult, CoinDebitError> {
+        let transaction = self.make_transaction(
+            user_id,
+            CoinTransactionKind::Spend,
+            coins,
+            description,
+            reference,
+        );
+        self.create_transaction(txn, transaction)
+            .await?
+            .ok_or(CoinDebitError::NotEnoughCoins)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn purchase(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+    ) -> anyhow::Result<CoinTransactionResult> {
+        let transaction = self.make_transaction(
+            user_id,
+            CoinTransactionKind::Purchase,
+            coins,
+            description,
+            None,
+        );
+        self.create_transaction(txn, transaction)
+            .await?
+            .ok_or_else(|| anyhow!("Coin balance overflow"))
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn release_withheld(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> anyhow::Result<Option<CoinTransaction>> {
+        let balance = self
+            .coin_repo
+            .get_balance(txn, user_id)
+            .await
+            .context("Failed to get coin balance from database")?;
+
+        let Ok(coins) = CoinAmount::try_new(balance.withheld_coins) else {
+            return Ok(None);
+        };
+
+        let transaction =
+            self.make_transaction(user_id, CoinTransactionKind::Released, coins, None, None);
+        Ok(self
+            .create_transaction(txn, transaction)
+            .await?
+            .map(|result| result.transaction))
+    }
+}
+
+impl<Id, Time, CoinRepo> CoinServiceImpl<Id, Time, CoinRepo>
+where
+    Id: IdService,
+    Time: TimeService,
+{
+    /// Append a transaction to the ledger.
+    ///
+    /// If the user already has a transaction with the same reference, the
+    /// existing transaction is returned instead. Returns `None` if the
+    /// transaction would overdraw one of the accounts of the user.
+    async fn create_transaction<Txn>(
+        &self,
+        txn: &mut Txn,
+        transaction: CoinTransaction,
+    ) -> anyhow::Result<Option<CoinTransactionResult>>
+    where
+        Txn: Send + Sync + 'static,
+        CoinRepo: CoinRepository<Txn>,
+    {
+        let result = self
+            .coin_repo
+            .create_transaction(txn, &transaction)
+            .await
+            .context("Failed to create coin transaction in database")?;
+
+        Ok(match result {
+            CoinTransactionCreateResult::Created(balance) => Some(CoinTransactionResult {
+                transaction,
+                balance,
+            }),
+            CoinTransactionCreateResult::Overdrawn => None,
+            CoinTransactionCreateResult::Duplicate(transaction) => {
+                let balance = self
+                    .coin_repo
+                    .get_balance(txn, transaction.user_id)
+                    .await
+                    .context("Failed to get coin balance from database")?;
+                Some(CoinTransactionResult {
+                    transaction,
+                    balance,
+                })
+            }
+        })
+    }
+
+    fn make_transaction(
+        &self,
+        user_id: UserId,
+        kind: CoinTransactionKind,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> CoinTransaction {
+        CoinTransaction {
+            id: self.id.generate(),
+            user_id,
+            kind,
+            coins,
+            description,
+            reference,
+            created_at: self.time.now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_demo::{
+        coin::{BAR_BALANCE, FOO_BALANCE, FOO_EARN},
+        user::{BAR, FOO},
+        UUID1,
+    };
+    use academy_models::coin::CoinBalance;
+    use academy_persistence_contracts::coin::MockCoinRepository;
+    use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+    use academy_utils::assert_matches;
+
+    use super::*;
+
+    type Sut = CoinServiceImpl<MockIdService, MockTimeService, MockCoinRepository<()>>;
+
+    #[tokio::test]
+    async fn credit_earn() {
+        // Arrange
+        let expected = CoinTransactionResult {
+            transaction: CoinTransaction {
+                id: UUID1.into(),
+                user_id: FOO.user.id,
+                kind: CoinTransactionKind::Earn,
+                coins: 42.try_into().unwrap(),
+                description: Some("Solved challenge".try_into().unwrap()),
+                reference: Some("challenge:42".try_into().unwrap()),
+                created_at: FOO.user.created_at,
+            },
+            balance: CoinBalance {
+                coins: FOO_BALANCE.coins + 42,
+                ..FOO_BALANCE
+            },
+        };
+
+        let sut = make_sut(&expected.transaction, Some(expected.balance));
+
+        // Act
+        let result = sut
+            .credit(
+                &mut (),
+                &FOO,
+                CoinCreditKind::Earn,
+                expected.transaction.coins,
+                expected.transaction.description.clone(),
+                expected.transaction.reference.clone(),
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn credit_duplicate_reference() {
+        // Arrange
+        let expected = CoinTransactionResult {
+            transaction: FOO_EARN.clone(),
+            balance: FOO_BALANCE,
+        };
+
+        let transaction = CoinTransaction {
+            id: UUID1.into(),
+            description: None,
+            created_at: FOO.user.created_at,
+            ..FOO_EARN.clone()
+        };
+
+        let id = MockIdService::new().with_generate(transaction.id);
+        let time = MockTimeService::new().with_now(transaction.created_at);
+        let coin_repo = MockCoinRepository::new()
+            .with_create_transaction(
+                transaction.clone(),
+                CoinTransactionCreateResult::Duplicate(FOO_EARN.clone()),
+            )
+            .with_get_balance(FOO.user.id, FOO_BALANCE);
+
+        let sut = Sut {
+            id,
+            time,
+            coin_repo,
+        };
+
+        // Act
+        let result = sut
+            .credit(
+                &mut (),
+                &FOO,
+                CoinCreditKind::Earn,
+                transaction.coins,
+                None,
+                transaction.reference.clone(),
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn credit_earn_withheld() {
+        // Arrange
+        let expected = CoinTransactionResult {
+            transaction: CoinTransaction {
+                id: UUID1.into(),
+                user_id: BAR.user.id,
+                kind: CoinTransactionKind::Withheld,
+                coins: 42.try_into().unwrap(),
+                description: None,
+                reference: None,
+                created_at: FOO.user.created_at,
+            },
+            balance: CoinBalance {
+                withheld_coins: BAR_BALANCE.withheld_coins + 42,
+                ..BAR_BALANCE
+            },
+        };
+
+        let sut = make_sut(&expected.transaction, Some(expected.balance));
+
+        // Act
+        let result = sut
+            .credit(
+                &mut (),
+                &BAR,
+                CoinCreditKind::Earn,
+                expected.transaction.coins,
+                None,
+                None,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn credit_refund() {
+        // Arrange
+        let expected = CoinTransactionResult {
+            transaction: CoinTransaction {
+                id: UUID1.into(),
+                user_id: BAR.user.id,
+                kind: CoinTransactionKind::Refund,
+                coins: 42.try_into().unwrap(),
+                description: None,
+                reference: None,
+                created_at: FOO.user.created_at,
+            },
+            balance: CoinBalance {
+                coins: 42,
+                ..BAR_BALANCE
+            },
+        };
+
+        let sut = make_sut(&expected.transaction, Some(expected.balance));
+
+        // Act
+        let result = sut
+            .credit(
+                &mut (),
+                &BAR,
+                CoinCreditKind::Refund,
+                expected.transaction.coins,
+                None,
+                None,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn debit_ok() {
+        // Arrange
+        let expected = CoinTransactionResult {
+            transaction: CoinTransaction {
+                id: UUID1.into(),
+                user_id: FOO.user.id,
+                kind: CoinTransactionKind::Spend,
+                coins: 100.try_into().unwrap(),
+                description: Some("Bought course".try_into().unwrap()),
+                reference: Some("order:1337".try_into().unwrap()),
+                created_at: FOO.user.created_at,
+            },
+            balance: CoinBalance {
+                coins: FOO_BALANCE.coins - 100,
+                ..FOO_BALANCE
+            },
+        };
+
+        let sut = make_sut(&expected.transaction, Some(expected.balance));
+
+        // Act
+        let result = sut
+            .debit(
+                &mut (),
+                FOO.user.id,
+                expected.transaction.coins,
+                expected.transaction.description.clone(),
+                expected.transaction.reference.clone(),
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn debit_not_enough_coins() {
+        // Arrange
+        let transaction = CoinTransaction {
+            id: UUID1.into(),
+            user_id: FOO.user.id,
+            kind: CoinTransactionKind::Spend,
+            coins: (FOO_BALANCE.coins + 1).try_into().unwrap(),
+            description: None,
+            reference: None,
+            created_at: FOO.user.created_at,
+        };
+
+        let sut = make_sut(&transaction, None);
+
+        // Act
+        let result = sut
+            .debit(&mut (), FOO.user.id, transaction.coins, None, None)
+            .await;
+
+        // Assert
+        assert_matches!(result, Err(CoinDebitError::NotEnoughCoins));
+    }
+
+    #[tokio::test]
+    async fn purchase() {
+        // Arrange
+        let expected = CoinTransactionResult {
+            transaction: CoinTransaction {
+                id: UUID1.into(),
+                user_id: BAR.user.id,
+                kind: CoinTransactionKind::Purchase,
+                coins: 500.try_into().unwrap(),
+                description: Some("500 Coins".try_into().unwrap()),
+                reference: None,
+                created_at: FOO.user.created_at,
+            },
+            balance: CoinBalance {
+                coins: 500,
+                ..BAR_BALANCE
+            },
+        };
+
+        let sut = make_sut(&expected.transaction, Some(expected.balance));
+
+        // Act
+        let result = sut
+            .purchase(
+                &mut (),
+                BAR.user.id,
+                expected.transaction.coins,
+                expected.transaction.description.clone(),
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn release_withheld() {
+        // Arrange
+        let expected = CoinTransaction {
+            id: UUID1.into(),
+            user_id: BAR.user.id,
+            kind: CoinTransactionKind::Released,
+            coins: BAR_BALANCE.withheld_coins.try_into().unwrap(),
+            description: None,
+            reference: None,
+            created_at: FOO.user.created_at,
+        };
+
+        let mut sut = make_sut(
+            &expected,
+            Some(CoinBalance {
+                coins: BAR_BALANCE.withheld_coins,
+                withheld_coins: 0,
+            }),
+        );
+        sut.coin_repo = sut.coin_repo.with_get_balance(BAR.user.id, BAR_BALANCE);
+
+        // Act
+        let result = sut.release_withheld(&mut (), BAR.user.id).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), Some(expected));
+    }
+
+    #[tokio::test]
+    async fn release_withheld_nothing_withheld() {
+        // Arrange
+        let coin_repo = MockCoinRepository::new().with_get_balance(FOO.user.id, FOO_BALANCE);
+
+        let sut = Sut {
+            coin_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.release_withheld(&mut (), FOO.user.id).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), None);
+    }
+
+    fn make_sut(transaction: &CoinTransaction, result: Option<CoinBalance>) -> Sut {
+        let id = MockIdService::new().with_generate(transaction.id);
+        let time = MockTimeService::new().with_now(transaction.created_at);
+        let coin_repo = MockCoinRepository::new().with_create_transaction(
+            transaction.clone(),
+            result.map_or(
+                CoinTransactionCreateResult::Overdrawn,
+                CoinTransactionCreateResult::Created,
+            ),
+        );
+
+        Sut {
+            id,
+            time,
+            coin_repo,
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use academy_auth_contracts::{internal::AuthInternalService, AuthResultExt, AuthService};
+use academy_core_coin_contracts::{
+    coin::{CoinDebitError, CoinService},
+    CoinFeatureService, CoinGetBalanceError, CoinInternalCreditError, CoinInternalDebitError,
+    CoinInternalGetBalanceError, CoinListTransactionsError, CoinListTransactionsResult,
+    CoinTransactionResult,
+};
+use academy_di::Build;
+use academy_models::{
+    auth::{AccessToken, InternalToken},
+    coin::{
+        CoinAmount, CoinBalance, CoinCreditKind, CoinTransactionDescription,
+        CoinTransactionReference,
+    },
+    pagination::PaginationSlice,
+    user::{UserId, UserIdOrSelf},
+};
+use academy_persistence_contracts::{
+    coin::CoinRepository, user::UserRepository, Database, Transaction,
+};
+use academy_utils::trace_instrument;
+use anyhow::Context;
+
+pub mod coin;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Build, Default)]
+pub struct CoinFeatureServiceImpl<Db, Auth, AuthInternal, Coin, UserRepo, CoinRepo> {
+    db: Db,
+    auth: Auth,
+    auth_internal: AuthInternal,
+    coin: Coin,
+    user_repo: UserRepo,
+    coin_repo: CoinRepo,
+}
+
+impl<Db, Auth, AuthInternal, Coin, UserRepo, CoinRepo> CoinFeatureService
+    for CoinFeatureServiceImpl<Db, Auth, AuthInternal, Coin, UserRepo, CoinRepo>
+where
+    Db: Database,
+    Auth: AuthService<Db::Transaction>,
+    AuthInternal: AuthInternalService,
+    Coin: CoinService<Db::Transaction>,
+    UserRepo: UserRepository<Db::Transaction>,
+    CoinRepo: CoinRepository<Db::Transaction>,
+{
+    #[trace_instrument(skip(self))]
+    async fn get_balance(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+    ) -> Result<CoinBalance, CoinGetBalanceError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .user_repo
+            .exists(&mut txn, user_id)
+            .await
+            .context("Failed to check user existence")?
+        {
+            return Err(CoinGetBalanceError::NotFound);
+        }
+
+        self.coin_repo
+            .get_balance(&mut txn, user_id)
+            .await
+            .context("Failed to get coin balance from database")
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn list_transactions(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        pagination: PaginationSlice,
+    ) -> Result<CoinListTransactionsResult, CoinListTransactionsError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .user_repo
+            .exists(&mut txn, user_id)
+            .await
+            .context("Failed to check user existence")?
+        {
+            return Err(CoinListTransactionsError::NotFound);
+        }
+
+        let total = self
+            .coin_repo
+            .count_transactions(&mut txn, user_id)
+            .await
+            .context("Failed to count coin transactions in database")?;
+
+        let transactions = self
+            .coin_repo
+            .list_transactions(&mut txn, user_id, pagination)
+            .await
+            .context("Failed to list coin transactions from database")?;
+
+        Ok(CoinListTransactionsResult {
+            total,
+            transactions,
+        })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn internal_get_balance(
+        &self,
+        token: &InternalToken,
+        user_id: UserId,
+    ) -> Result<CoinBalance, CoinInternalGetBalanceError> {
+        self.auth_internal.authenticate(token, "auth")?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .user_repo
+            .exists(&mut txn, user_id)
+            .await
+            .context("Failed to check user existence")?
+        {
+            return Err(CoinInternalGetBalanceError::NotFound);
+        }
+
+        self.coin_repo
+            .get_balance(&mut txn, user_id)
+            .await
+            .context("Failed to get coin balance from database")
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn internal_credit(
+        &self,
+        token: &InternalToken,
+        user_id: UserId,
+        kind: CoinCreditKind,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> Result<CoinTransactionResult, CoinInternalCreditError> {
+        self.auth_internal.authenticate(token, "auth")?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let user_composite = self
+            .user_repo
+            .get_composite(&mut txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or(CoinInternalCreditError::NotFound)?;
+
+        let result = self
+            .coin
+            .credit(
+                &mut txn,
+                &user_composite,
+                kind,
+                coins,
+                description,
+                reference,
+            )
+            .await
+            .context("Failed to credit coins")?;
+
+        txn.commit().await?;
+
+        Ok(result)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn internal_debit(
+        &self,
+        token: &InternalToken,
+        user_id: UserId,
+        coins: CoinAmount,
+        description: Option<CoinTransactionDescription>,
+        reference: Option<CoinTransactionReference>,
+    ) -> Result<CoinTransactionResult, CoinInternalDebitError> {
+        self.auth_internal.authenticate(token, "auth")?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .user_repo
+            .exists(&mut txn, user_id)
+            .await
+            .context("Failed to check user existence")?
+        {
+            return Err(CoinInternalDebitError::NotFound);
+        }
+
+        let result = self
+            .coin
+            .debit(&mut txn, user_id, coins, description, reference)
+            .await
+            .map_err(|err| match err {
+                CoinDebitError::NotEnoughCoins => CoinInternalDebitError::NotEnoughCoins,
+                CoinDebitError::Other(err) => err.context("Failed to debit coins").into(),
+            })?;
+
+        txn.commit().await?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,138 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_coin_contracts::{CoinFeatureService, CoinGetBalanceError};
+use academy_demo::{
+    coin::FOO_BALANCE,
+    session::{ADMIN_1, BAR_1, FOO_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthenticateError, AuthorizeError},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{
+    coin::MockCoinRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CoinFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_self() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let coin_repo = MockCoinRepository::new().with_get_balance(FOO.user.id, FOO_BALANCE);
+
+    let sut = CoinFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        coin_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_balance(&"token".into(), UserIdOrSelf::Slf).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), FOO_BALANCE);
+}
+
+#[tokio::test]
+async fn ok_admin() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let coin_repo = MockCoinRepository::new().with_get_balance(FOO.user.id, FOO_BALANCE);
+
+    let sut = CoinFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        coin_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_balance(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), FOO_BALANCE);
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(None);
+
+    let sut = CoinFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_balance(&"token".into(), UserIdOrSelf::Slf).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CoinGetBalanceError::Auth(AuthError::Authenticate(
+            AuthenticateError::InvalidToken
+        )))
+    );
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = CoinFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_balance(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CoinGetBalanceError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+
+    let sut = CoinFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_balance(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_matches!(result, Err(CoinGetBalanceError::NotFound));
+}
@@ -0,0 +1,131 @@
+use academy_auth_contracts::internal::{AuthInternalAuthenticateError, MockAuthInternalService};
+use academy_core_coin_contracts::{
+    coin::MockCoinService, CoinFeatureService, CoinInternalCreditError, CoinTransactionResult,
+};
+use academy_demo::{coin::BAR_BALANCE, user::BAR, UUID1};
+use academy_models::coin::{CoinBalance, CoinCreditKind, CoinTransaction, CoinTransactionKind};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CoinFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = CoinTransactionResult {
+        transaction: CoinTransaction {
+            id: UUID1.into(),
+            user_id: BAR.user.id,
+            kind: CoinTransactionKind::Withheld,
+            coins: 42.try_into().unwrap(),
+            description: Some("Solved challenge".try_into().unwrap()),
+            reference: Some("challenge:42".try_into().unwrap()),
+            created_at: BAR.user.created_at,
+        },
+        balance: CoinBalance {
+            withheld_coins: BAR_BALANCE.withheld_coins + 42,
+            ..BAR_BALANCE
+        },
+    };
+
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, Some(BAR.clone()));
+
+    let coin = MockCoinService::new().with_credit(
+        BAR.clone(),
+        CoinCreditKind::Earn,
+        expected.transaction.coins,
+        expected.transaction.description.clone(),
+        expected.transaction.reference.clone(),
+        expected.clone(),
+    );
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        coin,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_credit(
+            &"internal token".into(),
+            BAR.user.id,
+            CoinCreditKind::Earn,
+            expected.transaction.coins,
+            expected.transaction.description.clone(),
+            expected.transaction.reference.clone(),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", false);
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_credit(
+            &"internal token".into(),
+            BAR.user.id,
+            CoinCreditKind::Earn,
+            42.try_into().unwrap(),
+            None,
+            None,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CoinInternalCreditError::Auth(
+            AuthInternalAuthenticateError::InvalidToken
+        ))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, None);
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_credit(
+            &"internal token".into(),
+            BAR.user.id,
+            CoinCreditKind::Refund,
+            42.try_into().unwrap(),
+            None,
+            None,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CoinInternalCreditError::NotFound));
+}
@@ -0,0 +1,164 @@
+use academy_auth_contracts::internal::{AuthInternalAuthenticateError, MockAuthInternalService};
+use academy_core_coin_contracts::{
+    coin::{CoinDebitError, MockCoinService},
+    CoinFeatureService, CoinInternalDebitError, CoinTransactionResult,
+};
+use academy_demo::{coin::FOO_BALANCE, user::FOO, UUID1};
+use academy_models::coin::{CoinBalance, CoinTransaction, CoinTransactionKind};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CoinFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = CoinTransactionResult {
+        transaction: CoinTransaction {
+            id: UUID1.into(),
+            user_id: FOO.user.id,
+            kind: CoinTransactionKind::Spend,
+            coins: 200.try_into().unwrap(),
+            description: Some("Bought course".try_into().unwrap()),
+            reference: Some("order:1337".try_into().unwrap()),
+            created_at: FOO.user.created_at,
+        },
+        balance: CoinBalance {
+            coins: FOO_BALANCE.coins - 200,
+            ..FOO_BALANCE
+        },
+    };
+
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let coin = MockCoinService::new().with_debit(
+        FOO.user.id,
+        expected.transaction.coins,
+        expected.transaction.description.clone(),
+        expected.transaction.reference.clone(),
+        Ok(expected.clone()),
+    );
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        coin,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_debit(
+            &"internal token".into(),
+            FOO.user.id,
+            expected.transaction.coins,
+            expected.transaction.description.clone(),
+            expected.transaction.reference.clone(),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", false);
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_debit(
+            &"internal token".into(),
+            FOO.user.id,
+            200.try_into().unwrap(),
+            None,
+            None,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CoinInternalDebitError::Auth(
+            AuthInternalAuthenticateError::InvalidToken
+        ))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_debit(
+            &"internal token".into(),
+            FOO.user.id,
+            200.try_into().unwrap(),
+            None,
+            None,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CoinInternalDebitError::NotFound));
+}
+
+#[tokio::test]
+async fn not_enough_coins() {
+    // Arrange
+    let coins = (FOO_BALANCE.coins + 1).try_into().unwrap();
+
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let coin = MockCoinService::new().with_debit(
+        FOO.user.id,
+        coins,
+        None,
+        None,
+        Err(CoinDebitError::NotEnoughCoins),
+    );
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        coin,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_debit(&"internal token".into(), FOO.user.id, coins, None, None)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CoinInternalDebitError::NotEnoughCoins));
+}
@@ -0,0 +1,86 @@
+use academy_auth_contracts::internal::{AuthInternalAuthenticateError, MockAuthInternalService};
+use academy_core_coin_contracts::{CoinFeatureService, CoinInternalGetBalanceError};
+use academy_demo::{coin::BAR_BALANCE, user::BAR};
+use academy_persistence_contracts::{
+    coin::MockCoinRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CoinFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(BAR.user.id, true);
+
+    let coin_repo = MockCoinRepository::new().with_get_balance(BAR.user.id, BAR_BALANCE);
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        coin_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_get_balance(&"internal token".into(), BAR.user.id)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), BAR_BALANCE);
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", false);
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_get_balance(&"internal token".into(), BAR.user.id)
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CoinInternalGetBalanceError::Auth(
+            AuthInternalAuthenticateError::InvalidToken
+        ))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(BAR.user.id, false);
+
+    let sut = CoinFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_get_balance(&"internal token".into(), BAR.user.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CoinInternalGetBalanceError::NotFound));
+}
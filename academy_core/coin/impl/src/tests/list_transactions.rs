@@ -0,0 +1,109 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_coin_contracts::{
+    CoinFeatureService, CoinListTransactionsError, CoinListTransactionsResult,
+};
+use academy_demo::{
+    coin::{FOO_EARN, FOO_SPEND},
+    session::{ADMIN_1, BAR_1, FOO_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    pagination::PaginationSlice,
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{
+    coin::MockCoinRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, CoinFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let pagination = PaginationSlice {
+        limit: 10.try_into().unwrap(),
+        offset: 0,
+    };
+    let expected = CoinListTransactionsResult {
+        total: 2,
+        transactions: vec![FOO_SPEND.clone(), FOO_EARN.clone()],
+    };
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let coin_repo = MockCoinRepository::new()
+        .with_count_transactions(FOO.user.id, expected.total)
+        .with_list_transactions(FOO.user.id, pagination, expected.transactions.clone());
+
+    let sut = CoinFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        coin_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_transactions(&"token".into(), UserIdOrSelf::Slf, pagination)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = CoinFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_transactions(&"token".into(), FOO.user.id.into(), Default::default())
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(CoinListTransactionsError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+
+    let sut = CoinFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_transactions(&"token".into(), FOO.user.id.into(), Default::default())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(CoinListTransactionsError::NotFound));
+}
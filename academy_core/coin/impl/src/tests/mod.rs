@@ -0,0 +1,22 @@
+use academy_auth_contracts::{internal::MockAuthInternalService, MockAuthService};
+use academy_core_coin_contracts::coin::MockCoinService;
+use academy_persistence_contracts::{
+    coin::MockCoinRepository, user::MockUserRepository, MockDatabase, MockTransaction,
+};
+
+use crate::CoinFeatureServiceImpl;
+
+mod get_balance;
+mod internal_credit;
+mod internal_debit;
+mod internal_get_balance;
+mod list_transactions;
+
+type Sut = CoinFeatureServiceImpl<
+    MockDatabase,
+    MockAuthService<MockTransaction>,
+    MockAuthInternalService,
+    MockCoinService<MockTransaction>,
+    MockUserRepository<MockTransaction>,
+    MockCoinRepository<MockTransaction>,
+>;
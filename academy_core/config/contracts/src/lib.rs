@@ -1,4 +1,28 @@
+use std::future::Future;
+
+use academy_models::{
+    captcha::{CaptchaChallenge, CaptchaConfig},
+    country::Country,
+};
+use thiserror::Error;
+
 pub trait ConfigFeatureService: Send + Sync + 'static {
-    /// Return the public reCAPTCHA sitekey if reCAPTCHA is enabled.
-    fn get_recaptcha_sitekey(&self) -> Option<&str>;
+    /// Return the public configuration of the captcha provider.
+    fn get_captcha_config(&self) -> CaptchaConfig;
+
+    /// Create a new proof-of-work captcha challenge.
+    fn create_captcha_challenge(
+        &self,
+    ) -> impl Future<Output = Result<CaptchaChallenge, ConfigCreateCaptchaChallengeError>> + Send;
+
+    /// Return all countries which can be used in invoice addresses.
+    fn list_countries(&self) -> &'static [Country];
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigCreateCaptchaChallengeError {
+    #[error("The proof-of-work captcha is not enabled.")]
+    NotAvailable,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
 }
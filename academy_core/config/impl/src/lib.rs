@@ -1,6 +1,10 @@
-use academy_core_config_contracts::ConfigFeatureService;
+use academy_core_config_contracts::{ConfigCreateCaptchaChallengeError, ConfigFeatureService};
 use academy_di::Build;
-use academy_shared_contracts::captcha::CaptchaService;
+use academy_models::{
+    captcha::{CaptchaChallenge, CaptchaConfig},
+    country::Country,
+};
+use academy_shared_contracts::captcha::{CaptchaCreateChallengeError, CaptchaService};
 use academy_utils::trace_instrument;
 
 #[derive(Debug, Clone, Build)]
@@ -13,42 +17,107 @@ where
     Captcha: CaptchaService,
 {
     #[trace_instrument(skip(self))]
-    fn get_recaptcha_sitekey(&self) -> Option<&str> {
-        self.captcha.get_recaptcha_sitekey()
+    fn get_captcha_config(&self) -> CaptchaConfig {
+        self.captcha.get_config()
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn create_captcha_challenge(
+        &self,
+    ) -> Result<CaptchaChallenge, ConfigCreateCaptchaChallengeError> {
+        self.captcha
+            .create_challenge()
+            .await
+            .map_err(|err| match err {
+                CaptchaCreateChallengeError::NotAvailable => {
+                    ConfigCreateCaptchaChallengeError::NotAvailable
+                }
+                CaptchaCreateChallengeError::Other(err) => {
+                    err.context("Failed to create captcha challenge").into()
+                }
+            })
+    }
+
+    #[trace_instrument(skip(self))]
+    fn list_countries(&self) -> &'static [Country] {
+        Country::ALL
     }
 }
 
 #[cfg(test)]
 mod tests {
     use academy_shared_contracts::captcha::MockCaptchaService;
+    use academy_utils::assert_matches;
 
     use super::*;
 
     #[test]
-    fn get_sitekey_enabled() {
+    fn get_captcha_config() {
         // Arrange
-        let captcha = MockCaptchaService::new().with_get_recaptcha_sitekey(Some("sitekey"));
+        let captcha = MockCaptchaService::new().with_get_config(CaptchaConfig::Recaptcha {
+            sitekey: "sitekey".into(),
+        });
 
         let sut = ConfigFeatureServiceImpl { captcha };
 
         // Act
-        let result = sut.get_recaptcha_sitekey();
+        let result = sut.get_captcha_config();
 
         // Assert
-        assert_eq!(result, Some("sitekey"));
+        assert_eq!(
+            result,
+            CaptchaConfig::Recaptcha {
+                sitekey: "sitekey".into()
+            }
+        );
     }
 
-    #[test]
-    fn get_sitekey_disabled() {
+    #[tokio::test]
+    async fn create_captcha_challenge_ok() {
         // Arrange
-        let captcha = MockCaptchaService::new().with_get_recaptcha_sitekey(None);
+        let challenge = CaptchaChallenge {
+            challenge: "challenge".into(),
+            difficulty: 18,
+        };
+
+        let captcha = MockCaptchaService::new().with_create_challenge(Ok(challenge.clone()));
 
         let sut = ConfigFeatureServiceImpl { captcha };
 
         // Act
-        let result = sut.get_recaptcha_sitekey();
+        let result = sut.create_captcha_challenge().await;
+
+        // Assert
+        assert_eq!(result.unwrap(), challenge);
+    }
+
+    #[tokio::test]
+    async fn create_captcha_challenge_not_available() {
+        // Arrange
+        let captcha = MockCaptchaService::new()
+            .with_create_challenge(Err(CaptchaCreateChallengeError::NotAvailable));
+
+        let sut = ConfigFeatureServiceImpl { captcha };
+
+        // Act
+        let result = sut.create_captcha_challenge().await;
+
+        // Assert
+        assert_matches!(result, Err(ConfigCreateCaptchaChallengeError::NotAvailable));
+    }
+
+    #[test]
+    fn list_countries() {
+        // Arrange
+        let sut = ConfigFeatureServiceImpl {
+            captcha: MockCaptchaService::new(),
+        };
+
+        // Act
+        let result = sut.list_countries();
 
         // Assert
-        assert_eq!(result, None);
+        assert_eq!(result.len(), 249);
+        assert!(result.contains(&Country::DE));
     }
 }
@@ -1,23 +1,176 @@
-use std::future::Future;
+use std::{future::Future, net::IpAddr};
 
-use academy_models::{contact::ContactMessage, RecaptchaResponse};
+use academy_models::{
+    auth::{AccessToken, AuthError},
+    contact::{
+        ContactInboxMessage, ContactMessage, ContactMessageContent, ContactMessageFilter,
+        ContactMessageId, ContactMessageReply, ContactMessageStatus,
+    },
+    pagination::PaginationSlice,
+    user::UserId,
+    RecaptchaResponse,
+};
 use thiserror::Error;
 
+pub mod spam;
+
 pub trait ContactFeatureService: Send + Sync + 'static {
     /// Send a message to the support team.
+    ///
+    /// The message is stored in the contact inbox and forwarded to the
+    /// support team via email. Messages which are classified as spam are
+    /// either rejected or quarantined, in which case they are stored but not
+    /// forwarded.
     fn send_message(
         &self,
         message: ContactMessage,
         recaptcha_response: Option<RecaptchaResponse>,
+        client_ip: IpAddr,
+        honeypot: bool,
     ) -> impl Future<Output = Result<(), ContactSendMessageError>> + Send;
+
+    /// Return the most recent messages in the contact inbox matching the
+    /// given filter.
+    ///
+    /// Requires admin privileges.
+    fn list_messages(
+        &self,
+        token: &AccessToken,
+        query: ContactListQuery,
+    ) -> impl Future<Output = Result<ContactListResult, ContactListMessagesError>> + Send;
+
+    /// Return the contact message with the given id.
+    ///
+    /// Requires admin privileges.
+    fn get_message(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+    ) -> impl Future<Output = Result<ContactInboxMessage, ContactGetMessageError>> + Send;
+
+    /// Assign a contact message to an admin or remove the current assignee.
+    ///
+    /// Requires admin privileges.
+    fn assign_message(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+        assignee: Option<UserId>,
+    ) -> impl Future<Output = Result<ContactInboxMessage, ContactAssignMessageError>> + Send;
+
+    /// Mark a contact message as open or resolved.
+    ///
+    /// Requires admin privileges.
+    fn update_message_status(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+        status: ContactMessageStatus,
+    ) -> impl Future<Output = Result<ContactInboxMessage, ContactUpdateMessageStatusError>> + Send;
+
+    /// Return all replies to a contact message, oldest first.
+    ///
+    /// Requires admin privileges.
+    fn list_replies(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+    ) -> impl Future<Output = Result<Vec<ContactMessageReply>, ContactListRepliesError>> + Send;
+
+    /// Send a reply to the author of a contact message.
+    ///
+    /// The reply email is queued in the email outbox in the same transaction
+    /// that stores the reply. It references the original message and all
+    /// previous replies, so that email clients display the conversation as a
+    /// single thread.
+    ///
+    /// Requires admin privileges.
+    fn reply_to_message(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+        content: ContactMessageContent,
+    ) -> impl Future<Output = Result<ContactMessageReply, ContactReplyToMessageError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ContactListQuery {
+    pub filter: ContactMessageFilter,
+    pub pagination: PaginationSlice,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactListResult {
+    pub total: u64,
+    pub messages: Vec<ContactInboxMessage>,
 }
 
 #[derive(Debug, Error)]
 pub enum ContactSendMessageError {
     #[error("Invalid recaptcha response")]
     Recaptcha,
-    #[error("Failed to send message.")]
-    Send,
+    #[error("The message has been classified as spam.")]
+    Spam,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum ContactListMessagesError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum ContactGetMessageError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The message does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum ContactAssignMessageError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The message does not exist.")]
+    NotFound,
+    #[error("The assignee does not exist or is not an admin.")]
+    InvalidAssignee,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum ContactUpdateMessageStatusError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The message does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum ContactListRepliesError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The message does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum ContactReplyToMessageError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The message does not exist.")]
+    NotFound,
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
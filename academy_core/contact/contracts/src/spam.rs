@@ -0,0 +1,54 @@
+use std::{future::Future, net::IpAddr};
+
+use academy_models::contact::ContactMessage;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait ContactSpamService: Send + Sync + 'static {
+    /// Compute the spam score of the given message and decide whether it
+    /// should be accepted, quarantined or rejected.
+    ///
+    /// The score is increased for every link, blocklisted keyword and link to
+    /// or author email address of a blocklisted domain, for every previous
+    /// message which has recently been submitted from the same ip address or
+    /// email address and if the honeypot field has been filled. The
+    /// submission is recorded so that it can be taken into account for
+    /// subsequent messages.
+    fn check(
+        &self,
+        message: &ContactMessage,
+        client_ip: IpAddr,
+        honeypot: bool,
+    ) -> impl Future<Output = anyhow::Result<ContactSpamVerdict>> + Send;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactSpamVerdict {
+    /// The message is forwarded to the support team.
+    Accept,
+    /// The message is stored in the contact inbox but not forwarded to the
+    /// support team.
+    Quarantine,
+    /// The message is rejected.
+    Reject,
+}
+
+#[cfg(feature = "mock")]
+impl MockContactSpamService {
+    pub fn with_check(
+        mut self,
+        message: ContactMessage,
+        client_ip: IpAddr,
+        honeypot: bool,
+        result: ContactSpamVerdict,
+    ) -> Self {
+        self.expect_check()
+            .once()
+            .with(
+                mockall::predicate::eq(message),
+                mockall::predicate::eq(client_ip),
+                mockall::predicate::eq(honeypot),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
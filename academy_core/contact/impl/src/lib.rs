@@ -1,39 +1,119 @@
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc, time::Duration};
 
-use academy_core_contact_contracts::{ContactFeatureService, ContactSendMessageError};
+use academy_auth_contracts::{AuthResultExt, AuthService};
+use academy_core_contact_contracts::{
+    spam::{ContactSpamService, ContactSpamVerdict},
+    ContactAssignMessageError, ContactFeatureService, ContactGetMessageError,
+    ContactListMessagesError, ContactListQuery, ContactListRepliesError, ContactListResult,
+    ContactReplyToMessageError, ContactSendMessageError, ContactUpdateMessageStatusError,
+};
 use academy_di::Build;
-use academy_email_contracts::{ContentType, Email, EmailService};
+use academy_email_contracts::{
+    outbox::EmailOutboxService, Email, EmailBody, EmailHeader, EmailService,
+};
 use academy_models::{
-    contact::ContactMessage, email_address::EmailAddressWithName, RecaptchaResponse,
+    auth::AccessToken,
+    contact::{
+        ContactInboxMessage, ContactMessage, ContactMessageContent, ContactMessageId,
+        ContactMessageReply, ContactMessageReplyId, ContactMessageStatus,
+    },
+    email_address::EmailAddressWithName,
+    user::UserId,
+    RecaptchaResponse,
+};
+use academy_persistence_contracts::{
+    contact::ContactRepository, user::UserRepository, Database, Transaction,
+};
+use academy_shared_contracts::{
+    captcha::{CaptchaCheckError, CaptchaService},
+    id::IdService,
+    time::TimeService,
 };
-use academy_shared_contracts::captcha::{CaptchaCheckError, CaptchaService};
 use academy_utils::trace_instrument;
 use anyhow::Context;
 use tracing::{error, trace};
+use uuid::Uuid;
+
+pub mod spam;
+
+#[cfg(test)]
+mod tests;
 
 #[derive(Debug, Clone, Build)]
 #[cfg_attr(test, derive(Default))]
-pub struct ContactFeatureServiceImpl<Captcha, Email> {
+pub struct ContactFeatureServiceImpl<
+    Db,
+    Auth,
+    Id,
+    Time,
+    Captcha,
+    Email,
+    EmailOutbox,
+    Spam,
+    UserRepo,
+    ContactRepo,
+> {
+    db: Db,
+    auth: Auth,
+    id: Id,
+    time: Time,
     captcha: Captcha,
     email: Email,
+    email_outbox: EmailOutbox,
+    spam: Spam,
+    user_repo: UserRepo,
+    contact_repo: ContactRepo,
     config: ContactFeatureConfig,
 }
 
 #[derive(Debug, Clone)]
 pub struct ContactFeatureConfig {
     pub email: Arc<EmailAddressWithName>,
+    pub spam_reject_score: u64,
+    pub spam_quarantine_score: u64,
+    pub spam_link_score: u64,
+    pub spam_keywords: Arc<[String]>,
+    pub spam_keyword_score: u64,
+    pub spam_domains: Arc<[String]>,
+    pub spam_domain_score: u64,
+    pub spam_repeat_window: Duration,
+    pub spam_repeat_score: u64,
+    pub spam_honeypot_score: u64,
 }
 
-impl<Captcha, EmailS> ContactFeatureService for ContactFeatureServiceImpl<Captcha, EmailS>
+impl<Db, Auth, Id, Time, Captcha, EmailS, EmailOutbox, Spam, UserRepo, ContactRepo>
+    ContactFeatureService
+    for ContactFeatureServiceImpl<
+        Db,
+        Auth,
+        Id,
+        Time,
+        Captcha,
+        EmailS,
+        EmailOutbox,
+        Spam,
+        UserRepo,
+        ContactRepo,
+    >
 where
+    Db: Database,
+    Auth: AuthService<Db::Transaction>,
+    Id: IdService,
+    Time: TimeService,
     Captcha: CaptchaService,
     EmailS: EmailService,
+    EmailOutbox: EmailOutboxService<Db::Transaction>,
+    Spam: ContactSpamService,
+    UserRepo: UserRepository<Db::Transaction>,
+    ContactRepo: ContactRepository<Db::Transaction>,
 {
     #[trace_instrument(skip(self))]
     async fn send_message(
         &self,
         message: ContactMessage,
         recaptcha_response: Option<RecaptchaResponse>,
+        client_ip: IpAddr,
+        honeypot: bool,
     ) -> Result<(), ContactSendMessageError> {
         trace!("check captcha");
         self.captcha
@@ -44,143 +124,331 @@ where
                 CaptchaCheckError::Other(err) => err.context("Failed to check captcha").into(),
             })?;
 
+        trace!("check spam score");
+        let verdict = self
+            .spam
+            .check(&message, client_ip, honeypot)
+            .await
+            .context("Failed to check spam score")?;
+        let status = match verdict {
+            ContactSpamVerdict::Accept => ContactMessageStatus::Open,
+            ContactSpamVerdict::Quarantine => ContactMessageStatus::Quarantined,
+            ContactSpamVerdict::Reject => return Err(ContactSendMessageError::Spam),
+        };
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let id = self.id.generate::<ContactMessageId>();
+        let message = ContactInboxMessage {
+            id,
+            message,
+            status,
+            assignee: None,
+            email_message_id: self.email_message_id(*id),
+            created_at: self.time.now(),
+        };
+
+        self.contact_repo
+            .create_message(&mut txn, &message)
+            .await
+            .context("Failed to create contact message in database")?;
+
+        txn.commit().await?;
+
+        if status == ContactMessageStatus::Quarantined {
+            trace!("message quarantined");
+            return Ok(());
+        }
+
+        let ContactInboxMessage {
+            message,
+            email_message_id,
+            ..
+        } = message;
+
         let email = Email {
             recipient: (*self.config.email).clone(),
             subject: format!("[Contact Form] {}", *message.subject),
-            body: format!(
+            body: EmailBody::Text(format!(
                 "Message from {} ({}):\n\n{}",
                 *message.author.name,
                 message.author.email.as_str(),
                 *message.content
-            ),
-            content_type: ContentType::Text,
+            )),
             reply_to: Some(
                 message
                     .author
                     .email
                     .with_name(message.author.name.into_inner()),
             ),
+            headers: vec![EmailHeader {
+                name: "Message-ID".into(),
+                value: email_message_id,
+            }],
+            inline_images: Vec::new(),
         };
 
+        // The message has already been stored in the contact inbox, so a failure to forward it
+        // to the support team is not reported to the author.
         trace!("send email");
-        if !self
-            .email
-            .send(email)
-            .await
-            .context("Failed to send email")?
-        {
-            error!("Failed to send email");
-            return Err(ContactSendMessageError::Send);
+        match self.email.send(email).await {
+            Ok(true) => trace!("email sent"),
+            Ok(false) => error!("Failed to forward contact message"),
+            Err(err) => error!("Failed to forward contact message: {err:#}"),
         }
 
-        trace!("email sent");
         Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use academy_email_contracts::MockEmailService;
-    use academy_models::contact::ContactMessageAuthor;
-    use academy_shared_contracts::captcha::{CaptchaCheckError, MockCaptchaService};
-    use academy_utils::assert_matches;
+    #[trace_instrument(skip(self))]
+    async fn list_messages(
+        &self,
+        token: &AccessToken,
+        ContactListQuery { filter, pagination }: ContactListQuery,
+    ) -> Result<ContactListResult, ContactListMessagesError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
 
-    use super::*;
+        let mut txn = self.db.begin_transaction().await?;
 
-    type Sut = ContactFeatureServiceImpl<MockCaptchaService, MockEmailService>;
+        let total = self
+            .contact_repo
+            .count_messages(&mut txn, filter)
+            .await
+            .context("Failed to count contact messages in database")?;
 
-    #[tokio::test]
-    async fn ok() {
-        // Arrange
-        let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
+        let messages = self
+            .contact_repo
+            .list_messages(&mut txn, filter, pagination)
+            .await
+            .context("Failed to get contact messages from database")?;
 
-        let email = MockEmailService::new().with_send(make_email(), true);
+        Ok(ContactListResult { total, messages })
+    }
 
-        let sut = ContactFeatureServiceImpl {
-            captcha,
-            email,
-            ..Sut::default()
-        };
+    #[trace_instrument(skip(self))]
+    async fn get_message(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+    ) -> Result<ContactInboxMessage, ContactGetMessageError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
 
-        // Act
-        let result = sut
-            .send_message(make_contact_message(), Some("resp".try_into().unwrap()))
-            .await;
+        let mut txn = self.db.begin_transaction().await?;
 
-        // Assert
-        result.unwrap();
+        self.contact_repo
+            .get_message(&mut txn, message_id)
+            .await
+            .context("Failed to get contact message from database")?
+            .ok_or(ContactGetMessageError::NotFound)
     }
 
-    #[tokio::test]
-    async fn error_invalid_recaptcha_response() {
-        // Arrange
-        let captcha =
-            MockCaptchaService::new().with_check(Some("resp"), Err(CaptchaCheckError::Failed));
+    #[trace_instrument(skip(self))]
+    async fn assign_message(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+        assignee: Option<UserId>,
+    ) -> Result<ContactInboxMessage, ContactAssignMessageError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
 
-        let sut = ContactFeatureServiceImpl {
-            captcha,
-            ..Sut::default()
-        };
+        let mut txn = self.db.begin_transaction().await?;
+
+        let message = self
+            .contact_repo
+            .get_message(&mut txn, message_id)
+            .await
+            .context("Failed to get contact message from database")?
+            .ok_or(ContactAssignMessageError::NotFound)?;
+
+        if message.assignee == assignee {
+            return Ok(message);
+        }
+
+        if let Some(assignee) = assignee {
+            let user = self
+                .user_repo
+                .get_composite(&mut txn, assignee)
+                .await
+                .context("Failed to get user from database")?
+                .ok_or(ContactAssignMessageError::InvalidAssignee)?;
+            if !user.user.admin {
+                return Err(ContactAssignMessageError::InvalidAssignee);
+            }
+        }
+
+        self.contact_repo
+            .update_message_assignee(&mut txn, message_id, assignee)
+            .await
+            .context("Failed to update contact message in database")?;
 
-        // Act
-        let result = sut
-            .send_message(make_contact_message(), Some("resp".try_into().unwrap()))
-            .await;
+        txn.commit().await?;
 
-        // Assert
-        assert_matches!(result, Err(ContactSendMessageError::Recaptcha));
+        Ok(ContactInboxMessage {
+            assignee,
+            ..message
+        })
     }
 
-    #[tokio::test]
-    async fn error() {
-        // Arrange
-        let captcha = MockCaptchaService::new().with_check(None, Ok(()));
+    #[trace_instrument(skip(self))]
+    async fn update_message_status(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+        status: ContactMessageStatus,
+    ) -> Result<ContactInboxMessage, ContactUpdateMessageStatusError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
 
-        let email = MockEmailService::new().with_send(make_email(), false);
+        let mut txn = self.db.begin_transaction().await?;
 
-        let sut = ContactFeatureServiceImpl {
-            captcha,
-            email,
-            ..Sut::default()
-        };
+        let message = self
+            .contact_repo
+            .get_message(&mut txn, message_id)
+            .await
+            .context("Failed to get contact message from database")?
+            .ok_or(ContactUpdateMessageStatusError::NotFound)?;
 
-        // Act
-        let result = sut.send_message(make_contact_message(), None).await;
+        if message.status == status {
+            return Ok(message);
+        }
 
-        // Assert
-        assert_matches!(result, Err(ContactSendMessageError::Send));
+        self.contact_repo
+            .update_message_status(&mut txn, message_id, status)
+            .await
+            .context("Failed to update contact message in database")?;
+
+        txn.commit().await?;
+
+        Ok(ContactInboxMessage { status, ..message })
     }
 
-    impl Default for ContactFeatureConfig {
-        fn default() -> Self {
-            ContactFeatureConfig {
-                email: Arc::new("contact@example.com".parse().unwrap()),
-            }
-        }
+    #[trace_instrument(skip(self))]
+    async fn list_replies(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+    ) -> Result<Vec<ContactMessageReply>, ContactListRepliesError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.contact_repo
+            .get_message(&mut txn, message_id)
+            .await
+            .context("Failed to get contact message from database")?
+            .ok_or(ContactListRepliesError::NotFound)?;
+
+        self.contact_repo
+            .list_replies(&mut txn, message_id)
+            .await
+            .context("Failed to get contact message replies from database")
+            .map_err(Into::into)
     }
 
-    fn make_contact_message() -> ContactMessage {
-        ContactMessage {
-            author: ContactMessageAuthor {
-                name: "Max Mustermann".try_into().unwrap(),
-                email: "max.mustermann@example.de".parse().unwrap(),
-            },
-            subject: "Test".try_into().unwrap(),
-            content: "Hello World!".try_into().unwrap(),
-        }
+    #[trace_instrument(skip(self))]
+    async fn reply_to_message(
+        &self,
+        token: &AccessToken,
+        message_id: ContactMessageId,
+        content: ContactMessageContent,
+    ) -> Result<ContactMessageReply, ContactReplyToMessageError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let message = self
+            .contact_repo
+            .get_message(&mut txn, message_id)
+            .await
+            .context("Failed to get contact message from database")?
+            .ok_or(ContactReplyToMessageError::NotFound)?;
+
+        let replies = self
+            .contact_repo
+            .list_replies(&mut txn, message_id)
+            .await
+            .context("Failed to get contact message replies from database")?;
+
+        let id = self.id.generate::<ContactMessageReplyId>();
+        let reply = ContactMessageReply {
+            id,
+            message_id,
+            author: Some(auth.user_id),
+            content,
+            email_message_id: self.email_message_id(*id),
+            created_at: self.time.now(),
+        };
+
+        let references = std::iter::once(&message.email_message_id)
+            .chain(replies.iter().map(|reply| &reply.email_message_id))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+
+        let email = Email {
+            recipient: message
+                .message
+                .author
+                .email
+                .with_name(message.message.author.name.into_inner()),
+            subject: format!("Re: {}", *message.message.subject),
+            body: EmailBody::Text(reply.content.clone().into_inner()),
+            reply_to: Some((*self.config.email).clone()),
+            headers: vec![
+                EmailHeader {
+                    name: "Message-ID".into(),
+                    value: reply.email_message_id.clone(),
+                },
+                EmailHeader {
+                    name: "In-Reply-To".into(),
+                    value: references.last().copied().unwrap_or_default().into(),
+                },
+                EmailHeader {
+                    name: "References".into(),
+                    value: references.join(" "),
+                },
+            ],
+            inline_images: Vec::new(),
+        };
+
+        self.contact_repo
+            .create_reply(&mut txn, &reply)
+            .await
+            .context("Failed to create contact message reply in database")?;
+
+        trace!("enqueue reply");
+        self.email_outbox
+            .enqueue(&mut txn, email)
+            .await
+            .context("Failed to enqueue reply")?;
+
+        txn.commit().await?;
+
+        Ok(reply)
     }
+}
 
-    fn make_email() -> Email {
-        Email {
-            recipient: "contact@example.com".parse().unwrap(),
-            subject: "[Contact Form] Test".into(),
-            body: "Message from Max Mustermann (max.mustermann@example.de):\n\nHello World!".into(),
-            content_type: ContentType::Text,
-            reply_to: Some(
-                "Max Mustermann <max.mustermann@example.de>"
-                    .parse()
-                    .unwrap(),
-            ),
-        }
+impl<Db, Auth, Id, Time, Captcha, Email, EmailOutbox, Spam, UserRepo, ContactRepo>
+    ContactFeatureServiceImpl<
+        Db,
+        Auth,
+        Id,
+        Time,
+        Captcha,
+        Email,
+        EmailOutbox,
+        Spam,
+        UserRepo,
+        ContactRepo,
+    >
+{
+    /// Build the `Message-ID` of an email sent or forwarded by the contact
+    /// inbox, using the domain of the support team's email address.
+    fn email_message_id(&self, id: Uuid) -> String {
+        format!("<{id}@{}>", self.config.email.0.email.domain())
     }
 }
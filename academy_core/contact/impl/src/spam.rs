@@ -0,0 +1,278 @@
+use std::net::IpAddr;
+
+use academy_cache_contracts::CacheService;
+use academy_core_contact_contracts::spam::{ContactSpamService, ContactSpamVerdict};
+use academy_di::Build;
+use academy_models::contact::ContactMessage;
+use academy_shared_contracts::hash::HashService;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use tracing::trace;
+
+use crate::ContactFeatureConfig;
+
+#[derive(Debug, Clone, Build)]
+pub struct ContactSpamServiceImpl<Hash, Cache> {
+    hash: Hash,
+    cache: Cache,
+    config: ContactFeatureConfig,
+}
+
+impl<Hash, Cache> ContactSpamService for ContactSpamServiceImpl<Hash, Cache>
+where
+    Hash: HashService,
+    Cache: CacheService,
+{
+    #[trace_instrument(skip(self))]
+    async fn check(
+        &self,
+        message: &ContactMessage,
+        client_ip: IpAddr,
+        honeypot: bool,
+    ) -> anyhow::Result<ContactSpamVerdict> {
+        let previous_submissions = self
+            .record_submission(&format!("ip:{client_ip}"))
+            .await?
+            .max(
+                self.record_submission(&format!(
+                    "email:{}",
+                    message.author.email.as_str().to_lowercase()
+                ))
+                .await?,
+            );
+
+        let text = format!("{}\n{}", *message.subject, *message.content);
+        let links = find_links(&text).collect::<Vec<_>>();
+        let text = text.to_lowercase();
+
+        let keywords = self
+            .config
+            .spam_keywords
+            .iter()
+            .filter(|keyword| text.contains(keyword.as_str()))
+            .count() as u64;
+
+        let domains = links
+            .iter()
+            .filter_map(|link| link_domain(link))
+            .chain([message.author.email.0.domain().to_lowercase()])
+            .filter(|domain| {
+                self.config
+                    .spam_domains
+                    .iter()
+                    .any(|blocked| matches_domain(domain, blocked))
+            })
+            .count() as u64;
+
+        let score = links.len() as u64 * self.config.spam_link_score
+            + keywords * self.config.spam_keyword_score
+            + domains * self.config.spam_domain_score
+            + previous_submissions * self.config.spam_repeat_score
+            + if honeypot {
+                self.config.spam_honeypot_score
+            } else {
+                0
+            };
+        trace!(score, "computed spam score");
+
+        Ok(
+            if self.config.spam_reject_score != 0 && score >= self.config.spam_reject_score {
+                ContactSpamVerdict::Reject
+            } else if self.config.spam_quarantine_score != 0
+                && score >= self.config.spam_quarantine_score
+            {
+                ContactSpamVerdict::Quarantine
+            } else {
+                ContactSpamVerdict::Accept
+            },
+        )
+    }
+}
+
+impl<Hash, Cache> ContactSpamServiceImpl<Hash, Cache>
+where
+    Hash: HashService,
+    Cache: CacheService,
+{
+    /// Increment the number of recent submissions for the given key and return
+    /// the previous value.
+    async fn record_submission(&self, key: &str) -> anyhow::Result<u64> {
+        let cache_key = format!(
+            "contact_submissions:{}",
+            hex::encode(self.hash.sha256(&key.to_owned()).0)
+        );
+
+        self.cache
+            .increment(&cache_key, self.config.spam_repeat_window)
+            .await
+            .context("Failed to increment contact submission count in cache")
+            .map(|count| count.saturating_sub(1))
+    }
+}
+
+/// Return an iterator over all words in the given text which look like links.
+fn find_links(text: &str) -> impl Iterator<Item = &str> {
+    text.split_whitespace()
+        .map(|word| word.trim_start_matches(['(', '<', '[', '"', '\'']))
+        .filter(|word| {
+            let word = word.to_lowercase();
+            word.starts_with("http://") || word.starts_with("https://") || word.starts_with("www.")
+        })
+}
+
+/// Extract the lowercase domain from the given link.
+fn link_domain(link: &str) -> Option<String> {
+    let host = link.split_once("://").map_or(link, |(_, rest)| rest);
+    let host = host.split(['/', '?', '#']).next()?;
+    let host = host.rsplit('@').next()?;
+    let host = host.split(':').next()?;
+    let host = host.trim_end_matches(['.', ',', ';', ')', '>', ']', '"', '\'']);
+    (!host.is_empty()).then(|| host.to_lowercase())
+}
+
+/// Return whether `domain` is equal to `rule` or a subdomain of it.
+fn matches_domain(domain: &str, rule: &str) -> bool {
+    std::iter::successors(Some(domain), |d| {
+        d.split_once('.').map(|(_, parent)| parent)
+    })
+    .any(|d| d == rule)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{IpAddr, Ipv4Addr},
+        time::Duration,
+    };
+
+    use academy_cache_contracts::MockCacheService;
+    use academy_demo::{
+        contact::OPEN_MESSAGE, SHA256HASH1, SHA256HASH1_HEX, SHA256HASH2, SHA256HASH2_HEX,
+    };
+    use academy_shared_contracts::hash::MockHashService;
+
+    use super::*;
+
+    const CLIENT_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7));
+
+    type Sut = ContactSpamServiceImpl<MockHashService, MockCacheService>;
+
+    #[tokio::test]
+    async fn accept() {
+        // Arrange
+        let sut = make_sut(&OPEN_MESSAGE.message, 0, 0, ContactFeatureConfig::default());
+
+        // Act
+        let result = sut.check(&OPEN_MESSAGE.message, CLIENT_IP, false).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ContactSpamVerdict::Accept);
+    }
+
+    #[tokio::test]
+    async fn quarantine_links_and_keywords() {
+        // Arrange
+        let message = ContactMessage {
+            subject: "Online CASINO".try_into().unwrap(),
+            content: "Visit https://example.com/bonus or (www.example.org) now!"
+                .try_into()
+                .unwrap(),
+            ..OPEN_MESSAGE.message.clone()
+        };
+
+        let sut = make_sut(&message, 0, 0, ContactFeatureConfig::default());
+
+        // Act
+        let result = sut.check(&message, CLIENT_IP, false).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ContactSpamVerdict::Quarantine);
+    }
+
+    #[tokio::test]
+    async fn quarantine_repeated_submissions() {
+        // Arrange
+        let sut = make_sut(&OPEN_MESSAGE.message, 1, 3, ContactFeatureConfig::default());
+
+        // Act
+        let result = sut.check(&OPEN_MESSAGE.message, CLIENT_IP, false).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ContactSpamVerdict::Quarantine);
+    }
+
+    #[tokio::test]
+    async fn reject_blocklisted_domains() {
+        // Arrange
+        let mut message = OPEN_MESSAGE.message.clone();
+        message.author.email = "seo@Spam.example".parse().unwrap();
+        message.content = "See http://user@offers.spam.example:8080/?ref=1."
+            .try_into()
+            .unwrap();
+
+        let sut = make_sut(&message, 0, 0, ContactFeatureConfig::default());
+
+        // Act
+        let result = sut.check(&message, CLIENT_IP, false).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ContactSpamVerdict::Reject);
+    }
+
+    #[tokio::test]
+    async fn reject_honeypot() {
+        // Arrange
+        let sut = make_sut(&OPEN_MESSAGE.message, 0, 0, ContactFeatureConfig::default());
+
+        // Act
+        let result = sut.check(&OPEN_MESSAGE.message, CLIENT_IP, true).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ContactSpamVerdict::Reject);
+    }
+
+    #[tokio::test]
+    async fn accept_thresholds_disabled() {
+        // Arrange
+        let config = ContactFeatureConfig {
+            spam_reject_score: 0,
+            spam_quarantine_score: 0,
+            ..Default::default()
+        };
+
+        let sut = make_sut(&OPEN_MESSAGE.message, 5, 5, config);
+
+        // Act
+        let result = sut.check(&OPEN_MESSAGE.message, CLIENT_IP, true).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ContactSpamVerdict::Accept);
+    }
+
+    fn make_sut(
+        message: &ContactMessage,
+        previous_ip_submissions: u64,
+        previous_email_submissions: u64,
+        config: ContactFeatureConfig,
+    ) -> Sut {
+        let hash = MockHashService::new()
+            .with_sha256(format!("ip:{CLIENT_IP}"), *SHA256HASH1)
+            .with_sha256(
+                format!("email:{}", message.author.email.as_str().to_lowercase()),
+                *SHA256HASH2,
+            );
+
+        let ip_key = format!("contact_submissions:{SHA256HASH1_HEX}");
+        let email_key = format!("contact_submissions:{SHA256HASH2_HEX}");
+        let ttl = Duration::from_secs(3600);
+        let cache = MockCacheService::new()
+            .with_increment(ip_key, ttl, previous_ip_submissions + 1)
+            .with_increment(email_key, ttl, previous_email_submissions + 1);
+
+        ContactSpamServiceImpl {
+            hash,
+            cache,
+            config,
+        }
+    }
+}
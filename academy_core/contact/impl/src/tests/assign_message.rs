@@ -0,0 +1,197 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_contact_contracts::{ContactAssignMessageError, ContactFeatureService};
+use academy_demo::{
+    contact::{OPEN_MESSAGE, RESOLVED_MESSAGE},
+    session::ADMIN_1,
+    user::{ADMIN, ADMIN2, FOO},
+};
+use academy_models::contact::ContactInboxMessage;
+use academy_persistence_contracts::{
+    contact::MockContactRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, ContactFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = ContactInboxMessage {
+        assignee: Some(ADMIN2.user.id),
+        ..OPEN_MESSAGE.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo =
+        MockUserRepository::new().with_get_composite(ADMIN2.user.id, Some(ADMIN2.clone()));
+
+    let contact_repo = MockContactRepository::new()
+        .with_get_message(OPEN_MESSAGE.id, Some(OPEN_MESSAGE.clone()))
+        .with_update_message_assignee(OPEN_MESSAGE.id, Some(ADMIN2.user.id), true);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .assign_message(&"token".into(), OPEN_MESSAGE.id, Some(ADMIN2.user.id))
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_unassign() {
+    // Arrange
+    let expected = ContactInboxMessage {
+        assignee: None,
+        ..RESOLVED_MESSAGE.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let contact_repo = MockContactRepository::new()
+        .with_get_message(RESOLVED_MESSAGE.id, Some(RESOLVED_MESSAGE.clone()))
+        .with_update_message_assignee(RESOLVED_MESSAGE.id, None, true);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .assign_message(&"token".into(), RESOLVED_MESSAGE.id, None)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_unchanged() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo = MockContactRepository::new()
+        .with_get_message(RESOLVED_MESSAGE.id, Some(RESOLVED_MESSAGE.clone()));
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .assign_message(&"token".into(), RESOLVED_MESSAGE.id, Some(ADMIN.user.id))
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *RESOLVED_MESSAGE);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo = MockContactRepository::new().with_get_message(OPEN_MESSAGE.id, None);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .assign_message(&"token".into(), OPEN_MESSAGE.id, Some(ADMIN.user.id))
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(ContactAssignMessageError::NotFound));
+}
+
+#[tokio::test]
+async fn assignee_not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(ADMIN2.user.id, None);
+
+    let contact_repo =
+        MockContactRepository::new().with_get_message(OPEN_MESSAGE.id, Some(OPEN_MESSAGE.clone()));
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .assign_message(&"token".into(), OPEN_MESSAGE.id, Some(ADMIN2.user.id))
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(ContactAssignMessageError::InvalidAssignee));
+}
+
+#[tokio::test]
+async fn assignee_not_admin() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+    let contact_repo =
+        MockContactRepository::new().with_get_message(OPEN_MESSAGE.id, Some(OPEN_MESSAGE.clone()));
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .assign_message(&"token".into(), OPEN_MESSAGE.id, Some(FOO.user.id))
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(ContactAssignMessageError::InvalidAssignee));
+}
@@ -0,0 +1,125 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_contact_contracts::{
+    ContactFeatureService, ContactListMessagesError, ContactListQuery, ContactListResult,
+};
+use academy_demo::{
+    contact::{ALL_MESSAGES, OPEN_MESSAGE},
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    contact::{ContactMessageFilter, ContactMessageStatus},
+    pagination::PaginationSlice,
+};
+use academy_persistence_contracts::{contact::MockContactRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, ContactFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_all() {
+    // Arrange
+    let expected = ALL_MESSAGES.iter().copied().cloned().collect::<Vec<_>>();
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo = MockContactRepository::new()
+        .with_count_messages(Default::default(), expected.len() as _)
+        .with_list_messages(
+            Default::default(),
+            PaginationSlice::default(),
+            expected.clone(),
+        );
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_messages(&"token".into(), ContactListQuery::default())
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        ContactListResult {
+            total: expected.len() as _,
+            messages: expected
+        }
+    );
+}
+
+#[tokio::test]
+async fn ok_filter() {
+    // Arrange
+    let query = ContactListQuery {
+        filter: ContactMessageFilter {
+            status: Some(ContactMessageStatus::Open),
+            assignee: None,
+        },
+        pagination: PaginationSlice {
+            limit: 5.try_into().unwrap(),
+            offset: 0,
+        },
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo = MockContactRepository::new()
+        .with_count_messages(query.filter, 1)
+        .with_list_messages(query.filter, query.pagination, vec![OPEN_MESSAGE.clone()]);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_messages(&"token".into(), query).await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        ContactListResult {
+            total: 1,
+            messages: vec![OPEN_MESSAGE.clone()]
+        }
+    );
+}
+
+#[tokio::test]
+async fn not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_messages(&"token".into(), ContactListQuery::default())
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(ContactListMessagesError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
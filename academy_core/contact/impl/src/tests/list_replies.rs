@@ -0,0 +1,61 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_contact_contracts::{ContactFeatureService, ContactListRepliesError};
+use academy_demo::{
+    contact::{RESOLVED_MESSAGE, RESOLVED_MESSAGE_REPLY},
+    session::ADMIN_1,
+    user::ADMIN,
+};
+use academy_persistence_contracts::{contact::MockContactRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, ContactFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo = MockContactRepository::new()
+        .with_get_message(RESOLVED_MESSAGE.id, Some(RESOLVED_MESSAGE.clone()))
+        .with_list_replies(RESOLVED_MESSAGE.id, vec![RESOLVED_MESSAGE_REPLY.clone()]);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_replies(&"token".into(), RESOLVED_MESSAGE.id).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), vec![RESOLVED_MESSAGE_REPLY.clone()]);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo = MockContactRepository::new().with_get_message(RESOLVED_MESSAGE.id, None);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_replies(&"token".into(), RESOLVED_MESSAGE.id).await;
+
+    // Assert
+    assert_matches!(result, Err(ContactListRepliesError::NotFound));
+}
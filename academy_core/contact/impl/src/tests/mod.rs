@@ -0,0 +1,52 @@
+use std::{sync::Arc, time::Duration};
+
+use academy_auth_contracts::MockAuthService;
+use academy_core_contact_contracts::spam::MockContactSpamService;
+use academy_email_contracts::{outbox::MockEmailOutboxService, MockEmailService};
+use academy_persistence_contracts::{
+    contact::MockContactRepository, user::MockUserRepository, MockDatabase, MockTransaction,
+};
+use academy_shared_contracts::{
+    captcha::MockCaptchaService, id::MockIdService, time::MockTimeService,
+};
+
+use crate::{ContactFeatureConfig, ContactFeatureServiceImpl};
+
+mod assign_message;
+mod get_message;
+mod list_messages;
+mod list_replies;
+mod reply_to_message;
+mod send_message;
+mod update_message_status;
+
+type Sut = ContactFeatureServiceImpl<
+    MockDatabase,
+    MockAuthService<MockTransaction>,
+    MockIdService,
+    MockTimeService,
+    MockCaptchaService,
+    MockEmailService,
+    MockEmailOutboxService<MockTransaction>,
+    MockContactSpamService,
+    MockUserRepository<MockTransaction>,
+    MockContactRepository<MockTransaction>,
+>;
+
+impl Default for ContactFeatureConfig {
+    fn default() -> Self {
+        ContactFeatureConfig {
+            email: Arc::new("contact@example.com".parse().unwrap()),
+            spam_reject_score: 10,
+            spam_quarantine_score: 5,
+            spam_link_score: 1,
+            spam_keywords: ["casino".into()].into(),
+            spam_keyword_score: 3,
+            spam_domains: ["spam.example".into()].into(),
+            spam_domain_score: 5,
+            spam_repeat_window: Duration::from_secs(3600),
+            spam_repeat_score: 2,
+            spam_honeypot_score: 10,
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use academy_auth_contracts::MockAuthService;
+use academy_core_contact_contracts::{ContactFeatureService, ContactReplyToMessageError};
+use academy_demo::{
+    contact::{OPEN_MESSAGE, RESOLVED_MESSAGE, RESOLVED_MESSAGE_REPLY},
+    session::ADMIN_1,
+    user::{ADMIN, FOO},
+    UUID1, UUID2,
+};
+use academy_email_contracts::{outbox::MockEmailOutboxService, Email, EmailBody, EmailHeader};
+use academy_models::contact::{ContactMessageReply, ContactMessageReplyId};
+use academy_persistence_contracts::{contact::MockContactRepository, MockDatabase};
+use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, ContactFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = make_reply();
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(ContactMessageReplyId::from(UUID1));
+
+    let time = MockTimeService::new().with_now(expected.created_at);
+
+    let contact_repo = MockContactRepository::new()
+        .with_get_message(RESOLVED_MESSAGE.id, Some(RESOLVED_MESSAGE.clone()))
+        .with_list_replies(RESOLVED_MESSAGE.id, vec![RESOLVED_MESSAGE_REPLY.clone()])
+        .with_create_reply(expected.clone());
+
+    let email_outbox = MockEmailOutboxService::new().with_enqueue(make_email(), UUID2.into());
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        id,
+        time,
+        email_outbox,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .reply_to_message(
+            &"token".into(),
+            RESOLVED_MESSAGE.id,
+            expected.content.clone(),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo = MockContactRepository::new().with_get_message(OPEN_MESSAGE.id, None);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .reply_to_message(
+            &"token".into(),
+            OPEN_MESSAGE.id,
+            "Hello World!".try_into().unwrap(),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(ContactReplyToMessageError::NotFound));
+}
+
+fn make_reply() -> ContactMessageReply {
+    ContactMessageReply {
+        id: UUID1.into(),
+        message_id: RESOLVED_MESSAGE.id,
+        author: Some(ADMIN.user.id),
+        content: "Did this solve your problem?".try_into().unwrap(),
+        email_message_id: format!("<{UUID1}@example.com>"),
+        created_at: RESOLVED_MESSAGE_REPLY.created_at + Duration::from_secs(3600),
+    }
+}
+
+fn make_email() -> Email {
+    Email {
+        recipient: FOO
+            .user
+            .email
+            .clone()
+            .unwrap()
+            .with_name(FOO.profile.display_name.clone().into_inner()),
+        subject: "Re: Password reset".into(),
+        body: EmailBody::Text("Did this solve your problem?".into()),
+        reply_to: Some("contact@example.com".parse().unwrap()),
+        headers: vec![
+            EmailHeader {
+                name: "Message-ID".into(),
+                value: format!("<{UUID1}@example.com>"),
+            },
+            EmailHeader {
+                name: "In-Reply-To".into(),
+                value: RESOLVED_MESSAGE_REPLY.email_message_id.clone(),
+            },
+            EmailHeader {
+                name: "References".into(),
+                value: format!(
+                    "{} {}",
+                    RESOLVED_MESSAGE.email_message_id, RESOLVED_MESSAGE_REPLY.email_message_id
+                ),
+            },
+        ],
+        inline_images: Vec::new(),
+    }
+}
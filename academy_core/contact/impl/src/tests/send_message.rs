@@ -0,0 +1,233 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use academy_core_contact_contracts::{
+    spam::{ContactSpamVerdict, MockContactSpamService},
+    ContactFeatureService, ContactSendMessageError,
+};
+use academy_demo::{contact::OPEN_MESSAGE, UUID1};
+use academy_email_contracts::{Email, EmailBody, EmailHeader, MockEmailService};
+use academy_models::contact::{ContactInboxMessage, ContactMessageId, ContactMessageStatus};
+use academy_persistence_contracts::{contact::MockContactRepository, MockDatabase};
+use academy_shared_contracts::{
+    captcha::{CaptchaCheckError, MockCaptchaService},
+    id::MockIdService,
+    time::MockTimeService,
+};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, ContactFeatureServiceImpl};
+
+const CLIENT_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7));
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
+
+    let spam = MockContactSpamService::new().with_check(
+        OPEN_MESSAGE.message.clone(),
+        CLIENT_IP,
+        false,
+        ContactSpamVerdict::Accept,
+    );
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(ContactMessageId::from(UUID1));
+
+    let time = MockTimeService::new().with_now(OPEN_MESSAGE.created_at);
+
+    let contact_repo = MockContactRepository::new().with_create_message(make_message());
+
+    let email = MockEmailService::new().with_send(make_email(), true);
+
+    let sut = ContactFeatureServiceImpl {
+        db,
+        id,
+        time,
+        captcha,
+        email,
+        spam,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .send_message(
+            OPEN_MESSAGE.message.clone(),
+            Some("resp".try_into().unwrap()),
+            CLIENT_IP,
+            false,
+        )
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn ok_forward_failed() {
+    // Arrange
+    let captcha = MockCaptchaService::new().with_check(None, Ok(()));
+
+    let spam = MockContactSpamService::new().with_check(
+        OPEN_MESSAGE.message.clone(),
+        CLIENT_IP,
+        false,
+        ContactSpamVerdict::Accept,
+    );
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(ContactMessageId::from(UUID1));
+
+    let time = MockTimeService::new().with_now(OPEN_MESSAGE.created_at);
+
+    let contact_repo = MockContactRepository::new().with_create_message(make_message());
+
+    let email = MockEmailService::new().with_send(make_email(), false);
+
+    let sut = ContactFeatureServiceImpl {
+        db,
+        id,
+        time,
+        captcha,
+        email,
+        spam,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .send_message(OPEN_MESSAGE.message.clone(), None, CLIENT_IP, false)
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn error_invalid_recaptcha_response() {
+    // Arrange
+    let captcha =
+        MockCaptchaService::new().with_check(Some("resp"), Err(CaptchaCheckError::Failed));
+
+    let sut = ContactFeatureServiceImpl {
+        captcha,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .send_message(
+            OPEN_MESSAGE.message.clone(),
+            Some("resp".try_into().unwrap()),
+            CLIENT_IP,
+            false,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(ContactSendMessageError::Recaptcha));
+}
+
+#[tokio::test]
+async fn ok_quarantined() {
+    // Arrange
+    let captcha = MockCaptchaService::new().with_check(None, Ok(()));
+
+    let spam = MockContactSpamService::new().with_check(
+        OPEN_MESSAGE.message.clone(),
+        CLIENT_IP,
+        true,
+        ContactSpamVerdict::Quarantine,
+    );
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(ContactMessageId::from(UUID1));
+
+    let time = MockTimeService::new().with_now(OPEN_MESSAGE.created_at);
+
+    let contact_repo = MockContactRepository::new().with_create_message(ContactInboxMessage {
+        status: ContactMessageStatus::Quarantined,
+        ..make_message()
+    });
+
+    let sut = ContactFeatureServiceImpl {
+        db,
+        id,
+        time,
+        captcha,
+        spam,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .send_message(OPEN_MESSAGE.message.clone(), None, CLIENT_IP, true)
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn error_spam() {
+    // Arrange
+    let captcha = MockCaptchaService::new().with_check(None, Ok(()));
+
+    let spam = MockContactSpamService::new().with_check(
+        OPEN_MESSAGE.message.clone(),
+        CLIENT_IP,
+        false,
+        ContactSpamVerdict::Reject,
+    );
+
+    let sut = ContactFeatureServiceImpl {
+        captcha,
+        spam,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .send_message(OPEN_MESSAGE.message.clone(), None, CLIENT_IP, false)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(ContactSendMessageError::Spam));
+}
+
+fn make_message() -> ContactInboxMessage {
+    ContactInboxMessage {
+        id: UUID1.into(),
+        email_message_id: format!("<{UUID1}@example.com>"),
+        ..OPEN_MESSAGE.clone()
+    }
+}
+
+fn make_email() -> Email {
+    Email {
+        recipient: "contact@example.com".parse().unwrap(),
+        subject: "[Contact Form] Certificate".into(),
+        body: EmailBody::Text(
+            "Message from Max Mustermann (max.mustermann@example.de):\n\nWhere can I download my \
+             certificate?"
+                .into(),
+        ),
+        reply_to: Some(
+            "Max Mustermann <max.mustermann@example.de>"
+                .parse()
+                .unwrap(),
+        ),
+        headers: vec![EmailHeader {
+            name: "Message-ID".into(),
+            value: format!("<{UUID1}@example.com>"),
+        }],
+        inline_images: Vec::new(),
+    }
+}
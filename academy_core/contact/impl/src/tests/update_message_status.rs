@@ -0,0 +1,102 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_contact_contracts::{ContactFeatureService, ContactUpdateMessageStatusError};
+use academy_demo::{contact::OPEN_MESSAGE, session::ADMIN_1, user::ADMIN};
+use academy_models::contact::{ContactInboxMessage, ContactMessageStatus};
+use academy_persistence_contracts::{contact::MockContactRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, ContactFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = ContactInboxMessage {
+        status: ContactMessageStatus::Resolved,
+        ..OPEN_MESSAGE.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let contact_repo = MockContactRepository::new()
+        .with_get_message(OPEN_MESSAGE.id, Some(OPEN_MESSAGE.clone()))
+        .with_update_message_status(OPEN_MESSAGE.id, ContactMessageStatus::Resolved, true);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_message_status(
+            &"token".into(),
+            OPEN_MESSAGE.id,
+            ContactMessageStatus::Resolved,
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_unchanged() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo =
+        MockContactRepository::new().with_get_message(OPEN_MESSAGE.id, Some(OPEN_MESSAGE.clone()));
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_message_status(&"token".into(), OPEN_MESSAGE.id, ContactMessageStatus::Open)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *OPEN_MESSAGE);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let contact_repo = MockContactRepository::new().with_get_message(OPEN_MESSAGE.id, None);
+
+    let sut = ContactFeatureServiceImpl {
+        auth,
+        db,
+        contact_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_message_status(
+            &"token".into(),
+            OPEN_MESSAGE.id,
+            ContactMessageStatus::Resolved,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(ContactUpdateMessageStatusError::NotFound));
+}
@@ -0,0 +1,70 @@
+use std::future::Future;
+
+use academy_models::{
+    invoice::{Invoice, InvoiceDescription, InvoiceKind},
+    locale::Locale,
+    user::UserComposite,
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait InvoiceService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Issue a new invoice of the given gross amount (in euro cents) to a user
+    /// and store its PDF document.
+    ///
+    /// The invoice number is reserved in the given transaction, so it is
+    /// released again if the transaction is rolled back. Fails if the country
+    /// of the user is unknown.
+    fn create(
+        &self,
+        txn: &mut Txn,
+        user_composite: &UserComposite,
+        kind: InvoiceKind,
+        description: InvoiceDescription,
+        gross: u64,
+    ) -> impl Future<Output = anyhow::Result<Invoice>> + Send;
+
+    /// Return the PDF document of an invoice.
+    ///
+    /// The document is rendered in the given locale and stored, if it has not
+    /// been stored before.
+    fn get_pdf(
+        &self,
+        invoice: &Invoice,
+        locale: &Locale,
+    ) -> impl Future<Output = anyhow::Result<Vec<u8>>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockInvoiceService<Txn> {
+    pub fn with_create(
+        mut self,
+        user_composite: UserComposite,
+        kind: InvoiceKind,
+        description: InvoiceDescription,
+        gross: u64,
+        result: Invoice,
+    ) -> Self {
+        self.expect_create()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_composite),
+                mockall::predicate::eq(kind),
+                mockall::predicate::eq(description),
+                mockall::predicate::eq(gross),
+            )
+            .return_once(|_, _, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_pdf(mut self, invoice: Invoice, locale: Locale, result: Vec<u8>) -> Self {
+        self.expect_get_pdf()
+            .once()
+            .with(
+                mockall::predicate::eq(invoice),
+                mockall::predicate::eq(locale),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
@@ -0,0 +1,102 @@
+use std::future::Future;
+
+use academy_auth_contracts::internal::AuthInternalAuthenticateError;
+use academy_models::{
+    auth::{AccessToken, AuthError, InternalToken},
+    invoice::{Invoice, InvoiceDescription, InvoiceId},
+    pagination::PaginationSlice,
+    user::{UserId, UserIdOrSelf},
+};
+use thiserror::Error;
+
+pub mod invoice;
+
+pub trait InvoiceFeatureService: Send + Sync + 'static {
+    /// Return the most recent invoices of a user.
+    ///
+    /// Can only be used by administrators, if not used on the authenticated
+    /// user.
+    fn list_invoices(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        pagination: PaginationSlice,
+    ) -> impl Future<Output = Result<InvoiceListResult, InvoiceListError>> + Send;
+
+    /// Return an invoice of a user.
+    ///
+    /// Can only be used by administrators, if not used on the authenticated
+    /// user.
+    fn get_invoice(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        invoice_id: InvoiceId,
+    ) -> impl Future<Output = Result<Invoice, InvoiceGetError>> + Send;
+
+    /// Return an invoice of a user together with its PDF document.
+    ///
+    /// Can only be used by administrators, if not used on the authenticated
+    /// user.
+    fn download_invoice(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        invoice_id: InvoiceId,
+    ) -> impl Future<Output = Result<InvoiceDocument, InvoiceGetError>> + Send;
+
+    /// Issue a self-billed invoice for a payout of the given gross amount (in
+    /// euro cents) to a user.
+    fn internal_create_payout_invoice(
+        &self,
+        token: &InternalToken,
+        user_id: UserId,
+        description: InvoiceDescription,
+        amount: u64,
+    ) -> impl Future<Output = Result<Invoice, InvoiceInternalCreatePayoutError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvoiceListResult {
+    pub total: u64,
+    pub invoices: Vec<Invoice>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvoiceDocument {
+    pub invoice: Invoice,
+    /// The rendered PDF document
+    pub pdf: Vec<u8>,
+}
+
+#[derive(Debug, Error)]
+pub enum InvoiceListError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum InvoiceGetError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The invoice does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum InvoiceInternalCreatePayoutError {
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error("The invoice info of the user is incomplete.")]
+    InvoiceInfoIncomplete,
+    #[error(transparent)]
+    Auth(#[from] AuthInternalAuthenticateError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
@@ -0,0 +1,443 @@
+use std::sync::Arc;
+
+use academy_core_invoice_contracts::invoice::InvoiceService;
+use academy_di::Build;
+use academy_models::{
+    country::Country,
+    invoice::{
+        split_gross_amount, Invoice, InvoiceDescription, InvoiceId, InvoiceKind, InvoiceRecipient,
+    },
+    locale::Locale,
+    user::UserComposite,
+};
+use academy_persistence_contracts::invoice::InvoiceRepository;
+use academy_shared_contracts::{id::IdService, pdf::PdfService, time::TimeService};
+use academy_storage_contracts::StorageService;
+use academy_templates_contracts::{InvoiceTemplate, TemplateService};
+use academy_utils::trace_instrument;
+use anyhow::{anyhow, Context};
+use chrono::Datelike;
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct InvoiceServiceImpl<Id, Time, Template, Pdf, Storage, InvoiceRepo> {
+    id: Id,
+    time: Time,
+    template: Template,
+    pdf: Pdf,
+    storage: Storage,
+    invoice_repo: InvoiceRepo,
+    config: InvoiceServiceConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct InvoiceServiceConfig {
+    /// Name and address of the issuer of all invoices, one line each
+    pub issuer: Arc<[String]>,
+    pub issuer_vat_id: Option<Arc<str>>,
+    /// The country the issuer is established in
+    pub issuer_country: Country,
+    /// Prefix of all invoice numbers, which are formatted as
+    /// `{prefix}{year}-{number}`.
+    pub number_prefix: Arc<str>,
+}
+
+impl<Txn, Id, Time, Template, Pdf, Storage, InvoiceRepo> InvoiceService<Txn>
+    for InvoiceServiceImpl<Id, Time, Template, Pdf, Storage, InvoiceRepo>
+where
+    Txn: Send + Sync + 'static,
+    Id: IdService,
+    Time: TimeService,
+    Template: TemplateService,
+    Pdf: PdfService,
+    Storage: StorageService,
+    InvoiceRepo: InvoiceRepository<Txn>,
+{
+    #[trace_instrument(skip(self, txn))]
+    async fn create(
+        &self,
+        txn: &mut Txn,
+        user_composite: &UserComposite,
+        kind: InvoiceKind,
+        description: InvoiceDescription,
+        gross: u64,
+    ) -> anyhow::Result<Invoice> {
+        let recipient = InvoiceRecipient::from_invoice_info(&user_composite.invoice_info)
+            .ok_or_else(|| anyhow!("The country of the invoice recipient is unknown"))?;
+        let (vat_treatment, vat_rate) = recipient.vat_treatment(kind, self.config.issuer_country);
+        let (net, vat) = split_gross_amount(gross, vat_rate);
+
+        let now = self.time.now();
+        let year = now.year();
+        let sequence_number = self
+            .invoice_repo
+            .next_number(txn, year)
+            .await
+            .context("Failed to reserve invoice number in database")?;
+        let number = format!("{}{year}-{sequence_number:06}", self.config.number_prefix)
+            .try_into()
+            .context("Failed to build invoice number")?;
+
+        let invoice = Invoice {
+            id: self.id.generate(),
+            user_id: user_composite.user.id,
+            number,
+            kind,
+            recipient,
+            description,
+            net,
+            vat,
+            gross,
+            vat_rate,
+            vat_treatment,
+            created_at: now,
+        };
+
+        self.invoice_repo
+            .create(txn, &invoice)
+            .await
+            .context("Failed to create invoice in database")?;
+
+        let pdf = self.render_pdf(&invoice, &user_composite.user.locale)?;
+        self.storage
+            .put(&storage_key(invoice.id), pdf)
+            .await
+            .context("Failed to store invoice document")?;
+
+        Ok(invoice)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_pdf(&self, invoice: &Invoice, locale: &Locale) -> anyhow::Result<Vec<u8>> {
+        let key = storage_key(invoice.id);
+
+        if let Some(pdf) = self
+            .storage
+            .get(&key)
+            .await
+            .context("Failed to get invoice document from storage")?
+        {
+            return Ok(pdf);
+        }
+
+        let pdf = self.render_pdf(invoice, locale)?;
+        self.storage
+            .put(&key, pdf.clone())
+            .await
+            .context("Failed to store invoice document")?;
+
+        Ok(pdf)
+    }
+}
+
+impl<Id, Time, Template, Pdf, Storage, InvoiceRepo>
+    InvoiceServiceImpl<Id, Time, Template, Pdf, Storage, InvoiceRepo>
+where
+    Template: TemplateService,
+    Pdf: PdfService,
+{
+    fn render_pdf(&self, invoice: &Invoice, locale: &Locale) -> anyhow::Result<Vec<u8>> {
+        let template = make_template(&self.config, invoice, locale);
+        let text = self
+            .template
+            .render(&template, locale)
+            .context("Failed to render invoice template")?;
+        self.pdf
+            .render_text(&invoice.number, &text)
+            .context("Failed to render invoice document")
+    }
+}
+
+fn make_template(
+    config: &InvoiceServiceConfig,
+    invoice: &Invoice,
+    locale: &Locale,
+) -> InvoiceTemplate {
+    let german = locale.language() == "de";
+    let recipient = &invoice.recipient;
+
+    let name = [
+        recipient.first_name.as_deref().map(String::as_str),
+        recipient.last_name.as_deref().map(String::as_str),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ");
+    let city = [
+        recipient.zip_code.as_deref().map(String::as_str),
+        recipient.city.as_deref().map(String::as_str),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ");
+    let recipient_lines = [
+        Some(name),
+        recipient.street.as_deref().map(Into::into),
+        Some(city),
+        Some(recipient.country.name(locale).into()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|line| !line.is_empty())
+    .collect();
+
+    let date_format = if german { "%d.%m.%Y" } else { "%Y-%m-%d" };
+
+    InvoiceTemplate {
+        kind: invoice.kind,
+        number: invoice.number.to_string(),
+        date: invoice.created_at.format(date_format).to_string(),
+        issuer: config.issuer.to_vec(),
+        issuer_vat_id: config.issuer_vat_id.as_deref().map(Into::into),
+        recipient: recipient_lines,
+        recipient_vat_id: recipient.vat_id.as_deref().map(Into::into),
+        description: invoice.description.to_string(),
+        net: format_amount(invoice.net, german),
+        vat: format_amount(invoice.vat, german),
+        vat_rate: format_vat_rate(invoice.vat_rate, german),
+        gross: format_amount(invoice.gross, german),
+        vat_treatment: invoice.vat_treatment,
+    }
+}
+
+fn format_amount(cents: u64, german: bool) -> String {
+    let (euros, cents) = (cents / 100, cents % 100);
+    if german {
+        format!("{euros},{cents:02} €")
+    } else {
+        format!("€{euros}.{cents:02}")
+    }
+}
+
+fn format_vat_rate(vat_rate: u32, german: bool) -> String {
+    let (whole, fraction) = (vat_rate / 100, vat_rate % 100);
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{fraction:02}");
+    let separator = if german { ',' } else { '.' };
+    format!("{whole}{separator}{}", fraction.trim_end_matches('0'))
+}
+
+fn storage_key(invoice_id: InvoiceId) -> String {
+    format!("invoices/{}.pdf", invoice_id.hyphenated())
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_demo::{
+        invoice::FOO_INVOICE_PURCHASE,
+        user::{BAR, FOO},
+    };
+    use academy_models::invoice::InvoiceVatTreatment;
+    use academy_persistence_contracts::invoice::MockInvoiceRepository;
+    use academy_shared_contracts::{id::MockIdService, pdf::MockPdfService, time::MockTimeService};
+    use academy_storage_contracts::MockStorageService;
+    use academy_templates_contracts::MockTemplateService;
+
+    use super::*;
+
+    type Sut = InvoiceServiceImpl<
+        MockIdService,
+        MockTimeService,
+        MockTemplateService,
+        MockPdfService,
+        MockStorageService,
+        MockInvoiceRepository<()>,
+    >;
+
+    #[tokio::test]
+    async fn create() {
+        // Arrange
+        let config = InvoiceServiceConfig::default();
+        let locale = FOO.user.locale.clone();
+        let expected = FOO_INVOICE_PURCHASE.clone();
+        let template = make_template(&config, &expected, &locale);
+
+        let id = MockIdService::new().with_generate(expected.id);
+        let time = MockTimeService::new().with_now(expected.created_at);
+        let invoice_repo = MockInvoiceRepository::new()
+            .with_next_number(2024, 1)
+            .with_create(expected.clone());
+        let template_service =
+            MockTemplateService::new().with_render(template, locale, "the invoice".into());
+        let pdf = MockPdfService::new().with_render_text(
+            expected.number.to_string(),
+            "the invoice".into(),
+            b"the pdf".to_vec(),
+        );
+        let storage =
+            MockStorageService::new().with_put(storage_key(expected.id), b"the pdf".to_vec());
+
+        let sut = Sut {
+            id,
+            time,
+            template: template_service,
+            pdf,
+            storage,
+            invoice_repo,
+            config,
+        };
+
+        // Act
+        let result = sut
+            .create(
+                &mut (),
+                &FOO,
+                expected.kind,
+                expected.description.clone(),
+                expected.gross,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn create_unknown_country() {
+        // Arrange
+        let time = MockTimeService::new();
+
+        let sut = Sut {
+            time,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut
+            .create(
+                &mut (),
+                &BAR,
+                InvoiceKind::Purchase,
+                "500 Coins".try_into().unwrap(),
+                500,
+            )
+            .await;
+
+        // Assert
+        result.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn get_pdf_stored() {
+        // Arrange
+        let storage = MockStorageService::new().with_get(
+            storage_key(FOO_INVOICE_PURCHASE.id),
+            Some(b"the pdf".to_vec()),
+        );
+
+        let sut = Sut {
+            storage,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.get_pdf(&FOO_INVOICE_PURCHASE, &FOO.user.locale).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), b"the pdf");
+    }
+
+    #[tokio::test]
+    async fn get_pdf_not_stored() {
+        // Arrange
+        let config = InvoiceServiceConfig::default();
+        let invoice = &*FOO_INVOICE_PURCHASE;
+        let locale = "en".try_into().unwrap();
+        let template = make_template(&config, invoice, &locale);
+
+        let template_service =
+            MockTemplateService::new().with_render(template, locale.clone(), "the invoice".into());
+        let pdf = MockPdfService::new().with_render_text(
+            invoice.number.to_string(),
+            "the invoice".into(),
+            b"the pdf".to_vec(),
+        );
+        let storage = MockStorageService::new()
+            .with_get(storage_key(invoice.id), None)
+            .with_put(storage_key(invoice.id), b"the pdf".to_vec());
+
+        let sut = Sut {
+            template: template_service,
+            pdf,
+            storage,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.get_pdf(invoice, &locale).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), b"the pdf");
+    }
+
+    #[test]
+    fn template() {
+        let config = InvoiceServiceConfig::default();
+        let invoice = Invoice {
+            vat_rate: 2550,
+            vat_treatment: InvoiceVatTreatment::ReverseCharge,
+            ..FOO_INVOICE_PURCHASE.clone()
+        };
+
+        let template = make_template(&config, &invoice, &"de".try_into().unwrap());
+        assert_eq!(
+            template,
+            InvoiceTemplate {
+                kind: InvoiceKind::Purchase,
+                number: "BA-2024-000001".into(),
+                date: "17.03.2024".into(),
+                issuer: config.issuer.to_vec(),
+                issuer_vat_id: Some("DE999999999".into()),
+                recipient: vec![
+                    "x y".into(),
+                    "asdf".into(),
+                    "12345 xyz".into(),
+                    "Deutschland".into()
+                ],
+                recipient_vat_id: Some("DE0123456789".into()),
+                description: "1000 Coins".into(),
+                net: "7,98 €".into(),
+                vat: "1,52 €".into(),
+                vat_rate: "25,5".into(),
+                gross: "9,50 €".into(),
+                vat_treatment: InvoiceVatTreatment::ReverseCharge,
+            }
+        );
+
+        let template = make_template(&config, &invoice, &"en".try_into().unwrap());
+        assert_eq!(template.date, "2024-03-17");
+        assert_eq!(template.recipient[3], "Germany");
+        assert_eq!(template.gross, "€9.50");
+        assert_eq!(template.vat_rate, "25.5");
+    }
+
+    #[test]
+    fn vat_rate() {
+        for (vat_rate, german, expected) in [
+            (1900, true, "19"),
+            (2550, true, "25,5"),
+            (2550, false, "25.5"),
+            (705, false, "7.05"),
+            (0, false, "0"),
+        ] {
+            assert_eq!(format_vat_rate(vat_rate, german), expected);
+        }
+    }
+
+    impl Default for InvoiceServiceConfig {
+        fn default() -> Self {
+            Self {
+                issuer: ["Bootstrap Academy GmbH".into(), "Example Street 1".into()].into(),
+                issuer_vat_id: Some("DE999999999".into()),
+                issuer_country: Country::DE,
+                number_prefix: "BA-".into(),
+            }
+        }
+    }
+}
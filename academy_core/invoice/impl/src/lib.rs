@@ -0,0 +1,178 @@
+use academy_auth_contracts::{internal::AuthInternalService, AuthResultExt, AuthService};
+use academy_core_invoice_contracts::{
+    invoice::InvoiceService, InvoiceDocument, InvoiceFeatureService, InvoiceGetError,
+    InvoiceInternalCreatePayoutError, InvoiceListError, InvoiceListResult,
+};
+use academy_di::Build;
+use academy_models::{
+    auth::{AccessToken, InternalToken},
+    invoice::{Invoice, InvoiceDescription, InvoiceId, InvoiceKind},
+    pagination::PaginationSlice,
+    user::{UserId, UserIdOrSelf},
+};
+use academy_persistence_contracts::{
+    invoice::InvoiceRepository, user::UserRepository, Database, Transaction,
+};
+use academy_utils::trace_instrument;
+use anyhow::Context;
+
+pub mod invoice;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Build, Default)]
+pub struct InvoiceFeatureServiceImpl<Db, Auth, AuthInternal, Invoice, UserRepo, InvoiceRepo> {
+    db: Db,
+    auth: Auth,
+    auth_internal: AuthInternal,
+    invoice: Invoice,
+    user_repo: UserRepo,
+    invoice_repo: InvoiceRepo,
+}
+
+impl<Db, Auth, AuthInternal, InvoiceS, UserRepo, InvoiceRepo> InvoiceFeatureService
+    for InvoiceFeatureServiceImpl<Db, Auth, AuthInternal, InvoiceS, UserRepo, InvoiceRepo>
+where
+    Db: Database,
+    Auth: AuthService<Db::Transaction>,
+    AuthInternal: AuthInternalService,
+    InvoiceS: InvoiceService<Db::Transaction>,
+    UserRepo: UserRepository<Db::Transaction>,
+    InvoiceRepo: InvoiceRepository<Db::Transaction>,
+{
+    #[trace_instrument(skip(self))]
+    async fn list_invoices(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        pagination: PaginationSlice,
+    ) -> Result<InvoiceListResult, InvoiceListError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .user_repo
+            .exists(&mut txn, user_id)
+            .await
+            .context("Failed to check user existence")?
+        {
+            return Err(InvoiceListError::NotFound);
+        }
+
+        let total = self
+            .invoice_repo
+            .count(&mut txn, user_id)
+            .await
+            .context("Failed to count invoices in database")?;
+
+        let invoices = self
+            .invoice_repo
+            .list(&mut txn, user_id, pagination)
+            .await
+            .context("Failed to list invoices from database")?;
+
+        Ok(InvoiceListResult { total, invoices })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_invoice(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        invoice_id: InvoiceId,
+    ) -> Result<Invoice, InvoiceGetError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.invoice_repo
+            .get(&mut txn, invoice_id)
+            .await
+            .context("Failed to get invoice from database")?
+            .filter(|invoice| invoice.user_id == user_id)
+            .ok_or(InvoiceGetError::NotFound)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn download_invoice(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        invoice_id: InvoiceId,
+    ) -> Result<InvoiceDocument, InvoiceGetError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let invoice = self
+            .invoice_repo
+            .get(&mut txn, invoice_id)
+            .await
+            .context("Failed to get invoice from database")?
+            .filter(|invoice| invoice.user_id == user_id)
+            .ok_or(InvoiceGetError::NotFound)?;
+
+        let user = self
+            .user_repo
+            .get_composite(&mut txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or(InvoiceGetError::NotFound)?;
+
+        let pdf = self
+            .invoice
+            .get_pdf(&invoice, &user.user.locale)
+            .await
+            .context("Failed to get invoice document")?;
+
+        Ok(InvoiceDocument { invoice, pdf })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn internal_create_payout_invoice(
+        &self,
+        token: &InternalToken,
+        user_id: UserId,
+        description: InvoiceDescription,
+        amount: u64,
+    ) -> Result<Invoice, InvoiceInternalCreatePayoutError> {
+        self.auth_internal.authenticate(token, "auth")?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let user_composite = self
+            .user_repo
+            .get_composite(&mut txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or(InvoiceInternalCreatePayoutError::NotFound)?;
+
+        if !user_composite.can_receive_coins() {
+            return Err(InvoiceInternalCreatePayoutError::InvoiceInfoIncomplete);
+        }
+
+        let invoice = self
+            .invoice
+            .create(
+                &mut txn,
+                &user_composite,
+                InvoiceKind::Payout,
+                description,
+                amount,
+            )
+            .await
+            .context("Failed to create invoice")?;
+
+        txn.commit().await?;
+
+        Ok(invoice)
+    }
+}
@@ -0,0 +1,110 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_invoice_contracts::{
+    invoice::MockInvoiceService, InvoiceDocument, InvoiceFeatureService, InvoiceGetError,
+};
+use academy_demo::{
+    invoice::FOO_INVOICE_PURCHASE,
+    session::{ADMIN_1, BAR_1, FOO_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{
+    invoice::MockInvoiceRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, InvoiceFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = InvoiceDocument {
+        invoice: FOO_INVOICE_PURCHASE.clone(),
+        pdf: b"the pdf".to_vec(),
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invoice_repo = MockInvoiceRepository::new()
+        .with_get(FOO_INVOICE_PURCHASE.id, Some(FOO_INVOICE_PURCHASE.clone()));
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+    let invoice = MockInvoiceService::new().with_get_pdf(
+        FOO_INVOICE_PURCHASE.clone(),
+        FOO.user.locale.clone(),
+        expected.pdf.clone(),
+    );
+
+    let sut = InvoiceFeatureServiceImpl {
+        db,
+        auth,
+        invoice,
+        user_repo,
+        invoice_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .download_invoice(&"token".into(), FOO.user.id.into(), FOO_INVOICE_PURCHASE.id)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .download_invoice(&"token".into(), FOO.user.id.into(), FOO_INVOICE_PURCHASE.id)
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(InvoiceGetError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invoice_repo = MockInvoiceRepository::new().with_get(FOO_INVOICE_PURCHASE.id, None);
+
+    let sut = InvoiceFeatureServiceImpl {
+        db,
+        auth,
+        invoice_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .download_invoice(&"token".into(), UserIdOrSelf::Slf, FOO_INVOICE_PURCHASE.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(InvoiceGetError::NotFound));
+}
@@ -0,0 +1,116 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_invoice_contracts::{InvoiceFeatureService, InvoiceGetError};
+use academy_demo::{
+    invoice::FOO_INVOICE_PURCHASE,
+    session::{BAR_1, FOO_1},
+    user::{BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{invoice::MockInvoiceRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, InvoiceFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invoice_repo = MockInvoiceRepository::new()
+        .with_get(FOO_INVOICE_PURCHASE.id, Some(FOO_INVOICE_PURCHASE.clone()));
+
+    let sut = InvoiceFeatureServiceImpl {
+        db,
+        auth,
+        invoice_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_invoice(&"token".into(), UserIdOrSelf::Slf, FOO_INVOICE_PURCHASE.id)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *FOO_INVOICE_PURCHASE);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_invoice(&"token".into(), FOO.user.id.into(), FOO_INVOICE_PURCHASE.id)
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(InvoiceGetError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invoice_repo = MockInvoiceRepository::new().with_get(FOO_INVOICE_PURCHASE.id, None);
+
+    let sut = InvoiceFeatureServiceImpl {
+        db,
+        auth,
+        invoice_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_invoice(&"token".into(), UserIdOrSelf::Slf, FOO_INVOICE_PURCHASE.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(InvoiceGetError::NotFound));
+}
+
+#[tokio::test]
+async fn invoice_of_other_user() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invoice_repo = MockInvoiceRepository::new()
+        .with_get(FOO_INVOICE_PURCHASE.id, Some(FOO_INVOICE_PURCHASE.clone()));
+
+    let sut = InvoiceFeatureServiceImpl {
+        db,
+        auth,
+        invoice_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_invoice(&"token".into(), UserIdOrSelf::Slf, FOO_INVOICE_PURCHASE.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(InvoiceGetError::NotFound));
+}
@@ -0,0 +1,146 @@
+use academy_auth_contracts::internal::{AuthInternalAuthenticateError, MockAuthInternalService};
+use academy_core_invoice_contracts::{
+    invoice::MockInvoiceService, InvoiceFeatureService, InvoiceInternalCreatePayoutError,
+};
+use academy_demo::{
+    invoice::FOO_INVOICE_PAYOUT,
+    user::{BAR, FOO},
+};
+use academy_models::invoice::InvoiceKind;
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, InvoiceFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = FOO_INVOICE_PAYOUT.clone();
+
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+    let invoice = MockInvoiceService::new().with_create(
+        FOO.clone(),
+        InvoiceKind::Payout,
+        expected.description.clone(),
+        expected.gross,
+        expected.clone(),
+    );
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        invoice,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_create_payout_invoice(
+            &"internal token".into(),
+            FOO.user.id,
+            expected.description.clone(),
+            expected.gross,
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", false);
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth_internal,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_create_payout_invoice(
+            &"internal token".into(),
+            FOO.user.id,
+            FOO_INVOICE_PAYOUT.description.clone(),
+            FOO_INVOICE_PAYOUT.gross,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(InvoiceInternalCreatePayoutError::Auth(
+            AuthInternalAuthenticateError::InvalidToken
+        ))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_create_payout_invoice(
+            &"internal token".into(),
+            FOO.user.id,
+            FOO_INVOICE_PAYOUT.description.clone(),
+            FOO_INVOICE_PAYOUT.gross,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(InvoiceInternalCreatePayoutError::NotFound));
+}
+
+#[tokio::test]
+async fn invoice_info_incomplete() {
+    // Arrange
+    let auth_internal = MockAuthInternalService::new().with_authenticate("auth", true);
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, Some(BAR.clone()));
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth_internal,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .internal_create_payout_invoice(
+            &"internal token".into(),
+            BAR.user.id,
+            FOO_INVOICE_PAYOUT.description.clone(),
+            FOO_INVOICE_PAYOUT.gross,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(InvoiceInternalCreatePayoutError::InvoiceInfoIncomplete)
+    );
+}
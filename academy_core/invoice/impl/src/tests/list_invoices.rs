@@ -0,0 +1,107 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_invoice_contracts::{InvoiceFeatureService, InvoiceListError, InvoiceListResult};
+use academy_demo::{
+    invoice::{FOO_INVOICE_PAYOUT, FOO_INVOICE_PURCHASE},
+    session::{ADMIN_1, BAR_1, FOO_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    pagination::PaginationSlice,
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{
+    invoice::MockInvoiceRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, InvoiceFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let pagination = PaginationSlice {
+        limit: 10.try_into().unwrap(),
+        offset: 0,
+    };
+    let expected = InvoiceListResult {
+        total: 2,
+        invoices: vec![FOO_INVOICE_PAYOUT.clone(), FOO_INVOICE_PURCHASE.clone()],
+    };
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let invoice_repo = MockInvoiceRepository::new()
+        .with_count(FOO.user.id, expected.total)
+        .with_list(FOO.user.id, pagination, expected.invoices.clone());
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        invoice_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_invoices(&"token".into(), UserIdOrSelf::Slf, pagination)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_invoices(&"token".into(), FOO.user.id.into(), Default::default())
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(InvoiceListError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+
+    let sut = InvoiceFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_invoices(&"token".into(), FOO.user.id.into(), Default::default())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(InvoiceListError::NotFound));
+}
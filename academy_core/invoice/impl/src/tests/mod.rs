@@ -0,0 +1,21 @@
+use academy_auth_contracts::{internal::MockAuthInternalService, MockAuthService};
+use academy_core_invoice_contracts::invoice::MockInvoiceService;
+use academy_persistence_contracts::{
+    invoice::MockInvoiceRepository, user::MockUserRepository, MockDatabase, MockTransaction,
+};
+
+use crate::InvoiceFeatureServiceImpl;
+
+mod download_invoice;
+mod get_invoice;
+mod internal_create_payout_invoice;
+mod list_invoices;
+
+type Sut = InvoiceFeatureServiceImpl<
+    MockDatabase,
+    MockAuthService<MockTransaction>,
+    MockAuthInternalService,
+    MockInvoiceService<MockTransaction>,
+    MockUserRepository<MockTransaction>,
+    MockInvoiceRepository<MockTransaction>,
+>;
@@ -6,6 +6,8 @@ use academy_models::user::UserId;
 pub trait MfaDisableService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
     /// Completely disable MFA for the given user by deleting all TOTP devices
     /// and invalidating the MFA recovery code.
+    ///
+    /// The user is notified about the change via email.
     fn disable(
         &self,
         txn: &mut Txn,
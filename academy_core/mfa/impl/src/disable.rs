@@ -1,23 +1,42 @@
 use academy_core_mfa_contracts::disable::MfaDisableService;
 use academy_di::Build;
-use academy_models::user::UserId;
-use academy_persistence_contracts::mfa::MfaRepository;
+use academy_email_contracts::template::TemplateEmailService;
+use academy_models::user::{UserComposite, UserId};
+use academy_persistence_contracts::{mfa::MfaRepository, user::UserRepository};
+use academy_templates_contracts::MfaDisabledTemplate;
 use academy_utils::trace_instrument;
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use tracing::trace;
 
+use crate::MfaFeatureConfig;
+
 #[derive(Debug, Clone, Build)]
-pub struct MfaDisableServiceImpl<MfaRepo> {
+#[cfg_attr(test, derive(Default))]
+pub struct MfaDisableServiceImpl<TemplateEmail, UserRepo, MfaRepo> {
+    template_email: TemplateEmail,
+    user_repo: UserRepo,
     mfa_repo: MfaRepo,
+    config: MfaFeatureConfig,
 }
 
-impl<Txn, MfaRepo> MfaDisableService<Txn> for MfaDisableServiceImpl<MfaRepo>
+impl<Txn, TemplateEmail, UserRepo, MfaRepo> MfaDisableService<Txn>
+    for MfaDisableServiceImpl<TemplateEmail, UserRepo, MfaRepo>
 where
     Txn: Send + Sync + 'static,
+    TemplateEmail: TemplateEmailService<Txn>,
+    UserRepo: UserRepository<Txn>,
     MfaRepo: MfaRepository<Txn>,
 {
     #[trace_instrument(skip(self, txn))]
     async fn disable(&self, txn: &mut Txn, user_id: UserId) -> anyhow::Result<()> {
+        trace!("get user");
+        let UserComposite { user, profile, .. } = self
+            .user_repo
+            .get_composite(txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or_else(|| anyhow!("User {} does not exist", *user_id))?;
+
         trace!("delete totp devices");
         self.mfa_repo
             .delete_totp_devices_by_user(txn, user_id)
@@ -30,25 +49,69 @@ where
             .await
             .context("Failed to delete MFA recovery code hash from database")?;
 
+        if let Some(email) = user.email {
+            self.template_email
+                .send_mfa_disabled_email(
+                    txn,
+                    email.with_name(profile.display_name.into_inner()),
+                    &user.locale,
+                    &MfaDisabledTemplate {
+                        recovery_url: (*self.config.account_recovery_url).clone(),
+                    },
+                )
+                .await
+                .context("Failed to send mfa disabled email")?;
+        }
+
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use academy_demo::user::FOO;
-    use academy_persistence_contracts::mfa::MockMfaRepository;
+    use academy_demo::user::{BAR, FOO};
+    use academy_email_contracts::template::MockTemplateEmailService;
+    use academy_persistence_contracts::{mfa::MockMfaRepository, user::MockUserRepository};
+    use academy_utils::{assert_matches, Apply};
 
     use super::*;
 
+    type Sut = MfaDisableServiceImpl<
+        MockTemplateEmailService<()>,
+        MockUserRepository<()>,
+        MockMfaRepository<()>,
+    >;
+
     #[tokio::test]
     async fn ok() {
         // Arrange
+        let config = MfaFeatureConfig::default();
+
+        let user_repo =
+            MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
         let mfa_repo = MockMfaRepository::new()
             .with_delete_totp_devices_by_user(FOO.user.id)
             .with_delete_mfa_recovery_code_hash(FOO.user.id);
 
-        let sut = MfaDisableServiceImpl { mfa_repo };
+        let template_email = MockTemplateEmailService::new().with_send_mfa_disabled_email(
+            FOO.user
+                .email
+                .clone()
+                .unwrap()
+                .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
+            MfaDisabledTemplate {
+                recovery_url: (*config.account_recovery_url).clone(),
+            },
+        );
+
+        let sut = MfaDisableServiceImpl {
+            template_email,
+            user_repo,
+            mfa_repo,
+            config,
+        };
 
         // Act
         let result = sut.disable(&mut (), FOO.user.id).await;
@@ -56,4 +119,45 @@ mod tests {
         // Assert
         result.unwrap();
     }
+
+    #[tokio::test]
+    async fn ok_no_email() {
+        // Arrange
+        let user = BAR.clone().with(|x| x.user.email = None);
+
+        let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, Some(user));
+
+        let mfa_repo = MockMfaRepository::new()
+            .with_delete_totp_devices_by_user(BAR.user.id)
+            .with_delete_mfa_recovery_code_hash(BAR.user.id);
+
+        let sut = MfaDisableServiceImpl {
+            user_repo,
+            mfa_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.disable(&mut (), BAR.user.id).await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn user_not_found() {
+        // Arrange
+        let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
+
+        let sut = MfaDisableServiceImpl {
+            user_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.disable(&mut (), FOO.user.id).await;
+
+        // Assert
+        assert_matches!(result, Err(_));
+    }
 }
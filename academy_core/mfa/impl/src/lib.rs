@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use academy_auth_contracts::{AuthResultExt, AuthService};
 use academy_core_mfa_contracts::{
     disable::MfaDisableService,
@@ -6,14 +8,16 @@ use academy_core_mfa_contracts::{
     MfaDisableError, MfaEnableError, MfaFeatureService, MfaInitializeError,
 };
 use academy_di::Build;
+use academy_email_contracts::template::TemplateEmailService;
 use academy_models::{
     auth::AccessToken,
     mfa::{MfaRecoveryCode, TotpCode, TotpSetup},
-    user::UserIdOrSelf,
+    user::{UserComposite, UserIdOrSelf},
 };
 use academy_persistence_contracts::{
     mfa::MfaRepository, user::UserRepository, Database, Transaction,
 };
+use academy_templates_contracts::MfaEnabledTemplate;
 use academy_utils::trace_instrument;
 use anyhow::Context;
 use tracing::trace;
@@ -26,10 +30,12 @@ pub mod totp_device;
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Clone, Build, Default)]
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
 pub struct MfaFeatureServiceImpl<
     Db,
     Auth,
+    TemplateEmail,
     UserRepo,
     MfaRepo,
     MfaRecovery,
@@ -38,18 +44,36 @@ pub struct MfaFeatureServiceImpl<
 > {
     db: Db,
     auth: Auth,
+    template_email: TemplateEmail,
     user_repo: UserRepo,
     mfa_repo: MfaRepo,
     mfa_recovery: MfaRecovery,
     mfa_disable: MfaDisable,
     mfa_totp_device: MfaTotpDevice,
+    config: MfaFeatureConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct MfaFeatureConfig {
+    pub account_recovery_url: Arc<String>,
 }
 
-impl<Db, Auth, UserRepo, MfaRepo, MfaRecovery, MfaDisable, MfaTotpDevice> MfaFeatureService
-    for MfaFeatureServiceImpl<Db, Auth, UserRepo, MfaRepo, MfaRecovery, MfaDisable, MfaTotpDevice>
+impl<Db, Auth, TemplateEmail, UserRepo, MfaRepo, MfaRecovery, MfaDisable, MfaTotpDevice>
+    MfaFeatureService
+    for MfaFeatureServiceImpl<
+        Db,
+        Auth,
+        TemplateEmail,
+        UserRepo,
+        MfaRepo,
+        MfaRecovery,
+        MfaDisable,
+        MfaTotpDevice,
+    >
 where
     Db: Database,
     Auth: AuthService<Db::Transaction>,
+    TemplateEmail: TemplateEmailService<Db::Transaction>,
     UserRepo: UserRepository<Db::Transaction>,
     MfaRepo: MfaRepository<Db::Transaction>,
     MfaRecovery: MfaRecoveryService<Db::Transaction>,
@@ -124,15 +148,13 @@ where
 
         let mut txn = self.db.begin_transaction().await?;
 
-        trace!("check user existence");
-        if !self
+        trace!("get user");
+        let UserComposite { user, profile, .. } = self
             .user_repo
-            .exists(&mut txn, user_id)
+            .get_composite(&mut txn, user_id)
             .await
-            .context("Failed to check user existence")?
-        {
-            return Err(MfaEnableError::NotFound);
-        }
+            .context("Failed to get user from database")?
+            .ok_or(MfaEnableError::NotFound)?;
 
         trace!("list totp devices");
         let totp_devices = self
@@ -170,6 +192,20 @@ where
             .await
             .context("Failed to setup recovery code")?;
 
+        if let Some(email) = user.email {
+            self.template_email
+                .send_mfa_enabled_email(
+                    &mut txn,
+                    email.with_name(profile.display_name.into_inner()),
+                    &user.locale,
+                    &MfaEnabledTemplate {
+                        recovery_url: (*self.config.account_recovery_url).clone(),
+                    },
+                )
+                .await
+                .context("Failed to send mfa enabled email")?;
+        }
+
         txn.commit().await?;
 
         Ok(recovery_code)
@@ -187,15 +223,12 @@ where
 
         let mut txn = self.db.begin_transaction().await?;
 
-        trace!("check user existence");
-        if !self
-            .user_repo
-            .exists(&mut txn, user_id)
+        trace!("get user");
+        self.user_repo
+            .get_composite(&mut txn, user_id)
             .await
-            .context("Failed to check user existence")?
-        {
-            return Err(MfaDisableError::NotFound);
-        }
+            .context("Failed to get user from database")?
+            .ok_or(MfaDisableError::NotFound)?;
 
         trace!("list totp devices");
         let totp_devices = self
@@ -25,7 +25,7 @@ async fn ok() {
 
     let db = MockDatabase::build(true);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let mfa_repo = MockMfaRepository::new().with_list_totp_devices_by_user(
         FOO.user.id,
@@ -102,7 +102,7 @@ async fn user_not_found() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
 
     let sut = MfaFeatureServiceImpl {
         auth,
@@ -125,7 +125,7 @@ async fn not_initialized() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let mfa_repo = MockMfaRepository::new().with_list_totp_devices_by_user(FOO.user.id, vec![]);
 
@@ -151,7 +151,7 @@ async fn not_enabled() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let mfa_repo = MockMfaRepository::new()
         .with_list_totp_devices_by_user(FOO.user.id, vec![FOO_TOTP_1.clone()]);
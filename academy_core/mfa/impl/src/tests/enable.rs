@@ -9,6 +9,7 @@ use academy_demo::{
     session::{ADMIN_1, BAR_1, FOO_1},
     user::{ADMIN, BAR, FOO},
 };
+use academy_email_contracts::template::MockTemplateEmailService;
 use academy_models::{
     auth::{AuthError, AuthenticateError, AuthorizeError},
     mfa::{MfaRecoveryCode, TotpCode},
@@ -17,13 +18,16 @@ use academy_models::{
 use academy_persistence_contracts::{
     mfa::MockMfaRepository, user::MockUserRepository, MockDatabase,
 };
+use academy_templates_contracts::MfaEnabledTemplate;
 use academy_utils::{assert_matches, Apply};
 
-use crate::{tests::Sut, MfaFeatureServiceImpl};
+use crate::{tests::Sut, MfaFeatureConfig, MfaFeatureServiceImpl};
 
 #[tokio::test]
 async fn ok() {
     // Arrange
+    let config = MfaFeatureConfig::default();
+
     let expected = MfaRecoveryCode::try_new("PJVURV-QRK3YJ-O3U7T6-D50KAC").unwrap();
     let code = TotpCode::try_new("123456").unwrap();
 
@@ -31,7 +35,7 @@ async fn ok() {
 
     let db = MockDatabase::build(true);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let mfa_repo = MockMfaRepository::new()
         .with_list_totp_devices_by_user(FOO.user.id, vec![FOO_TOTP_1.clone()]);
@@ -44,6 +48,18 @@ async fn ok() {
 
     let mfa_recovery = MockMfaRecoveryService::new().with_setup(FOO.user.id, expected.clone());
 
+    let template_email = MockTemplateEmailService::new().with_send_mfa_enabled_email(
+        FOO.user
+            .email
+            .clone()
+            .unwrap()
+            .with_name(FOO.profile.display_name.clone().into_inner()),
+        FOO.user.locale.clone(),
+        MfaEnabledTemplate {
+            recovery_url: (*config.account_recovery_url).clone(),
+        },
+    );
+
     let sut = MfaFeatureServiceImpl {
         auth,
         db,
@@ -51,6 +67,8 @@ async fn ok() {
         mfa_repo,
         mfa_recovery,
         mfa_totp_device,
+        template_email,
+        config,
         ..Sut::default()
     };
 
@@ -119,7 +137,7 @@ async fn user_not_found() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
 
     let sut = MfaFeatureServiceImpl {
         auth,
@@ -144,7 +162,7 @@ async fn already_enabled() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let mfa_repo = MockMfaRepository::new().with_list_totp_devices_by_user(
         FOO.user.id,
@@ -175,7 +193,7 @@ async fn not_initialized() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let mfa_repo = MockMfaRepository::new().with_list_totp_devices_by_user(FOO.user.id, vec![]);
 
@@ -203,7 +221,7 @@ async fn invalid_code() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let mfa_repo = MockMfaRepository::new()
         .with_list_totp_devices_by_user(FOO.user.id, vec![FOO_TOTP_1.clone()]);
@@ -3,11 +3,12 @@ use academy_core_mfa_contracts::{
     disable::MockMfaDisableService, recovery::MockMfaRecoveryService,
     totp_device::MockMfaTotpDeviceService,
 };
+use academy_email_contracts::template::MockTemplateEmailService;
 use academy_persistence_contracts::{
     mfa::MockMfaRepository, user::MockUserRepository, MockDatabase, MockTransaction,
 };
 
-use crate::MfaFeatureServiceImpl;
+use crate::{MfaFeatureConfig, MfaFeatureServiceImpl};
 
 mod disable;
 mod enable;
@@ -16,9 +17,20 @@ mod initialize;
 type Sut = MfaFeatureServiceImpl<
     MockDatabase,
     MockAuthService<MockTransaction>,
+    MockTemplateEmailService<MockTransaction>,
     MockUserRepository<MockTransaction>,
     MockMfaRepository<MockTransaction>,
     MockMfaRecoveryService<MockTransaction>,
     MockMfaDisableService<MockTransaction>,
     MockMfaTotpDeviceService<MockTransaction>,
 >;
+
+impl Default for MfaFeatureConfig {
+    fn default() -> Self {
+        Self {
+            account_recovery_url: "https://bootstrap.academy/auth/reset-password"
+                .to_owned()
+                .into(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::future::Future;
+
+use academy_models::{
+    newsletter::{NewsletterCampaign, NewsletterCampaignPreview, NewsletterRecipient},
+    user::UserId,
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait NewsletterCampaignService: Send + Sync + 'static {
+    /// Render a campaign for the given user, including the links which allow
+    /// the user to unsubscribe from the newsletter.
+    fn render(
+        &self,
+        campaign: &NewsletterCampaign,
+        user_id: UserId,
+    ) -> anyhow::Result<NewsletterCampaignPreview>;
+
+    /// Send a campaign to a single recipient.
+    fn send(
+        &self,
+        campaign: &NewsletterCampaign,
+        recipient: &NewsletterRecipient,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Send a campaign to the next batch of pending recipients and persist
+    /// their delivery status.
+    ///
+    /// The delivery status of each recipient is committed in a separate
+    /// transaction right after the email has been sent, so an interrupted
+    /// batch can be resumed without sending the campaign to anyone twice.
+    /// Pending recipients who are no longer subscribed to the newsletter are
+    /// skipped. Once there are no pending recipients left, the campaign is
+    /// marked as sent.
+    fn send_batch(
+        &self,
+        campaign: &NewsletterCampaign,
+    ) -> impl Future<Output = anyhow::Result<NewsletterSendBatchResult>> + Send;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NewsletterSendBatchResult {
+    /// Number of recipients the campaign has been sent to.
+    pub sent: u64,
+    /// Number of recipients the campaign could not be delivered to.
+    pub failed: u64,
+    /// Number of recipients who have been skipped because they are no longer
+    /// subscribed to the newsletter.
+    pub skipped: u64,
+    /// Whether the campaign has been sent to all recipients.
+    pub completed: bool,
+}
+
+#[cfg(feature = "mock")]
+impl MockNewsletterCampaignService {
+    pub fn with_render(
+        mut self,
+        campaign: NewsletterCampaign,
+        user_id: UserId,
+        result: NewsletterCampaignPreview,
+    ) -> Self {
+        self.expect_render()
+            .once()
+            .with(
+                mockall::predicate::eq(campaign),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(|_, _| Ok(result));
+        self
+    }
+
+    pub fn with_send(
+        mut self,
+        campaign: NewsletterCampaign,
+        recipient: NewsletterRecipient,
+        result: bool,
+    ) -> Self {
+        self.expect_send()
+            .once()
+            .with(
+                mockall::predicate::eq(campaign),
+                mockall::predicate::eq(recipient),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_send_batch(
+        mut self,
+        campaign: NewsletterCampaign,
+        result: NewsletterSendBatchResult,
+    ) -> Self {
+        self.expect_send_batch()
+            .once()
+            .with(mockall::predicate::eq(campaign))
+            .return_once(move |_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
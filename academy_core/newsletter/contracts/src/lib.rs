@@ -0,0 +1,190 @@
+use std::future::Future;
+
+use academy_models::{
+    auth::{AccessToken, AuthError},
+    email_address::EmailAddress,
+    newsletter::{
+        NewsletterCampaign, NewsletterCampaignBody, NewsletterCampaignId, NewsletterCampaignPatch,
+        NewsletterCampaignPreview, NewsletterCampaignStatus, NewsletterCampaignSubject,
+    },
+};
+use thiserror::Error;
+
+pub mod campaign;
+
+pub trait NewsletterFeatureService: Send + Sync + 'static {
+    /// Return all newsletter campaigns, optionally only those with the given
+    /// status.
+    ///
+    /// Requires admin privileges.
+    fn list_campaigns(
+        &self,
+        token: &AccessToken,
+        status: Option<NewsletterCampaignStatus>,
+    ) -> impl Future<Output = Result<Vec<NewsletterCampaign>, NewsletterListCampaignsError>> + Send;
+
+    /// Return the newsletter campaign with the given id, including the
+    /// delivery progress.
+    ///
+    /// Requires admin privileges.
+    fn get_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+    ) -> impl Future<Output = Result<NewsletterCampaign, NewsletterGetCampaignError>> + Send;
+
+    /// Create a new draft newsletter campaign.
+    ///
+    /// Requires admin privileges.
+    fn create_campaign(
+        &self,
+        token: &AccessToken,
+        request: NewsletterCreateCampaignRequest,
+    ) -> impl Future<Output = Result<NewsletterCampaign, NewsletterCreateCampaignError>> + Send;
+
+    /// Update the content of a draft newsletter campaign.
+    ///
+    /// Requires admin privileges.
+    fn update_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+        patch: NewsletterCampaignPatch,
+    ) -> impl Future<Output = Result<NewsletterCampaign, NewsletterUpdateCampaignError>> + Send;
+
+    /// Delete a newsletter campaign which is not currently being sent.
+    ///
+    /// Requires admin privileges.
+    fn delete_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+    ) -> impl Future<Output = Result<(), NewsletterDeleteCampaignError>> + Send;
+
+    /// Render a newsletter campaign as it would be received by the
+    /// authenticated user.
+    ///
+    /// Requires admin privileges.
+    fn preview_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+    ) -> impl Future<Output = Result<NewsletterCampaignPreview, NewsletterPreviewCampaignError>> + Send;
+
+    /// Send a newsletter campaign to a single test address.
+    ///
+    /// The unsubscribe links in the test email belong to the authenticated
+    /// user. Does not affect the status of the campaign.
+    ///
+    /// Requires admin privileges.
+    fn send_test_email(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+        recipient: EmailAddress,
+    ) -> impl Future<Output = Result<(), NewsletterSendTestEmailError>> + Send;
+
+    /// Start sending a draft newsletter campaign to all enabled users with a
+    /// verified email address who are subscribed to the newsletter.
+    ///
+    /// The emails are not sent immediately but delivered in batches by the
+    /// `send-newsletters` task.
+    ///
+    /// Requires admin privileges.
+    fn send_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+    ) -> impl Future<Output = Result<NewsletterCampaign, NewsletterSendCampaignError>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum NewsletterListCampaignsError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum NewsletterGetCampaignError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The campaign does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug)]
+pub struct NewsletterCreateCampaignRequest {
+    pub subject: NewsletterCampaignSubject,
+    pub body_html: NewsletterCampaignBody,
+    pub body_text: NewsletterCampaignBody,
+}
+
+#[derive(Debug, Error)]
+pub enum NewsletterCreateCampaignError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum NewsletterUpdateCampaignError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The campaign does not exist.")]
+    NotFound,
+    #[error("The campaign has already been sent.")]
+    NotDraft,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum NewsletterDeleteCampaignError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The campaign does not exist.")]
+    NotFound,
+    #[error("The campaign is currently being sent.")]
+    Sending,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum NewsletterPreviewCampaignError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The campaign does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum NewsletterSendTestEmailError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The campaign does not exist.")]
+    NotFound,
+    #[error("Failed to send the test email.")]
+    Send,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum NewsletterSendCampaignError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The campaign does not exist.")]
+    NotFound,
+    #[error("The campaign has already been sent.")]
+    NotDraft,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
@@ -0,0 +1,603 @@
+use academy_core_newsletter_contracts::campaign::{
+    NewsletterCampaignService, NewsletterSendBatchResult,
+};
+use academy_core_user_contracts::newsletter::UserNewsletterService;
+use academy_di::Build;
+use academy_email_contracts::template::TemplateEmailService;
+use academy_models::{
+    locale::Locale,
+    newsletter::{
+        NewsletterCampaign, NewsletterCampaignPreview, NewsletterRecipient,
+        NewsletterRecipientStatus,
+    },
+    user::UserId,
+};
+use academy_persistence_contracts::{newsletter::NewsletterRepository, Database, Transaction};
+use academy_shared_contracts::time::TimeService;
+use academy_templates_contracts::{NewsletterTemplate, NewsletterTextTemplate, TemplateService};
+use academy_utils::trace_instrument;
+use anyhow::{anyhow, Context};
+use tracing::{trace, warn};
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct NewsletterCampaignServiceImpl<
+    Db,
+    Time,
+    Template,
+    TemplateEmail,
+    UserNewsletter,
+    NewsletterRepo,
+> {
+    db: Db,
+    time: Time,
+    template: Template,
+    template_email: TemplateEmail,
+    user_newsletter: UserNewsletter,
+    newsletter_repo: NewsletterRepo,
+    config: NewsletterCampaignServiceConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewsletterCampaignServiceConfig {
+    /// Maximum number of recipients to send a campaign to in a single batch.
+    pub batch_size: u64,
+    /// Locale used to render the email layout around the campaign content,
+    /// which is only written in a single language.
+    pub locale: Locale,
+}
+
+impl<Db, Time, Template, TemplateEmail, UserNewsletter, NewsletterRepo> NewsletterCampaignService
+    for NewsletterCampaignServiceImpl<
+        Db,
+        Time,
+        Template,
+        TemplateEmail,
+        UserNewsletter,
+        NewsletterRepo,
+    >
+where
+    Db: Database,
+    Time: TimeService,
+    Template: TemplateService,
+    TemplateEmail: TemplateEmailService<Db::Transaction>,
+    UserNewsletter: UserNewsletterService,
+    NewsletterRepo: NewsletterRepository<Db::Transaction>,
+{
+    #[trace_instrument(skip(self))]
+    fn render(
+        &self,
+        campaign: &NewsletterCampaign,
+        user_id: UserId,
+    ) -> anyhow::Result<NewsletterCampaignPreview> {
+        let unsubscribe_urls = self.user_newsletter.unsubscribe_urls(user_id)?;
+
+        let body_html = self.template.render(
+            &html_template(campaign, unsubscribe_urls.link.to_string()),
+            &self.config.locale,
+        )?;
+
+        let body_text = self.template.render(
+            &text_template(campaign, unsubscribe_urls.link.to_string()),
+            &self.config.locale,
+        )?;
+
+        Ok(NewsletterCampaignPreview {
+            subject: campaign.subject.clone(),
+            body_html,
+            body_text,
+        })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn send(
+        &self,
+        campaign: &NewsletterCampaign,
+        recipient: &NewsletterRecipient,
+    ) -> anyhow::Result<bool> {
+        let unsubscribe_urls = self.user_newsletter.unsubscribe_urls(recipient.user_id)?;
+
+        self.template_email
+            .send_newsletter_email(
+                recipient.email.clone(),
+                &self.config.locale,
+                &html_template(campaign, unsubscribe_urls.link.to_string()),
+                &text_template(campaign, unsubscribe_urls.link.to_string()),
+                &unsubscribe_urls.one_click,
+            )
+            .await
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn send_batch(
+        &self,
+        campaign: &NewsletterCampaign,
+    ) -> anyhow::Result<NewsletterSendBatchResult> {
+        let now = self.time.now();
+        let mut result = NewsletterSendBatchResult::default();
+
+        let mut txn = self.db.begin_transaction().await?;
+        result.skipped = self
+            .newsletter_repo
+            .skip_unsubscribed_recipients(&mut txn, campaign.id, now)
+            .await
+            .context("Failed to skip unsubscribed recipients")?;
+        txn.commit().await?;
+
+        for _ in 0..self.config.batch_size {
+            // Each recipient is locked, sent to and updated in a separate transaction, so that
+            // other workers skip the recipient while the email is being sent and the status of
+            // recipients who have already received the campaign is persisted immediately.
+            let mut txn = self.db.begin_transaction().await?;
+
+            let Some(recipient) = self
+                .newsletter_repo
+                .lock_pending_recipients(&mut txn, campaign.id, 1)
+                .await
+                .context("Failed to get pending recipient from database")?
+                .pop()
+            else {
+                break;
+            };
+            trace!(user_id = %*recipient.user_id, "send to recipient");
+
+            // Errors (e.g. the smtp server being unavailable) abort the batch, so that the
+            // recipient remains pending and is retried later. Only recipients rejected by the
+            // smtp server are marked as failed.
+            let status = if self
+                .send(campaign, &recipient)
+                .await
+                .context("Failed to send newsletter email")?
+            {
+                result.sent += 1;
+                NewsletterRecipientStatus::Sent
+            } else {
+                warn!(user_id = %*recipient.user_id, "Failed to send newsletter email");
+                result.failed += 1;
+                NewsletterRecipientStatus::Failed
+            };
+
+            self.newsletter_repo
+                .update_recipient_status(&mut txn, campaign.id, recipient.user_id, status, now)
+                .await
+                .context("Failed to update recipient status in database")?;
+
+            txn.commit().await?;
+        }
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let pending = self
+            .newsletter_repo
+            .get_campaign(&mut txn, campaign.id)
+            .await
+            .context("Failed to get campaign from database")?
+            .ok_or_else(|| anyhow!("Campaign {} has been deleted", *campaign.id))?
+            .progress
+            .pending;
+
+        if pending == 0 {
+            result.completed = self
+                .newsletter_repo
+                .complete_campaign(&mut txn, campaign.id, now)
+                .await
+                .context("Failed to complete campaign in database")?;
+            txn.commit().await?;
+        }
+
+        Ok(result)
+    }
+}
+
+fn html_template(campaign: &NewsletterCampaign, unsubscribe_url: String) -> NewsletterTemplate {
+    NewsletterTemplate {
+        subject: campaign.subject.clone().into_inner(),
+        content: campaign.body_html.clone().into_inner(),
+        unsubscribe_url,
+    }
+}
+
+fn text_template(campaign: &NewsletterCampaign, unsubscribe_url: String) -> NewsletterTextTemplate {
+    NewsletterTextTemplate {
+        content: campaign.body_text.clone().into_inner(),
+        unsubscribe_url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_core_user_contracts::newsletter::{
+        MockUserNewsletterService, UserNewsletterUnsubscribeUrls,
+    };
+    use academy_demo::{
+        newsletter::DRAFT_CAMPAIGN,
+        user::{ADMIN2, FOO},
+    };
+    use academy_email_contracts::template::MockTemplateEmailService;
+    use academy_models::newsletter::{NewsletterCampaignProgress, NewsletterCampaignStatus};
+    use academy_persistence_contracts::{
+        newsletter::MockNewsletterRepository, MockDatabase, MockTransaction,
+    };
+    use academy_shared_contracts::time::MockTimeService;
+    use academy_templates_contracts::MockTemplateService;
+    use academy_utils::assert_matches;
+
+    use super::*;
+
+    type Sut = NewsletterCampaignServiceImpl<
+        MockDatabase,
+        MockTimeService,
+        MockTemplateService,
+        MockTemplateEmailService<MockTransaction>,
+        MockUserNewsletterService,
+        MockNewsletterRepository<MockTransaction>,
+    >;
+
+    #[test]
+    fn render() {
+        // Arrange
+        let user_newsletter = MockUserNewsletterService::new()
+            .with_unsubscribe_urls(FOO.user.id, make_unsubscribe_urls());
+
+        let template = MockTemplateService::new()
+            .with_render(
+                make_html_template(),
+                make_locale(),
+                "<h1>New courses available</h1>".into(),
+            )
+            .with_render(
+                make_text_template(),
+                make_locale(),
+                "Check out our new courses!\n".into(),
+            );
+
+        let sut = NewsletterCampaignServiceImpl {
+            template,
+            user_newsletter,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.render(&DRAFT_CAMPAIGN, FOO.user.id).unwrap();
+
+        // Assert
+        assert_eq!(
+            result,
+            NewsletterCampaignPreview {
+                subject: DRAFT_CAMPAIGN.subject.clone(),
+                body_html: "<h1>New courses available</h1>".into(),
+                body_text: "Check out our new courses!\n".into(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn send() {
+        // Arrange
+        let user_newsletter = MockUserNewsletterService::new()
+            .with_unsubscribe_urls(FOO.user.id, make_unsubscribe_urls());
+
+        let template_email = MockTemplateEmailService::new().with_send_newsletter_email(
+            make_recipient(&FOO).email,
+            make_locale(),
+            make_html_template(),
+            make_text_template(),
+            make_unsubscribe_urls().one_click,
+            true,
+        );
+
+        let sut = NewsletterCampaignServiceImpl {
+            template_email,
+            user_newsletter,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.send(&DRAFT_CAMPAIGN, &make_recipient(&FOO)).await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn send_batch_completed() {
+        // Arrange
+        let campaign = sending_campaign();
+        let now = campaign.started_at.unwrap();
+
+        let time = MockTimeService::new().with_now(now);
+
+        let user_newsletter = MockUserNewsletterService::new()
+            .with_unsubscribe_urls(FOO.user.id, make_unsubscribe_urls())
+            .with_unsubscribe_urls(ADMIN2.user.id, make_unsubscribe_urls());
+
+        let template_email = MockTemplateEmailService::new()
+            .with_send_newsletter_email(
+                make_recipient(&FOO).email,
+                make_locale(),
+                make_html_template(),
+                make_text_template(),
+                make_unsubscribe_urls().one_click,
+                true,
+            )
+            .with_send_newsletter_email(
+                make_recipient(&ADMIN2).email,
+                make_locale(),
+                make_html_template(),
+                make_text_template(),
+                make_unsubscribe_urls().one_click,
+                false,
+            );
+
+        let newsletter_repo = MockNewsletterRepository::new()
+            .with_skip_unsubscribed_recipients(campaign.id, now, 1)
+            .with_lock_pending_recipients(campaign.id, 1, vec![make_recipient(&FOO)])
+            .with_lock_pending_recipients(campaign.id, 1, vec![make_recipient(&ADMIN2)])
+            .with_update_recipient_status(
+                campaign.id,
+                FOO.user.id,
+                NewsletterRecipientStatus::Sent,
+                now,
+            )
+            .with_update_recipient_status(
+                campaign.id,
+                ADMIN2.user.id,
+                NewsletterRecipientStatus::Failed,
+                now,
+            )
+            .with_get_campaign(
+                campaign.id,
+                Some(NewsletterCampaign {
+                    progress: NewsletterCampaignProgress {
+                        pending: 0,
+                        sent: 1,
+                        failed: 1,
+                        skipped: 1,
+                    },
+                    ..campaign.clone()
+                }),
+            )
+            .with_complete_campaign(campaign.id, now, true);
+
+        let db = MockDatabase::build_many(&[true, true, true, true]);
+
+        let sut = NewsletterCampaignServiceImpl {
+            db,
+            time,
+            template_email,
+            user_newsletter,
+            newsletter_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.send_batch(&campaign).await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            NewsletterSendBatchResult {
+                sent: 1,
+                failed: 1,
+                skipped: 1,
+                completed: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn send_batch_pending() {
+        // Arrange
+        let campaign = sending_campaign();
+        let now = campaign.started_at.unwrap();
+
+        let time = MockTimeService::new().with_now(now);
+
+        let user_newsletter = MockUserNewsletterService::new()
+            .with_unsubscribe_urls(FOO.user.id, make_unsubscribe_urls());
+
+        let template_email = MockTemplateEmailService::new().with_send_newsletter_email(
+            make_recipient(&FOO).email,
+            make_locale(),
+            make_html_template(),
+            make_text_template(),
+            make_unsubscribe_urls().one_click,
+            true,
+        );
+
+        let newsletter_repo = MockNewsletterRepository::new()
+            .with_skip_unsubscribed_recipients(campaign.id, now, 0)
+            .with_lock_pending_recipients(campaign.id, 1, vec![make_recipient(&FOO)])
+            .with_lock_pending_recipients(campaign.id, 1, vec![])
+            .with_update_recipient_status(
+                campaign.id,
+                FOO.user.id,
+                NewsletterRecipientStatus::Sent,
+                now,
+            )
+            .with_get_campaign(
+                campaign.id,
+                Some(NewsletterCampaign {
+                    progress: NewsletterCampaignProgress {
+                        pending: 3,
+                        sent: 1,
+                        ..Default::default()
+                    },
+                    ..campaign.clone()
+                }),
+            );
+
+        let db = MockDatabase::build_many(&[true, true, false, false]);
+
+        let sut = NewsletterCampaignServiceImpl {
+            db,
+            time,
+            template_email,
+            user_newsletter,
+            newsletter_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.send_batch(&campaign).await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            NewsletterSendBatchResult {
+                sent: 1,
+                failed: 0,
+                skipped: 0,
+                completed: false,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn send_batch_error() {
+        // Arrange
+        let campaign = sending_campaign();
+        let now = campaign.started_at.unwrap();
+
+        let time = MockTimeService::new().with_now(now);
+
+        let user_newsletter = MockUserNewsletterService::new()
+            .with_unsubscribe_urls(FOO.user.id, make_unsubscribe_urls())
+            .with_unsubscribe_urls(ADMIN2.user.id, make_unsubscribe_urls());
+
+        let template_email = MockTemplateEmailService::new()
+            .with_send_newsletter_email(
+                make_recipient(&FOO).email,
+                make_locale(),
+                make_html_template(),
+                make_text_template(),
+                make_unsubscribe_urls().one_click,
+                true,
+            )
+            .with_send_newsletter_email_error(
+                make_recipient(&ADMIN2).email,
+                make_locale(),
+                make_html_template(),
+                make_text_template(),
+                make_unsubscribe_urls().one_click,
+            );
+
+        let newsletter_repo = MockNewsletterRepository::new()
+            .with_skip_unsubscribed_recipients(campaign.id, now, 0)
+            .with_lock_pending_recipients(campaign.id, 1, vec![make_recipient(&FOO)])
+            .with_lock_pending_recipients(campaign.id, 1, vec![make_recipient(&ADMIN2)])
+            .with_update_recipient_status(
+                campaign.id,
+                FOO.user.id,
+                NewsletterRecipientStatus::Sent,
+                now,
+            );
+
+        // The status of the first recipient is committed, while the second
+        // recipient remains pending.
+        let db = MockDatabase::build_many(&[true, true, false]);
+
+        let sut = NewsletterCampaignServiceImpl {
+            db,
+            time,
+            template_email,
+            user_newsletter,
+            newsletter_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.send_batch(&campaign).await;
+
+        // Assert
+        assert_matches!(result, Err(_));
+    }
+
+    #[tokio::test]
+    async fn send_batch_deleted() {
+        // Arrange
+        let campaign = sending_campaign();
+        let now = campaign.started_at.unwrap();
+
+        let time = MockTimeService::new().with_now(now);
+
+        let newsletter_repo = MockNewsletterRepository::new()
+            .with_skip_unsubscribed_recipients(campaign.id, now, 0)
+            .with_lock_pending_recipients(campaign.id, 1, vec![])
+            .with_get_campaign(campaign.id, None);
+
+        let db = MockDatabase::build_many(&[true, false, false]);
+
+        let sut = NewsletterCampaignServiceImpl {
+            db,
+            time,
+            newsletter_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.send_batch(&campaign).await;
+
+        // Assert
+        assert_matches!(result, Err(_));
+    }
+
+    impl Default for NewsletterCampaignServiceConfig {
+        fn default() -> Self {
+            Self {
+                batch_size: 2,
+                locale: make_locale(),
+            }
+        }
+    }
+
+    fn make_locale() -> Locale {
+        "de".try_into().unwrap()
+    }
+
+    fn sending_campaign() -> NewsletterCampaign {
+        NewsletterCampaign {
+            status: NewsletterCampaignStatus::Sending,
+            started_at: Some(DRAFT_CAMPAIGN.created_at + std::time::Duration::from_secs(3600)),
+            ..DRAFT_CAMPAIGN.clone()
+        }
+    }
+
+    fn make_recipient(user: &academy_models::user::UserComposite) -> NewsletterRecipient {
+        NewsletterRecipient {
+            user_id: user.user.id,
+            email: user
+                .user
+                .email
+                .clone()
+                .unwrap()
+                .with_name(user.profile.display_name.clone().into_inner()),
+        }
+    }
+
+    fn make_unsubscribe_urls() -> UserNewsletterUnsubscribeUrls {
+        UserNewsletterUnsubscribeUrls {
+            link: "https://bootstrap.academy/unsubscribe?token=t"
+                .parse()
+                .unwrap(),
+            one_click: "https://bootstrap.academy/auth/newsletter/unsubscribe?token=t"
+                .parse()
+                .unwrap(),
+        }
+    }
+
+    fn make_html_template() -> NewsletterTemplate {
+        NewsletterTemplate {
+            subject: "New courses available".into(),
+            content: "<p>Check out our <b>new courses</b>!</p>".into(),
+            unsubscribe_url: "https://bootstrap.academy/unsubscribe?token=t".into(),
+        }
+    }
+
+    fn make_text_template() -> NewsletterTextTemplate {
+        NewsletterTextTemplate {
+            content: "Check out our new courses!".into(),
+            unsubscribe_url: "https://bootstrap.academy/unsubscribe?token=t".into(),
+        }
+    }
+}
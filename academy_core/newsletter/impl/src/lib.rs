@@ -0,0 +1,293 @@
+use academy_auth_contracts::{AuthResultExt, AuthService};
+use academy_core_newsletter_contracts::{
+    campaign::NewsletterCampaignService, NewsletterCreateCampaignError,
+    NewsletterCreateCampaignRequest, NewsletterDeleteCampaignError, NewsletterFeatureService,
+    NewsletterGetCampaignError, NewsletterListCampaignsError, NewsletterPreviewCampaignError,
+    NewsletterSendCampaignError, NewsletterSendTestEmailError, NewsletterUpdateCampaignError,
+};
+use academy_di::Build;
+use academy_models::{
+    auth::AccessToken,
+    email_address::EmailAddress,
+    newsletter::{
+        NewsletterCampaign, NewsletterCampaignId, NewsletterCampaignPatch,
+        NewsletterCampaignPreview, NewsletterCampaignProgress, NewsletterCampaignStatus,
+        NewsletterRecipient,
+    },
+};
+use academy_persistence_contracts::{newsletter::NewsletterRepository, Database, Transaction};
+use academy_shared_contracts::{id::IdService, time::TimeService};
+use academy_utils::{patch::Patch, trace_instrument};
+use anyhow::Context;
+use tracing::{error, trace};
+
+pub mod campaign;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Build, Default)]
+pub struct NewsletterFeatureServiceImpl<Db, Auth, Id, Time, NewsletterCampaign, NewsletterRepo> {
+    db: Db,
+    auth: Auth,
+    id: Id,
+    time: Time,
+    newsletter_campaign: NewsletterCampaign,
+    newsletter_repo: NewsletterRepo,
+}
+
+impl<Db, Auth, Id, Time, NewsletterCampaignS, NewsletterRepo> NewsletterFeatureService
+    for NewsletterFeatureServiceImpl<Db, Auth, Id, Time, NewsletterCampaignS, NewsletterRepo>
+where
+    Db: Database,
+    Auth: AuthService<Db::Transaction>,
+    Id: IdService,
+    Time: TimeService,
+    NewsletterCampaignS: NewsletterCampaignService,
+    NewsletterRepo: NewsletterRepository<Db::Transaction>,
+{
+    #[trace_instrument(skip(self))]
+    async fn list_campaigns(
+        &self,
+        token: &AccessToken,
+        status: Option<NewsletterCampaignStatus>,
+    ) -> Result<Vec<NewsletterCampaign>, NewsletterListCampaignsError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.newsletter_repo
+            .list_campaigns(&mut txn, status)
+            .await
+            .context("Failed to get campaigns from database")
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+    ) -> Result<NewsletterCampaign, NewsletterGetCampaignError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.newsletter_repo
+            .get_campaign(&mut txn, campaign_id)
+            .await
+            .context("Failed to get campaign from database")?
+            .ok_or(NewsletterGetCampaignError::NotFound)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn create_campaign(
+        &self,
+        token: &AccessToken,
+        request: NewsletterCreateCampaignRequest,
+    ) -> Result<NewsletterCampaign, NewsletterCreateCampaignError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let campaign = NewsletterCampaign {
+            id: self.id.generate(),
+            subject: request.subject,
+            body_html: request.body_html,
+            body_text: request.body_text,
+            status: NewsletterCampaignStatus::Draft,
+            created_at: self.time.now(),
+            started_at: None,
+            completed_at: None,
+            progress: Default::default(),
+        };
+
+        self.newsletter_repo
+            .create_campaign(&mut txn, &campaign)
+            .await
+            .context("Failed to create campaign in database")?;
+
+        txn.commit().await?;
+
+        Ok(campaign)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn update_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+        patch: NewsletterCampaignPatch,
+    ) -> Result<NewsletterCampaign, NewsletterUpdateCampaignError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let campaign = self
+            .newsletter_repo
+            .get_campaign(&mut txn, campaign_id)
+            .await
+            .context("Failed to get campaign from database")?
+            .ok_or(NewsletterUpdateCampaignError::NotFound)?;
+
+        if campaign.status != NewsletterCampaignStatus::Draft {
+            return Err(NewsletterUpdateCampaignError::NotDraft);
+        }
+
+        let patch = patch.minimize(&campaign);
+        if patch.is_unchanged() {
+            return Ok(campaign);
+        }
+
+        self.newsletter_repo
+            .update_campaign(&mut txn, campaign_id, patch.as_ref())
+            .await
+            .context("Failed to update campaign in database")?;
+
+        txn.commit().await?;
+
+        Ok(campaign.update(patch))
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn delete_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+    ) -> Result<(), NewsletterDeleteCampaignError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let campaign = self
+            .newsletter_repo
+            .get_campaign(&mut txn, campaign_id)
+            .await
+            .context("Failed to get campaign from database")?
+            .ok_or(NewsletterDeleteCampaignError::NotFound)?;
+
+        if campaign.status == NewsletterCampaignStatus::Sending {
+            return Err(NewsletterDeleteCampaignError::Sending);
+        }
+
+        self.newsletter_repo
+            .delete_campaign(&mut txn, campaign_id)
+            .await
+            .context("Failed to delete campaign from database")?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn preview_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+    ) -> Result<NewsletterCampaignPreview, NewsletterPreviewCampaignError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let campaign = self
+            .newsletter_repo
+            .get_campaign(&mut txn, campaign_id)
+            .await
+            .context("Failed to get campaign from database")?
+            .ok_or(NewsletterPreviewCampaignError::NotFound)?;
+
+        self.newsletter_campaign
+            .render(&campaign, auth.user_id)
+            .context("Failed to render campaign")
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn send_test_email(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+        recipient: EmailAddress,
+    ) -> Result<(), NewsletterSendTestEmailError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let campaign = self
+            .newsletter_repo
+            .get_campaign(&mut txn, campaign_id)
+            .await
+            .context("Failed to get campaign from database")?
+            .ok_or(NewsletterSendTestEmailError::NotFound)?;
+
+        let recipient = NewsletterRecipient {
+            user_id: auth.user_id,
+            email: recipient.into(),
+        };
+
+        trace!("send test email");
+        if !self
+            .newsletter_campaign
+            .send(&campaign, &recipient)
+            .await
+            .context("Failed to send test email")?
+        {
+            error!("Failed to send test email");
+            return Err(NewsletterSendTestEmailError::Send);
+        }
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn send_campaign(
+        &self,
+        token: &AccessToken,
+        campaign_id: NewsletterCampaignId,
+    ) -> Result<NewsletterCampaign, NewsletterSendCampaignError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let campaign = self
+            .newsletter_repo
+            .get_campaign(&mut txn, campaign_id)
+            .await
+            .context("Failed to get campaign from database")?
+            .ok_or(NewsletterSendCampaignError::NotFound)?;
+
+        if campaign.status != NewsletterCampaignStatus::Draft {
+            return Err(NewsletterSendCampaignError::NotDraft);
+        }
+
+        let now = self.time.now();
+        let recipients = self
+            .newsletter_repo
+            .start_campaign(&mut txn, campaign_id, now)
+            .await
+            .context("Failed to start campaign in database")?
+            .ok_or(NewsletterSendCampaignError::NotDraft)?;
+        trace!(recipients, "campaign started");
+
+        txn.commit().await?;
+
+        Ok(NewsletterCampaign {
+            status: NewsletterCampaignStatus::Sending,
+            started_at: Some(now),
+            progress: NewsletterCampaignProgress {
+                pending: recipients,
+                ..Default::default()
+            },
+            ..campaign
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_newsletter_contracts::{
+    NewsletterCreateCampaignError, NewsletterCreateCampaignRequest, NewsletterFeatureService,
+};
+use academy_demo::{
+    newsletter::DRAFT_CAMPAIGN,
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::auth::{AuthError, AuthorizeError};
+use academy_persistence_contracts::{newsletter::MockNewsletterRepository, MockDatabase};
+use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, NewsletterFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(DRAFT_CAMPAIGN.id);
+
+    let time = MockTimeService::new().with_now(DRAFT_CAMPAIGN.created_at);
+
+    let newsletter_repo =
+        MockNewsletterRepository::new().with_create_campaign(DRAFT_CAMPAIGN.clone());
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        id,
+        time,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.create_campaign(&"token".into(), make_request()).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *DRAFT_CAMPAIGN);
+}
+
+#[tokio::test]
+async fn not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.create_campaign(&"token".into(), make_request()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(NewsletterCreateCampaignError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+fn make_request() -> NewsletterCreateCampaignRequest {
+    NewsletterCreateCampaignRequest {
+        subject: DRAFT_CAMPAIGN.subject.clone(),
+        body_html: DRAFT_CAMPAIGN.body_html.clone(),
+        body_text: DRAFT_CAMPAIGN.body_text.clone(),
+    }
+}
@@ -0,0 +1,95 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_newsletter_contracts::{NewsletterDeleteCampaignError, NewsletterFeatureService};
+use academy_demo::{
+    newsletter::{DRAFT_CAMPAIGN, SENT_CAMPAIGN},
+    session::ADMIN_1,
+    user::ADMIN,
+};
+use academy_models::newsletter::{NewsletterCampaign, NewsletterCampaignStatus};
+use academy_persistence_contracts::{newsletter::MockNewsletterRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, NewsletterFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(SENT_CAMPAIGN.id, Some(SENT_CAMPAIGN.clone()))
+        .with_delete_campaign(SENT_CAMPAIGN.id, true);
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_campaign(&"token".into(), SENT_CAMPAIGN.id).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo =
+        MockNewsletterRepository::new().with_get_campaign(DRAFT_CAMPAIGN.id, None);
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .delete_campaign(&"token".into(), DRAFT_CAMPAIGN.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterDeleteCampaignError::NotFound));
+}
+
+#[tokio::test]
+async fn sending() {
+    // Arrange
+    let campaign = NewsletterCampaign {
+        status: NewsletterCampaignStatus::Sending,
+        ..DRAFT_CAMPAIGN.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo =
+        MockNewsletterRepository::new().with_get_campaign(campaign.id, Some(campaign.clone()));
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_campaign(&"token".into(), campaign.id).await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterDeleteCampaignError::Sending));
+}
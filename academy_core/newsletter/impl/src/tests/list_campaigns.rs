@@ -0,0 +1,93 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_newsletter_contracts::{NewsletterFeatureService, NewsletterListCampaignsError};
+use academy_demo::{
+    newsletter::{ALL_CAMPAIGNS, DRAFT_CAMPAIGN},
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    newsletter::NewsletterCampaignStatus,
+};
+use academy_persistence_contracts::{newsletter::MockNewsletterRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, NewsletterFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_all() {
+    // Arrange
+    let expected = ALL_CAMPAIGNS.iter().copied().cloned().collect::<Vec<_>>();
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo =
+        MockNewsletterRepository::new().with_list_campaigns(None, expected.clone());
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_campaigns(&"token".into(), None).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_status() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo = MockNewsletterRepository::new().with_list_campaigns(
+        Some(NewsletterCampaignStatus::Draft),
+        vec![DRAFT_CAMPAIGN.clone()],
+    );
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_campaigns(&"token".into(), Some(NewsletterCampaignStatus::Draft))
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), vec![DRAFT_CAMPAIGN.clone()]);
+}
+
+#[tokio::test]
+async fn not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_campaigns(&"token".into(), None).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(NewsletterListCampaignsError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
@@ -0,0 +1,25 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_newsletter_contracts::campaign::MockNewsletterCampaignService;
+use academy_persistence_contracts::{
+    newsletter::MockNewsletterRepository, MockDatabase, MockTransaction,
+};
+use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+
+use crate::NewsletterFeatureServiceImpl;
+
+mod create_campaign;
+mod delete_campaign;
+mod list_campaigns;
+mod preview_campaign;
+mod send_campaign;
+mod send_test_email;
+mod update_campaign;
+
+type Sut = NewsletterFeatureServiceImpl<
+    MockDatabase,
+    MockAuthService<MockTransaction>,
+    MockIdService,
+    MockTimeService,
+    MockNewsletterCampaignService,
+    MockNewsletterRepository<MockTransaction>,
+>;
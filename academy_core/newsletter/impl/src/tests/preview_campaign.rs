@@ -0,0 +1,78 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_newsletter_contracts::{
+    campaign::MockNewsletterCampaignService, NewsletterFeatureService,
+    NewsletterPreviewCampaignError,
+};
+use academy_demo::{newsletter::DRAFT_CAMPAIGN, session::ADMIN_1, user::ADMIN};
+use academy_models::newsletter::NewsletterCampaignPreview;
+use academy_persistence_contracts::{newsletter::MockNewsletterRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, NewsletterFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = NewsletterCampaignPreview {
+        subject: DRAFT_CAMPAIGN.subject.clone(),
+        body_html: "<h1>rendered</h1>".into(),
+        body_text: "rendered".into(),
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(DRAFT_CAMPAIGN.id, Some(DRAFT_CAMPAIGN.clone()));
+
+    let newsletter_campaign = MockNewsletterCampaignService::new().with_render(
+        DRAFT_CAMPAIGN.clone(),
+        ADMIN.user.id,
+        expected.clone(),
+    );
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        newsletter_campaign,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .preview_campaign(&"token".into(), DRAFT_CAMPAIGN.id)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo =
+        MockNewsletterRepository::new().with_get_campaign(DRAFT_CAMPAIGN.id, None);
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .preview_campaign(&"token".into(), DRAFT_CAMPAIGN.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterPreviewCampaignError::NotFound));
+}
@@ -0,0 +1,106 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_newsletter_contracts::{NewsletterFeatureService, NewsletterSendCampaignError};
+use academy_demo::{
+    newsletter::{DRAFT_CAMPAIGN, SENT_CAMPAIGN},
+    session::ADMIN_1,
+    user::ADMIN,
+};
+use academy_models::newsletter::{
+    NewsletterCampaign, NewsletterCampaignProgress, NewsletterCampaignStatus,
+};
+use academy_persistence_contracts::{newsletter::MockNewsletterRepository, MockDatabase};
+use academy_shared_contracts::time::MockTimeService;
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, NewsletterFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let now = DRAFT_CAMPAIGN.created_at + std::time::Duration::from_secs(3600);
+
+    let expected = NewsletterCampaign {
+        status: NewsletterCampaignStatus::Sending,
+        started_at: Some(now),
+        progress: NewsletterCampaignProgress {
+            pending: 2,
+            ..Default::default()
+        },
+        ..DRAFT_CAMPAIGN.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(DRAFT_CAMPAIGN.id, Some(DRAFT_CAMPAIGN.clone()))
+        .with_start_campaign(DRAFT_CAMPAIGN.id, now, Some(2));
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        time,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.send_campaign(&"token".into(), DRAFT_CAMPAIGN.id).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo =
+        MockNewsletterRepository::new().with_get_campaign(DRAFT_CAMPAIGN.id, None);
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.send_campaign(&"token".into(), DRAFT_CAMPAIGN.id).await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterSendCampaignError::NotFound));
+}
+
+#[tokio::test]
+async fn not_draft() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(SENT_CAMPAIGN.id, Some(SENT_CAMPAIGN.clone()));
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.send_campaign(&"token".into(), SENT_CAMPAIGN.id).await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterSendCampaignError::NotDraft));
+}
@@ -0,0 +1,116 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_newsletter_contracts::{
+    campaign::MockNewsletterCampaignService, NewsletterFeatureService, NewsletterSendTestEmailError,
+};
+use academy_demo::{newsletter::DRAFT_CAMPAIGN, session::ADMIN_1, user::ADMIN};
+use academy_models::{email_address::EmailAddress, newsletter::NewsletterRecipient};
+use academy_persistence_contracts::{newsletter::MockNewsletterRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, NewsletterFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(DRAFT_CAMPAIGN.id, Some(DRAFT_CAMPAIGN.clone()));
+
+    let newsletter_campaign = MockNewsletterCampaignService::new().with_send(
+        DRAFT_CAMPAIGN.clone(),
+        make_recipient(),
+        true,
+    );
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        newsletter_campaign,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .send_test_email(&"token".into(), DRAFT_CAMPAIGN.id, make_email())
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo =
+        MockNewsletterRepository::new().with_get_campaign(DRAFT_CAMPAIGN.id, None);
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .send_test_email(&"token".into(), DRAFT_CAMPAIGN.id, make_email())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterSendTestEmailError::NotFound));
+}
+
+#[tokio::test]
+async fn send_failed() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(DRAFT_CAMPAIGN.id, Some(DRAFT_CAMPAIGN.clone()));
+
+    let newsletter_campaign = MockNewsletterCampaignService::new().with_send(
+        DRAFT_CAMPAIGN.clone(),
+        make_recipient(),
+        false,
+    );
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        newsletter_campaign,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .send_test_email(&"token".into(), DRAFT_CAMPAIGN.id, make_email())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterSendTestEmailError::Send));
+}
+
+fn make_email() -> EmailAddress {
+    "test@example.com".parse().unwrap()
+}
+
+fn make_recipient() -> NewsletterRecipient {
+    NewsletterRecipient {
+        user_id: ADMIN.user.id,
+        email: make_email().into(),
+    }
+}
@@ -0,0 +1,148 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_newsletter_contracts::{NewsletterFeatureService, NewsletterUpdateCampaignError};
+use academy_demo::{
+    newsletter::{DRAFT_CAMPAIGN, SENT_CAMPAIGN},
+    session::ADMIN_1,
+    user::ADMIN,
+};
+use academy_models::newsletter::{NewsletterCampaign, NewsletterCampaignPatch};
+use academy_persistence_contracts::{newsletter::MockNewsletterRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, NewsletterFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = NewsletterCampaign {
+        subject: "Updated subject".try_into().unwrap(),
+        ..DRAFT_CAMPAIGN.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(DRAFT_CAMPAIGN.id, Some(DRAFT_CAMPAIGN.clone()))
+        .with_update_campaign(
+            DRAFT_CAMPAIGN.id,
+            NewsletterCampaignPatch::new().update_subject(expected.subject.clone()),
+            true,
+        );
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_campaign(
+            &"token".into(),
+            DRAFT_CAMPAIGN.id,
+            NewsletterCampaignPatch::new()
+                .update_subject(expected.subject.clone())
+                .update_body_text(DRAFT_CAMPAIGN.body_text.clone()),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_unchanged() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(DRAFT_CAMPAIGN.id, Some(DRAFT_CAMPAIGN.clone()));
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_campaign(
+            &"token".into(),
+            DRAFT_CAMPAIGN.id,
+            NewsletterCampaignPatch::new().update_subject(DRAFT_CAMPAIGN.subject.clone()),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *DRAFT_CAMPAIGN);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo =
+        MockNewsletterRepository::new().with_get_campaign(DRAFT_CAMPAIGN.id, None);
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_campaign(
+            &"token".into(),
+            DRAFT_CAMPAIGN.id,
+            NewsletterCampaignPatch::new(),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterUpdateCampaignError::NotFound));
+}
+
+#[tokio::test]
+async fn not_draft() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let newsletter_repo = MockNewsletterRepository::new()
+        .with_get_campaign(SENT_CAMPAIGN.id, Some(SENT_CAMPAIGN.clone()));
+
+    let sut = NewsletterFeatureServiceImpl {
+        auth,
+        db,
+        newsletter_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_campaign(
+            &"token".into(),
+            SENT_CAMPAIGN.id,
+            NewsletterCampaignPatch::new().update_subject("New subject".try_into().unwrap()),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(NewsletterUpdateCampaignError::NotDraft));
+}
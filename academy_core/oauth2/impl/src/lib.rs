@@ -10,6 +10,7 @@ use academy_core_oauth2_contracts::{
 };
 use academy_core_session_contracts::session::SessionService;
 use academy_di::Build;
+use academy_email_contracts::template::TemplateEmailService;
 use academy_extern_contracts::oauth2::OAuth2ApiService;
 use academy_models::{
     auth::AccessToken,
@@ -18,11 +19,12 @@ use academy_models::{
         OAuth2ProviderSummary, OAuth2Registration,
     },
     session::DeviceName,
-    user::UserIdOrSelf,
+    user::{UserComposite, UserIdOrSelf},
 };
 use academy_persistence_contracts::{
     oauth2::OAuth2Repository, user::UserRepository, Database, Transaction,
 };
+use academy_templates_contracts::{OAuth2LinkCreatedTemplate, OAuth2LinkDeletedTemplate};
 use academy_utils::trace_instrument;
 use anyhow::Context;
 
@@ -39,6 +41,7 @@ pub struct OAuth2FeatureServiceImpl<
     Db,
     Auth,
     OAuth2Api,
+    TemplateEmail,
     UserRepo,
     OAuth2Repo,
     OAuth2Link,
@@ -49,6 +52,7 @@ pub struct OAuth2FeatureServiceImpl<
     db: Db,
     auth: Auth,
     oauth2_api: OAuth2Api,
+    template_email: TemplateEmail,
     user_repo: UserRepo,
     oauth2_repo: OAuth2Repo,
     oauth2_create_link: OAuth2Link,
@@ -62,12 +66,14 @@ pub struct OAuth2FeatureServiceImpl<
 pub struct OAuth2FeatureConfig {
     pub providers: Arc<HashMap<OAuth2ProviderId, OAuth2Provider>>,
     pub registration_token_ttl: Duration,
+    pub account_recovery_url: Arc<String>,
 }
 
 impl<
         Db,
         Auth,
         OAuth2Api,
+        TemplateEmail,
         UserRepo,
         OAuth2Repo,
         OAuth2LinkS,
@@ -79,6 +85,7 @@ impl<
         Db,
         Auth,
         OAuth2Api,
+        TemplateEmail,
         UserRepo,
         OAuth2Repo,
         OAuth2LinkS,
@@ -90,6 +97,7 @@ where
     Db: Database,
     Auth: AuthService<Db::Transaction>,
     OAuth2Api: OAuth2ApiService,
+    TemplateEmail: TemplateEmailService<Db::Transaction>,
     UserRepo: UserRepository<Db::Transaction>,
     OAuth2Repo: OAuth2Repository<Db::Transaction>,
     OAuth2LinkS: OAuth2LinkService<Db::Transaction>,
@@ -156,14 +164,12 @@ where
 
         let mut txn = self.db.begin_transaction().await?;
 
-        if !self
+        let UserComposite { user, profile, .. } = self
             .user_repo
-            .exists(&mut txn, user_id)
+            .get_composite(&mut txn, user_id)
             .await
-            .context("Failed to check user existence")?
-        {
-            return Err(OAuth2CreateLinkError::NotFound);
-        }
+            .context("Failed to get user from database")?
+            .ok_or(OAuth2CreateLinkError::NotFound)?;
 
         let provider_id = login.provider_id.clone();
 
@@ -192,6 +198,22 @@ where
                 }
             })?;
 
+        if let Some(email) = user.email {
+            self.template_email
+                .send_oauth2_link_created_email(
+                    &mut txn,
+                    email.with_name(profile.display_name.into_inner()),
+                    &user.locale,
+                    &OAuth2LinkCreatedTemplate {
+                        provider_name: self.provider_name(&link.provider_id),
+                        remote_user_name: link.remote_user.name.clone().into_inner(),
+                        recovery_url: (*self.config.account_recovery_url).clone(),
+                    },
+                )
+                .await
+                .context("Failed to send OAuth2 link created email")?;
+        }
+
         txn.commit().await?;
 
         Ok(link)
@@ -235,6 +257,23 @@ where
             return Err(OAuth2DeleteLinkError::CannotRemoveLink);
         }
 
+        let UserComposite { user, profile, .. } = user_composite;
+        if let Some(email) = user.email {
+            self.template_email
+                .send_oauth2_link_deleted_email(
+                    &mut txn,
+                    email.with_name(profile.display_name.into_inner()),
+                    &user.locale,
+                    &OAuth2LinkDeletedTemplate {
+                        provider_name: self.provider_name(&link.provider_id),
+                        remote_user_name: link.remote_user.name.into_inner(),
+                        recovery_url: (*self.config.account_recovery_url).clone(),
+                    },
+                )
+                .await
+                .context("Failed to send OAuth2 link deleted email")?;
+        }
+
         txn.commit().await?;
 
         Ok(())
@@ -305,3 +344,39 @@ where
         Ok(OAuth2CreateSessionResponse::Login(login.into()))
     }
 }
+
+impl<
+        Db,
+        Auth,
+        OAuth2Api,
+        TemplateEmail,
+        UserRepo,
+        OAuth2Repo,
+        OAuth2LinkS,
+        OAuth2LoginS,
+        OAuth2RegistrationS,
+        Session,
+    >
+    OAuth2FeatureServiceImpl<
+        Db,
+        Auth,
+        OAuth2Api,
+        TemplateEmail,
+        UserRepo,
+        OAuth2Repo,
+        OAuth2LinkS,
+        OAuth2LoginS,
+        OAuth2RegistrationS,
+        Session,
+    >
+{
+    /// Return the display name of the given provider, falling back to its id
+    /// if it is no longer configured.
+    fn provider_name(&self, provider_id: &OAuth2ProviderId) -> String {
+        self.config
+            .providers
+            .get(provider_id)
+            .map(|provider| provider.name.clone().into_inner())
+            .unwrap_or_else(|| provider_id.clone().into_inner())
+    }
+}
@@ -5,23 +5,27 @@ use academy_core_oauth2_contracts::{
     OAuth2CreateLinkError, OAuth2FeatureService,
 };
 use academy_demo::{
-    oauth2::{FOO_OAUTH2_LINK_1, TEST_OAUTH2_PROVIDER_ID},
+    oauth2::{FOO_OAUTH2_LINK_1, TEST_OAUTH2_PROVIDER, TEST_OAUTH2_PROVIDER_ID},
     session::{ADMIN_1, BAR_1, FOO_1},
     user::{ADMIN, BAR, FOO},
 };
+use academy_email_contracts::template::MockTemplateEmailService;
 use academy_models::{
     auth::{AuthError, AuthenticateError, AuthorizeError},
     oauth2::OAuth2Login,
     user::UserIdOrSelf,
 };
 use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_templates_contracts::OAuth2LinkCreatedTemplate;
 use academy_utils::assert_matches;
 
-use crate::{tests::Sut, OAuth2FeatureServiceImpl};
+use crate::{tests::Sut, OAuth2FeatureConfig, OAuth2FeatureServiceImpl};
 
 #[tokio::test]
 async fn ok() {
     // Arrange
+    let config = OAuth2FeatureConfig::default();
+
     let login = OAuth2Login {
         provider_id: TEST_OAUTH2_PROVIDER_ID.clone(),
         code: "code".try_into().unwrap(),
@@ -32,7 +36,7 @@ async fn ok() {
 
     let db = MockDatabase::build(true);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let oauth2_login = MockOAuth2LoginService::new()
         .with_login(login.clone(), Ok(FOO_OAUTH2_LINK_1.remote_user.clone()));
@@ -44,12 +48,28 @@ async fn ok() {
         Ok(FOO_OAUTH2_LINK_1.clone()),
     );
 
+    let template_email = MockTemplateEmailService::new().with_send_oauth2_link_created_email(
+        FOO.user
+            .email
+            .clone()
+            .unwrap()
+            .with_name(FOO.profile.display_name.clone().into_inner()),
+        FOO.user.locale.clone(),
+        OAuth2LinkCreatedTemplate {
+            provider_name: TEST_OAUTH2_PROVIDER.name.clone().into_inner(),
+            remote_user_name: FOO_OAUTH2_LINK_1.remote_user.name.clone().into_inner(),
+            recovery_url: (*config.account_recovery_url).clone(),
+        },
+    );
+
     let sut = OAuth2FeatureServiceImpl {
         db,
         auth,
         user_repo,
         oauth2_login,
         oauth2_create_link,
+        template_email,
+        config,
         ..Sut::default()
     };
 
@@ -136,7 +156,7 @@ async fn not_found() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
 
     let sut = OAuth2FeatureServiceImpl {
         db,
@@ -167,7 +187,7 @@ async fn invalid_provider() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let oauth2_login = MockOAuth2LoginService::new()
         .with_login(login.clone(), Err(OAuth2LoginServiceError::InvalidProvider));
@@ -202,7 +222,7 @@ async fn invalid_code() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let oauth2_login = MockOAuth2LoginService::new()
         .with_login(login.clone(), Err(OAuth2LoginServiceError::InvalidCode));
@@ -237,7 +257,7 @@ async fn remote_already_linked() {
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
     let oauth2_login = MockOAuth2LoginService::new()
         .with_login(login.clone(), Ok(FOO_OAUTH2_LINK_1.remote_user.clone()));
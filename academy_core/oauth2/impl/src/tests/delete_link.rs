@@ -1,21 +1,25 @@
 use academy_auth_contracts::MockAuthService;
 use academy_core_oauth2_contracts::{OAuth2DeleteLinkError, OAuth2FeatureService};
 use academy_demo::{
-    oauth2::FOO_OAUTH2_LINK_1,
+    oauth2::{FOO_OAUTH2_LINK_1, TEST_OAUTH2_PROVIDER},
     session::{ADMIN_1, BAR_1, FOO_1},
     user::{ADMIN, BAR, FOO},
 };
+use academy_email_contracts::template::MockTemplateEmailService;
 use academy_models::auth::{AuthError, AuthenticateError, AuthorizeError};
 use academy_persistence_contracts::{
     oauth2::MockOAuth2Repository, user::MockUserRepository, MockDatabase,
 };
+use academy_templates_contracts::OAuth2LinkDeletedTemplate;
 use academy_utils::{assert_matches, Apply};
 
-use crate::{tests::Sut, OAuth2FeatureServiceImpl};
+use crate::{tests::Sut, OAuth2FeatureConfig, OAuth2FeatureServiceImpl};
 
 #[tokio::test]
 async fn ok() {
     // Arrange
+    let config = OAuth2FeatureConfig::default();
+
     let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
 
     let db = MockDatabase::build(true);
@@ -29,11 +33,27 @@ async fn ok() {
         Some(FOO.clone().with(|u| u.details.oauth2_login = false)),
     );
 
+    let template_email = MockTemplateEmailService::new().with_send_oauth2_link_deleted_email(
+        FOO.user
+            .email
+            .clone()
+            .unwrap()
+            .with_name(FOO.profile.display_name.clone().into_inner()),
+        FOO.user.locale.clone(),
+        OAuth2LinkDeletedTemplate {
+            provider_name: TEST_OAUTH2_PROVIDER.name.clone().into_inner(),
+            remote_user_name: FOO_OAUTH2_LINK_1.remote_user.name.clone().into_inner(),
+            recovery_url: (*config.account_recovery_url).clone(),
+        },
+    );
+
     let sut = OAuth2FeatureServiceImpl {
         db,
         auth,
         oauth2_repo,
         user_repo,
+        template_email,
+        config,
         ..Sut::default()
     };
 
@@ -7,6 +7,7 @@ use academy_core_oauth2_contracts::{
 };
 use academy_core_session_contracts::session::MockSessionService;
 use academy_demo::oauth2::{TEST_OAUTH2_PROVIDER, TEST_OAUTH2_PROVIDER_ID};
+use academy_email_contracts::template::MockTemplateEmailService;
 use academy_extern_contracts::oauth2::MockOAuth2ApiService;
 use academy_persistence_contracts::{
     oauth2::MockOAuth2Repository, user::MockUserRepository, MockDatabase, MockTransaction,
@@ -24,6 +25,7 @@ type Sut = OAuth2FeatureServiceImpl<
     MockDatabase,
     MockAuthService<MockTransaction>,
     MockOAuth2ApiService,
+    MockTemplateEmailService<MockTransaction>,
     MockUserRepository<MockTransaction>,
     MockOAuth2Repository<MockTransaction>,
     MockOAuth2LinkService<MockTransaction>,
@@ -41,6 +43,9 @@ impl Default for OAuth2FeatureConfig {
                 TEST_OAUTH2_PROVIDER.clone(),
             )])
             .into(),
+            account_recovery_url: "https://bootstrap.academy/auth/reset-password"
+                .to_owned()
+                .into(),
         }
     }
 }
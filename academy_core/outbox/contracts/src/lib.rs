@@ -0,0 +1,81 @@
+use std::future::Future;
+
+use academy_models::{
+    auth::{AccessToken, AuthError},
+    email_outbox::{EmailOutboxEntry, EmailOutboxId, EmailOutboxStatus},
+    pagination::PaginationSlice,
+};
+use thiserror::Error;
+
+pub trait OutboxFeatureService: Send + Sync + 'static {
+    /// Return the most recent emails in the outbox, optionally only those with
+    /// the given status.
+    ///
+    /// Requires admin privileges.
+    fn list_emails(
+        &self,
+        token: &AccessToken,
+        query: OutboxListQuery,
+    ) -> impl Future<Output = Result<OutboxListResult, OutboxListEmailsError>> + Send;
+
+    /// Return the email with the given id.
+    ///
+    /// Requires admin privileges.
+    fn get_email(
+        &self,
+        token: &AccessToken,
+        email_id: EmailOutboxId,
+    ) -> impl Future<Output = Result<EmailOutboxEntry, OutboxGetEmailError>> + Send;
+
+    /// Reset the delivery attempts of an email which has not been sent yet and
+    /// schedule it for immediate delivery.
+    ///
+    /// Requires admin privileges.
+    fn retry_email(
+        &self,
+        token: &AccessToken,
+        email_id: EmailOutboxId,
+    ) -> impl Future<Output = Result<EmailOutboxEntry, OutboxRetryEmailError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OutboxListQuery {
+    pub status: Option<EmailOutboxStatus>,
+    pub pagination: PaginationSlice,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxListResult {
+    pub total: u64,
+    pub emails: Vec<EmailOutboxEntry>,
+}
+
+#[derive(Debug, Error)]
+pub enum OutboxListEmailsError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum OutboxGetEmailError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The email does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum OutboxRetryEmailError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The email does not exist.")]
+    NotFound,
+    #[error("The email has already been sent.")]
+    AlreadySent,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
@@ -0,0 +1,120 @@
+use academy_auth_contracts::{AuthResultExt, AuthService};
+use academy_core_outbox_contracts::{
+    OutboxFeatureService, OutboxGetEmailError, OutboxListEmailsError, OutboxListQuery,
+    OutboxListResult, OutboxRetryEmailError,
+};
+use academy_di::Build;
+use academy_models::{
+    auth::AccessToken,
+    email_outbox::{EmailOutboxEntry, EmailOutboxId, EmailOutboxStatus},
+};
+use academy_persistence_contracts::{email_outbox::EmailOutboxRepository, Database, Transaction};
+use academy_shared_contracts::time::TimeService;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Build, Default)]
+pub struct OutboxFeatureServiceImpl<Db, Auth, Time, EmailOutboxRepo> {
+    db: Db,
+    auth: Auth,
+    time: Time,
+    email_outbox_repo: EmailOutboxRepo,
+}
+
+impl<Db, Auth, Time, EmailOutboxRepo> OutboxFeatureService
+    for OutboxFeatureServiceImpl<Db, Auth, Time, EmailOutboxRepo>
+where
+    Db: Database,
+    Auth: AuthService<Db::Transaction>,
+    Time: TimeService,
+    EmailOutboxRepo: EmailOutboxRepository<Db::Transaction>,
+{
+    #[trace_instrument(skip(self))]
+    async fn list_emails(
+        &self,
+        token: &AccessToken,
+        OutboxListQuery { status, pagination }: OutboxListQuery,
+    ) -> Result<OutboxListResult, OutboxListEmailsError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let total = self
+            .email_outbox_repo
+            .count(&mut txn, status)
+            .await
+            .context("Failed to count emails in database")?;
+
+        let emails = self
+            .email_outbox_repo
+            .list(&mut txn, status, pagination)
+            .await
+            .context("Failed to get emails from database")?;
+
+        Ok(OutboxListResult { total, emails })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_email(
+        &self,
+        token: &AccessToken,
+        email_id: EmailOutboxId,
+    ) -> Result<EmailOutboxEntry, OutboxGetEmailError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.email_outbox_repo
+            .get(&mut txn, email_id)
+            .await
+            .context("Failed to get email from database")?
+            .ok_or(OutboxGetEmailError::NotFound)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn retry_email(
+        &self,
+        token: &AccessToken,
+        email_id: EmailOutboxId,
+    ) -> Result<EmailOutboxEntry, OutboxRetryEmailError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let entry = self
+            .email_outbox_repo
+            .get(&mut txn, email_id)
+            .await
+            .context("Failed to get email from database")?
+            .ok_or(OutboxRetryEmailError::NotFound)?;
+
+        if entry.status == EmailOutboxStatus::Sent {
+            return Err(OutboxRetryEmailError::AlreadySent);
+        }
+
+        let now = self.time.now();
+        if !self
+            .email_outbox_repo
+            .retry(&mut txn, email_id, now)
+            .await
+            .context("Failed to retry email in database")?
+        {
+            return Err(OutboxRetryEmailError::AlreadySent);
+        }
+
+        txn.commit().await?;
+
+        Ok(EmailOutboxEntry {
+            status: EmailOutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Some(now),
+            ..entry
+        })
+    }
+}
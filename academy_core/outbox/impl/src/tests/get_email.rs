@@ -0,0 +1,83 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_outbox_contracts::{OutboxFeatureService, OutboxGetEmailError};
+use academy_demo::{
+    email_outbox::SENT_EMAIL,
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::auth::{AuthError, AuthorizeError};
+use academy_persistence_contracts::{email_outbox::MockEmailOutboxRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, OutboxFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let email_outbox_repo =
+        MockEmailOutboxRepository::new().with_get(SENT_EMAIL.id, Some(SENT_EMAIL.clone()));
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        db,
+        email_outbox_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_email(&"token".into(), SENT_EMAIL.id).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *SENT_EMAIL);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let email_outbox_repo = MockEmailOutboxRepository::new().with_get(SENT_EMAIL.id, None);
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        db,
+        email_outbox_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_email(&"token".into(), SENT_EMAIL.id).await;
+
+    // Assert
+    assert_matches!(result, Err(OutboxGetEmailError::NotFound));
+}
+
+#[tokio::test]
+async fn not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_email(&"token".into(), SENT_EMAIL.id).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(OutboxGetEmailError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
@@ -0,0 +1,122 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_outbox_contracts::{
+    OutboxFeatureService, OutboxListEmailsError, OutboxListQuery, OutboxListResult,
+};
+use academy_demo::{
+    email_outbox::{ALL_EMAILS, FAILED_EMAIL},
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    email_outbox::EmailOutboxStatus,
+    pagination::PaginationSlice,
+};
+use academy_persistence_contracts::{email_outbox::MockEmailOutboxRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, OutboxFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_all() {
+    // Arrange
+    let expected = ALL_EMAILS.iter().copied().cloned().collect::<Vec<_>>();
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let email_outbox_repo = MockEmailOutboxRepository::new()
+        .with_count(None, expected.len() as _)
+        .with_list(None, PaginationSlice::default(), expected.clone());
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        db,
+        email_outbox_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_emails(&"token".into(), OutboxListQuery::default())
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        OutboxListResult {
+            total: expected.len() as _,
+            emails: expected
+        }
+    );
+}
+
+#[tokio::test]
+async fn ok_status() {
+    // Arrange
+    let query = OutboxListQuery {
+        status: Some(EmailOutboxStatus::Failed),
+        pagination: PaginationSlice {
+            limit: 5.try_into().unwrap(),
+            offset: 0,
+        },
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let email_outbox_repo = MockEmailOutboxRepository::new()
+        .with_count(Some(EmailOutboxStatus::Failed), 1)
+        .with_list(
+            Some(EmailOutboxStatus::Failed),
+            query.pagination,
+            vec![FAILED_EMAIL.clone()],
+        );
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        db,
+        email_outbox_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_emails(&"token".into(), query).await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        OutboxListResult {
+            total: 1,
+            emails: vec![FAILED_EMAIL.clone()]
+        }
+    );
+}
+
+#[tokio::test]
+async fn not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_emails(&"token".into(), OutboxListQuery::default())
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(OutboxListEmailsError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
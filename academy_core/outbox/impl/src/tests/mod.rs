@@ -0,0 +1,18 @@
+use academy_auth_contracts::MockAuthService;
+use academy_persistence_contracts::{
+    email_outbox::MockEmailOutboxRepository, MockDatabase, MockTransaction,
+};
+use academy_shared_contracts::time::MockTimeService;
+
+use crate::OutboxFeatureServiceImpl;
+
+mod get_email;
+mod list_emails;
+mod retry_email;
+
+type Sut = OutboxFeatureServiceImpl<
+    MockDatabase,
+    MockAuthService<MockTransaction>,
+    MockTimeService,
+    MockEmailOutboxRepository<MockTransaction>,
+>;
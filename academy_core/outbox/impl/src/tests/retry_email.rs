@@ -0,0 +1,153 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_outbox_contracts::{OutboxFeatureService, OutboxRetryEmailError};
+use academy_demo::{
+    email_outbox::{FAILED_EMAIL, SENT_EMAIL},
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    email_outbox::{EmailOutboxEntry, EmailOutboxStatus},
+};
+use academy_persistence_contracts::{email_outbox::MockEmailOutboxRepository, MockDatabase};
+use academy_shared_contracts::time::MockTimeService;
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, OutboxFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let now = FAILED_EMAIL.created_at + std::time::Duration::from_secs(7200);
+    let expected = EmailOutboxEntry {
+        status: EmailOutboxStatus::Pending,
+        attempts: 0,
+        next_attempt_at: Some(now),
+        ..FAILED_EMAIL.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let email_outbox_repo = MockEmailOutboxRepository::new()
+        .with_get(FAILED_EMAIL.id, Some(FAILED_EMAIL.clone()))
+        .with_retry(FAILED_EMAIL.id, now, true);
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        db,
+        time,
+        email_outbox_repo,
+    };
+
+    // Act
+    let result = sut.retry_email(&"token".into(), FAILED_EMAIL.id).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let email_outbox_repo = MockEmailOutboxRepository::new().with_get(FAILED_EMAIL.id, None);
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        db,
+        email_outbox_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.retry_email(&"token".into(), FAILED_EMAIL.id).await;
+
+    // Assert
+    assert_matches!(result, Err(OutboxRetryEmailError::NotFound));
+}
+
+#[tokio::test]
+async fn already_sent() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let email_outbox_repo =
+        MockEmailOutboxRepository::new().with_get(SENT_EMAIL.id, Some(SENT_EMAIL.clone()));
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        db,
+        email_outbox_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.retry_email(&"token".into(), SENT_EMAIL.id).await;
+
+    // Assert
+    assert_matches!(result, Err(OutboxRetryEmailError::AlreadySent));
+}
+
+#[tokio::test]
+async fn sent_concurrently() {
+    // Arrange
+    let now = FAILED_EMAIL.created_at + std::time::Duration::from_secs(7200);
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let email_outbox_repo = MockEmailOutboxRepository::new()
+        .with_get(FAILED_EMAIL.id, Some(FAILED_EMAIL.clone()))
+        .with_retry(FAILED_EMAIL.id, now, false);
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        db,
+        time,
+        email_outbox_repo,
+    };
+
+    // Act
+    let result = sut.retry_email(&"token".into(), FAILED_EMAIL.id).await;
+
+    // Assert
+    assert_matches!(result, Err(OutboxRetryEmailError::AlreadySent));
+}
+
+#[tokio::test]
+async fn not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = OutboxFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.retry_email(&"token".into(), FAILED_EMAIL.id).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(OutboxRetryEmailError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
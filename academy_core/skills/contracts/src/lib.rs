@@ -0,0 +1,453 @@
+use std::future::Future;
+
+use academy_models::{
+    auth::{AccessToken, AuthError},
+    skill::{
+        Course, CourseDescription, CourseId, CoursePatch, CourseProgress, CourseSection,
+        CourseSectionId, CourseSectionPatch, CourseSectionTitle, CourseTitle, Lecture,
+        LectureDescription, LectureId, LecturePatch, LectureTitle, Skill, SkillDescription,
+        SkillId, SkillPatch, SkillPrerequisite, SkillProgress, SkillTitle,
+    },
+    user::UserIdOrSelf,
+};
+use thiserror::Error;
+
+pub mod progress;
+
+pub trait SkillsFeatureService: Send + Sync + 'static {
+    /// Return all skills together with the edges of the skill tree.
+    fn list_skills(&self) -> impl Future<Output = Result<SkillTree, SkillsListSkillsError>> + Send;
+
+    /// Create a new skill.
+    ///
+    /// Requires admin privileges.
+    fn create_skill(
+        &self,
+        token: &AccessToken,
+        request: SkillsCreateSkillRequest,
+    ) -> impl Future<Output = Result<Skill, SkillsCreateSkillError>> + Send;
+
+    /// Update an existing skill.
+    ///
+    /// Requires admin privileges.
+    fn update_skill(
+        &self,
+        token: &AccessToken,
+        skill_id: SkillId,
+        patch: SkillPatch,
+    ) -> impl Future<Output = Result<Skill, SkillsUpdateSkillError>> + Send;
+
+    /// Delete a skill together with all of its courses.
+    ///
+    /// Requires admin privileges.
+    fn delete_skill(
+        &self,
+        token: &AccessToken,
+        skill_id: SkillId,
+    ) -> impl Future<Output = Result<(), SkillsDeleteSkillError>> + Send;
+
+    /// Require `prerequisite_id` to be completed before `skill_id` is
+    /// unlocked.
+    ///
+    /// Requires admin privileges.
+    fn add_skill_prerequisite(
+        &self,
+        token: &AccessToken,
+        prerequisite: SkillPrerequisite,
+    ) -> impl Future<Output = Result<(), SkillsAddPrerequisiteError>> + Send;
+
+    /// Remove a prerequisite of a skill.
+    ///
+    /// Requires admin privileges.
+    fn remove_skill_prerequisite(
+        &self,
+        token: &AccessToken,
+        prerequisite: SkillPrerequisite,
+    ) -> impl Future<Output = Result<(), SkillsRemovePrerequisiteError>> + Send;
+
+    /// Return all courses, optionally only those of the given skill.
+    fn list_courses(
+        &self,
+        skill_id: Option<SkillId>,
+    ) -> impl Future<Output = Result<Vec<Course>, SkillsListCoursesError>> + Send;
+
+    /// Return a course together with its sections and lectures.
+    fn get_course(
+        &self,
+        course_id: CourseId,
+    ) -> impl Future<Output = Result<CourseOutline, SkillsGetCourseError>> + Send;
+
+    /// Create a new course for the given skill.
+    ///
+    /// Requires admin privileges.
+    fn create_course(
+        &self,
+        token: &AccessToken,
+        skill_id: SkillId,
+        request: SkillsCreateCourseRequest,
+    ) -> impl Future<Output = Result<Course, SkillsCreateCourseError>> + Send;
+
+    /// Update an existing course.
+    ///
+    /// Requires admin privileges.
+    fn update_course(
+        &self,
+        token: &AccessToken,
+        course_id: CourseId,
+        patch: CoursePatch,
+    ) -> impl Future<Output = Result<Course, SkillsUpdateCourseError>> + Send;
+
+    /// Delete a course together with its sections and lectures.
+    ///
+    /// Requires admin privileges.
+    fn delete_course(
+        &self,
+        token: &AccessToken,
+        course_id: CourseId,
+    ) -> impl Future<Output = Result<(), SkillsDeleteCourseError>> + Send;
+
+    /// Append a new section to a course.
+    ///
+    /// Requires admin privileges.
+    fn create_section(
+        &self,
+        token: &AccessToken,
+        course_id: CourseId,
+        title: CourseSectionTitle,
+    ) -> impl Future<Output = Result<CourseSection, SkillsCreateSectionError>> + Send;
+
+    /// Update an existing section.
+    ///
+    /// Requires admin privileges.
+    fn update_section(
+        &self,
+        token: &AccessToken,
+        section_id: CourseSectionId,
+        patch: CourseSectionPatch,
+    ) -> impl Future<Output = Result<CourseSection, SkillsUpdateSectionError>> + Send;
+
+    /// Delete a section together with its lectures.
+    ///
+    /// Requires admin privileges.
+    fn delete_section(
+        &self,
+        token: &AccessToken,
+        section_id: CourseSectionId,
+    ) -> impl Future<Output = Result<(), SkillsDeleteSectionError>> + Send;
+
+    /// Append a new lecture to a section.
+    ///
+    /// Requires admin privileges.
+    fn create_lecture(
+        &self,
+        token: &AccessToken,
+        section_id: CourseSectionId,
+        request: SkillsCreateLectureRequest,
+    ) -> impl Future<Output = Result<Lecture, SkillsCreateLectureError>> + Send;
+
+    /// Update an existing lecture.
+    ///
+    /// Requires admin privileges.
+    fn update_lecture(
+        &self,
+        token: &AccessToken,
+        lecture_id: LectureId,
+        patch: LecturePatch,
+    ) -> impl Future<Output = Result<Lecture, SkillsUpdateLectureError>> + Send;
+
+    /// Delete a lecture.
+    ///
+    /// Requires admin privileges.
+    fn delete_lecture(
+        &self,
+        token: &AccessToken,
+        lecture_id: LectureId,
+    ) -> impl Future<Output = Result<(), SkillsDeleteLectureError>> + Send;
+
+    /// Return the progress of the given user in all skills.
+    ///
+    /// Can only be used by administrators, if the user_id does not match the
+    /// authenticated user.
+    fn list_skill_progress(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+    ) -> impl Future<Output = Result<Vec<SkillProgress>, SkillsListSkillProgressError>> + Send;
+
+    /// Return the progress of the given user in a course.
+    ///
+    /// Can only be used by administrators, if the user_id does not match the
+    /// authenticated user.
+    fn get_course_progress(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        course_id: CourseId,
+    ) -> impl Future<Output = Result<CourseProgress, SkillsGetCourseProgressError>> + Send;
+
+    /// Mark a lecture as completed by the given user.
+    ///
+    /// The skill of the lecture has to be unlocked.
+    ///
+    /// Can only be used by administrators, if the user_id does not match the
+    /// authenticated user.
+    fn complete_lecture(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        lecture_id: LectureId,
+    ) -> impl Future<Output = Result<(), SkillsCompleteLectureError>> + Send;
+
+    /// Mark a lecture as not completed by the given user.
+    ///
+    /// Can only be used by administrators, if the user_id does not match the
+    /// authenticated user.
+    fn reset_lecture(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        lecture_id: LectureId,
+    ) -> impl Future<Output = Result<(), SkillsResetLectureError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillTree {
+    pub skills: Vec<Skill>,
+    pub prerequisites: Vec<SkillPrerequisite>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseOutline {
+    pub course: Course,
+    /// The sections of the course ordered by their position
+    pub sections: Vec<CourseOutlineSection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseOutlineSection {
+    pub section: CourseSection,
+    /// The lectures of the section ordered by their position
+    pub lectures: Vec<Lecture>,
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsListSkillsError {
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug)]
+pub struct SkillsCreateSkillRequest {
+    pub title: SkillTitle,
+    pub description: SkillDescription,
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsCreateSkillError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsUpdateSkillError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The skill does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsDeleteSkillError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The skill does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsAddPrerequisiteError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The skill does not exist.")]
+    NotFound,
+    #[error("The prerequisite would introduce a cycle into the skill tree.")]
+    Cycle,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsRemovePrerequisiteError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The prerequisite does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsListCoursesError {
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsGetCourseError {
+    #[error("The course does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug)]
+pub struct SkillsCreateCourseRequest {
+    pub title: CourseTitle,
+    pub description: CourseDescription,
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsCreateCourseError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The skill does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsUpdateCourseError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The course does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsDeleteCourseError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The course does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsCreateSectionError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The course does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsUpdateSectionError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The section does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsDeleteSectionError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The section does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug)]
+pub struct SkillsCreateLectureRequest {
+    pub title: LectureTitle,
+    pub description: LectureDescription,
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsCreateLectureError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The section does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsUpdateLectureError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The lecture does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsDeleteLectureError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The lecture does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsListSkillProgressError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsGetCourseProgressError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The course does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsCompleteLectureError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    UserNotFound,
+    #[error("The lecture does not exist.")]
+    LectureNotFound,
+    #[error("The prerequisites of the skill have not been completed.")]
+    SkillLocked,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SkillsResetLectureError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The lecture has not been completed.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
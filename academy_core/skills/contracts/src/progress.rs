@@ -0,0 +1,30 @@
+use std::future::Future;
+
+use academy_models::{skill::SkillProgress, user::UserId};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait SkillProgressService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Return the progress of a user in all skills.
+    ///
+    /// A skill is unlocked once all of its direct prerequisites have been
+    /// completed.
+    fn list(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<Vec<SkillProgress>>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockSkillProgressService<Txn> {
+    pub fn with_list(mut self, user_id: UserId, result: Vec<SkillProgress>) -> Self {
+        self.expect_list()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
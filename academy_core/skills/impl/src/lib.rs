@@ -0,0 +1,776 @@
+use std::collections::{HashMap, HashSet};
+
+use academy_auth_contracts::{AuthResultExt, AuthService};
+use academy_core_skills_contracts::{
+    progress::SkillProgressService, CourseOutline, CourseOutlineSection, SkillTree,
+    SkillsAddPrerequisiteError, SkillsCompleteLectureError, SkillsCreateCourseError,
+    SkillsCreateCourseRequest, SkillsCreateLectureError, SkillsCreateLectureRequest,
+    SkillsCreateSectionError, SkillsCreateSkillError, SkillsCreateSkillRequest,
+    SkillsDeleteCourseError, SkillsDeleteLectureError, SkillsDeleteSectionError,
+    SkillsDeleteSkillError, SkillsFeatureService, SkillsGetCourseError,
+    SkillsGetCourseProgressError, SkillsListCoursesError, SkillsListSkillProgressError,
+    SkillsListSkillsError, SkillsRemovePrerequisiteError, SkillsResetLectureError,
+    SkillsUpdateCourseError, SkillsUpdateLectureError, SkillsUpdateSectionError,
+    SkillsUpdateSkillError,
+};
+use academy_di::Build;
+use academy_models::{
+    auth::AccessToken,
+    skill::{
+        Course, CourseId, CoursePatch, CourseProgress, CourseSection, CourseSectionId,
+        CourseSectionPatch, CourseSectionTitle, LearningProgress, Lecture, LectureId, LecturePatch,
+        Skill, SkillId, SkillPatch, SkillPrerequisite, SkillProgress,
+    },
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{
+    skill::SkillRepository, user::UserRepository, Database, Transaction,
+};
+use academy_shared_contracts::{id::IdService, time::TimeService};
+use academy_utils::{patch::Patch, trace_instrument};
+use anyhow::Context;
+
+pub mod progress;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Build, Default)]
+pub struct SkillsFeatureServiceImpl<Db, Auth, Id, Time, SkillProgress, UserRepo, SkillRepo> {
+    db: Db,
+    auth: Auth,
+    id: Id,
+    time: Time,
+    skill_progress: SkillProgress,
+    user_repo: UserRepo,
+    skill_repo: SkillRepo,
+}
+
+impl<Db, Auth, Id, Time, SkillProgressS, UserRepo, SkillRepo> SkillsFeatureService
+    for SkillsFeatureServiceImpl<Db, Auth, Id, Time, SkillProgressS, UserRepo, SkillRepo>
+where
+    Db: Database,
+    Auth: AuthService<Db::Transaction>,
+    Id: IdService,
+    Time: TimeService,
+    SkillProgressS: SkillProgressService<Db::Transaction>,
+    UserRepo: UserRepository<Db::Transaction>,
+    SkillRepo: SkillRepository<Db::Transaction>,
+{
+    #[trace_instrument(skip(self))]
+    async fn list_skills(&self) -> Result<SkillTree, SkillsListSkillsError> {
+        let mut txn = self.db.begin_transaction().await?;
+
+        let skills = self
+            .skill_repo
+            .list_skills(&mut txn)
+            .await
+            .context("Failed to get skills from database")?;
+
+        let prerequisites = self
+            .skill_repo
+            .list_prerequisites(&mut txn)
+            .await
+            .context("Failed to get skill prerequisites from database")?;
+
+        Ok(SkillTree {
+            skills,
+            prerequisites,
+        })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn create_skill(
+        &self,
+        token: &AccessToken,
+        request: SkillsCreateSkillRequest,
+    ) -> Result<Skill, SkillsCreateSkillError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let skill = Skill {
+            id: self.id.generate(),
+            title: request.title,
+            description: request.description,
+            created_at: self.time.now(),
+        };
+
+        self.skill_repo
+            .create_skill(&mut txn, &skill)
+            .await
+            .context("Failed to create skill in database")?;
+
+        txn.commit().await?;
+
+        Ok(skill)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn update_skill(
+        &self,
+        token: &AccessToken,
+        skill_id: SkillId,
+        patch: SkillPatch,
+    ) -> Result<Skill, SkillsUpdateSkillError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let skill = self
+            .skill_repo
+            .get_skill(&mut txn, skill_id)
+            .await
+            .context("Failed to get skill from database")?
+            .ok_or(SkillsUpdateSkillError::NotFound)?;
+
+        let patch = patch.minimize(&skill);
+        if patch.is_unchanged() {
+            return Ok(skill);
+        }
+
+        self.skill_repo
+            .update_skill(&mut txn, skill_id, patch.as_ref())
+            .await
+            .context("Failed to update skill in database")?;
+
+        txn.commit().await?;
+
+        Ok(skill.update(patch))
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn delete_skill(
+        &self,
+        token: &AccessToken,
+        skill_id: SkillId,
+    ) -> Result<(), SkillsDeleteSkillError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .skill_repo
+            .delete_skill(&mut txn, skill_id)
+            .await
+            .context("Failed to delete skill from database")?
+        {
+            return Err(SkillsDeleteSkillError::NotFound);
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn add_skill_prerequisite(
+        &self,
+        token: &AccessToken,
+        prerequisite: SkillPrerequisite,
+    ) -> Result<(), SkillsAddPrerequisiteError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        for skill_id in [prerequisite.skill_id, prerequisite.prerequisite_id] {
+            self.skill_repo
+                .get_skill(&mut txn, skill_id)
+                .await
+                .context("Failed to get skill from database")?
+                .ok_or(SkillsAddPrerequisiteError::NotFound)?;
+        }
+
+        let prerequisites = self
+            .skill_repo
+            .list_prerequisites(&mut txn)
+            .await
+            .context("Failed to get skill prerequisites from database")?;
+
+        if creates_cycle(&prerequisites, prerequisite) {
+            return Err(SkillsAddPrerequisiteError::Cycle);
+        }
+
+        self.skill_repo
+            .add_prerequisite(&mut txn, prerequisite)
+            .await
+            .context("Failed to add skill prerequisite in database")?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn remove_skill_prerequisite(
+        &self,
+        token: &AccessToken,
+        prerequisite: SkillPrerequisite,
+    ) -> Result<(), SkillsRemovePrerequisiteError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .skill_repo
+            .remove_prerequisite(&mut txn, prerequisite)
+            .await
+            .context("Failed to remove skill prerequisite from database")?
+        {
+            return Err(SkillsRemovePrerequisiteError::NotFound);
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn list_courses(
+        &self,
+        skill_id: Option<SkillId>,
+    ) -> Result<Vec<Course>, SkillsListCoursesError> {
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.skill_repo
+            .list_courses(&mut txn, skill_id)
+            .await
+            .context("Failed to get courses from database")
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_course(&self, course_id: CourseId) -> Result<CourseOutline, SkillsGetCourseError> {
+        let mut txn = self.db.begin_transaction().await?;
+
+        let course = self
+            .skill_repo
+            .get_course(&mut txn, course_id)
+            .await
+            .context("Failed to get course from database")?
+            .ok_or(SkillsGetCourseError::NotFound)?;
+
+        let sections = self
+            .skill_repo
+            .list_sections(&mut txn, course_id)
+            .await
+            .context("Failed to get sections from database")?;
+
+        let mut lectures = HashMap::<CourseSectionId, Vec<Lecture>>::new();
+        for lecture in self
+            .skill_repo
+            .list_lectures(&mut txn, course_id)
+            .await
+            .context("Failed to get lectures from database")?
+        {
+            lectures
+                .entry(lecture.section_id)
+                .or_default()
+                .push(lecture);
+        }
+
+        let sections = sections
+            .into_iter()
+            .map(|section| CourseOutlineSection {
+                lectures: lectures.remove(&section.id).unwrap_or_default(),
+                section,
+            })
+            .collect();
+
+        Ok(CourseOutline { course, sections })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn create_course(
+        &self,
+        token: &AccessToken,
+        skill_id: SkillId,
+        request: SkillsCreateCourseRequest,
+    ) -> Result<Course, SkillsCreateCourseError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.skill_repo
+            .get_skill(&mut txn, skill_id)
+            .await
+            .context("Failed to get skill from database")?
+            .ok_or(SkillsCreateCourseError::NotFound)?;
+
+        let course = Course {
+            id: self.id.generate(),
+            skill_id,
+            title: request.title,
+            description: request.description,
+            created_at: self.time.now(),
+        };
+
+        self.skill_repo
+            .create_course(&mut txn, &course)
+            .await
+            .context("Failed to create course in database")?;
+
+        txn.commit().await?;
+
+        Ok(course)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn update_course(
+        &self,
+        token: &AccessToken,
+        course_id: CourseId,
+        patch: CoursePatch,
+    ) -> Result<Course, SkillsUpdateCourseError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let course = self
+            .skill_repo
+            .get_course(&mut txn, course_id)
+            .await
+            .context("Failed to get course from database")?
+            .ok_or(SkillsUpdateCourseError::NotFound)?;
+
+        let patch = patch.minimize(&course);
+        if patch.is_unchanged() {
+            return Ok(course);
+        }
+
+        self.skill_repo
+            .update_course(&mut txn, course_id, patch.as_ref())
+            .await
+            .context("Failed to update course in database")?;
+
+        txn.commit().await?;
+
+        Ok(course.update(patch))
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn delete_course(
+        &self,
+        token: &AccessToken,
+        course_id: CourseId,
+    ) -> Result<(), SkillsDeleteCourseError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .skill_repo
+            .delete_course(&mut txn, course_id)
+            .await
+            .context("Failed to delete course from database")?
+        {
+            return Err(SkillsDeleteCourseError::NotFound);
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn create_section(
+        &self,
+        token: &AccessToken,
+        course_id: CourseId,
+        title: CourseSectionTitle,
+    ) -> Result<CourseSection, SkillsCreateSectionError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.skill_repo
+            .get_course(&mut txn, course_id)
+            .await
+            .context("Failed to get course from database")?
+            .ok_or(SkillsCreateSectionError::NotFound)?;
+
+        let position = self
+            .skill_repo
+            .list_sections(&mut txn, course_id)
+            .await
+            .context("Failed to get sections from database")?
+            .iter()
+            .map(|section| section.position + 1)
+            .max()
+            .unwrap_or(0);
+
+        let section = CourseSection {
+            id: self.id.generate(),
+            course_id,
+            title,
+            position,
+        };
+
+        self.skill_repo
+            .create_section(&mut txn, &section)
+            .await
+            .context("Failed to create section in database")?;
+
+        txn.commit().await?;
+
+        Ok(section)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn update_section(
+        &self,
+        token: &AccessToken,
+        section_id: CourseSectionId,
+        patch: CourseSectionPatch,
+    ) -> Result<CourseSection, SkillsUpdateSectionError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let section = self
+            .skill_repo
+            .get_section(&mut txn, section_id)
+            .await
+            .context("Failed to get section from database")?
+            .ok_or(SkillsUpdateSectionError::NotFound)?;
+
+        let patch = patch.minimize(&section);
+        if patch.is_unchanged() {
+            return Ok(section);
+        }
+
+        self.skill_repo
+            .update_section(&mut txn, section_id, patch.as_ref())
+            .await
+            .context("Failed to update section in database")?;
+
+        txn.commit().await?;
+
+        Ok(section.update(patch))
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn delete_section(
+        &self,
+        token: &AccessToken,
+        section_id: CourseSectionId,
+    ) -> Result<(), SkillsDeleteSectionError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .skill_repo
+            .delete_section(&mut txn, section_id)
+            .await
+            .context("Failed to delete section from database")?
+        {
+            return Err(SkillsDeleteSectionError::NotFound);
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn create_lecture(
+        &self,
+        token: &AccessToken,
+        section_id: CourseSectionId,
+        request: SkillsCreateLectureRequest,
+    ) -> Result<Lecture, SkillsCreateLectureError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let section = self
+            .skill_repo
+            .get_section(&mut txn, section_id)
+            .await
+            .context("Failed to get section from database")?
+            .ok_or(SkillsCreateLectureError::NotFound)?;
+
+        let position = self
+            .skill_repo
+            .list_lectures(&mut txn, section.course_id)
+            .await
+            .context("Failed to get lectures from database")?
+            .iter()
+            .filter(|lecture| lecture.section_id == section_id)
+            .map(|lecture| lecture.position + 1)
+            .max()
+            .unwrap_or(0);
+
+        let lecture = Lecture {
+            id: self.id.generate(),
+            section_id,
+            title: request.title,
+            description: request.description,
+            position,
+        };
+
+        self.skill_repo
+            .create_lecture(&mut txn, &lecture)
+            .await
+            .context("Failed to create lecture in database")?;
+
+        txn.commit().await?;
+
+        Ok(lecture)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn update_lecture(
+        &self,
+        token: &AccessToken,
+        lecture_id: LectureId,
+        patch: LecturePatch,
+    ) -> Result<Lecture, SkillsUpdateLectureError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let lecture = self
+            .skill_repo
+            .get_lecture(&mut txn, lecture_id)
+            .await
+            .context("Failed to get lecture from database")?
+            .ok_or(SkillsUpdateLectureError::NotFound)?;
+
+        let patch = patch.minimize(&lecture);
+        if patch.is_unchanged() {
+            return Ok(lecture);
+        }
+
+        self.skill_repo
+            .update_lecture(&mut txn, lecture_id, patch.as_ref())
+            .await
+            .context("Failed to update lecture in database")?;
+
+        txn.commit().await?;
+
+        Ok(lecture.update(patch))
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn delete_lecture(
+        &self,
+        token: &AccessToken,
+        lecture_id: LectureId,
+    ) -> Result<(), SkillsDeleteLectureError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        auth.ensure_admin().map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .skill_repo
+            .delete_lecture(&mut txn, lecture_id)
+            .await
+            .context("Failed to delete lecture from database")?
+        {
+            return Err(SkillsDeleteLectureError::NotFound);
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn list_skill_progress(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+    ) -> Result<Vec<SkillProgress>, SkillsListSkillProgressError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .user_repo
+            .exists(&mut txn, user_id)
+            .await
+            .context("Failed to check user existence")?
+        {
+            return Err(SkillsListSkillProgressError::NotFound);
+        }
+
+        self.skill_progress
+            .list(&mut txn, user_id)
+            .await
+            .context("Failed to get skill progress")
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_course_progress(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        course_id: CourseId,
+    ) -> Result<CourseProgress, SkillsGetCourseProgressError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.skill_repo
+            .get_course(&mut txn, course_id)
+            .await
+            .context("Failed to get course from database")?
+            .ok_or(SkillsGetCourseProgressError::NotFound)?;
+
+        let total_lectures = self
+            .skill_repo
+            .list_lectures(&mut txn, course_id)
+            .await
+            .context("Failed to get lectures from database")?
+            .len() as u64;
+
+        let completed_lectures = self
+            .skill_repo
+            .list_completed_lectures(&mut txn, user_id, course_id)
+            .await
+            .context("Failed to get completed lectures from database")?;
+
+        Ok(CourseProgress {
+            course_id,
+            progress: LearningProgress {
+                completed_lectures: completed_lectures.len() as u64,
+                total_lectures,
+            },
+            completed_lectures,
+        })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn complete_lecture(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        lecture_id: LectureId,
+    ) -> Result<(), SkillsCompleteLectureError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .user_repo
+            .exists(&mut txn, user_id)
+            .await
+            .context("Failed to check user existence")?
+        {
+            return Err(SkillsCompleteLectureError::UserNotFound);
+        }
+
+        let lecture = self
+            .skill_repo
+            .get_lecture(&mut txn, lecture_id)
+            .await
+            .context("Failed to get lecture from database")?
+            .ok_or(SkillsCompleteLectureError::LectureNotFound)?;
+
+        let section = self
+            .skill_repo
+            .get_section(&mut txn, lecture.section_id)
+            .await
+            .context("Failed to get section from database")?
+            .context("Failed to get section of lecture")?;
+
+        let course = self
+            .skill_repo
+            .get_course(&mut txn, section.course_id)
+            .await
+            .context("Failed to get course from database")?
+            .context("Failed to get course of section")?;
+
+        let unlocked = self
+            .skill_progress
+            .list(&mut txn, user_id)
+            .await
+            .context("Failed to get skill progress")?
+            .into_iter()
+            .any(|progress| progress.skill_id == course.skill_id && progress.unlocked);
+        if !unlocked {
+            return Err(SkillsCompleteLectureError::SkillLocked);
+        }
+
+        self.skill_repo
+            .complete_lecture(&mut txn, user_id, lecture_id, self.time.now())
+            .await
+            .context("Failed to complete lecture in database")?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn reset_lecture(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        lecture_id: LectureId,
+    ) -> Result<(), SkillsResetLectureError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        if !self
+            .skill_repo
+            .reset_lecture(&mut txn, user_id, lecture_id)
+            .await
+            .context("Failed to reset lecture in database")?
+        {
+            return Err(SkillsResetLectureError::NotFound);
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
+
+/// Check whether adding `new` to the skill tree would make a skill depend on
+/// itself.
+fn creates_cycle(prerequisites: &[SkillPrerequisite], new: SkillPrerequisite) -> bool {
+    let mut edges = HashMap::<SkillId, Vec<SkillId>>::new();
+    for p in prerequisites {
+        edges.entry(p.skill_id).or_default().push(p.prerequisite_id);
+    }
+
+    let mut visited = HashSet::new();
+    let mut stack = vec![new.prerequisite_id];
+    while let Some(skill_id) = stack.pop() {
+        if skill_id == new.skill_id {
+            return true;
+        }
+        if visited.insert(skill_id) {
+            stack.extend(edges.get(&skill_id).into_iter().flatten().copied());
+        }
+    }
+
+    false
+}
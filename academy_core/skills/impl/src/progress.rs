@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use academy_core_skills_contracts::progress::SkillProgressService;
+use academy_di::Build;
+use academy_models::{
+    skill::{LearningProgress, SkillId, SkillProgress},
+    user::UserId,
+};
+use academy_persistence_contracts::skill::SkillRepository;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+
+#[derive(Debug, Clone, Build)]
+pub struct SkillProgressServiceImpl<SkillRepo> {
+    skill_repo: SkillRepo,
+}
+
+impl<Txn, SkillRepo> SkillProgressService<Txn> for SkillProgressServiceImpl<SkillRepo>
+where
+    Txn: Send + Sync + 'static,
+    SkillRepo: SkillRepository<Txn>,
+{
+    #[trace_instrument(skip(self, txn))]
+    async fn list(&self, txn: &mut Txn, user_id: UserId) -> anyhow::Result<Vec<SkillProgress>> {
+        let skills = self
+            .skill_repo
+            .list_skills(txn)
+            .await
+            .context("Failed to get skills from database")?;
+
+        let prerequisites = self
+            .skill_repo
+            .list_prerequisites(txn)
+            .await
+            .context("Failed to get skill prerequisites from database")?;
+
+        let course_skills = self
+            .skill_repo
+            .list_courses(txn, None)
+            .await
+            .context("Failed to get courses from database")?
+            .into_iter()
+            .map(|course| (course.id, course.skill_id))
+            .collect::<HashMap<_, _>>();
+
+        let mut progress = HashMap::<SkillId, LearningProgress>::new();
+        for (course_id, course_progress) in self
+            .skill_repo
+            .list_course_progress(txn, user_id)
+            .await
+            .context("Failed to get course progress from database")?
+        {
+            if let Some(&skill_id) = course_skills.get(&course_id) {
+                *progress.entry(skill_id).or_default() += course_progress;
+            }
+        }
+
+        Ok(skills
+            .into_iter()
+            .map(|skill| SkillProgress {
+                skill_id: skill.id,
+                progress: progress.get(&skill.id).copied().unwrap_or_default(),
+                unlocked: prerequisites
+                    .iter()
+                    .filter(|p| p.skill_id == skill.id)
+                    .all(|p| {
+                        progress
+                            .get(&p.prerequisite_id)
+                            .copied()
+                            .unwrap_or_default()
+                            .is_completed()
+                    }),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_demo::{
+        skill::{
+            ADVANCED_PYTHON_COURSE, ALL_COURSES, ALL_PREREQUISITES, ALL_SKILLS, GIT,
+            PYTHON_ADVANCED, PYTHON_BASICS, PYTHON_COURSE,
+        },
+        user::FOO,
+    };
+    use academy_models::skill::CourseId;
+    use academy_persistence_contracts::skill::MockSkillRepository;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn locked() {
+        // Arrange
+        let skill_repo = make_skill_repo(vec![
+            (PYTHON_COURSE.id, make_progress(1, 3)),
+            (ADVANCED_PYTHON_COURSE.id, make_progress(0, 1)),
+        ]);
+
+        let sut = SkillProgressServiceImpl { skill_repo };
+
+        // Act
+        let result = sut.list(&mut (), FOO.user.id).await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            [
+                SkillProgress {
+                    skill_id: PYTHON_BASICS.id,
+                    progress: make_progress(1, 3),
+                    unlocked: true,
+                },
+                SkillProgress {
+                    skill_id: PYTHON_ADVANCED.id,
+                    progress: make_progress(0, 1),
+                    unlocked: false,
+                },
+                SkillProgress {
+                    skill_id: GIT.id,
+                    progress: make_progress(0, 0),
+                    unlocked: true,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn unlocked() {
+        // Arrange
+        let skill_repo = make_skill_repo(vec![
+            (PYTHON_COURSE.id, make_progress(3, 3)),
+            (ADVANCED_PYTHON_COURSE.id, make_progress(0, 1)),
+        ]);
+
+        let sut = SkillProgressServiceImpl { skill_repo };
+
+        // Act
+        let result = sut.list(&mut (), FOO.user.id).await;
+
+        // Assert
+        let result = result.unwrap();
+        assert_eq!(result[0].progress.percent(), 100);
+        assert!(result[1].unlocked);
+    }
+
+    fn make_skill_repo(
+        course_progress: Vec<(CourseId, LearningProgress)>,
+    ) -> MockSkillRepository<()> {
+        MockSkillRepository::new()
+            .with_list_skills(ALL_SKILLS.iter().copied().cloned().collect())
+            .with_list_prerequisites(ALL_PREREQUISITES.clone())
+            .with_list_courses(None, ALL_COURSES.iter().copied().cloned().collect())
+            .with_list_course_progress(FOO.user.id, course_progress)
+    }
+
+    fn make_progress(completed_lectures: u64, total_lectures: u64) -> LearningProgress {
+        LearningProgress {
+            completed_lectures,
+            total_lectures,
+        }
+    }
+}
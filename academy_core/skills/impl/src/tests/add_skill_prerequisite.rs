@@ -0,0 +1,159 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsAddPrerequisiteError, SkillsFeatureService};
+use academy_demo::{
+    session::ADMIN_1,
+    skill::{ALL_PREREQUISITES, GIT, PYTHON_ADVANCED, PYTHON_BASICS},
+    user::ADMIN,
+};
+use academy_models::skill::SkillPrerequisite;
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let prerequisite = SkillPrerequisite {
+        skill_id: PYTHON_ADVANCED.id,
+        prerequisite_id: GIT.id,
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_skill(PYTHON_ADVANCED.id, Some(PYTHON_ADVANCED.clone()))
+        .with_get_skill(GIT.id, Some(GIT.clone()))
+        .with_list_prerequisites(ALL_PREREQUISITES.clone())
+        .with_add_prerequisite(prerequisite, true);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .add_skill_prerequisite(&"token".into(), prerequisite)
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_skill(PYTHON_ADVANCED.id, Some(PYTHON_ADVANCED.clone()))
+        .with_get_skill(GIT.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .add_skill_prerequisite(
+            &"token".into(),
+            SkillPrerequisite {
+                skill_id: PYTHON_ADVANCED.id,
+                prerequisite_id: GIT.id,
+            },
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsAddPrerequisiteError::NotFound));
+}
+
+#[tokio::test]
+async fn cycle() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_skill(GIT.id, Some(GIT.clone()))
+        .with_get_skill(PYTHON_ADVANCED.id, Some(PYTHON_ADVANCED.clone()))
+        .with_list_prerequisites(vec![
+            ALL_PREREQUISITES[0],
+            SkillPrerequisite {
+                skill_id: PYTHON_BASICS.id,
+                prerequisite_id: GIT.id,
+            },
+        ]);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .add_skill_prerequisite(
+            &"token".into(),
+            SkillPrerequisite {
+                skill_id: GIT.id,
+                prerequisite_id: PYTHON_ADVANCED.id,
+            },
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsAddPrerequisiteError::Cycle));
+}
+
+#[tokio::test]
+async fn self_reference() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let mut skill_repo = MockSkillRepository::new();
+    skill_repo
+        .expect_get_skill()
+        .times(2)
+        .returning(|_, _| Box::pin(std::future::ready(Ok(Some(GIT.clone())))));
+    let skill_repo = skill_repo.with_list_prerequisites(ALL_PREREQUISITES.clone());
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .add_skill_prerequisite(
+            &"token".into(),
+            SkillPrerequisite {
+                skill_id: GIT.id,
+                prerequisite_id: GIT.id,
+            },
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsAddPrerequisiteError::Cycle));
+}
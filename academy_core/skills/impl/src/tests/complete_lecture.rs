@@ -0,0 +1,185 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{
+    progress::MockSkillProgressService, SkillsCompleteLectureError, SkillsFeatureService,
+};
+use academy_demo::{
+    session::{ADMIN_1, FOO_1},
+    skill::{
+        ADVANCED_PYTHON_COURSE, ADVANCED_PYTHON_LECTURE, ADVANCED_PYTHON_SECTION, PYTHON_ADVANCED,
+        PYTHON_BASICS, PYTHON_COURSE, PYTHON_LECTURE_2, PYTHON_SECTION_1,
+    },
+    user::{ADMIN, FOO},
+};
+use academy_models::{
+    skill::{LearningProgress, SkillProgress},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{
+    skill::MockSkillRepository, user::MockUserRepository, MockDatabase,
+};
+use academy_shared_contracts::time::MockTimeService;
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let now = FOO.user.created_at;
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let skill_progress = MockSkillProgressService::new().with_list(FOO.user.id, make_progress());
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_lecture(PYTHON_LECTURE_2.id, Some(PYTHON_LECTURE_2.clone()))
+        .with_get_section(PYTHON_SECTION_1.id, Some(PYTHON_SECTION_1.clone()))
+        .with_get_course(PYTHON_COURSE.id, Some(PYTHON_COURSE.clone()))
+        .with_complete_lecture(FOO.user.id, PYTHON_LECTURE_2.id, now, true);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        time,
+        skill_progress,
+        user_repo,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .complete_lecture(&"token".into(), UserIdOrSelf::Slf, PYTHON_LECTURE_2.id)
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn user_not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .complete_lecture(&"token".into(), FOO.user.id.into(), PYTHON_LECTURE_2.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsCompleteLectureError::UserNotFound));
+}
+
+#[tokio::test]
+async fn lecture_not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let skill_repo = MockSkillRepository::new().with_get_lecture(PYTHON_LECTURE_2.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .complete_lecture(&"token".into(), UserIdOrSelf::Slf, PYTHON_LECTURE_2.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsCompleteLectureError::LectureNotFound));
+}
+
+#[tokio::test]
+async fn skill_locked() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_progress = MockSkillProgressService::new().with_list(FOO.user.id, make_progress());
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_lecture(
+            ADVANCED_PYTHON_LECTURE.id,
+            Some(ADVANCED_PYTHON_LECTURE.clone()),
+        )
+        .with_get_section(
+            ADVANCED_PYTHON_SECTION.id,
+            Some(ADVANCED_PYTHON_SECTION.clone()),
+        )
+        .with_get_course(
+            ADVANCED_PYTHON_COURSE.id,
+            Some(ADVANCED_PYTHON_COURSE.clone()),
+        );
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_progress,
+        user_repo,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .complete_lecture(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            ADVANCED_PYTHON_LECTURE.id,
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsCompleteLectureError::SkillLocked));
+}
+
+fn make_progress() -> Vec<SkillProgress> {
+    vec![
+        SkillProgress {
+            skill_id: PYTHON_BASICS.id,
+            progress: LearningProgress {
+                completed_lectures: 1,
+                total_lectures: 3,
+            },
+            unlocked: true,
+        },
+        SkillProgress {
+            skill_id: PYTHON_ADVANCED.id,
+            progress: LearningProgress {
+                completed_lectures: 0,
+                total_lectures: 1,
+            },
+            unlocked: false,
+        },
+    ]
+}
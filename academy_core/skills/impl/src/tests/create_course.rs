@@ -0,0 +1,81 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{
+    SkillsCreateCourseError, SkillsCreateCourseRequest, SkillsFeatureService,
+};
+use academy_demo::{
+    session::ADMIN_1,
+    skill::{PYTHON_BASICS, PYTHON_COURSE},
+    user::ADMIN,
+};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(PYTHON_COURSE.id);
+
+    let time = MockTimeService::new().with_now(PYTHON_COURSE.created_at);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_skill(PYTHON_BASICS.id, Some(PYTHON_BASICS.clone()))
+        .with_create_course(PYTHON_COURSE.clone());
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        id,
+        time,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_course(&"token".into(), PYTHON_BASICS.id, make_request())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *PYTHON_COURSE);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_skill(PYTHON_BASICS.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_course(&"token".into(), PYTHON_BASICS.id, make_request())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsCreateCourseError::NotFound));
+}
+
+fn make_request() -> SkillsCreateCourseRequest {
+    SkillsCreateCourseRequest {
+        title: PYTHON_COURSE.title.clone(),
+        description: PYTHON_COURSE.description.clone(),
+    }
+}
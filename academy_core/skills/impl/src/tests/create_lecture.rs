@@ -0,0 +1,105 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{
+    SkillsCreateLectureError, SkillsCreateLectureRequest, SkillsFeatureService,
+};
+use academy_demo::{
+    session::ADMIN_1,
+    skill::{
+        PYTHON_COURSE, PYTHON_LECTURE_1, PYTHON_LECTURE_2, PYTHON_LECTURE_3, PYTHON_SECTION_2,
+    },
+    user::ADMIN,
+    UUID1,
+};
+use academy_models::skill::Lecture;
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_shared_contracts::id::MockIdService;
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = Lecture {
+        id: UUID1.into(),
+        section_id: PYTHON_SECTION_2.id,
+        title: "Return Values".try_into().unwrap(),
+        description: "".try_into().unwrap(),
+        position: 1,
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(expected.id);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_section(PYTHON_SECTION_2.id, Some(PYTHON_SECTION_2.clone()))
+        .with_list_lectures(
+            PYTHON_COURSE.id,
+            vec![
+                PYTHON_LECTURE_1.clone(),
+                PYTHON_LECTURE_2.clone(),
+                PYTHON_LECTURE_3.clone(),
+            ],
+        )
+        .with_create_lecture(expected.clone());
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        id,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_lecture(
+            &"token".into(),
+            PYTHON_SECTION_2.id,
+            SkillsCreateLectureRequest {
+                title: expected.title.clone(),
+                description: expected.description.clone(),
+            },
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_section(PYTHON_SECTION_2.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_lecture(
+            &"token".into(),
+            PYTHON_SECTION_2.id,
+            SkillsCreateLectureRequest {
+                title: PYTHON_LECTURE_3.title.clone(),
+                description: PYTHON_LECTURE_3.description.clone(),
+            },
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsCreateLectureError::NotFound));
+}
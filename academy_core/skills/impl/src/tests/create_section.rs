@@ -0,0 +1,86 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsCreateSectionError, SkillsFeatureService};
+use academy_demo::{
+    session::ADMIN_1,
+    skill::{PYTHON_COURSE, PYTHON_SECTION_1, PYTHON_SECTION_2},
+    user::ADMIN,
+    UUID1,
+};
+use academy_models::skill::CourseSection;
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_shared_contracts::id::MockIdService;
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = CourseSection {
+        id: UUID1.into(),
+        course_id: PYTHON_COURSE.id,
+        title: "Classes".try_into().unwrap(),
+        position: 2,
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(expected.id);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_course(PYTHON_COURSE.id, Some(PYTHON_COURSE.clone()))
+        .with_list_sections(
+            PYTHON_COURSE.id,
+            vec![PYTHON_SECTION_1.clone(), PYTHON_SECTION_2.clone()],
+        )
+        .with_create_section(expected.clone());
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        id,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_section(&"token".into(), PYTHON_COURSE.id, expected.title.clone())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_course(PYTHON_COURSE.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_section(
+            &"token".into(),
+            PYTHON_COURSE.id,
+            PYTHON_SECTION_1.title.clone(),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsCreateSectionError::NotFound));
+}
@@ -0,0 +1,74 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{
+    SkillsCreateSkillError, SkillsCreateSkillRequest, SkillsFeatureService,
+};
+use academy_demo::{
+    session::{ADMIN_1, FOO_1},
+    skill::GIT,
+    user::{ADMIN, FOO},
+};
+use academy_models::auth::{AuthError, AuthorizeError};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let id = MockIdService::new().with_generate(GIT.id);
+
+    let time = MockTimeService::new().with_now(GIT.created_at);
+
+    let skill_repo = MockSkillRepository::new().with_create_skill(GIT.clone());
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        id,
+        time,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.create_skill(&"token".into(), make_request()).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *GIT);
+}
+
+#[tokio::test]
+async fn not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.create_skill(&"token".into(), make_request()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(SkillsCreateSkillError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+fn make_request() -> SkillsCreateSkillRequest {
+    SkillsCreateSkillRequest {
+        title: GIT.title.clone(),
+        description: GIT.description.clone(),
+    }
+}
@@ -0,0 +1,59 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsDeleteLectureError, SkillsFeatureService};
+use academy_demo::{session::ADMIN_1, skill::PYTHON_LECTURE_1, user::ADMIN};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo = MockSkillRepository::new().with_delete_lecture(PYTHON_LECTURE_1.id, true);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .delete_lecture(&"token".into(), PYTHON_LECTURE_1.id)
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_delete_lecture(PYTHON_LECTURE_1.id, false);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .delete_lecture(&"token".into(), PYTHON_LECTURE_1.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsDeleteLectureError::NotFound));
+}
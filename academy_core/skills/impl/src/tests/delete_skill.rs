@@ -0,0 +1,55 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsDeleteSkillError, SkillsFeatureService};
+use academy_demo::{session::ADMIN_1, skill::GIT, user::ADMIN};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo = MockSkillRepository::new().with_delete_skill(GIT.id, true);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_skill(&"token".into(), GIT.id).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_delete_skill(GIT.id, false);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_skill(&"token".into(), GIT.id).await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsDeleteSkillError::NotFound));
+}
@@ -0,0 +1,78 @@
+use academy_core_skills_contracts::{
+    CourseOutline, CourseOutlineSection, SkillsFeatureService, SkillsGetCourseError,
+};
+use academy_demo::skill::{
+    PYTHON_COURSE, PYTHON_LECTURE_1, PYTHON_LECTURE_2, PYTHON_LECTURE_3, PYTHON_SECTION_1,
+    PYTHON_SECTION_2,
+};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = CourseOutline {
+        course: PYTHON_COURSE.clone(),
+        sections: vec![
+            CourseOutlineSection {
+                section: PYTHON_SECTION_1.clone(),
+                lectures: vec![PYTHON_LECTURE_1.clone(), PYTHON_LECTURE_2.clone()],
+            },
+            CourseOutlineSection {
+                section: PYTHON_SECTION_2.clone(),
+                lectures: vec![PYTHON_LECTURE_3.clone()],
+            },
+        ],
+    };
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_course(PYTHON_COURSE.id, Some(PYTHON_COURSE.clone()))
+        .with_list_sections(
+            PYTHON_COURSE.id,
+            vec![PYTHON_SECTION_1.clone(), PYTHON_SECTION_2.clone()],
+        )
+        .with_list_lectures(
+            PYTHON_COURSE.id,
+            vec![
+                PYTHON_LECTURE_1.clone(),
+                PYTHON_LECTURE_2.clone(),
+                PYTHON_LECTURE_3.clone(),
+            ],
+        );
+
+    let sut = SkillsFeatureServiceImpl {
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_course(PYTHON_COURSE.id).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_course(PYTHON_COURSE.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_course(PYTHON_COURSE.id).await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsGetCourseError::NotFound));
+}
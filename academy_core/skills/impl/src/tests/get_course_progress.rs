@@ -0,0 +1,84 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsFeatureService, SkillsGetCourseProgressError};
+use academy_demo::{
+    session::FOO_1,
+    skill::{PYTHON_COURSE, PYTHON_LECTURE_1, PYTHON_LECTURE_2, PYTHON_LECTURE_3},
+    user::FOO,
+};
+use academy_models::{
+    skill::{CourseProgress, LearningProgress},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = CourseProgress {
+        course_id: PYTHON_COURSE.id,
+        progress: LearningProgress {
+            completed_lectures: 1,
+            total_lectures: 3,
+        },
+        completed_lectures: vec![PYTHON_LECTURE_1.id],
+    };
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_course(PYTHON_COURSE.id, Some(PYTHON_COURSE.clone()))
+        .with_list_lectures(
+            PYTHON_COURSE.id,
+            vec![
+                PYTHON_LECTURE_1.clone(),
+                PYTHON_LECTURE_2.clone(),
+                PYTHON_LECTURE_3.clone(),
+            ],
+        )
+        .with_list_completed_lectures(FOO.user.id, PYTHON_COURSE.id, vec![PYTHON_LECTURE_1.id]);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_course_progress(&"token".into(), UserIdOrSelf::Slf, PYTHON_COURSE.id)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_course(PYTHON_COURSE.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_course_progress(&"token".into(), UserIdOrSelf::Slf, PYTHON_COURSE.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsGetCourseProgressError::NotFound));
+}
@@ -0,0 +1,26 @@
+use academy_core_skills_contracts::SkillsFeatureService;
+use academy_demo::skill::{PYTHON_BASICS, PYTHON_COURSE};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_list_courses(Some(PYTHON_BASICS.id), vec![PYTHON_COURSE.clone()]);
+
+    let sut = SkillsFeatureServiceImpl {
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_courses(Some(PYTHON_BASICS.id)).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), vec![PYTHON_COURSE.clone()]);
+}
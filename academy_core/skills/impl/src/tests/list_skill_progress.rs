@@ -0,0 +1,105 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{
+    progress::MockSkillProgressService, SkillsFeatureService, SkillsListSkillProgressError,
+};
+use academy_demo::{
+    session::{ADMIN_1, BAR_1, FOO_1},
+    skill::PYTHON_BASICS,
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    skill::{LearningProgress, SkillProgress},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = vec![SkillProgress {
+        skill_id: PYTHON_BASICS.id,
+        progress: LearningProgress {
+            completed_lectures: 1,
+            total_lectures: 3,
+        },
+        unlocked: true,
+    }];
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, true);
+
+    let skill_progress = MockSkillProgressService::new().with_list(FOO.user.id, expected.clone());
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_progress,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_skill_progress(&"token".into(), UserIdOrSelf::Slf)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_skill_progress(&"token".into(), FOO.user.id.into())
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(SkillsListSkillProgressError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_exists(FOO.user.id, false);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_skill_progress(&"token".into(), FOO.user.id.into())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsListSkillProgressError::NotFound));
+}
@@ -0,0 +1,32 @@
+use academy_core_skills_contracts::{SkillTree, SkillsFeatureService};
+use academy_demo::skill::{ALL_PREREQUISITES, ALL_SKILLS};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = SkillTree {
+        skills: ALL_SKILLS.iter().copied().cloned().collect(),
+        prerequisites: ALL_PREREQUISITES.clone(),
+    };
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_list_skills(expected.skills.clone())
+        .with_list_prerequisites(expected.prerequisites.clone());
+
+    let sut = SkillsFeatureServiceImpl {
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_skills().await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
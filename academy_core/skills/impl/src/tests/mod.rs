@@ -0,0 +1,40 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::progress::MockSkillProgressService;
+use academy_persistence_contracts::{
+    skill::MockSkillRepository, user::MockUserRepository, MockDatabase, MockTransaction,
+};
+use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+
+use crate::SkillsFeatureServiceImpl;
+
+mod add_skill_prerequisite;
+mod complete_lecture;
+mod create_course;
+mod create_lecture;
+mod create_section;
+mod create_skill;
+mod delete_course;
+mod delete_lecture;
+mod delete_section;
+mod delete_skill;
+mod get_course;
+mod get_course_progress;
+mod list_courses;
+mod list_skill_progress;
+mod list_skills;
+mod remove_skill_prerequisite;
+mod reset_lecture;
+mod update_course;
+mod update_lecture;
+mod update_section;
+mod update_skill;
+
+type Sut = SkillsFeatureServiceImpl<
+    MockDatabase,
+    MockAuthService<MockTransaction>,
+    MockIdService,
+    MockTimeService,
+    MockSkillProgressService<MockTransaction>,
+    MockUserRepository<MockTransaction>,
+    MockSkillRepository<MockTransaction>,
+>;
@@ -0,0 +1,61 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsFeatureService, SkillsRemovePrerequisiteError};
+use academy_demo::{session::ADMIN_1, skill::ALL_PREREQUISITES, user::ADMIN};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo =
+        MockSkillRepository::new().with_remove_prerequisite(ALL_PREREQUISITES[0], true);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .remove_skill_prerequisite(&"token".into(), ALL_PREREQUISITES[0])
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo =
+        MockSkillRepository::new().with_remove_prerequisite(ALL_PREREQUISITES[0], false);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .remove_skill_prerequisite(&"token".into(), ALL_PREREQUISITES[0])
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsRemovePrerequisiteError::NotFound));
+}
@@ -0,0 +1,64 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsFeatureService, SkillsResetLectureError};
+use academy_demo::{
+    session::FOO_1,
+    skill::{PYTHON_LECTURE_1, PYTHON_LECTURE_2},
+    user::FOO,
+};
+use academy_models::user::UserIdOrSelf;
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo =
+        MockSkillRepository::new().with_reset_lecture(FOO.user.id, PYTHON_LECTURE_1.id, true);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .reset_lecture(&"token".into(), UserIdOrSelf::Slf, PYTHON_LECTURE_1.id)
+        .await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo =
+        MockSkillRepository::new().with_reset_lecture(FOO.user.id, PYTHON_LECTURE_2.id, false);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .reset_lecture(&"token".into(), UserIdOrSelf::Slf, PYTHON_LECTURE_2.id)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsResetLectureError::NotFound));
+}
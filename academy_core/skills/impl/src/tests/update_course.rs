@@ -0,0 +1,75 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsFeatureService, SkillsUpdateCourseError};
+use academy_demo::{session::ADMIN_1, skill::PYTHON_COURSE, user::ADMIN};
+use academy_models::skill::{Course, CoursePatch};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = Course {
+        title: "Python for Beginners".try_into().unwrap(),
+        ..PYTHON_COURSE.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_course(PYTHON_COURSE.id, Some(PYTHON_COURSE.clone()))
+        .with_update_course(
+            PYTHON_COURSE.id,
+            CoursePatch::new().update_title(expected.title.clone()),
+            true,
+        );
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_course(
+            &"token".into(),
+            PYTHON_COURSE.id,
+            CoursePatch::new().update_title(expected.title.clone()),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_course(PYTHON_COURSE.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_course(&"token".into(), PYTHON_COURSE.id, CoursePatch::new())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsUpdateCourseError::NotFound));
+}
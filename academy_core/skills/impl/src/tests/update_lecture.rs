@@ -0,0 +1,75 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsFeatureService, SkillsUpdateLectureError};
+use academy_demo::{session::ADMIN_1, skill::PYTHON_LECTURE_1, user::ADMIN};
+use academy_models::skill::{Lecture, LecturePatch};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = Lecture {
+        description: "Download and install Python".try_into().unwrap(),
+        ..PYTHON_LECTURE_1.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_lecture(PYTHON_LECTURE_1.id, Some(PYTHON_LECTURE_1.clone()))
+        .with_update_lecture(
+            PYTHON_LECTURE_1.id,
+            LecturePatch::new().update_description(expected.description.clone()),
+            true,
+        );
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_lecture(
+            &"token".into(),
+            PYTHON_LECTURE_1.id,
+            LecturePatch::new().update_description(expected.description.clone()),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_lecture(PYTHON_LECTURE_1.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_lecture(&"token".into(), PYTHON_LECTURE_1.id, LecturePatch::new())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsUpdateLectureError::NotFound));
+}
@@ -0,0 +1,79 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsFeatureService, SkillsUpdateSectionError};
+use academy_demo::{session::ADMIN_1, skill::PYTHON_SECTION_1, user::ADMIN};
+use academy_models::skill::{CourseSection, CourseSectionPatch};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = CourseSection {
+        title: "Setup".try_into().unwrap(),
+        ..PYTHON_SECTION_1.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_section(PYTHON_SECTION_1.id, Some(PYTHON_SECTION_1.clone()))
+        .with_update_section(
+            PYTHON_SECTION_1.id,
+            CourseSectionPatch::new().update_title(expected.title.clone()),
+            true,
+        );
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_section(
+            &"token".into(),
+            PYTHON_SECTION_1.id,
+            CourseSectionPatch::new().update_title(expected.title.clone()),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_section(PYTHON_SECTION_1.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_section(
+            &"token".into(),
+            PYTHON_SECTION_1.id,
+            CourseSectionPatch::new(),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsUpdateSectionError::NotFound));
+}
@@ -0,0 +1,107 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_skills_contracts::{SkillsFeatureService, SkillsUpdateSkillError};
+use academy_demo::{session::ADMIN_1, skill::GIT, user::ADMIN};
+use academy_models::skill::{Skill, SkillPatch};
+use academy_persistence_contracts::{skill::MockSkillRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, SkillsFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let expected = Skill {
+        description: "Version control".try_into().unwrap(),
+        ..GIT.clone()
+    };
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let skill_repo = MockSkillRepository::new()
+        .with_get_skill(GIT.id, Some(GIT.clone()))
+        .with_update_skill(
+            GIT.id,
+            SkillPatch::new().update_description(expected.description.clone()),
+            true,
+        );
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_skill(
+            &"token".into(),
+            GIT.id,
+            SkillPatch::new()
+                .update_title(GIT.title.clone())
+                .update_description(expected.description.clone()),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_unchanged() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_skill(GIT.id, Some(GIT.clone()));
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_skill(
+            &"token".into(),
+            GIT.id,
+            SkillPatch::new().update_title(GIT.title.clone()),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *GIT);
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let skill_repo = MockSkillRepository::new().with_get_skill(GIT.id, None);
+
+    let sut = SkillsFeatureServiceImpl {
+        auth,
+        db,
+        skill_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_skill(&"token".into(), GIT.id, SkillPatch::new())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(SkillsUpdateSkillError::NotFound));
+}
@@ -0,0 +1,80 @@
+use std::future::Future;
+
+use academy_models::{url::Url, user::UserId};
+use thiserror::Error;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait UserAvatarService: Send + Sync + 'static {
+    /// Validate the uploaded image, generate thumbnails in all configured
+    /// sizes and store them, replacing any existing avatar of the user.
+    ///
+    /// Returns the public url of the new avatar.
+    fn store(
+        &self,
+        user_id: UserId,
+        data: Vec<u8>,
+    ) -> impl Future<Output = Result<Url, UserAvatarStoreError>> + Send;
+
+    /// Remove all stored thumbnails of a user's avatar.
+    fn remove(&self, user_id: UserId) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Return the PNG encoded avatar of a user.
+    ///
+    /// Selects the smallest thumbnail that is at least as large as the
+    /// requested `size` or the largest thumbnail if no `size` is given.
+    fn get(
+        &self,
+        user_id: UserId,
+        size: Option<u32>,
+    ) -> impl Future<Output = anyhow::Result<Option<Vec<u8>>>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum UserAvatarStoreError {
+    #[error("The image is too large.")]
+    TooLarge,
+    #[error("The image format is not supported.")]
+    UnsupportedFormat,
+    #[error("The image is invalid.")]
+    InvalidImage,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[cfg(feature = "mock")]
+impl MockUserAvatarService {
+    pub fn with_store(
+        mut self,
+        user_id: UserId,
+        data: Vec<u8>,
+        result: Result<Url, UserAvatarStoreError>,
+    ) -> Self {
+        self.expect_store()
+            .once()
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(result)));
+        self
+    }
+
+    pub fn with_remove(mut self, user_id: UserId) -> Self {
+        self.expect_remove()
+            .once()
+            .with(mockall::predicate::eq(user_id))
+            .return_once(|_| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_get(mut self, user_id: UserId, size: Option<u32>, result: Option<Vec<u8>>) -> Self {
+        self.expect_get()
+            .once()
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(size),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
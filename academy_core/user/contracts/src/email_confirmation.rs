@@ -2,6 +2,7 @@ use std::future::Future;
 
 use academy_models::{
     email_address::EmailAddressWithName,
+    locale::Locale,
     user::{UserComposite, UserId, UserPassword},
     VerificationCode,
 };
@@ -12,7 +13,9 @@ pub trait UserEmailConfirmationService<Txn: Send + Sync + 'static>: Send + Sync
     /// Send a verification email to verify a user's email address.
     fn request_verification(
         &self,
+        txn: &mut Txn,
         email: EmailAddressWithName,
+        locale: &Locale,
     ) -> impl Future<Output = anyhow::Result<()>> + Send;
 
     /// Verify a user's email address.
@@ -25,8 +28,10 @@ pub trait UserEmailConfirmationService<Txn: Send + Sync + 'static>: Send + Sync
     /// Send a verification email to reset a user's password.
     fn request_password_reset(
         &self,
+        txn: &mut Txn,
         user_id: UserId,
         email: EmailAddressWithName,
+        locale: &Locale,
     ) -> impl Future<Output = anyhow::Result<()>> + Send;
 
     /// Reset a user's password.
@@ -41,8 +46,10 @@ pub trait UserEmailConfirmationService<Txn: Send + Sync + 'static>: Send + Sync
     /// Send a verification email to confirm a user's newsletter subscription.
     fn request_newsletter_subscription(
         &self,
+        txn: &mut Txn,
         user_id: UserId,
         email: EmailAddressWithName,
+        locale: &Locale,
     ) -> impl Future<Output = anyhow::Result<()>> + Send;
 
     /// Confirm a user's newsletter subscription.
@@ -82,11 +89,19 @@ pub enum UserEmailConfirmationSubscribeToNewsletterError {
 
 #[cfg(feature = "mock")]
 impl<Txn: Send + Sync + 'static> MockUserEmailConfirmationService<Txn> {
-    pub fn with_request_verification(mut self, email: EmailAddressWithName) -> Self {
+    pub fn with_request_verification(
+        mut self,
+        email: EmailAddressWithName,
+        locale: Locale,
+    ) -> Self {
         self.expect_request_verification()
             .once()
-            .with(mockall::predicate::eq(email))
-            .return_once(|_| Box::pin(std::future::ready(Ok(()))));
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(email),
+                mockall::predicate::eq(locale),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(()))));
         self
     }
 
@@ -109,14 +124,17 @@ impl<Txn: Send + Sync + 'static> MockUserEmailConfirmationService<Txn> {
         mut self,
         user_id: UserId,
         email: EmailAddressWithName,
+        locale: Locale,
     ) -> Self {
         self.expect_request_password_reset()
             .once()
             .with(
+                mockall::predicate::always(),
                 mockall::predicate::eq(user_id),
                 mockall::predicate::eq(email),
+                mockall::predicate::eq(locale),
             )
-            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
         self
     }
 
@@ -143,14 +161,17 @@ impl<Txn: Send + Sync + 'static> MockUserEmailConfirmationService<Txn> {
         mut self,
         user_id: UserId,
         email: EmailAddressWithName,
+        locale: Locale,
     ) -> Self {
         self.expect_request_newsletter_subscription()
             .once()
             .with(
+                mockall::predicate::always(),
                 mockall::predicate::eq(user_id),
                 mockall::predicate::eq(email),
+                mockall::predicate::eq(locale),
             )
-            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
         self
     }
 
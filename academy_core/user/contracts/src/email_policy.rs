@@ -0,0 +1,40 @@
+use std::future::Future;
+
+use academy_models::email_address::EmailAddress;
+use thiserror::Error;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait UserEmailPolicyService: Send + Sync + 'static {
+    /// Check whether the given email address may be used for a user account.
+    ///
+    /// Rejects addresses whose domain is not on the allow list (if
+    /// configured), is on the deny list, belongs to a known disposable email
+    /// provider or (if enabled) does not have any MX records.
+    fn check(
+        &self,
+        email: &EmailAddress,
+    ) -> impl Future<Output = Result<(), UserEmailPolicyCheckError>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum UserEmailPolicyCheckError {
+    #[error("The email address is not allowed.")]
+    Rejected,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[cfg(feature = "mock")]
+impl MockUserEmailPolicyService {
+    pub fn with_check(
+        mut self,
+        email: EmailAddress,
+        result: Result<(), UserEmailPolicyCheckError>,
+    ) -> Self {
+        self.expect_check()
+            .once()
+            .with(mockall::predicate::eq(email))
+            .return_once(|_| Box::pin(std::future::ready(result)));
+        self
+    }
+}
@@ -0,0 +1,87 @@
+use std::future::Future;
+
+use academy_models::{
+    invite::{Invite, InviteCode, InviteId, InviteMaxUses},
+    user::{UserId, UserTag},
+};
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait UserInviteService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Create a new invite with a random code.
+    fn create(
+        &self,
+        txn: &mut Txn,
+        cmd: UserInviteCreateCommand,
+    ) -> impl Future<Output = anyhow::Result<Invite>> + Send;
+
+    /// Return the invite with the given code if it can still be used to
+    /// register a new user.
+    fn get_valid(
+        &self,
+        txn: &mut Txn,
+        code: &InviteCode,
+    ) -> impl Future<Output = anyhow::Result<Option<Invite>>> + Send;
+
+    /// Record that the given user has registered using the invite.
+    fn redeem(
+        &self,
+        txn: &mut Txn,
+        invite_id: InviteId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<(), UserInviteRedeemError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserInviteCreateCommand {
+    pub created_by: Option<UserId>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_uses: Option<InviteMaxUses>,
+    pub admin: bool,
+    pub cohort: Option<UserTag>,
+}
+
+#[derive(Debug, Error)]
+pub enum UserInviteRedeemError {
+    #[error("The invite has expired or its usage limit has been reached.")]
+    Invalid,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockUserInviteService<Txn> {
+    pub fn with_create(mut self, cmd: UserInviteCreateCommand, result: Invite) -> Self {
+        self.expect_create()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(cmd))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_valid(mut self, code: InviteCode, result: Option<Invite>) -> Self {
+        self.expect_get_valid()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(code))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_redeem(
+        mut self,
+        invite_id: InviteId,
+        user_id: UserId,
+        result: Result<(), UserInviteRedeemError>,
+    ) -> Self {
+        self.expect_redeem()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(invite_id),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(result)));
+        self
+    }
+}
@@ -3,11 +3,14 @@ use std::future::Future;
 use academy_models::{
     auth::{AccessToken, AuthError, Login},
     email_address::EmailAddress,
+    invite::{Invite, InviteCode, InviteId, InviteMaxUses},
+    locale::Locale,
     oauth2::OAuth2RegistrationToken,
     session::DeviceName,
     user::{
-        UserComposite, UserDisplayName, UserIdOrSelf, UserInvoiceInfo, UserName, UserPassword,
-        UserProfilePatch,
+        NewsletterUnsubscribeToken, UserComposite, UserDisplayName, UserId, UserIdOrSelf,
+        UserInvoiceInfo, UserName, UserPassword, UserPrivacy, UserPrivacyPatch, UserProfilePatch,
+        UserPublicProfile, UserTag,
     },
     RecaptchaResponse, VerificationCode,
 };
@@ -16,9 +19,14 @@ use chrono::{DateTime, Utc};
 use thiserror::Error;
 use user::{UserListQuery, UserListResult};
 
+pub mod avatar;
 pub mod email_confirmation;
+pub mod email_policy;
+pub mod invite;
+pub mod newsletter;
 pub mod update;
 pub mod user;
+pub mod vat;
 
 pub trait UserFeatureService: Send + Sync + 'static {
     /// Return all users matching the given query.
@@ -40,6 +48,11 @@ pub trait UserFeatureService: Send + Sync + 'static {
     ) -> impl Future<Output = Result<UserComposite, UserGetError>> + Send;
 
     /// Create a new user and logs them in.
+    ///
+    /// Depending on the configured registration mode, a valid invite code may
+    /// be required. If an invite code is provided, the new user is granted the
+    /// admin privileges and cohort tag of the invite. If no locale is
+    /// provided, the configured default locale is used.
     fn create_user(
         &self,
         request: UserCreateRequest,
@@ -107,6 +120,15 @@ pub trait UserFeatureService: Send + Sync + 'static {
         code: VerificationCode,
     ) -> impl Future<Output = Result<UserComposite, UserVerifyNewsletterSubscriptionError>> + Send;
 
+    /// Unsubscribe a user from the newsletter using the signed token included
+    /// in newsletter emails.
+    ///
+    /// Does not require authentication.
+    fn unsubscribe_from_newsletter(
+        &self,
+        token: &NewsletterUnsubscribeToken,
+    ) -> impl Future<Output = Result<(), UserUnsubscribeFromNewsletterError>> + Send;
+
     /// Request an email with a verification code to reset a user's password.
     fn request_password_reset(
         &self,
@@ -121,10 +143,112 @@ pub trait UserFeatureService: Send + Sync + 'static {
         code: VerificationCode,
         new_password: UserPassword,
     ) -> impl Future<Output = Result<UserComposite, UserResetPasswordError>> + Send;
+
+    /// Return the public profile of the user with the given name.
+    ///
+    /// Each field is only included if the user's privacy settings allow the
+    /// visitor to see it. Unauthenticated visitors can only see public fields.
+    /// The user themselves and administrators can always see all fields.
+    /// Disabled users are only visible to administrators.
+    fn get_public_profile(
+        &self,
+        token: Option<&AccessToken>,
+        name: UserName,
+    ) -> impl Future<Output = Result<UserPublicProfile, UserGetPublicProfileError>> + Send;
+
+    /// Return the privacy settings of a user.
+    ///
+    /// Requires admin privileges if not used on the authenticated user.
+    fn get_privacy(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+    ) -> impl Future<Output = Result<UserPrivacy, UserGetPrivacyError>> + Send;
+
+    /// Update the privacy settings of a user.
+    ///
+    /// Requires admin privileges if not used on the authenticated user.
+    fn update_privacy(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        patch: UserPrivacyPatch,
+    ) -> impl Future<Output = Result<UserPrivacy, UserUpdatePrivacyError>> + Send;
+
+    /// Upload a new avatar image for a user, replacing the existing one.
+    ///
+    /// Supported formats are PNG, JPEG and WebP.
+    ///
+    /// Requires admin privileges if not used on the authenticated user.
+    fn upload_avatar(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        data: Vec<u8>,
+    ) -> impl Future<Output = Result<UserComposite, UserUploadAvatarError>> + Send;
+
+    /// Remove a user's avatar image.
+    ///
+    /// Requires admin privileges if not used on the authenticated user.
+    fn delete_avatar(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+    ) -> impl Future<Output = Result<UserComposite, UserDeleteAvatarError>> + Send;
+
+    /// Return the PNG encoded avatar image of a user.
+    ///
+    /// The avatar is only returned if the user's privacy settings allow the
+    /// visitor to see it, following the same rules as
+    /// [`get_public_profile`](Self::get_public_profile).
+    fn get_avatar(
+        &self,
+        token: Option<&AccessToken>,
+        user_id: UserId,
+        size: Option<u32>,
+    ) -> impl Future<Output = Result<Vec<u8>, UserGetAvatarError>> + Send;
+
+    /// Return all invites created by the given user or, if no user is
+    /// specified, all invites.
+    ///
+    /// Requires admin privileges if not used on the authenticated user.
+    fn list_invites(
+        &self,
+        token: &AccessToken,
+        created_by: Option<UserIdOrSelf>,
+    ) -> impl Future<Output = Result<Vec<Invite>, UserListInvitesError>> + Send;
+
+    /// Create a new invite.
+    ///
+    /// If the authenticated user is not an administrator:
+    /// - The number of valid invites per user is limited.
+    /// - The invite can be used only once and expires after a fixed duration.
+    /// - Setting any of the following fields is not allowed:
+    ///   - `expires_at`
+    ///   - `max_uses`
+    ///   - `admin`
+    ///   - `cohort`
+    fn create_invite(
+        &self,
+        token: &AccessToken,
+        request: UserCreateInviteRequest,
+    ) -> impl Future<Output = Result<Invite, UserCreateInviteError>> + Send;
+
+    /// Delete an invite.
+    ///
+    /// Requires admin privileges if the invite has not been created by the
+    /// authenticated user.
+    fn delete_invite(
+        &self,
+        token: &AccessToken,
+        invite_id: InviteId,
+    ) -> impl Future<Output = Result<(), UserDeleteInviteError>> + Send;
 }
 
 #[derive(Debug, Error)]
 pub enum UserListError {
+    #[error("The pagination cursor is invalid, has expired or does not match the sorting.")]
+    InvalidCursor,
     #[error(transparent)]
     Auth(#[from] AuthError),
     #[error(transparent)]
@@ -148,6 +272,8 @@ pub struct UserCreateRequest {
     pub email: EmailAddress,
     pub password: Option<UserPassword>,
     pub oauth2_registration_token: Option<OAuth2RegistrationToken>,
+    pub invite_code: Option<InviteCode>,
+    pub locale: Option<Locale>,
 }
 
 #[derive(Debug, Error)]
@@ -164,6 +290,14 @@ pub enum UserCreateError {
     InvalidOAuthRegistrationToken,
     #[error("The remote user has already been linked.")]
     RemoteAlreadyLinked,
+    #[error("Registration is currently disabled.")]
+    RegistrationClosed,
+    #[error("An invite code is required to register.")]
+    InviteCodeRequired,
+    #[error("The invite code is invalid, has expired or has already been used up.")]
+    InvalidInviteCode,
+    #[error("The email address is not allowed.")]
+    EmailNotAllowed,
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -184,6 +318,7 @@ pub struct UserUpdateUserRequest {
     pub enabled: PatchValue<bool>,
     pub admin: PatchValue<bool>,
     pub newsletter: PatchValue<bool>,
+    pub locale: PatchValue<Locale>,
 }
 
 #[derive(Debug)]
@@ -202,6 +337,8 @@ pub enum UserUpdateError {
     NameConflict,
     #[error("A user with the same email address already exists.")]
     EmailConflict,
+    #[error("The email address is not allowed.")]
+    EmailNotAllowed,
     #[error(
         "The password cannot be removed from the user because they don't have any other login \
          methods."
@@ -217,6 +354,10 @@ pub enum UserUpdateError {
     NoEmail,
     #[error("The vat id is invalid.")]
     InvalidVatId,
+    #[error("The zip code is not valid in the user's country.")]
+    InvalidZipCode,
+    #[error("The vat id has not been issued by the user's country.")]
+    VatIdCountryMismatch,
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -267,6 +408,14 @@ pub enum UserVerifyNewsletterSubscriptionError {
     Other(#[from] anyhow::Error),
 }
 
+#[derive(Debug, Error)]
+pub enum UserUnsubscribeFromNewsletterError {
+    #[error("The unsubscribe token is invalid.")]
+    InvalidToken,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
 #[derive(Debug, Error)]
 pub enum UserRequestPasswordResetError {
     #[error("Invalid recaptcha response")]
@@ -282,3 +431,105 @@ pub enum UserResetPasswordError {
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
+
+#[derive(Debug, Error)]
+pub enum UserGetPublicProfileError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum UserGetPrivacyError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum UserUpdatePrivacyError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum UserUploadAvatarError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error("The image is too large.")]
+    TooLarge,
+    #[error("The image format is not supported.")]
+    UnsupportedFormat,
+    #[error("The image is invalid.")]
+    InvalidImage,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum UserDeleteAvatarError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum UserGetAvatarError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user does not have an avatar.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum UserListInvitesError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Default)]
+pub struct UserCreateInviteRequest {
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_uses: Option<InviteMaxUses>,
+    pub admin: bool,
+    pub cohort: Option<UserTag>,
+}
+
+#[derive(Debug, Error)]
+pub enum UserCreateInviteError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The user has reached the maximum number of valid invites.")]
+    TooManyInvites,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum UserDeleteInviteError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error("The invite does not exist.")]
+    NotFound,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
@@ -0,0 +1,52 @@
+use academy_models::{
+    url::Url,
+    user::{NewsletterUnsubscribeToken, UserId},
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait UserNewsletterService: Send + Sync + 'static {
+    /// Return the urls which allow the given user to unsubscribe from the
+    /// newsletter without logging in.
+    fn unsubscribe_urls(&self, user_id: UserId) -> anyhow::Result<UserNewsletterUnsubscribeUrls>;
+
+    /// Verify the signature of an unsubscribe token and return the id of the
+    /// user it has been issued for.
+    fn verify_unsubscribe_token(&self, token: &NewsletterUnsubscribeToken) -> Option<UserId>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserNewsletterUnsubscribeUrls {
+    /// Link to the unsubscribe page of the frontend, to be included in the
+    /// body of newsletter emails.
+    pub link: Url,
+    /// Endpoint for one-click unsubscription, to be used in the
+    /// `List-Unsubscribe` header of newsletter emails.
+    pub one_click: Url,
+}
+
+#[cfg(feature = "mock")]
+impl MockUserNewsletterService {
+    pub fn with_unsubscribe_urls(
+        mut self,
+        user_id: UserId,
+        result: UserNewsletterUnsubscribeUrls,
+    ) -> Self {
+        self.expect_unsubscribe_urls()
+            .once()
+            .with(mockall::predicate::eq(user_id))
+            .return_once(|_| Ok(result));
+        self
+    }
+
+    pub fn with_verify_unsubscribe_token(
+        mut self,
+        token: NewsletterUnsubscribeToken,
+        result: Option<UserId>,
+    ) -> Self {
+        self.expect_verify_unsubscribe_token()
+            .once()
+            .with(mockall::predicate::eq(token))
+            .return_once(move |_| result);
+        self
+    }
+}
@@ -2,13 +2,30 @@ use std::future::Future;
 
 use academy_models::{
     email_address::EmailAddress,
-    user::{User, UserId, UserInvoiceInfo, UserInvoiceInfoPatch, UserName, UserPassword},
+    user::{
+        User, UserComposite, UserId, UserInvoiceInfo, UserInvoiceInfoPatch, UserName, UserPassword,
+        UserVatIdValidation,
+    },
 };
+use academy_utils::patch::PatchValue;
 use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 #[cfg_attr(feature = "mock", mockall::automock)]
 pub trait UserUpdateService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Update a user's account data.
+    ///
+    /// Values which would not change the user are skipped. Changing the email
+    /// address resets its verification status, unless `email_verified` is
+    /// updated explicitly.
+    fn update_account(
+        &self,
+        txn: &mut Txn,
+        user_composite: UserComposite,
+        update: UserAccountUpdate,
+        rate_limit_policy: UserUpdateNameRateLimitPolicy,
+    ) -> impl Future<Output = Result<UserComposite, UserUpdateAccountError>> + Send;
+
     /// Update a user's name.
     fn update_name(
         &self,
@@ -52,15 +69,56 @@ pub trait UserUpdateService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
     ) -> impl Future<Output = anyhow::Result<bool>> + Send;
 
     /// Update a user's invoice information.
+    ///
+    /// `vat_id_validation` is the result of validating the new VAT id and is
+    /// only saved if the patch updates the VAT id.
     fn update_invoice_info(
         &self,
         txn: &mut Txn,
         user_id: UserId,
         invoice_info: UserInvoiceInfo,
         patch: UserInvoiceInfoPatch,
+        vat_id_validation: Option<UserVatIdValidation>,
     ) -> impl Future<Output = anyhow::Result<UserInvoiceInfo>> + Send;
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserAccountUpdate {
+    pub name: PatchValue<UserName>,
+    pub email: PatchValue<Option<EmailAddress>>,
+    pub email_verified: PatchValue<bool>,
+    pub enabled: PatchValue<bool>,
+    pub admin: PatchValue<bool>,
+    pub password: PatchValue<UserPassword>,
+}
+
+impl UserAccountUpdate {
+    /// Remove all values which would not change the given user.
+    pub fn minimize(self, user: &User) -> Self {
+        let email = self.email.minimize(&user.email);
+        let email_verified = self
+            .email_verified
+            .minimize(&(user.email_verified && email.is_unchanged()));
+        Self {
+            name: self.name.minimize(&user.name),
+            email,
+            email_verified,
+            enabled: self.enabled.minimize(&user.enabled),
+            admin: self.admin.minimize(&user.admin),
+            password: self.password,
+        }
+    }
+
+    pub fn is_update(&self) -> bool {
+        self.name.is_update()
+            || self.email.is_update()
+            || self.email_verified.is_update()
+            || self.enabled.is_update()
+            || self.admin.is_update()
+            || self.password.is_update()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UserUpdateNameRateLimitPolicy {
     Enforce,
@@ -77,6 +135,18 @@ pub enum UserUpdateNameError {
     Other(#[from] anyhow::Error),
 }
 
+#[derive(Debug, Error)]
+pub enum UserUpdateAccountError {
+    #[error("The user cannot change their name until {until}.")]
+    NameChangeRateLimit { until: DateTime<Utc> },
+    #[error("A user with the same name already exists.")]
+    NameConflict,
+    #[error("A user with the same email address already exists.")]
+    EmailConflict,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
 #[derive(Debug, Error)]
 pub enum UserUpdateEmailError {
     #[error("A user with the same email address already exists.")]
@@ -87,6 +157,25 @@ pub enum UserUpdateEmailError {
 
 #[cfg(feature = "mock")]
 impl<Txn: Send + Sync + 'static> MockUserUpdateService<Txn> {
+    pub fn with_update_account(
+        mut self,
+        user_composite: UserComposite,
+        update: UserAccountUpdate,
+        rate_limit_policy: UserUpdateNameRateLimitPolicy,
+        result: Result<UserComposite, UserUpdateAccountError>,
+    ) -> Self {
+        self.expect_update_account()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_composite),
+                mockall::predicate::eq(update),
+                mockall::predicate::eq(rate_limit_policy),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(result)));
+        self
+    }
+
     pub fn with_update_name(
         mut self,
         user: User,
@@ -166,6 +255,7 @@ impl<Txn: Send + Sync + 'static> MockUserUpdateService<Txn> {
         user_id: UserId,
         invoice_info: UserInvoiceInfo,
         patch: UserInvoiceInfoPatch,
+        vat_id_validation: Option<UserVatIdValidation>,
         result: UserInvoiceInfo,
     ) -> Self {
         self.expect_update_invoice_info()
@@ -175,8 +265,9 @@ impl<Txn: Send + Sync + 'static> MockUserUpdateService<Txn> {
                 mockall::predicate::eq(user_id),
                 mockall::predicate::eq(invoice_info),
                 mockall::predicate::eq(patch),
+                mockall::predicate::eq(vat_id_validation),
             )
-            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(result))));
+            .return_once(|_, _, _, _, _| Box::pin(std::future::ready(Ok(result))));
         self
     }
 }
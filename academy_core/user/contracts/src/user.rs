@@ -2,20 +2,27 @@ use std::future::Future;
 
 use academy_models::{
     email_address::EmailAddress,
+    locale::Locale,
     oauth2::OAuth2Registration,
-    pagination::PaginationSlice,
-    user::{UserComposite, UserDisplayName, UserFilter, UserName, UserPassword},
+    pagination::{Pagination, PaginationCursor},
+    user::{
+        UserComposite, UserDisplayName, UserFilter, UserId, UserName, UserPassword, UserSorting,
+        UserTags,
+    },
 };
 use thiserror::Error;
 
 #[cfg_attr(feature = "mock", mockall::automock)]
 pub trait UserService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
     /// Return a paginated and filtered list of all users.
+    ///
+    /// If the page is full, the result contains a cursor which can be used to
+    /// request the next page.
     fn list(
         &self,
         txn: &mut Txn,
         query: UserListQuery,
-    ) -> impl Future<Output = anyhow::Result<UserListResult>> + Send;
+    ) -> impl Future<Output = Result<UserListResult, UserListError>> + Send;
 
     /// Create a new user.
     fn create(
@@ -23,18 +30,40 @@ pub trait UserService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
         txn: &mut Txn,
         cmd: UserCreateCommand,
     ) -> impl Future<Output = Result<UserComposite, UserCreateError>> + Send;
+
+    /// Delete a user and invalidate all of their access tokens.
+    ///
+    /// Returns `false` if the user does not exist. The avatar of the user is
+    /// not part of the transaction and has to be removed separately using
+    /// [`UserAvatarService::remove`](crate::avatar::UserAvatarService::remove)
+    /// after the transaction has been committed.
+    fn delete(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct UserListQuery {
-    pub pagination: PaginationSlice,
+    pub pagination: Pagination<PaginationCursor>,
     pub filter: UserFilter,
+    pub sorting: UserSorting,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UserListResult {
     pub total: u64,
     pub user_composites: Vec<UserComposite>,
+    pub next_cursor: Option<PaginationCursor>,
+}
+
+#[derive(Debug, Error)]
+pub enum UserListError {
+    #[error("The pagination cursor is invalid, has expired or does not match the sorting.")]
+    InvalidCursor,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -47,6 +76,8 @@ pub struct UserCreateCommand {
     pub enabled: bool,
     pub email_verified: bool,
     pub oauth2_registration: Option<OAuth2Registration>,
+    pub tags: UserTags,
+    pub locale: Locale,
 }
 
 #[derive(Debug, Error)]
@@ -63,11 +94,15 @@ pub enum UserCreateError {
 
 #[cfg(feature = "mock")]
 impl<Txn: Send + Sync + 'static> MockUserService<Txn> {
-    pub fn with_list(mut self, query: UserListQuery, result: UserListResult) -> Self {
+    pub fn with_list(
+        mut self,
+        query: UserListQuery,
+        result: Result<UserListResult, UserListError>,
+    ) -> Self {
         self.expect_list()
             .once()
             .with(mockall::predicate::always(), mockall::predicate::eq(query))
-            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+            .return_once(|_, _| Box::pin(std::future::ready(result)));
         self
     }
 
@@ -82,4 +117,15 @@ impl<Txn: Send + Sync + 'static> MockUserService<Txn> {
             .return_once(|_, _| Box::pin(std::future::ready(result)));
         self
     }
+
+    pub fn with_delete(mut self, user_id: UserId, result: bool) -> Self {
+        self.expect_delete()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
 }
@@ -0,0 +1,40 @@
+use std::future::Future;
+
+use academy_models::user::{UserVatId, UserVatIdValidation};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait UserVatService: Send + Sync + 'static {
+    /// Validate the given VAT id, reusing a recent result if available.
+    ///
+    /// If VIES is currently unavailable, the validation is reported as
+    /// pending.
+    fn validate(
+        &self,
+        vat_id: &UserVatId,
+    ) -> impl Future<Output = anyhow::Result<UserVatIdValidation>> + Send;
+
+    /// Validate the given VAT id without reusing a previous result.
+    fn revalidate(
+        &self,
+        vat_id: &UserVatId,
+    ) -> impl Future<Output = anyhow::Result<UserVatIdValidation>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl MockUserVatService {
+    pub fn with_validate(mut self, vat_id: UserVatId, result: UserVatIdValidation) -> Self {
+        self.expect_validate()
+            .once()
+            .with(mockall::predicate::eq(vat_id))
+            .return_once(|_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_revalidate(mut self, vat_id: UserVatId, result: UserVatIdValidation) -> Self {
+        self.expect_revalidate()
+            .once()
+            .with(mockall::predicate::eq(vat_id))
+            .return_once(|_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
@@ -0,0 +1,296 @@
+use academy_core_user_contracts::avatar::{UserAvatarService, UserAvatarStoreError};
+use academy_di::Build;
+use academy_models::{url::Url, user::UserId};
+use academy_shared_contracts::{
+    image::{ImageError, ImageService},
+    time::TimeService,
+};
+use academy_storage_contracts::StorageService;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+
+use crate::UserFeatureConfig;
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct UserAvatarServiceImpl<Time, Image, Storage> {
+    time: Time,
+    image: Image,
+    storage: Storage,
+    config: UserFeatureConfig,
+}
+
+impl<Time, Image, Storage> UserAvatarService for UserAvatarServiceImpl<Time, Image, Storage>
+where
+    Time: TimeService,
+    Image: ImageService,
+    Storage: StorageService,
+{
+    #[trace_instrument(skip(self, data))]
+    async fn store(&self, user_id: UserId, data: Vec<u8>) -> Result<Url, UserAvatarStoreError> {
+        if data.len() > self.config.avatar_max_upload_size {
+            return Err(UserAvatarStoreError::TooLarge);
+        }
+
+        let sizes = self.config.avatar_sizes.to_vec();
+        let thumbnails = self
+            .image
+            .make_square_thumbnails(data, sizes.clone())
+            .await
+            .map_err(|err| match err {
+                ImageError::UnsupportedFormat => UserAvatarStoreError::UnsupportedFormat,
+                ImageError::Invalid => UserAvatarStoreError::InvalidImage,
+                ImageError::Other(err) => err.context("Failed to create thumbnails").into(),
+            })?;
+
+        for (size, thumbnail) in sizes.into_iter().zip(thumbnails) {
+            self.storage
+                .put(&storage_key(user_id, size), thumbnail)
+                .await
+                .context("Failed to store avatar")?;
+        }
+
+        // Include the upload time in the url, so clients don't keep showing a
+        // cached version of the previous avatar.
+        let mut url = self
+            .config
+            .avatar_url
+            .join(&user_id.hyphenated().to_string())
+            .context("Failed to build avatar url")?;
+        url.query_pairs_mut()
+            .append_pair("v", &self.time.now().timestamp().to_string());
+
+        Ok(url.into())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn remove(&self, user_id: UserId) -> anyhow::Result<()> {
+        for &size in self.config.avatar_sizes.iter() {
+            self.storage
+                .remove(&storage_key(user_id, size))
+                .await
+                .context("Failed to remove avatar")?;
+        }
+
+        Ok(())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get(&self, user_id: UserId, size: Option<u32>) -> anyhow::Result<Option<Vec<u8>>> {
+        let sizes = self.config.avatar_sizes.iter().copied();
+        let largest = sizes.clone().max();
+        let size = match size {
+            Some(size) => sizes.filter(|&s| s >= size).min().or(largest),
+            None => largest,
+        };
+
+        let Some(size) = size else {
+            return Ok(None);
+        };
+
+        self.storage
+            .get(&storage_key(user_id, size))
+            .await
+            .context("Failed to get avatar from storage")
+    }
+}
+
+fn storage_key(user_id: UserId, size: u32) -> String {
+    format!("avatars/{}/{size}.png", user_id.hyphenated())
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_demo::user::FOO;
+    use academy_shared_contracts::{image::MockImageService, time::MockTimeService};
+    use academy_storage_contracts::MockStorageService;
+    use academy_utils::assert_matches;
+
+    use super::*;
+
+    type Sut = UserAvatarServiceImpl<MockTimeService, MockImageService, MockStorageService>;
+
+    #[tokio::test]
+    async fn store_ok() {
+        // Arrange
+        let config = UserFeatureConfig::default();
+        let data = b"some image".to_vec();
+        let sizes = config.avatar_sizes.to_vec();
+        let thumbnails = sizes
+            .iter()
+            .map(|size| size.to_string().into_bytes())
+            .collect::<Vec<_>>();
+
+        let time = MockTimeService::new().with_now(FOO.user.created_at);
+
+        let image = MockImageService::new().with_make_square_thumbnails(
+            data.clone(),
+            sizes.clone(),
+            Ok(thumbnails.clone()),
+        );
+
+        let storage = sizes.iter().zip(thumbnails).fold(
+            MockStorageService::new(),
+            |storage, (&size, thumbnail)| {
+                storage.with_put(storage_key(FOO.user.id, size), thumbnail)
+            },
+        );
+
+        let sut = Sut {
+            time,
+            image,
+            storage,
+            config,
+        };
+
+        // Act
+        let result = sut.store(FOO.user.id, data).await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap().as_str(),
+            format!(
+                "https://bootstrap.academy/auth/avatars/{}?v={}",
+                FOO.user.id.hyphenated(),
+                FOO.user.created_at.timestamp()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn store_too_large() {
+        // Arrange
+        let config = UserFeatureConfig::default();
+        let data = vec![0; config.avatar_max_upload_size + 1];
+
+        let sut = Sut {
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.store(FOO.user.id, data).await;
+
+        // Assert
+        assert_matches!(result, Err(UserAvatarStoreError::TooLarge));
+    }
+
+    #[tokio::test]
+    async fn store_unsupported_format() {
+        // Arrange
+        let config = UserFeatureConfig::default();
+        let data = b"some image".to_vec();
+
+        let image = MockImageService::new().with_make_square_thumbnails(
+            data.clone(),
+            config.avatar_sizes.to_vec(),
+            Err(ImageError::UnsupportedFormat),
+        );
+
+        let sut = Sut {
+            image,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.store(FOO.user.id, data).await;
+
+        // Assert
+        assert_matches!(result, Err(UserAvatarStoreError::UnsupportedFormat));
+    }
+
+    #[tokio::test]
+    async fn store_invalid_image() {
+        // Arrange
+        let config = UserFeatureConfig::default();
+        let data = b"some image".to_vec();
+
+        let image = MockImageService::new().with_make_square_thumbnails(
+            data.clone(),
+            config.avatar_sizes.to_vec(),
+            Err(ImageError::Invalid),
+        );
+
+        let sut = Sut {
+            image,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.store(FOO.user.id, data).await;
+
+        // Assert
+        assert_matches!(result, Err(UserAvatarStoreError::InvalidImage));
+    }
+
+    #[tokio::test]
+    async fn remove() {
+        // Arrange
+        let config = UserFeatureConfig::default();
+
+        let storage = config
+            .avatar_sizes
+            .iter()
+            .fold(MockStorageService::new(), |storage, &size| {
+                storage.with_remove(storage_key(FOO.user.id, size))
+            });
+
+        let sut = Sut {
+            storage,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.remove(FOO.user.id).await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_size() {
+        for (requested, expected) in [
+            (None, 512),
+            (Some(1024), 512),
+            (Some(512), 512),
+            (Some(200), 256),
+            (Some(64), 64),
+            (Some(1), 64),
+        ] {
+            // Arrange
+            let storage = MockStorageService::new()
+                .with_get(storage_key(FOO.user.id, expected), Some(vec![1, 2, 3]));
+
+            let sut = Sut {
+                storage,
+                ..Sut::default()
+            };
+
+            // Act
+            let result = sut.get(FOO.user.id, requested).await;
+
+            // Assert
+            assert_eq!(result.unwrap().unwrap(), [1, 2, 3]);
+        }
+    }
+
+    #[tokio::test]
+    async fn get_not_found() {
+        // Arrange
+        let storage = MockStorageService::new().with_get(storage_key(FOO.user.id, 512), None);
+
+        let sut = Sut {
+            storage,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.get(FOO.user.id, None).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), None);
+    }
+}
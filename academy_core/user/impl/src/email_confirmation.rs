@@ -1,18 +1,23 @@
 use academy_auth_contracts::AuthService;
 use academy_cache_contracts::CacheService;
-use academy_core_user_contracts::email_confirmation::{
-    UserEmailConfirmationResetPasswordError, UserEmailConfirmationService,
-    UserEmailConfirmationSubscribeToNewsletterError, UserEmailConfirmationVerifyEmailError,
+use academy_core_user_contracts::{
+    email_confirmation::{
+        UserEmailConfirmationResetPasswordError, UserEmailConfirmationService,
+        UserEmailConfirmationSubscribeToNewsletterError, UserEmailConfirmationVerifyEmailError,
+    },
+    newsletter::UserNewsletterService,
+    update::UserUpdateService,
 };
 use academy_di::Build;
 use academy_email_contracts::template::TemplateEmailService;
 use academy_models::{
     email_address::EmailAddressWithName,
+    locale::Locale,
     user::{UserComposite, UserId, UserPassword, UserPatchRef},
     VerificationCode,
 };
 use academy_persistence_contracts::user::UserRepository;
-use academy_shared_contracts::{password::PasswordService, secret::SecretService};
+use academy_shared_contracts::secret::SecretService;
 use academy_templates_contracts::{
     ResetPasswordTemplate, SubscribeNewsletterTemplate, VerifyEmailTemplate,
 };
@@ -23,30 +28,53 @@ use crate::UserFeatureConfig;
 
 #[derive(Debug, Clone, Build)]
 #[cfg_attr(test, derive(Default))]
-pub struct UserEmailConfirmationServiceImpl<Auth, Secret, TemplateEmail, Cache, Password, UserRepo>
-{
+pub struct UserEmailConfirmationServiceImpl<
+    Auth,
+    Secret,
+    TemplateEmail,
+    Cache,
+    UserNewsletter,
+    UserUpdate,
+    UserRepo,
+> {
     auth: Auth,
     secret: Secret,
     template_email: TemplateEmail,
     cache: Cache,
-    password: Password,
+    user_newsletter: UserNewsletter,
+    user_update: UserUpdate,
     user_repo: UserRepo,
     config: UserFeatureConfig,
 }
 
-impl<Txn, Auth, Secret, TemplateEmail, Cache, Password, UserRepo> UserEmailConfirmationService<Txn>
-    for UserEmailConfirmationServiceImpl<Auth, Secret, TemplateEmail, Cache, Password, UserRepo>
+impl<Txn, Auth, Secret, TemplateEmail, Cache, UserNewsletter, UserUpdate, UserRepo>
+    UserEmailConfirmationService<Txn>
+    for UserEmailConfirmationServiceImpl<
+        Auth,
+        Secret,
+        TemplateEmail,
+        Cache,
+        UserNewsletter,
+        UserUpdate,
+        UserRepo,
+    >
 where
     Txn: Send + Sync + 'static,
     Auth: AuthService<Txn>,
     Secret: SecretService,
-    TemplateEmail: TemplateEmailService,
+    TemplateEmail: TemplateEmailService<Txn>,
     Cache: CacheService,
-    Password: PasswordService,
+    UserNewsletter: UserNewsletterService,
+    UserUpdate: UserUpdateService<Txn>,
     UserRepo: UserRepository<Txn>,
 {
-    #[trace_instrument(skip(self))]
-    async fn request_verification(&self, email: EmailAddressWithName) -> anyhow::Result<()> {
+    #[trace_instrument(skip(self, txn))]
+    async fn request_verification(
+        &self,
+        txn: &mut Txn,
+        email: EmailAddressWithName,
+        locale: &Locale,
+    ) -> anyhow::Result<()> {
         let code = self.secret.generate_verification_code();
 
         self.cache
@@ -60,14 +88,16 @@ where
 
         self.template_email
             .send_verification_email(
+                txn,
                 email,
+                locale,
                 &VerifyEmailTemplate {
                     code: code.into_inner(),
                     url: (*self.config.verification_redirect_url).clone(),
                 },
             )
             .await
-            .context("Failed to send email")?;
+            .context("Failed to queue email")?;
 
         Ok(())
     }
@@ -126,11 +156,13 @@ where
         Ok(user_composite)
     }
 
-    #[trace_instrument(skip(self))]
+    #[trace_instrument(skip(self, txn))]
     async fn request_password_reset(
         &self,
+        txn: &mut Txn,
         user_id: UserId,
         email: EmailAddressWithName,
+        locale: &Locale,
     ) -> anyhow::Result<()> {
         let code = self.secret.generate_verification_code();
 
@@ -145,14 +177,16 @@ where
 
         self.template_email
             .send_reset_password_email(
+                txn,
                 email,
+                locale,
                 &ResetPasswordTemplate {
                     code: code.into_inner(),
                     url: (*self.config.password_reset_redirect_url).clone(),
                 },
             )
             .await
-            .context("Failed to send email")?;
+            .context("Failed to queue email")?;
 
         Ok(())
     }
@@ -176,16 +210,10 @@ where
             return Err(UserEmailConfirmationResetPasswordError::InvalidCode);
         }
 
-        let password_hash = self
-            .password
-            .hash(new_password.into_inner().into())
-            .await
-            .context("Failed to hash password")?;
-
-        self.user_repo
-            .save_password_hash(txn, user_id, password_hash)
+        self.user_update
+            .update_password(txn, user_id, new_password)
             .await
-            .context("Failed to save password hash in database")?;
+            .context("Failed to update user password")?;
 
         self.cache
             .remove(&cache_key)
@@ -195,11 +223,13 @@ where
         Ok(())
     }
 
-    #[trace_instrument(skip(self))]
+    #[trace_instrument(skip(self, txn))]
     async fn request_newsletter_subscription(
         &self,
+        txn: &mut Txn,
         user_id: UserId,
         email: EmailAddressWithName,
+        locale: &Locale,
     ) -> anyhow::Result<()> {
         let code = self.secret.generate_verification_code();
 
@@ -212,16 +242,25 @@ where
             .await
             .context("Failed to save code in cache")?;
 
+        let unsubscribe_urls = self
+            .user_newsletter
+            .unsubscribe_urls(user_id)
+            .context("Failed to generate unsubscribe urls")?;
+
         self.template_email
             .send_subscribe_newsletter_email(
+                txn,
                 email,
+                locale,
                 &SubscribeNewsletterTemplate {
                     code: code.into_inner(),
                     url: self.config.newsletter_subscription_redirect_url.to_string(),
+                    unsubscribe_url: unsubscribe_urls.link.to_string(),
                 },
+                &unsubscribe_urls.one_click,
             )
             .await
-            .context("Failed to send email")?;
+            .context("Failed to queue email")?;
 
         Ok(())
     }
@@ -274,6 +313,10 @@ fn reset_password_cache_key(user_id: UserId) -> String {
 mod tests {
     use academy_auth_contracts::MockAuthService;
     use academy_cache_contracts::MockCacheService;
+    use academy_core_user_contracts::{
+        newsletter::{MockUserNewsletterService, UserNewsletterUnsubscribeUrls},
+        update::MockUserUpdateService,
+    };
     use academy_demo::{
         user::{FOO, FOO_PASSWORD},
         VERIFICATION_CODE_1, VERIFICATION_CODE_2,
@@ -281,7 +324,7 @@ mod tests {
     use academy_email_contracts::template::MockTemplateEmailService;
     use academy_models::{email_address::EmailAddress, user::UserPatch};
     use academy_persistence_contracts::user::MockUserRepository;
-    use academy_shared_contracts::{password::MockPasswordService, secret::MockSecretService};
+    use academy_shared_contracts::secret::MockSecretService;
     use academy_utils::{assert_matches, Apply};
 
     use super::*;
@@ -289,9 +332,10 @@ mod tests {
     type Sut = UserEmailConfirmationServiceImpl<
         MockAuthService<()>,
         MockSecretService,
-        MockTemplateEmailService,
+        MockTemplateEmailService<()>,
         MockCacheService,
-        MockPasswordService,
+        MockUserNewsletterService,
+        MockUserUpdateService<()>,
         MockUserRepository<()>,
     >;
 
@@ -312,11 +356,11 @@ mod tests {
 
         let template_email = MockTemplateEmailService::new().with_send_verification_email(
             recipient.clone(),
+            FOO.user.locale.clone(),
             VerifyEmailTemplate {
                 code: VERIFICATION_CODE_1.clone().into_inner(),
                 url: (*config.verification_redirect_url).clone(),
             },
-            true,
         );
 
         let cache = MockCacheService::new().with_set(
@@ -333,7 +377,9 @@ mod tests {
         };
 
         // Act
-        let result = sut.request_verification(recipient).await;
+        let result = sut
+            .request_verification(&mut (), recipient, &FOO.user.locale)
+            .await;
 
         // Assert
         result.unwrap();
@@ -482,8 +528,8 @@ mod tests {
                 .clone()
                 .unwrap()
                 .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
             expected_email,
-            true,
         );
 
         let cache = MockCacheService::new().with_set(
@@ -502,12 +548,14 @@ mod tests {
         // Act
         let result = sut
             .request_password_reset(
+                &mut (),
                 FOO.user.id,
                 FOO.user
                     .email
                     .clone()
                     .unwrap()
                     .with_name(FOO.profile.display_name.clone().into_inner()),
+                &FOO.user.locale,
             )
             .await;
 
@@ -523,16 +571,12 @@ mod tests {
             .with_get(cache_key.clone(), Some(VERIFICATION_CODE_1.clone()))
             .with_remove(cache_key);
 
-        let password = MockPasswordService::new()
-            .with_hash(FOO_PASSWORD.clone().into_inner(), "new pw hash".into());
-
-        let user_repo =
-            MockUserRepository::new().with_save_password_hash(FOO.user.id, "new pw hash".into());
+        let user_update =
+            MockUserUpdateService::new().with_update_password(FOO.user.id, FOO_PASSWORD.clone());
 
         let sut = UserEmailConfirmationServiceImpl {
             cache,
-            password,
-            user_repo,
+            user_update,
             ..Sut::default()
         };
 
@@ -618,9 +662,21 @@ mod tests {
         let secret =
             MockSecretService::new().with_generate_verification_code(VERIFICATION_CODE_1.clone());
 
+        let unsubscribe_urls = UserNewsletterUnsubscribeUrls {
+            link: "https://bootstrap.academy/account/newsletter/unsubscribe?token=token"
+                .parse()
+                .unwrap(),
+            one_click: "https://bootstrap.academy/auth/newsletter/unsubscribe?token=token"
+                .parse()
+                .unwrap(),
+        };
+        let user_newsletter = MockUserNewsletterService::new()
+            .with_unsubscribe_urls(FOO.user.id, unsubscribe_urls.clone());
+
         let expected_email = SubscribeNewsletterTemplate {
             code: VERIFICATION_CODE_1.clone().into_inner(),
             url: (*config.newsletter_subscription_redirect_url).clone(),
+            unsubscribe_url: unsubscribe_urls.link.to_string(),
         };
 
         let template_email = MockTemplateEmailService::new().with_send_subscribe_newsletter_email(
@@ -629,8 +685,9 @@ mod tests {
                 .clone()
                 .unwrap()
                 .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
             expected_email,
-            true,
+            unsubscribe_urls.one_click,
         );
 
         let cache = MockCacheService::new().with_set(
@@ -643,18 +700,21 @@ mod tests {
             secret,
             template_email,
             cache,
+            user_newsletter,
             ..Sut::default()
         };
 
         // Act
         let result = sut
             .request_newsletter_subscription(
+                &mut (),
                 FOO.user.id,
                 FOO.user
                     .email
                     .clone()
                     .unwrap()
                     .with_name(FOO.profile.display_name.clone().into_inner()),
+                &FOO.user.locale,
             )
             .await;
 
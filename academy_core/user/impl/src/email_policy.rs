@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+
+use academy_core_user_contracts::email_policy::{
+    UserEmailPolicyCheckError, UserEmailPolicyService,
+};
+use academy_di::Build;
+use academy_extern_contracts::dns::DnsResolverService;
+use academy_models::email_address::EmailAddress;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use tracing::trace;
+
+use crate::UserFeatureConfig;
+
+/// Bundled list of known disposable email providers.
+pub const DISPOSABLE_EMAIL_DOMAINS: &str = include_str!("../assets/disposable_email_domains.txt");
+
+/// Parse a list of domains containing one domain per line, ignoring empty
+/// lines and comments starting with `#`.
+pub fn parse_domain_list(list: &str) -> HashSet<String> {
+    list.lines()
+        .map(|line| line.split_once('#').map_or(line, |(line, _)| line).trim())
+        .filter(|line| !line.is_empty())
+        .map(normalize_domain)
+        .collect()
+}
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct UserEmailPolicyServiceImpl<DnsResolver> {
+    dns_resolver: DnsResolver,
+    config: UserFeatureConfig,
+}
+
+impl<DnsResolver> UserEmailPolicyService for UserEmailPolicyServiceImpl<DnsResolver>
+where
+    DnsResolver: DnsResolverService,
+{
+    #[trace_instrument(skip(self))]
+    async fn check(&self, email: &EmailAddress) -> Result<(), UserEmailPolicyCheckError> {
+        let domain = normalize_domain(email.0.domain());
+
+        if !self.config.email_allowlist.is_empty()
+            && !self
+                .config
+                .email_allowlist
+                .iter()
+                .any(|allowed| matches_domain(&domain, allowed))
+        {
+            trace!("domain not allowed");
+            return Err(UserEmailPolicyCheckError::Rejected);
+        }
+
+        if self
+            .config
+            .email_denylist
+            .iter()
+            .any(|denied| matches_domain(&domain, denied))
+        {
+            trace!("domain denied");
+            return Err(UserEmailPolicyCheckError::Rejected);
+        }
+
+        if parent_domains(&domain).any(|d| self.config.email_disposable_domains.contains(d)) {
+            trace!("disposable domain");
+            return Err(UserEmailPolicyCheckError::Rejected);
+        }
+
+        if self.config.email_require_mx
+            && !self
+                .dns_resolver
+                .has_mx_records(&domain)
+                .await
+                .context("Failed to lookup MX records")?
+        {
+            trace!("no mx records");
+            return Err(UserEmailPolicyCheckError::Rejected);
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert the domain to lowercase ASCII (punycode) without a trailing dot.
+pub fn normalize_domain(domain: &str) -> String {
+    let domain = domain.strip_suffix('.').unwrap_or(domain);
+    idna::domain_to_ascii(domain).unwrap_or_else(|_| domain.to_lowercase())
+}
+
+/// Return whether `domain` is equal to `rule` or a subdomain of it.
+fn matches_domain(domain: &str, rule: &str) -> bool {
+    parent_domains(domain).any(|d| d == rule)
+}
+
+/// Return an iterator over the given domain and all of its parent domains.
+fn parent_domains(domain: &str) -> impl Iterator<Item = &str> {
+    std::iter::successors(Some(domain), |d| {
+        d.split_once('.').map(|(_, parent)| parent)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use academy_extern_contracts::dns::MockDnsResolverService;
+    use academy_utils::assert_matches;
+
+    use super::*;
+
+    type Sut = UserEmailPolicyServiceImpl<MockDnsResolverService>;
+
+    #[tokio::test]
+    async fn ok() {
+        // Arrange
+        let sut = Sut::default();
+
+        // Act
+        let result = sut.check(&"foo@example.com".parse().unwrap()).await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn ok_allowlist_subdomain() {
+        // Arrange
+        let config = UserFeatureConfig {
+            email_allowlist: ["example.com".into()].into(),
+            ..Default::default()
+        };
+
+        let sut = UserEmailPolicyServiceImpl {
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut
+            .check(&"foo@students.Example.com".parse().unwrap())
+            .await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn not_on_allowlist() {
+        // Arrange
+        let config = UserFeatureConfig {
+            email_allowlist: ["example.com".into()].into(),
+            ..Default::default()
+        };
+
+        let sut = UserEmailPolicyServiceImpl {
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(&"foo@notexample.com".parse().unwrap()).await;
+
+        // Assert
+        assert_matches!(result, Err(UserEmailPolicyCheckError::Rejected));
+    }
+
+    #[tokio::test]
+    async fn denylist() {
+        // Arrange
+        let config = UserFeatureConfig {
+            email_denylist: ["example.com".into()].into(),
+            ..Default::default()
+        };
+
+        let sut = UserEmailPolicyServiceImpl {
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(&"foo@mail.example.com".parse().unwrap()).await;
+
+        // Assert
+        assert_matches!(result, Err(UserEmailPolicyCheckError::Rejected));
+    }
+
+    #[tokio::test]
+    async fn disposable() {
+        // Arrange
+        let sut = Sut::default();
+
+        // Act
+        let result = sut.check(&"foo@MAILINATOR.com".parse().unwrap()).await;
+
+        // Assert
+        assert_matches!(result, Err(UserEmailPolicyCheckError::Rejected));
+    }
+
+    #[tokio::test]
+    async fn disposable_disabled() {
+        // Arrange
+        let config = UserFeatureConfig {
+            email_disposable_domains: Arc::new(HashSet::new()),
+            ..Default::default()
+        };
+
+        let sut = UserEmailPolicyServiceImpl {
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(&"foo@mailinator.com".parse().unwrap()).await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn ok_mx() {
+        // Arrange
+        let config = UserFeatureConfig {
+            email_require_mx: true,
+            ..Default::default()
+        };
+
+        let dns_resolver =
+            MockDnsResolverService::new().with_has_mx_records("example.com".into(), true);
+
+        let sut = UserEmailPolicyServiceImpl {
+            dns_resolver,
+            config,
+        };
+
+        // Act
+        let result = sut.check(&"foo@example.com".parse().unwrap()).await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn no_mx() {
+        // Arrange
+        let config = UserFeatureConfig {
+            email_require_mx: true,
+            ..Default::default()
+        };
+
+        let dns_resolver = MockDnsResolverService::new()
+            .with_has_mx_records("xn--bcher-kva.example".into(), false);
+
+        let sut = UserEmailPolicyServiceImpl {
+            dns_resolver,
+            config,
+        };
+
+        // Act
+        let result = sut.check(&"foo@bücher.example".parse().unwrap()).await;
+
+        // Assert
+        assert_matches!(result, Err(UserEmailPolicyCheckError::Rejected));
+    }
+
+    #[test]
+    fn parse_bundled_list() {
+        let domains = parse_domain_list(DISPOSABLE_EMAIL_DOMAINS);
+        assert!(domains.contains("mailinator.com"));
+        assert!(!domains.iter().any(|d| d.starts_with('#') || d.is_empty()));
+    }
+}
@@ -0,0 +1,244 @@
+use academy_core_user_contracts::invite::{
+    UserInviteCreateCommand, UserInviteRedeemError, UserInviteService,
+};
+use academy_di::Build;
+use academy_models::{
+    invite::{Invite, InviteCode, InviteId},
+    user::UserId,
+};
+use academy_persistence_contracts::invite::InviteRepository;
+use academy_shared_contracts::{id::IdService, secret::SecretService, time::TimeService};
+use academy_utils::trace_instrument;
+use anyhow::Context;
+
+#[derive(Debug, Clone, Build, Default)]
+pub struct UserInviteServiceImpl<Id, Time, Secret, InviteRepo> {
+    id: Id,
+    time: Time,
+    secret: Secret,
+    invite_repo: InviteRepo,
+}
+
+impl<Txn, Id, Time, Secret, InviteRepo> UserInviteService<Txn>
+    for UserInviteServiceImpl<Id, Time, Secret, InviteRepo>
+where
+    Txn: Send + Sync + 'static,
+    Id: IdService,
+    Time: TimeService,
+    Secret: SecretService,
+    InviteRepo: InviteRepository<Txn>,
+{
+    #[trace_instrument(skip(self, txn))]
+    async fn create(&self, txn: &mut Txn, cmd: UserInviteCreateCommand) -> anyhow::Result<Invite> {
+        let invite = Invite {
+            id: self.id.generate(),
+            code: self.secret.generate_invite_code(),
+            created_by: cmd.created_by,
+            created_at: self.time.now(),
+            expires_at: cmd.expires_at,
+            max_uses: cmd.max_uses,
+            uses: 0,
+            admin: cmd.admin,
+            cohort: cmd.cohort,
+        };
+
+        self.invite_repo
+            .create(txn, &invite)
+            .await
+            .context("Failed to create invite in database")?;
+
+        Ok(invite)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_valid(&self, txn: &mut Txn, code: &InviteCode) -> anyhow::Result<Option<Invite>> {
+        let invite = self
+            .invite_repo
+            .get_by_code(txn, code)
+            .await
+            .context("Failed to get invite from database")?;
+
+        let now = self.time.now();
+        Ok(invite.filter(|invite| invite.is_valid(now)))
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn redeem(
+        &self,
+        txn: &mut Txn,
+        invite_id: InviteId,
+        user_id: UserId,
+    ) -> Result<(), UserInviteRedeemError> {
+        let now = self.time.now();
+
+        self.invite_repo
+            .redeem(txn, invite_id, user_id, now)
+            .await
+            .context("Failed to redeem invite in database")?
+            .then_some(())
+            .ok_or(UserInviteRedeemError::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use academy_demo::{
+        invite::{ADMIN_INVITE_1, FOO_INVITE_1},
+        user::{BAR, FOO},
+    };
+    use academy_persistence_contracts::invite::MockInviteRepository;
+    use academy_shared_contracts::{
+        id::MockIdService, secret::MockSecretService, time::MockTimeService,
+    };
+    use academy_utils::assert_matches;
+
+    use super::*;
+
+    type Sut = UserInviteServiceImpl<
+        MockIdService,
+        MockTimeService,
+        MockSecretService,
+        MockInviteRepository<()>,
+    >;
+
+    #[tokio::test]
+    async fn create() {
+        // Arrange
+        let expected = FOO_INVITE_1.clone();
+
+        let id = MockIdService::new().with_generate(expected.id);
+        let time = MockTimeService::new().with_now(expected.created_at);
+        let secret = MockSecretService::new().with_generate_invite_code(expected.code.clone());
+        let invite_repo = MockInviteRepository::new().with_create(expected.clone());
+
+        let sut = UserInviteServiceImpl {
+            id,
+            time,
+            secret,
+            invite_repo,
+        };
+
+        // Act
+        let result = sut
+            .create(
+                &mut (),
+                UserInviteCreateCommand {
+                    created_by: expected.created_by,
+                    expires_at: expected.expires_at,
+                    max_uses: expected.max_uses,
+                    admin: expected.admin,
+                    cohort: expected.cohort.clone(),
+                },
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn get_valid_ok() {
+        // Arrange
+        let time = MockTimeService::new().with_now(ADMIN_INVITE_1.created_at);
+        let invite_repo = MockInviteRepository::new()
+            .with_get_by_code(ADMIN_INVITE_1.code.clone(), Some(ADMIN_INVITE_1.clone()));
+
+        let sut = UserInviteServiceImpl {
+            time,
+            invite_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.get_valid(&mut (), &ADMIN_INVITE_1.code).await;
+
+        // Assert
+        assert_eq!(result.unwrap().as_ref(), Some(&*ADMIN_INVITE_1));
+    }
+
+    #[tokio::test]
+    async fn get_valid_expired() {
+        // Arrange
+        let time = MockTimeService::new().with_now(FOO_INVITE_1.expires_at.unwrap());
+        let invite_repo = MockInviteRepository::new()
+            .with_get_by_code(FOO_INVITE_1.code.clone(), Some(FOO_INVITE_1.clone()));
+
+        let sut = UserInviteServiceImpl {
+            time,
+            invite_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.get_valid(&mut (), &FOO_INVITE_1.code).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn get_valid_not_found() {
+        // Arrange
+        let time = MockTimeService::new().with_now(FOO.user.created_at);
+        let invite_repo =
+            MockInviteRepository::new().with_get_by_code(FOO_INVITE_1.code.clone(), None);
+
+        let sut = UserInviteServiceImpl {
+            time,
+            invite_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.get_valid(&mut (), &FOO_INVITE_1.code).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn redeem_ok() {
+        // Arrange
+        let now = FOO_INVITE_1.created_at + Duration::from_secs(60);
+
+        let time = MockTimeService::new().with_now(now);
+        let invite_repo =
+            MockInviteRepository::new().with_redeem(FOO_INVITE_1.id, BAR.user.id, now, true);
+
+        let sut = UserInviteServiceImpl {
+            time,
+            invite_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.redeem(&mut (), FOO_INVITE_1.id, BAR.user.id).await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn redeem_invalid() {
+        // Arrange
+        let now = FOO_INVITE_1.created_at + Duration::from_secs(60);
+
+        let time = MockTimeService::new().with_now(now);
+        let invite_repo =
+            MockInviteRepository::new().with_redeem(FOO_INVITE_1.id, BAR.user.id, now, false);
+
+        let sut = UserInviteServiceImpl {
+            time,
+            invite_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.redeem(&mut (), FOO_INVITE_1.id, BAR.user.id).await;
+
+        // Assert
+        assert_matches!(result, Err(UserInviteRedeemError::Invalid));
+    }
+}
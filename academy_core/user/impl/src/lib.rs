@@ -1,71 +1,110 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
-use academy_auth_contracts::{AuthResultExt, AuthService};
+use academy_auth_contracts::{AuthResultExt, AuthService, Authentication};
+use academy_core_coin_contracts::coin::CoinService;
 use academy_core_oauth2_contracts::registration::OAuth2RegistrationService;
 use academy_core_session_contracts::session::SessionService;
 use academy_core_user_contracts::{
+    avatar::{UserAvatarService, UserAvatarStoreError},
     email_confirmation::{
         UserEmailConfirmationResetPasswordError, UserEmailConfirmationService,
         UserEmailConfirmationSubscribeToNewsletterError, UserEmailConfirmationVerifyEmailError,
     },
+    email_policy::{UserEmailPolicyCheckError, UserEmailPolicyService},
+    invite::{UserInviteCreateCommand, UserInviteRedeemError, UserInviteService},
+    newsletter::UserNewsletterService,
     update::{
-        UserUpdateEmailError, UserUpdateNameError, UserUpdateNameRateLimitPolicy, UserUpdateService,
+        UserAccountUpdate, UserUpdateAccountError, UserUpdateNameRateLimitPolicy, UserUpdateService,
     },
     user::{UserCreateCommand, UserListQuery, UserListResult, UserService},
-    PasswordUpdate, UserCreateError, UserCreateRequest, UserDeleteError, UserFeatureService,
-    UserGetError, UserListError, UserRequestPasswordResetError, UserRequestVerificationEmailError,
-    UserResetPasswordError, UserUpdateError, UserUpdateRequest, UserUpdateUserRequest,
-    UserVerifyEmailError, UserVerifyNewsletterSubscriptionError,
+    vat::UserVatService,
+    PasswordUpdate, UserCreateError, UserCreateInviteError, UserCreateInviteRequest,
+    UserCreateRequest, UserDeleteAvatarError, UserDeleteError, UserDeleteInviteError,
+    UserFeatureService, UserGetAvatarError, UserGetError, UserGetPrivacyError,
+    UserGetPublicProfileError, UserListError, UserListInvitesError, UserRequestPasswordResetError,
+    UserRequestVerificationEmailError, UserResetPasswordError, UserUnsubscribeFromNewsletterError,
+    UserUpdateError, UserUpdatePrivacyError, UserUpdateRequest, UserUpdateUserRequest,
+    UserUploadAvatarError, UserVerifyEmailError, UserVerifyNewsletterSubscriptionError,
 };
 use academy_di::Build;
-use academy_extern_contracts::{internal::InternalApiService, vat::VatApiService};
 use academy_models::{
     auth::{AccessToken, Login},
     email_address::EmailAddress,
+    invite::{Invite, InviteId, InviteMaxUses, RegistrationMode},
+    locale::Locale,
     session::DeviceName,
-    user::{UserComposite, UserIdOrSelf, UserInvoiceInfoPatch, UserPassword, UserPatchRef},
+    url::Url,
+    user::{
+        NewsletterUnsubscribeToken, UserComposite, UserId, UserIdOrSelf, UserInvoiceInfoPatch,
+        UserName, UserPassword, UserPatchRef, UserPrivacy, UserPrivacyPatch, UserProfileVisibility,
+        UserPublicProfile, UserVatIdStatus,
+    },
     RecaptchaResponse, VerificationCode,
 };
-use academy_persistence_contracts::{user::UserRepository, Database, Transaction};
-use academy_shared_contracts::captcha::{CaptchaCheckError, CaptchaService};
+use academy_persistence_contracts::{
+    invite::InviteRepository, user::UserRepository, Database, Transaction,
+};
+use academy_shared_contracts::{
+    captcha::{CaptchaCheckError, CaptchaService},
+    time::TimeService,
+};
 use academy_utils::{
     patch::{Patch, PatchValue},
     trace_instrument,
 };
 use anyhow::{anyhow, Context};
 
+pub mod avatar;
 pub mod email_confirmation;
+pub mod email_policy;
+pub mod invite;
+pub mod newsletter;
 pub mod update;
 pub mod user;
+pub mod vat;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Clone, Default, Build)]
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
 pub struct UserFeatureServiceImpl<
     Db,
     Auth,
     Captcha,
-    VatApi,
-    InternalApi,
+    Time,
+    Coin,
     User,
     UserEmailConfirmation,
+    UserEmailPolicy,
+    UserNewsletter,
     UserUpdate,
+    UserAvatar,
+    UserInvite,
+    UserVat,
     Session,
     OAuth2Registration,
     UserRepo,
+    InviteRepo,
 > {
     db: Db,
     auth: Auth,
     captcha: Captcha,
-    vat_api: VatApi,
-    internal_api: InternalApi,
+    time: Time,
+    coin: Coin,
     user: User,
     user_email_confirmation: UserEmailConfirmation,
+    user_email_policy: UserEmailPolicy,
+    user_newsletter: UserNewsletter,
     user_update: UserUpdate,
+    user_avatar: UserAvatar,
+    user_invite: UserInvite,
+    user_vat: UserVat,
     session: Session,
     oauth2_registration: OAuth2Registration,
     user_repo: UserRepo,
+    invite_repo: InviteRepo,
+    config: UserFeatureConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -75,48 +114,83 @@ pub struct UserFeatureConfig {
     pub verification_verification_code_ttl: Duration,
     pub password_reset_redirect_url: Arc<String>,
     pub password_reset_verification_code_ttl: Duration,
+    pub account_recovery_url: Arc<String>,
     pub newsletter_subscription_redirect_url: Arc<String>,
     pub newsletter_subscription_verification_code_ttl: Duration,
+    pub avatar_max_upload_size: usize,
+    pub avatar_sizes: Arc<[u32]>,
+    pub avatar_url: Arc<Url>,
+    pub list_cursor_ttl: Duration,
+    pub registration_mode: RegistrationMode,
+    pub invite_limit: u64,
+    pub invite_ttl: Duration,
+    pub email_allowlist: Arc<[String]>,
+    pub email_denylist: Arc<[String]>,
+    pub email_disposable_domains: Arc<HashSet<String>>,
+    pub email_require_mx: bool,
+    pub newsletter_unsubscribe_url: Arc<Url>,
+    pub newsletter_unsubscribe_redirect_url: Arc<String>,
+    pub newsletter_unsubscribe_token_ttl: Duration,
+    pub default_locale: Locale,
+    pub vat_cache_ttl: Duration,
 }
 
 impl<
         Db,
         Auth,
         Captcha,
-        VatApi,
-        InternalApi,
+        Time,
+        Coin,
         UserS,
         UserEmailConfirmation,
+        UserEmailPolicy,
+        UserNewsletter,
         UserUpdate,
+        UserAvatar,
+        UserInvite,
+        UserVat,
         Session,
         OAuth2RegistrationS,
         UserRepo,
+        InviteRepo,
     > UserFeatureService
     for UserFeatureServiceImpl<
         Db,
         Auth,
         Captcha,
-        VatApi,
-        InternalApi,
+        Time,
+        Coin,
         UserS,
         UserEmailConfirmation,
+        UserEmailPolicy,
+        UserNewsletter,
         UserUpdate,
+        UserAvatar,
+        UserInvite,
+        UserVat,
         Session,
         OAuth2RegistrationS,
         UserRepo,
+        InviteRepo,
     >
 where
     Db: Database,
     Auth: AuthService<Db::Transaction>,
     Captcha: CaptchaService,
-    VatApi: VatApiService,
-    InternalApi: InternalApiService,
+    Time: TimeService,
+    Coin: CoinService<Db::Transaction>,
     UserS: UserService<Db::Transaction>,
     UserEmailConfirmation: UserEmailConfirmationService<Db::Transaction>,
+    UserEmailPolicy: UserEmailPolicyService,
+    UserNewsletter: UserNewsletterService,
     UserUpdate: UserUpdateService<Db::Transaction>,
+    UserAvatar: UserAvatarService,
+    UserInvite: UserInviteService<Db::Transaction>,
+    UserVat: UserVatService,
     Session: SessionService<Db::Transaction>,
     OAuth2RegistrationS: OAuth2RegistrationService,
     UserRepo: UserRepository<Db::Transaction>,
+    InviteRepo: InviteRepository<Db::Transaction>,
 {
     #[trace_instrument(skip(self))]
     async fn list_users(
@@ -129,11 +203,13 @@ where
 
         let mut txn = self.db.begin_transaction().await.unwrap();
 
-        self.user
-            .list(&mut txn, query)
-            .await
-            .context("Failed to list users")
-            .map_err(Into::into)
+        self.user.list(&mut txn, query).await.map_err(|err| {
+            use academy_core_user_contracts::user::UserListError as E;
+            match err {
+                E::InvalidCursor => UserListError::InvalidCursor,
+                E::Other(err) => err.context("Failed to list users").into(),
+            }
+        })
     }
 
     #[trace_instrument(skip(self))]
@@ -162,6 +238,13 @@ where
         device_name: Option<DeviceName>,
         recaptcha_response: Option<RecaptchaResponse>,
     ) -> Result<Login, UserCreateError> {
+        match self.config.registration_mode {
+            RegistrationMode::Open => {}
+            RegistrationMode::InviteOnly if request.invite_code.is_some() => {}
+            RegistrationMode::InviteOnly => return Err(UserCreateError::InviteCodeRequired),
+            RegistrationMode::Closed => return Err(UserCreateError::RegistrationClosed),
+        }
+
         if request.password.is_none() && request.oauth2_registration_token.is_none() {
             return Err(UserCreateError::NoLoginMethod);
         }
@@ -174,6 +257,16 @@ where
                 CaptchaCheckError::Other(err) => err.context("Failed to check captcha").into(),
             })?;
 
+        self.user_email_policy
+            .check(&request.email)
+            .await
+            .map_err(|err| match err {
+                UserEmailPolicyCheckError::Rejected => UserCreateError::EmailNotAllowed,
+                UserEmailPolicyCheckError::Other(err) => {
+                    err.context("Failed to check email policy").into()
+                }
+            })?;
+
         let oauth2_registration = match &request.oauth2_registration_token {
             Some(oauth2_registration_token) => Some(
                 self.oauth2_registration
@@ -187,15 +280,36 @@ where
 
         let mut txn = self.db.begin_transaction().await.unwrap();
 
+        let invite = match &request.invite_code {
+            Some(invite_code) => Some(
+                self.user_invite
+                    .get_valid(&mut txn, invite_code)
+                    .await
+                    .context("Failed to get invite")?
+                    .ok_or(UserCreateError::InvalidInviteCode)?,
+            ),
+            None => None,
+        };
+
         let cmd = UserCreateCommand {
             name: request.name,
             display_name: request.display_name,
             email: request.email,
             password: request.password,
-            admin: false,
+            admin: invite.as_ref().is_some_and(|invite| invite.admin),
             enabled: true,
             email_verified: false,
             oauth2_registration,
+            tags: invite
+                .as_ref()
+                .and_then(|invite| invite.cohort.clone())
+                .map(|cohort| vec![cohort].try_into())
+                .transpose()
+                .context("Invalid invite cohort")?
+                .unwrap_or_default(),
+            locale: request
+                .locale
+                .unwrap_or_else(|| self.config.default_locale.clone()),
         };
 
         let user = self.user.create(&mut txn, cmd).await.map_err(|err| {
@@ -208,6 +322,18 @@ where
             }
         })?;
 
+        if let Some(invite) = invite {
+            self.user_invite
+                .redeem(&mut txn, invite.id, user.user.id)
+                .await
+                .map_err(|err| match err {
+                    UserInviteRedeemError::Invalid => UserCreateError::InvalidInviteCode,
+                    UserInviteRedeemError::Other(err) => {
+                        err.context("Failed to redeem invite").into()
+                    }
+                })?;
+        }
+
         let result = self
             .session
             .create(&mut txn, user, device_name, true)
@@ -241,6 +367,7 @@ where
                     enabled,
                     admin,
                     newsletter,
+                    locale,
                 },
             profile: profile_update,
             invoice_info: invoice_info_update,
@@ -268,13 +395,22 @@ where
         let mut commit = false;
 
         // Minimize patch
-        let name = name.minimize(&user.name);
-        let email = email.map(Some).minimize(&user.email);
-        let email_verified =
-            email_verified.minimize(&(user.email_verified && email.is_unchanged()));
-        let enabled = enabled.minimize(&user.enabled);
-        let admin = admin.minimize(&user.admin);
+        let (password, remove_password) = match password {
+            PatchValue::Update(PasswordUpdate::Change(password)) => (password.into(), false),
+            PatchValue::Update(PasswordUpdate::Remove) => (PatchValue::Unchanged, true),
+            PatchValue::Unchanged => (PatchValue::Unchanged, false),
+        };
+        let account_update = UserAccountUpdate {
+            name,
+            email: email.map(Some),
+            email_verified,
+            enabled,
+            admin,
+            password,
+        }
+        .minimize(&user);
         let newsletter = newsletter.minimize(&user.newsletter);
+        let locale = locale.minimize(&user.locale);
 
         let profile_update = profile_update.minimize(&profile);
 
@@ -291,27 +427,80 @@ where
         .minimize(&invoice_info);
 
         // Validate patch
-        if email_verified.is_update() || enabled.is_update() || admin.is_update() {
+        if account_update.email_verified.is_update()
+            || account_update.enabled.is_update()
+            || account_update.admin.is_update()
+        {
             auth.ensure_admin().map_auth_err()?;
         }
 
-        if enabled == PatchValue::Update(false) && user_id == auth.user_id {
+        if account_update.enabled == PatchValue::Update(false) && user_id == auth.user_id {
             return Err(UserUpdateError::CannotDisableSelf);
         }
 
-        if admin.is_update() && user_id == auth.user_id {
+        if account_update.admin.is_update() && user_id == auth.user_id {
             return Err(UserUpdateError::CannotDemoteSelf);
         }
 
+        if let PatchValue::Update(Some(email)) = &account_update.email {
+            if !auth.admin {
+                self.user_email_policy
+                    .check(email)
+                    .await
+                    .map_err(|err| match err {
+                        UserEmailPolicyCheckError::Rejected => UserUpdateError::EmailNotAllowed,
+                        UserEmailPolicyCheckError::Other(err) => {
+                            err.context("Failed to check email policy").into()
+                        }
+                    })?;
+            }
+        }
+
+        if let Some(country) = *invoice_info_update
+            .country
+            .as_ref()
+            .update(&invoice_info.country)
+        {
+            let country_updated = invoice_info_update.country.is_update();
+
+            if country_updated || invoice_info_update.zip_code.is_update() {
+                let zip_code = invoice_info_update
+                    .zip_code
+                    .as_ref()
+                    .update(&invoice_info.zip_code);
+                if zip_code
+                    .as_ref()
+                    .is_some_and(|zip_code| !country.is_valid_zip_code(zip_code))
+                {
+                    return Err(UserUpdateError::InvalidZipCode);
+                }
+            }
+
+            if country_updated || invoice_info_update.vat_id.is_update() {
+                let vat_id = invoice_info_update
+                    .vat_id
+                    .as_ref()
+                    .update(&invoice_info.vat_id);
+                if vat_id
+                    .as_ref()
+                    .is_some_and(|vat_id| !country.is_valid_vat_id(vat_id))
+                {
+                    return Err(UserUpdateError::VatIdCountryMismatch);
+                }
+            }
+        }
+
+        let mut vat_id_validation = None;
         if let PatchValue::Update(Some(vat_id)) = &invoice_info_update.vat_id {
-            if !self
-                .vat_api
-                .is_vat_id_valid(vat_id.as_str())
+            let validation = self
+                .user_vat
+                .validate(vat_id)
                 .await
-                .context("Failed to validate VAT id")?
-            {
+                .context("Failed to validate VAT id")?;
+            if validation.status == UserVatIdStatus::Invalid {
                 return Err(UserUpdateError::InvalidVatId);
             }
+            vat_id_validation = Some(validation);
         }
 
         // Apply patch
@@ -324,95 +513,82 @@ where
             commit = true;
         }
 
-        if let PatchValue::Update(name) = name {
+        if account_update.is_update() {
             let rate_limit_policy = if auth.admin {
                 UserUpdateNameRateLimitPolicy::Bypass
             } else {
                 UserUpdateNameRateLimitPolicy::Enforce
             };
-            user = self
+            UserComposite {
+                user,
+                profile,
+                details,
+                invoice_info,
+            } = self
                 .user_update
-                .update_name(&mut txn, user, name, rate_limit_policy)
+                .update_account(
+                    &mut txn,
+                    UserComposite {
+                        user,
+                        profile,
+                        details,
+                        invoice_info,
+                    },
+                    account_update,
+                    rate_limit_policy,
+                )
                 .await
                 .map_err(|err| match err {
-                    UserUpdateNameError::Conflict => UserUpdateError::NameConflict,
-                    UserUpdateNameError::RateLimit { until } => {
+                    UserUpdateAccountError::NameConflict => UserUpdateError::NameConflict,
+                    UserUpdateAccountError::NameChangeRateLimit { until } => {
                         UserUpdateError::NameChangeRateLimit { until }
                     }
-                    UserUpdateNameError::Other(err) => {
-                        err.context("Failed to update user name").into()
-                    }
-                })?;
-            commit = true;
-        }
-
-        if email.is_update() || email_verified.is_update() {
-            user.email_verified =
-                email_verified.update(user.email_verified && email.is_unchanged());
-            user.email = email.update(user.email);
-            self.user_update
-                .update_email(&mut txn, user_id, &user.email, user.email_verified)
-                .await
-                .map_err(|err| match err {
-                    UserUpdateEmailError::Conflict => UserUpdateError::EmailConflict,
-                    UserUpdateEmailError::Other(err) => {
-                        err.context("Failed to update user email").into()
+                    UserUpdateAccountError::EmailConflict => UserUpdateError::EmailConflict,
+                    UserUpdateAccountError::Other(err) => {
+                        err.context("Failed to update user account").into()
                     }
                 })?;
             commit = true;
         }
 
-        if let PatchValue::Update(enabled) = enabled {
-            self.user_update
-                .update_enabled(&mut txn, user_id, enabled)
+        if remove_password {
+            if !details.oauth2_login {
+                return Err(UserUpdateError::CannotRemovePassword);
+            }
+            self.user_repo
+                .remove_password_hash(&mut txn, user.id)
                 .await
-                .context("Failed to update enabled status")?;
-            user.enabled = enabled;
+                .context("Failed to remove password hash from database")?;
+            details.password_login = false;
             commit = true;
         }
 
-        if let PatchValue::Update(admin) = admin {
-            self.user_update
-                .update_admin(&mut txn, user_id, admin)
+        if let PatchValue::Update(locale) = locale {
+            self.user_repo
+                .update(
+                    &mut txn,
+                    user_id,
+                    UserPatchRef::new().update_locale(&locale),
+                )
                 .await
-                .context("Failed to update admin status")?;
-            user.admin = admin;
+                .map_err(|err| anyhow!(err).context("Failed to update user locale in database"))?;
+            user.locale = locale;
             commit = true;
         }
 
-        match password {
-            PatchValue::Update(PasswordUpdate::Remove) => {
-                if !details.oauth2_login {
-                    return Err(UserUpdateError::CannotRemovePassword);
-                }
-                self.user_repo
-                    .remove_password_hash(&mut txn, user.id)
-                    .await
-                    .context("Failed to remove password hash from database")?;
-                details.password_login = false;
-                commit = true;
-            }
-            PatchValue::Update(PasswordUpdate::Change(password)) => {
-                self.user_update
-                    .update_password(&mut txn, user_id, password)
-                    .await
-                    .context("Failed to update user password")?;
-                details.password_login = true;
-                commit = true;
-            }
-            PatchValue::Unchanged => (),
-        }
-
         if let PatchValue::Update(newsletter) = newsletter {
             if newsletter && !auth.admin {
                 let email = user.email.clone().ok_or(UserUpdateError::NoEmail)?;
                 self.user_email_confirmation
                     .request_newsletter_subscription(
+                        &mut txn,
                         user_id,
                         email.with_name(profile.display_name.clone().into_inner()),
+                        &user.locale,
                     )
                     .await
                     .context("Failed to request newsletter subscription email")?;
+                commit = true;
             } else {
                 user.newsletter = newsletter;
                 self.user_repo
@@ -433,16 +609,18 @@ where
         if invoice_info_updated {
             invoice_info = self
                 .user_update
-                .update_invoice_info(&mut txn, user.id, invoice_info, invoice_info_update)
+                .update_invoice_info(
+                    &mut txn,
+                    user.id,
+                    invoice_info,
+                    invoice_info_update,
+                    vat_id_validation,
+                )
                 .await
                 .context("Failed to update user invoice info")?;
             commit = true;
         }
 
-        if commit {
-            txn.commit().await?;
-        }
-
         let user_composite = UserComposite {
             user,
             profile,
@@ -451,10 +629,14 @@ where
         };
 
         if invoice_info_updated && user_composite.can_receive_coins() {
-            self.internal_api
-                .release_coins(user_composite.user.id)
+            self.coin
+                .release_withheld(&mut txn, user_composite.user.id)
                 .await
-                .context("Failed to release coins")?;
+                .context("Failed to release withheld coins")?;
+        }
+
+        if commit {
+            txn.commit().await?;
         }
 
         Ok(user_composite)
@@ -472,22 +654,22 @@ where
 
         let mut txn = self.db.begin_transaction().await?;
 
-        self.auth
-            .invalidate_access_tokens(&mut txn, user_id)
-            .await
-            .context("Failed to invalidate access tokens")?;
-
         if !self
-            .user_repo
+            .user
             .delete(&mut txn, user_id)
             .await
-            .context("Failed to delete user from database")?
+            .context("Failed to delete user")?
         {
             return Err(UserDeleteError::NotFound);
         }
 
         txn.commit().await?;
 
+        self.user_avatar
+            .remove(user_id)
+            .await
+            .context("Failed to remove avatar")?;
+
         Ok(())
     }
 
@@ -520,10 +702,16 @@ where
             .ok_or(UserRequestVerificationEmailError::NoEmail)?;
 
         self.user_email_confirmation
-            .request_verification(email.with_name(user_composite.profile.display_name.into_inner()))
+            .request_verification(
+                &mut txn,
+                email.with_name(user_composite.profile.display_name.into_inner()),
+                &user_composite.user.locale,
+            )
             .await
             .context("Failed to request verification email")?;
 
+        txn.commit().await?;
+
         Ok(())
     }
 
@@ -593,6 +781,34 @@ where
         Ok(user_composite)
     }
 
+    #[trace_instrument(skip(self))]
+    async fn unsubscribe_from_newsletter(
+        &self,
+        token: &NewsletterUnsubscribeToken,
+    ) -> Result<(), UserUnsubscribeFromNewsletterError> {
+        let user_id = self
+            .user_newsletter
+            .verify_unsubscribe_token(token)
+            .ok_or(UserUnsubscribeFromNewsletterError::InvalidToken)?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.user_repo
+            .update(
+                &mut txn,
+                user_id,
+                UserPatchRef::new().update_newsletter(&false),
+            )
+            .await
+            .map_err(|err| anyhow!(err).context("Failed to update user in database"))?
+            .then_some(())
+            .ok_or(UserUnsubscribeFromNewsletterError::InvalidToken)?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
     #[trace_instrument(skip(self))]
     async fn request_password_reset(
         &self,
@@ -624,11 +840,15 @@ where
             })?;
             self.user_email_confirmation
                 .request_password_reset(
+                    &mut txn,
                     user_composite.user.id,
                     email.with_name(user_composite.profile.display_name.into_inner()),
+                    &user_composite.user.locale,
                 )
                 .await
                 .context("Failed to request password reset email")?;
+
+            txn.commit().await?;
         }
 
         Ok(())
@@ -666,4 +886,352 @@ where
 
         Ok(user_composite)
     }
+
+    #[trace_instrument(skip(self))]
+    async fn get_public_profile(
+        &self,
+        token: Option<&AccessToken>,
+        name: UserName,
+    ) -> Result<UserPublicProfile, UserGetPublicProfileError> {
+        let auth = match token {
+            Some(token) => Some(self.auth.authenticate(token).await.map_auth_err()?),
+            None => None,
+        };
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let UserComposite { user, profile, .. } = self
+            .user_repo
+            .get_composite_by_name(&mut txn, &name)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or(UserGetPublicProfileError::NotFound)?;
+
+        let admin = auth.as_ref().is_some_and(|auth| auth.admin);
+        if !user.enabled && !admin {
+            return Err(UserGetPublicProfileError::NotFound);
+        }
+
+        let privacy = self
+            .user_repo
+            .get_privacy(&mut txn, user.id)
+            .await
+            .context("Failed to get privacy settings from database")?
+            .ok_or(UserGetPublicProfileError::NotFound)?;
+
+        let visible =
+            |visibility: UserProfileVisibility| is_visible(auth.as_ref(), user.id, visibility);
+
+        Ok(UserPublicProfile {
+            id: user.id,
+            name: user.name,
+            display_name: visible(privacy.display_name).then_some(profile.display_name),
+            bio: visible(privacy.bio).then_some(profile.bio),
+            tags: visible(privacy.tags).then_some(profile.tags),
+            created_at: visible(privacy.registration).then_some(user.created_at),
+            avatar_url: profile.avatar_url.filter(|_| visible(privacy.avatar)),
+        })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_privacy(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+    ) -> Result<UserPrivacy, UserGetPrivacyError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.user_repo
+            .get_privacy(&mut txn, user_id)
+            .await
+            .context("Failed to get privacy settings from database")?
+            .ok_or(UserGetPrivacyError::NotFound)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn update_privacy(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        patch: UserPrivacyPatch,
+    ) -> Result<UserPrivacy, UserUpdatePrivacyError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let privacy = self
+            .user_repo
+            .get_privacy(&mut txn, user_id)
+            .await
+            .context("Failed to get privacy settings from database")?
+            .ok_or(UserUpdatePrivacyError::NotFound)?;
+
+        let patch = patch.minimize(&privacy);
+        if !patch.is_update() {
+            return Ok(privacy);
+        }
+
+        self.user_repo
+            .update_privacy(&mut txn, user_id, patch.as_ref())
+            .await
+            .context("Failed to update privacy settings in database")?;
+
+        txn.commit().await?;
+
+        Ok(privacy.update(patch))
+    }
+
+    #[trace_instrument(skip(self, data))]
+    async fn upload_avatar(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+        data: Vec<u8>,
+    ) -> Result<UserComposite, UserUploadAvatarError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let mut user_composite = self
+            .user_repo
+            .get_composite(&mut txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or(UserUploadAvatarError::NotFound)?;
+
+        let avatar_url = self
+            .user_avatar
+            .store(user_id, data)
+            .await
+            .map(Some)
+            .map_err(|err| match err {
+                UserAvatarStoreError::TooLarge => UserUploadAvatarError::TooLarge,
+                UserAvatarStoreError::UnsupportedFormat => UserUploadAvatarError::UnsupportedFormat,
+                UserAvatarStoreError::InvalidImage => UserUploadAvatarError::InvalidImage,
+                UserAvatarStoreError::Other(err) => err.context("Failed to store avatar").into(),
+            })?;
+
+        self.user_repo
+            .update_avatar_url(&mut txn, user_id, &avatar_url)
+            .await
+            .context("Failed to update avatar url in database")?;
+        user_composite.profile.avatar_url = avatar_url;
+
+        txn.commit().await?;
+
+        Ok(user_composite)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn delete_avatar(
+        &self,
+        token: &AccessToken,
+        user_id: UserIdOrSelf,
+    ) -> Result<UserComposite, UserDeleteAvatarError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let user_id = user_id.unwrap_or(auth.user_id);
+        auth.ensure_self_or_admin(user_id).map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let mut user_composite = self
+            .user_repo
+            .get_composite(&mut txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or(UserDeleteAvatarError::NotFound)?;
+
+        if user_composite.profile.avatar_url.is_none() {
+            return Ok(user_composite);
+        }
+
+        self.user_repo
+            .update_avatar_url(&mut txn, user_id, &None)
+            .await
+            .context("Failed to update avatar url in database")?;
+        user_composite.profile.avatar_url = None;
+
+        txn.commit().await?;
+
+        self.user_avatar
+            .remove(user_id)
+            .await
+            .context("Failed to remove avatar")?;
+
+        Ok(user_composite)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn get_avatar(
+        &self,
+        token: Option<&AccessToken>,
+        user_id: UserId,
+        size: Option<u32>,
+    ) -> Result<Vec<u8>, UserGetAvatarError> {
+        let auth = match token {
+            Some(token) => Some(self.auth.authenticate(token).await.map_auth_err()?),
+            None => None,
+        };
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let user = self
+            .user_repo
+            .get_composite(&mut txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or(UserGetAvatarError::NotFound)?
+            .user;
+
+        let admin = auth.as_ref().is_some_and(|auth| auth.admin);
+        if !user.enabled && !admin {
+            return Err(UserGetAvatarError::NotFound);
+        }
+
+        let privacy = self
+            .user_repo
+            .get_privacy(&mut txn, user.id)
+            .await
+            .context("Failed to get privacy settings from database")?
+            .ok_or(UserGetAvatarError::NotFound)?;
+
+        if !is_visible(auth.as_ref(), user.id, privacy.avatar) {
+            return Err(UserGetAvatarError::NotFound);
+        }
+
+        self.user_avatar
+            .get(user_id, size)
+            .await
+            .context("Failed to get avatar")?
+            .ok_or(UserGetAvatarError::NotFound)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn list_invites(
+        &self,
+        token: &AccessToken,
+        created_by: Option<UserIdOrSelf>,
+    ) -> Result<Vec<Invite>, UserListInvitesError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+        let created_by = created_by.map(|user_id| user_id.unwrap_or(auth.user_id));
+        match created_by {
+            Some(user_id) => auth.ensure_self_or_admin(user_id).map_auth_err()?,
+            None => auth.ensure_admin().map_auth_err()?,
+        }
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        self.invite_repo
+            .list(&mut txn, created_by)
+            .await
+            .context("Failed to get invites from database")
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn create_invite(
+        &self,
+        token: &AccessToken,
+        request: UserCreateInviteRequest,
+    ) -> Result<Invite, UserCreateInviteError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let cmd = if auth.admin {
+            UserInviteCreateCommand {
+                created_by: Some(auth.user_id),
+                expires_at: request.expires_at,
+                max_uses: request.max_uses,
+                admin: request.admin,
+                cohort: request.cohort,
+            }
+        } else {
+            if request.expires_at.is_some()
+                || request.max_uses.is_some()
+                || request.admin
+                || request.cohort.is_some()
+            {
+                auth.ensure_admin().map_auth_err()?;
+            }
+
+            let now = self.time.now();
+            let valid_invites = self
+                .invite_repo
+                .count_valid_by_creator(&mut txn, auth.user_id, now)
+                .await
+                .context("Failed to count invites in database")?;
+            if valid_invites >= self.config.invite_limit {
+                return Err(UserCreateInviteError::TooManyInvites);
+            }
+
+            UserInviteCreateCommand {
+                created_by: Some(auth.user_id),
+                expires_at: Some(now + self.config.invite_ttl),
+                max_uses: Some(InviteMaxUses::try_new(1).unwrap()),
+                admin: false,
+                cohort: None,
+            }
+        };
+
+        let invite = self
+            .user_invite
+            .create(&mut txn, cmd)
+            .await
+            .context("Failed to create invite")?;
+
+        txn.commit().await?;
+
+        Ok(invite)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn delete_invite(
+        &self,
+        token: &AccessToken,
+        invite_id: InviteId,
+    ) -> Result<(), UserDeleteInviteError> {
+        let auth = self.auth.authenticate(token).await.map_auth_err()?;
+
+        let mut txn = self.db.begin_transaction().await?;
+
+        let invite = self
+            .invite_repo
+            .get(&mut txn, invite_id)
+            .await
+            .context("Failed to get invite from database")?
+            .ok_or(UserDeleteInviteError::NotFound)?;
+
+        if invite.created_by != Some(auth.user_id) {
+            auth.ensure_admin().map_auth_err()?;
+        }
+
+        self.invite_repo
+            .delete(&mut txn, invite_id)
+            .await
+            .context("Failed to delete invite from database")?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
+
+/// Whether a profile field with the given visibility may be shown to the
+/// visitor. The user themselves and administrators can always see all fields.
+fn is_visible(
+    auth: Option<&Authentication>,
+    user_id: UserId,
+    visibility: UserProfileVisibility,
+) -> bool {
+    auth.is_some_and(|auth| auth.admin || auth.user_id == user_id)
+        || visibility.is_visible(auth.is_some())
 }
@@ -0,0 +1,154 @@
+use academy_core_user_contracts::newsletter::{
+    UserNewsletterService, UserNewsletterUnsubscribeUrls,
+};
+use academy_di::Build;
+use academy_models::{
+    url::Url,
+    user::{NewsletterUnsubscribeToken, UserId},
+};
+use academy_shared_contracts::jwt::JwtService;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::UserFeatureConfig;
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct UserNewsletterServiceImpl<Jwt> {
+    jwt: Jwt,
+    config: UserFeatureConfig,
+}
+
+impl<Jwt> UserNewsletterService for UserNewsletterServiceImpl<Jwt>
+where
+    Jwt: JwtService,
+{
+    #[trace_instrument(skip(self))]
+    fn unsubscribe_urls(&self, user_id: UserId) -> anyhow::Result<UserNewsletterUnsubscribeUrls> {
+        let token = self
+            .jwt
+            .sign::<_, NewsletterUnsubscribeToken>(
+                UnsubscribeTokenData {
+                    newsletter_unsubscribe: user_id,
+                },
+                self.config.newsletter_unsubscribe_token_ttl,
+            )
+            .context("Failed to sign unsubscribe token")?;
+
+        let link = self
+            .config
+            .newsletter_unsubscribe_redirect_url
+            .parse()
+            .context("Failed to parse newsletter unsubscribe redirect url")?;
+
+        Ok(UserNewsletterUnsubscribeUrls {
+            link: with_token(link, &token),
+            one_click: with_token((*self.config.newsletter_unsubscribe_url).clone(), &token),
+        })
+    }
+
+    #[trace_instrument(skip(self))]
+    fn verify_unsubscribe_token(&self, token: &NewsletterUnsubscribeToken) -> Option<UserId> {
+        self.jwt
+            .verify::<_, UnsubscribeTokenData>(token)
+            .ok()
+            .map(|data| data.newsletter_unsubscribe)
+    }
+}
+
+fn with_token(mut url: Url, token: &NewsletterUnsubscribeToken) -> Url {
+    url.query_pairs_mut().append_pair("token", token);
+    url
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct UnsubscribeTokenData {
+    newsletter_unsubscribe: UserId,
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_demo::user::FOO;
+    use academy_shared_contracts::jwt::{MockJwtService, VerifyJwtError};
+
+    use super::*;
+
+    type Sut = UserNewsletterServiceImpl<MockJwtService>;
+
+    #[test]
+    fn unsubscribe_urls() {
+        // Arrange
+        let config = UserFeatureConfig::default();
+
+        let jwt = MockJwtService::new().with_sign(
+            UnsubscribeTokenData {
+                newsletter_unsubscribe: FOO.user.id,
+            },
+            config.newsletter_unsubscribe_token_ttl,
+            Ok(NewsletterUnsubscribeToken::from(
+                "the.unsubscribe+token".to_owned(),
+            )),
+        );
+
+        let sut = UserNewsletterServiceImpl { jwt, config };
+
+        // Act
+        let result = sut.unsubscribe_urls(FOO.user.id);
+
+        // Assert
+        let result = result.unwrap();
+        assert_eq!(
+            result.link.as_str(),
+            "https://bootstrap.academy/account/newsletter/unsubscribe?token=the.unsubscribe%2Btoken"
+        );
+        assert_eq!(
+            result.one_click.as_str(),
+            "https://bootstrap.academy/auth/newsletter/unsubscribe?token=the.unsubscribe%2Btoken"
+        );
+    }
+
+    #[test]
+    fn verify_unsubscribe_token_ok() {
+        // Arrange
+        let token = NewsletterUnsubscribeToken::from("the token".to_owned());
+
+        let jwt = MockJwtService::new().with_verify(
+            token.clone(),
+            Ok(UnsubscribeTokenData {
+                newsletter_unsubscribe: FOO.user.id,
+            }),
+        );
+
+        let sut = UserNewsletterServiceImpl {
+            jwt,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.verify_unsubscribe_token(&token);
+
+        // Assert
+        assert_eq!(result, Some(FOO.user.id));
+    }
+
+    #[test]
+    fn verify_unsubscribe_token_invalid() {
+        // Arrange
+        let token = NewsletterUnsubscribeToken::from("the token".to_owned());
+
+        let jwt = MockJwtService::new()
+            .with_verify::<_, UnsubscribeTokenData>(token.clone(), Err(VerifyJwtError::Invalid));
+
+        let sut = UserNewsletterServiceImpl {
+            jwt,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.verify_unsubscribe_token(&token);
+
+        // Assert
+        assert_eq!(result, None);
+    }
+}
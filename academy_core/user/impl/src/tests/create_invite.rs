@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{
+    invite::{MockUserInviteService, UserInviteCreateCommand},
+    UserCreateInviteError, UserCreateInviteRequest, UserFeatureService,
+};
+use academy_demo::{
+    invite::{ADMIN_INVITE_1, FOO_INVITE_1},
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::auth::{AuthError, AuthorizeError};
+use academy_persistence_contracts::{invite::MockInviteRepository, MockDatabase};
+use academy_shared_contracts::time::MockTimeService;
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, UserFeatureConfig, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_admin() {
+    // Arrange
+    let expected = ADMIN_INVITE_1.clone();
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_invite = MockUserInviteService::new().with_create(
+        UserInviteCreateCommand {
+            created_by: Some(ADMIN.user.id),
+            expires_at: expected.expires_at,
+            max_uses: expected.max_uses,
+            admin: expected.admin,
+            cohort: expected.cohort.clone(),
+        },
+        expected.clone(),
+    );
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_invite,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_invite(
+            &"token".into(),
+            UserCreateInviteRequest {
+                expires_at: expected.expires_at,
+                max_uses: expected.max_uses,
+                admin: expected.admin,
+                cohort: expected.cohort.clone(),
+            },
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_user() {
+    // Arrange
+    let expected = FOO_INVITE_1.clone();
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let time = MockTimeService::new().with_now(expected.created_at);
+
+    let invite_repo = MockInviteRepository::new().with_count_valid_by_creator(
+        FOO.user.id,
+        expected.created_at,
+        2,
+    );
+
+    let user_invite = MockUserInviteService::new().with_create(
+        UserInviteCreateCommand {
+            created_by: Some(FOO.user.id),
+            expires_at: Some(expected.created_at + Duration::from_secs(30 * 24 * 3600)),
+            max_uses: Some(1.try_into().unwrap()),
+            admin: false,
+            cohort: None,
+        },
+        expected.clone(),
+    );
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        time,
+        invite_repo,
+        user_invite,
+        config: UserFeatureConfig {
+            invite_ttl: Duration::from_secs(30 * 24 * 3600),
+            ..Default::default()
+        },
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_invite(&"token".into(), UserCreateInviteRequest::default())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn too_many_invites() {
+    // Arrange
+    let now = FOO_INVITE_1.created_at;
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let time = MockTimeService::new().with_now(now);
+
+    let invite_repo = MockInviteRepository::new().with_count_valid_by_creator(FOO.user.id, now, 3);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        time,
+        invite_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_invite(&"token".into(), UserCreateInviteRequest::default())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserCreateInviteError::TooManyInvites));
+}
+
+#[tokio::test]
+async fn custom_invite_not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_invite(
+            &"token".into(),
+            UserCreateInviteRequest {
+                max_uses: Some(10.try_into().unwrap()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserCreateInviteError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
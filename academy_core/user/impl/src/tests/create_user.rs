@@ -1,23 +1,27 @@
 use academy_core_oauth2_contracts::registration::MockOAuth2RegistrationService;
 use academy_core_session_contracts::session::MockSessionService;
 use academy_core_user_contracts::{
+    email_policy::{MockUserEmailPolicyService, UserEmailPolicyCheckError},
+    invite::{MockUserInviteService, UserInviteRedeemError},
     user::{MockUserService, UserCreateCommand},
     UserCreateError, UserCreateRequest, UserFeatureService,
 };
 use academy_demo::{
+    invite::{ADMIN_INVITE_1, FOO_INVITE_1},
     oauth2::{FOO_OAUTH2_LINK_1, TEST_OAUTH2_PROVIDER_ID},
     session::FOO_1,
     user::FOO,
 };
 use academy_models::{
     auth::Login,
+    invite::RegistrationMode,
     oauth2::{OAuth2Registration, OAuth2RegistrationToken},
 };
 use academy_persistence_contracts::MockDatabase;
 use academy_shared_contracts::captcha::{CaptchaCheckError, MockCaptchaService};
-use academy_utils::assert_matches;
+use academy_utils::{assert_matches, Apply};
 
-use crate::{tests::Sut, UserFeatureServiceImpl};
+use crate::{tests::Sut, UserFeatureConfig, UserFeatureServiceImpl};
 
 #[tokio::test]
 async fn ok() {
@@ -28,6 +32,8 @@ async fn ok() {
         email: FOO.user.email.clone().unwrap(),
         password: Some("secure password".try_into().unwrap()),
         oauth2_registration_token: None,
+        invite_code: None,
+        locale: None,
     };
 
     let expected = Login {
@@ -41,6 +47,9 @@ async fn ok() {
 
     let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
 
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
     let user = MockUserService::new().with_create(req_to_cmd(&request), Ok(FOO.clone()));
 
     let session = MockSessionService::new().with_create(
@@ -53,6 +62,7 @@ async fn ok() {
     let sut = UserFeatureServiceImpl {
         db,
         captcha,
+        user_email_policy,
         user,
         session,
         ..Sut::default()
@@ -71,6 +81,64 @@ async fn ok() {
     assert_eq!(result.unwrap(), expected);
 }
 
+#[tokio::test]
+async fn ok_locale() {
+    // Arrange
+    let request = UserCreateRequest {
+        name: FOO.user.name.clone(),
+        display_name: FOO.profile.display_name.clone(),
+        email: FOO.user.email.clone().unwrap(),
+        password: Some("secure password".try_into().unwrap()),
+        oauth2_registration_token: None,
+        invite_code: None,
+        locale: Some("en".try_into().unwrap()),
+    };
+
+    let foo = FOO
+        .clone()
+        .with(|u| u.user.locale = "en".try_into().unwrap());
+
+    let expected = Login {
+        user_composite: foo.clone(),
+        session: FOO_1.clone(),
+        access_token: "the access token".into(),
+        refresh_token: "some refresh token".into(),
+    };
+
+    let db = MockDatabase::build(true);
+
+    let captcha = MockCaptchaService::new().with_check(None, Ok(()));
+
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
+    let user = MockUserService::new().with_create(req_to_cmd(&request), Ok(foo.clone()));
+
+    let session = MockSessionService::new().with_create(
+        foo,
+        FOO_1.device_name.clone(),
+        true,
+        expected.clone(),
+    );
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        captcha,
+        user_email_policy,
+        user,
+        session,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_user(request, FOO_1.device_name.clone(), None)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
 #[tokio::test]
 async fn ok_oauth2() {
     // Arrange
@@ -85,6 +153,8 @@ async fn ok_oauth2() {
         email: FOO.user.email.clone().unwrap(),
         password: None,
         oauth2_registration_token: Some(token.clone()),
+        invite_code: None,
+        locale: None,
     };
 
     let expected = Login {
@@ -98,6 +168,9 @@ async fn ok_oauth2() {
 
     let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
 
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
     let oauth2_registration = MockOAuth2RegistrationService::new()
         .with_get(
             token.clone(),
@@ -120,6 +193,7 @@ async fn ok_oauth2() {
     let sut = UserFeatureServiceImpl {
         db,
         captcha,
+        user_email_policy,
         user,
         oauth2_registration,
         session,
@@ -148,6 +222,8 @@ async fn no_login_method() {
         email: FOO.user.email.clone().unwrap(),
         password: None,
         oauth2_registration_token: None,
+        invite_code: None,
+        locale: None,
     };
 
     let sut = Sut::default();
@@ -170,6 +246,8 @@ async fn invalid_recaptcha_response() {
         email: FOO.user.email.clone().unwrap(),
         password: Some("secure password".try_into().unwrap()),
         oauth2_registration_token: None,
+        invite_code: None,
+        locale: None,
     };
 
     let captcha =
@@ -202,12 +280,17 @@ async fn name_conflict() {
         email: FOO.user.email.clone().unwrap(),
         password: Some("secure password".try_into().unwrap()),
         oauth2_registration_token: None,
+        invite_code: None,
+        locale: None,
     };
 
     let db = MockDatabase::build(false);
 
     let captcha = MockCaptchaService::new().with_check(None, Ok(()));
 
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
     let user = MockUserService::new().with_create(
         req_to_cmd(&request),
         Err(academy_core_user_contracts::user::UserCreateError::NameConflict),
@@ -216,6 +299,7 @@ async fn name_conflict() {
     let sut = UserFeatureServiceImpl {
         db,
         captcha,
+        user_email_policy,
         user,
         ..Sut::default()
     };
@@ -238,12 +322,17 @@ async fn email_conflict() {
         email: FOO.user.email.clone().unwrap(),
         password: Some("secure password".try_into().unwrap()),
         oauth2_registration_token: None,
+        invite_code: None,
+        locale: None,
     };
 
     let db = MockDatabase::build(false);
 
     let captcha = MockCaptchaService::new().with_check(None, Ok(()));
 
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
     let user = MockUserService::new().with_create(
         req_to_cmd(&request),
         Err(academy_core_user_contracts::user::UserCreateError::EmailConflict),
@@ -252,6 +341,7 @@ async fn email_conflict() {
     let sut = UserFeatureServiceImpl {
         db,
         captcha,
+        user_email_policy,
         user,
         ..Sut::default()
     };
@@ -278,14 +368,20 @@ async fn oauth2_invalid_registration_token() {
         email: FOO.user.email.clone().unwrap(),
         password: None,
         oauth2_registration_token: Some(token.clone()),
+        invite_code: None,
+        locale: None,
     };
 
     let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
 
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
     let oauth2_registration = MockOAuth2RegistrationService::new().with_get(token, None);
 
     let sut = UserFeatureServiceImpl {
         captcha,
+        user_email_policy,
         oauth2_registration,
         ..Sut::default()
     };
@@ -320,12 +416,17 @@ async fn oauth2_remote_already_linked() {
                 .try_into()
                 .unwrap(),
         ),
+        invite_code: None,
+        locale: None,
     };
 
     let db = MockDatabase::build(false);
 
     let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
 
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
     let oauth2_registration = MockOAuth2RegistrationService::new().with_get(
         token,
         Some(OAuth2Registration {
@@ -342,6 +443,7 @@ async fn oauth2_remote_already_linked() {
     let sut = UserFeatureServiceImpl {
         db,
         captcha,
+        user_email_policy,
         user,
         oauth2_registration,
         ..Sut::default()
@@ -360,6 +462,273 @@ async fn oauth2_remote_already_linked() {
     assert_matches!(result, Err(UserCreateError::RemoteAlreadyLinked));
 }
 
+#[tokio::test]
+async fn ok_invite() {
+    // Arrange
+    let request = UserCreateRequest {
+        name: FOO.user.name.clone(),
+        display_name: FOO.profile.display_name.clone(),
+        email: FOO.user.email.clone().unwrap(),
+        password: Some("secure password".try_into().unwrap()),
+        oauth2_registration_token: None,
+        invite_code: Some(ADMIN_INVITE_1.code.clone()),
+        locale: None,
+    };
+
+    let expected = Login {
+        user_composite: FOO.clone(),
+        session: FOO_1.clone(),
+        access_token: "the access token".into(),
+        refresh_token: "some refresh token".into(),
+    };
+
+    let db = MockDatabase::build(true);
+
+    let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
+
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
+    let user_invite = MockUserInviteService::new()
+        .with_get_valid(ADMIN_INVITE_1.code.clone(), Some(ADMIN_INVITE_1.clone()))
+        .with_redeem(ADMIN_INVITE_1.id, FOO.user.id, Ok(()));
+
+    let user = MockUserService::new().with_create(
+        UserCreateCommand {
+            tags: vec!["beta".try_into().unwrap()].try_into().unwrap(),
+            ..req_to_cmd(&request)
+        },
+        Ok(FOO.clone()),
+    );
+
+    let session = MockSessionService::new().with_create(
+        FOO.clone(),
+        FOO_1.device_name.clone(),
+        true,
+        expected.clone(),
+    );
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        captcha,
+        user_email_policy,
+        user,
+        user_invite,
+        session,
+        config: UserFeatureConfig {
+            registration_mode: RegistrationMode::InviteOnly,
+            ..Default::default()
+        },
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_user(
+            request,
+            FOO_1.device_name.clone(),
+            Some("resp".try_into().unwrap()),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn registration_closed() {
+    // Arrange
+    let request = UserCreateRequest {
+        name: FOO.user.name.clone(),
+        display_name: FOO.profile.display_name.clone(),
+        email: FOO.user.email.clone().unwrap(),
+        password: Some("secure password".try_into().unwrap()),
+        oauth2_registration_token: None,
+        invite_code: Some(ADMIN_INVITE_1.code.clone()),
+        locale: None,
+    };
+
+    let sut = UserFeatureServiceImpl {
+        config: UserFeatureConfig {
+            registration_mode: RegistrationMode::Closed,
+            ..Default::default()
+        },
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_user(request, FOO_1.device_name.clone(), None)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserCreateError::RegistrationClosed));
+}
+
+#[tokio::test]
+async fn invite_code_required() {
+    // Arrange
+    let request = UserCreateRequest {
+        name: FOO.user.name.clone(),
+        display_name: FOO.profile.display_name.clone(),
+        email: FOO.user.email.clone().unwrap(),
+        password: Some("secure password".try_into().unwrap()),
+        oauth2_registration_token: None,
+        invite_code: None,
+        locale: None,
+    };
+
+    let sut = UserFeatureServiceImpl {
+        config: UserFeatureConfig {
+            registration_mode: RegistrationMode::InviteOnly,
+            ..Default::default()
+        },
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_user(request, FOO_1.device_name.clone(), None)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserCreateError::InviteCodeRequired));
+}
+
+#[tokio::test]
+async fn invalid_invite_code() {
+    // Arrange
+    let request = UserCreateRequest {
+        name: FOO.user.name.clone(),
+        display_name: FOO.profile.display_name.clone(),
+        email: FOO.user.email.clone().unwrap(),
+        password: Some("secure password".try_into().unwrap()),
+        oauth2_registration_token: None,
+        invite_code: Some(FOO_INVITE_1.code.clone()),
+        locale: None,
+    };
+
+    let db = MockDatabase::build(false);
+
+    let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
+
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
+    let user_invite = MockUserInviteService::new().with_get_valid(FOO_INVITE_1.code.clone(), None);
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        captcha,
+        user_email_policy,
+        user_invite,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_user(
+            request,
+            FOO_1.device_name.clone(),
+            Some("resp".try_into().unwrap()),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserCreateError::InvalidInviteCode));
+}
+
+#[tokio::test]
+async fn invite_exhausted() {
+    // Arrange
+    let request = UserCreateRequest {
+        name: FOO.user.name.clone(),
+        display_name: FOO.profile.display_name.clone(),
+        email: FOO.user.email.clone().unwrap(),
+        password: Some("secure password".try_into().unwrap()),
+        oauth2_registration_token: None,
+        invite_code: Some(FOO_INVITE_1.code.clone()),
+        locale: None,
+    };
+
+    let db = MockDatabase::build(false);
+
+    let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
+
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(FOO.user.email.clone().unwrap(), Ok(()));
+
+    let user_invite = MockUserInviteService::new()
+        .with_get_valid(FOO_INVITE_1.code.clone(), Some(FOO_INVITE_1.clone()))
+        .with_redeem(
+            FOO_INVITE_1.id,
+            FOO.user.id,
+            Err(UserInviteRedeemError::Invalid),
+        );
+
+    let user = MockUserService::new().with_create(req_to_cmd(&request), Ok(FOO.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        captcha,
+        user_email_policy,
+        user,
+        user_invite,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_user(
+            request,
+            FOO_1.device_name.clone(),
+            Some("resp".try_into().unwrap()),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserCreateError::InvalidInviteCode));
+}
+
+#[tokio::test]
+async fn email_not_allowed() {
+    // Arrange
+    let request = UserCreateRequest {
+        name: FOO.user.name.clone(),
+        display_name: FOO.profile.display_name.clone(),
+        email: "foo@mailinator.com".parse().unwrap(),
+        password: Some("secure password".try_into().unwrap()),
+        oauth2_registration_token: None,
+        invite_code: None,
+        locale: None,
+    };
+
+    let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
+
+    let user_email_policy = MockUserEmailPolicyService::new().with_check(
+        request.email.clone(),
+        Err(UserEmailPolicyCheckError::Rejected),
+    );
+
+    let sut = UserFeatureServiceImpl {
+        captcha,
+        user_email_policy,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .create_user(
+            request,
+            FOO_1.device_name.clone(),
+            Some("resp".try_into().unwrap()),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserCreateError::EmailNotAllowed));
+}
+
 fn req_to_cmd(req: &UserCreateRequest) -> UserCreateCommand {
     UserCreateCommand {
         name: req.name.clone(),
@@ -376,5 +745,10 @@ fn req_to_cmd(req: &UserCreateRequest) -> UserCreateCommand {
                 provider_id: TEST_OAUTH2_PROVIDER_ID.clone(),
                 remote_user: FOO_OAUTH2_LINK_1.remote_user.clone(),
             }),
+        tags: Default::default(),
+        locale: req
+            .locale
+            .clone()
+            .unwrap_or_else(|| UserFeatureConfig::default().default_locale),
     }
 }
@@ -0,0 +1,174 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{
+    avatar::MockUserAvatarService, UserDeleteAvatarError, UserFeatureService,
+};
+use academy_demo::{
+    session::{ADMIN_1, BAR_1, FOO_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthenticateError, AuthorizeError},
+    user::{UserComposite, UserIdOrSelf},
+};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::{assert_matches, Apply};
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_self() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(foo_with_avatar()))
+        .with_update_avatar_url(FOO.user.id, None, true);
+
+    let user_avatar = MockUserAvatarService::new().with_remove(FOO.user.id);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_avatar(&"token".into(), UserIdOrSelf::Slf).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *FOO);
+}
+
+#[tokio::test]
+async fn ok_admin() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(foo_with_avatar()))
+        .with_update_avatar_url(FOO.user.id, None, true);
+
+    let user_avatar = MockUserAvatarService::new().with_remove(FOO.user.id);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_avatar(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *FOO);
+}
+
+#[tokio::test]
+async fn ok_no_avatar() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_avatar(&"token".into(), UserIdOrSelf::Slf).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), *FOO);
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_avatar(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserDeleteAvatarError::Auth(AuthError::Authenticate(
+            AuthenticateError::InvalidToken
+        )))
+    );
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_avatar(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserDeleteAvatarError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_avatar(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_matches!(result, Err(UserDeleteAvatarError::NotFound));
+}
+
+fn foo_with_avatar() -> UserComposite {
+    FOO.clone().with(|u| {
+        u.profile.avatar_url = Some(
+            "https://bootstrap.academy/auth/avatars/foo?v=1"
+                .parse()
+                .unwrap(),
+        )
+    })
+}
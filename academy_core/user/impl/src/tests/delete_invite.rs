@@ -0,0 +1,115 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{UserDeleteInviteError, UserFeatureService};
+use academy_demo::{
+    invite::{ADMIN_INVITE_1, FOO_INVITE_1},
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::auth::{AuthError, AuthorizeError};
+use academy_persistence_contracts::{invite::MockInviteRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_creator() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let invite_repo = MockInviteRepository::new()
+        .with_get(FOO_INVITE_1.id, Some(FOO_INVITE_1.clone()))
+        .with_delete(FOO_INVITE_1.id, true);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        invite_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_invite(&"token".into(), FOO_INVITE_1.id).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn ok_admin() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let invite_repo = MockInviteRepository::new()
+        .with_get(FOO_INVITE_1.id, Some(FOO_INVITE_1.clone()))
+        .with_delete(FOO_INVITE_1.id, true);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        invite_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_invite(&"token".into(), FOO_INVITE_1.id).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invite_repo = MockInviteRepository::new().with_get(FOO_INVITE_1.id, None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        invite_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_invite(&"token".into(), FOO_INVITE_1.id).await;
+
+    // Assert
+    assert_matches!(result, Err(UserDeleteInviteError::NotFound));
+}
+
+#[tokio::test]
+async fn other_user_not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invite_repo =
+        MockInviteRepository::new().with_get(ADMIN_INVITE_1.id, Some(ADMIN_INVITE_1.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        invite_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.delete_invite(&"token".into(), ADMIN_INVITE_1.id).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserDeleteInviteError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
@@ -1,5 +1,7 @@
 use academy_auth_contracts::MockAuthService;
-use academy_core_user_contracts::{UserDeleteError, UserFeatureService};
+use academy_core_user_contracts::{
+    avatar::MockUserAvatarService, user::MockUserService, UserDeleteError, UserFeatureService,
+};
 use academy_demo::{
     session::{ADMIN_1, BAR_1, FOO_1},
     user::{ADMIN, BAR, FOO},
@@ -8,7 +10,7 @@ use academy_models::{
     auth::{AuthError, AuthenticateError, AuthorizeError},
     user::UserIdOrSelf,
 };
-use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_persistence_contracts::MockDatabase;
 use academy_utils::assert_matches;
 
 use crate::{tests::Sut, UserFeatureServiceImpl};
@@ -16,18 +18,19 @@ use crate::{tests::Sut, UserFeatureServiceImpl};
 #[tokio::test]
 async fn ok_self() {
     // Arrange
-    let auth = MockAuthService::new()
-        .with_authenticate(Some((FOO.user.clone(), FOO_1.clone())))
-        .with_invalidate_access_tokens(FOO.user.id);
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
 
     let db = MockDatabase::build(true);
 
-    let user_repo = MockUserRepository::new().with_delete(FOO.user.id, true);
+    let user = MockUserService::new().with_delete(FOO.user.id, true);
+
+    let user_avatar = MockUserAvatarService::new().with_remove(FOO.user.id);
 
     let sut = UserFeatureServiceImpl {
         auth,
         db,
-        user_repo,
+        user,
+        user_avatar,
         ..Sut::default()
     };
 
@@ -41,18 +44,20 @@ async fn ok_self() {
 #[tokio::test]
 async fn ok_admin() {
     // Arrange
-    let auth = MockAuthService::new()
-        .with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())))
-        .with_invalidate_access_tokens(FOO.user.id);
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
 
     let db = MockDatabase::build(true);
 
-    let user_repo = MockUserRepository::new().with_delete(FOO.user.id, true);
+    let user = MockUserService::new().with_delete(FOO.user.id, true);
+
+    let user_avatar = MockUserAvatarService::new().with_remove(FOO.user.id);
 
     let sut = UserFeatureServiceImpl {
         auth,
         db,
-        user_repo,
+        user,
+        user_avatar,
         ..Sut::default()
     };
 
@@ -110,18 +115,17 @@ async fn unauthorized() {
 #[tokio::test]
 async fn not_found() {
     // Arrange
-    let auth = MockAuthService::new()
-        .with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())))
-        .with_invalidate_access_tokens(FOO.user.id);
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
 
     let db = MockDatabase::build(false);
 
-    let user_repo = MockUserRepository::new().with_delete(FOO.user.id, false);
+    let user = MockUserService::new().with_delete(FOO.user.id, false);
 
     let sut = UserFeatureServiceImpl {
         auth,
         db,
-        user_repo,
+        user,
         ..Sut::default()
     };
 
@@ -0,0 +1,257 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{
+    avatar::MockUserAvatarService, UserFeatureService, UserGetAvatarError,
+};
+use academy_demo::{
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, ADMIN2, BAR, FOO},
+};
+use academy_models::user::{UserPrivacy, UserProfileVisibility};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(FOO.clone()))
+        .with_get_privacy(FOO.user.id, Some(UserPrivacy::default()));
+
+    let user_avatar = MockUserAvatarService::new().with_get(FOO.user.id, Some(128), Some(vec![42]));
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_avatar(None, FOO.user.id, Some(128)).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), [42]);
+}
+
+#[tokio::test]
+async fn ok_restricted_authenticated() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(ADMIN2.user.id, Some(ADMIN2.clone()))
+        .with_get_privacy(ADMIN2.user.id, Some(users_only_privacy()));
+
+    let user_avatar = MockUserAvatarService::new().with_get(ADMIN2.user.id, None, Some(vec![42]));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_avatar(Some(&"token".into()), ADMIN2.user.id, None)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), [42]);
+}
+
+#[tokio::test]
+async fn ok_private_self() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(FOO.clone()))
+        .with_get_privacy(FOO.user.id, Some(private_privacy()));
+
+    let user_avatar = MockUserAvatarService::new().with_get(FOO.user.id, None, Some(vec![42]));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_avatar(Some(&"token".into()), FOO.user.id, None)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), [42]);
+}
+
+#[tokio::test]
+async fn hidden_unauthenticated() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(FOO.clone()))
+        .with_get_privacy(FOO.user.id, Some(users_only_privacy()));
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_avatar(None, FOO.user.id, None).await;
+
+    // Assert
+    assert_matches!(result, Err(UserGetAvatarError::NotFound));
+}
+
+#[tokio::test]
+async fn hidden_private_authenticated() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(ADMIN2.user.id, Some(ADMIN2.clone()))
+        .with_get_privacy(ADMIN2.user.id, Some(private_privacy()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_avatar(Some(&"token".into()), ADMIN2.user.id, None)
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserGetAvatarError::NotFound));
+}
+
+#[tokio::test]
+async fn ok_admin_private_disabled() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(BAR.user.id, Some(BAR.clone()))
+        .with_get_privacy(BAR.user.id, Some(private_privacy()));
+
+    let user_avatar = MockUserAvatarService::new().with_get(BAR.user.id, None, Some(vec![42]));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_avatar(Some(&"token".into()), BAR.user.id, None)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), [42]);
+}
+
+#[tokio::test]
+async fn disabled() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, Some(BAR.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_avatar(None, BAR.user.id, None).await;
+
+    // Assert
+    assert_matches!(result, Err(UserGetAvatarError::NotFound));
+}
+
+#[tokio::test]
+async fn user_not_found() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_avatar(None, FOO.user.id, None).await;
+
+    // Assert
+    assert_matches!(result, Err(UserGetAvatarError::NotFound));
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(FOO.clone()))
+        .with_get_privacy(FOO.user.id, Some(UserPrivacy::default()));
+
+    let user_avatar = MockUserAvatarService::new().with_get(FOO.user.id, None, None);
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_avatar(None, FOO.user.id, None).await;
+
+    // Assert
+    assert_matches!(result, Err(UserGetAvatarError::NotFound));
+}
+
+fn users_only_privacy() -> UserPrivacy {
+    UserPrivacy {
+        avatar: UserProfileVisibility::Users,
+        ..Default::default()
+    }
+}
+
+fn private_privacy() -> UserPrivacy {
+    UserPrivacy {
+        avatar: UserProfileVisibility::Private,
+        ..Default::default()
+    }
+}
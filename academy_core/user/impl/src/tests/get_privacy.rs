@@ -0,0 +1,137 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{UserFeatureService, UserGetPrivacyError};
+use academy_demo::{
+    session::{ADMIN_1, BAR_1, FOO_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthenticateError, AuthorizeError},
+    user::{UserIdOrSelf, UserPrivacy, UserProfileVisibility},
+};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_self() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_privacy(FOO.user.id, Some(privacy()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_privacy(&"token".into(), UserIdOrSelf::Slf).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), privacy());
+}
+
+#[tokio::test]
+async fn ok_admin() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_privacy(FOO.user.id, Some(privacy()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_privacy(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), privacy());
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_privacy(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserGetPrivacyError::Auth(AuthError::Authenticate(
+            AuthenticateError::InvalidToken
+        )))
+    );
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_privacy(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserGetPrivacyError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_privacy(FOO.user.id, None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_privacy(&"token".into(), FOO.user.id.into()).await;
+
+    // Assert
+    assert_matches!(result, Err(UserGetPrivacyError::NotFound));
+}
+
+fn privacy() -> UserPrivacy {
+    UserPrivacy {
+        bio: UserProfileVisibility::Users,
+        avatar: UserProfileVisibility::Private,
+        ..Default::default()
+    }
+}
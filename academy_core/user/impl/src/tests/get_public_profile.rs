@@ -0,0 +1,218 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{UserFeatureService, UserGetPublicProfileError};
+use academy_demo::{
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, ADMIN2, BAR, FOO},
+};
+use academy_models::user::{UserComposite, UserPrivacy, UserProfileVisibility, UserPublicProfile};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_public() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite_by_name(FOO.user.name.clone(), Some(FOO.clone()))
+        .with_get_privacy(FOO.user.id, Some(UserPrivacy::default()));
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_public_profile(None, FOO.user.name.clone()).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), full_profile());
+}
+
+#[tokio::test]
+async fn ok_restricted() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite_by_name(FOO.user.name.clone(), Some(FOO.clone()))
+        .with_get_privacy(FOO.user.id, Some(restricted_privacy()));
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_public_profile(None, FOO.user.name.clone()).await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        UserPublicProfile {
+            bio: None,
+            tags: None,
+            created_at: None,
+            ..full_profile()
+        }
+    );
+}
+
+#[tokio::test]
+async fn ok_restricted_authenticated() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite_by_name(FOO.user.name.clone(), Some(FOO.clone()))
+        .with_get_privacy(FOO.user.id, Some(restricted_privacy()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_public_profile(Some(&"token".into()), FOO.user.name.clone())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), full_profile());
+}
+
+#[tokio::test]
+async fn ok_restricted_authenticated_user() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite_by_name(ADMIN2.user.name.clone(), Some(ADMIN2.clone()))
+        .with_get_privacy(ADMIN2.user.id, Some(restricted_privacy()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_public_profile(Some(&"token".into()), ADMIN2.user.name.clone())
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        UserPublicProfile {
+            tags: None,
+            ..make_profile(&ADMIN2)
+        }
+    );
+}
+
+#[tokio::test]
+async fn ok_restricted_self() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite_by_name(FOO.user.name.clone(), Some(FOO.clone()))
+        .with_get_privacy(FOO.user.id, Some(restricted_privacy()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .get_public_profile(Some(&"token".into()), FOO.user.name.clone())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), full_profile());
+}
+
+#[tokio::test]
+async fn disabled() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite_by_name(BAR.user.name.clone(), Some(BAR.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_public_profile(None, BAR.user.name.clone()).await;
+
+    // Assert
+    assert_matches!(result, Err(UserGetPublicProfileError::NotFound));
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let db = MockDatabase::build(false);
+
+    let user_repo =
+        MockUserRepository::new().with_get_composite_by_name(FOO.user.name.clone(), None);
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.get_public_profile(None, FOO.user.name.clone()).await;
+
+    // Assert
+    assert_matches!(result, Err(UserGetPublicProfileError::NotFound));
+}
+
+fn full_profile() -> UserPublicProfile {
+    make_profile(&FOO)
+}
+
+fn make_profile(user: &UserComposite) -> UserPublicProfile {
+    UserPublicProfile {
+        id: user.user.id,
+        name: user.user.name.clone(),
+        display_name: Some(user.profile.display_name.clone()),
+        bio: Some(user.profile.bio.clone()),
+        tags: Some(user.profile.tags.clone()),
+        created_at: Some(user.user.created_at),
+        avatar_url: user.profile.avatar_url.clone(),
+    }
+}
+
+fn restricted_privacy() -> UserPrivacy {
+    UserPrivacy {
+        bio: UserProfileVisibility::Users,
+        tags: UserProfileVisibility::Private,
+        registration: UserProfileVisibility::Users,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,143 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{UserFeatureService, UserListInvitesError};
+use academy_demo::{
+    invite::{ADMIN_INVITE_1, ALL_INVITES, FOO_INVITE_1},
+    session::{ADMIN_1, FOO_1},
+    user::{ADMIN, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthorizeError},
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{invite::MockInviteRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_all() {
+    // Arrange
+    let expected = ALL_INVITES.iter().copied().cloned().collect::<Vec<_>>();
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invite_repo = MockInviteRepository::new().with_list(None, expected.clone());
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        invite_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_invites(&"token".into(), None).await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_self() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invite_repo =
+        MockInviteRepository::new().with_list(Some(FOO.user.id), vec![FOO_INVITE_1.clone()]);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        invite_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_invites(&"token".into(), Some(UserIdOrSelf::Slf))
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), vec![FOO_INVITE_1.clone()]);
+}
+
+#[tokio::test]
+async fn ok_admin_other_user() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let invite_repo =
+        MockInviteRepository::new().with_list(Some(FOO.user.id), vec![FOO_INVITE_1.clone()]);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        invite_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_invites(&"token".into(), Some(FOO.user.id.into()))
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), vec![FOO_INVITE_1.clone()]);
+}
+
+#[tokio::test]
+async fn all_not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_invites(&"token".into(), None).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserListInvitesError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn other_user_not_admin() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .list_invites(
+            &"token".into(),
+            Some(ADMIN_INVITE_1.created_by.unwrap().into()),
+        )
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserListInvitesError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
@@ -4,13 +4,14 @@ use academy_core_user_contracts::{
     UserFeatureService, UserListError,
 };
 use academy_demo::{
+    oauth2::TEST_OAUTH2_PROVIDER_ID,
     session::{ADMIN_1, FOO_1},
     user::{ADMIN, ALL_USERS, FOO},
 };
 use academy_models::{
     auth::{AuthError, AuthenticateError, AuthorizeError},
-    pagination::PaginationSlice,
-    user::UserFilter,
+    pagination::{PaginationSlice, SortDirection},
+    user::{UserFilter, UserSortField, UserSorting},
 };
 use academy_persistence_contracts::MockDatabase;
 use academy_utils::assert_matches;
@@ -25,13 +26,14 @@ async fn ok() {
     let expected = UserListResult {
         total: 42,
         user_composites: ALL_USERS.iter().copied().cloned().collect(),
+        next_cursor: None,
     };
 
     let db = MockDatabase::build(false);
     let auth =
         MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
 
-    let user = MockUserService::new().with_list(query.clone(), expected.clone());
+    let user = MockUserService::new().with_list(query.clone(), Ok(expected.clone()));
 
     let sut = UserFeatureServiceImpl {
         db,
@@ -47,6 +49,34 @@ async fn ok() {
     assert_eq!(result.unwrap(), expected);
 }
 
+#[tokio::test]
+async fn invalid_cursor() {
+    // Arrange
+    let query = build_query();
+
+    let db = MockDatabase::build(false);
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let user = MockUserService::new().with_list(
+        query.clone(),
+        Err(academy_core_user_contracts::user::UserListError::InvalidCursor),
+    );
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        auth,
+        user,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.list_users(&"token".into(), query).await;
+
+    // Assert
+    assert_matches!(result, Err(UserListError::InvalidCursor));
+}
+
 #[tokio::test]
 async fn unauthenticated() {
     // Arrange
@@ -100,8 +130,10 @@ fn build_query() -> UserListQuery {
         pagination: PaginationSlice {
             limit: 42.try_into().unwrap(),
             offset: 7,
-        },
+        }
+        .into(),
         filter: UserFilter {
+            query: Some("the query".try_into().unwrap()),
             name: Some("the name".try_into().unwrap()),
             email: Some("the email".try_into().unwrap()),
             enabled: Some(true),
@@ -109,6 +141,15 @@ fn build_query() -> UserListQuery {
             mfa_enabled: None,
             email_verified: Some(true),
             newsletter: Some(false),
+            created_after: Some(FOO.user.created_at),
+            created_before: None,
+            last_login_after: None,
+            last_login_before: FOO.user.last_login,
+            oauth2_provider: Some(TEST_OAUTH2_PROVIDER_ID.clone()),
+        },
+        sorting: UserSorting {
+            field: UserSortField::LastLogin,
+            direction: SortDirection::Desc,
         },
     }
 }
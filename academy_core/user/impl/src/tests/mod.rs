@@ -1,26 +1,44 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use academy_auth_contracts::MockAuthService;
+use academy_core_coin_contracts::coin::MockCoinService;
 use academy_core_oauth2_contracts::registration::MockOAuth2RegistrationService;
 use academy_core_session_contracts::session::MockSessionService;
 use academy_core_user_contracts::{
-    email_confirmation::MockUserEmailConfirmationService, update::MockUserUpdateService,
-    user::MockUserService,
+    avatar::MockUserAvatarService, email_confirmation::MockUserEmailConfirmationService,
+    email_policy::MockUserEmailPolicyService, invite::MockUserInviteService,
+    newsletter::MockUserNewsletterService, update::MockUserUpdateService, user::MockUserService,
+    vat::MockUserVatService,
 };
-use academy_extern_contracts::{internal::MockInternalApiService, vat::MockVatApiService};
-use academy_persistence_contracts::{user::MockUserRepository, MockDatabase, MockTransaction};
-use academy_shared_contracts::captcha::MockCaptchaService;
+use academy_models::invite::RegistrationMode;
+use academy_persistence_contracts::{
+    invite::MockInviteRepository, user::MockUserRepository, MockDatabase, MockTransaction,
+};
+use academy_shared_contracts::{captcha::MockCaptchaService, time::MockTimeService};
 
-use crate::{UserFeatureConfig, UserFeatureServiceImpl};
+use crate::{
+    email_policy::{parse_domain_list, DISPOSABLE_EMAIL_DOMAINS},
+    UserFeatureConfig, UserFeatureServiceImpl,
+};
 
+mod create_invite;
 mod create_user;
+mod delete_avatar;
+mod delete_invite;
 mod delete_user;
+mod get_avatar;
+mod get_privacy;
+mod get_public_profile;
 mod get_user;
+mod list_invites;
 mod list_users;
 mod request_password_reset;
 mod request_verification_email;
 mod reset_password;
+mod unsubscribe_from_newsletter;
+mod update_privacy;
 mod update_user;
+mod upload_avatar;
 mod verify_email;
 mod verify_newsletter_subscription;
 
@@ -28,14 +46,20 @@ type Sut = UserFeatureServiceImpl<
     MockDatabase,
     MockAuthService<MockTransaction>,
     MockCaptchaService,
-    MockVatApiService,
-    MockInternalApiService,
+    MockTimeService,
+    MockCoinService<MockTransaction>,
     MockUserService<MockTransaction>,
     MockUserEmailConfirmationService<MockTransaction>,
+    MockUserEmailPolicyService,
+    MockUserNewsletterService,
     MockUserUpdateService<MockTransaction>,
+    MockUserAvatarService,
+    MockUserInviteService<MockTransaction>,
+    MockUserVatService,
     MockSessionService<MockTransaction>,
     MockOAuth2RegistrationService,
     MockUserRepository<MockTransaction>,
+    MockInviteRepository<MockTransaction>,
 >;
 
 impl Default for UserFeatureConfig {
@@ -50,10 +74,36 @@ impl Default for UserFeatureConfig {
                 .to_owned()
                 .into(),
             password_reset_verification_code_ttl: Duration::from_secs(3600),
+            account_recovery_url: "https://bootstrap.academy/auth/reset-password"
+                .to_owned()
+                .into(),
             newsletter_subscription_redirect_url: "https://bootstrap.academy/account/newsletter"
                 .to_owned()
                 .into(),
             newsletter_subscription_verification_code_ttl: Duration::from_secs(3600),
+            avatar_max_upload_size: 2 * 1024 * 1024,
+            avatar_sizes: [512, 256, 128, 64].into(),
+            avatar_url: Arc::new("https://bootstrap.academy/auth/avatars/".parse().unwrap()),
+            list_cursor_ttl: Duration::from_secs(24 * 3600),
+            registration_mode: RegistrationMode::Open,
+            invite_limit: 3,
+            invite_ttl: Duration::from_secs(14 * 24 * 3600),
+            email_allowlist: [].into(),
+            email_denylist: [].into(),
+            email_disposable_domains: parse_domain_list(DISPOSABLE_EMAIL_DOMAINS).into(),
+            email_require_mx: false,
+            newsletter_unsubscribe_url: Arc::new(
+                "https://bootstrap.academy/auth/newsletter/unsubscribe"
+                    .parse()
+                    .unwrap(),
+            ),
+            newsletter_unsubscribe_redirect_url:
+                "https://bootstrap.academy/account/newsletter/unsubscribe"
+                    .to_owned()
+                    .into(),
+            newsletter_unsubscribe_token_ttl: Duration::from_secs(365 * 24 * 3600),
+            default_locale: "de".try_into().unwrap(),
+            vat_cache_ttl: Duration::from_secs(24 * 3600),
         }
     }
 }
@@ -12,7 +12,7 @@ use crate::{tests::Sut, UserFeatureServiceImpl};
 #[tokio::test]
 async fn ok() {
     // Arrange
-    let db = MockDatabase::build(false);
+    let db = MockDatabase::build(true);
 
     let captcha = MockCaptchaService::new().with_check(Some("resp"), Ok(()));
 
@@ -27,6 +27,7 @@ async fn ok() {
                 .clone()
                 .unwrap()
                 .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
         );
 
     let sut = UserFeatureServiceImpl {
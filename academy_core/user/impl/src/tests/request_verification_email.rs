@@ -21,7 +21,7 @@ async fn ok_self() {
     // Arrange
     let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
 
-    let db = MockDatabase::build(false);
+    let db = MockDatabase::build(true);
 
     let user_repo = MockUserRepository::new().with_get_composite(
         FOO.user.id,
@@ -35,6 +35,7 @@ async fn ok_self() {
                 .clone()
                 .unwrap()
                 .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
         );
 
     let sut = UserFeatureServiceImpl {
@@ -60,7 +61,7 @@ async fn ok_admin() {
     let auth =
         MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
 
-    let db = MockDatabase::build(false);
+    let db = MockDatabase::build(true);
 
     let user_repo = MockUserRepository::new().with_get_composite(
         FOO.user.id,
@@ -74,6 +75,7 @@ async fn ok_admin() {
                 .clone()
                 .unwrap()
                 .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
         );
 
     let sut = UserFeatureServiceImpl {
@@ -0,0 +1,89 @@
+use academy_core_user_contracts::{
+    newsletter::MockUserNewsletterService, UserFeatureService, UserUnsubscribeFromNewsletterError,
+};
+use academy_demo::user::FOO;
+use academy_models::user::UserPatch;
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let user_newsletter = MockUserNewsletterService::new()
+        .with_verify_unsubscribe_token("token".into(), Some(FOO.user.id));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new().with_update(
+        FOO.user.id,
+        UserPatch::new().update_newsletter(false),
+        Ok(true),
+    );
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_newsletter,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.unsubscribe_from_newsletter(&"token".into()).await;
+
+    // Assert
+    result.unwrap();
+}
+
+#[tokio::test]
+async fn invalid_token() {
+    // Arrange
+    let user_newsletter =
+        MockUserNewsletterService::new().with_verify_unsubscribe_token("token".into(), None);
+
+    let sut = UserFeatureServiceImpl {
+        user_newsletter,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.unsubscribe_from_newsletter(&"token".into()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserUnsubscribeFromNewsletterError::InvalidToken)
+    );
+}
+
+#[tokio::test]
+async fn user_not_found() {
+    // Arrange
+    let user_newsletter = MockUserNewsletterService::new()
+        .with_verify_unsubscribe_token("token".into(), Some(FOO.user.id));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_update(
+        FOO.user.id,
+        UserPatch::new().update_newsletter(false),
+        Ok(false),
+    );
+
+    let sut = UserFeatureServiceImpl {
+        db,
+        user_newsletter,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut.unsubscribe_from_newsletter(&"token".into()).await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserUnsubscribeFromNewsletterError::InvalidToken)
+    );
+}
@@ -0,0 +1,157 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{UserFeatureService, UserUpdatePrivacyError};
+use academy_demo::{
+    session::{ADMIN_1, BAR_1, FOO_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthenticateError, AuthorizeError},
+    user::{UserIdOrSelf, UserPrivacy, UserPrivacyPatch, UserProfileVisibility},
+};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::assert_matches;
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let patch = UserPrivacyPatch::new()
+        .update_bio(UserProfileVisibility::Users)
+        .update_tags(UserProfileVisibility::Private);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_privacy(FOO.user.id, Some(UserPrivacy::default()))
+        .with_update_privacy(FOO.user.id, patch.clone(), true);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_privacy(&"token".into(), UserIdOrSelf::Slf, patch)
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        UserPrivacy {
+            bio: UserProfileVisibility::Users,
+            tags: UserProfileVisibility::Private,
+            ..Default::default()
+        }
+    );
+}
+
+#[tokio::test]
+async fn ok_no_change() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo =
+        MockUserRepository::new().with_get_privacy(FOO.user.id, Some(UserPrivacy::default()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_privacy(
+            &"token".into(),
+            FOO.user.id.into(),
+            UserPrivacyPatch::new().update_bio(UserProfileVisibility::Public),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), UserPrivacy::default());
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_privacy(&"token".into(), FOO.user.id.into(), UserPrivacyPatch::new())
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserUpdatePrivacyError::Auth(AuthError::Authenticate(
+            AuthenticateError::InvalidToken
+        )))
+    );
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_privacy(&"token".into(), FOO.user.id.into(), UserPrivacyPatch::new())
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserUpdatePrivacyError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_privacy(FOO.user.id, None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_privacy(&"token".into(), FOO.user.id.into(), UserPrivacyPatch::new())
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserUpdatePrivacyError::NotFound));
+}
@@ -1,7 +1,7 @@
 use academy_auth_contracts::MockAuthService;
 use academy_core_user_contracts::{
-    update::MockUserUpdateService, UserFeatureService, UserUpdateError, UserUpdateRequest,
-    UserUpdateUserRequest,
+    update::{MockUserUpdateService, UserAccountUpdate, UserUpdateNameRateLimitPolicy},
+    UserFeatureService, UserUpdateError, UserUpdateRequest, UserUpdateUserRequest,
 };
 use academy_demo::{
     session::ADMIN_1,
@@ -42,8 +42,15 @@ async fn update_admin() {
         let user_repo = MockUserRepository::new()
             .with_get_composite(user_composite.user.id, Some(user_composite.clone()));
 
-        let user_update =
-            MockUserUpdateService::new().with_update_admin(user_composite.user.id, admin, true);
+        let user_update = MockUserUpdateService::new().with_update_account(
+            user_composite.clone(),
+            UserAccountUpdate {
+                admin: admin.into(),
+                ..Default::default()
+            },
+            UserUpdateNameRateLimitPolicy::Bypass,
+            Ok(expected.clone()),
+        );
 
         let sut = UserFeatureServiceImpl {
             auth,
@@ -1,15 +1,22 @@
 use academy_auth_contracts::MockAuthService;
 use academy_core_user_contracts::{
-    update::{MockUserUpdateService, UserUpdateEmailError},
+    email_policy::{MockUserEmailPolicyService, UserEmailPolicyCheckError},
+    update::{
+        MockUserUpdateService, UserAccountUpdate, UserUpdateAccountError,
+        UserUpdateNameRateLimitPolicy,
+    },
     UserFeatureService, UserUpdateError, UserUpdateRequest, UserUpdateUserRequest,
 };
 use academy_demo::{
     session::{ADMIN_1, FOO_1},
     user::{ADMIN, FOO},
 };
-use academy_models::user::{User, UserComposite, UserIdOrSelf};
+use academy_models::{
+    email_address::EmailAddress,
+    user::{User, UserComposite, UserIdOrSelf},
+};
 use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
-use academy_utils::assert_matches;
+use academy_utils::{assert_matches, patch::PatchValue};
 
 use crate::{tests::Sut, UserFeatureServiceImpl};
 
@@ -31,16 +38,23 @@ async fn update_email_self() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_email(
-        FOO.user.id,
-        expected.user.email.clone().unwrap(),
-        expected.user.email_verified,
-        Ok(true),
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(ADMIN.user.email.clone().unwrap(), Ok(()));
+
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            email: PatchValue::Update(Some(ADMIN.user.email.clone().unwrap())),
+            ..Default::default()
+        },
+        UserUpdateNameRateLimitPolicy::Enforce,
+        Ok(expected.clone()),
     );
 
     let sut = UserFeatureServiceImpl {
         auth,
         db,
+        user_email_policy,
         user_update,
         user_repo,
         ..Sut::default()
@@ -84,11 +98,14 @@ async fn update_email_admin() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_email(
-        FOO.user.id,
-        expected.user.email.clone().unwrap(),
-        expected.user.email_verified,
-        Ok(true),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            email: PatchValue::Update(Some(ADMIN.user.email.clone().unwrap())),
+            ..Default::default()
+        },
+        UserUpdateNameRateLimitPolicy::Bypass,
+        Ok(expected.clone()),
     );
 
     let sut = UserFeatureServiceImpl {
@@ -137,11 +154,15 @@ async fn update_email_admin_verified() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_email(
-        FOO.user.id,
-        expected.user.email.clone().unwrap(),
-        expected.user.email_verified,
-        Ok(true),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            email: PatchValue::Update(Some(ADMIN.user.email.clone().unwrap())),
+            email_verified: true.into(),
+            ..Default::default()
+        },
+        UserUpdateNameRateLimitPolicy::Bypass,
+        Ok(expected.clone()),
     );
 
     let sut = UserFeatureServiceImpl {
@@ -191,11 +212,14 @@ async fn update_email_admin_unverified() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_email(
-        FOO.user.id,
-        expected.user.email.clone().unwrap(),
-        expected.user.email_verified,
-        Ok(true),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            email: PatchValue::Update(Some(ADMIN.user.email.clone().unwrap())),
+            ..Default::default()
+        },
+        UserUpdateNameRateLimitPolicy::Bypass,
+        Ok(expected.clone()),
     );
 
     let sut = UserFeatureServiceImpl {
@@ -247,11 +271,20 @@ async fn update_set_email_verified() {
         }),
     );
 
-    let user_update = MockUserUpdateService::new().with_update_email(
-        FOO.user.id,
-        expected.user.email.clone().unwrap(),
-        expected.user.email_verified,
-        Ok(true),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        UserComposite {
+            user: User {
+                email_verified: false,
+                ..FOO.user.clone()
+            },
+            ..FOO.clone()
+        },
+        UserAccountUpdate {
+            email_verified: true.into(),
+            ..Default::default()
+        },
+        UserUpdateNameRateLimitPolicy::Bypass,
+        Ok(expected.clone()),
     );
 
     let sut = UserFeatureServiceImpl {
@@ -299,11 +332,14 @@ async fn update_set_email_unverified() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_email(
-        FOO.user.id,
-        expected.user.email.clone().unwrap(),
-        expected.user.email_verified,
-        Ok(true),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            email_verified: false.into(),
+            ..Default::default()
+        },
+        UserUpdateNameRateLimitPolicy::Bypass,
+        Ok(expected.clone()),
     );
 
     let sut = UserFeatureServiceImpl {
@@ -342,16 +378,23 @@ async fn update_email_conflict() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_email(
-        FOO.user.id,
-        ADMIN.user.email.clone().unwrap(),
-        false,
-        Err(UserUpdateEmailError::Conflict),
+    let user_email_policy =
+        MockUserEmailPolicyService::new().with_check(ADMIN.user.email.clone().unwrap(), Ok(()));
+
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            email: PatchValue::Update(Some(ADMIN.user.email.clone().unwrap())),
+            ..Default::default()
+        },
+        UserUpdateNameRateLimitPolicy::Enforce,
+        Err(UserUpdateAccountError::EmailConflict),
     );
 
     let sut = UserFeatureServiceImpl {
         auth,
         db,
+        user_email_policy,
         user_update,
         user_repo,
         ..Sut::default()
@@ -375,3 +418,44 @@ async fn update_email_conflict() {
     // Assert
     assert_matches!(result, Err(UserUpdateError::EmailConflict));
 }
+
+#[tokio::test]
+async fn update_email_not_allowed() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+    let email: EmailAddress = "foo@mailinator.com".parse().unwrap();
+
+    let user_email_policy = MockUserEmailPolicyService::new()
+        .with_check(email.clone(), Err(UserEmailPolicyCheckError::Rejected));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_email_policy,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_user(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            UserUpdateRequest {
+                user: UserUpdateUserRequest {
+                    email: email.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserUpdateError::EmailNotAllowed));
+}
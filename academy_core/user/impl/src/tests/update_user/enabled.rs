@@ -1,7 +1,7 @@
 use academy_auth_contracts::MockAuthService;
 use academy_core_user_contracts::{
-    update::MockUserUpdateService, UserFeatureService, UserUpdateError, UserUpdateRequest,
-    UserUpdateUserRequest,
+    update::{MockUserUpdateService, UserAccountUpdate, UserUpdateNameRateLimitPolicy},
+    UserFeatureService, UserUpdateError, UserUpdateRequest, UserUpdateUserRequest,
 };
 use academy_demo::{
     session::ADMIN_1,
@@ -33,8 +33,15 @@ async fn update_enabled() {
         let user_repo = MockUserRepository::new()
             .with_get_composite(user_composite.user.id, Some(user_composite.clone()));
 
-        let user_update =
-            MockUserUpdateService::new().with_update_enabled(user_composite.user.id, enabled, true);
+        let user_update = MockUserUpdateService::new().with_update_account(
+            user_composite.clone(),
+            UserAccountUpdate {
+                enabled: enabled.into(),
+                ..Default::default()
+            },
+            UserUpdateNameRateLimitPolicy::Bypass,
+            Ok(expected.clone()),
+        );
 
         let sut = UserFeatureServiceImpl {
             auth,
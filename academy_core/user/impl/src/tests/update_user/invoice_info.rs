@@ -1,13 +1,17 @@
 use academy_auth_contracts::MockAuthService;
+use academy_core_coin_contracts::coin::MockCoinService;
 use academy_core_user_contracts::{
-    update::MockUserUpdateService, UserFeatureService, UserUpdateError, UserUpdateRequest,
+    update::MockUserUpdateService, vat::MockUserVatService, UserFeatureService, UserUpdateError,
+    UserUpdateRequest,
 };
 use academy_demo::{
     session::BAR_1,
     user::{BAR, FOO},
 };
-use academy_extern_contracts::{internal::MockInternalApiService, vat::MockVatApiService};
-use academy_models::user::{UserComposite, UserIdOrSelf, UserInvoiceInfo};
+use academy_models::{
+    country::Country,
+    user::{UserComposite, UserIdOrSelf, UserInvoiceInfo, UserVatIdStatus, UserVatIdValidation},
+};
 use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
 use academy_utils::{assert_matches, patch::Patch, Apply};
 
@@ -19,7 +23,7 @@ async fn ok() {
     let expected = UserComposite {
         invoice_info: UserInvoiceInfo {
             business: Some(false),
-            country: Some("Germany".try_into().unwrap()),
+            country: Some(Country::DE),
             ..Default::default()
         },
         ..BAR.clone().with(|u| u.user.email_verified = true)
@@ -39,7 +43,8 @@ async fn ok() {
         BAR.invoice_info.clone(),
         academy_models::user::UserInvoiceInfoPatch::new()
             .update_business(expected.invoice_info.business)
-            .update_country(expected.invoice_info.country.clone()),
+            .update_country(expected.invoice_info.country),
+        None,
         expected.invoice_info.clone(),
     );
 
@@ -88,21 +93,89 @@ async fn ok_release_coins() {
         BAR.user.id,
         BAR.invoice_info.clone(),
         FOO.invoice_info.clone().into_patch(),
+        FOO.invoice_info.vat_id_validation.clone(),
         FOO.invoice_info.clone(),
     );
 
-    let vat_api = MockVatApiService::new()
-        .with_is_vat_id_valid(FOO.invoice_info.vat_id.clone().unwrap().into_inner(), true);
+    let user_vat = MockUserVatService::new().with_validate(
+        FOO.invoice_info.vat_id.clone().unwrap(),
+        FOO.invoice_info.vat_id_validation.clone().unwrap(),
+    );
 
-    let internal_api = MockInternalApiService::new().with_release_coins(BAR.user.id);
+    let coin = MockCoinService::new().with_release_withheld(BAR.user.id, None);
 
     let sut = UserFeatureServiceImpl {
         auth,
         db,
         user_repo,
         user_update,
-        vat_api,
-        internal_api,
+        user_vat,
+        coin,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_user(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            UserUpdateRequest {
+                invoice_info: expected.invoice_info.clone(),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn ok_vies_unavailable() {
+    // Arrange
+    let vat_id_validation = UserVatIdValidation {
+        status: UserVatIdStatus::Pending,
+        consultation_number: None,
+        ..FOO.invoice_info.vat_id_validation.clone().unwrap()
+    };
+
+    let expected = UserComposite {
+        invoice_info: UserInvoiceInfo {
+            vat_id_validation: Some(vat_id_validation.clone()),
+            ..FOO.invoice_info.clone()
+        },
+        ..BAR.clone().with(|u| u.user.email_verified = true)
+    };
+
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new().with_get_composite(
+        BAR.user.id,
+        Some(BAR.clone().with(|u| u.user.email_verified = true)),
+    );
+
+    let user_update = MockUserUpdateService::new().with_update_invoice_info(
+        BAR.user.id,
+        BAR.invoice_info.clone(),
+        FOO.invoice_info.clone().into_patch(),
+        Some(vat_id_validation.clone()),
+        expected.invoice_info.clone(),
+    );
+
+    let user_vat = MockUserVatService::new()
+        .with_validate(FOO.invoice_info.vat_id.clone().unwrap(), vat_id_validation);
+
+    let coin = MockCoinService::new().with_release_withheld(BAR.user.id, None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_update,
+        user_vat,
+        coin,
         ..Sut::default()
     };
 
@@ -140,13 +213,20 @@ async fn invalid_vat_id() {
 
     let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, Some(BAR.clone()));
 
-    let vat_api = MockVatApiService::new().with_is_vat_id_valid("DE1234".into(), false);
+    let user_vat = MockUserVatService::new().with_validate(
+        "DE1234".try_into().unwrap(),
+        UserVatIdValidation {
+            status: UserVatIdStatus::Invalid,
+            consultation_number: None,
+            ..FOO.invoice_info.vat_id_validation.clone().unwrap()
+        },
+    );
 
     let sut = UserFeatureServiceImpl {
         auth,
         db,
         user_repo,
-        vat_api,
+        user_vat,
         ..Sut::default()
     };
 
@@ -165,3 +245,76 @@ async fn invalid_vat_id() {
     // Assert
     assert_matches!(result, Err(UserUpdateError::InvalidVatId));
 }
+
+#[tokio::test]
+async fn invalid_zip_code() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, Some(BAR.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_user(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            UserUpdateRequest {
+                invoice_info: UserInvoiceInfo {
+                    zip_code: Some("1234".try_into().unwrap()),
+                    country: Some(Country::DE),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserUpdateError::InvalidZipCode));
+}
+
+#[tokio::test]
+async fn vat_id_country_mismatch() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(BAR.user.id, Some(BAR.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_user(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            UserUpdateRequest {
+                invoice_info: UserInvoiceInfo {
+                    business: Some(true),
+                    country: Some(Country::AT),
+                    vat_id: Some("DE0123456789".try_into().unwrap()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserUpdateError::VatIdCountryMismatch));
+}
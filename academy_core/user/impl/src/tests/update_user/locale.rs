@@ -0,0 +1,136 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{
+    email_confirmation::MockUserEmailConfirmationService, UserFeatureService, UserUpdateRequest,
+    UserUpdateUserRequest,
+};
+use academy_demo::{session::FOO_1, user::FOO};
+use academy_models::{
+    locale::Locale,
+    user::{User, UserComposite, UserIdOrSelf, UserPatch},
+};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok() {
+    // Arrange
+    let locale = Locale::try_new("en-US").unwrap();
+
+    let expected = UserComposite {
+        user: User {
+            locale: locale.clone(),
+            ..FOO.user.clone()
+        },
+        ..FOO.clone()
+    };
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(FOO.clone()))
+        .with_update(
+            FOO.user.id,
+            UserPatch::new().update_locale(locale.clone()),
+            Ok(true),
+        );
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_user(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            UserUpdateRequest {
+                user: UserUpdateUserRequest {
+                    locale: locale.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn newsletter_uses_new_locale() {
+    // Arrange
+    let locale = Locale::try_new("en").unwrap();
+
+    let foo = UserComposite {
+        user: User {
+            newsletter: false,
+            ..FOO.user.clone()
+        },
+        ..FOO.clone()
+    };
+
+    let expected = UserComposite {
+        user: User {
+            locale: locale.clone(),
+            ..foo.user.clone()
+        },
+        ..foo.clone()
+    };
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(foo))
+        .with_update(
+            FOO.user.id,
+            UserPatch::new().update_locale(locale.clone()),
+            Ok(true),
+        );
+
+    let user_email_confirmation = MockUserEmailConfirmationService::new()
+        .with_request_newsletter_subscription(
+            FOO.user.id,
+            FOO.user
+                .email
+                .clone()
+                .unwrap()
+                .with_name(FOO.profile.display_name.clone().into_inner()),
+            locale.clone(),
+        );
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_email_confirmation,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .update_user(
+            &"token".into(),
+            UserIdOrSelf::Slf,
+            UserUpdateRequest {
+                user: UserUpdateUserRequest {
+                    newsletter: true.into(),
+                    locale: locale.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap(), expected);
+}
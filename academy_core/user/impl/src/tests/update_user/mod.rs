@@ -16,6 +16,7 @@ mod admin;
 mod email;
 mod enabled;
 mod invoice_info;
+mod locale;
 mod name;
 mod newsletter;
 mod no_op;
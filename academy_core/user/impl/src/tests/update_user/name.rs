@@ -2,7 +2,10 @@ use std::time::Duration;
 
 use academy_auth_contracts::MockAuthService;
 use academy_core_user_contracts::{
-    update::{MockUserUpdateService, UserUpdateNameError, UserUpdateNameRateLimitPolicy},
+    update::{
+        MockUserUpdateService, UserAccountUpdate, UserUpdateAccountError,
+        UserUpdateNameRateLimitPolicy,
+    },
     UserFeatureService, UserUpdateError, UserUpdateRequest, UserUpdateUserRequest,
 };
 use academy_demo::{
@@ -33,11 +36,14 @@ async fn update_name_self() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_name(
-        FOO.user.clone(),
-        BAR.user.name.clone(),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            name: BAR.user.name.clone().into(),
+            ..Default::default()
+        },
         UserUpdateNameRateLimitPolicy::Enforce,
-        Ok(expected.user.clone()),
+        Ok(expected.clone()),
     );
 
     let sut = UserFeatureServiceImpl {
@@ -86,11 +92,14 @@ async fn update_name_admin_no_rate_limit() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_name(
-        FOO.user.clone(),
-        BAR.user.name.clone(),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            name: BAR.user.name.clone().into(),
+            ..Default::default()
+        },
         UserUpdateNameRateLimitPolicy::Bypass,
-        Ok(expected.user.clone()),
+        Ok(expected.clone()),
     );
 
     let sut = UserFeatureServiceImpl {
@@ -131,11 +140,14 @@ async fn update_name_self_rate_limit() {
 
     let expected = FOO.user.last_name_change.unwrap() + Duration::from_secs(17);
 
-    let user_update = MockUserUpdateService::new().with_update_name(
-        FOO.user.clone(),
-        BAR.user.name.clone(),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            name: BAR.user.name.clone().into(),
+            ..Default::default()
+        },
         UserUpdateNameRateLimitPolicy::Enforce,
-        Err(UserUpdateNameError::RateLimit { until: expected }),
+        Err(UserUpdateAccountError::NameChangeRateLimit { until: expected }),
     );
 
     let sut = UserFeatureServiceImpl {
@@ -174,11 +186,14 @@ async fn update_name_conflict() {
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
 
-    let user_update = MockUserUpdateService::new().with_update_name(
-        FOO.user.clone(),
-        BAR.user.name.clone(),
+    let user_update = MockUserUpdateService::new().with_update_account(
+        FOO.clone(),
+        UserAccountUpdate {
+            name: BAR.user.name.clone().into(),
+            ..Default::default()
+        },
         UserUpdateNameRateLimitPolicy::Enforce,
-        Err(UserUpdateNameError::Conflict),
+        Err(UserUpdateAccountError::NameConflict),
     );
 
     let sut = UserFeatureServiceImpl {
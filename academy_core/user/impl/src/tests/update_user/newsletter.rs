@@ -26,7 +26,7 @@ async fn enable_self() {
 
     let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
 
-    let db = MockDatabase::build(false);
+    let db = MockDatabase::build(true);
 
     let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(foo.clone()));
 
@@ -38,6 +38,7 @@ async fn enable_self() {
                 .clone()
                 .unwrap()
                 .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
         );
 
     let sut = UserFeatureServiceImpl {
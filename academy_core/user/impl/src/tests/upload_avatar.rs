@@ -0,0 +1,235 @@
+use academy_auth_contracts::MockAuthService;
+use academy_core_user_contracts::{
+    avatar::{MockUserAvatarService, UserAvatarStoreError},
+    UserFeatureService, UserUploadAvatarError,
+};
+use academy_demo::{
+    session::{ADMIN_1, BAR_1, FOO_1},
+    user::{ADMIN, BAR, FOO},
+};
+use academy_models::{
+    auth::{AuthError, AuthenticateError, AuthorizeError},
+    url::Url,
+    user::UserIdOrSelf,
+};
+use academy_persistence_contracts::{user::MockUserRepository, MockDatabase};
+use academy_utils::{assert_matches, Apply};
+
+use crate::{tests::Sut, UserFeatureServiceImpl};
+
+#[tokio::test]
+async fn ok_self() {
+    // Arrange
+    let avatar_url = avatar_url();
+    let data = b"some image".to_vec();
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(FOO.clone()))
+        .with_update_avatar_url(FOO.user.id, Some(avatar_url.clone()), true);
+
+    let user_avatar =
+        MockUserAvatarService::new().with_store(FOO.user.id, data.clone(), Ok(avatar_url.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .upload_avatar(&"token".into(), UserIdOrSelf::Slf, data)
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        FOO.clone()
+            .with(|u| u.profile.avatar_url = Some(avatar_url))
+    );
+}
+
+#[tokio::test]
+async fn ok_admin() {
+    // Arrange
+    let avatar_url = avatar_url();
+    let data = b"some image".to_vec();
+
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(true);
+
+    let user_repo = MockUserRepository::new()
+        .with_get_composite(FOO.user.id, Some(FOO.clone()))
+        .with_update_avatar_url(FOO.user.id, Some(avatar_url.clone()), true);
+
+    let user_avatar =
+        MockUserAvatarService::new().with_store(FOO.user.id, data.clone(), Ok(avatar_url.clone()));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .upload_avatar(&"token".into(), FOO.user.id.into(), data)
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap(),
+        FOO.clone()
+            .with(|u| u.profile.avatar_url = Some(avatar_url))
+    );
+}
+
+#[tokio::test]
+async fn unauthenticated() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .upload_avatar(&"token".into(), FOO.user.id.into(), vec![])
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserUploadAvatarError::Auth(AuthError::Authenticate(
+            AuthenticateError::InvalidToken
+        )))
+    );
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    // Arrange
+    let auth = MockAuthService::new().with_authenticate(Some((BAR.user.clone(), BAR_1.clone())));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .upload_avatar(&"token".into(), FOO.user.id.into(), vec![])
+        .await;
+
+    // Assert
+    assert_matches!(
+        result,
+        Err(UserUploadAvatarError::Auth(AuthError::Authorize(
+            AuthorizeError::Admin
+        )))
+    );
+}
+
+#[tokio::test]
+async fn not_found() {
+    // Arrange
+    let auth =
+        MockAuthService::new().with_authenticate(Some((ADMIN.user.clone(), ADMIN_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, None);
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .upload_avatar(&"token".into(), FOO.user.id.into(), vec![])
+        .await;
+
+    // Assert
+    assert_matches!(result, Err(UserUploadAvatarError::NotFound));
+}
+
+#[tokio::test]
+async fn too_large() {
+    test_store_error(UserAvatarStoreError::TooLarge, |err| {
+        matches!(err, UserUploadAvatarError::TooLarge)
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn unsupported_format() {
+    test_store_error(UserAvatarStoreError::UnsupportedFormat, |err| {
+        matches!(err, UserUploadAvatarError::UnsupportedFormat)
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn invalid_image() {
+    test_store_error(UserAvatarStoreError::InvalidImage, |err| {
+        matches!(err, UserUploadAvatarError::InvalidImage)
+    })
+    .await;
+}
+
+async fn test_store_error(
+    store_error: UserAvatarStoreError,
+    expected: impl FnOnce(&UserUploadAvatarError) -> bool,
+) {
+    // Arrange
+    let data = b"some image".to_vec();
+
+    let auth = MockAuthService::new().with_authenticate(Some((FOO.user.clone(), FOO_1.clone())));
+
+    let db = MockDatabase::build(false);
+
+    let user_repo = MockUserRepository::new().with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+    let user_avatar =
+        MockUserAvatarService::new().with_store(FOO.user.id, data.clone(), Err(store_error));
+
+    let sut = UserFeatureServiceImpl {
+        auth,
+        db,
+        user_repo,
+        user_avatar,
+        ..Sut::default()
+    };
+
+    // Act
+    let result = sut
+        .upload_avatar(&"token".into(), UserIdOrSelf::Slf, data)
+        .await;
+
+    // Assert
+    assert!(expected(&result.unwrap_err()));
+}
+
+fn avatar_url() -> Url {
+    format!(
+        "https://bootstrap.academy/auth/avatars/{}?v=1",
+        FOO.user.id.hyphenated()
+    )
+    .parse()
+    .unwrap()
+}
@@ -1,18 +1,24 @@
 use academy_auth_contracts::AuthService;
 use academy_core_session_contracts::session::SessionService;
 use academy_core_user_contracts::update::{
-    UserUpdateEmailError, UserUpdateNameError, UserUpdateNameRateLimitPolicy, UserUpdateService,
+    UserAccountUpdate, UserUpdateAccountError, UserUpdateEmailError, UserUpdateNameError,
+    UserUpdateNameRateLimitPolicy, UserUpdateService,
 };
 use academy_di::Build;
+use academy_email_contracts::template::TemplateEmailService;
 use academy_models::{
-    email_address::EmailAddress,
+    email_address::{EmailAddress, EmailAddressWithName},
+    locale::Locale,
     user::{
-        User, UserId, UserInvoiceInfo, UserInvoiceInfoPatch, UserName, UserPassword, UserPatch,
-        UserPatchRef,
+        User, UserComposite, UserId, UserInvoiceInfo, UserInvoiceInfoPatch, UserName, UserPassword,
+        UserPatch, UserPatchRef, UserVatIdValidation,
     },
 };
 use academy_persistence_contracts::user::{UserRepoError, UserRepository};
 use academy_shared_contracts::{password::PasswordService, time::TimeService};
+use academy_templates_contracts::{
+    AccountDisabledTemplate, EmailChangedTemplate, PasswordChangedTemplate,
+};
 use academy_utils::{
     patch::{Patch, PatchValue},
     trace_instrument,
@@ -23,25 +29,139 @@ use crate::UserFeatureConfig;
 
 #[derive(Debug, Clone, Build)]
 #[cfg_attr(test, derive(Default))]
-pub struct UserUpdateServiceImpl<Auth, Time, Password, Session, UserRepo> {
+pub struct UserUpdateServiceImpl<Auth, Time, Password, TemplateEmail, Session, UserRepo> {
     auth: Auth,
     time: Time,
     password: Password,
+    template_email: TemplateEmail,
     session: Session,
     user_repo: UserRepo,
     config: UserFeatureConfig,
 }
 
-impl<Txn, Auth, Time, Password, Session, UserRepo> UserUpdateService<Txn>
-    for UserUpdateServiceImpl<Auth, Time, Password, Session, UserRepo>
+impl<Txn, Auth, Time, Password, TemplateEmail, Session, UserRepo> UserUpdateService<Txn>
+    for UserUpdateServiceImpl<Auth, Time, Password, TemplateEmail, Session, UserRepo>
 where
     Txn: Send + Sync + 'static,
     Auth: AuthService<Txn>,
     Time: TimeService,
     Password: PasswordService,
+    TemplateEmail: TemplateEmailService<Txn>,
     Session: SessionService<Txn>,
     UserRepo: UserRepository<Txn>,
 {
+    #[trace_instrument(skip(self, txn))]
+    async fn update_account(
+        &self,
+        txn: &mut Txn,
+        UserComposite {
+            mut user,
+            profile,
+            mut details,
+            invoice_info,
+        }: UserComposite,
+        update: UserAccountUpdate,
+        rate_limit_policy: UserUpdateNameRateLimitPolicy,
+    ) -> Result<UserComposite, UserUpdateAccountError> {
+        let UserAccountUpdate {
+            name,
+            email,
+            email_verified,
+            enabled,
+            admin,
+            password,
+        } = update.minimize(&user);
+        let user_id = user.id;
+
+        // security notifications must go to the address the user had before this
+        // update, so that a hijacked account cannot silence them by changing the
+        // email address first
+        let previous_recipient = user.email.clone().map(|email| {
+            (
+                email.with_name(profile.display_name.clone().into_inner()),
+                user.locale.clone(),
+            )
+        });
+
+        if let PatchValue::Update(name) = name {
+            user = self
+                .update_name(txn, user, name, rate_limit_policy)
+                .await
+                .map_err(|err| match err {
+                    UserUpdateNameError::Conflict => UserUpdateAccountError::NameConflict,
+                    UserUpdateNameError::RateLimit { until } => {
+                        UserUpdateAccountError::NameChangeRateLimit { until }
+                    }
+                    UserUpdateNameError::Other(err) => {
+                        err.context("Failed to update user name").into()
+                    }
+                })?;
+        }
+
+        if email.is_update() || email_verified.is_update() {
+            user.email_verified =
+                email_verified.update(user.email_verified && email.is_unchanged());
+            let email_changed = email.is_update();
+            user.email = email.update(user.email);
+            self.update_email(txn, user_id, &user.email, user.email_verified)
+                .await
+                .map_err(|err| match err {
+                    UserUpdateEmailError::Conflict => UserUpdateAccountError::EmailConflict,
+                    UserUpdateEmailError::Other(err) => {
+                        err.context("Failed to update user email").into()
+                    }
+                })?;
+
+            if let (true, Some(new_email), Some((recipient, locale))) =
+                (email_changed, &user.email, &previous_recipient)
+            {
+                self.send_email_changed_email(txn, recipient.clone(), locale, new_email)
+                    .await?;
+            }
+        }
+
+        if let PatchValue::Update(enabled) = enabled {
+            let result = self
+                .set_enabled(txn, user_id, enabled)
+                .await
+                .context("Failed to update enabled status")?;
+            user.enabled = enabled;
+
+            if let Some((recipient, locale)) =
+                previous_recipient.as_ref().filter(|_| result && !enabled)
+            {
+                self.send_account_disabled_email(txn, recipient.clone(), locale)
+                    .await?;
+            }
+        }
+
+        if let PatchValue::Update(admin) = admin {
+            self.update_admin(txn, user_id, admin)
+                .await
+                .context("Failed to update admin status")?;
+            user.admin = admin;
+        }
+
+        if let PatchValue::Update(password) = password {
+            self.set_password(txn, user_id, password)
+                .await
+                .context("Failed to update user password")?;
+            details.password_login = true;
+
+            if let Some((recipient, locale)) = previous_recipient {
+                self.send_password_changed_email(txn, recipient, &locale)
+                    .await?;
+            }
+        }
+
+        Ok(UserComposite {
+            user,
+            profile,
+            details,
+            invoice_info,
+        })
+    }
+
     #[trace_instrument(skip(self, txn))]
     async fn update_name(
         &self,
@@ -128,16 +248,12 @@ where
         user_id: UserId,
         password: UserPassword,
     ) -> anyhow::Result<()> {
-        let hash = self
-            .password
-            .hash(password.into_inner().into())
-            .await
-            .context("Failed to hash password")?;
+        self.set_password(txn, user_id, password).await?;
 
-        self.user_repo
-            .save_password_hash(txn, user_id, hash)
-            .await
-            .context("Failed to save password hash in database")?;
+        if let Some((recipient, locale)) = self.get_notification_recipient(txn, user_id).await? {
+            self.send_password_changed_email(txn, recipient, &locale)
+                .await?;
+        }
 
         Ok(())
     }
@@ -149,17 +265,17 @@ where
         user_id: UserId,
         enabled: bool,
     ) -> anyhow::Result<bool> {
-        if !enabled {
-            self.session
-                .delete_by_user(txn, user_id)
-                .await
-                .context("Failed to log out user")?;
+        let result = self.set_enabled(txn, user_id, enabled).await?;
+
+        if result && !enabled {
+            if let Some((recipient, locale)) = self.get_notification_recipient(txn, user_id).await?
+            {
+                self.send_account_disabled_email(txn, recipient, &locale)
+                    .await?;
+            }
         }
 
-        self.user_repo
-            .update(txn, user_id, UserPatchRef::new().update_enabled(&enabled))
-            .await
-            .context("Failed to update user in database")
+        Ok(result)
     }
 
     #[trace_instrument(skip(self, txn))]
@@ -189,6 +305,7 @@ where
         user_id: UserId,
         invoice_info: UserInvoiceInfo,
         mut patch: UserInvoiceInfoPatch,
+        vat_id_validation: Option<UserVatIdValidation>,
     ) -> anyhow::Result<UserInvoiceInfo> {
         if patch.business.update(invoice_info.business) == Some(false) {
             patch.vat_id = PatchValue::Update(None).minimize(&invoice_info.vat_id);
@@ -199,7 +316,169 @@ where
             .await
             .context("Failed to update user in database")?;
 
-        Ok(invoice_info.update(patch))
+        let vat_id_updated = patch.vat_id.is_update();
+        let mut invoice_info = invoice_info.update(patch);
+
+        if vat_id_updated {
+            invoice_info.vat_id_validation = None;
+            if let (Some(vat_id), Some(validation)) = (&invoice_info.vat_id, vat_id_validation) {
+                self.user_repo
+                    .update_vat_id_validation(txn, user_id, vat_id, &validation)
+                    .await
+                    .context("Failed to save VAT id validation in database")?;
+                invoice_info.vat_id_validation = Some(validation);
+            }
+        }
+
+        Ok(invoice_info)
+    }
+}
+
+impl<Auth, Time, Password, TemplateEmail, Session, UserRepo>
+    UserUpdateServiceImpl<Auth, Time, Password, TemplateEmail, Session, UserRepo>
+{
+    async fn set_password<Txn>(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        password: UserPassword,
+    ) -> anyhow::Result<()>
+    where
+        Txn: Send + Sync + 'static,
+        Password: PasswordService,
+        UserRepo: UserRepository<Txn>,
+    {
+        let hash = self
+            .password
+            .hash(password.into_inner().into())
+            .await
+            .context("Failed to hash password")?;
+
+        self.user_repo
+            .save_password_hash(txn, user_id, hash)
+            .await
+            .context("Failed to save password hash in database")
+    }
+
+    async fn set_enabled<Txn>(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        enabled: bool,
+    ) -> anyhow::Result<bool>
+    where
+        Txn: Send + Sync + 'static,
+        Session: SessionService<Txn>,
+        UserRepo: UserRepository<Txn>,
+    {
+        if !enabled {
+            self.session
+                .delete_by_user(txn, user_id)
+                .await
+                .context("Failed to log out user")?;
+        }
+
+        self.user_repo
+            .update(txn, user_id, UserPatchRef::new().update_enabled(&enabled))
+            .await
+            .context("Failed to update user in database")
+    }
+
+    async fn send_password_changed_email<Txn>(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+    ) -> anyhow::Result<()>
+    where
+        Txn: Send + Sync + 'static,
+        TemplateEmail: TemplateEmailService<Txn>,
+    {
+        self.template_email
+            .send_password_changed_email(
+                txn,
+                recipient,
+                locale,
+                &PasswordChangedTemplate {
+                    recovery_url: (*self.config.account_recovery_url).clone(),
+                },
+            )
+            .await
+            .context("Failed to send password changed email")
+    }
+
+    async fn send_email_changed_email<Txn>(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        new_email: &EmailAddress,
+    ) -> anyhow::Result<()>
+    where
+        Txn: Send + Sync + 'static,
+        TemplateEmail: TemplateEmailService<Txn>,
+    {
+        self.template_email
+            .send_email_changed_email(
+                txn,
+                recipient,
+                locale,
+                &EmailChangedTemplate {
+                    new_email: new_email.as_str().into(),
+                    recovery_url: (*self.config.account_recovery_url).clone(),
+                },
+            )
+            .await
+            .context("Failed to send email changed email")
+    }
+
+    async fn send_account_disabled_email<Txn>(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+    ) -> anyhow::Result<()>
+    where
+        Txn: Send + Sync + 'static,
+        TemplateEmail: TemplateEmailService<Txn>,
+    {
+        self.template_email
+            .send_account_disabled_email(
+                txn,
+                recipient,
+                locale,
+                &AccountDisabledTemplate {
+                    recovery_url: (*self.config.account_recovery_url).clone(),
+                },
+            )
+            .await
+            .context("Failed to send account disabled email")
+    }
+
+    /// Return the email address and locale to send security notifications to,
+    /// if the user has an email address.
+    async fn get_notification_recipient<Txn>(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> anyhow::Result<Option<(EmailAddressWithName, Locale)>>
+    where
+        Txn: Send + Sync + 'static,
+        UserRepo: UserRepository<Txn>,
+    {
+        let UserComposite { user, profile, .. } = self
+            .user_repo
+            .get_composite(txn, user_id)
+            .await
+            .context("Failed to get user from database")?
+            .ok_or_else(|| anyhow!("User {} does not exist", *user_id))?;
+
+        Ok(user.email.map(|email| {
+            (
+                email.with_name(profile.display_name.into_inner()),
+                user.locale,
+            )
+        }))
     }
 }
 
@@ -210,6 +489,7 @@ mod tests {
     use academy_auth_contracts::MockAuthService;
     use academy_core_session_contracts::session::MockSessionService;
     use academy_demo::user::{ADMIN, BAR, FOO};
+    use academy_email_contracts::template::MockTemplateEmailService;
     use academy_models::user::UserPatch;
     use academy_persistence_contracts::user::MockUserRepository;
     use academy_shared_contracts::{password::MockPasswordService, time::MockTimeService};
@@ -221,10 +501,252 @@ mod tests {
         MockAuthService<()>,
         MockTimeService,
         MockPasswordService,
+        MockTemplateEmailService<()>,
         MockSessionService<()>,
         MockUserRepository<()>,
     >;
 
+    #[tokio::test]
+    async fn update_account_unchanged() {
+        // Arrange
+        let sut = Sut::default();
+
+        // Act
+        let result = sut
+            .update_account(
+                &mut (),
+                FOO.clone(),
+                UserAccountUpdate {
+                    name: FOO.user.name.clone().into(),
+                    email: FOO.user.email.clone().into(),
+                    enabled: FOO.user.enabled.into(),
+                    admin: FOO.user.admin.into(),
+                    ..Default::default()
+                },
+                UserUpdateNameRateLimitPolicy::Enforce,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), *FOO);
+    }
+
+    #[tokio::test]
+    async fn update_account_email_resets_verification() {
+        // Arrange
+        let expected = UserComposite {
+            user: User {
+                email: Some(ADMIN.user.email.clone().unwrap()),
+                email_verified: false,
+                ..FOO.user.clone()
+            },
+            ..FOO.clone()
+        };
+
+        let config = UserFeatureConfig::default();
+
+        let auth = MockAuthService::new().with_invalidate_access_tokens(FOO.user.id);
+
+        let user_repo = MockUserRepository::new().with_update(
+            FOO.user.id,
+            UserPatch::new()
+                .update_email(expected.user.email.clone())
+                .update_email_verified(false),
+            Ok(true),
+        );
+
+        let template_email = MockTemplateEmailService::new().with_send_email_changed_email(
+            FOO.user
+                .email
+                .clone()
+                .unwrap()
+                .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
+            EmailChangedTemplate {
+                new_email: ADMIN.user.email.clone().unwrap().as_str().into(),
+                recovery_url: (*config.account_recovery_url).clone(),
+            },
+        );
+
+        let sut = UserUpdateServiceImpl {
+            auth,
+            template_email,
+            user_repo,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut
+            .update_account(
+                &mut (),
+                FOO.clone(),
+                UserAccountUpdate {
+                    email: expected.user.email.clone().into(),
+                    ..Default::default()
+                },
+                UserUpdateNameRateLimitPolicy::Bypass,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn update_account_admin_password() {
+        // Arrange
+        let user_composite = FOO.clone().with(|x| {
+            x.user.email = None;
+            x.details.password_login = false;
+        });
+        let expected = user_composite.clone().with(|x| {
+            x.user.admin = true;
+            x.details.password_login = true;
+        });
+
+        let auth = MockAuthService::new().with_invalidate_access_tokens(FOO.user.id);
+
+        let password =
+            MockPasswordService::new().with_hash("new password".into(), "the hash".into());
+
+        let user_repo = MockUserRepository::new()
+            .with_update(FOO.user.id, UserPatch::new().update_admin(true), Ok(true))
+            .with_save_password_hash(FOO.user.id, "the hash".into());
+
+        let sut = UserUpdateServiceImpl {
+            auth,
+            password,
+            user_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut
+            .update_account(
+                &mut (),
+                user_composite,
+                UserAccountUpdate {
+                    admin: true.into(),
+                    password: UserPassword::try_new("new password").unwrap().into(),
+                    ..Default::default()
+                },
+                UserUpdateNameRateLimitPolicy::Bypass,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn update_account_email_password_notifies_previous_email() {
+        // Arrange
+        let config = UserFeatureConfig::default();
+
+        let new_email = ADMIN.user.email.clone().unwrap();
+        let expected = FOO.clone().with(|x| {
+            x.user.email = Some(new_email.clone());
+            x.user.email_verified = false;
+        });
+
+        let previous_recipient = FOO
+            .user
+            .email
+            .clone()
+            .unwrap()
+            .with_name(FOO.profile.display_name.clone().into_inner());
+
+        let auth = MockAuthService::new().with_invalidate_access_tokens(FOO.user.id);
+
+        let password =
+            MockPasswordService::new().with_hash("new password".into(), "the hash".into());
+
+        let user_repo = MockUserRepository::new()
+            .with_update(
+                FOO.user.id,
+                UserPatch::new()
+                    .update_email(Some(new_email.clone()))
+                    .update_email_verified(false),
+                Ok(true),
+            )
+            .with_save_password_hash(FOO.user.id, "the hash".into());
+
+        let template_email = MockTemplateEmailService::new()
+            .with_send_email_changed_email(
+                previous_recipient.clone(),
+                FOO.user.locale.clone(),
+                EmailChangedTemplate {
+                    new_email: new_email.as_str().into(),
+                    recovery_url: (*config.account_recovery_url).clone(),
+                },
+            )
+            .with_send_password_changed_email(
+                previous_recipient,
+                FOO.user.locale.clone(),
+                PasswordChangedTemplate {
+                    recovery_url: (*config.account_recovery_url).clone(),
+                },
+            );
+
+        let sut = UserUpdateServiceImpl {
+            auth,
+            password,
+            template_email,
+            user_repo,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut
+            .update_account(
+                &mut (),
+                FOO.clone(),
+                UserAccountUpdate {
+                    email: Some(new_email).into(),
+                    password: UserPassword::try_new("new password").unwrap().into(),
+                    ..Default::default()
+                },
+                UserUpdateNameRateLimitPolicy::Bypass,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn update_account_name_conflict() {
+        // Arrange
+        let user_repo = MockUserRepository::new().with_update(
+            FOO.user.id,
+            UserPatch::new().update_name(BAR.user.name.clone()),
+            Err(UserRepoError::NameConflict),
+        );
+
+        let sut = UserUpdateServiceImpl {
+            user_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut
+            .update_account(
+                &mut (),
+                FOO.clone(),
+                UserAccountUpdate {
+                    name: BAR.user.name.clone().into(),
+                    ..Default::default()
+                },
+                UserUpdateNameRateLimitPolicy::Bypass,
+            )
+            .await;
+
+        // Assert
+        assert_matches!(result, Err(UserUpdateAccountError::NameConflict));
+    }
+
     #[tokio::test]
     async fn update_name_ok_rate_limit() {
         // Arrange
@@ -441,12 +963,54 @@ mod tests {
 
     #[tokio::test]
     async fn update_password() {
+        // Arrange
+        let config = UserFeatureConfig::default();
+
+        let password =
+            MockPasswordService::new().with_hash("new password".into(), "the hash".into());
+
+        let user_repo = MockUserRepository::new()
+            .with_save_password_hash(FOO.user.id, "the hash".into())
+            .with_get_composite(FOO.user.id, Some(FOO.clone()));
+
+        let template_email = MockTemplateEmailService::new().with_send_password_changed_email(
+            FOO.user
+                .email
+                .clone()
+                .unwrap()
+                .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
+            PasswordChangedTemplate {
+                recovery_url: (*config.account_recovery_url).clone(),
+            },
+        );
+
+        let sut = UserUpdateServiceImpl {
+            password,
+            template_email,
+            user_repo,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut
+            .update_password(&mut (), FOO.user.id, "new password".try_into().unwrap())
+            .await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn update_password_no_email() {
         // Arrange
         let password =
             MockPasswordService::new().with_hash("new password".into(), "the hash".into());
 
-        let user_repo =
-            MockUserRepository::new().with_save_password_hash(FOO.user.id, "the hash".into());
+        let user_repo = MockUserRepository::new()
+            .with_save_password_hash(FOO.user.id, "the hash".into())
+            .with_get_composite(FOO.user.id, Some(FOO.clone().with(|u| u.user.email = None)));
 
         let sut = UserUpdateServiceImpl {
             password,
@@ -487,17 +1051,35 @@ mod tests {
     #[tokio::test]
     async fn update_enabled_disable() {
         // Arrange
-        let user_repo = MockUserRepository::new().with_update(
-            FOO.user.id,
-            UserPatch::new().update_enabled(false),
-            Ok(true),
-        );
+        let config = UserFeatureConfig::default();
+
+        let user_repo = MockUserRepository::new()
+            .with_update(
+                FOO.user.id,
+                UserPatch::new().update_enabled(false),
+                Ok(true),
+            )
+            .with_get_composite(FOO.user.id, Some(FOO.clone()));
 
         let session = MockSessionService::new().with_delete_by_user(FOO.user.id);
 
+        let template_email = MockTemplateEmailService::new().with_send_account_disabled_email(
+            FOO.user
+                .email
+                .clone()
+                .unwrap()
+                .with_name(FOO.profile.display_name.clone().into_inner()),
+            FOO.user.locale.clone(),
+            AccountDisabledTemplate {
+                recovery_url: (*config.account_recovery_url).clone(),
+            },
+        );
+
         let sut = UserUpdateServiceImpl {
             user_repo,
             session,
+            template_email,
+            config,
             ..Sut::default()
         };
 
@@ -562,8 +1144,16 @@ mod tests {
         let expected = FOO.invoice_info.clone();
         let patch = FOO.invoice_info.clone().into_patch();
 
-        let user_repo =
-            MockUserRepository::new().with_update_invoice_info(BAR.user.id, patch.clone(), true);
+        let vat_id_validation = FOO.invoice_info.vat_id_validation.clone();
+
+        let user_repo = MockUserRepository::new()
+            .with_update_invoice_info(BAR.user.id, patch.clone(), true)
+            .with_update_vat_id_validation(
+                BAR.user.id,
+                FOO.invoice_info.vat_id.clone().unwrap(),
+                vat_id_validation.clone().unwrap(),
+                true,
+            );
 
         let sut = UserUpdateServiceImpl {
             user_repo,
@@ -572,7 +1162,13 @@ mod tests {
 
         // Act
         let result = sut
-            .update_invoice_info(&mut (), BAR.user.id, BAR.invoice_info.clone(), patch)
+            .update_invoice_info(
+                &mut (),
+                BAR.user.id,
+                BAR.invoice_info.clone(),
+                patch,
+                vat_id_validation,
+            )
             .await;
 
         // Assert
@@ -585,6 +1181,7 @@ mod tests {
         let expected = FOO.invoice_info.clone().with(|u| {
             u.business = Some(false);
             u.vat_id = None;
+            u.vat_id_validation = None;
         });
         let patch = UserInvoiceInfoPatch::new().update_business(Some(false));
 
@@ -601,7 +1198,7 @@ mod tests {
 
         // Act
         let result = sut
-            .update_invoice_info(&mut (), FOO.user.id, FOO.invoice_info.clone(), patch)
+            .update_invoice_info(&mut (), FOO.user.id, FOO.invoice_info.clone(), patch, None)
             .await;
 
         // Assert
@@ -1,35 +1,75 @@
+use academy_auth_contracts::AuthService;
 use academy_core_oauth2_contracts::link::{OAuth2LinkService, OAuth2LinkServiceError};
 use academy_core_user_contracts::user::{
-    UserCreateCommand, UserCreateError, UserListQuery, UserListResult, UserService,
+    UserCreateCommand, UserCreateError, UserListError, UserListQuery, UserListResult, UserService,
 };
 use academy_di::Build;
-use academy_models::user::{User, UserComposite, UserDetails, UserInvoiceInfo, UserProfile};
+use academy_models::{
+    pagination::{Pagination, PaginationCursor},
+    user::{
+        User, UserComposite, UserDetails, UserId, UserInvoiceInfo, UserListKey, UserProfile,
+        UserSorting,
+    },
+};
 use academy_persistence_contracts::user::{UserRepoError, UserRepository};
-use academy_shared_contracts::{id::IdService, password::PasswordService, time::TimeService};
+use academy_shared_contracts::{
+    id::IdService, jwt::JwtService, password::PasswordService, time::TimeService,
+};
 use academy_utils::trace_instrument;
 use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::UserFeatureConfig;
 
-#[derive(Debug, Clone, Copy, Build, Default)]
-pub struct UserServiceImpl<Id, Time, Password, UserRepo, OAuth2CreateLink> {
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct UserServiceImpl<Id, Time, Auth, Password, Jwt, UserRepo, OAuth2CreateLink> {
     id: Id,
     time: Time,
+    auth: Auth,
     password: Password,
+    jwt: Jwt,
     user_repo: UserRepo,
     oauth2_create_link: OAuth2CreateLink,
+    config: UserFeatureConfig,
 }
 
-impl<Txn, Id, Time, Password, UserRepo, OAuth2Link> UserService<Txn>
-    for UserServiceImpl<Id, Time, Password, UserRepo, OAuth2Link>
+impl<Txn, Id, Time, Auth, Password, Jwt, UserRepo, OAuth2Link> UserService<Txn>
+    for UserServiceImpl<Id, Time, Auth, Password, Jwt, UserRepo, OAuth2Link>
 where
     Txn: Send + Sync + 'static,
     Id: IdService,
     Time: TimeService,
+    Auth: AuthService<Txn>,
     Password: PasswordService,
+    Jwt: JwtService,
     UserRepo: UserRepository<Txn>,
     OAuth2Link: OAuth2LinkService<Txn>,
 {
     #[trace_instrument(skip(self, txn))]
-    async fn list(&self, txn: &mut Txn, query: UserListQuery) -> anyhow::Result<UserListResult> {
+    async fn list(
+        &self,
+        txn: &mut Txn,
+        query: UserListQuery,
+    ) -> Result<UserListResult, UserListError> {
+        let pagination = match query.pagination {
+            Pagination::Offset(slice) => Pagination::Offset(slice),
+            Pagination::After { limit, key: cursor } => {
+                let cursor = self
+                    .jwt
+                    .verify::<_, ListCursor>(&cursor)
+                    .map_err(|_| UserListError::InvalidCursor)?;
+                if cursor.sorting != query.sorting {
+                    return Err(UserListError::InvalidCursor);
+                }
+                Pagination::After {
+                    limit,
+                    key: cursor.key,
+                }
+            }
+        };
+        let limit = pagination.limit();
+
         let total = self
             .user_repo
             .count(txn, &query.filter)
@@ -38,13 +78,28 @@ where
 
         let user_composites = self
             .user_repo
-            .list_composites(txn, &query.filter, query.pagination)
+            .list_composites(txn, &query.filter, query.sorting, pagination)
             .await
             .context("Failed to get users from database")?;
 
+        let next_cursor = user_composites
+            .last()
+            .filter(|_| user_composites.len() as u64 == *limit)
+            .map(|last| {
+                let cursor = ListCursor {
+                    sorting: query.sorting,
+                    key: UserListKey::new(&last.user, query.sorting.field),
+                };
+                self.jwt
+                    .sign::<_, PaginationCursor>(cursor, self.config.list_cursor_ttl)
+                    .context("Failed to sign pagination cursor")
+            })
+            .transpose()?;
+
         Ok(UserListResult {
             total,
             user_composites,
+            next_cursor,
         })
     }
 
@@ -61,6 +116,8 @@ where
             enabled,
             email_verified,
             oauth2_registration,
+            tags,
+            locale,
         }: UserCreateCommand,
     ) -> Result<UserComposite, UserCreateError> {
         let password_hash = match password {
@@ -84,12 +141,14 @@ where
             enabled,
             admin,
             newsletter: false,
+            locale,
         };
 
         let profile = UserProfile {
             display_name,
             bio: Default::default(),
-            tags: Default::default(),
+            tags,
+            avatar_url: None,
         };
 
         let details = UserDetails {
@@ -146,10 +205,30 @@ where
 
         Ok(user_composite)
     }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn delete(&self, txn: &mut Txn, user_id: UserId) -> anyhow::Result<bool> {
+        self.auth
+            .invalidate_access_tokens(txn, user_id)
+            .await
+            .context("Failed to invalidate access tokens")?;
+
+        self.user_repo
+            .delete(txn, user_id)
+            .await
+            .context("Failed to delete user from database")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ListCursor {
+    sorting: UserSorting,
+    key: UserListKey,
 }
 
 #[cfg(test)]
 mod tests {
+    use academy_auth_contracts::MockAuthService;
     use academy_core_oauth2_contracts::link::MockOAuth2LinkService;
     use academy_demo::{
         oauth2::{FOO_OAUTH2_LINK_1, TEST_OAUTH2_PROVIDER_ID},
@@ -157,12 +236,15 @@ mod tests {
     };
     use academy_models::{
         oauth2::OAuth2Registration,
-        pagination::PaginationSlice,
-        user::{UserFilter, UserPassword},
+        pagination::{PaginationSlice, SortDirection},
+        user::{UserFilter, UserPassword, UserSortField, UserSorting},
     };
     use academy_persistence_contracts::user::MockUserRepository;
     use academy_shared_contracts::{
-        id::MockIdService, password::MockPasswordService, time::MockTimeService,
+        id::MockIdService,
+        jwt::{MockJwtService, VerifyJwtError},
+        password::MockPasswordService,
+        time::MockTimeService,
     };
     use academy_utils::assert_matches;
 
@@ -171,7 +253,9 @@ mod tests {
     type Sut = UserServiceImpl<
         MockIdService,
         MockTimeService,
+        MockAuthService<()>,
         MockPasswordService,
+        MockJwtService,
         MockUserRepository<()>,
         MockOAuth2LinkService<()>,
     >;
@@ -183,8 +267,10 @@ mod tests {
             pagination: PaginationSlice {
                 limit: 42.try_into().unwrap(),
                 offset: 7,
-            },
+            }
+            .into(),
             filter: UserFilter {
+                query: Some("the query".try_into().unwrap()),
                 name: Some("the name".try_into().unwrap()),
                 email: Some("the email".try_into().unwrap()),
                 enabled: Some(true),
@@ -192,15 +278,81 @@ mod tests {
                 mfa_enabled: None,
                 email_verified: Some(true),
                 newsletter: Some(false),
+                created_after: Some(FOO.user.created_at),
+                created_before: None,
+                last_login_after: None,
+                last_login_before: FOO.user.last_login,
+                oauth2_provider: Some(TEST_OAUTH2_PROVIDER_ID.clone()),
+            },
+            sorting: UserSorting {
+                field: UserSortField::LastLogin,
+                direction: SortDirection::Desc,
             },
         };
         let expected = ALL_USERS.iter().copied().cloned().collect::<Vec<_>>();
 
         let user_repo = MockUserRepository::new()
             .with_count(query.filter.clone(), 17)
-            .with_list_composites(query.filter.clone(), query.pagination, expected.clone());
+            .with_list_composites(
+                query.filter.clone(),
+                query.sorting,
+                Pagination::Offset(PaginationSlice {
+                    limit: 42.try_into().unwrap(),
+                    offset: 7,
+                }),
+                expected.clone(),
+            );
+
+        let sut = UserServiceImpl {
+            user_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.list(&mut (), query).await;
+
+        // Assert
+        let result = result.unwrap();
+        assert_eq!(result.user_composites, expected);
+        assert_eq!(result.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn list_full_page() {
+        // Arrange
+        let query = UserListQuery {
+            pagination: PaginationSlice {
+                limit: 4.try_into().unwrap(),
+                offset: 0,
+            }
+            .into(),
+            ..Default::default()
+        };
+        let expected = ALL_USERS.iter().copied().cloned().collect::<Vec<_>>();
+
+        let jwt = MockJwtService::new().with_sign(
+            ListCursor {
+                sorting: Default::default(),
+                key: UserListKey::new(&expected[3].user, UserSortField::CreatedAt),
+            },
+            Sut::default().config.list_cursor_ttl,
+            Ok(PaginationCursor::from("the cursor".to_owned())),
+        );
+
+        let user_repo = MockUserRepository::new()
+            .with_count(Default::default(), 17)
+            .with_list_composites(
+                Default::default(),
+                Default::default(),
+                Pagination::Offset(PaginationSlice {
+                    limit: 4.try_into().unwrap(),
+                    offset: 0,
+                }),
+                expected.clone(),
+            );
 
         let sut = UserServiceImpl {
+            jwt,
             user_repo,
             ..Sut::default()
         };
@@ -211,6 +363,121 @@ mod tests {
         // Assert
         let result = result.unwrap();
         assert_eq!(result.user_composites, expected);
+        assert_eq!(result.next_cursor.unwrap().into_inner(), "the cursor");
+    }
+
+    #[tokio::test]
+    async fn list_cursor() {
+        // Arrange
+        let sorting = UserSorting {
+            field: UserSortField::Name,
+            direction: SortDirection::Desc,
+        };
+        let limit = 10.try_into().unwrap();
+        let cursor = PaginationCursor::from("the cursor".to_owned());
+        let key = UserListKey::new(&FOO.user, UserSortField::Name);
+        let query = UserListQuery {
+            pagination: Pagination::After {
+                limit,
+                key: cursor.clone(),
+            },
+            sorting,
+            ..Default::default()
+        };
+        let expected = ALL_USERS.iter().copied().cloned().collect::<Vec<_>>();
+
+        let jwt = MockJwtService::new().with_verify(
+            cursor,
+            Ok(ListCursor {
+                sorting,
+                key: key.clone(),
+            }),
+        );
+
+        let user_repo = MockUserRepository::new()
+            .with_count(Default::default(), 17)
+            .with_list_composites(
+                Default::default(),
+                sorting,
+                Pagination::After { limit, key },
+                expected.clone(),
+            );
+
+        let sut = UserServiceImpl {
+            jwt,
+            user_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.list(&mut (), query).await;
+
+        // Assert
+        let result = result.unwrap();
+        assert_eq!(result.user_composites, expected);
+        assert_eq!(result.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn list_invalid_cursor() {
+        // Arrange
+        let cursor = PaginationCursor::from("the cursor".to_owned());
+        let query = UserListQuery {
+            pagination: Pagination::After {
+                limit: Default::default(),
+                key: cursor.clone(),
+            },
+            ..Default::default()
+        };
+
+        let jwt =
+            MockJwtService::new().with_verify(cursor, Err(VerifyJwtError::<ListCursor>::Invalid));
+
+        let sut = UserServiceImpl {
+            jwt,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.list(&mut (), query).await;
+
+        // Assert
+        assert_matches!(result, Err(UserListError::InvalidCursor));
+    }
+
+    #[tokio::test]
+    async fn list_cursor_sorting_mismatch() {
+        // Arrange
+        let cursor = PaginationCursor::from("the cursor".to_owned());
+        let query = UserListQuery {
+            pagination: Pagination::After {
+                limit: Default::default(),
+                key: cursor.clone(),
+            },
+            ..Default::default()
+        };
+
+        let jwt = MockJwtService::new().with_verify(
+            cursor,
+            Ok(ListCursor {
+                sorting: UserSorting {
+                    field: UserSortField::Name,
+                    direction: SortDirection::Asc,
+                },
+                key: UserListKey::new(&FOO.user, UserSortField::Name),
+            }),
+        );
+
+        let sut = UserServiceImpl {
+            jwt,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.list(&mut (), query).await;
+
+        // Assert
+        assert_matches!(result, Err(UserListError::InvalidCursor));
     }
 
     #[tokio::test]
@@ -253,6 +520,8 @@ mod tests {
             enabled: true,
             email_verified: false,
             oauth2_registration: None,
+            tags: Default::default(),
+            locale: FOO.user.locale.clone(),
         };
 
         // Act
@@ -303,6 +572,8 @@ mod tests {
                 provider_id: TEST_OAUTH2_PROVIDER_ID.clone(),
                 remote_user: FOO_OAUTH2_LINK_1.remote_user.clone(),
             }),
+            tags: Default::default(),
+            locale: FOO.user.locale.clone(),
         };
 
         // Act
@@ -350,6 +621,8 @@ mod tests {
             enabled: true,
             email_verified: false,
             oauth2_registration: None,
+            tags: Default::default(),
+            locale: FOO.user.locale.clone(),
         };
 
         // Act
@@ -397,6 +670,8 @@ mod tests {
             enabled: true,
             email_verified: false,
             oauth2_registration: None,
+            tags: Default::default(),
+            locale: FOO.user.locale.clone(),
         };
 
         // Act
@@ -447,6 +722,8 @@ mod tests {
                 provider_id: TEST_OAUTH2_PROVIDER_ID.clone(),
                 remote_user: FOO_OAUTH2_LINK_1.remote_user.clone(),
             }),
+            tags: Default::default(),
+            locale: FOO.user.locale.clone(),
         };
 
         // Act
@@ -456,6 +733,46 @@ mod tests {
         assert_matches!(result, Err(UserCreateError::RemoteAlreadyLinked));
     }
 
+    #[tokio::test]
+    async fn delete() {
+        // Arrange
+        let auth = MockAuthService::new().with_invalidate_access_tokens(FOO.user.id);
+
+        let user_repo = MockUserRepository::new().with_delete(FOO.user.id, true);
+
+        let sut = UserServiceImpl {
+            auth,
+            user_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.delete(&mut (), FOO.user.id).await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn delete_not_found() {
+        // Arrange
+        let auth = MockAuthService::new().with_invalidate_access_tokens(FOO.user.id);
+
+        let user_repo = MockUserRepository::new().with_delete(FOO.user.id, false);
+
+        let sut = UserServiceImpl {
+            auth,
+            user_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.delete(&mut (), FOO.user.id).await;
+
+        // Assert
+        assert!(!result.unwrap());
+    }
+
     fn make_user_composite(password_login: bool, oauth2_login: bool) -> UserComposite {
         UserComposite {
             user: User {
@@ -469,11 +786,13 @@ mod tests {
                 enabled: true,
                 admin: false,
                 newsletter: false,
+                locale: FOO.user.locale.clone(),
             },
             profile: UserProfile {
                 display_name: FOO.profile.display_name.clone(),
                 bio: Default::default(),
                 tags: Default::default(),
+                avatar_url: None,
             },
             details: UserDetails {
                 mfa_enabled: false,
@@ -0,0 +1,257 @@
+use academy_cache_contracts::CacheService;
+use academy_core_user_contracts::vat::UserVatService;
+use academy_di::Build;
+use academy_extern_contracts::vat::{VatApiService, VatIdValidationResult};
+use academy_models::user::{UserVatId, UserVatIdStatus, UserVatIdValidation};
+use academy_shared_contracts::time::TimeService;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+
+use crate::UserFeatureConfig;
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct UserVatServiceImpl<Time, Cache, VatApi> {
+    time: Time,
+    cache: Cache,
+    vat_api: VatApi,
+    config: UserFeatureConfig,
+}
+
+impl<Time, Cache, VatApi> UserVatService for UserVatServiceImpl<Time, Cache, VatApi>
+where
+    Time: TimeService,
+    Cache: CacheService,
+    VatApi: VatApiService,
+{
+    #[trace_instrument(skip(self))]
+    async fn validate(&self, vat_id: &UserVatId) -> anyhow::Result<UserVatIdValidation> {
+        if let Some(validation) = self
+            .cache
+            .get(&cache_key(vat_id))
+            .await
+            .context("Failed to get VAT id validation from cache")?
+        {
+            return Ok(validation);
+        }
+
+        self.revalidate(vat_id).await
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn revalidate(&self, vat_id: &UserVatId) -> anyhow::Result<UserVatIdValidation> {
+        let result = self
+            .vat_api
+            .validate_vat_id(vat_id.as_str())
+            .await
+            .context("Failed to validate VAT id")?;
+
+        let (status, consultation_number) = match result {
+            VatIdValidationResult::Valid {
+                consultation_number,
+            } => (UserVatIdStatus::Valid, consultation_number),
+            VatIdValidationResult::Invalid => (UserVatIdStatus::Invalid, None),
+            VatIdValidationResult::Unavailable => (UserVatIdStatus::Pending, None),
+        };
+
+        let validation = UserVatIdValidation {
+            status,
+            consultation_number,
+            validated_at: self.time.now(),
+        };
+
+        // Pending validations are not cached so the VAT id is checked again as
+        // soon as VIES is available.
+        if status != UserVatIdStatus::Pending {
+            self.cache
+                .set(
+                    &cache_key(vat_id),
+                    &validation,
+                    Some(self.config.vat_cache_ttl),
+                )
+                .await
+                .context("Failed to save VAT id validation in cache")?;
+        }
+
+        Ok(validation)
+    }
+}
+
+fn cache_key(vat_id: &UserVatId) -> String {
+    format!("vat_id_validation:{}", vat_id.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_cache_contracts::MockCacheService;
+    use academy_demo::user::FOO;
+    use academy_extern_contracts::vat::MockVatApiService;
+    use academy_shared_contracts::time::MockTimeService;
+
+    use super::*;
+
+    type Sut = UserVatServiceImpl<MockTimeService, MockCacheService, MockVatApiService>;
+
+    #[tokio::test]
+    async fn validate_cached() {
+        // Arrange
+        let vat_id = FOO.invoice_info.vat_id.clone().unwrap();
+        let expected = FOO.invoice_info.vat_id_validation.clone().unwrap();
+
+        let cache = MockCacheService::new().with_get(cache_key(&vat_id), Some(expected.clone()));
+
+        let sut = UserVatServiceImpl {
+            cache,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.validate(&vat_id).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn validate_valid() {
+        // Arrange
+        let vat_id = FOO.invoice_info.vat_id.clone().unwrap();
+        let expected = FOO.invoice_info.vat_id_validation.clone().unwrap();
+
+        let time = MockTimeService::new().with_now(expected.validated_at);
+
+        let cache = MockCacheService::new()
+            .with_get(cache_key(&vat_id), None::<UserVatIdValidation>)
+            .with_set(
+                cache_key(&vat_id),
+                expected.clone(),
+                Some(UserFeatureConfig::default().vat_cache_ttl),
+            );
+
+        let vat_api = MockVatApiService::new().with_validate_vat_id(
+            vat_id.clone().into_inner(),
+            VatIdValidationResult::Valid {
+                consultation_number: expected.consultation_number.clone(),
+            },
+        );
+
+        let sut = UserVatServiceImpl {
+            time,
+            cache,
+            vat_api,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.validate(&vat_id).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn validate_invalid() {
+        // Arrange
+        let vat_id = FOO.invoice_info.vat_id.clone().unwrap();
+        let expected = UserVatIdValidation {
+            status: UserVatIdStatus::Invalid,
+            consultation_number: None,
+            ..FOO.invoice_info.vat_id_validation.clone().unwrap()
+        };
+
+        let time = MockTimeService::new().with_now(expected.validated_at);
+
+        let cache = MockCacheService::new()
+            .with_get(cache_key(&vat_id), None::<UserVatIdValidation>)
+            .with_set(
+                cache_key(&vat_id),
+                expected.clone(),
+                Some(UserFeatureConfig::default().vat_cache_ttl),
+            );
+
+        let vat_api = MockVatApiService::new()
+            .with_validate_vat_id(vat_id.clone().into_inner(), VatIdValidationResult::Invalid);
+
+        let sut = UserVatServiceImpl {
+            time,
+            cache,
+            vat_api,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.validate(&vat_id).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn validate_unavailable() {
+        // Arrange
+        let vat_id = FOO.invoice_info.vat_id.clone().unwrap();
+        let expected = UserVatIdValidation {
+            status: UserVatIdStatus::Pending,
+            consultation_number: None,
+            ..FOO.invoice_info.vat_id_validation.clone().unwrap()
+        };
+
+        let time = MockTimeService::new().with_now(expected.validated_at);
+
+        let cache =
+            MockCacheService::new().with_get(cache_key(&vat_id), None::<UserVatIdValidation>);
+
+        let vat_api = MockVatApiService::new().with_validate_vat_id(
+            vat_id.clone().into_inner(),
+            VatIdValidationResult::Unavailable,
+        );
+
+        let sut = UserVatServiceImpl {
+            time,
+            cache,
+            vat_api,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.validate(&vat_id).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn revalidate_ignores_cache() {
+        // Arrange
+        let vat_id = FOO.invoice_info.vat_id.clone().unwrap();
+        let expected = FOO.invoice_info.vat_id_validation.clone().unwrap();
+
+        let time = MockTimeService::new().with_now(expected.validated_at);
+
+        let cache = MockCacheService::new().with_set(
+            cache_key(&vat_id),
+            expected.clone(),
+            Some(UserFeatureConfig::default().vat_cache_ttl),
+        );
+
+        let vat_api = MockVatApiService::new().with_validate_vat_id(
+            vat_id.clone().into_inner(),
+            VatIdValidationResult::Valid {
+                consultation_number: expected.consultation_number.clone(),
+            },
+        );
+
+        let sut = UserVatServiceImpl {
+            time,
+            cache,
+            vat_api,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.revalidate(&vat_id).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected);
+    }
+}
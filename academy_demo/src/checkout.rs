@@ -0,0 +1,44 @@
+use std::{sync::LazyLock, time::Duration};
+
+use academy_models::checkout::{CoinOrder, CoinOrderStatus};
+use academy_persistence_contracts::checkout::CheckoutRepository;
+use uuid::uuid;
+
+use crate::user::FOO;
+
+pub static ALL_ORDERS: LazyLock<Vec<&CoinOrder>> =
+    LazyLock::new(|| vec![&FOO_ORDER_CANCELLED, &FOO_ORDER_PENDING]);
+
+pub static FOO_ORDER_CANCELLED: LazyLock<CoinOrder> = LazyLock::new(|| CoinOrder {
+    id: uuid!("2b6e9c41-8f3d-4a57-b1e0-5c7a9d3f6e82").into(),
+    user_id: FOO.user.id,
+    package: "small".try_into().unwrap(),
+    coins: 100.try_into().unwrap(),
+    price: 100,
+    status: CoinOrderStatus::Cancelled,
+    payment_id: "cs_4f1b8e2a9c7d4e6f".try_into().unwrap(),
+    created_at: FOO.user.created_at + Duration::from_secs(4 * 24 * 3600),
+    updated_at: FOO.user.created_at + Duration::from_secs(4 * 24 * 3600 + 120),
+});
+
+pub static FOO_ORDER_PENDING: LazyLock<CoinOrder> = LazyLock::new(|| CoinOrder {
+    id: uuid!("7a3d5f19-c2e8-4b60-9d47-e1f8b2c6a053").into(),
+    user_id: FOO.user.id,
+    package: "large".try_into().unwrap(),
+    coins: 1000.try_into().unwrap(),
+    price: 900,
+    status: CoinOrderStatus::Pending,
+    payment_id: "cs_a9e3c6d1f8b24057".try_into().unwrap(),
+    created_at: FOO.user.created_at + Duration::from_secs(5 * 24 * 3600),
+    updated_at: FOO.user.created_at + Duration::from_secs(5 * 24 * 3600),
+});
+
+pub async fn create<Txn: Send + Sync + 'static>(
+    txn: &mut Txn,
+    repo: impl CheckoutRepository<Txn>,
+) -> anyhow::Result<()> {
+    for &order in &*ALL_ORDERS {
+        repo.create_order(txn, order).await?;
+    }
+    Ok(())
+}
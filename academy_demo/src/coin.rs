@@ -0,0 +1,60 @@
+use std::{sync::LazyLock, time::Duration};
+
+use academy_models::coin::{CoinBalance, CoinTransaction, CoinTransactionKind};
+use academy_persistence_contracts::coin::CoinRepository;
+use uuid::uuid;
+
+use crate::user::{BAR, FOO};
+
+pub static ALL_TRANSACTIONS: LazyLock<Vec<&CoinTransaction>> =
+    LazyLock::new(|| vec![&FOO_EARN, &FOO_SPEND, &BAR_WITHHELD]);
+
+pub const FOO_BALANCE: CoinBalance = CoinBalance {
+    coins: 700,
+    withheld_coins: 0,
+};
+
+pub const BAR_BALANCE: CoinBalance = CoinBalance {
+    coins: 0,
+    withheld_coins: 50,
+};
+
+pub static FOO_EARN: LazyLock<CoinTransaction> = LazyLock::new(|| CoinTransaction {
+    id: uuid!("c3a7e1d9-5b82-4f60-9e14-7d2b8a6f0c35").into(),
+    user_id: FOO.user.id,
+    kind: CoinTransactionKind::Earn,
+    coins: 1000.try_into().unwrap(),
+    description: Some("Solved coding challenge".try_into().unwrap()),
+    reference: Some("challenge:1".try_into().unwrap()),
+    created_at: FOO.user.created_at + Duration::from_secs(2 * 24 * 3600),
+});
+
+pub static FOO_SPEND: LazyLock<CoinTransaction> = LazyLock::new(|| CoinTransaction {
+    id: uuid!("4e8b2f6a-a1c7-4d93-8b05-e6f3c9d17a28").into(),
+    user_id: FOO.user.id,
+    kind: CoinTransactionKind::Spend,
+    coins: 300.try_into().unwrap(),
+    description: Some("Bought course".try_into().unwrap()),
+    reference: None,
+    created_at: FOO.user.created_at + Duration::from_secs(3 * 24 * 3600),
+});
+
+pub static BAR_WITHHELD: LazyLock<CoinTransaction> = LazyLock::new(|| CoinTransaction {
+    id: uuid!("9d5f0b3e-7c26-48a1-b3e9-1a4d6c8f2e07").into(),
+    user_id: BAR.user.id,
+    kind: CoinTransactionKind::Withheld,
+    coins: 50.try_into().unwrap(),
+    description: None,
+    reference: Some("challenge:2".try_into().unwrap()),
+    created_at: BAR.user.created_at + Duration::from_secs(24 * 3600),
+});
+
+pub async fn create<Txn: Send + Sync + 'static>(
+    txn: &mut Txn,
+    repo: impl CoinRepository<Txn>,
+) -> anyhow::Result<()> {
+    for &transaction in &*ALL_TRANSACTIONS {
+        repo.create_transaction(txn, transaction).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::{sync::LazyLock, time::Duration};
+
+use academy_models::contact::{
+    ContactInboxMessage, ContactMessage, ContactMessageAuthor, ContactMessageReply,
+    ContactMessageStatus,
+};
+use academy_persistence_contracts::contact::ContactRepository;
+use uuid::uuid;
+
+use crate::user::{ADMIN, FOO};
+
+pub static ALL_MESSAGES: LazyLock<Vec<&ContactInboxMessage>> =
+    LazyLock::new(|| vec![&OPEN_MESSAGE, &RESOLVED_MESSAGE]);
+
+pub static ALL_REPLIES: LazyLock<Vec<&ContactMessageReply>> =
+    LazyLock::new(|| vec![&RESOLVED_MESSAGE_REPLY]);
+
+pub static OPEN_MESSAGE: LazyLock<ContactInboxMessage> = LazyLock::new(|| ContactInboxMessage {
+    id: uuid!("8f3c2a61-7d4e-4b19-a0c5-2e6f9d1b7a43").into(),
+    message: ContactMessage {
+        author: ContactMessageAuthor {
+            name: "Max Mustermann".try_into().unwrap(),
+            email: "max.mustermann@example.de".parse().unwrap(),
+        },
+        subject: "Certificate".try_into().unwrap(),
+        content: "Where can I download my certificate?".try_into().unwrap(),
+    },
+    status: ContactMessageStatus::Open,
+    assignee: None,
+    email_message_id: "<8f3c2a61-7d4e-4b19-a0c5-2e6f9d1b7a43@bootstrap.academy>".into(),
+    created_at: FOO.user.created_at + Duration::from_secs(14 * 24 * 3600),
+});
+
+pub static RESOLVED_MESSAGE: LazyLock<ContactInboxMessage> =
+    LazyLock::new(|| ContactInboxMessage {
+        id: uuid!("2b7e9d04-c1a8-4f36-8e52-91d3a6c0f7b8").into(),
+        message: ContactMessage {
+            author: ContactMessageAuthor {
+                name: FOO
+                    .profile
+                    .display_name
+                    .clone()
+                    .into_inner()
+                    .try_into()
+                    .unwrap(),
+                email: FOO.user.email.clone().unwrap(),
+            },
+            subject: "Password reset".try_into().unwrap(),
+            content: "I did not receive a password reset email."
+                .try_into()
+                .unwrap(),
+        },
+        status: ContactMessageStatus::Resolved,
+        assignee: Some(ADMIN.user.id),
+        email_message_id: "<2b7e9d04-c1a8-4f36-8e52-91d3a6c0f7b8@bootstrap.academy>".into(),
+        created_at: FOO.user.created_at + Duration::from_secs(3600),
+    });
+
+pub static RESOLVED_MESSAGE_REPLY: LazyLock<ContactMessageReply> =
+    LazyLock::new(|| ContactMessageReply {
+        id: uuid!("e5d1b8f2-6a93-4c07-b4e1-0f8a2c7d9e36").into(),
+        message_id: RESOLVED_MESSAGE.id,
+        author: Some(ADMIN.user.id),
+        content: "Please check your spam folder.".try_into().unwrap(),
+        email_message_id: "<e5d1b8f2-6a93-4c07-b4e1-0f8a2c7d9e36@bootstrap.academy>".into(),
+        created_at: RESOLVED_MESSAGE.created_at + Duration::from_secs(1800),
+    });
+
+pub async fn create<Txn: Send + Sync + 'static>(
+    txn: &mut Txn,
+    repo: impl ContactRepository<Txn>,
+) -> anyhow::Result<()> {
+    for &message in &*ALL_MESSAGES {
+        repo.create_message(txn, message).await?;
+    }
+    for &reply in &*ALL_REPLIES {
+        repo.create_reply(txn, reply).await?;
+    }
+    Ok(())
+}
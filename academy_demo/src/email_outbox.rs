@@ -0,0 +1,69 @@
+use std::{sync::LazyLock, time::Duration};
+
+use academy_models::{
+    email::{Email, EmailBody},
+    email_outbox::{EmailOutboxEntry, EmailOutboxStatus},
+};
+use academy_persistence_contracts::email_outbox::EmailOutboxRepository;
+use uuid::uuid;
+
+use crate::user::{ADMIN2, FOO};
+
+pub static ALL_EMAILS: LazyLock<Vec<&EmailOutboxEntry>> =
+    LazyLock::new(|| vec![&SENT_EMAIL, &FAILED_EMAIL]);
+
+pub static SENT_EMAIL: LazyLock<EmailOutboxEntry> = LazyLock::new(|| EmailOutboxEntry {
+    id: uuid!("5b8e1c2a-3f7d-4a96-8e0b-c4d2f19a6e37").into(),
+    email: Email {
+        recipient: FOO
+            .user
+            .email
+            .clone()
+            .unwrap()
+            .with_name(FOO.profile.display_name.clone().into_inner()),
+        subject: "Verify your email address".into(),
+        body: EmailBody::Text("Your verification code: UH86-I3DC-PWPP-VKQ9".into()),
+        reply_to: None,
+        headers: Vec::new(),
+        inline_images: Vec::new(),
+    },
+    status: EmailOutboxStatus::Sent,
+    attempts: 0,
+    created_at: FOO.user.created_at + Duration::from_secs(60),
+    next_attempt_at: None,
+    sent_at: Some(FOO.user.created_at + Duration::from_secs(61)),
+    last_error: None,
+});
+
+pub static FAILED_EMAIL: LazyLock<EmailOutboxEntry> = LazyLock::new(|| EmailOutboxEntry {
+    id: uuid!("d4a07f63-2b91-4c5e-9f18-7e3b6a0c852d").into(),
+    email: Email {
+        recipient: ADMIN2
+            .user
+            .email
+            .clone()
+            .unwrap()
+            .with_name(ADMIN2.profile.display_name.clone().into_inner()),
+        subject: "Reset your password".into(),
+        body: EmailBody::Text("Your password reset code: HFWG-6TTY-0UY4-73YZ".into()),
+        reply_to: None,
+        headers: Vec::new(),
+        inline_images: Vec::new(),
+    },
+    status: EmailOutboxStatus::Failed,
+    attempts: 5,
+    created_at: ADMIN2.user.created_at + Duration::from_secs(3600),
+    next_attempt_at: None,
+    sent_at: None,
+    last_error: Some("The smtp server returned a negative response".into()),
+});
+
+pub async fn create<Txn: Send + Sync + 'static>(
+    txn: &mut Txn,
+    repo: impl EmailOutboxRepository<Txn>,
+) -> anyhow::Result<()> {
+    for &entry in &*ALL_EMAILS {
+        repo.create(txn, entry).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use std::{sync::LazyLock, time::Duration};
+
+use academy_models::invite::Invite;
+use academy_persistence_contracts::invite::InviteRepository;
+use uuid::uuid;
+
+use crate::user::{ADMIN, FOO};
+
+pub static ALL_INVITES: LazyLock<Vec<&Invite>> =
+    LazyLock::new(|| vec![&ADMIN_INVITE_1, &FOO_INVITE_1]);
+
+pub static ADMIN_INVITE_1: LazyLock<Invite> = LazyLock::new(|| Invite {
+    id: uuid!("5c1f8a0e-2d5e-4c1f-9f0b-6a0e3f6b8d21").into(),
+    code: "BETA-2024-C0HT".try_into().unwrap(),
+    created_by: Some(ADMIN.user.id),
+    created_at: ADMIN.user.created_at + Duration::from_secs(3600),
+    expires_at: None,
+    max_uses: Some(100.try_into().unwrap()),
+    uses: 0,
+    admin: false,
+    cohort: Some("beta".try_into().unwrap()),
+});
+
+pub static FOO_INVITE_1: LazyLock<Invite> = LazyLock::new(|| Invite {
+    id: uuid!("b6a3d7c4-81e9-4f62-a5d0-3e7c9f1a2b54").into(),
+    code: "F00F-R1EN-D5X7".try_into().unwrap(),
+    created_by: Some(FOO.user.id),
+    created_at: FOO.user.created_at + Duration::from_secs(7 * 24 * 3600),
+    expires_at: Some(FOO.user.created_at + Duration::from_secs(37 * 24 * 3600)),
+    max_uses: Some(1.try_into().unwrap()),
+    uses: 0,
+    admin: false,
+    cohort: None,
+});
+
+pub async fn create<Txn: Send + Sync + 'static>(
+    txn: &mut Txn,
+    repo: impl InviteRepository<Txn>,
+) -> anyhow::Result<()> {
+    for &invite in &*ALL_INVITES {
+        repo.create(txn, invite).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::{sync::LazyLock, time::Duration};
+
+use academy_models::invoice::{Invoice, InvoiceKind, InvoiceRecipient, InvoiceVatTreatment};
+use academy_persistence_contracts::invoice::InvoiceRepository;
+use chrono::Datelike;
+use uuid::uuid;
+
+use crate::user::FOO;
+
+pub static ALL_INVOICES: LazyLock<Vec<&Invoice>> =
+    LazyLock::new(|| vec![&FOO_INVOICE_PURCHASE, &FOO_INVOICE_PAYOUT]);
+
+pub static FOO_INVOICE_PURCHASE: LazyLock<Invoice> = LazyLock::new(|| Invoice {
+    id: uuid!("c4e1a7f2-3b9d-4e08-a6c5-8f2d1b7e9a34").into(),
+    user_id: FOO.user.id,
+    number: "BA-2024-000001".try_into().unwrap(),
+    kind: InvoiceKind::Purchase,
+    recipient: InvoiceRecipient::from_invoice_info(&FOO.invoice_info).unwrap(),
+    description: "1000 Coins".try_into().unwrap(),
+    net: 798,
+    vat: 152,
+    gross: 950,
+    vat_rate: 1900,
+    vat_treatment: InvoiceVatTreatment::Standard,
+    created_at: FOO.user.created_at + Duration::from_secs(3 * 24 * 3600),
+});
+
+pub static FOO_INVOICE_PAYOUT: LazyLock<Invoice> = LazyLock::new(|| Invoice {
+    id: uuid!("5d8b2e6a-f1c7-4a93-b0e4-7c9a3f5d1e68").into(),
+    user_id: FOO.user.id,
+    number: "BA-2024-000002".try_into().unwrap(),
+    kind: InvoiceKind::Payout,
+    recipient: InvoiceRecipient::from_invoice_info(&FOO.invoice_info).unwrap(),
+    description: "Payout of 2000 Coins".try_into().unwrap(),
+    net: 1681,
+    vat: 319,
+    gross: 2000,
+    vat_rate: 1900,
+    vat_treatment: InvoiceVatTreatment::Standard,
+    created_at: FOO.user.created_at + Duration::from_secs(6 * 24 * 3600),
+});
+
+pub async fn create<Txn: Send + Sync + 'static>(
+    txn: &mut Txn,
+    repo: impl InvoiceRepository<Txn>,
+) -> anyhow::Result<()> {
+    for &invoice in &*ALL_INVOICES {
+        repo.next_number(txn, invoice.created_at.year()).await?;
+        repo.create(txn, invoice).await?;
+    }
+    Ok(())
+}
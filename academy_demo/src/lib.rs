@@ -2,14 +2,25 @@ use std::sync::LazyLock;
 
 use academy_models::{Sha256Hash, VerificationCode};
 use academy_persistence_contracts::{
-    mfa::MfaRepository, oauth2::OAuth2Repository, session::SessionRepository, user::UserRepository,
+    checkout::CheckoutRepository, coin::CoinRepository, contact::ContactRepository,
+    email_outbox::EmailOutboxRepository, invite::InviteRepository, invoice::InvoiceRepository,
+    mfa::MfaRepository, newsletter::NewsletterRepository, oauth2::OAuth2Repository,
+    session::SessionRepository, skill::SkillRepository, user::UserRepository,
 };
 use anyhow::Context;
 use uuid::{uuid, Uuid};
 
+pub mod checkout;
+pub mod coin;
+pub mod contact;
+pub mod email_outbox;
+pub mod invite;
+pub mod invoice;
 pub mod mfa;
+pub mod newsletter;
 pub mod oauth2;
 pub mod session;
+pub mod skill;
 pub mod user;
 
 pub const UUID1: Uuid = uuid!("eb1cd87a-4475-4d68-a2c2-0216bdaac8f7");
@@ -32,12 +43,21 @@ pub static VERIFICATION_CODE_1: LazyLock<VerificationCode> =
 pub static VERIFICATION_CODE_2: LazyLock<VerificationCode> =
     LazyLock::new(|| "HFWG-6TTY-0UY4-73YZ".try_into().unwrap());
 
+#[allow(clippy::too_many_arguments, reason = "one repository per demo dataset")]
 pub async fn create<Txn: Send + Sync + 'static>(
     txn: &mut Txn,
     user: impl UserRepository<Txn>,
     session: impl SessionRepository<Txn>,
     mfa: impl MfaRepository<Txn>,
     oauth2: impl OAuth2Repository<Txn>,
+    invite: impl InviteRepository<Txn>,
+    newsletter: impl NewsletterRepository<Txn>,
+    email_outbox: impl EmailOutboxRepository<Txn>,
+    contact: impl ContactRepository<Txn>,
+    coin: impl CoinRepository<Txn>,
+    checkout: impl CheckoutRepository<Txn>,
+    invoice: impl InvoiceRepository<Txn>,
+    skill: impl SkillRepository<Txn>,
 ) -> anyhow::Result<()> {
     macro_rules! create {
         ($($ident:ident),* $(,)?) => { $(
@@ -47,7 +67,20 @@ pub async fn create<Txn: Send + Sync + 'static>(
         )*};
     }
 
-    create!(user, session, mfa, oauth2);
+    create!(
+        user,
+        session,
+        mfa,
+        oauth2,
+        invite,
+        newsletter,
+        email_outbox,
+        contact,
+        coin,
+        checkout,
+        invoice,
+        skill
+    );
 
     Ok(())
 }
@@ -0,0 +1,48 @@
+use std::{sync::LazyLock, time::Duration};
+
+use academy_models::newsletter::{NewsletterCampaign, NewsletterCampaignStatus};
+use academy_persistence_contracts::newsletter::NewsletterRepository;
+use uuid::uuid;
+
+use crate::user::ADMIN;
+
+pub static ALL_CAMPAIGNS: LazyLock<Vec<&NewsletterCampaign>> =
+    LazyLock::new(|| vec![&SENT_CAMPAIGN, &DRAFT_CAMPAIGN]);
+
+pub static SENT_CAMPAIGN: LazyLock<NewsletterCampaign> = LazyLock::new(|| NewsletterCampaign {
+    id: uuid!("0d7e2a4f-9c3b-4e8a-b1f6-52c8d9e0a7b3").into(),
+    subject: "Welcome to the Bootstrap Academy newsletter"
+        .try_into()
+        .unwrap(),
+    body_html: "<p>Hello world!</p>".try_into().unwrap(),
+    body_text: "Hello world!".try_into().unwrap(),
+    status: NewsletterCampaignStatus::Sent,
+    created_at: ADMIN.user.created_at + Duration::from_secs(24 * 3600),
+    started_at: Some(ADMIN.user.created_at + Duration::from_secs(2 * 24 * 3600)),
+    completed_at: Some(ADMIN.user.created_at + Duration::from_secs(2 * 24 * 3600 + 600)),
+    progress: Default::default(),
+});
+
+pub static DRAFT_CAMPAIGN: LazyLock<NewsletterCampaign> = LazyLock::new(|| NewsletterCampaign {
+    id: uuid!("a3c91f57-6e2d-4b08-8d4a-f1e7b2c65d90").into(),
+    subject: "New courses available".try_into().unwrap(),
+    body_html: "<p>Check out our <b>new courses</b>!</p>"
+        .try_into()
+        .unwrap(),
+    body_text: "Check out our new courses!".try_into().unwrap(),
+    status: NewsletterCampaignStatus::Draft,
+    created_at: ADMIN.user.created_at + Duration::from_secs(30 * 24 * 3600),
+    started_at: None,
+    completed_at: None,
+    progress: Default::default(),
+});
+
+pub async fn create<Txn: Send + Sync + 'static>(
+    txn: &mut Txn,
+    repo: impl NewsletterRepository<Txn>,
+) -> anyhow::Result<()> {
+    for &campaign in &*ALL_CAMPAIGNS {
+        repo.create_campaign(txn, campaign).await?;
+    }
+    Ok(())
+}
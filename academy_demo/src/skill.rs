@@ -0,0 +1,158 @@
+use std::{sync::LazyLock, time::Duration};
+
+use academy_models::skill::{Course, CourseSection, Lecture, Skill, SkillPrerequisite};
+use academy_persistence_contracts::skill::SkillRepository;
+use uuid::uuid;
+
+use crate::user::{ADMIN, FOO};
+
+pub static ALL_SKILLS: LazyLock<Vec<&Skill>> =
+    LazyLock::new(|| vec![&PYTHON_BASICS, &PYTHON_ADVANCED, &GIT]);
+pub static ALL_PREREQUISITES: LazyLock<Vec<SkillPrerequisite>> = LazyLock::new(|| {
+    vec![SkillPrerequisite {
+        skill_id: PYTHON_ADVANCED.id,
+        prerequisite_id: PYTHON_BASICS.id,
+    }]
+});
+pub static ALL_COURSES: LazyLock<Vec<&Course>> =
+    LazyLock::new(|| vec![&PYTHON_COURSE, &ADVANCED_PYTHON_COURSE]);
+pub static ALL_SECTIONS: LazyLock<Vec<&CourseSection>> = LazyLock::new(|| {
+    vec![
+        &PYTHON_SECTION_1,
+        &PYTHON_SECTION_2,
+        &ADVANCED_PYTHON_SECTION,
+    ]
+});
+pub static ALL_LECTURES: LazyLock<Vec<&Lecture>> = LazyLock::new(|| {
+    vec![
+        &PYTHON_LECTURE_1,
+        &PYTHON_LECTURE_2,
+        &PYTHON_LECTURE_3,
+        &ADVANCED_PYTHON_LECTURE,
+    ]
+});
+
+/// The lectures which have been completed by [`FOO`]
+pub static FOO_COMPLETED_LECTURES: LazyLock<Vec<&Lecture>> =
+    LazyLock::new(|| vec![&PYTHON_LECTURE_1]);
+
+pub static PYTHON_BASICS: LazyLock<Skill> = LazyLock::new(|| Skill {
+    id: uuid!("5b0f6f1e-0a64-4f7c-9a8e-3c2d1b7e4a91").into(),
+    title: "Python Basics".try_into().unwrap(),
+    description: "Variables, control flow and functions".try_into().unwrap(),
+    created_at: ADMIN.user.created_at + Duration::from_secs(3600),
+});
+
+pub static PYTHON_ADVANCED: LazyLock<Skill> = LazyLock::new(|| Skill {
+    id: uuid!("c7e2d98a-3b1f-4e60-8f5d-9a04b6c3e218").into(),
+    title: "Advanced Python".try_into().unwrap(),
+    description: "Generators, decorators and async".try_into().unwrap(),
+    created_at: ADMIN.user.created_at + Duration::from_secs(2 * 3600),
+});
+
+pub static GIT: LazyLock<Skill> = LazyLock::new(|| Skill {
+    id: uuid!("1e9a4c70-d2b5-4a3f-86e1-7f0c5d2b9e46").into(),
+    title: "Git".try_into().unwrap(),
+    description: "".try_into().unwrap(),
+    created_at: ADMIN.user.created_at + Duration::from_secs(3 * 3600),
+});
+
+pub static PYTHON_COURSE: LazyLock<Course> = LazyLock::new(|| Course {
+    id: uuid!("8d3f1a62-7c4e-4b95-a0d1-2e6b9f8c5a73").into(),
+    skill_id: PYTHON_BASICS.id,
+    title: "Introduction to Python".try_into().unwrap(),
+    description: "Learn the fundamentals of Python".try_into().unwrap(),
+    created_at: ADMIN.user.created_at + Duration::from_secs(4 * 3600),
+});
+
+pub static ADVANCED_PYTHON_COURSE: LazyLock<Course> = LazyLock::new(|| Course {
+    id: uuid!("f2a5b8c1-9e3d-4f76-b4a2-6d1c0e7f3b58").into(),
+    skill_id: PYTHON_ADVANCED.id,
+    title: "Python Deep Dive".try_into().unwrap(),
+    description: "".try_into().unwrap(),
+    created_at: ADMIN.user.created_at + Duration::from_secs(5 * 3600),
+});
+
+pub static PYTHON_SECTION_1: LazyLock<CourseSection> = LazyLock::new(|| CourseSection {
+    id: uuid!("3a7c9e51-b4d2-4f08-9e6a-c1b5d8f2a407").into(),
+    course_id: PYTHON_COURSE.id,
+    title: "Getting Started".try_into().unwrap(),
+    position: 0,
+});
+
+pub static PYTHON_SECTION_2: LazyLock<CourseSection> = LazyLock::new(|| CourseSection {
+    id: uuid!("b6e1f483-2d9c-4a75-8b3e-05f7a9c2d6e1").into(),
+    course_id: PYTHON_COURSE.id,
+    title: "Functions".try_into().unwrap(),
+    position: 1,
+});
+
+pub static ADVANCED_PYTHON_SECTION: LazyLock<CourseSection> = LazyLock::new(|| CourseSection {
+    id: uuid!("e4c8a2b7-5f1d-4e93-a6c0-9d3b7f1e5a82").into(),
+    course_id: ADVANCED_PYTHON_COURSE.id,
+    title: "Generators".try_into().unwrap(),
+    position: 0,
+});
+
+pub static PYTHON_LECTURE_1: LazyLock<Lecture> = LazyLock::new(|| Lecture {
+    id: uuid!("0c5e7b9d-1a3f-4d62-8e4b-f6a2c9d1b735").into(),
+    section_id: PYTHON_SECTION_1.id,
+    title: "Installing Python".try_into().unwrap(),
+    description: "".try_into().unwrap(),
+    position: 0,
+});
+
+pub static PYTHON_LECTURE_2: LazyLock<Lecture> = LazyLock::new(|| Lecture {
+    id: uuid!("7f2b4d86-e9a1-4c35-b7d0-3e8c5a1f6b24").into(),
+    section_id: PYTHON_SECTION_1.id,
+    title: "Hello World".try_into().unwrap(),
+    description: "Your first Python program".try_into().unwrap(),
+    position: 1,
+});
+
+pub static PYTHON_LECTURE_3: LazyLock<Lecture> = LazyLock::new(|| Lecture {
+    id: uuid!("a9d3c6e2-4b7f-4180-9c5a-d2e6f0b8a419").into(),
+    section_id: PYTHON_SECTION_2.id,
+    title: "Defining Functions".try_into().unwrap(),
+    description: "".try_into().unwrap(),
+    position: 0,
+});
+
+pub static ADVANCED_PYTHON_LECTURE: LazyLock<Lecture> = LazyLock::new(|| Lecture {
+    id: uuid!("d5f9b1a4-6c2e-4a87-b3d9-8e1f4c7a2b60").into(),
+    section_id: ADVANCED_PYTHON_SECTION.id,
+    title: "The yield Keyword".try_into().unwrap(),
+    description: "".try_into().unwrap(),
+    position: 0,
+});
+
+pub async fn create<Txn: Send + Sync + 'static>(
+    txn: &mut Txn,
+    repo: impl SkillRepository<Txn>,
+) -> anyhow::Result<()> {
+    for &skill in &*ALL_SKILLS {
+        repo.create_skill(txn, skill).await?;
+    }
+    for &prerequisite in &*ALL_PREREQUISITES {
+        repo.add_prerequisite(txn, prerequisite).await?;
+    }
+    for &course in &*ALL_COURSES {
+        repo.create_course(txn, course).await?;
+    }
+    for &section in &*ALL_SECTIONS {
+        repo.create_section(txn, section).await?;
+    }
+    for &lecture in &*ALL_LECTURES {
+        repo.create_lecture(txn, lecture).await?;
+    }
+    for &lecture in &*FOO_COMPLETED_LECTURES {
+        repo.complete_lecture(
+            txn,
+            FOO.user.id,
+            lecture.id,
+            FOO.user.created_at + Duration::from_secs(24 * 3600),
+        )
+        .await?;
+    }
+    Ok(())
+}
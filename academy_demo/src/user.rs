@@ -1,7 +1,11 @@
 use std::sync::LazyLock;
 
-use academy_models::user::{
-    User, UserComposite, UserDetails, UserInvoiceInfo, UserPassword, UserProfile,
+use academy_models::{
+    country::Country,
+    user::{
+        User, UserComposite, UserDetails, UserInvoiceInfo, UserPassword, UserProfile,
+        UserVatIdStatus, UserVatIdValidation,
+    },
 };
 use academy_persistence_contracts::user::UserRepository;
 use argon2::{
@@ -26,11 +30,13 @@ pub static ADMIN: LazyLock<UserComposite> = LazyLock::new(|| UserComposite {
         enabled: true,
         admin: true,
         newsletter: false,
+        locale: "en".try_into().unwrap(),
     },
     profile: UserProfile {
         display_name: "Administrator".try_into().unwrap(),
         bio: Default::default(),
         tags: Default::default(),
+        avatar_url: None,
     },
     details: UserDetails {
         mfa_enabled: false,
@@ -55,11 +61,13 @@ pub static ADMIN2: LazyLock<UserComposite> = LazyLock::new(|| UserComposite {
         enabled: true,
         admin: true,
         newsletter: true,
+        locale: "de".try_into().unwrap(),
     },
     profile: UserProfile {
         display_name: "Administrator2".try_into().unwrap(),
         bio: Default::default(),
         tags: Default::default(),
+        avatar_url: None,
     },
     details: UserDetails {
         mfa_enabled: true,
@@ -84,6 +92,7 @@ pub static FOO: LazyLock<UserComposite> = LazyLock::new(|| UserComposite {
         enabled: true,
         admin: false,
         newsletter: true,
+        locale: "de".try_into().unwrap(),
     },
     profile: UserProfile {
         display_name: "Foo 42".try_into().unwrap(),
@@ -95,6 +104,7 @@ pub static FOO: LazyLock<UserComposite> = LazyLock::new(|| UserComposite {
             .unwrap()
             .try_into()
             .unwrap(),
+        avatar_url: None,
     },
     details: UserDetails {
         mfa_enabled: false,
@@ -106,10 +116,15 @@ pub static FOO: LazyLock<UserComposite> = LazyLock::new(|| UserComposite {
         first_name: Some("x".try_into().unwrap()),
         last_name: Some("y".try_into().unwrap()),
         street: Some("asdf".try_into().unwrap()),
-        zip_code: Some("1234".try_into().unwrap()),
+        zip_code: Some("12345".try_into().unwrap()),
         city: Some("xyz".try_into().unwrap()),
-        country: Some("asdf".try_into().unwrap()),
-        vat_id: Some("1234".try_into().unwrap()),
+        country: Some(Country::DE),
+        vat_id: Some("DE0123456789".try_into().unwrap()),
+        vat_id_validation: Some(UserVatIdValidation {
+            status: UserVatIdStatus::Valid,
+            consultation_number: Some("WAPIAAAAZ0123456".into()),
+            validated_at: Utc.with_ymd_and_hms(2024, 3, 15, 13, 40, 0).unwrap(),
+        }),
     },
 });
 
@@ -128,6 +143,7 @@ pub static BAR: LazyLock<UserComposite> = LazyLock::new(|| UserComposite {
         enabled: false,
         admin: false,
         newsletter: false,
+        locale: "en".try_into().unwrap(),
     },
     profile: UserProfile {
         display_name: "Bar".try_into().unwrap(),
@@ -139,6 +155,7 @@ pub static BAR: LazyLock<UserComposite> = LazyLock::new(|| UserComposite {
             .unwrap()
             .try_into()
             .unwrap(),
+        avatar_url: None,
     },
     details: UserDetails {
         mfa_enabled: false,
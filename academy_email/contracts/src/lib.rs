@@ -1,7 +1,8 @@
 use std::future::Future;
 
-use academy_models::email_address::EmailAddressWithName;
+pub use academy_models::email::{Email, EmailBody, EmailHeader, EmailInlineImage};
 
+pub mod outbox;
 pub mod template;
 
 #[cfg_attr(feature = "mock", mockall::automock)]
@@ -13,21 +14,6 @@ pub trait EmailService: Send + Sync + 'static {
     fn ping(&self) -> impl Future<Output = anyhow::Result<()>> + Send;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Email {
-    pub recipient: EmailAddressWithName,
-    pub subject: String,
-    pub body: String,
-    pub content_type: ContentType,
-    pub reply_to: Option<EmailAddressWithName>,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ContentType {
-    Text,
-    Html,
-}
-
 #[cfg(feature = "mock")]
 impl MockEmailService {
     pub fn with_send(mut self, email: Email, result: bool) -> Self {
@@ -0,0 +1,62 @@
+use std::future::Future;
+
+use academy_models::{email::Email, email_outbox::EmailOutboxId};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait EmailOutboxService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Queue the given [`Email`] for delivery.
+    ///
+    /// The email is only delivered once the transaction has been committed.
+    fn enqueue(
+        &self,
+        txn: &mut Txn,
+        email: Email,
+    ) -> impl Future<Output = anyhow::Result<EmailOutboxId>> + Send;
+
+    /// Try to deliver a batch of pending emails whose next delivery attempt is
+    /// due.
+    ///
+    /// The delivery status of each email is committed in a separate
+    /// transaction right after the email has been sent, so an interrupted
+    /// batch can be resumed without sending any email twice. Failed
+    /// deliveries are retried with exponential backoff until the maximum
+    /// number of attempts has been reached.
+    fn deliver_batch(
+        &self,
+    ) -> impl Future<Output = anyhow::Result<EmailOutboxDeliveryResult>> + Send;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EmailOutboxDeliveryResult {
+    /// Number of emails which have been sent.
+    pub sent: u64,
+    /// Number of emails which could not be sent and will be retried later.
+    pub retried: u64,
+    /// Number of emails which could not be sent and will not be retried.
+    pub failed: u64,
+}
+
+impl EmailOutboxDeliveryResult {
+    /// Return the total number of emails processed in this batch.
+    pub fn total(&self) -> u64 {
+        self.sent + self.retried + self.failed
+    }
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockEmailOutboxService<Txn> {
+    pub fn with_enqueue(mut self, email: Email, result: EmailOutboxId) -> Self {
+        self.expect_enqueue()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(email))
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_deliver_batch(mut self, result: EmailOutboxDeliveryResult) -> Self {
+        self.expect_deliver_batch()
+            .once()
+            .return_once(move || Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
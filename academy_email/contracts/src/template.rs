@@ -1,78 +1,360 @@
 use std::future::Future;
 
-use academy_models::email_address::EmailAddressWithName;
+use academy_models::{email_address::EmailAddressWithName, locale::Locale, url::Url};
 use academy_templates_contracts::{
-    ResetPasswordTemplate, SubscribeNewsletterTemplate, VerifyEmailTemplate,
+    AccountDisabledTemplate, EmailChangedTemplate, MfaDisabledTemplate, MfaEnabledTemplate,
+    NewsletterTemplate, NewsletterTextTemplate, OAuth2LinkCreatedTemplate,
+    OAuth2LinkDeletedTemplate, PasswordChangedTemplate, ResetPasswordTemplate,
+    SubscribeNewsletterTemplate, VerifyEmailTemplate,
 };
 
+/// Renders templated emails and queues them in the email outbox.
+///
+/// Emails are rendered in the given locale, falling back to less specific
+/// locales if no translation is available, and contain both an HTML and a
+/// plain text version. Queued emails are only delivered once the transaction
+/// has been committed.
 #[cfg_attr(feature = "mock", mockall::automock)]
-pub trait TemplateEmailService: Send + Sync + 'static {
+pub trait TemplateEmailService<Txn: Send + Sync + 'static>: Send + Sync + 'static {
     fn send_reset_password_email(
         &self,
+        txn: &mut Txn,
         recipient: EmailAddressWithName,
+        locale: &Locale,
         data: &ResetPasswordTemplate,
-    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
 
+    /// Send the newsletter subscription confirmation email including
+    /// `List-Unsubscribe` headers pointing to the given one-click unsubscribe
+    /// url.
     fn send_subscribe_newsletter_email(
         &self,
+        txn: &mut Txn,
         recipient: EmailAddressWithName,
+        locale: &Locale,
         data: &SubscribeNewsletterTemplate,
-    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+        unsubscribe_url: &Url,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
 
     fn send_verification_email(
         &self,
+        txn: &mut Txn,
         recipient: EmailAddressWithName,
+        locale: &Locale,
         data: &VerifyEmailTemplate,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Notify a user that their password has been changed or reset.
+    fn send_password_changed_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &PasswordChangedTemplate,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Notify a user at their previous email address that the email address of
+    /// their account has been changed.
+    fn send_email_changed_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &EmailChangedTemplate,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Notify a user that their account has been disabled by an administrator.
+    fn send_account_disabled_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &AccountDisabledTemplate,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Notify a user that MFA has been enabled for their account.
+    fn send_mfa_enabled_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &MfaEnabledTemplate,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Notify a user that MFA has been disabled for their account.
+    fn send_mfa_disabled_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &MfaDisabledTemplate,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Notify a user that an OAuth2 link has been added to their account.
+    fn send_oauth2_link_created_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &OAuth2LinkCreatedTemplate,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Notify a user that an OAuth2 link has been removed from their account.
+    fn send_oauth2_link_deleted_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &OAuth2LinkDeletedTemplate,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Send a newsletter campaign email including `List-Unsubscribe` headers
+    /// pointing to the given one-click unsubscribe url.
+    ///
+    /// The plain text version is rendered from the given text template instead
+    /// of being generated from the HTML version.
+    ///
+    /// Unlike the other emails, newsletter emails bypass the outbox and are
+    /// sent immediately, as campaigns are already delivered in throttled and
+    /// resumable batches.
+    fn send_newsletter_email(
+        &self,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &NewsletterTemplate,
+        text_data: &NewsletterTextTemplate,
+        unsubscribe_url: &Url,
     ) -> impl Future<Output = anyhow::Result<bool>> + Send;
 }
 
 #[cfg(feature = "mock")]
-impl MockTemplateEmailService {
+impl<Txn: Send + Sync + 'static> MockTemplateEmailService<Txn> {
     pub fn with_send_reset_password_email(
         mut self,
         recipient: EmailAddressWithName,
+        locale: Locale,
         data: ResetPasswordTemplate,
-        result: bool,
     ) -> Self {
         self.expect_send_reset_password_email()
             .once()
             .with(
+                mockall::predicate::always(),
                 mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
                 mockall::predicate::eq(data),
             )
-            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
         self
     }
 
     pub fn with_send_subscribe_newsletter_email(
         mut self,
         recipient: EmailAddressWithName,
+        locale: Locale,
         data: SubscribeNewsletterTemplate,
-        result: bool,
+        unsubscribe_url: Url,
     ) -> Self {
         self.expect_send_subscribe_newsletter_email()
             .once()
             .with(
+                mockall::predicate::always(),
                 mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
                 mockall::predicate::eq(data),
+                mockall::predicate::eq(unsubscribe_url),
             )
-            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+            .return_once(|_, _, _, _, _| Box::pin(std::future::ready(Ok(()))));
         self
     }
 
     pub fn with_send_verification_email(
         mut self,
         recipient: EmailAddressWithName,
+        locale: Locale,
         data: VerifyEmailTemplate,
-        result: bool,
     ) -> Self {
         self.expect_send_verification_email()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_send_password_changed_email(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: PasswordChangedTemplate,
+    ) -> Self {
+        self.expect_send_password_changed_email()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_send_email_changed_email(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: EmailChangedTemplate,
+    ) -> Self {
+        self.expect_send_email_changed_email()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_send_account_disabled_email(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: AccountDisabledTemplate,
+    ) -> Self {
+        self.expect_send_account_disabled_email()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_send_mfa_enabled_email(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: MfaEnabledTemplate,
+    ) -> Self {
+        self.expect_send_mfa_enabled_email()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_send_mfa_disabled_email(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: MfaDisabledTemplate,
+    ) -> Self {
+        self.expect_send_mfa_disabled_email()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_send_oauth2_link_created_email(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: OAuth2LinkCreatedTemplate,
+    ) -> Self {
+        self.expect_send_oauth2_link_created_email()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_send_oauth2_link_deleted_email(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: OAuth2LinkDeletedTemplate,
+    ) -> Self {
+        self.expect_send_oauth2_link_deleted_email()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_send_newsletter_email(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: NewsletterTemplate,
+        text_data: NewsletterTextTemplate,
+        unsubscribe_url: Url,
+        result: bool,
+    ) -> Self {
+        self.expect_send_newsletter_email()
+            .once()
+            .with(
+                mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
+                mockall::predicate::eq(data),
+                mockall::predicate::eq(text_data),
+                mockall::predicate::eq(unsubscribe_url),
+            )
+            .return_once(move |_, _, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_send_newsletter_email_error(
+        mut self,
+        recipient: EmailAddressWithName,
+        locale: Locale,
+        data: NewsletterTemplate,
+        text_data: NewsletterTextTemplate,
+        unsubscribe_url: Url,
+    ) -> Self {
+        self.expect_send_newsletter_email()
             .once()
             .with(
                 mockall::predicate::eq(recipient),
+                mockall::predicate::eq(locale),
                 mockall::predicate::eq(data),
+                mockall::predicate::eq(text_data),
+                mockall::predicate::eq(unsubscribe_url),
             )
-            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+            .return_once(|_, _, _, _, _| {
+                Box::pin(std::future::ready(Err(anyhow::anyhow!(
+                    "Failed to connect to the smtp server"
+                ))))
+            });
         self
     }
 }
@@ -1,25 +1,109 @@
-use academy_email_contracts::{ContentType, Email, EmailService};
+use std::{io::Write, path::PathBuf, sync::Arc};
+
+use academy_email_contracts::{Email, EmailBody, EmailInlineImage, EmailService};
 use academy_models::email_address::EmailAddressWithName;
 use academy_utils::{trace_instrument, Apply};
 use anyhow::{anyhow, Context};
 use lettre::{
-    message::{header, MessageBuilder},
-    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{
+        dkim::{
+            DkimCanonicalization, DkimCanonicalizationType, DkimConfig, DkimSigningAlgorithm,
+            DkimSigningKey,
+        },
+        header::{self, HeaderName, HeaderValue},
+        Attachment, MessageBuilder, MultiPart, SinglePart,
+    },
+    AsyncFileTransport, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
 };
 
+pub mod outbox;
 pub mod template;
 
 #[derive(Debug, Clone)]
 pub struct EmailServiceImpl {
     from: EmailAddressWithName,
-    transport: AsyncSmtpTransport<Tokio1Executor>,
+    transport: EmailTransport,
+    dkim: Option<Arc<DkimConfig>>,
+}
+
+#[derive(Debug, Clone)]
+enum EmailTransport {
+    /// Deliver emails to an smtp server.
+    Smtp(AsyncSmtpTransport<Tokio1Executor>),
+    /// Write each email as an `.eml` file into a directory.
+    File(PathBuf),
+    /// Print each email to stdout.
+    Stdout,
 }
 
 impl EmailServiceImpl {
+    /// Create a new email service using the transport selected by the scheme
+    /// of the given url:
+    ///
+    /// - `file:///path/to/dir`: write emails as `.eml` files into the given
+    ///   directory, which is created if it does not exist yet
+    /// - `stdout://`: print emails to stdout
+    /// - anything else is treated as an smtp url
     pub async fn new(url: &str, from: EmailAddressWithName) -> anyhow::Result<Self> {
-        let transport = AsyncSmtpTransport::<Tokio1Executor>::from_url(url)?.build();
+        let transport = if let Some(path) = url.strip_prefix("file://") {
+            std::fs::create_dir_all(path)
+                .with_context(|| format!("Failed to create email directory {path}"))?;
+            EmailTransport::File(path.into())
+        } else if matches!(url, "stdout:" | "stdout://") {
+            EmailTransport::Stdout
+        } else {
+            EmailTransport::Smtp(AsyncSmtpTransport::<Tokio1Executor>::from_url(url)?.build())
+        };
+
+        Ok(Self {
+            from,
+            transport,
+            dkim: None,
+        })
+    }
+
+    /// Sign all outgoing emails using DKIM.
+    ///
+    /// The private key is expected to be either an RSA key in PKCS#1 PEM
+    /// format or a base64 encoded Ed25519 key. Headers and body are
+    /// canonicalized using the `relaxed` algorithm, so the signature survives
+    /// relays that rewrap headers or adjust whitespace.
+    pub fn with_dkim(
+        mut self,
+        selector: String,
+        domain: String,
+        private_key: &str,
+        headers: &[String],
+    ) -> anyhow::Result<Self> {
+        let private_key = private_key.trim();
+        let algorithm = if private_key.starts_with("-----BEGIN") {
+            DkimSigningAlgorithm::Rsa
+        } else {
+            DkimSigningAlgorithm::Ed25519
+        };
+        let private_key = DkimSigningKey::new(private_key, algorithm)
+            .context("Failed to parse DKIM private key")?;
+
+        let headers = headers
+            .iter()
+            .map(|name| {
+                HeaderName::new_from_ascii(name.clone())
+                    .with_context(|| format!("Invalid DKIM signed header name {name}"))
+            })
+            .collect::<anyhow::Result<_>>()?;
 
-        Ok(Self { from, transport })
+        self.dkim = Some(Arc::new(DkimConfig::new(
+            selector,
+            domain,
+            private_key,
+            headers,
+            DkimCanonicalization {
+                header: DkimCanonicalizationType::Relaxed,
+                body: DkimCanonicalizationType::Relaxed,
+            },
+        )));
+
+        Ok(self)
     }
 
     #[cfg(feature = "dummy")]
@@ -33,32 +117,96 @@ impl EmailServiceImpl {
 impl EmailService for EmailServiceImpl {
     #[trace_instrument(skip(self))]
     async fn send(&self, email: Email) -> anyhow::Result<bool> {
-        let message = Message::builder()
+        let builder = Message::builder()
             .from(self.from.0.clone())
             .to(email.recipient.0)
             .apply_map(email.reply_to.map(|x| x.0), MessageBuilder::reply_to)
-            .subject(email.subject)
-            .header(match email.content_type {
-                ContentType::Text => header::ContentType::TEXT_PLAIN,
-                ContentType::Html => header::ContentType::TEXT_HTML,
-            })
-            .body(email.body)
-            .context("Failed to build email message")?;
+            .subject(email.subject);
 
-        self.transport
-            .send(message)
-            .await
-            .map(|response| response.is_positive())
-            .context("Failed to send email")
+        let inline_images = email.inline_images;
+        let mut message = match email.body {
+            EmailBody::Text(text) => builder.header(header::ContentType::TEXT_PLAIN).body(text),
+            EmailBody::Html(html) if inline_images.is_empty() => {
+                builder.header(header::ContentType::TEXT_HTML).body(html)
+            }
+            EmailBody::Html(html) => builder.multipart(html_part(html, inline_images)?),
+            EmailBody::Alternative { text, html } => {
+                let alternative = MultiPart::alternative().singlepart(SinglePart::plain(text));
+                builder.multipart(if inline_images.is_empty() {
+                    alternative.singlepart(SinglePart::html(html))
+                } else {
+                    alternative.multipart(html_part(html, inline_images)?)
+                })
+            }
+        }
+        .context("Failed to build email message")?;
+
+        for custom_header in email.headers {
+            let name = HeaderName::new_from_ascii(custom_header.name)
+                .context("Failed to build email header name")?;
+            message
+                .headers_mut()
+                .insert_raw(HeaderValue::new(name, custom_header.value));
+        }
+
+        if let Some(dkim) = &self.dkim {
+            message.sign(dkim);
+        }
+
+        match &self.transport {
+            EmailTransport::Smtp(transport) => transport
+                .send(message)
+                .await
+                .map(|response| response.is_positive())
+                .context("Failed to send email"),
+            EmailTransport::File(path) => AsyncFileTransport::<Tokio1Executor>::new(path)
+                .send(message)
+                .await
+                .map(|_| true)
+                .context("Failed to write email to file"),
+            EmailTransport::Stdout => {
+                let mut stdout = std::io::stdout().lock();
+                stdout
+                    .write_all(&message.formatted())
+                    .and_then(|_| stdout.write_all(b"\n\n"))
+                    .and_then(|_| stdout.flush())
+                    .map(|_| true)
+                    .context("Failed to write email to stdout")
+            }
+        }
     }
 
     #[trace_instrument(skip(self))]
     async fn ping(&self) -> anyhow::Result<()> {
-        self.transport
-            .test_connection()
-            .await
-            .context("Failed to ping smtp server")?
-            .then_some(())
-            .ok_or_else(|| anyhow!("Failed to ping smtp server"))
+        match &self.transport {
+            EmailTransport::Smtp(transport) => transport
+                .test_connection()
+                .await
+                .context("Failed to ping smtp server")?
+                .then_some(())
+                .ok_or_else(|| anyhow!("Failed to ping smtp server")),
+            EmailTransport::File(path) => path
+                .is_dir()
+                .then_some(())
+                .ok_or_else(|| anyhow!("Email directory {} does not exist", path.display())),
+            EmailTransport::Stdout => Ok(()),
+        }
     }
 }
+
+/// Build a `multipart/related` part containing the HTML body and the images
+/// it references.
+fn html_part(html: String, inline_images: Vec<EmailInlineImage>) -> anyhow::Result<MultiPart> {
+    inline_images.into_iter().try_fold(
+        MultiPart::related().singlepart(SinglePart::html(html)),
+        |multipart, image| {
+            let content_type =
+                header::ContentType::parse(&image.content_type).with_context(|| {
+                    format!("Invalid content type of inline image {}", image.content_id)
+                })?;
+            Ok(multipart.singlepart(
+                Attachment::new_inline(image.content_id).body(image.data, content_type),
+            ))
+        },
+    )
+}
@@ -0,0 +1,335 @@
+use std::time::Duration;
+
+use academy_di::Build;
+use academy_email_contracts::{
+    outbox::{EmailOutboxDeliveryResult, EmailOutboxService},
+    EmailService,
+};
+use academy_models::{
+    email::Email,
+    email_outbox::{EmailOutboxEntry, EmailOutboxId, EmailOutboxStatus},
+};
+use academy_persistence_contracts::{email_outbox::EmailOutboxRepository, Database, Transaction};
+use academy_shared_contracts::{id::IdService, time::TimeService};
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use tracing::{trace, warn};
+
+#[derive(Debug, Clone, Build)]
+#[cfg_attr(test, derive(Default))]
+pub struct EmailOutboxServiceImpl<Db, Id, Time, Email, EmailOutboxRepo> {
+    db: Db,
+    id: Id,
+    time: Time,
+    email: Email,
+    email_outbox_repo: EmailOutboxRepo,
+    config: EmailOutboxServiceConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailOutboxServiceConfig {
+    /// Maximum number of emails to deliver in a single batch.
+    pub batch_size: u64,
+    /// Maximum number of delivery attempts before an email is marked as
+    /// failed.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled after every failed attempt.
+    pub retry_delay: Duration,
+    /// Upper bound for the delay between two delivery attempts.
+    pub max_retry_delay: Duration,
+}
+
+impl<Db, Id, Time, EmailS, EmailOutboxRepo> EmailOutboxService<Db::Transaction>
+    for EmailOutboxServiceImpl<Db, Id, Time, EmailS, EmailOutboxRepo>
+where
+    Db: Database,
+    Id: IdService,
+    Time: TimeService,
+    EmailS: EmailService,
+    EmailOutboxRepo: EmailOutboxRepository<Db::Transaction>,
+{
+    #[trace_instrument(skip(self, txn))]
+    async fn enqueue(
+        &self,
+        txn: &mut Db::Transaction,
+        email: Email,
+    ) -> anyhow::Result<EmailOutboxId> {
+        let now = self.time.now();
+        let entry = EmailOutboxEntry {
+            id: self.id.generate(),
+            email,
+            status: EmailOutboxStatus::Pending,
+            attempts: 0,
+            created_at: now,
+            next_attempt_at: Some(now),
+            sent_at: None,
+            last_error: None,
+        };
+
+        self.email_outbox_repo
+            .create(txn, &entry)
+            .await
+            .context("Failed to create outbox email in database")?;
+
+        Ok(entry.id)
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn deliver_batch(&self) -> anyhow::Result<EmailOutboxDeliveryResult> {
+        let now = self.time.now();
+        let mut result = EmailOutboxDeliveryResult::default();
+
+        for _ in 0..self.config.batch_size {
+            // Each email is locked, sent and updated in a separate transaction, so that other
+            // workers skip the email while it is being sent and the status of emails which have
+            // already been sent is persisted immediately.
+            let mut txn = self.db.begin_transaction().await?;
+
+            let Some(entry) = self
+                .email_outbox_repo
+                .lock_due(&mut txn, now, 1)
+                .await
+                .context("Failed to get due email from database")?
+                .pop()
+            else {
+                break;
+            };
+            trace!(id = %*entry.id, "deliver email");
+
+            let error = match self.email.send(entry.email).await {
+                Ok(true) => None,
+                Ok(false) => Some("The smtp server returned a negative response".into()),
+                Err(err) => Some(format!("{err:#}")),
+            };
+
+            if let Some(error) = error {
+                let attempts = entry.attempts + 1;
+                let next_attempt_at = (attempts < self.config.max_attempts)
+                    .then(|| now + self.retry_delay(entry.attempts));
+                warn!(id = %*entry.id, attempts, ?next_attempt_at, error, "Failed to deliver email");
+
+                self.email_outbox_repo
+                    .record_failed_attempt(&mut txn, entry.id, &error, next_attempt_at)
+                    .await
+                    .context("Failed to record failed delivery attempt in database")?;
+
+                if next_attempt_at.is_some() {
+                    result.retried += 1;
+                } else {
+                    result.failed += 1;
+                }
+            } else {
+                self.email_outbox_repo
+                    .mark_sent(&mut txn, entry.id, now)
+                    .await
+                    .context("Failed to mark email as sent in database")?;
+                result.sent += 1;
+            }
+
+            txn.commit().await?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl<Db, Id, Time, EmailS, EmailOutboxRepo>
+    EmailOutboxServiceImpl<Db, Id, Time, EmailS, EmailOutboxRepo>
+{
+    fn retry_delay(&self, previous_attempts: u32) -> Duration {
+        self.config
+            .retry_delay
+            .checked_mul(2u32.saturating_pow(previous_attempts))
+            .unwrap_or(Duration::MAX)
+            .min(self.config.max_retry_delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_demo::UUID1;
+    use academy_email_contracts::{EmailBody, MockEmailService};
+    use academy_persistence_contracts::{
+        email_outbox::MockEmailOutboxRepository, MockDatabase, MockTransaction,
+    };
+    use academy_shared_contracts::{id::MockIdService, time::MockTimeService};
+    use academy_utils::assert_matches;
+    use chrono::{DateTime, Utc};
+
+    use super::*;
+
+    type Sut = EmailOutboxServiceImpl<
+        MockDatabase,
+        MockIdService,
+        MockTimeService,
+        MockEmailService,
+        MockEmailOutboxRepository<MockTransaction>,
+    >;
+
+    #[tokio::test]
+    async fn enqueue() {
+        // Arrange
+        let expected = make_entry(UUID1.into(), 0);
+        let now = expected.created_at;
+
+        let id = MockIdService::new().with_generate(expected.id);
+        let time = MockTimeService::new().with_now(now);
+        let email_outbox_repo = MockEmailOutboxRepository::new().with_create(expected.clone());
+
+        let sut = EmailOutboxServiceImpl {
+            id,
+            time,
+            email_outbox_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut
+            .enqueue(&mut MockTransaction::new(), expected.email)
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), expected.id);
+    }
+
+    #[tokio::test]
+    async fn deliver_batch() {
+        // Arrange
+        let sent = make_entry(uuid::Uuid::from_u128(1).into(), 0);
+        let rejected = make_entry(uuid::Uuid::from_u128(2).into(), 2);
+        let failed = make_entry(uuid::Uuid::from_u128(3).into(), 4);
+        let now = sent.created_at + Duration::from_secs(3600);
+
+        let time = MockTimeService::new().with_now(now);
+
+        let email = MockEmailService::new()
+            .with_send(sent.email.clone(), true)
+            .with_send(rejected.email.clone(), false)
+            .with_send(failed.email.clone(), false);
+
+        let email_outbox_repo = MockEmailOutboxRepository::new()
+            .with_lock_due(now, 1, vec![sent.clone()])
+            .with_lock_due(now, 1, vec![rejected.clone()])
+            .with_lock_due(now, 1, vec![failed.clone()])
+            .with_lock_due(now, 1, vec![])
+            .with_mark_sent(sent.id, now)
+            .with_record_failed_attempt(
+                rejected.id,
+                "The smtp server returned a negative response".into(),
+                Some(now + Duration::from_secs(4 * 60)),
+            )
+            .with_record_failed_attempt(
+                failed.id,
+                "The smtp server returned a negative response".into(),
+                None,
+            );
+
+        let db = MockDatabase::build_many(&[true, true, true, false]);
+
+        let sut = EmailOutboxServiceImpl {
+            db,
+            time,
+            email,
+            email_outbox_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.deliver_batch().await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            EmailOutboxDeliveryResult {
+                sent: 1,
+                retried: 1,
+                failed: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_batch_error() {
+        // Arrange
+        let first = make_entry(uuid::Uuid::from_u128(1).into(), 0);
+        let second = make_entry(uuid::Uuid::from_u128(2).into(), 0);
+        let third = make_entry(uuid::Uuid::from_u128(3).into(), 0);
+        let now = first.created_at + Duration::from_secs(3600);
+
+        let time = MockTimeService::new().with_now(now);
+
+        let email = MockEmailService::new()
+            .with_send(first.email.clone(), true)
+            .with_send(second.email.clone(), true)
+            .with_send(third.email.clone(), true);
+
+        let email_outbox_repo = MockEmailOutboxRepository::new()
+            .with_lock_due(now, 1, vec![first.clone()])
+            .with_lock_due(now, 1, vec![second.clone()])
+            .with_lock_due(now, 1, vec![third.clone()])
+            .with_mark_sent(first.id, now)
+            .with_mark_sent(second.id, now)
+            .with_mark_sent_error(third.id, now);
+
+        // The first two emails are committed, while the third email remains
+        // pending and is retried later.
+        let db = MockDatabase::build_many(&[true, true, false]);
+
+        let sut = EmailOutboxServiceImpl {
+            db,
+            time,
+            email,
+            email_outbox_repo,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.deliver_batch().await;
+
+        // Assert
+        assert_matches!(result, Err(_));
+    }
+
+    #[test]
+    fn retry_delay() {
+        let sut = Sut::default();
+        let delays = (0..6).map(|x| sut.retry_delay(x)).collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            [1, 2, 4, 8, 16, 30].map(|x| Duration::from_secs(x * 60))
+        );
+        assert_eq!(sut.retry_delay(u32::MAX), Duration::from_secs(30 * 60));
+    }
+
+    impl Default for EmailOutboxServiceConfig {
+        fn default() -> Self {
+            Self {
+                batch_size: 10,
+                max_attempts: 5,
+                retry_delay: Duration::from_secs(60),
+                max_retry_delay: Duration::from_secs(30 * 60),
+            }
+        }
+    }
+
+    fn make_entry(id: EmailOutboxId, attempts: u32) -> EmailOutboxEntry {
+        let created_at = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        EmailOutboxEntry {
+            id,
+            email: Email {
+                recipient: format!("user{}@example.com", id.as_u128()).parse().unwrap(),
+                subject: "Test".into(),
+                body: EmailBody::Text("Hello World!".into()),
+                reply_to: None,
+                headers: Vec::new(),
+                inline_images: Vec::new(),
+            },
+            status: EmailOutboxStatus::Pending,
+            attempts,
+            created_at,
+            next_attempt_at: Some(created_at),
+            sent_at: None,
+            last_error: None,
+        }
+    }
+}
@@ -1,73 +1,215 @@
+use std::sync::Arc;
+
 use academy_di::Build;
-use academy_email_contracts::{template::TemplateEmailService, ContentType, Email, EmailService};
-use academy_models::email_address::EmailAddressWithName;
+use academy_email_contracts::{
+    outbox::EmailOutboxService, template::TemplateEmailService, Email, EmailBody, EmailInlineImage,
+    EmailService,
+};
+use academy_models::{email_address::EmailAddressWithName, locale::Locale, url::Url};
 use academy_templates_contracts::{
-    ResetPasswordTemplate, SubscribeNewsletterTemplate, Template, TemplateService,
-    VerifyEmailTemplate,
+    AccountDisabledTemplate, EmailChangedTemplate, EmailTemplate, MfaDisabledTemplate,
+    MfaEnabledTemplate, NewsletterTemplate, NewsletterTextTemplate, OAuth2LinkCreatedTemplate,
+    OAuth2LinkDeletedTemplate, PasswordChangedTemplate, ResetPasswordTemplate,
+    SubscribeNewsletterTemplate, TemplateService, VerifyEmailTemplate,
 };
 use academy_utils::trace_instrument;
 
 #[derive(Debug, Clone, Build)]
-pub struct TemplateEmailServiceImpl<Email, Template> {
+pub struct TemplateEmailServiceImpl<Email, Template, EmailOutbox> {
     email: Email,
     template: Template,
+    email_outbox: EmailOutbox,
+    config: TemplateEmailServiceConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct TemplateEmailServiceConfig {
+    /// Images attached to every templated email, e.g. the logo referenced by
+    /// the base template.
+    pub inline_images: Arc<[EmailInlineImage]>,
 }
 
-impl<EmailS, Template> TemplateEmailService for TemplateEmailServiceImpl<EmailS, Template>
+impl<Txn, EmailS, Template, EmailOutbox> TemplateEmailService<Txn>
+    for TemplateEmailServiceImpl<EmailS, Template, EmailOutbox>
 where
+    Txn: Send + Sync + 'static,
     EmailS: EmailService,
     Template: TemplateService,
+    EmailOutbox: EmailOutboxService<Txn>,
 {
-    #[trace_instrument(skip(self))]
+    #[trace_instrument(skip(self, txn))]
     async fn send_reset_password_email(
         &self,
+        txn: &mut Txn,
         recipient: EmailAddressWithName,
+        locale: &Locale,
         data: &ResetPasswordTemplate,
-    ) -> anyhow::Result<bool> {
-        self.send_email(recipient, data, "Passwort zurücksetzen - Bootstrap Academy")
-            .await
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
     }
 
-    #[trace_instrument(skip(self))]
+    #[trace_instrument(skip(self, txn))]
     async fn send_subscribe_newsletter_email(
         &self,
+        txn: &mut Txn,
         recipient: EmailAddressWithName,
+        locale: &Locale,
         data: &SubscribeNewsletterTemplate,
-    ) -> anyhow::Result<bool> {
-        self.send_email(recipient, data, "Newsletter abonnieren - Bootstrap Academy")
-            .await
+        unsubscribe_url: &Url,
+    ) -> anyhow::Result<()> {
+        let email = self
+            .render_localized_email(recipient, locale, data)?
+            .with_list_unsubscribe(unsubscribe_url);
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
     }
 
-    #[trace_instrument(skip(self))]
+    #[trace_instrument(skip(self, txn))]
     async fn send_verification_email(
         &self,
+        txn: &mut Txn,
         recipient: EmailAddressWithName,
+        locale: &Locale,
         data: &VerifyEmailTemplate,
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn send_password_changed_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &PasswordChangedTemplate,
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn send_email_changed_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &EmailChangedTemplate,
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn send_account_disabled_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &AccountDisabledTemplate,
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn send_mfa_enabled_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &MfaEnabledTemplate,
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn send_mfa_disabled_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &MfaDisabledTemplate,
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn send_oauth2_link_created_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &OAuth2LinkCreatedTemplate,
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn send_oauth2_link_deleted_email(
+        &self,
+        txn: &mut Txn,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &OAuth2LinkDeletedTemplate,
+    ) -> anyhow::Result<()> {
+        let email = self.render_localized_email(recipient, locale, data)?;
+        self.email_outbox.enqueue(txn, email).await.map(|_| ())
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn send_newsletter_email(
+        &self,
+        recipient: EmailAddressWithName,
+        locale: &Locale,
+        data: &NewsletterTemplate,
+        text_data: &NewsletterTextTemplate,
+        unsubscribe_url: &Url,
     ) -> anyhow::Result<bool> {
-        self.send_email(recipient, data, "Willkommen bei der Bootstrap Academy!")
-            .await
+        let text = self.template.render(text_data, locale)?;
+        let html = self.template.render(data, locale)?;
+        let email = self
+            .build_email(recipient, &data.subject, text, html)
+            .with_list_unsubscribe(unsubscribe_url);
+        self.email.send(email).await
     }
 }
 
-impl<EmailS, TemplateS> TemplateEmailServiceImpl<EmailS, TemplateS>
+impl<EmailS, TemplateS, EmailOutbox> TemplateEmailServiceImpl<EmailS, TemplateS, EmailOutbox>
 where
-    EmailS: EmailService,
     TemplateS: TemplateService,
 {
-    async fn send_email<T: Template + 'static>(
+    fn render_localized_email<T: EmailTemplate + 'static>(
         &self,
         recipient: EmailAddressWithName,
+        locale: &Locale,
         data: &T,
+    ) -> anyhow::Result<Email> {
+        Ok(self.build_email(
+            recipient,
+            T::subject(locale),
+            self.template.render_text(data, locale)?,
+            self.template.render(data, locale)?,
+        ))
+    }
+
+    fn build_email(
+        &self,
+        recipient: EmailAddressWithName,
         subject: impl Into<String>,
-    ) -> anyhow::Result<bool> {
-        self.email
-            .send(Email {
-                recipient,
-                subject: subject.into(),
-                body: self.template.render(data)?,
-                content_type: ContentType::Html,
-                reply_to: None,
-            })
-            .await
+        text: String,
+        html: String,
+    ) -> Email {
+        Email {
+            recipient,
+            subject: subject.into(),
+            body: EmailBody::Alternative { text, html },
+            reply_to: None,
+            headers: Vec::new(),
+            inline_images: self.config.inline_images.to_vec(),
+        }
     }
 }
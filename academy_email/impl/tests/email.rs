@@ -1,10 +1,13 @@
 use std::time::{Duration, Instant};
 
-use academy_email_contracts::{ContentType, Email, EmailService};
+use academy_email_contracts::{Email, EmailBody, EmailInlineImage, EmailService};
 use academy_email_impl::EmailServiceImpl;
 use academy_models::{email_address::EmailAddressWithName, url::Url};
 use anyhow::Context;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use ed25519_dalek::{Signature, SigningKey, Verifier};
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -16,9 +19,10 @@ async fn send_email() {
         .send(Email {
             recipient: "recipient@example.com".parse().unwrap(),
             subject: "The Subject".into(),
-            body: "<h1>Hello World!</h1>".into(),
-            content_type: ContentType::Html,
+            body: EmailBody::Html("<h1>Hello World!</h1>".into()),
             reply_to: Some("replyto@example.com".parse().unwrap()),
+            headers: Vec::new(),
+            inline_images: Vec::new(),
         })
         .await
         .unwrap();
@@ -43,6 +47,246 @@ async fn send_email() {
     assert_eq!(source, "<h1>Hello World!</h1>");
 }
 
+#[tokio::test]
+async fn send_email_with_list_unsubscribe() {
+    let client = setup().await;
+
+    let result = client
+        .email
+        .send(
+            Email {
+                recipient: "recipient@example.com".parse().unwrap(),
+                subject: "Newsletter".into(),
+                body: EmailBody::Text("Hello World!".into()),
+                reply_to: None,
+                headers: Vec::new(),
+                inline_images: Vec::new(),
+            }
+            .with_list_unsubscribe(&"https://example.com/unsubscribe?token=abc".parse().unwrap()),
+        )
+        .await
+        .unwrap();
+
+    assert!(result);
+
+    let mail = client.wait_for_mail().await;
+    let details = client.fetch_email_details(mail.id).await;
+    let header = |name: &str| {
+        details
+            .headers
+            .iter()
+            .find(|h| h.name == name)
+            .map(|h| h.value.as_str())
+    };
+    assert_eq!(
+        header("List-Unsubscribe"),
+        Some("<https://example.com/unsubscribe?token=abc>")
+    );
+    assert_eq!(
+        header("List-Unsubscribe-Post"),
+        Some("List-Unsubscribe=One-Click")
+    );
+}
+
+#[tokio::test]
+async fn send_multipart_email() {
+    let client = setup().await;
+
+    let result = client
+        .email
+        .send(Email {
+            recipient: "recipient@example.com".parse().unwrap(),
+            subject: "Multipart".into(),
+            body: EmailBody::Alternative {
+                text: "Hello World!".into(),
+                html: r#"<h1>Hello World!</h1><img src="cid:logo" />"#.into(),
+            },
+            reply_to: None,
+            headers: Vec::new(),
+            inline_images: vec![EmailInlineImage {
+                content_id: "logo".into(),
+                content_type: "image/gif".into(),
+                data: b"GIF89a".to_vec(),
+            }],
+        })
+        .await
+        .unwrap();
+
+    assert!(result);
+
+    let mail = client.wait_for_mail().await;
+    let details = client.fetch_email_details(mail.id).await;
+    assert!(details.plain_text);
+    assert!(details.html);
+
+    let raw = client.fetch_email_raw(mail.id).await;
+    assert!(raw.contains("multipart/alternative"));
+    assert!(raw.contains("multipart/related"));
+    assert!(raw.contains("Content-ID: <logo>"));
+}
+
+#[tokio::test]
+async fn send_email_to_file() {
+    let dir = std::env::temp_dir().join(format!("academy-email-{}", Uuid::new_v4()));
+    let email = EmailServiceImpl::new(
+        &format!("file://{}", dir.display()),
+        "sender@example.com".parse().unwrap(),
+    )
+    .await
+    .unwrap();
+
+    email.ping().await.unwrap();
+
+    let result = email
+        .send(Email {
+            recipient: "recipient@example.com".parse().unwrap(),
+            subject: "The Subject".into(),
+            body: EmailBody::Text("Hello World!".into()),
+            reply_to: None,
+            headers: Vec::new(),
+            inline_images: Vec::new(),
+        })
+        .await
+        .unwrap();
+
+    assert!(result);
+
+    let files = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].extension().unwrap(), "eml");
+
+    let content = std::fs::read_to_string(&files[0]).unwrap();
+    assert!(content.contains("Subject: The Subject"));
+    assert!(content.contains("To: recipient@example.com"));
+    assert!(content.contains("Hello World!"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn send_dkim_signed_email_to_file() {
+    let dir = std::env::temp_dir().join(format!("academy-email-{}", Uuid::new_v4()));
+    let signing_key = SigningKey::from_bytes(&[42; 32]);
+    let email = EmailServiceImpl::new(
+        &format!("file://{}", dir.display()),
+        "sender@example.com".parse().unwrap(),
+    )
+    .await
+    .unwrap()
+    .with_dkim(
+        "academy".into(),
+        "example.com".into(),
+        &BASE64_STANDARD.encode(signing_key.to_bytes()),
+        &["From".into(), "To".into(), "Subject".into()],
+    )
+    .unwrap();
+
+    let result = email
+        .send(Email {
+            recipient: "recipient@example.com".parse().unwrap(),
+            subject: "The Subject".into(),
+            body: EmailBody::Text("Hello  World! \r\n\r\n".into()),
+            reply_to: None,
+            headers: Vec::new(),
+            inline_images: Vec::new(),
+        })
+        .await
+        .unwrap();
+
+    assert!(result);
+
+    let file = std::fs::read_dir(&dir)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    let content = std::fs::read_to_string(file).unwrap();
+    std::fs::remove_dir_all(dir).unwrap();
+
+    let (headers, body) = content.split_once("\r\n\r\n").unwrap();
+    let headers = parse_headers(headers);
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+            .unwrap()
+    };
+
+    let signature = header("DKIM-Signature");
+    let tags = signature
+        .split(';')
+        .filter_map(|tag| tag.trim().split_once('='))
+        .collect::<Vec<_>>();
+    let tag = |name: &str| tags.iter().find(|(n, _)| *n == name).unwrap().1;
+    assert_eq!(tag("v"), "1");
+    assert_eq!(tag("a"), "ed25519-sha256");
+    assert_eq!(tag("d"), "example.com");
+    assert_eq!(tag("s"), "academy");
+    assert_eq!(tag("c"), "relaxed/relaxed");
+    assert_eq!(tag("h"), "from:to:subject");
+
+    let body_hash = Sha256::digest(canonicalize_body(body));
+    assert_eq!(tag("bh"), BASE64_STANDARD.encode(body_hash));
+
+    let mut signed = String::new();
+    for name in tag("h").split(':') {
+        signed += &format!("{name}:{}\r\n", header(name));
+    }
+    let unsigned_signature = &signature[..signature.rfind("b=").unwrap() + 2];
+    signed += &format!("dkim-signature:{unsigned_signature}");
+    let signed_hash = Sha256::digest(signed);
+
+    let b = BASE64_STANDARD.decode(tag("b").replace(' ', "")).unwrap();
+    signing_key
+        .verifying_key()
+        .verify(&signed_hash, &Signature::from_slice(&b).unwrap())
+        .unwrap();
+}
+
+/// Parse raw email headers and apply the `relaxed` DKIM canonicalization to
+/// their values.
+fn parse_headers(headers: &str) -> Vec<(String, String)> {
+    let mut out = Vec::<(String, String)>::new();
+    for line in headers.split("\r\n") {
+        if line.starts_with([' ', '\t']) {
+            out.last_mut().unwrap().1.push_str(line);
+        } else {
+            let (name, value) = line.split_once(':').unwrap();
+            out.push((name.into(), value.into()));
+        }
+    }
+
+    out.into_iter()
+        .map(|(name, value)| {
+            let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+            (name, value)
+        })
+        .collect()
+}
+
+/// Apply the `relaxed` DKIM canonicalization to an email body.
+fn canonicalize_body(body: &str) -> String {
+    let mut out = body
+        .split("\r\n")
+        .map(|line| {
+            line.split([' ', '\t'])
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n");
+    while out.ends_with("\r\n\r\n") {
+        out.truncate(out.len() - 2);
+    }
+    out
+}
+
 struct TestClient {
     email: EmailServiceImpl,
     from: EmailAddressWithName,
@@ -118,6 +362,23 @@ impl TestClient {
             .await
             .unwrap()
     }
+
+    async fn fetch_email_raw(&self, id: Uuid) -> String {
+        reqwest::Client::new()
+            .get(
+                self.smtp4dev_url
+                    .join(&format!("api/Messages/{id}/raw"))
+                    .unwrap(),
+            )
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap()
+            .text()
+            .await
+            .unwrap()
+    }
 }
 
 async fn setup() -> TestClient {
@@ -163,6 +424,8 @@ struct EmailDetails {
     headers: Vec<EmailHeader>,
     #[serde(rename = "hasPlainTextBody")]
     plain_text: bool,
+    #[serde(rename = "hasHtmlBody")]
+    html: bool,
 }
 
 #[derive(Debug, Deserialize)]
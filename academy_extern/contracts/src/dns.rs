@@ -0,0 +1,19 @@
+use std::future::Future;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait DnsResolverService: Send + Sync + 'static {
+    /// Return whether the given domain has at least one MX record which
+    /// accepts mail.
+    fn has_mx_records(&self, domain: &str) -> impl Future<Output = anyhow::Result<bool>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl MockDnsResolverService {
+    pub fn with_has_mx_records(mut self, domain: String, result: bool) -> Self {
+        self.expect_has_mx_records()
+            .once()
+            .with(mockall::predicate::eq(domain))
+            .return_once(move |_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
@@ -0,0 +1,36 @@
+use std::future::Future;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait HcaptchaApiService: Send + Sync + 'static {
+    /// Verify the given hCaptcha response.
+    fn siteverify(
+        &self,
+        response: &str,
+        secret: &str,
+    ) -> impl Future<Output = anyhow::Result<HcaptchaSiteverifyResponse>> + Send;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HcaptchaSiteverifyResponse {
+    /// Whether the response is valid.
+    pub success: bool,
+}
+
+#[cfg(feature = "mock")]
+impl MockHcaptchaApiService {
+    pub fn with_siteverify(
+        mut self,
+        response: String,
+        secret: String,
+        result: HcaptchaSiteverifyResponse,
+    ) -> Self {
+        self.expect_siteverify()
+            .once()
+            .with(
+                mockall::predicate::eq(response),
+                mockall::predicate::eq(secret),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
@@ -1,4 +1,7 @@
-pub mod internal;
+pub mod dns;
+pub mod hcaptcha;
 pub mod oauth2;
+pub mod payment;
 pub mod recaptcha;
+pub mod turnstile;
 pub mod vat;
@@ -0,0 +1,89 @@
+use std::future::Future;
+
+use academy_models::{checkout::PaymentId, url::Url};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait PaymentApiService: Send + Sync + 'static {
+    /// Create a checkout session at the payment provider.
+    ///
+    /// The user has to be redirected to the returned url to complete the
+    /// payment. The result of the payment is reported asynchronously via
+    /// webhook.
+    fn create_checkout(
+        &self,
+        request: PaymentCheckoutRequest,
+    ) -> impl Future<Output = anyhow::Result<PaymentCheckout>> + Send;
+
+    /// Verify the signature of a webhook request and parse its payload.
+    ///
+    /// Returns `None` if the signature is invalid.
+    fn parse_webhook(
+        &self,
+        signature: &str,
+        payload: &[u8],
+    ) -> anyhow::Result<Option<PaymentWebhookEvent>>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentCheckoutRequest {
+    /// Our own identifier of the payment
+    pub reference: String,
+    /// The amount to pay in euro cents
+    pub amount: u64,
+    /// Description of the payment which is displayed to the user
+    pub description: String,
+    /// The url to redirect the user to after a successful payment
+    pub success_url: Url,
+    /// The url to redirect the user to if the payment has been cancelled
+    pub cancel_url: Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentCheckout {
+    pub payment_id: PaymentId,
+    pub redirect_url: Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentWebhookEvent {
+    pub payment_id: PaymentId,
+    pub status: PaymentStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentStatus {
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+#[cfg(feature = "mock")]
+impl MockPaymentApiService {
+    pub fn with_create_checkout(
+        mut self,
+        request: PaymentCheckoutRequest,
+        result: PaymentCheckout,
+    ) -> Self {
+        self.expect_create_checkout()
+            .once()
+            .with(mockall::predicate::eq(request))
+            .return_once(move |_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_parse_webhook(
+        mut self,
+        signature: String,
+        payload: Vec<u8>,
+        result: Option<PaymentWebhookEvent>,
+    ) -> Self {
+        self.expect_parse_webhook()
+            .once()
+            .with(
+                mockall::predicate::eq(signature),
+                mockall::predicate::eq(payload),
+            )
+            .return_once(move |_, _| Ok(result));
+        self
+    }
+}
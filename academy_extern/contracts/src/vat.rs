@@ -3,13 +3,28 @@ use std::future::Future;
 #[cfg_attr(feature = "mock", mockall::automock)]
 pub trait VatApiService: Send + Sync + 'static {
     /// Validate the given VAT id.
-    fn is_vat_id_valid(&self, vat_id: &str) -> impl Future<Output = anyhow::Result<bool>> + Send;
+    fn validate_vat_id(
+        &self,
+        vat_id: &str,
+    ) -> impl Future<Output = anyhow::Result<VatIdValidationResult>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VatIdValidationResult {
+    Valid {
+        /// Identifier of the request, only returned if a requester VAT id is
+        /// configured
+        consultation_number: Option<String>,
+    },
+    Invalid,
+    /// VIES or the service of the member state is currently unavailable.
+    Unavailable,
 }
 
 #[cfg(feature = "mock")]
 impl MockVatApiService {
-    pub fn with_is_vat_id_valid(mut self, vat_id: String, result: bool) -> Self {
-        self.expect_is_vat_id_valid()
+    pub fn with_validate_vat_id(mut self, vat_id: String, result: VatIdValidationResult) -> Self {
+        self.expect_validate_vat_id()
             .once()
             .with(mockall::predicate::eq(vat_id))
             .return_once(move |_| Box::pin(std::future::ready(Ok(result))));
@@ -0,0 +1,112 @@
+use std::{net::SocketAddr, time::Duration};
+
+use academy_di::Build;
+use academy_extern_contracts::dns::DnsResolverService;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use hickory_resolver::{
+    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
+    error::ResolveErrorKind,
+    proto::{op::ResponseCode, rr::rdata::MX},
+    TokioAsyncResolver,
+};
+use tracing::{trace, warn};
+
+/// Number of times a query is sent to each nameserver before giving up.
+const ATTEMPTS: usize = 2;
+
+#[derive(Debug, Clone, Build)]
+pub struct DnsResolverServiceImpl {
+    config: DnsResolverServiceConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct DnsResolverServiceConfig {
+    resolver: TokioAsyncResolver,
+}
+
+impl DnsResolverServiceConfig {
+    /// Use the given nameserver or fall back to the nameservers configured in
+    /// `/etc/resolv.conf`.
+    ///
+    /// Queries are sent via UDP and retried via TCP if the response has been
+    /// truncated.
+    pub fn new(nameserver_override: Option<SocketAddr>, timeout: Duration) -> Self {
+        let (config, mut opts) = match nameserver_override {
+            Some(nameserver) => (
+                ResolverConfig::from_parts(
+                    None,
+                    vec![],
+                    NameServerConfigGroup::from_ips_clear(
+                        &[nameserver.ip()],
+                        nameserver.port(),
+                        true,
+                    ),
+                ),
+                ResolverOpts::default(),
+            ),
+            None => hickory_resolver::system_conf::read_system_conf().unwrap_or_else(|err| {
+                warn!("Failed to read system DNS configuration, using defaults: {err}");
+                Default::default()
+            }),
+        };
+
+        opts.timeout = timeout;
+        opts.attempts = ATTEMPTS;
+
+        Self {
+            resolver: TokioAsyncResolver::tokio(config, opts),
+        }
+    }
+}
+
+impl DnsResolverService for DnsResolverServiceImpl {
+    #[trace_instrument(skip(self))]
+    async fn has_mx_records(&self, domain: &str) -> anyhow::Result<bool> {
+        // always treat the domain as fully qualified to skip search domains
+        let fqdn = match domain.ends_with('.') {
+            true => domain.to_owned(),
+            false => format!("{domain}."),
+        };
+
+        let result = match self.config.resolver.mx_lookup(fqdn).await {
+            Ok(lookup) => lookup.iter().any(|mx| !is_null_mx(mx)),
+            Err(err) => match err.kind() {
+                ResolveErrorKind::NoRecordsFound {
+                    response_code: ResponseCode::NoError | ResponseCode::NXDomain,
+                    ..
+                } => false,
+                _ => return Err(err).context("Failed to look up MX records"),
+            },
+        };
+
+        trace!(result, "mx lookup completed");
+
+        Ok(result)
+    }
+}
+
+/// Return whether the given record is a "null MX" record, which indicates that
+/// the domain does not accept email.
+///
+/// https://www.rfc-editor.org/rfc/rfc7505
+fn is_null_mx(mx: &MX) -> bool {
+    mx.preference() == 0 && mx.exchange().is_root()
+}
+
+#[cfg(test)]
+mod tests {
+    use hickory_resolver::Name;
+
+    use super::*;
+
+    #[test]
+    fn null_mx() {
+        assert!(is_null_mx(&MX::new(0, Name::root())));
+        assert!(!is_null_mx(&MX::new(10, Name::root())));
+        assert!(!is_null_mx(&MX::new(
+            0,
+            Name::from_ascii("mail.example.com.").unwrap()
+        )));
+    }
+}
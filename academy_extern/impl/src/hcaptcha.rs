@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use academy_di::Build;
+use academy_extern_contracts::hcaptcha::{HcaptchaApiService, HcaptchaSiteverifyResponse};
+use academy_models::url::Url;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::http::HttpClient;
+
+/// API documentation: https://docs.hcaptcha.com/#verify-the-user-response-server-side
+const SITEVERIFY_ENDPOINT: &str = "https://api.hcaptcha.com/siteverify";
+
+#[derive(Debug, Clone, Build)]
+pub struct HcaptchaApiServiceImpl {
+    config: HcaptchaApiServiceConfig,
+    #[di(default)]
+    client: HttpClient,
+}
+
+#[derive(Debug, Clone)]
+pub struct HcaptchaApiServiceConfig {
+    siteverify_endpoint: Arc<Url>,
+}
+
+impl HcaptchaApiServiceConfig {
+    pub fn new(siteverify_endpoint_override: Option<Url>) -> Self {
+        Self {
+            siteverify_endpoint: siteverify_endpoint_override
+                .unwrap_or_else(|| SITEVERIFY_ENDPOINT.parse().unwrap())
+                .into(),
+        }
+    }
+}
+
+impl HcaptchaApiService for HcaptchaApiServiceImpl {
+    #[trace_instrument(skip(self))]
+    async fn siteverify(
+        &self,
+        response: &str,
+        secret: &str,
+    ) -> anyhow::Result<HcaptchaSiteverifyResponse> {
+        self.client
+            .post((**self.config.siteverify_endpoint).clone())
+            .form(&SiteverifyRequest { response, secret })
+            .send()
+            .await
+            .context("Failed to send siteverify request")?
+            .error_for_status()
+            .context("Siteverify request returned an error")?
+            .json::<SiteverifyResponse>()
+            .await
+            .map(Into::into)
+            .context("Failed to deserialize siteverify response")
+    }
+}
+
+#[derive(Serialize)]
+struct SiteverifyRequest<'a> {
+    response: &'a str,
+    secret: &'a str,
+}
+
+#[derive(Deserialize)]
+struct SiteverifyResponse {
+    success: bool,
+}
+
+impl From<SiteverifyResponse> for HcaptchaSiteverifyResponse {
+    fn from(value: SiteverifyResponse) -> Self {
+        Self {
+            success: value.success,
+        }
+    }
+}
@@ -1,5 +1,8 @@
+pub mod dns;
+pub mod hcaptcha;
 mod http;
-pub mod internal;
 pub mod oauth2;
+pub mod payment;
 pub mod recaptcha;
+pub mod turnstile;
 pub mod vat;
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use academy_di::Build;
+use academy_extern_contracts::payment::{
+    PaymentApiService, PaymentCheckout, PaymentCheckoutRequest, PaymentStatus, PaymentWebhookEvent,
+};
+use academy_models::url::Url;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tracing::trace;
+
+use crate::http::HttpClient;
+
+const CURRENCY: &str = "EUR";
+
+#[derive(Debug, Clone, Build)]
+pub struct PaymentApiServiceImpl {
+    config: PaymentApiServiceConfig,
+    #[di(default)]
+    http: HttpClient,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentApiServiceConfig {
+    pub api_url: Arc<Url>,
+    pub secret_key: Arc<str>,
+    pub webhook_secret: Arc<str>,
+}
+
+impl PaymentApiService for PaymentApiServiceImpl {
+    #[trace_instrument(skip(self))]
+    async fn create_checkout(
+        &self,
+        request: PaymentCheckoutRequest,
+    ) -> anyhow::Result<PaymentCheckout> {
+        let url = self
+            .config
+            .api_url
+            .join("checkouts")
+            .context("Failed to build create checkout URL")?;
+
+        let response = self
+            .http
+            .post(url)
+            .bearer_auth(&*self.config.secret_key)
+            .json(&CreateCheckoutRequest {
+                reference: &request.reference,
+                amount: request.amount,
+                currency: CURRENCY,
+                description: &request.description,
+                success_url: &request.success_url,
+                cancel_url: &request.cancel_url,
+            })
+            .send()
+            .await
+            .context("Failed to send create checkout request")?
+            .error_for_status()
+            .context("Create checkout request returned an error")?
+            .json::<CreateCheckoutResponse>()
+            .await
+            .context("Failed to deserialize create checkout response")?;
+
+        Ok(PaymentCheckout {
+            payment_id: response
+                .id
+                .try_into()
+                .context("Invalid checkout id returned by payment provider")?,
+            redirect_url: response.url,
+        })
+    }
+
+    #[trace_instrument(skip(self, payload))]
+    fn parse_webhook(
+        &self,
+        signature: &str,
+        payload: &[u8],
+    ) -> anyhow::Result<Option<PaymentWebhookEvent>> {
+        let Ok(signature) = hex::decode(signature.trim()) else {
+            trace!("malformed signature");
+            return Ok(None);
+        };
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.config.webhook_secret.as_bytes())
+            .context("Failed to initialize hmac")?;
+        mac.update(payload);
+        if mac.verify_slice(&signature).is_err() {
+            trace!("invalid signature");
+            return Ok(None);
+        }
+
+        let event = serde_json::from_slice::<WebhookEvent>(payload)
+            .context("Failed to deserialize webhook event")?;
+
+        Ok(Some(PaymentWebhookEvent {
+            payment_id: event
+                .checkout_id
+                .try_into()
+                .context("Invalid checkout id in webhook event")?,
+            status: event.status.into(),
+        }))
+    }
+}
+
+#[derive(Serialize)]
+struct CreateCheckoutRequest<'a> {
+    reference: &'a str,
+    amount: u64,
+    currency: &'a str,
+    description: &'a str,
+    success_url: &'a Url,
+    cancel_url: &'a Url,
+}
+
+#[derive(Deserialize)]
+struct CreateCheckoutResponse {
+    id: String,
+    url: Url,
+}
+
+#[derive(Deserialize)]
+struct WebhookEvent {
+    checkout_id: String,
+    status: WebhookEventStatus,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum WebhookEventStatus {
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl From<WebhookEventStatus> for PaymentStatus {
+    fn from(value: WebhookEventStatus) -> Self {
+        match value {
+            WebhookEventStatus::Succeeded => Self::Succeeded,
+            WebhookEventStatus::Failed => Self::Failed,
+            WebhookEventStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use academy_di::Build;
+use academy_extern_contracts::turnstile::{TurnstileApiService, TurnstileSiteverifyResponse};
+use academy_models::url::Url;
+use academy_utils::trace_instrument;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::http::HttpClient;
+
+/// API documentation: https://developers.cloudflare.com/turnstile/get-started/server-side-validation/
+const SITEVERIFY_ENDPOINT: &str = "https://challenges.cloudflare.com/turnstile/v0/siteverify";
+
+#[derive(Debug, Clone, Build)]
+pub struct TurnstileApiServiceImpl {
+    config: TurnstileApiServiceConfig,
+    #[di(default)]
+    client: HttpClient,
+}
+
+#[derive(Debug, Clone)]
+pub struct TurnstileApiServiceConfig {
+    siteverify_endpoint: Arc<Url>,
+}
+
+impl TurnstileApiServiceConfig {
+    pub fn new(siteverify_endpoint_override: Option<Url>) -> Self {
+        Self {
+            siteverify_endpoint: siteverify_endpoint_override
+                .unwrap_or_else(|| SITEVERIFY_ENDPOINT.parse().unwrap())
+                .into(),
+        }
+    }
+}
+
+impl TurnstileApiService for TurnstileApiServiceImpl {
+    #[trace_instrument(skip(self))]
+    async fn siteverify(
+        &self,
+        response: &str,
+        secret: &str,
+    ) -> anyhow::Result<TurnstileSiteverifyResponse> {
+        self.client
+            .post((**self.config.siteverify_endpoint).clone())
+            .form(&SiteverifyRequest { response, secret })
+            .send()
+            .await
+            .context("Failed to send siteverify request")?
+            .error_for_status()
+            .context("Siteverify request returned an error")?
+            .json::<SiteverifyResponse>()
+            .await
+            .map(Into::into)
+            .context("Failed to deserialize siteverify response")
+    }
+}
+
+#[derive(Serialize)]
+struct SiteverifyRequest<'a> {
+    response: &'a str,
+    secret: &'a str,
+}
+
+#[derive(Deserialize)]
+struct SiteverifyResponse {
+    success: bool,
+}
+
+impl From<SiteverifyResponse> for TurnstileSiteverifyResponse {
+    fn from(value: SiteverifyResponse) -> Self {
+        Self {
+            success: value.success,
+        }
+    }
+}
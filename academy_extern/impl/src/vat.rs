@@ -1,13 +1,13 @@
 use std::sync::{Arc, LazyLock};
 
 use academy_di::Build;
-use academy_extern_contracts::vat::VatApiService;
+use academy_extern_contracts::vat::{VatApiService, VatIdValidationResult};
 use academy_models::url::Url;
 use academy_utils::trace_instrument;
 use anyhow::Context;
 use regex::Regex;
 use serde::Deserialize;
-use tracing::trace;
+use tracing::{trace, warn};
 
 use crate::http::HttpClient;
 
@@ -25,51 +25,109 @@ pub struct VatApiServiceImpl {
 #[derive(Debug, Clone)]
 pub struct VatApiServiceConfig {
     validate_endpoint: Arc<Url>,
+    requester: Option<Arc<(String, String)>>,
 }
 
 impl VatApiServiceConfig {
-    pub fn new(validate_endpoint_override: Option<Url>) -> Self {
-        Self {
+    pub fn new(
+        validate_endpoint_override: Option<Url>,
+        requester_vat_id: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        let requester = requester_vat_id
+            .map(|vat_id| {
+                let captures = VAT_ID_REGEX
+                    .captures(vat_id)
+                    .with_context(|| format!("Invalid requester vat id: {vat_id}"))?;
+                anyhow::Ok(Arc::new((captures[1].into(), captures[2].into())))
+            })
+            .transpose()?;
+
+        Ok(Self {
             validate_endpoint: validate_endpoint_override
                 .unwrap_or_else(|| VALIDATE_ENDPOINT.parse().unwrap())
                 .into(),
-        }
+            requester,
+        })
     }
 }
 
 static VAT_ID_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new("^([A-Z]{2}) *([0-9A-Z]+)$").unwrap());
 
+/// Errors reported by VIES if the service of a member state is temporarily
+/// unavailable.
+const UNAVAILABLE_ERRORS: &[&str] = &[
+    "SERVICE_UNAVAILABLE",
+    "MS_UNAVAILABLE",
+    "TIMEOUT",
+    "GLOBAL_MAX_CONCURRENT_REQ",
+    "MS_MAX_CONCURRENT_REQ",
+];
+
 impl VatApiService for VatApiServiceImpl {
     #[trace_instrument(skip(self))]
-    async fn is_vat_id_valid(&self, vat_id: &str) -> anyhow::Result<bool> {
+    async fn validate_vat_id(&self, vat_id: &str) -> anyhow::Result<VatIdValidationResult> {
         let Some(captures) = VAT_ID_REGEX.captures(vat_id) else {
             trace!("regex mismatch");
-            return Ok(false);
+            return Ok(VatIdValidationResult::Invalid);
         };
 
-        let url = self
+        let mut url = self
             .config
             .validate_endpoint
             .join(&format!("{}/vat/{}", &captures[1], &captures[2]))
             .context("Failed to build vat validate URL")?;
+        if let Some(requester) = &self.config.requester {
+            url.query_pairs_mut()
+                .append_pair("requesterMemberStateCode", &requester.0)
+                .append_pair("requesterNumber", &requester.1);
+        }
 
-        self.http
-            .get(url)
-            .send()
-            .await
-            .context("Failed to send vat validate request")?
+        let response = match self.http.get(url).send().await {
+            Ok(response) if response.status().is_server_error() => {
+                warn!(status = %response.status(), "vat validate request failed");
+                return Ok(VatIdValidationResult::Unavailable);
+            }
+            Ok(response) => response,
+            Err(err) if err.is_connect() || err.is_timeout() => {
+                warn!(%err, "failed to reach vat validate endpoint");
+                return Ok(VatIdValidationResult::Unavailable);
+            }
+            Err(err) => return Err(err).context("Failed to send vat validate request"),
+        };
+
+        let response = response
             .error_for_status()
             .context("Vat validate request returned an error")?
-            .json::<IsVatIdValidResponse>()
+            .json::<ValidateVatIdResponse>()
             .await
-            .map(|x| x.is_valid)
-            .context("Failed to deserialize vat validate response")
+            .context("Failed to deserialize vat validate response")?;
+
+        Ok(
+            if response
+                .user_error
+                .as_deref()
+                .is_some_and(|err| UNAVAILABLE_ERRORS.contains(&err))
+            {
+                trace!(user_error = response.user_error, "vies unavailable");
+                VatIdValidationResult::Unavailable
+            } else if response.is_valid {
+                VatIdValidationResult::Valid {
+                    consultation_number: response.request_identifier.filter(|x| !x.is_empty()),
+                }
+            } else {
+                VatIdValidationResult::Invalid
+            },
+        )
     }
 }
 
 #[derive(Deserialize)]
-struct IsVatIdValidResponse {
+struct ValidateVatIdResponse {
     #[serde(rename = "isValid")]
     is_valid: bool,
+    #[serde(rename = "userError")]
+    user_error: Option<String>,
+    #[serde(rename = "requestIdentifier")]
+    request_identifier: Option<String>,
 }
@@ -0,0 +1,42 @@
+use academy_di::{provider, Provide};
+use academy_extern_contracts::dns::DnsResolverService;
+use academy_extern_impl::dns::{DnsResolverServiceConfig, DnsResolverServiceImpl};
+
+#[tokio::test]
+async fn has_mx_records() {
+    let sut = make_sut();
+    let result = sut.has_mx_records("bootstrap.academy").await.unwrap();
+    assert!(result);
+}
+
+#[tokio::test]
+async fn no_mx_records() {
+    let sut = make_sut();
+    let result = sut.has_mx_records("no-mx.bootstrap.academy").await.unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn nx_domain() {
+    let sut = make_sut();
+    let result = sut.has_mx_records("bootstrap.invalid").await.unwrap();
+    assert!(!result);
+}
+
+fn make_sut() -> DnsResolverServiceImpl {
+    let config = academy_config::load().unwrap();
+
+    provider! {
+        Provider { dns_resolver_service_config: DnsResolverServiceConfig, }
+    }
+
+    let mut provider = Provider {
+        _cache: Default::default(),
+        dns_resolver_service_config: DnsResolverServiceConfig::new(
+            config.dns.nameserver_override,
+            config.dns.timeout.into(),
+        ),
+    };
+
+    provider.provide()
+}
@@ -0,0 +1,46 @@
+use academy_config::HcaptchaConfig;
+use academy_di::{provider, Provide};
+use academy_extern_contracts::hcaptcha::{HcaptchaApiService, HcaptchaSiteverifyResponse};
+use academy_extern_impl::hcaptcha::{HcaptchaApiServiceConfig, HcaptchaApiServiceImpl};
+
+#[tokio::test]
+async fn success() {
+    let (sut, secret) = make_sut();
+    let result = sut.siteverify("success", &secret).await.unwrap();
+    assert_eq!(result, HcaptchaSiteverifyResponse { success: true });
+}
+
+#[tokio::test]
+async fn failure() {
+    let (sut, secret) = make_sut();
+    let result = sut.siteverify("failure", &secret).await.unwrap();
+    assert_eq!(result, HcaptchaSiteverifyResponse { success: false });
+}
+
+#[tokio::test]
+async fn invalid_secret() {
+    let (sut, _) = make_sut();
+    let result = sut.siteverify("success", "invalid").await.unwrap();
+    assert_eq!(result, HcaptchaSiteverifyResponse { success: false });
+}
+
+fn make_sut() -> (HcaptchaApiServiceImpl, String) {
+    let config = academy_config::load_with_overrides(&["hcaptcha.enable = true"]).unwrap();
+
+    let HcaptchaConfig {
+        siteverify_endpoint_override,
+        secret,
+        ..
+    } = config.hcaptcha.unwrap();
+
+    provider! {
+        Provider { hcaptcha_api_service_config: HcaptchaApiServiceConfig, }
+    }
+
+    let mut provider = Provider {
+        _cache: Default::default(),
+        hcaptcha_api_service_config: HcaptchaApiServiceConfig::new(siteverify_endpoint_override),
+    };
+
+    (provider.provide(), secret)
+}
@@ -0,0 +1,91 @@
+use academy_di::{provider, Provide};
+use academy_extern_contracts::payment::{
+    PaymentApiService, PaymentCheckoutRequest, PaymentStatus, PaymentWebhookEvent,
+};
+use academy_extern_impl::payment::{PaymentApiServiceConfig, PaymentApiServiceImpl};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+#[tokio::test]
+async fn create_checkout() {
+    let sut = make_sut();
+    let result = sut
+        .create_checkout(PaymentCheckoutRequest {
+            reference: "order".into(),
+            amount: 950,
+            description: "1000 Coins".into(),
+            success_url: "https://bootstrap.academy/shop/orders/order"
+                .parse()
+                .unwrap(),
+            cancel_url: "https://bootstrap.academy/shop?cancelled=order"
+                .parse()
+                .unwrap(),
+        })
+        .await
+        .unwrap();
+    assert!(result.payment_id.starts_with("cs_"));
+    assert_eq!(
+        result.redirect_url.as_str(),
+        format!(
+            "http://127.0.0.1:8004/payment/checkout/{}",
+            *result.payment_id
+        )
+    );
+}
+
+#[test]
+fn parse_webhook() {
+    let sut = make_sut();
+    let payload = br#"{"checkout_id":"cs_42","status":"succeeded"}"#;
+    let result = sut.parse_webhook(&sign(payload), payload).unwrap();
+    assert_eq!(
+        result,
+        Some(PaymentWebhookEvent {
+            payment_id: "cs_42".try_into().unwrap(),
+            status: PaymentStatus::Succeeded,
+        })
+    );
+}
+
+#[test]
+fn parse_webhook_invalid_signature() {
+    let sut = make_sut();
+    let payload = br#"{"checkout_id":"cs_42","status":"succeeded"}"#;
+    let signature = sign(br#"{"checkout_id":"cs_42","status":"failed"}"#);
+    let result = sut.parse_webhook(&signature, payload).unwrap();
+    assert_eq!(result, None);
+}
+
+#[test]
+fn parse_webhook_malformed_signature() {
+    let sut = make_sut();
+    let payload = br#"{"checkout_id":"cs_42","status":"succeeded"}"#;
+    let result = sut.parse_webhook("foobar", payload).unwrap();
+    assert_eq!(result, None);
+}
+
+fn sign(payload: &[u8]) -> String {
+    let config = academy_config::load().unwrap();
+    let mut mac = Hmac::<Sha256>::new_from_slice(config.payment.webhook_secret.as_bytes()).unwrap();
+    mac.update(payload);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+fn make_sut() -> PaymentApiServiceImpl {
+    let config = academy_config::load().unwrap();
+
+    provider! {
+        Provider { payment_api_service_config: PaymentApiServiceConfig, }
+    }
+
+    let mut provider = Provider {
+        _cache: Default::default(),
+        payment_api_service_config: PaymentApiServiceConfig {
+            api_url: config.payment.api_url.into(),
+            secret_key: config.payment.secret_key.into(),
+            webhook_secret: config.payment.webhook_secret.into(),
+        },
+    };
+
+    provider.provide()
+}
@@ -0,0 +1,46 @@
+use academy_config::TurnstileConfig;
+use academy_di::{provider, Provide};
+use academy_extern_contracts::turnstile::{TurnstileApiService, TurnstileSiteverifyResponse};
+use academy_extern_impl::turnstile::{TurnstileApiServiceConfig, TurnstileApiServiceImpl};
+
+#[tokio::test]
+async fn success() {
+    let (sut, secret) = make_sut();
+    let result = sut.siteverify("success", &secret).await.unwrap();
+    assert_eq!(result, TurnstileSiteverifyResponse { success: true });
+}
+
+#[tokio::test]
+async fn failure() {
+    let (sut, secret) = make_sut();
+    let result = sut.siteverify("failure", &secret).await.unwrap();
+    assert_eq!(result, TurnstileSiteverifyResponse { success: false });
+}
+
+#[tokio::test]
+async fn invalid_secret() {
+    let (sut, _) = make_sut();
+    let result = sut.siteverify("success", "invalid").await.unwrap();
+    assert_eq!(result, TurnstileSiteverifyResponse { success: false });
+}
+
+fn make_sut() -> (TurnstileApiServiceImpl, String) {
+    let config = academy_config::load_with_overrides(&["turnstile.enable = true"]).unwrap();
+
+    let TurnstileConfig {
+        siteverify_endpoint_override,
+        secret,
+        ..
+    } = config.turnstile.unwrap();
+
+    provider! {
+        Provider { turnstile_api_service_config: TurnstileApiServiceConfig, }
+    }
+
+    let mut provider = Provider {
+        _cache: Default::default(),
+        turnstile_api_service_config: TurnstileApiServiceConfig::new(siteverify_endpoint_override),
+    };
+
+    (provider.provide(), secret)
+}
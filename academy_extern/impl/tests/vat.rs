@@ -1,26 +1,38 @@
 use academy_di::{provider, Provide};
-use academy_extern_contracts::vat::VatApiService;
+use academy_extern_contracts::vat::{VatApiService, VatIdValidationResult};
 use academy_extern_impl::vat::{VatApiServiceConfig, VatApiServiceImpl};
 
 #[tokio::test]
 async fn valid() {
     let sut = make_sut();
-    let result = sut.is_vat_id_valid("DE0123456789").await.unwrap();
-    assert!(result);
+    let result = sut.validate_vat_id("DE0123456789").await.unwrap();
+    assert_eq!(
+        result,
+        VatIdValidationResult::Valid {
+            consultation_number: Some("WAPIAAAAZ0123456".into())
+        }
+    );
 }
 
 #[tokio::test]
 async fn invalid_regex_mismatch() {
     let sut = make_sut();
-    let result = sut.is_vat_id_valid("012345").await.unwrap();
-    assert!(!result);
+    let result = sut.validate_vat_id("012345").await.unwrap();
+    assert_eq!(result, VatIdValidationResult::Invalid);
 }
 
 #[tokio::test]
 async fn invalid_api_rejection() {
     let sut = make_sut();
-    let result = sut.is_vat_id_valid("DE54321").await.unwrap();
-    assert!(!result);
+    let result = sut.validate_vat_id("DE54321").await.unwrap();
+    assert_eq!(result, VatIdValidationResult::Invalid);
+}
+
+#[tokio::test]
+async fn unavailable() {
+    let sut = make_sut();
+    let result = sut.validate_vat_id("DE9999999999").await.unwrap();
+    assert_eq!(result, VatIdValidationResult::Unavailable);
 }
 
 fn make_sut() -> VatApiServiceImpl {
@@ -32,7 +44,11 @@ fn make_sut() -> VatApiServiceImpl {
 
     let mut provider = Provider {
         _cache: Default::default(),
-        vat_api_service_config: VatApiServiceConfig::new(config.vat.validate_endpoint_override),
+        vat_api_service_config: VatApiServiceConfig::new(
+            config.vat.validate_endpoint_override,
+            config.vat.requester_vat_id.as_deref(),
+        )
+        .unwrap(),
     };
 
     provider.provide()
@@ -0,0 +1,21 @@
+/// Public configuration of the captcha provider, which is required by the
+/// frontend to obtain a captcha response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptchaConfig {
+    Disabled,
+    Recaptcha { sitekey: String },
+    Hcaptcha { sitekey: String },
+    Turnstile { sitekey: String },
+    ProofOfWork { difficulty: u8 },
+}
+
+/// A proof-of-work challenge.
+///
+/// The captcha response is `{challenge}:{nonce}` where `nonce` is an
+/// arbitrary string such that the SHA-256 hash of the response has at least
+/// `difficulty` leading zero bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptchaChallenge {
+    pub challenge: String,
+    pub difficulty: u8,
+}
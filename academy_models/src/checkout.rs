@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    coin::CoinAmount,
+    macros::{id, nutype_string},
+    user::UserId,
+};
+
+id!(CoinOrderId);
+
+nutype_string!(CoinPackageId(validate(len_char_min = 1, len_char_max = 32)));
+
+// identifier of a payment at the payment provider
+nutype_string!(PaymentId(validate(len_char_min = 1, len_char_max = 256)));
+
+/// A package of coins which can be bought by users.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinPackage {
+    pub id: CoinPackageId,
+    pub coins: CoinAmount,
+    /// The gross price in euro cents
+    pub price: u64,
+}
+
+/// An order of a coin package.
+///
+/// The ordered coins are credited as soon as the payment provider reports
+/// the payment as successful.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinOrder {
+    pub id: CoinOrderId,
+    pub user_id: UserId,
+    pub package: CoinPackageId,
+    pub coins: CoinAmount,
+    /// The gross price in euro cents
+    pub price: u64,
+    pub status: CoinOrderStatus,
+    pub payment_id: PaymentId,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinOrderStatus {
+    /// The order has been created and is waiting for the payment.
+    Pending,
+    /// The payment has succeeded and the coins have been credited.
+    Completed,
+    /// The payment has failed.
+    Failed,
+    /// The payment has been cancelled by the user.
+    Cancelled,
+}
+
+impl CoinOrderStatus {
+    /// Return whether an order can be moved from this status to the given
+    /// status.
+    ///
+    /// Pending orders can be completed, failed or cancelled. Failed and
+    /// cancelled orders can still be completed, as the payment may be retried
+    /// by the user. Completed orders are final.
+    pub fn can_transition_to(self, status: Self) -> bool {
+        match self {
+            Self::Pending => status != Self::Pending,
+            Self::Failed | Self::Cancelled => status == Self::Completed,
+            Self::Completed => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_transition_to() {
+        use CoinOrderStatus::*;
+
+        assert!(Pending.can_transition_to(Completed));
+        assert!(Pending.can_transition_to(Failed));
+        assert!(Pending.can_transition_to(Cancelled));
+        assert!(!Pending.can_transition_to(Pending));
+
+        for from in [Failed, Cancelled] {
+            assert!(from.can_transition_to(Completed), "{from:?} -> Completed");
+            for to in [Pending, Failed, Cancelled] {
+                assert!(!from.can_transition_to(to), "{from:?} -> {to:?}");
+            }
+        }
+
+        for to in [Pending, Completed, Failed, Cancelled] {
+            assert!(!Completed.can_transition_to(to), "Completed -> {to:?}");
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use nutype::nutype;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    macros::{id, nutype_string},
+    user::UserId,
+};
+
+id!(CoinTransactionId);
+
+/// The coin balance of a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CoinBalance {
+    /// The number of coins the user can spend.
+    pub coins: u64,
+    /// The number of coins which the user has earned but which are withheld
+    /// until their invoice info is complete.
+    pub withheld_coins: u64,
+}
+
+#[nutype(
+    validate(greater = 0, less_or_equal = CoinAmount::MAX),
+    derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Deref,
+        TryFrom,
+        Serialize,
+        Deserialize,
+        JsonSchema
+    )
+)]
+pub struct CoinAmount(u64);
+
+impl CoinAmount {
+    pub const MAX: u64 = i64::MAX as u64;
+}
+
+nutype_string!(CoinTransactionDescription(validate(len_char_max = 256)));
+nutype_string!(CoinTransactionReference(validate(
+    len_char_min = 1,
+    len_char_max = 256
+)));
+
+/// An entry in the append-only coin ledger.
+///
+/// Every transaction moves `coins` from its [source](CoinTransactionKind::source)
+/// account to its [destination](CoinTransactionKind::destination) account, so
+/// the balance of a user is always the sum of all their transactions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinTransaction {
+    pub id: CoinTransactionId,
+    pub user_id: UserId,
+    pub kind: CoinTransactionKind,
+    pub coins: CoinAmount,
+    pub description: Option<CoinTransactionDescription>,
+    /// Caller-supplied idempotency key, unique per user and kind of
+    /// transaction.
+    ///
+    /// Withheld coins have been earned, so [`CoinTransactionKind::Earn`] and
+    /// [`CoinTransactionKind::Withheld`] share their references.
+    pub reference: Option<CoinTransactionReference>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinTransactionKind {
+    /// The user has earned coins (e.g. by solving a challenge).
+    Earn,
+    /// The user has spent coins (e.g. to buy a course).
+    Spend,
+    /// The user has earned coins, but cannot receive them yet because their
+    /// invoice info is incomplete.
+    Withheld,
+    /// Previously withheld coins have been released to the user.
+    Released,
+    /// Previously spent coins have been refunded to the user.
+    Refund,
+    /// The user has bought coins.
+    Purchase,
+}
+
+impl CoinTransactionKind {
+    /// Return the account which is debited by this kind of transaction.
+    pub fn source(self) -> CoinAccount {
+        match self {
+            Self::Earn | Self::Withheld | Self::Refund | Self::Purchase => CoinAccount::External,
+            Self::Spend => CoinAccount::Available,
+            Self::Released => CoinAccount::Withheld,
+        }
+    }
+
+    /// Return the account which is credited by this kind of transaction.
+    pub fn destination(self) -> CoinAccount {
+        match self {
+            Self::Earn | Self::Released | Self::Refund | Self::Purchase => CoinAccount::Available,
+            Self::Spend => CoinAccount::External,
+            Self::Withheld => CoinAccount::Withheld,
+        }
+    }
+}
+
+/// The kinds of transactions which add coins to the wallet of a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinCreditKind {
+    /// The user has earned coins, which are withheld if the user cannot
+    /// receive coins yet.
+    Earn,
+    /// Previously spent coins are refunded to the user.
+    Refund,
+}
+
+/// An account in the double-entry coin ledger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinAccount {
+    /// Coins entering or leaving the wallet of the user.
+    External,
+    /// Coins the user can spend.
+    Available,
+    /// Coins which are withheld until the invoice info of the user is
+    /// complete.
+    Withheld,
+}
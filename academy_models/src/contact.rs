@@ -1,4 +1,15 @@
-use crate::{email_address::EmailAddress, macros::nutype_string};
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    email_address::EmailAddress,
+    macros::{id, nutype_string},
+    user::UserId,
+};
+
+id!(ContactMessageId);
+id!(ContactMessageReplyId);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ContactMessage {
@@ -27,3 +38,50 @@ nutype_string!(ContactMessageContent(validate(
     len_char_min = 1,
     len_char_max = 4096
 )));
+
+/// A message which has been sent to the support team using the contact form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactInboxMessage {
+    pub id: ContactMessageId,
+    pub message: ContactMessage,
+    pub status: ContactMessageStatus,
+    /// The admin responsible for answering the message.
+    pub assignee: Option<UserId>,
+    /// The `Message-ID` of the email which has been forwarded to the support
+    /// team. Replies reference this id to thread the conversation.
+    pub email_message_id: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContactMessageStatus {
+    /// The message has not been dealt with yet.
+    Open,
+    /// The message has been answered or requires no further action.
+    Resolved,
+    /// The message has been classified as spam and has not been forwarded to
+    /// the support team.
+    Quarantined,
+}
+
+/// A reply to a contact message which has been sent to its author.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactMessageReply {
+    pub id: ContactMessageReplyId,
+    pub message_id: ContactMessageId,
+    /// The admin who has written the reply, `None` if the user has been
+    /// deleted.
+    pub author: Option<UserId>,
+    pub content: ContactMessageContent,
+    /// The `Message-ID` of the email which has been sent to the author of the
+    /// contact message.
+    pub email_message_id: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ContactMessageFilter {
+    pub status: Option<ContactMessageStatus>,
+    pub assignee: Option<UserId>,
+}
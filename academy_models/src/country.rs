@@ -0,0 +1,761 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::locale::Locale;
+
+macro_rules! countries {
+    ($($code:ident($alpha3:literal, $en:literal, $de:literal)),* $(,)?) => {
+        /// A country as defined by ISO 3166-1, identified by its alpha-2 code
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+        )]
+        pub enum Country {
+            $($code,)*
+        }
+
+        impl Country {
+            /// All countries, ordered by their alpha-2 code
+            pub const ALL: &'static [Self] = &[$(Self::$code,)*];
+
+            /// Return the ISO 3166-1 alpha-2 code of this country.
+            pub fn code(self) -> &'static str {
+                match self {
+                    $(Self::$code => stringify!($code),)*
+                }
+            }
+
+            /// Return the ISO 3166-1 alpha-3 code of this country.
+            pub fn alpha3(self) -> &'static str {
+                match self {
+                    $(Self::$code => $alpha3,)*
+                }
+            }
+
+            /// Return the country with the given ISO 3166-1 alpha-2 code.
+            pub fn from_code(code: &str) -> Option<Self> {
+                match code {
+                    $(stringify!($code) => Some(Self::$code),)*
+                    _ => None,
+                }
+            }
+
+            fn english_name(self) -> &'static str {
+                match self {
+                    $(Self::$code => $en,)*
+                }
+            }
+
+            fn german_name(self) -> &'static str {
+                match self {
+                    $(Self::$code => $de,)*
+                }
+            }
+        }
+    };
+}
+
+countries! {
+    AD("AND", "Andorra", "Andorra"),
+    AE("ARE", "United Arab Emirates", "Vereinigte Arabische Emirate"),
+    AF("AFG", "Afghanistan", "Afghanistan"),
+    AG("ATG", "Antigua and Barbuda", "Antigua und Barbuda"),
+    AI("AIA", "Anguilla", "Anguilla"),
+    AL("ALB", "Albania", "Albanien"),
+    AM("ARM", "Armenia", "Armenien"),
+    AO("AGO", "Angola", "Angola"),
+    AQ("ATA", "Antarctica", "Antarktis"),
+    AR("ARG", "Argentina", "Argentinien"),
+    AS("ASM", "American Samoa", "Amerikanisch-Samoa"),
+    AT("AUT", "Austria", "Österreich"),
+    AU("AUS", "Australia", "Australien"),
+    AW("ABW", "Aruba", "Aruba"),
+    AX("ALA", "Åland Islands", "Åland-Inseln"),
+    AZ("AZE", "Azerbaijan", "Aserbaidschan"),
+    BA("BIH", "Bosnia and Herzegovina", "Bosnien und Herzegowina"),
+    BB("BRB", "Barbados", "Barbados"),
+    BD("BGD", "Bangladesh", "Bangladesch"),
+    BE("BEL", "Belgium", "Belgien"),
+    BF("BFA", "Burkina Faso", "Burkina Faso"),
+    BG("BGR", "Bulgaria", "Bulgarien"),
+    BH("BHR", "Bahrain", "Bahrain"),
+    BI("BDI", "Burundi", "Burundi"),
+    BJ("BEN", "Benin", "Benin"),
+    BL("BLM", "Saint Barthélemy", "Saint-Barthélemy"),
+    BM("BMU", "Bermuda", "Bermuda"),
+    BN("BRN", "Brunei Darussalam", "Brunei Darussalam"),
+    BO("BOL", "Bolivia", "Bolivien"),
+    BQ("BES", "Bonaire, Sint Eustatius and Saba", "Bonaire, Sint Eustatius und Saba"),
+    BR("BRA", "Brazil", "Brasilien"),
+    BS("BHS", "Bahamas", "Bahamas"),
+    BT("BTN", "Bhutan", "Bhutan"),
+    BV("BVT", "Bouvet Island", "Bouvet-Insel"),
+    BW("BWA", "Botswana", "Botsuana"),
+    BY("BLR", "Belarus", "Belarus"),
+    BZ("BLZ", "Belize", "Belize"),
+    CA("CAN", "Canada", "Kanada"),
+    CC("CCK", "Cocos (Keeling) Islands", "Kokos-(Keeling-)Inseln"),
+    CD("COD", "Congo, The Democratic Republic of the", "Demokratische Republik Kongo"),
+    CF("CAF", "Central African Republic", "Zentralafrikanische Republik"),
+    CG("COG", "Congo", "Kongo"),
+    CH("CHE", "Switzerland", "Schweiz"),
+    CI("CIV", "Côte d'Ivoire", "Côte d'Ivoire"),
+    CK("COK", "Cook Islands", "Cookinseln"),
+    CL("CHL", "Chile", "Chile"),
+    CM("CMR", "Cameroon", "Kamerun"),
+    CN("CHN", "China", "China"),
+    CO("COL", "Colombia", "Kolumbien"),
+    CR("CRI", "Costa Rica", "Costa Rica"),
+    CU("CUB", "Cuba", "Kuba"),
+    CV("CPV", "Cabo Verde", "Kap Verde"),
+    CW("CUW", "Curaçao", "Curaçao"),
+    CX("CXR", "Christmas Island", "Weihnachtsinseln"),
+    CY("CYP", "Cyprus", "Zypern"),
+    CZ("CZE", "Czechia", "Tschechien"),
+    DE("DEU", "Germany", "Deutschland"),
+    DJ("DJI", "Djibouti", "Dschibuti"),
+    DK("DNK", "Denmark", "Dänemark"),
+    DM("DMA", "Dominica", "Dominica"),
+    DO("DOM", "Dominican Republic", "Dominikanische Republik"),
+    DZ("DZA", "Algeria", "Algerien"),
+    EC("ECU", "Ecuador", "Ecuador"),
+    EE("EST", "Estonia", "Estland"),
+    EG("EGY", "Egypt", "Ägypten"),
+    EH("ESH", "Western Sahara", "Westsahara"),
+    ER("ERI", "Eritrea", "Eritrea"),
+    ES("ESP", "Spain", "Spanien"),
+    ET("ETH", "Ethiopia", "Äthiopien"),
+    FI("FIN", "Finland", "Finnland"),
+    FJ("FJI", "Fiji", "Fidschi"),
+    FK("FLK", "Falkland Islands (Malvinas)", "Falklandinseln (Malwinen)"),
+    FM("FSM", "Micronesia, Federated States of", "Mikronesien, Föderierte Staaten von"),
+    FO("FRO", "Faroe Islands", "Färöer-Inseln"),
+    FR("FRA", "France", "Frankreich"),
+    GA("GAB", "Gabon", "Gabun"),
+    GB("GBR", "United Kingdom", "Vereinigtes Königreich"),
+    GD("GRD", "Grenada", "Grenada"),
+    GE("GEO", "Georgia", "Georgien"),
+    GF("GUF", "French Guiana", "Französisch-Guyana"),
+    GG("GGY", "Guernsey", "Guernsey"),
+    GH("GHA", "Ghana", "Ghana"),
+    GI("GIB", "Gibraltar", "Gibraltar"),
+    GL("GRL", "Greenland", "Grönland"),
+    GM("GMB", "Gambia", "Gambia"),
+    GN("GIN", "Guinea", "Guinea"),
+    GP("GLP", "Guadeloupe", "Guadeloupe"),
+    GQ("GNQ", "Equatorial Guinea", "Äquatorialguinea"),
+    GR("GRC", "Greece", "Griechenland"),
+    GS("SGS", "South Georgia and the South Sandwich Islands", "South Georgia und die Südlichen Sandwichinseln"),
+    GT("GTM", "Guatemala", "Guatemala"),
+    GU("GUM", "Guam", "Guam"),
+    GW("GNB", "Guinea-Bissau", "Guinea-Bissau"),
+    GY("GUY", "Guyana", "Guyana"),
+    HK("HKG", "Hong Kong", "Hongkong"),
+    HM("HMD", "Heard Island and McDonald Islands", "Heard und McDonaldinseln"),
+    HN("HND", "Honduras", "Honduras"),
+    HR("HRV", "Croatia", "Kroatien"),
+    HT("HTI", "Haiti", "Haiti"),
+    HU("HUN", "Hungary", "Ungarn"),
+    ID("IDN", "Indonesia", "Indonesien"),
+    IE("IRL", "Ireland", "Irland"),
+    IL("ISR", "Israel", "Israel"),
+    IM("IMN", "Isle of Man", "Insel Man"),
+    IN("IND", "India", "Indien"),
+    IO("IOT", "British Indian Ocean Territory", "Britisches Territorium im Indischen Ozean"),
+    IQ("IRQ", "Iraq", "Irak"),
+    IR("IRN", "Iran", "Iran, Islamische Republik"),
+    IS("ISL", "Iceland", "Island"),
+    IT("ITA", "Italy", "Italien"),
+    JE("JEY", "Jersey", "Jersey"),
+    JM("JAM", "Jamaica", "Jamaika"),
+    JO("JOR", "Jordan", "Jordanien"),
+    JP("JPN", "Japan", "Japan"),
+    KE("KEN", "Kenya", "Kenia"),
+    KG("KGZ", "Kyrgyzstan", "Kirgisistan"),
+    KH("KHM", "Cambodia", "Kambodscha"),
+    KI("KIR", "Kiribati", "Kiribati"),
+    KM("COM", "Comoros", "Komoren"),
+    KN("KNA", "Saint Kitts and Nevis", "St. Kitts und Nevis"),
+    KP("PRK", "North Korea", "Nordkorea"),
+    KR("KOR", "South Korea", "Südkorea"),
+    KW("KWT", "Kuwait", "Kuwait"),
+    KY("CYM", "Cayman Islands", "Cayman-Inseln"),
+    KZ("KAZ", "Kazakhstan", "Kasachstan"),
+    LA("LAO", "Laos", "Laos, Demokratische Volksrepublik"),
+    LB("LBN", "Lebanon", "Libanon"),
+    LC("LCA", "Saint Lucia", "St. Lucia"),
+    LI("LIE", "Liechtenstein", "Liechtenstein"),
+    LK("LKA", "Sri Lanka", "Sri Lanka"),
+    LR("LBR", "Liberia", "Liberia"),
+    LS("LSO", "Lesotho", "Lesotho"),
+    LT("LTU", "Lithuania", "Litauen"),
+    LU("LUX", "Luxembourg", "Luxemburg"),
+    LV("LVA", "Latvia", "Lettland"),
+    LY("LBY", "Libya", "Libyen"),
+    MA("MAR", "Morocco", "Marokko"),
+    MC("MCO", "Monaco", "Monaco"),
+    MD("MDA", "Moldova", "Moldau"),
+    ME("MNE", "Montenegro", "Montenegro"),
+    MF("MAF", "Saint Martin (French part)", "Saint Martin (Französischer Teil)"),
+    MG("MDG", "Madagascar", "Madagaskar"),
+    MH("MHL", "Marshall Islands", "Marshallinseln"),
+    MK("MKD", "North Macedonia", "Nordmazedonien"),
+    ML("MLI", "Mali", "Mali"),
+    MM("MMR", "Myanmar", "Myanmar"),
+    MN("MNG", "Mongolia", "Mongolei"),
+    MO("MAC", "Macao", "Macao"),
+    MP("MNP", "Northern Mariana Islands", "Nördliche Marianen"),
+    MQ("MTQ", "Martinique", "Martinique"),
+    MR("MRT", "Mauritania", "Mauretanien"),
+    MS("MSR", "Montserrat", "Montserrat"),
+    MT("MLT", "Malta", "Malta"),
+    MU("MUS", "Mauritius", "Mauritius"),
+    MV("MDV", "Maldives", "Malediven"),
+    MW("MWI", "Malawi", "Malawi"),
+    MX("MEX", "Mexico", "Mexiko"),
+    MY("MYS", "Malaysia", "Malaysia"),
+    MZ("MOZ", "Mozambique", "Mosambik"),
+    NA("NAM", "Namibia", "Namibia"),
+    NC("NCL", "New Caledonia", "Neukaledonien"),
+    NE("NER", "Niger", "Niger"),
+    NF("NFK", "Norfolk Island", "Norfolkinsel"),
+    NG("NGA", "Nigeria", "Nigeria"),
+    NI("NIC", "Nicaragua", "Nicaragua"),
+    NL("NLD", "Netherlands", "Niederlande"),
+    NO("NOR", "Norway", "Norwegen"),
+    NP("NPL", "Nepal", "Nepal"),
+    NR("NRU", "Nauru", "Nauru"),
+    NU("NIU", "Niue", "Niue"),
+    NZ("NZL", "New Zealand", "Neuseeland"),
+    OM("OMN", "Oman", "Oman"),
+    PA("PAN", "Panama", "Panama"),
+    PE("PER", "Peru", "Peru"),
+    PF("PYF", "French Polynesia", "Französisch-Polynesien"),
+    PG("PNG", "Papua New Guinea", "Papua-Neuguinea"),
+    PH("PHL", "Philippines", "Philippinen"),
+    PK("PAK", "Pakistan", "Pakistan"),
+    PL("POL", "Poland", "Polen"),
+    PM("SPM", "Saint Pierre and Miquelon", "St. Pierre und Miquelon"),
+    PN("PCN", "Pitcairn", "Pitcairn"),
+    PR("PRI", "Puerto Rico", "Puerto Rico"),
+    PS("PSE", "Palestine, State of", "Palästina, Staat"),
+    PT("PRT", "Portugal", "Portugal"),
+    PW("PLW", "Palau", "Palau"),
+    PY("PRY", "Paraguay", "Paraguay"),
+    QA("QAT", "Qatar", "Katar"),
+    RE("REU", "Réunion", "Réunion"),
+    RO("ROU", "Romania", "Rumänien"),
+    RS("SRB", "Serbia", "Serbien"),
+    RU("RUS", "Russian Federation", "Russische Föderation"),
+    RW("RWA", "Rwanda", "Ruanda"),
+    SA("SAU", "Saudi Arabia", "Saudi-Arabien"),
+    SB("SLB", "Solomon Islands", "Salomoninseln"),
+    SC("SYC", "Seychelles", "Seychellen"),
+    SD("SDN", "Sudan", "Sudan"),
+    SE("SWE", "Sweden", "Schweden"),
+    SG("SGP", "Singapore", "Singapur"),
+    SH("SHN", "Saint Helena, Ascension and Tristan da Cunha", "St. Helena, Ascension und Tristan da Cunha"),
+    SI("SVN", "Slovenia", "Slowenien"),
+    SJ("SJM", "Svalbard and Jan Mayen", "Svalbard und Jan Mayen"),
+    SK("SVK", "Slovakia", "Slowakei"),
+    SL("SLE", "Sierra Leone", "Sierra Leone"),
+    SM("SMR", "San Marino", "San Marino"),
+    SN("SEN", "Senegal", "Senegal"),
+    SO("SOM", "Somalia", "Somalia"),
+    SR("SUR", "Suriname", "Suriname"),
+    SS("SSD", "South Sudan", "Südsudan"),
+    ST("STP", "Sao Tome and Principe", "São Tomé und Príncipe"),
+    SV("SLV", "El Salvador", "El Salvador"),
+    SX("SXM", "Sint Maarten (Dutch part)", "Saint-Martin (Niederländischer Teil)"),
+    SY("SYR", "Syria", "Syrien"),
+    SZ("SWZ", "Eswatini", "Eswatini"),
+    TC("TCA", "Turks and Caicos Islands", "Turks- und Caicosinseln"),
+    TD("TCD", "Chad", "Tschad"),
+    TF("ATF", "French Southern Territories", "Französische Süd- und Antarktisgebiete"),
+    TG("TGO", "Togo", "Togo"),
+    TH("THA", "Thailand", "Thailand"),
+    TJ("TJK", "Tajikistan", "Tadschikistan"),
+    TK("TKL", "Tokelau", "Tokelau"),
+    TL("TLS", "Timor-Leste", "Timor-Leste"),
+    TM("TKM", "Turkmenistan", "Turkmenistan"),
+    TN("TUN", "Tunisia", "Tunesien"),
+    TO("TON", "Tonga", "Tonga"),
+    TR("TUR", "Türkiye", "Türkei"),
+    TT("TTO", "Trinidad and Tobago", "Trinidad und Tobago"),
+    TV("TUV", "Tuvalu", "Tuvalu"),
+    TW("TWN", "Taiwan", "Taiwan, Chinesische Provinz"),
+    TZ("TZA", "Tanzania", "Tansania"),
+    UA("UKR", "Ukraine", "Ukraine"),
+    UG("UGA", "Uganda", "Uganda"),
+    UM("UMI", "United States Minor Outlying Islands", "United States Minor Outlying Islands"),
+    US("USA", "United States", "Vereinigte Staaten"),
+    UY("URY", "Uruguay", "Uruguay"),
+    UZ("UZB", "Uzbekistan", "Usbekistan"),
+    VA("VAT", "Holy See (Vatican City State)", "Heiliger Stuhl (Staat Vatikanstadt)"),
+    VC("VCT", "Saint Vincent and the Grenadines", "St. Vincent und die Grenadinen"),
+    VE("VEN", "Venezuela", "Venezuela, Bolivarische Republik"),
+    VG("VGB", "Virgin Islands, British", "Britische Jungferninseln"),
+    VI("VIR", "Virgin Islands, U.S.", "Amerikanische Jungferninseln"),
+    VN("VNM", "Vietnam", "Vietnam"),
+    VU("VUT", "Vanuatu", "Vanuatu"),
+    WF("WLF", "Wallis and Futuna", "Wallis und Futuna"),
+    WS("WSM", "Samoa", "Samoa"),
+    YE("YEM", "Yemen", "Jemen"),
+    YT("MYT", "Mayotte", "Mayotte"),
+    ZA("ZAF", "South Africa", "Südafrika"),
+    ZM("ZMB", "Zambia", "Sambia"),
+    ZW("ZWE", "Zimbabwe", "Simbabwe"),
+}
+
+/// Alternative names which are recognized by [`Country::from_name`]
+const ALIASES: &[(&str, Country)] = &[
+    ("Principality of Andorra", Country::AD),
+    ("Islamic Republic of Afghanistan", Country::AF),
+    ("Republic of Albania", Country::AL),
+    ("Republic of Armenia", Country::AM),
+    ("Republic of Angola", Country::AO),
+    ("Argentine Republic", Country::AR),
+    ("Republic of Austria", Country::AT),
+    ("Republic of Azerbaijan", Country::AZ),
+    ("Republic of Bosnia and Herzegovina", Country::BA),
+    ("People's Republic of Bangladesh", Country::BD),
+    ("Kingdom of Belgium", Country::BE),
+    ("Republic of Bulgaria", Country::BG),
+    ("Kingdom of Bahrain", Country::BH),
+    ("Republic of Burundi", Country::BI),
+    ("Republic of Benin", Country::BJ),
+    ("Bolivia, Plurinational State of", Country::BO),
+    ("Plurinational State of Bolivia", Country::BO),
+    ("Federative Republic of Brazil", Country::BR),
+    ("Commonwealth of the Bahamas", Country::BS),
+    ("Kingdom of Bhutan", Country::BT),
+    ("Republic of Botswana", Country::BW),
+    ("Republic of Belarus", Country::BY),
+    ("Republic of the Congo", Country::CG),
+    ("Swiss Confederation", Country::CH),
+    ("Republic of Côte d'Ivoire", Country::CI),
+    ("Republic of Chile", Country::CL),
+    ("Republic of Cameroon", Country::CM),
+    ("People's Republic of China", Country::CN),
+    ("Republic of Colombia", Country::CO),
+    ("Republic of Costa Rica", Country::CR),
+    ("Republic of Cuba", Country::CU),
+    ("Republic of Cabo Verde", Country::CV),
+    ("Republic of Cyprus", Country::CY),
+    ("Czech Republic", Country::CZ),
+    ("Federal Republic of Germany", Country::DE),
+    ("Republic of Djibouti", Country::DJ),
+    ("Kingdom of Denmark", Country::DK),
+    ("Commonwealth of Dominica", Country::DM),
+    ("People's Democratic Republic of Algeria", Country::DZ),
+    ("Republic of Ecuador", Country::EC),
+    ("Republic of Estonia", Country::EE),
+    ("Arab Republic of Egypt", Country::EG),
+    ("the State of Eritrea", Country::ER),
+    ("Kingdom of Spain", Country::ES),
+    ("Federal Democratic Republic of Ethiopia", Country::ET),
+    ("Republic of Finland", Country::FI),
+    ("Republic of Fiji", Country::FJ),
+    ("Federated States of Micronesia", Country::FM),
+    ("French Republic", Country::FR),
+    ("Gabonese Republic", Country::GA),
+    (
+        "United Kingdom of Great Britain and Northern Ireland",
+        Country::GB,
+    ),
+    ("Republic of Ghana", Country::GH),
+    ("Republic of the Gambia", Country::GM),
+    ("Republic of Guinea", Country::GN),
+    ("Republic of Equatorial Guinea", Country::GQ),
+    ("Hellenic Republic", Country::GR),
+    ("Republic of Guatemala", Country::GT),
+    ("Republic of Guinea-Bissau", Country::GW),
+    ("Republic of Guyana", Country::GY),
+    (
+        "Hong Kong Special Administrative Region of China",
+        Country::HK,
+    ),
+    ("Republic of Honduras", Country::HN),
+    ("Republic of Croatia", Country::HR),
+    ("Republic of Haiti", Country::HT),
+    ("Republic of Indonesia", Country::ID),
+    ("State of Israel", Country::IL),
+    ("Republic of India", Country::IN),
+    ("Republic of Iraq", Country::IQ),
+    ("Iran, Islamic Republic of", Country::IR),
+    ("Islamic Republic of Iran", Country::IR),
+    ("Republic of Iceland", Country::IS),
+    ("Italian Republic", Country::IT),
+    ("Hashemite Kingdom of Jordan", Country::JO),
+    ("Republic of Kenya", Country::KE),
+    ("Kyrgyz Republic", Country::KG),
+    ("Kingdom of Cambodia", Country::KH),
+    ("Republic of Kiribati", Country::KI),
+    ("Union of the Comoros", Country::KM),
+    ("Korea, Democratic People's Republic of", Country::KP),
+    ("Democratic People's Republic of Korea", Country::KP),
+    ("Korea, Republic of", Country::KR),
+    ("State of Kuwait", Country::KW),
+    ("Republic of Kazakhstan", Country::KZ),
+    ("Lao People's Democratic Republic", Country::LA),
+    ("Lebanese Republic", Country::LB),
+    ("Principality of Liechtenstein", Country::LI),
+    ("Democratic Socialist Republic of Sri Lanka", Country::LK),
+    ("Republic of Liberia", Country::LR),
+    ("Kingdom of Lesotho", Country::LS),
+    ("Republic of Lithuania", Country::LT),
+    ("Grand Duchy of Luxembourg", Country::LU),
+    ("Republic of Latvia", Country::LV),
+    ("Kingdom of Morocco", Country::MA),
+    ("Principality of Monaco", Country::MC),
+    ("Moldova, Republic of", Country::MD),
+    ("Republic of Moldova", Country::MD),
+    ("Republic of Madagascar", Country::MG),
+    ("Republic of the Marshall Islands", Country::MH),
+    ("Republic of North Macedonia", Country::MK),
+    ("Republic of Mali", Country::ML),
+    ("Republic of Myanmar", Country::MM),
+    ("Macao Special Administrative Region of China", Country::MO),
+    ("Commonwealth of the Northern Mariana Islands", Country::MP),
+    ("Islamic Republic of Mauritania", Country::MR),
+    ("Republic of Malta", Country::MT),
+    ("Republic of Mauritius", Country::MU),
+    ("Republic of Maldives", Country::MV),
+    ("Republic of Malawi", Country::MW),
+    ("United Mexican States", Country::MX),
+    ("Republic of Mozambique", Country::MZ),
+    ("Republic of Namibia", Country::NA),
+    ("Republic of the Niger", Country::NE),
+    ("Federal Republic of Nigeria", Country::NG),
+    ("Republic of Nicaragua", Country::NI),
+    ("Kingdom of the Netherlands", Country::NL),
+    ("Kingdom of Norway", Country::NO),
+    ("Federal Democratic Republic of Nepal", Country::NP),
+    ("Republic of Nauru", Country::NR),
+    ("Sultanate of Oman", Country::OM),
+    ("Republic of Panama", Country::PA),
+    ("Republic of Peru", Country::PE),
+    ("Independent State of Papua New Guinea", Country::PG),
+    ("Republic of the Philippines", Country::PH),
+    ("Islamic Republic of Pakistan", Country::PK),
+    ("Republic of Poland", Country::PL),
+    ("the State of Palestine", Country::PS),
+    ("Portuguese Republic", Country::PT),
+    ("Republic of Palau", Country::PW),
+    ("Republic of Paraguay", Country::PY),
+    ("State of Qatar", Country::QA),
+    ("Republic of Serbia", Country::RS),
+    ("Rwandese Republic", Country::RW),
+    ("Kingdom of Saudi Arabia", Country::SA),
+    ("Republic of Seychelles", Country::SC),
+    ("Republic of the Sudan", Country::SD),
+    ("Kingdom of Sweden", Country::SE),
+    ("Republic of Singapore", Country::SG),
+    ("Republic of Slovenia", Country::SI),
+    ("Slovak Republic", Country::SK),
+    ("Republic of Sierra Leone", Country::SL),
+    ("Republic of San Marino", Country::SM),
+    ("Republic of Senegal", Country::SN),
+    ("Federal Republic of Somalia", Country::SO),
+    ("Republic of Suriname", Country::SR),
+    ("Republic of South Sudan", Country::SS),
+    ("Democratic Republic of Sao Tome and Principe", Country::ST),
+    ("Republic of El Salvador", Country::SV),
+    ("Syrian Arab Republic", Country::SY),
+    ("Kingdom of Eswatini", Country::SZ),
+    ("Republic of Chad", Country::TD),
+    ("Togolese Republic", Country::TG),
+    ("Kingdom of Thailand", Country::TH),
+    ("Republic of Tajikistan", Country::TJ),
+    ("Democratic Republic of Timor-Leste", Country::TL),
+    ("Republic of Tunisia", Country::TN),
+    ("Kingdom of Tonga", Country::TO),
+    ("Republic of Türkiye", Country::TR),
+    ("Republic of Trinidad and Tobago", Country::TT),
+    ("Taiwan, Province of China", Country::TW),
+    ("Tanzania, United Republic of", Country::TZ),
+    ("United Republic of Tanzania", Country::TZ),
+    ("Republic of Uganda", Country::UG),
+    ("United States of America", Country::US),
+    ("Eastern Republic of Uruguay", Country::UY),
+    ("Republic of Uzbekistan", Country::UZ),
+    ("Venezuela, Bolivarian Republic of", Country::VE),
+    ("Bolivarian Republic of Venezuela", Country::VE),
+    ("British Virgin Islands", Country::VG),
+    ("Virgin Islands of the United States", Country::VI),
+    ("Viet Nam", Country::VN),
+    ("Socialist Republic of Viet Nam", Country::VN),
+    ("Republic of Vanuatu", Country::VU),
+    ("Independent State of Samoa", Country::WS),
+    ("Republic of Yemen", Country::YE),
+    ("Republic of South Africa", Country::ZA),
+    ("Republic of Zambia", Country::ZM),
+    ("Republic of Zimbabwe", Country::ZW),
+    ("Czech Republic", Country::CZ),
+    ("Tschechische Republik", Country::CZ),
+    ("UK", Country::GB),
+    ("Great Britain", Country::GB),
+    ("Großbritannien", Country::GB),
+    ("England", Country::GB),
+    ("Holland", Country::NL),
+    ("Russia", Country::RU),
+    ("Russland", Country::RU),
+    ("Turkey", Country::TR),
+    ("United States of America", Country::US),
+    ("Vereinigte Staaten von Amerika", Country::US),
+    ("Vatican", Country::VA),
+    ("Democratic Republic of the Congo", Country::CD),
+];
+
+/// Postal code formats of the countries whose postal codes are validated
+static ZIP_CODE_PATTERNS: LazyLock<HashMap<Country, (&str, Regex)>> = LazyLock::new(|| {
+    [
+        (Country::AT, r"^[0-9]{4}$"),
+        (Country::AU, r"^[0-9]{4}$"),
+        (Country::BE, r"^[0-9]{4}$"),
+        (Country::BG, r"^[0-9]{4}$"),
+        (Country::CA, r"^[A-Z][0-9][A-Z] ?[0-9][A-Z][0-9]$"),
+        (Country::CH, r"^[0-9]{4}$"),
+        (Country::CY, r"^[0-9]{4}$"),
+        (Country::CZ, r"^[0-9]{3} ?[0-9]{2}$"),
+        (Country::DE, r"^[0-9]{5}$"),
+        (Country::DK, r"^[0-9]{4}$"),
+        (Country::EE, r"^[0-9]{5}$"),
+        (Country::ES, r"^[0-9]{5}$"),
+        (Country::FI, r"^[0-9]{5}$"),
+        (Country::FR, r"^[0-9]{5}$"),
+        (Country::GB, r"^[A-Z]{1,2}[0-9][A-Z0-9]? ?[0-9][A-Z]{2}$"),
+        (Country::GR, r"^[0-9]{3} ?[0-9]{2}$"),
+        (Country::HR, r"^[0-9]{5}$"),
+        (Country::HU, r"^[0-9]{4}$"),
+        (Country::IE, r"^[A-Z][0-9][0-9W] ?[A-Z0-9]{4}$"),
+        (Country::IN, r"^[0-9]{6}$"),
+        (Country::IS, r"^[0-9]{3}$"),
+        (Country::IT, r"^[0-9]{5}$"),
+        (Country::JP, r"^[0-9]{3}-?[0-9]{4}$"),
+        (Country::LI, r"^[0-9]{4}$"),
+        (Country::LT, r"^(LT-)?[0-9]{5}$"),
+        (Country::LU, r"^(L-)?[0-9]{4}$"),
+        (Country::LV, r"^(LV-)?[0-9]{4}$"),
+        (Country::MT, r"^[A-Z]{3} ?[0-9]{4}$"),
+        (Country::NL, r"^[0-9]{4} ?[A-Z]{2}$"),
+        (Country::NO, r"^[0-9]{4}$"),
+        (Country::PL, r"^[0-9]{2}-[0-9]{3}$"),
+        (Country::PT, r"^[0-9]{4}-[0-9]{3}$"),
+        (Country::RO, r"^[0-9]{6}$"),
+        (Country::SE, r"^[0-9]{3} ?[0-9]{2}$"),
+        (Country::SI, r"^[0-9]{4}$"),
+        (Country::SK, r"^[0-9]{3} ?[0-9]{2}$"),
+        (Country::US, r"^[0-9]{5}(-[0-9]{4})?$"),
+    ]
+    .into_iter()
+    .map(|(country, pattern)| {
+        let regex = Regex::new(pattern).unwrap();
+        (country, (pattern, regex))
+    })
+    .collect()
+});
+
+impl Country {
+    /// Return the name of this country in the given locale, falling back to
+    /// English if no translation is available.
+    pub fn name(self, locale: &Locale) -> &'static str {
+        match locale.language() {
+            "de" => self.german_name(),
+            _ => self.english_name(),
+        }
+    }
+
+    /// Find a country by its alpha-2 code, alpha-3 code or its English or
+    /// German name (case insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|country| {
+                [
+                    country.code(),
+                    country.alpha3(),
+                    country.english_name(),
+                    country.german_name(),
+                ]
+                .into_iter()
+                .any(|x| x.to_lowercase() == name)
+            })
+            .or_else(|| {
+                ALIASES
+                    .iter()
+                    .find(|(alias, _)| alias.to_lowercase() == name)
+                    .map(|&(_, country)| country)
+            })
+    }
+
+    /// Return the prefix of VAT ids issued by this country, if it is a member
+    /// state of the European Union.
+    pub fn vat_prefix(self) -> Option<&'static str> {
+        match self {
+            Self::AT => Some("AT"),
+            Self::BE => Some("BE"),
+            Self::BG => Some("BG"),
+            Self::CY => Some("CY"),
+            Self::CZ => Some("CZ"),
+            Self::DE => Some("DE"),
+            Self::DK => Some("DK"),
+            Self::EE => Some("EE"),
+            Self::ES => Some("ES"),
+            Self::FI => Some("FI"),
+            Self::FR => Some("FR"),
+            Self::GR => Some("EL"),
+            Self::HR => Some("HR"),
+            Self::HU => Some("HU"),
+            Self::IE => Some("IE"),
+            Self::IT => Some("IT"),
+            Self::LT => Some("LT"),
+            Self::LU => Some("LU"),
+            Self::LV => Some("LV"),
+            Self::MT => Some("MT"),
+            Self::NL => Some("NL"),
+            Self::PL => Some("PL"),
+            Self::PT => Some("PT"),
+            Self::RO => Some("RO"),
+            Self::SE => Some("SE"),
+            Self::SI => Some("SI"),
+            Self::SK => Some("SK"),
+            _ => None,
+        }
+    }
+
+    /// Return the standard VAT rate of this country in basis points (1/100 of a
+    /// percent), if it is a member state of the European Union.
+    pub fn vat_rate(self) -> Option<u32> {
+        match self {
+            Self::AT => Some(2000),
+            Self::BE => Some(2100),
+            Self::BG => Some(2000),
+            Self::CY => Some(1900),
+            Self::CZ => Some(2100),
+            Self::DE => Some(1900),
+            Self::DK => Some(2500),
+            Self::EE => Some(2400),
+            Self::ES => Some(2100),
+            Self::FI => Some(2550),
+            Self::FR => Some(2000),
+            Self::GR => Some(2400),
+            Self::HR => Some(2500),
+            Self::HU => Some(2700),
+            Self::IE => Some(2300),
+            Self::IT => Some(2200),
+            Self::LT => Some(2100),
+            Self::LU => Some(1700),
+            Self::LV => Some(2100),
+            Self::MT => Some(1800),
+            Self::NL => Some(2100),
+            Self::PL => Some(2300),
+            Self::PT => Some(2300),
+            Self::RO => Some(2100),
+            Self::SE => Some(2500),
+            Self::SI => Some(2200),
+            Self::SK => Some(2300),
+            _ => None,
+        }
+    }
+
+    /// Return whether the given VAT id may have been issued by this country.
+    ///
+    /// VAT ids are only checked for member states of the European Union.
+    pub fn is_valid_vat_id(self, vat_id: &str) -> bool {
+        self.vat_prefix()
+            .is_none_or(|prefix| vat_id.trim_start().starts_with(prefix))
+    }
+
+    /// Return the regular expression which postal codes in this country must
+    /// match (after conversion to uppercase), if postal codes of this country
+    /// are validated.
+    pub fn zip_code_pattern(self) -> Option<&'static str> {
+        ZIP_CODE_PATTERNS.get(&self).map(|&(pattern, _)| pattern)
+    }
+
+    /// Return whether the given postal code is valid in this country.
+    pub fn is_valid_zip_code(self, zip_code: &str) -> bool {
+        ZIP_CODE_PATTERNS
+            .get(&self)
+            .is_none_or(|(_, regex)| regex.is_match(&zip_code.trim().to_uppercase()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code() {
+        for &country in Country::ALL {
+            assert_eq!(Country::from_code(country.code()), Some(country));
+        }
+        assert_eq!(Country::from_code("XX"), None);
+        assert_eq!(Country::from_code("de"), None);
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(Country::DE.name(&"en".try_into().unwrap()), "Germany");
+        assert_eq!(
+            Country::DE.name(&"de-AT".try_into().unwrap()),
+            "Deutschland"
+        );
+        assert_eq!(Country::AT.name(&"fr".try_into().unwrap()), "Austria");
+    }
+
+    #[test]
+    fn from_name() {
+        for (name, expected) in [
+            ("DE", Some(Country::DE)),
+            ("de", Some(Country::DE)),
+            ("DEU", Some(Country::DE)),
+            (" germany ", Some(Country::DE)),
+            ("Deutschland", Some(Country::DE)),
+            ("österreich", Some(Country::AT)),
+            ("Great Britain", Some(Country::GB)),
+            ("Korea, Republic of", Some(Country::KR)),
+            ("asdf", None),
+            ("", None),
+        ] {
+            assert_eq!(Country::from_name(name), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn vat_rate() {
+        for &country in Country::ALL {
+            assert_eq!(
+                country.vat_rate().is_some(),
+                country.vat_prefix().is_some(),
+                "{country:?}"
+            );
+        }
+        assert_eq!(Country::DE.vat_rate(), Some(1900));
+        assert_eq!(Country::FI.vat_rate(), Some(2550));
+        assert_eq!(Country::CH.vat_rate(), None);
+    }
+
+    #[test]
+    fn vat_id() {
+        assert!(Country::DE.is_valid_vat_id("DE0123456789"));
+        assert!(Country::GR.is_valid_vat_id("EL123456789"));
+        assert!(!Country::DE.is_valid_vat_id("AT0123456789"));
+        assert!(Country::CH.is_valid_vat_id("CHE123456789"));
+    }
+
+    #[test]
+    fn zip_code() {
+        assert!(Country::DE.is_valid_zip_code("12345"));
+        assert!(!Country::DE.is_valid_zip_code("1234"));
+        assert!(Country::NL.is_valid_zip_code("1234 ab"));
+        assert!(Country::GB.is_valid_zip_code("SW1A 1AA"));
+        assert!(!Country::PL.is_valid_zip_code("12345"));
+        assert!(Country::BR.is_valid_zip_code("anything"));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{email_address::EmailAddressWithName, url::Url};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Email {
+    pub recipient: EmailAddressWithName,
+    pub subject: String,
+    pub body: EmailBody,
+    pub reply_to: Option<EmailAddressWithName>,
+    pub headers: Vec<EmailHeader>,
+    /// Images embedded in the HTML body, referenced via `cid:<content_id>`
+    /// urls.
+    pub inline_images: Vec<EmailInlineImage>,
+}
+
+impl Email {
+    /// Add `List-Unsubscribe` and `List-Unsubscribe-Post` headers to allow
+    /// one-click unsubscription using a `POST` request to the given url.
+    ///
+    /// https://www.rfc-editor.org/rfc/rfc8058
+    pub fn with_list_unsubscribe(mut self, url: &Url) -> Self {
+        self.headers.extend([
+            EmailHeader {
+                name: "List-Unsubscribe".into(),
+                value: format!("<{}>", url.as_str()),
+            },
+            EmailHeader {
+                name: "List-Unsubscribe-Post".into(),
+                value: "List-Unsubscribe=One-Click".into(),
+            },
+        ]);
+        self
+    }
+}
+
+/// A custom email header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailBody {
+    /// A plain text body.
+    Text(String),
+    /// An HTML body without a plain text alternative.
+    Html(String),
+    /// A `multipart/alternative` body containing both a plain text and an
+    /// HTML version of the same content.
+    Alternative { text: String, html: String },
+}
+
+/// An image attached to an email for use in its HTML body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailInlineImage {
+    pub content_id: String,
+    /// The MIME type of the image, e.g. `image/png`.
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
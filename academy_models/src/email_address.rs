@@ -48,6 +48,12 @@ impl EmailAddressWithName {
     }
 }
 
+impl From<EmailAddress> for EmailAddressWithName {
+    fn from(value: EmailAddress) -> Self {
+        Self(lettre::message::Mailbox::new(None, value.0))
+    }
+}
+
 impl FromStr for EmailAddress {
     type Err = <lettre::Address as FromStr>::Err;
 
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{email::Email, macros::id};
+
+id!(EmailOutboxId);
+
+/// An email which has been queued for delivery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailOutboxEntry {
+    pub id: EmailOutboxId,
+    pub email: Email,
+    pub status: EmailOutboxStatus,
+    /// The number of failed delivery attempts.
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    /// The time of the next delivery attempt, `None` if the email has been
+    /// sent or delivery has failed permanently.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub sent_at: Option<DateTime<Utc>>,
+    /// The error of the most recent failed delivery attempt.
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailOutboxStatus {
+    /// The email is waiting to be delivered.
+    Pending,
+    /// The email has been delivered to the smtp server.
+    Sent,
+    /// All delivery attempts have failed.
+    Failed,
+}
@@ -0,0 +1,132 @@
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Utc};
+use nutype::nutype;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    hyphenated_code_regex,
+    macros::{id, nutype_string},
+    user::{UserId, UserTag},
+};
+
+id!(InviteId);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invite {
+    pub id: InviteId,
+    pub code: InviteCode,
+    /// The user who created the invite, `None` if the user has been deleted or
+    /// the invite has been created by an operator via `academy admin invite
+    /// create`.
+    pub created_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// The maximum number of users that can register using this invite,
+    /// `None` if unlimited.
+    pub max_uses: Option<InviteMaxUses>,
+    pub uses: u32,
+    /// Grant admin privileges to users registering with this invite.
+    pub admin: bool,
+    /// Tag to add to the profile of users registering with this invite.
+    pub cohort: Option<UserTag>,
+}
+
+impl Invite {
+    /// Return whether the invite can still be used to register a new user.
+    pub fn is_valid(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_none_or(|expires_at| now < expires_at)
+            && self.max_uses.is_none_or(|max_uses| self.uses < *max_uses)
+    }
+}
+
+nutype_string!(InviteCode(
+    sanitize(trim, uppercase),
+    validate(regex = INVITE_CODE_REGEX),
+));
+
+pub static INVITE_CODE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| hyphenated_code_regex(InviteCode::CHUNK_COUNT, InviteCode::CHUNK_SIZE));
+
+impl InviteCode {
+    pub const CHUNK_COUNT: usize = 3;
+    pub const CHUNK_SIZE: usize = 4;
+}
+
+#[nutype(
+    validate(greater_or_equal = 1),
+    derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Deref,
+        TryFrom,
+        Serialize,
+        Deserialize,
+        JsonSchema
+    )
+)]
+pub struct InviteMaxUses(u32);
+
+/// Controls who is allowed to create a new account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistrationMode {
+    /// Everyone can register, invite codes are optional.
+    #[default]
+    Open,
+    /// A valid invite code is required to register.
+    InviteOnly,
+    /// Registration is disabled.
+    Closed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invite_code_sanitize() {
+        let code = InviteCode::try_new(" ab12-cd34-ef56\n").unwrap();
+        assert_eq!(code.as_str(), "AB12-CD34-EF56");
+    }
+
+    #[test]
+    fn invite_is_valid() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let invite = Invite {
+            id: uuid::Uuid::nil().into(),
+            code: "AAAA-BBBB-CCCC".try_into().unwrap(),
+            created_by: None,
+            created_at: now,
+            expires_at: None,
+            max_uses: None,
+            uses: 42,
+            admin: false,
+            cohort: None,
+        };
+        assert!(invite.is_valid(now));
+
+        let expired = Invite {
+            expires_at: Some(now),
+            ..invite.clone()
+        };
+        assert!(!expired.is_valid(now));
+
+        let exhausted = Invite {
+            max_uses: Some(42.try_into().unwrap()),
+            ..invite.clone()
+        };
+        assert!(!exhausted.is_valid(now));
+
+        let remaining = Invite {
+            max_uses: Some(43.try_into().unwrap()),
+            ..invite
+        };
+        assert!(remaining.is_valid(now));
+    }
+}
@@ -0,0 +1,237 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    country::Country,
+    macros::{id, nutype_string},
+    user::{
+        UserCity, UserFirstName, UserId, UserInvoiceInfo, UserLastName, UserStreet, UserVatId,
+        UserVatIdStatus, UserZipCode,
+    },
+};
+
+id!(InvoiceId);
+
+nutype_string!(InvoiceNumber(validate(len_char_min = 1, len_char_max = 32)));
+nutype_string!(InvoiceDescription(validate(
+    len_char_min = 1,
+    len_char_max = 256
+)));
+
+/// An invoice issued by the academy for a purchase of a user or a self-billed
+/// invoice (credit note) for a payout to a user.
+///
+/// Invoices are immutable, all amounts are in euro cents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invoice {
+    pub id: InvoiceId,
+    pub user_id: UserId,
+    /// Sequential number of the invoice, gap-free per year
+    pub number: InvoiceNumber,
+    pub kind: InvoiceKind,
+    /// Snapshot of the invoice info of the user at the time the invoice was
+    /// issued
+    pub recipient: InvoiceRecipient,
+    pub description: InvoiceDescription,
+    pub net: u64,
+    pub vat: u64,
+    pub gross: u64,
+    /// VAT rate in basis points (1/100 of a percent)
+    pub vat_rate: u32,
+    pub vat_treatment: InvoiceVatTreatment,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceKind {
+    /// The user has bought something from the academy.
+    Purchase,
+    /// The academy has paid out money to the user.
+    Payout,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceVatTreatment {
+    /// VAT is charged at the rate of the invoice.
+    Standard,
+    /// The recipient is liable for the VAT (intra-community supply to a
+    /// business with a valid VAT id).
+    ReverseCharge,
+    /// No VAT is charged, e.g. because the supply is not taxable in the EU or
+    /// the supplier is a private person.
+    NotTaxable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvoiceRecipient {
+    pub business: bool,
+    pub first_name: Option<UserFirstName>,
+    pub last_name: Option<UserLastName>,
+    pub street: Option<UserStreet>,
+    pub zip_code: Option<UserZipCode>,
+    pub city: Option<UserCity>,
+    pub country: Country,
+    pub vat_id: Option<UserVatId>,
+    /// Whether the VAT id has been confirmed by VIES
+    pub vat_id_valid: bool,
+}
+
+impl InvoiceRecipient {
+    /// Take a snapshot of the given invoice info.
+    ///
+    /// Returns `None` if the country is missing, as VAT cannot be determined
+    /// without it.
+    pub fn from_invoice_info(invoice_info: &UserInvoiceInfo) -> Option<Self> {
+        let business = invoice_info.business == Some(true);
+        let vat_id = invoice_info.vat_id.clone().filter(|_| business);
+        let vat_id_valid = vat_id.is_some()
+            && invoice_info
+                .vat_id_validation
+                .as_ref()
+                .is_some_and(|v| v.status == UserVatIdStatus::Valid);
+
+        Some(Self {
+            business,
+            first_name: invoice_info.first_name.clone(),
+            last_name: invoice_info.last_name.clone(),
+            street: invoice_info.street.clone(),
+            zip_code: invoice_info.zip_code.clone(),
+            city: invoice_info.city.clone(),
+            country: invoice_info.country?,
+            vat_id,
+            vat_id_valid,
+        })
+    }
+
+    /// Determine how VAT is handled for an invoice of the given kind issued to
+    /// this recipient by a business in `issuer_country`.
+    ///
+    /// Returns the VAT treatment and the VAT rate in basis points.
+    pub fn vat_treatment(
+        &self,
+        kind: InvoiceKind,
+        issuer_country: Country,
+    ) -> (InvoiceVatTreatment, u32) {
+        let eu = self.country.vat_rate().is_some();
+        let domestic = self.country == issuer_country;
+        let reverse_charge = self.business && self.vat_id_valid;
+
+        match kind {
+            InvoiceKind::Purchase if domestic => (
+                InvoiceVatTreatment::Standard,
+                issuer_country.vat_rate().unwrap_or_default(),
+            ),
+            InvoiceKind::Purchase if eu && reverse_charge => {
+                (InvoiceVatTreatment::ReverseCharge, 0)
+            }
+            // B2C supplies of digital services are taxed in the country of the
+            // recipient
+            InvoiceKind::Purchase if eu => (
+                InvoiceVatTreatment::Standard,
+                self.country.vat_rate().unwrap_or_default(),
+            ),
+            InvoiceKind::Payout if domestic && reverse_charge => (
+                InvoiceVatTreatment::Standard,
+                issuer_country.vat_rate().unwrap_or_default(),
+            ),
+            InvoiceKind::Payout if eu && reverse_charge => (InvoiceVatTreatment::ReverseCharge, 0),
+            InvoiceKind::Purchase | InvoiceKind::Payout => (InvoiceVatTreatment::NotTaxable, 0),
+        }
+    }
+}
+
+/// Split the given gross amount into the net amount and the VAT for the given
+/// VAT rate in basis points, rounding the VAT to the nearest cent.
+pub fn split_gross_amount(gross: u64, vat_rate: u32) -> (u64, u64) {
+    let divisor = 10000 + u128::from(vat_rate);
+    let net = (u128::from(gross) * 10000 * 2 + divisor) / (2 * divisor);
+    let net = net as u64;
+    (net, gross - net)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vat_treatment() {
+        use InvoiceKind::*;
+        use InvoiceVatTreatment::*;
+
+        let private = |country| InvoiceRecipient {
+            business: false,
+            first_name: None,
+            last_name: None,
+            street: None,
+            zip_code: None,
+            city: None,
+            country,
+            vat_id: None,
+            vat_id_valid: false,
+        };
+        let business = |country, vat_id_valid| InvoiceRecipient {
+            business: true,
+            vat_id: Some("XX0123456789".try_into().unwrap()),
+            vat_id_valid,
+            ..private(country)
+        };
+
+        for (recipient, kind, expected) in [
+            (private(Country::DE), Purchase, (Standard, 1900)),
+            (private(Country::AT), Purchase, (Standard, 2000)),
+            (private(Country::CH), Purchase, (NotTaxable, 0)),
+            (business(Country::DE, true), Purchase, (Standard, 1900)),
+            (business(Country::AT, true), Purchase, (ReverseCharge, 0)),
+            (business(Country::AT, false), Purchase, (Standard, 2000)),
+            (business(Country::US, true), Purchase, (NotTaxable, 0)),
+            (private(Country::DE), Payout, (NotTaxable, 0)),
+            (private(Country::AT), Payout, (NotTaxable, 0)),
+            (business(Country::DE, true), Payout, (Standard, 1900)),
+            (business(Country::DE, false), Payout, (NotTaxable, 0)),
+            (business(Country::AT, true), Payout, (ReverseCharge, 0)),
+            (business(Country::CH, true), Payout, (NotTaxable, 0)),
+        ] {
+            assert_eq!(
+                recipient.vat_treatment(kind, Country::DE),
+                expected,
+                "{kind:?} {recipient:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn from_invoice_info() {
+        assert_eq!(
+            InvoiceRecipient::from_invoice_info(&UserInvoiceInfo::default()),
+            None
+        );
+
+        let recipient = InvoiceRecipient::from_invoice_info(&UserInvoiceInfo {
+            business: Some(false),
+            country: Some(Country::DE),
+            vat_id: Some("DE0123456789".try_into().unwrap()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(!recipient.business);
+        assert_eq!(recipient.vat_id, None);
+        assert!(!recipient.vat_id_valid);
+    }
+
+    #[test]
+    fn split_gross_amount() {
+        for (gross, vat_rate, expected) in [
+            (950, 1900, (798, 152)),
+            (119, 1900, (100, 19)),
+            (1000, 0, (1000, 0)),
+            (0, 1900, (0, 0)),
+            (4500, 2550, (3586, 914)),
+            (u64::MAX, 0, (u64::MAX, 0)),
+        ] {
+            assert_eq!(super::split_gross_amount(gross, vat_rate), expected);
+        }
+    }
+}
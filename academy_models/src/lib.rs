@@ -6,13 +6,24 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 pub mod auth;
+pub mod captcha;
+pub mod checkout;
+pub mod coin;
 pub mod contact;
+pub mod country;
+pub mod email;
 pub mod email_address;
+pub mod email_outbox;
+pub mod invite;
+pub mod invoice;
+pub mod locale;
 mod macros;
 pub mod mfa;
+pub mod newsletter;
 pub mod oauth2;
 pub mod pagination;
 pub mod session;
+pub mod skill;
 pub mod url;
 pub mod user;
 
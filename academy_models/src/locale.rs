@@ -0,0 +1,125 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::macros::nutype_string;
+
+pub static LOCALE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^[a-z]{2,3}(-[A-Z]{2})?$").unwrap());
+
+nutype_string!(Locale(
+    sanitize(with = normalize_locale),
+    validate(regex = LOCALE_REGEX),
+));
+
+impl Locale {
+    /// The locale used if no translation is available for the requested
+    /// locale.
+    pub const FALLBACK: &'static str = "en";
+
+    /// Return the language subtag of this locale (e.g. `de` for `de-AT`).
+    pub fn language(&self) -> &str {
+        self.split('-').next().unwrap_or_default()
+    }
+
+    /// Return this locale followed by the less specific locales to try if no
+    /// translation is available, ending with [`Locale::FALLBACK`].
+    ///
+    /// For example, `de-AT` yields `de-AT`, `de` and `en`.
+    pub fn fallbacks(&self) -> impl Iterator<Item = &str> {
+        let mut fallbacks = vec![self.as_str()];
+        for locale in [self.language(), Self::FALLBACK] {
+            if !fallbacks.contains(&locale) {
+                fallbacks.push(locale);
+            }
+        }
+        fallbacks.into_iter()
+    }
+
+    /// Select the most preferred locale from the value of an
+    /// `Accept-Language` header.
+    ///
+    /// Wildcards and invalid language ranges are ignored.
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut candidates = header
+            .split(',')
+            .enumerate()
+            .filter_map(|(i, range)| {
+                let mut parts = range.split(';');
+                let locale = Self::try_new(parts.next()?.trim()).ok()?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                (quality > 0.0).then_some((locale, quality, i))
+            })
+            .collect::<Vec<_>>();
+
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)));
+        candidates.into_iter().next().map(|(locale, _, _)| locale)
+    }
+}
+
+fn normalize_locale(locale: String) -> String {
+    let locale = locale.trim().replace('_', "-");
+    match locale.split_once('-') {
+        Some((language, region)) => {
+            format!("{}-{}", language.to_lowercase(), region.to_uppercase())
+        }
+        None => locale.to_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        for (input, expected) in [
+            ("de", "de"),
+            ("EN", "en"),
+            ("de_at", "de-AT"),
+            (" en-us ", "en-US"),
+        ] {
+            assert_eq!(Locale::try_new(input).unwrap().as_str(), expected);
+        }
+
+        for input in ["", "*", "german", "de-", "de-AT-x"] {
+            assert!(Locale::try_new(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn fallbacks() {
+        for (locale, expected) in [
+            ("de-AT", &["de-AT", "de", "en"][..]),
+            ("de", &["de", "en"]),
+            ("en-GB", &["en-GB", "en"]),
+            ("en", &["en"]),
+        ] {
+            let locale = Locale::try_new(locale).unwrap();
+            assert_eq!(locale.fallbacks().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn from_accept_language() {
+        for (header, expected) in [
+            ("de-DE,de;q=0.9,en-US;q=0.8,en;q=0.7", Some("de-DE")),
+            ("en;q=0.5, fr;q=0.8", Some("fr")),
+            ("*, en;q=0.1", Some("en")),
+            ("de;q=0, en", Some("en")),
+            ("en, de", Some("en")),
+            ("*", None),
+            ("", None),
+        ] {
+            assert_eq!(
+                Locale::from_accept_language(header)
+                    .as_ref()
+                    .map(|x| x.as_str()),
+                expected,
+                "{header}"
+            );
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use academy_utils::patch::Patch;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    email_address::EmailAddressWithName,
+    macros::{id, nutype_string},
+    user::UserId,
+};
+
+id!(NewsletterCampaignId);
+
+#[derive(Debug, Clone, PartialEq, Eq, Patch)]
+pub struct NewsletterCampaign {
+    #[no_patch]
+    pub id: NewsletterCampaignId,
+    pub subject: NewsletterCampaignSubject,
+    /// HTML content of the newsletter, embedded into the newsletter template.
+    pub body_html: NewsletterCampaignBody,
+    /// Plain text content of the newsletter.
+    pub body_text: NewsletterCampaignBody,
+    #[no_patch]
+    pub status: NewsletterCampaignStatus,
+    #[no_patch]
+    pub created_at: DateTime<Utc>,
+    #[no_patch]
+    pub started_at: Option<DateTime<Utc>>,
+    #[no_patch]
+    pub completed_at: Option<DateTime<Utc>>,
+    #[no_patch]
+    pub progress: NewsletterCampaignProgress,
+}
+
+nutype_string!(NewsletterCampaignSubject(validate(
+    len_char_min = 1,
+    len_char_max = 256
+)));
+
+nutype_string!(NewsletterCampaignBody(validate(len_char_max = 65536)));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NewsletterCampaignStatus {
+    /// The campaign can still be edited and has not been sent yet.
+    Draft,
+    /// The campaign is currently being sent to its recipients.
+    Sending,
+    /// The campaign has been sent to all recipients.
+    Sent,
+}
+
+/// Number of recipients of a campaign by delivery status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NewsletterCampaignProgress {
+    pub pending: u64,
+    pub sent: u64,
+    pub failed: u64,
+    pub skipped: u64,
+}
+
+impl NewsletterCampaignProgress {
+    pub fn total(&self) -> u64 {
+        self.pending + self.sent + self.failed + self.skipped
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NewsletterRecipientStatus {
+    /// The newsletter has not been sent to the recipient yet.
+    Pending,
+    /// The newsletter has been accepted by the smtp server.
+    Sent,
+    /// The newsletter could not be delivered to the recipient.
+    Failed,
+    /// The recipient has unsubscribed from the newsletter or no longer has a
+    /// verified email address.
+    Skipped,
+}
+
+/// A recipient of a campaign who has not received the newsletter yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewsletterRecipient {
+    pub user_id: UserId,
+    pub email: EmailAddressWithName,
+}
+
+/// A campaign rendered for a specific recipient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewsletterCampaignPreview {
+    pub subject: NewsletterCampaignSubject,
+    pub body_html: String,
+    pub body_text: String,
+}
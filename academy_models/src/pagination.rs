@@ -1,4 +1,8 @@
 use nutype::nutype;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::macros::nutype_string;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct PaginationSlice {
@@ -6,6 +10,49 @@ pub struct PaginationSlice {
     pub offset: u64,
 }
 
+/// Either offset based or keyset based pagination.
+///
+/// Keyset pagination selects the items after the position `key` and stays
+/// consistent while new items are being inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pagination<K> {
+    Offset(PaginationSlice),
+    After { limit: PaginationLimit, key: K },
+}
+
+impl<K> Pagination<K> {
+    pub fn limit(&self) -> PaginationLimit {
+        match self {
+            Self::Offset(slice) => slice.limit,
+            Self::After { limit, .. } => *limit,
+        }
+    }
+}
+
+// no `#[derive(Default)]` to avoid the `K: Default` bound
+impl<K> Default for Pagination<K> {
+    fn default() -> Self {
+        Self::Offset(Default::default())
+    }
+}
+
+impl<K> From<PaginationSlice> for Pagination<K> {
+    fn from(value: PaginationSlice) -> Self {
+        Self::Offset(value)
+    }
+}
+
+// Opaque and signed keyset pagination cursor
+nutype_string!(PaginationCursor);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
 #[nutype(
     validate(less_or_equal = PaginationLimit::MAX),
     derive(Debug, Clone, Copy, PartialEq, Eq, Deref, TryFrom, Serialize, Deserialize, JsonSchema)
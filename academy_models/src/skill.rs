@@ -0,0 +1,150 @@
+use std::ops::AddAssign;
+
+use academy_utils::patch::Patch;
+use chrono::{DateTime, Utc};
+
+use crate::macros::{id, nutype_string};
+
+id!(SkillId);
+id!(CourseId);
+id!(CourseSectionId);
+id!(LectureId);
+
+/// A node of the skill tree, which is learned by completing its courses.
+#[derive(Debug, Clone, PartialEq, Eq, Patch)]
+pub struct Skill {
+    #[no_patch]
+    pub id: SkillId,
+    pub title: SkillTitle,
+    pub description: SkillDescription,
+    #[no_patch]
+    pub created_at: DateTime<Utc>,
+}
+
+nutype_string!(SkillTitle(validate(len_char_min = 1, len_char_max = 256)));
+nutype_string!(SkillDescription(validate(len_char_max = 4096)));
+
+/// An edge of the skill tree: `prerequisite_id` has to be completed before
+/// `skill_id` is unlocked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SkillPrerequisite {
+    pub skill_id: SkillId,
+    pub prerequisite_id: SkillId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Patch)]
+pub struct Course {
+    #[no_patch]
+    pub id: CourseId,
+    /// The skill which is learned by this course
+    #[no_patch]
+    pub skill_id: SkillId,
+    pub title: CourseTitle,
+    pub description: CourseDescription,
+    #[no_patch]
+    pub created_at: DateTime<Utc>,
+}
+
+nutype_string!(CourseTitle(validate(len_char_min = 1, len_char_max = 256)));
+nutype_string!(CourseDescription(validate(len_char_max = 4096)));
+
+#[derive(Debug, Clone, PartialEq, Eq, Patch)]
+pub struct CourseSection {
+    #[no_patch]
+    pub id: CourseSectionId,
+    #[no_patch]
+    pub course_id: CourseId,
+    pub title: CourseSectionTitle,
+    /// Sections are ordered by their position within a course
+    #[no_patch]
+    pub position: u32,
+}
+
+nutype_string!(CourseSectionTitle(validate(
+    len_char_min = 1,
+    len_char_max = 256
+)));
+
+#[derive(Debug, Clone, PartialEq, Eq, Patch)]
+pub struct Lecture {
+    #[no_patch]
+    pub id: LectureId,
+    #[no_patch]
+    pub section_id: CourseSectionId,
+    pub title: LectureTitle,
+    pub description: LectureDescription,
+    /// Lectures are ordered by their position within a section
+    #[no_patch]
+    pub position: u32,
+}
+
+nutype_string!(LectureTitle(validate(len_char_min = 1, len_char_max = 256)));
+nutype_string!(LectureDescription(validate(len_char_max = 4096)));
+
+/// The number of lectures of a course or skill which have been completed by
+/// a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LearningProgress {
+    pub completed_lectures: u64,
+    pub total_lectures: u64,
+}
+
+impl LearningProgress {
+    /// Return the percentage of completed lectures, rounded down.
+    ///
+    /// Courses and skills without any lectures are considered complete.
+    pub fn percent(&self) -> u8 {
+        if self.is_completed() {
+            return 100;
+        }
+        (self.completed_lectures * 100 / self.total_lectures) as u8
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed_lectures >= self.total_lectures
+    }
+}
+
+impl AddAssign for LearningProgress {
+    fn add_assign(&mut self, rhs: Self) {
+        self.completed_lectures += rhs.completed_lectures;
+        self.total_lectures += rhs.total_lectures;
+    }
+}
+
+/// The progress of a user in a skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillProgress {
+    pub skill_id: SkillId,
+    /// The combined progress in all courses of the skill
+    pub progress: LearningProgress,
+    /// Whether all prerequisites of the skill have been completed
+    pub unlocked: bool,
+}
+
+/// The progress of a user in a course.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseProgress {
+    pub course_id: CourseId,
+    pub progress: LearningProgress,
+    pub completed_lectures: Vec<LectureId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent() {
+        for (completed_lectures, total_lectures, expected) in
+            [(0, 0, 100), (0, 3, 0), (1, 3, 33), (2, 3, 66), (3, 3, 100)]
+        {
+            let progress = LearningProgress {
+                completed_lectures,
+                total_lectures,
+            };
+            assert_eq!(progress.percent(), expected);
+            assert_eq!(progress.is_completed(), expected == 100);
+        }
+    }
+}
@@ -8,8 +8,13 @@ use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    country::Country,
     email_address::EmailAddress,
+    locale::Locale,
     macros::{id, nutype_string},
+    oauth2::OAuth2ProviderId,
+    pagination::SortDirection,
+    url::Url,
     SearchTerm,
 };
 
@@ -61,6 +66,8 @@ pub struct User {
     pub enabled: bool,
     pub admin: bool,
     pub newsletter: bool,
+    /// Preferred language of the user, e.g. for emails
+    pub locale: Locale,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Patch)]
@@ -68,6 +75,8 @@ pub struct UserProfile {
     pub display_name: UserDisplayName,
     pub bio: UserBio,
     pub tags: UserTags,
+    #[no_patch]
+    pub avatar_url: Option<Url>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -85,8 +94,77 @@ pub struct UserInvoiceInfo {
     pub street: Option<UserStreet>,
     pub zip_code: Option<UserZipCode>,
     pub city: Option<UserCity>,
-    pub country: Option<UserCountry>,
+    pub country: Option<Country>,
     pub vat_id: Option<UserVatId>,
+    /// Result of the last validation of `vat_id`
+    #[no_patch]
+    pub vat_id_validation: Option<UserVatIdValidation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserVatIdValidation {
+    pub status: UserVatIdStatus,
+    /// Consultation number returned by VIES as proof of the validation
+    pub consultation_number: Option<String>,
+    pub validated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserVatIdStatus {
+    /// The VAT id has been confirmed by VIES
+    Valid,
+    /// VIES was unavailable, the VAT id will be validated again later
+    Pending,
+    /// The VAT id has been rejected by VIES
+    Invalid,
+}
+
+/// Controls which fields of a user's profile are shown on their public profile
+/// page. The user's name is always visible.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Patch)]
+pub struct UserPrivacy {
+    pub display_name: UserProfileVisibility,
+    pub bio: UserProfileVisibility,
+    pub tags: UserProfileVisibility,
+    pub registration: UserProfileVisibility,
+    pub avatar: UserProfileVisibility,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserProfileVisibility {
+    /// Visible to everyone, including unauthenticated visitors
+    #[default]
+    Public,
+    /// Only visible to authenticated users
+    Users,
+    /// Only visible to the user themselves and administrators
+    Private,
+}
+
+impl UserProfileVisibility {
+    /// Return whether a field with this visibility is shown to a visitor who is
+    /// neither the user themselves nor an administrator.
+    pub fn is_visible(self, authenticated: bool) -> bool {
+        match self {
+            Self::Public => true,
+            Self::Users => authenticated,
+            Self::Private => false,
+        }
+    }
+}
+
+/// The parts of a user's profile that are visible to a specific visitor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserPublicProfile {
+    pub id: UserId,
+    pub name: UserName,
+    pub display_name: Option<UserDisplayName>,
+    pub bio: Option<UserBio>,
+    pub tags: Option<UserTags>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub avatar_url: Option<Url>,
 }
 
 impl UserComposite {
@@ -99,7 +177,13 @@ impl UserComposite {
             && self.invoice_info.zip_code.is_some()
             && self.invoice_info.city.is_some()
             && self.invoice_info.country.is_some()
-            && (self.invoice_info.business != Some(true) || self.invoice_info.vat_id.is_some())
+            && (self.invoice_info.business != Some(true)
+                || (self.invoice_info.vat_id.is_some()
+                    && self
+                        .invoice_info
+                        .vat_id_validation
+                        .as_ref()
+                        .is_none_or(|v| v.status != UserVatIdStatus::Invalid)))
     }
 
     pub fn can_buy_coins(&self) -> bool {
@@ -161,11 +245,14 @@ nutype_string!(UserLastName(validate(len_char_max = 128)));
 nutype_string!(UserStreet(validate(len_char_max = 256)));
 nutype_string!(UserZipCode(validate(len_char_max = 16)));
 nutype_string!(UserCity(validate(len_char_max = 64)));
-nutype_string!(UserCountry(validate(len_char_max = 64)));
 nutype_string!(UserVatId(validate(len_char_max = 64)));
 
+nutype_string!(NewsletterUnsubscribeToken);
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct UserFilter {
+    /// Fuzzy search across name, display name, email and invoice name
+    pub query: Option<SearchTerm>,
     pub name: Option<SearchTerm>,
     pub email: Option<SearchTerm>,
     pub enabled: Option<bool>,
@@ -173,6 +260,50 @@ pub struct UserFilter {
     pub mfa_enabled: Option<bool>,
     pub email_verified: Option<bool>,
     pub newsletter: Option<bool>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub last_login_after: Option<DateTime<Utc>>,
+    pub last_login_before: Option<DateTime<Utc>>,
+    /// Only include users with at least one link to this OAuth2 provider
+    pub oauth2_provider: Option<OAuth2ProviderId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct UserSorting {
+    pub field: UserSortField,
+    pub direction: SortDirection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSortField {
+    #[default]
+    CreatedAt,
+    LastLogin,
+    Name,
+}
+
+/// Position of a user in a list sorted by a [`UserSortField`], used for keyset
+/// pagination. Ties are broken by the case insensitive name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserListKey {
+    /// The value of the sort field (`None` if the user has never logged in or
+    /// if sorting by name)
+    pub value: Option<DateTime<Utc>>,
+    pub name: UserName,
+}
+
+impl UserListKey {
+    pub fn new(user: &User, field: UserSortField) -> Self {
+        Self {
+            value: match field {
+                UserSortField::CreatedAt => Some(user.created_at),
+                UserSortField::LastLogin => user.last_login,
+                UserSortField::Name => None,
+            },
+            name: user.name.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,191 @@
+use std::future::Future;
+
+use academy_models::{
+    checkout::{CoinOrder, CoinOrderId, CoinOrderStatus, PaymentId},
+    invoice::InvoiceId,
+    pagination::PaginationSlice,
+    user::UserId,
+};
+use chrono::{DateTime, Utc};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait CheckoutRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Create a new coin order.
+    fn create_order(
+        &self,
+        txn: &mut Txn,
+        order: &CoinOrder,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Return the coin order with the given id.
+    fn get_order(
+        &self,
+        txn: &mut Txn,
+        order_id: CoinOrderId,
+    ) -> impl Future<Output = anyhow::Result<Option<CoinOrder>>> + Send;
+
+    /// Return the coin order which is paid by the given payment.
+    fn get_order_by_payment_id(
+        &self,
+        txn: &mut Txn,
+        payment_id: &PaymentId,
+    ) -> impl Future<Output = anyhow::Result<Option<CoinOrder>>> + Send;
+
+    /// Return the most recent coin orders of a user.
+    fn list_orders(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        pagination: PaginationSlice,
+    ) -> impl Future<Output = anyhow::Result<Vec<CoinOrder>>> + Send;
+
+    /// Return the number of coin orders of a user.
+    fn count_orders(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+
+    /// Change the status of a coin order, but only if it still has the
+    /// expected status.
+    ///
+    /// Returns `false` if the order does not exist or has a different status,
+    /// in which case nothing is changed.
+    fn update_order_status(
+        &self,
+        txn: &mut Txn,
+        order_id: CoinOrderId,
+        expected: CoinOrderStatus,
+        status: CoinOrderStatus,
+        updated_at: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return all completed coin orders for which no invoice has been issued
+    /// yet, oldest first.
+    fn list_orders_without_invoice(
+        &self,
+        txn: &mut Txn,
+    ) -> impl Future<Output = anyhow::Result<Vec<CoinOrder>>> + Send;
+
+    /// Link the invoice issued for a coin order to the order.
+    ///
+    /// Returns `false` if the order does not exist or already has an invoice,
+    /// in which case nothing is changed.
+    fn set_order_invoice(
+        &self,
+        txn: &mut Txn,
+        order_id: CoinOrderId,
+        invoice_id: InvoiceId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockCheckoutRepository<Txn> {
+    pub fn with_create_order(mut self, order: CoinOrder) -> Self {
+        self.expect_create_order()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(order))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_get_order(mut self, order_id: CoinOrderId, result: Option<CoinOrder>) -> Self {
+        self.expect_get_order()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(order_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_order_by_payment_id(
+        mut self,
+        payment_id: PaymentId,
+        result: Option<CoinOrder>,
+    ) -> Self {
+        self.expect_get_order_by_payment_id()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(payment_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_orders(
+        mut self,
+        user_id: UserId,
+        pagination: PaginationSlice,
+        result: Vec<CoinOrder>,
+    ) -> Self {
+        self.expect_list_orders()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(pagination),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_count_orders(mut self, user_id: UserId, result: u64) -> Self {
+        self.expect_count_orders()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_update_order_status(
+        mut self,
+        order_id: CoinOrderId,
+        expected: CoinOrderStatus,
+        status: CoinOrderStatus,
+        updated_at: DateTime<Utc>,
+        result: bool,
+    ) -> Self {
+        self.expect_update_order_status()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(order_id),
+                mockall::predicate::eq(expected),
+                mockall::predicate::eq(status),
+                mockall::predicate::eq(updated_at),
+            )
+            .return_once(move |_, _, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_orders_without_invoice(mut self, result: Vec<CoinOrder>) -> Self {
+        self.expect_list_orders_without_invoice()
+            .once()
+            .with(mockall::predicate::always())
+            .return_once(|_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_set_order_invoice(
+        mut self,
+        order_id: CoinOrderId,
+        invoice_id: InvoiceId,
+        result: bool,
+    ) -> Self {
+        self.expect_set_order_invoice()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(order_id),
+                mockall::predicate::eq(invoice_id),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
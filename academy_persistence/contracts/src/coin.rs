@@ -0,0 +1,148 @@
+use std::future::Future;
+
+use academy_models::{
+    coin::{CoinBalance, CoinTransaction, CoinTransactionKind, CoinTransactionReference},
+    pagination::PaginationSlice,
+    user::UserId,
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait CoinRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Return the coin balance of a user.
+    ///
+    /// Users who have never had any coins have an empty balance.
+    fn get_balance(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<CoinBalance>> + Send;
+
+    /// Append a transaction to the ledger and update the balance of its user
+    /// accordingly.
+    ///
+    /// Nothing is changed if the transaction would overdraw one of the
+    /// accounts of the user or if the user already has a transaction with the
+    /// same reference (see [`get_transaction_by_reference`]). In the latter
+    /// case, the existing transaction is returned, even if it has been created
+    /// by a concurrent transaction.
+    ///
+    /// [`get_transaction_by_reference`]: Self::get_transaction_by_reference
+    fn create_transaction(
+        &self,
+        txn: &mut Txn,
+        transaction: &CoinTransaction,
+    ) -> impl Future<Output = anyhow::Result<CoinTransactionCreateResult>> + Send;
+
+    /// Return the transaction of a user with the given kind and reference.
+    ///
+    /// Withheld transactions are returned for [`CoinTransactionKind::Earn`]
+    /// and vice versa.
+    fn get_transaction_by_reference(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        kind: CoinTransactionKind,
+        reference: &CoinTransactionReference,
+    ) -> impl Future<Output = anyhow::Result<Option<CoinTransaction>>> + Send;
+
+    /// Return the most recent transactions of a user.
+    fn list_transactions(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        pagination: PaginationSlice,
+    ) -> impl Future<Output = anyhow::Result<Vec<CoinTransaction>>> + Send;
+
+    /// Return the number of transactions of a user.
+    fn count_transactions(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoinTransactionCreateResult {
+    /// The transaction has been created. Contains the new balance of the user.
+    Created(CoinBalance),
+    /// The transaction would overdraw one of the accounts of the user.
+    Overdrawn,
+    /// The user already has a transaction with the same reference.
+    Duplicate(CoinTransaction),
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockCoinRepository<Txn> {
+    pub fn with_get_balance(mut self, user_id: UserId, result: CoinBalance) -> Self {
+        self.expect_get_balance()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_transaction(
+        mut self,
+        transaction: CoinTransaction,
+        result: CoinTransactionCreateResult,
+    ) -> Self {
+        self.expect_create_transaction()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(transaction),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_transaction_by_reference(
+        mut self,
+        user_id: UserId,
+        kind: CoinTransactionKind,
+        reference: CoinTransactionReference,
+        result: Option<CoinTransaction>,
+    ) -> Self {
+        self.expect_get_transaction_by_reference()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(kind),
+                mockall::predicate::eq(reference),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_transactions(
+        mut self,
+        user_id: UserId,
+        pagination: PaginationSlice,
+        result: Vec<CoinTransaction>,
+    ) -> Self {
+        self.expect_list_transactions()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(pagination),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_count_transactions(mut self, user_id: UserId, result: u64) -> Self {
+        self.expect_count_transactions()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
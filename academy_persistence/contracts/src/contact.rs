@@ -0,0 +1,183 @@
+use std::future::Future;
+
+use academy_models::{
+    contact::{
+        ContactInboxMessage, ContactMessageFilter, ContactMessageId, ContactMessageReply,
+        ContactMessageStatus,
+    },
+    pagination::PaginationSlice,
+    user::UserId,
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait ContactRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Return the most recent contact messages matching the given filter.
+    fn list_messages(
+        &self,
+        txn: &mut Txn,
+        filter: ContactMessageFilter,
+        pagination: PaginationSlice,
+    ) -> impl Future<Output = anyhow::Result<Vec<ContactInboxMessage>>> + Send;
+
+    /// Return the number of contact messages matching the given filter.
+    fn count_messages(
+        &self,
+        txn: &mut Txn,
+        filter: ContactMessageFilter,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+
+    /// Return the contact message with the given id.
+    fn get_message(
+        &self,
+        txn: &mut Txn,
+        message_id: ContactMessageId,
+    ) -> impl Future<Output = anyhow::Result<Option<ContactInboxMessage>>> + Send;
+
+    /// Create a new contact message.
+    fn create_message(
+        &self,
+        txn: &mut Txn,
+        message: &ContactInboxMessage,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Update the admin responsible for answering a contact message.
+    fn update_message_assignee(
+        &self,
+        txn: &mut Txn,
+        message_id: ContactMessageId,
+        assignee: Option<UserId>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Update the status of a contact message.
+    fn update_message_status(
+        &self,
+        txn: &mut Txn,
+        message_id: ContactMessageId,
+        status: ContactMessageStatus,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return all replies to a contact message, oldest first.
+    fn list_replies(
+        &self,
+        txn: &mut Txn,
+        message_id: ContactMessageId,
+    ) -> impl Future<Output = anyhow::Result<Vec<ContactMessageReply>>> + Send;
+
+    /// Create a new reply to a contact message.
+    fn create_reply(
+        &self,
+        txn: &mut Txn,
+        reply: &ContactMessageReply,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockContactRepository<Txn> {
+    pub fn with_list_messages(
+        mut self,
+        filter: ContactMessageFilter,
+        pagination: PaginationSlice,
+        result: Vec<ContactInboxMessage>,
+    ) -> Self {
+        self.expect_list_messages()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(filter),
+                mockall::predicate::eq(pagination),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_count_messages(mut self, filter: ContactMessageFilter, result: u64) -> Self {
+        self.expect_count_messages()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(filter))
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_message(
+        mut self,
+        message_id: ContactMessageId,
+        result: Option<ContactInboxMessage>,
+    ) -> Self {
+        self.expect_get_message()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(message_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_message(mut self, message: ContactInboxMessage) -> Self {
+        self.expect_create_message()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(message),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_update_message_assignee(
+        mut self,
+        message_id: ContactMessageId,
+        assignee: Option<UserId>,
+        result: bool,
+    ) -> Self {
+        self.expect_update_message_assignee()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(message_id),
+                mockall::predicate::eq(assignee),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_update_message_status(
+        mut self,
+        message_id: ContactMessageId,
+        status: ContactMessageStatus,
+        result: bool,
+    ) -> Self {
+        self.expect_update_message_status()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(message_id),
+                mockall::predicate::eq(status),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_replies(
+        mut self,
+        message_id: ContactMessageId,
+        result: Vec<ContactMessageReply>,
+    ) -> Self {
+        self.expect_list_replies()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(message_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_reply(mut self, reply: ContactMessageReply) -> Self {
+        self.expect_create_reply()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(reply))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+}
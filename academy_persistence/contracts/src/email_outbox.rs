@@ -0,0 +1,214 @@
+use std::future::Future;
+
+use academy_models::{
+    email_outbox::{EmailOutboxEntry, EmailOutboxId, EmailOutboxStatus},
+    pagination::PaginationSlice,
+};
+use chrono::{DateTime, Utc};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait EmailOutboxRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Return the most recent emails, optionally only those with the given
+    /// status.
+    fn list(
+        &self,
+        txn: &mut Txn,
+        status: Option<EmailOutboxStatus>,
+        pagination: PaginationSlice,
+    ) -> impl Future<Output = anyhow::Result<Vec<EmailOutboxEntry>>> + Send;
+
+    /// Return the number of emails, optionally only those with the given
+    /// status.
+    fn count(
+        &self,
+        txn: &mut Txn,
+        status: Option<EmailOutboxStatus>,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+
+    /// Return the email with the given id.
+    fn get(
+        &self,
+        txn: &mut Txn,
+        email_id: EmailOutboxId,
+    ) -> impl Future<Output = anyhow::Result<Option<EmailOutboxEntry>>> + Send;
+
+    /// Add a new email to the outbox.
+    fn create(
+        &self,
+        txn: &mut Txn,
+        entry: &EmailOutboxEntry,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Return up to `limit` pending emails whose next delivery attempt is due
+    /// and lock them until the end of the transaction.
+    ///
+    /// Emails which are already locked by another transaction are skipped.
+    fn lock_due(
+        &self,
+        txn: &mut Txn,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = anyhow::Result<Vec<EmailOutboxEntry>>> + Send;
+
+    /// Mark an email as sent.
+    fn mark_sent(
+        &self,
+        txn: &mut Txn,
+        email_id: EmailOutboxId,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Record a failed delivery attempt and schedule the next attempt at the
+    /// given time.
+    ///
+    /// If `next_attempt_at` is `None`, the email is marked as failed.
+    fn record_failed_attempt(
+        &self,
+        txn: &mut Txn,
+        email_id: EmailOutboxId,
+        error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Reset the delivery attempts of an email which has not been sent yet
+    /// and schedule it for immediate delivery.
+    ///
+    /// Returns `false` if the email does not exist or has already been sent.
+    fn retry(
+        &self,
+        txn: &mut Txn,
+        email_id: EmailOutboxId,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Delete all emails which have been sent before the given time.
+    ///
+    /// Returns the number of deleted emails.
+    fn delete_sent_before(
+        &self,
+        txn: &mut Txn,
+        sent_before: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockEmailOutboxRepository<Txn> {
+    pub fn with_list(
+        mut self,
+        status: Option<EmailOutboxStatus>,
+        pagination: PaginationSlice,
+        result: Vec<EmailOutboxEntry>,
+    ) -> Self {
+        self.expect_list()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(status),
+                mockall::predicate::eq(pagination),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_count(mut self, status: Option<EmailOutboxStatus>, result: u64) -> Self {
+        self.expect_count()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(status))
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get(mut self, email_id: EmailOutboxId, result: Option<EmailOutboxEntry>) -> Self {
+        self.expect_get()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(email_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create(mut self, entry: EmailOutboxEntry) -> Self {
+        self.expect_create()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(entry))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_lock_due(
+        mut self,
+        now: DateTime<Utc>,
+        limit: u64,
+        result: Vec<EmailOutboxEntry>,
+    ) -> Self {
+        self.expect_lock_due()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(now),
+                mockall::predicate::eq(limit),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_mark_sent(mut self, email_id: EmailOutboxId, now: DateTime<Utc>) -> Self {
+        self.expect_mark_sent()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(email_id),
+                mockall::predicate::eq(now),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_mark_sent_error(mut self, email_id: EmailOutboxId, now: DateTime<Utc>) -> Self {
+        self.expect_mark_sent()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(email_id),
+                mockall::predicate::eq(now),
+            )
+            .return_once(|_, _, _| {
+                Box::pin(std::future::ready(Err(anyhow::anyhow!(
+                    "Failed to connect to the database"
+                ))))
+            });
+        self
+    }
+
+    pub fn with_record_failed_attempt(
+        mut self,
+        email_id: EmailOutboxId,
+        error: String,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.expect_record_failed_attempt()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(email_id),
+                mockall::predicate::eq(error),
+                mockall::predicate::eq(next_attempt_at),
+            )
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_retry(mut self, email_id: EmailOutboxId, now: DateTime<Utc>, result: bool) -> Self {
+        self.expect_retry()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(email_id),
+                mockall::predicate::eq(now),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
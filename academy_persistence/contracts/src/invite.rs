@@ -0,0 +1,173 @@
+use std::future::Future;
+
+use academy_models::{
+    invite::{Invite, InviteCode, InviteId},
+    user::UserId,
+};
+use chrono::{DateTime, Utc};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait InviteRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Return all invites, optionally only those created by the given user.
+    fn list(
+        &self,
+        txn: &mut Txn,
+        created_by: Option<UserId>,
+    ) -> impl Future<Output = anyhow::Result<Vec<Invite>>> + Send;
+
+    /// Return the number of invites created by the given user which can still
+    /// be used at the given time.
+    fn count_valid_by_creator(
+        &self,
+        txn: &mut Txn,
+        created_by: UserId,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+
+    /// Return the invite with the given id.
+    fn get(
+        &self,
+        txn: &mut Txn,
+        invite_id: InviteId,
+    ) -> impl Future<Output = anyhow::Result<Option<Invite>>> + Send;
+
+    /// Return the invite with the given code.
+    fn get_by_code(
+        &self,
+        txn: &mut Txn,
+        code: &InviteCode,
+    ) -> impl Future<Output = anyhow::Result<Option<Invite>>> + Send;
+
+    /// Create a new invite.
+    fn create(
+        &self,
+        txn: &mut Txn,
+        invite: &Invite,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Delete an invite.
+    fn delete(
+        &self,
+        txn: &mut Txn,
+        invite_id: InviteId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Increment the number of uses of an invite and record that the given
+    /// user has registered using it.
+    ///
+    /// Returns `false` and leaves the invite unchanged if it has expired or its
+    /// usage limit has been reached at the given time.
+    fn redeem(
+        &self,
+        txn: &mut Txn,
+        invite_id: InviteId,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return the id of the invite the given user has registered with.
+    fn get_invite_id_by_user(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<Option<InviteId>>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockInviteRepository<Txn> {
+    pub fn with_list(mut self, created_by: Option<UserId>, result: Vec<Invite>) -> Self {
+        self.expect_list()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(created_by),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_count_valid_by_creator(
+        mut self,
+        created_by: UserId,
+        now: DateTime<Utc>,
+        result: u64,
+    ) -> Self {
+        self.expect_count_valid_by_creator()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(created_by),
+                mockall::predicate::eq(now),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get(mut self, invite_id: InviteId, result: Option<Invite>) -> Self {
+        self.expect_get()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(invite_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_by_code(mut self, code: InviteCode, result: Option<Invite>) -> Self {
+        self.expect_get_by_code()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(code))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create(mut self, invite: Invite) -> Self {
+        self.expect_create()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(invite))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_delete(mut self, invite_id: InviteId, result: bool) -> Self {
+        self.expect_delete()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(invite_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_redeem(
+        mut self,
+        invite_id: InviteId,
+        user_id: UserId,
+        now: DateTime<Utc>,
+        result: bool,
+    ) -> Self {
+        self.expect_redeem()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(invite_id),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(now),
+            )
+            .return_once(move |_, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_invite_id_by_user(mut self, user_id: UserId, result: Option<InviteId>) -> Self {
+        self.expect_get_invite_id_by_user()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
@@ -0,0 +1,111 @@
+use std::future::Future;
+
+use academy_models::{
+    invoice::{Invoice, InvoiceId},
+    pagination::PaginationSlice,
+    user::UserId,
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait InvoiceRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Reserve the next invoice number of the given year.
+    ///
+    /// Numbers start at 1 and are only consumed if the transaction is
+    /// committed, so the numbers of each year are gap-free. Concurrent
+    /// transactions reserving a number of the same year are serialized.
+    fn next_number(
+        &self,
+        txn: &mut Txn,
+        year: i32,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+
+    /// Create a new invoice.
+    fn create(
+        &self,
+        txn: &mut Txn,
+        invoice: &Invoice,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Return the invoice with the given id.
+    fn get(
+        &self,
+        txn: &mut Txn,
+        invoice_id: InvoiceId,
+    ) -> impl Future<Output = anyhow::Result<Option<Invoice>>> + Send;
+
+    /// Return the most recent invoices of a user.
+    fn list(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        pagination: PaginationSlice,
+    ) -> impl Future<Output = anyhow::Result<Vec<Invoice>>> + Send;
+
+    /// Return the number of invoices of a user.
+    fn count(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockInvoiceRepository<Txn> {
+    pub fn with_next_number(mut self, year: i32, result: u64) -> Self {
+        self.expect_next_number()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(year))
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create(mut self, invoice: Invoice) -> Self {
+        self.expect_create()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(invoice),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_get(mut self, invoice_id: InvoiceId, result: Option<Invoice>) -> Self {
+        self.expect_get()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(invoice_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list(
+        mut self,
+        user_id: UserId,
+        pagination: PaginationSlice,
+        result: Vec<Invoice>,
+    ) -> Self {
+        self.expect_list()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(pagination),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_count(mut self, user_id: UserId, result: u64) -> Self {
+        self.expect_count()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
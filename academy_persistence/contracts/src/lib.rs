@@ -1,8 +1,16 @@
 use std::future::Future;
 
+pub mod checkout;
+pub mod coin;
+pub mod contact;
+pub mod email_outbox;
+pub mod invite;
+pub mod invoice;
 pub mod mfa;
+pub mod newsletter;
 pub mod oauth2;
 pub mod session;
+pub mod skill;
 pub mod user;
 
 #[cfg_attr(feature = "mock", mockall::automock(type Transaction = MockTransaction;))]
@@ -46,6 +54,31 @@ impl MockDatabase {
         db
     }
 
+    /// Expect one transaction to be started for each element of
+    /// `expect_commit`, which specifies whether the respective transaction
+    /// is expected to be committed.
+    pub fn build_many(expect_commit: &[bool]) -> Self {
+        let mut txns = expect_commit
+            .iter()
+            .map(|&expect_commit| {
+                let mut txn = MockTransaction::new();
+                if expect_commit {
+                    txn.expect_commit()
+                        .once()
+                        .return_once(|| Box::pin(std::future::ready(Ok(()))));
+                }
+                txn
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let mut db = Self::new();
+        db.expect_begin_transaction()
+            .times(expect_commit.len())
+            .returning(move || Box::pin(std::future::ready(Ok(txns.next().unwrap()))));
+        db
+    }
+
     pub fn build_expect_rollback() -> Self {
         let mut txn = MockTransaction::new();
         txn.expect_rollback()
@@ -0,0 +1,257 @@
+use std::future::Future;
+
+use academy_models::{
+    newsletter::{
+        NewsletterCampaign, NewsletterCampaignId, NewsletterCampaignPatchRef,
+        NewsletterCampaignStatus, NewsletterRecipient, NewsletterRecipientStatus,
+    },
+    user::UserId,
+};
+use chrono::{DateTime, Utc};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait NewsletterRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Return all campaigns, optionally only those with the given status.
+    fn list_campaigns(
+        &self,
+        txn: &mut Txn,
+        status: Option<NewsletterCampaignStatus>,
+    ) -> impl Future<Output = anyhow::Result<Vec<NewsletterCampaign>>> + Send;
+
+    /// Return the campaign with the given id.
+    fn get_campaign(
+        &self,
+        txn: &mut Txn,
+        campaign_id: NewsletterCampaignId,
+    ) -> impl Future<Output = anyhow::Result<Option<NewsletterCampaign>>> + Send;
+
+    /// Create a new campaign.
+    fn create_campaign(
+        &self,
+        txn: &mut Txn,
+        campaign: &NewsletterCampaign,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Update the content of an existing campaign.
+    fn update_campaign<'a>(
+        &self,
+        txn: &mut Txn,
+        campaign_id: NewsletterCampaignId,
+        patch: NewsletterCampaignPatchRef<'a>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Delete a campaign and all of its recipients.
+    fn delete_campaign(
+        &self,
+        txn: &mut Txn,
+        campaign_id: NewsletterCampaignId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Mark a draft campaign as sending and add all enabled users with a
+    /// verified email address who are subscribed to the newsletter as pending
+    /// recipients.
+    ///
+    /// Returns the number of recipients or `None` if the campaign does not
+    /// exist or is not a draft.
+    fn start_campaign(
+        &self,
+        txn: &mut Txn,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<Option<u64>>> + Send;
+
+    /// Mark a sending campaign as sent.
+    fn complete_campaign(
+        &self,
+        txn: &mut Txn,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Mark pending recipients of a campaign as skipped if they have
+    /// unsubscribed from the newsletter, no longer have a verified email
+    /// address or have been disabled.
+    ///
+    /// Returns the number of skipped recipients.
+    fn skip_unsubscribed_recipients(
+        &self,
+        txn: &mut Txn,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<u64>> + Send;
+
+    /// Return up to `limit` pending recipients of a campaign and lock them
+    /// until the end of the transaction.
+    ///
+    /// Recipients which are already locked by another transaction are
+    /// skipped.
+    fn lock_pending_recipients(
+        &self,
+        txn: &mut Txn,
+        campaign_id: NewsletterCampaignId,
+        limit: u64,
+    ) -> impl Future<Output = anyhow::Result<Vec<NewsletterRecipient>>> + Send;
+
+    /// Update the delivery status of a recipient.
+    fn update_recipient_status(
+        &self,
+        txn: &mut Txn,
+        campaign_id: NewsletterCampaignId,
+        user_id: UserId,
+        status: NewsletterRecipientStatus,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockNewsletterRepository<Txn> {
+    pub fn with_list_campaigns(
+        mut self,
+        status: Option<NewsletterCampaignStatus>,
+        result: Vec<NewsletterCampaign>,
+    ) -> Self {
+        self.expect_list_campaigns()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(status))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_campaign(
+        mut self,
+        campaign_id: NewsletterCampaignId,
+        result: Option<NewsletterCampaign>,
+    ) -> Self {
+        self.expect_get_campaign()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(campaign_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_campaign(mut self, campaign: NewsletterCampaign) -> Self {
+        self.expect_create_campaign()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(campaign),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_update_campaign(
+        mut self,
+        campaign_id: NewsletterCampaignId,
+        patch: academy_models::newsletter::NewsletterCampaignPatch,
+        result: bool,
+    ) -> Self {
+        self.expect_update_campaign()
+            .once()
+            .withf(move |_, id, p| *id == campaign_id && p == &patch.as_ref())
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_delete_campaign(mut self, campaign_id: NewsletterCampaignId, result: bool) -> Self {
+        self.expect_delete_campaign()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(campaign_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_start_campaign(
+        mut self,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+        result: Option<u64>,
+    ) -> Self {
+        self.expect_start_campaign()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(campaign_id),
+                mockall::predicate::eq(now),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_complete_campaign(
+        mut self,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+        result: bool,
+    ) -> Self {
+        self.expect_complete_campaign()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(campaign_id),
+                mockall::predicate::eq(now),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_skip_unsubscribed_recipients(
+        mut self,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+        result: u64,
+    ) -> Self {
+        self.expect_skip_unsubscribed_recipients()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(campaign_id),
+                mockall::predicate::eq(now),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_lock_pending_recipients(
+        mut self,
+        campaign_id: NewsletterCampaignId,
+        limit: u64,
+        result: Vec<NewsletterRecipient>,
+    ) -> Self {
+        self.expect_lock_pending_recipients()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(campaign_id),
+                mockall::predicate::eq(limit),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_update_recipient_status(
+        mut self,
+        campaign_id: NewsletterCampaignId,
+        user_id: UserId,
+        status: NewsletterRecipientStatus,
+        now: DateTime<Utc>,
+    ) -> Self {
+        self.expect_update_recipient_status()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(campaign_id),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(status),
+                mockall::predicate::eq(now),
+            )
+            .return_once(|_, _, _, _, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+}
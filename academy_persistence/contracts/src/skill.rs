@@ -0,0 +1,541 @@
+use std::future::Future;
+
+use academy_models::{
+    skill::{
+        Course, CourseId, CoursePatchRef, CourseSection, CourseSectionId, CourseSectionPatchRef,
+        LearningProgress, Lecture, LectureId, LecturePatchRef, Skill, SkillId, SkillPatchRef,
+        SkillPrerequisite,
+    },
+    user::UserId,
+};
+use chrono::{DateTime, Utc};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait SkillRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
+    /// Return all skills.
+    fn list_skills(&self, txn: &mut Txn)
+        -> impl Future<Output = anyhow::Result<Vec<Skill>>> + Send;
+
+    /// Return the skill with the given id.
+    fn get_skill(
+        &self,
+        txn: &mut Txn,
+        skill_id: SkillId,
+    ) -> impl Future<Output = anyhow::Result<Option<Skill>>> + Send;
+
+    /// Create a new skill.
+    fn create_skill(
+        &self,
+        txn: &mut Txn,
+        skill: &Skill,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Update an existing skill.
+    fn update_skill<'a>(
+        &self,
+        txn: &mut Txn,
+        skill_id: SkillId,
+        patch: SkillPatchRef<'a>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Delete a skill together with its courses and prerequisites.
+    fn delete_skill(
+        &self,
+        txn: &mut Txn,
+        skill_id: SkillId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return all edges of the skill tree.
+    fn list_prerequisites(
+        &self,
+        txn: &mut Txn,
+    ) -> impl Future<Output = anyhow::Result<Vec<SkillPrerequisite>>> + Send;
+
+    /// Add an edge to the skill tree.
+    ///
+    /// Returns `false` if the edge already exists.
+    fn add_prerequisite(
+        &self,
+        txn: &mut Txn,
+        prerequisite: SkillPrerequisite,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Remove an edge from the skill tree.
+    fn remove_prerequisite(
+        &self,
+        txn: &mut Txn,
+        prerequisite: SkillPrerequisite,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return all courses, optionally only those of the given skill.
+    fn list_courses(
+        &self,
+        txn: &mut Txn,
+        skill_id: Option<SkillId>,
+    ) -> impl Future<Output = anyhow::Result<Vec<Course>>> + Send;
+
+    /// Return the course with the given id.
+    fn get_course(
+        &self,
+        txn: &mut Txn,
+        course_id: CourseId,
+    ) -> impl Future<Output = anyhow::Result<Option<Course>>> + Send;
+
+    /// Create a new course.
+    fn create_course(
+        &self,
+        txn: &mut Txn,
+        course: &Course,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Update an existing course.
+    fn update_course<'a>(
+        &self,
+        txn: &mut Txn,
+        course_id: CourseId,
+        patch: CoursePatchRef<'a>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Delete a course together with its sections and lectures.
+    fn delete_course(
+        &self,
+        txn: &mut Txn,
+        course_id: CourseId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return the sections of a course ordered by their position.
+    fn list_sections(
+        &self,
+        txn: &mut Txn,
+        course_id: CourseId,
+    ) -> impl Future<Output = anyhow::Result<Vec<CourseSection>>> + Send;
+
+    /// Return the section with the given id.
+    fn get_section(
+        &self,
+        txn: &mut Txn,
+        section_id: CourseSectionId,
+    ) -> impl Future<Output = anyhow::Result<Option<CourseSection>>> + Send;
+
+    /// Create a new section.
+    fn create_section(
+        &self,
+        txn: &mut Txn,
+        section: &CourseSection,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Update an existing section.
+    fn update_section<'a>(
+        &self,
+        txn: &mut Txn,
+        section_id: CourseSectionId,
+        patch: CourseSectionPatchRef<'a>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Delete a section together with its lectures.
+    fn delete_section(
+        &self,
+        txn: &mut Txn,
+        section_id: CourseSectionId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return all lectures of a course ordered by the position of their
+    /// section and their own position.
+    fn list_lectures(
+        &self,
+        txn: &mut Txn,
+        course_id: CourseId,
+    ) -> impl Future<Output = anyhow::Result<Vec<Lecture>>> + Send;
+
+    /// Return the lecture with the given id.
+    fn get_lecture(
+        &self,
+        txn: &mut Txn,
+        lecture_id: LectureId,
+    ) -> impl Future<Output = anyhow::Result<Option<Lecture>>> + Send;
+
+    /// Create a new lecture.
+    fn create_lecture(
+        &self,
+        txn: &mut Txn,
+        lecture: &Lecture,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Update an existing lecture.
+    fn update_lecture<'a>(
+        &self,
+        txn: &mut Txn,
+        lecture_id: LectureId,
+        patch: LecturePatchRef<'a>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Delete a lecture.
+    fn delete_lecture(
+        &self,
+        txn: &mut Txn,
+        lecture_id: LectureId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return the progress of a user in all courses with at least one
+    /// lecture.
+    fn list_course_progress(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<Vec<(CourseId, LearningProgress)>>> + Send;
+
+    /// Return the lectures of a course which have been completed by a user.
+    fn list_completed_lectures(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        course_id: CourseId,
+    ) -> impl Future<Output = anyhow::Result<Vec<LectureId>>> + Send;
+
+    /// Mark a lecture as completed by a user.
+    ///
+    /// Returns `false` if the lecture had already been completed.
+    fn complete_lecture(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        lecture_id: LectureId,
+        completed_at: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Mark a lecture as not completed by a user.
+    fn reset_lecture(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        lecture_id: LectureId,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl<Txn: Send + Sync + 'static> MockSkillRepository<Txn> {
+    pub fn with_list_skills(mut self, result: Vec<Skill>) -> Self {
+        self.expect_list_skills()
+            .once()
+            .with(mockall::predicate::always())
+            .return_once(|_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_skill(mut self, skill_id: SkillId, result: Option<Skill>) -> Self {
+        self.expect_get_skill()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(skill_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_skill(mut self, skill: Skill) -> Self {
+        self.expect_create_skill()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(skill))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_update_skill(
+        mut self,
+        skill_id: SkillId,
+        patch: academy_models::skill::SkillPatch,
+        result: bool,
+    ) -> Self {
+        self.expect_update_skill()
+            .once()
+            .withf(move |_, id, p| *id == skill_id && p == &patch.as_ref())
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_delete_skill(mut self, skill_id: SkillId, result: bool) -> Self {
+        self.expect_delete_skill()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(skill_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_prerequisites(mut self, result: Vec<SkillPrerequisite>) -> Self {
+        self.expect_list_prerequisites()
+            .once()
+            .with(mockall::predicate::always())
+            .return_once(|_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_add_prerequisite(mut self, prerequisite: SkillPrerequisite, result: bool) -> Self {
+        self.expect_add_prerequisite()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(prerequisite),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_remove_prerequisite(
+        mut self,
+        prerequisite: SkillPrerequisite,
+        result: bool,
+    ) -> Self {
+        self.expect_remove_prerequisite()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(prerequisite),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_courses(mut self, skill_id: Option<SkillId>, result: Vec<Course>) -> Self {
+        self.expect_list_courses()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(skill_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_course(mut self, course_id: CourseId, result: Option<Course>) -> Self {
+        self.expect_get_course()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(course_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_course(mut self, course: Course) -> Self {
+        self.expect_create_course()
+            .once()
+            .with(mockall::predicate::always(), mockall::predicate::eq(course))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_update_course(
+        mut self,
+        course_id: CourseId,
+        patch: academy_models::skill::CoursePatch,
+        result: bool,
+    ) -> Self {
+        self.expect_update_course()
+            .once()
+            .withf(move |_, id, p| *id == course_id && p == &patch.as_ref())
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_delete_course(mut self, course_id: CourseId, result: bool) -> Self {
+        self.expect_delete_course()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(course_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_sections(mut self, course_id: CourseId, result: Vec<CourseSection>) -> Self {
+        self.expect_list_sections()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(course_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_section(
+        mut self,
+        section_id: CourseSectionId,
+        result: Option<CourseSection>,
+    ) -> Self {
+        self.expect_get_section()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(section_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_section(mut self, section: CourseSection) -> Self {
+        self.expect_create_section()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(section),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_update_section(
+        mut self,
+        section_id: CourseSectionId,
+        patch: academy_models::skill::CourseSectionPatch,
+        result: bool,
+    ) -> Self {
+        self.expect_update_section()
+            .once()
+            .withf(move |_, id, p| *id == section_id && p == &patch.as_ref())
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_delete_section(mut self, section_id: CourseSectionId, result: bool) -> Self {
+        self.expect_delete_section()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(section_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_lectures(mut self, course_id: CourseId, result: Vec<Lecture>) -> Self {
+        self.expect_list_lectures()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(course_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_lecture(mut self, lecture_id: LectureId, result: Option<Lecture>) -> Self {
+        self.expect_get_lecture()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(lecture_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_create_lecture(mut self, lecture: Lecture) -> Self {
+        self.expect_create_lecture()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(lecture),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_update_lecture(
+        mut self,
+        lecture_id: LectureId,
+        patch: academy_models::skill::LecturePatch,
+        result: bool,
+    ) -> Self {
+        self.expect_update_lecture()
+            .once()
+            .withf(move |_, id, p| *id == lecture_id && p == &patch.as_ref())
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_delete_lecture(mut self, lecture_id: LectureId, result: bool) -> Self {
+        self.expect_delete_lecture()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(lecture_id),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_course_progress(
+        mut self,
+        user_id: UserId,
+        result: Vec<(CourseId, LearningProgress)>,
+    ) -> Self {
+        self.expect_list_course_progress()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_completed_lectures(
+        mut self,
+        user_id: UserId,
+        course_id: CourseId,
+        result: Vec<LectureId>,
+    ) -> Self {
+        self.expect_list_completed_lectures()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(course_id),
+            )
+            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_complete_lecture(
+        mut self,
+        user_id: UserId,
+        lecture_id: LectureId,
+        completed_at: DateTime<Utc>,
+        result: bool,
+    ) -> Self {
+        self.expect_complete_lecture()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(lecture_id),
+                mockall::predicate::eq(completed_at),
+            )
+            .return_once(move |_, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_reset_lecture(
+        mut self,
+        user_id: UserId,
+        lecture_id: LectureId,
+        result: bool,
+    ) -> Self {
+        self.expect_reset_lecture()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(lecture_id),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+}
@@ -3,12 +3,16 @@ use std::future::Future;
 use academy_models::{
     email_address::EmailAddress,
     oauth2::{OAuth2ProviderId, OAuth2RemoteUserId},
-    pagination::PaginationSlice,
+    pagination::Pagination,
+    url::Url,
     user::{
         User, UserComposite, UserFilter, UserId, UserInvoiceInfo, UserInvoiceInfoPatchRef,
-        UserName, UserNameOrEmailAddress, UserPatchRef, UserProfile, UserProfilePatchRef,
+        UserListKey, UserName, UserNameOrEmailAddress, UserPatchRef, UserPrivacy,
+        UserPrivacyPatchRef, UserProfile, UserProfilePatchRef, UserSorting, UserVatId,
+        UserVatIdValidation,
     },
 };
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 #[cfg_attr(feature = "mock", mockall::automock)]
@@ -20,13 +24,17 @@ pub trait UserRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
         filter: &UserFilter,
     ) -> impl Future<Output = anyhow::Result<u64>> + Send;
 
-    /// Return all user composites matching the given filter and pagination
-    /// slice.
+    /// Return all user composites matching the given filter, ordered by the
+    /// given sorting and paginated either by offset or by keyset.
+    ///
+    /// The key of keyset pagination must have been created using the same
+    /// sorting.
     fn list_composites(
         &self,
         txn: &mut Txn,
         filter: &UserFilter,
-        pagination: PaginationSlice,
+        sorting: UserSorting,
+        pagination: Pagination<UserListKey>,
     ) -> impl Future<Output = anyhow::Result<Vec<UserComposite>>> + Send;
 
     /// Return whether the user with the given id exists.
@@ -109,6 +117,29 @@ pub trait UserRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
         patch: UserProfilePatchRef<'a>,
     ) -> impl Future<Output = anyhow::Result<bool>> + Send;
 
+    /// Update the avatar url of an existing user.
+    fn update_avatar_url(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        avatar_url: &Option<Url>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return the privacy settings of the user with the given id.
+    fn get_privacy(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+    ) -> impl Future<Output = anyhow::Result<Option<UserPrivacy>>> + Send;
+
+    /// Update the privacy settings of an existing user.
+    fn update_privacy<'a>(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        patch: UserPrivacyPatchRef<'a>,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
     /// Update the invoice info of an existing user.
     fn update_invoice_info<'a>(
         &self,
@@ -117,6 +148,35 @@ pub trait UserRepository<Txn: Send + Sync + 'static>: Send + Sync + 'static {
         patch: UserInvoiceInfoPatchRef<'a>,
     ) -> impl Future<Output = anyhow::Result<bool>> + Send;
 
+    /// Save the result of a validation of a user's VAT id.
+    ///
+    /// Returns `false` if the user does not exist or their VAT id has been
+    /// changed in the meantime.
+    fn update_vat_id_validation(
+        &self,
+        txn: &mut Txn,
+        user_id: UserId,
+        vat_id: &UserVatId,
+        validation: &UserVatIdValidation,
+    ) -> impl Future<Output = anyhow::Result<bool>> + Send;
+
+    /// Return all VAT ids which have not been validated since
+    /// `validated_before` or whose validation is still pending, together with
+    /// the ids of their users.
+    fn list_vat_ids_to_revalidate(
+        &self,
+        txn: &mut Txn,
+        validated_before: DateTime<Utc>,
+    ) -> impl Future<Output = anyhow::Result<Vec<(UserId, UserVatId)>>> + Send;
+
+    /// Return the original values of all countries which could not be
+    /// converted to ISO 3166-1 country codes, together with the ids of their
+    /// users.
+    fn list_unconverted_countries(
+        &self,
+        txn: &mut Txn,
+    ) -> impl Future<Output = anyhow::Result<Vec<(UserId, String)>>> + Send;
+
     /// Delete an existing user.
     fn delete(
         &self,
@@ -170,7 +230,8 @@ impl<Txn: Send + Sync + 'static> MockUserRepository<Txn> {
     pub fn with_list_composites(
         mut self,
         filter: UserFilter,
-        pagination: PaginationSlice,
+        sorting: UserSorting,
+        pagination: Pagination<UserListKey>,
         result: Vec<UserComposite>,
     ) -> Self {
         self.expect_list_composites()
@@ -178,9 +239,10 @@ impl<Txn: Send + Sync + 'static> MockUserRepository<Txn> {
             .with(
                 mockall::predicate::always(),
                 mockall::predicate::eq(filter),
+                mockall::predicate::eq(sorting),
                 mockall::predicate::eq(pagination),
             )
-            .return_once(|_, _, _| Box::pin(std::future::ready(Ok(result))));
+            .return_once(|_, _, _, _| Box::pin(std::future::ready(Ok(result))));
         self
     }
 
@@ -307,6 +369,47 @@ impl<Txn: Send + Sync + 'static> MockUserRepository<Txn> {
         self
     }
 
+    pub fn with_update_avatar_url(
+        mut self,
+        user_id: UserId,
+        avatar_url: Option<Url>,
+        result: bool,
+    ) -> Self {
+        self.expect_update_avatar_url()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(avatar_url),
+            )
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_get_privacy(mut self, user_id: UserId, result: Option<UserPrivacy>) -> Self {
+        self.expect_get_privacy()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+            )
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_update_privacy(
+        mut self,
+        user_id: UserId,
+        patch: academy_models::user::UserPrivacyPatch,
+        result: bool,
+    ) -> Self {
+        self.expect_update_privacy()
+            .once()
+            .withf(move |_, id, p| *id == user_id && p == &patch.as_ref())
+            .return_once(move |_, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
     pub fn with_update_invoice_info(
         mut self,
         user_id: UserId,
@@ -320,6 +423,40 @@ impl<Txn: Send + Sync + 'static> MockUserRepository<Txn> {
         self
     }
 
+    pub fn with_update_vat_id_validation(
+        mut self,
+        user_id: UserId,
+        vat_id: UserVatId,
+        validation: UserVatIdValidation,
+        result: bool,
+    ) -> Self {
+        self.expect_update_vat_id_validation()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(vat_id),
+                mockall::predicate::eq(validation),
+            )
+            .return_once(move |_, _, _, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_list_vat_ids_to_revalidate(
+        mut self,
+        validated_before: DateTime<Utc>,
+        result: Vec<(UserId, UserVatId)>,
+    ) -> Self {
+        self.expect_list_vat_ids_to_revalidate()
+            .once()
+            .with(
+                mockall::predicate::always(),
+                mockall::predicate::eq(validated_before),
+            )
+            .return_once(move |_, _| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
     pub fn with_delete(mut self, user_id: UserId, result: bool) -> Self {
         self.expect_delete()
             .once()
@@ -0,0 +1,6 @@
+alter table user_profiles
+    drop column display_name_visibility,
+    drop column bio_visibility,
+    drop column tags_visibility,
+    drop column registration_visibility,
+    drop column avatar_visibility;
@@ -0,0 +1,6 @@
+alter table user_profiles
+    add column display_name_visibility text not null default 'public',
+    add column bio_visibility text not null default 'public',
+    add column tags_visibility text not null default 'public',
+    add column registration_visibility text not null default 'public',
+    add column avatar_visibility text not null default 'public';
@@ -0,0 +1,7 @@
+drop index oauth2_links_user_id_provider_id_idx;
+drop index user_invoice_info_name_trgm_idx;
+drop index user_profiles_display_name_trgm_idx;
+drop index users_last_login_idx;
+drop index users_created_at_idx;
+drop index users_email_trgm_idx;
+drop index users_name_trgm_idx;
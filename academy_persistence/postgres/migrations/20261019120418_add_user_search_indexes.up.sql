@@ -0,0 +1,9 @@
+create extension if not exists pg_trgm;
+
+create index users_name_trgm_idx on users using gin (lower(name) gin_trgm_ops);
+create index users_email_trgm_idx on users using gin (lower(email) gin_trgm_ops);
+create index users_created_at_idx on users (created_at);
+create index users_last_login_idx on users (last_login);
+create index user_profiles_display_name_trgm_idx on user_profiles using gin (lower(display_name) gin_trgm_ops);
+create index user_invoice_info_name_trgm_idx on user_invoice_info using gin (lower(coalesce(first_name, '') || ' ' || coalesce(last_name, '')) gin_trgm_ops);
+create index oauth2_links_user_id_provider_id_idx on oauth2_links (user_id, provider_id);
@@ -0,0 +1,2 @@
+drop table user_invites;
+drop table invites;
@@ -0,0 +1,19 @@
+create table invites (
+    id uuid primary key,
+    code text not null unique,
+    created_by uuid references users(id) on delete set null,
+    created_at timestamp with time zone not null,
+    expires_at timestamp with time zone,
+    max_uses bigint,
+    uses bigint not null,
+    admin boolean not null,
+    cohort text
+);
+
+create index invites_created_by_idx on invites (created_by);
+
+create table user_invites (
+    user_id uuid primary key references users(id) on delete cascade,
+    invite_id uuid references invites(id) on delete set null,
+    redeemed_at timestamp with time zone not null
+);
@@ -0,0 +1,2 @@
+drop table newsletter_recipients;
+drop table newsletter_campaigns;
@@ -0,0 +1,21 @@
+create table newsletter_campaigns (
+    id uuid primary key,
+    subject text not null,
+    body_html text not null,
+    body_text text not null,
+    status text not null,
+    created_at timestamp with time zone not null,
+    started_at timestamp with time zone,
+    completed_at timestamp with time zone
+);
+
+create table newsletter_recipients (
+    campaign_id uuid not null references newsletter_campaigns(id) on delete cascade,
+    user_id uuid not null references users(id) on delete cascade,
+    status text not null,
+    updated_at timestamp with time zone not null,
+    primary key (campaign_id, user_id)
+);
+
+create index newsletter_recipients_user_id_idx on newsletter_recipients (user_id);
+create index newsletter_recipients_pending_idx on newsletter_recipients (campaign_id, user_id) where status = 'pending';
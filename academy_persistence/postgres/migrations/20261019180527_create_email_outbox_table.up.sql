@@ -0,0 +1,19 @@
+create table email_outbox (
+    id uuid primary key,
+    recipient text not null,
+    subject text not null,
+    body text not null,
+    content_type text not null,
+    reply_to text,
+    header_names text[] not null,
+    header_values text[] not null,
+    status text not null,
+    attempts integer not null,
+    created_at timestamp with time zone not null,
+    next_attempt_at timestamp with time zone,
+    sent_at timestamp with time zone,
+    last_error text
+);
+
+create index email_outbox_created_at_idx on email_outbox (created_at);
+create index email_outbox_due_idx on email_outbox (next_attempt_at) where status = 'pending';
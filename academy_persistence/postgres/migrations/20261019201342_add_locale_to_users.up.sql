@@ -0,0 +1,2 @@
+alter table users add column locale text not null default 'de';
+alter table users alter column locale drop default;
@@ -0,0 +1,6 @@
+update email_outbox set content_type = 'html' where content_type = 'alternative';
+
+alter table email_outbox drop column body_text;
+alter table email_outbox drop column inline_image_ids;
+alter table email_outbox drop column inline_image_content_types;
+alter table email_outbox drop column inline_image_data;
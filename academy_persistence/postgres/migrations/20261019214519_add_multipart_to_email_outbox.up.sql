@@ -0,0 +1,8 @@
+alter table email_outbox add column body_text text;
+alter table email_outbox add column inline_image_ids text[] not null default '{}';
+alter table email_outbox add column inline_image_content_types text[] not null default '{}';
+alter table email_outbox add column inline_image_data bytea[] not null default '{}';
+
+alter table email_outbox alter column inline_image_ids drop default;
+alter table email_outbox alter column inline_image_content_types drop default;
+alter table email_outbox alter column inline_image_data drop default;
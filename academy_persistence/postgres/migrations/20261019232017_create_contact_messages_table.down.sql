@@ -0,0 +1,2 @@
+drop table contact_message_replies;
+drop table contact_messages;
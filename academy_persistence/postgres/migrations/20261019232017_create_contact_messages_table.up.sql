@@ -0,0 +1,25 @@
+create table contact_messages (
+    id uuid primary key,
+    author_name text not null,
+    author_email text not null,
+    subject text not null,
+    content text not null,
+    status text not null,
+    assignee uuid references users(id) on delete set null,
+    email_message_id text not null,
+    created_at timestamp with time zone not null
+);
+
+create index contact_messages_status_idx on contact_messages (status, created_at);
+create index contact_messages_assignee_idx on contact_messages (assignee);
+
+create table contact_message_replies (
+    id uuid primary key,
+    message_id uuid not null references contact_messages(id) on delete cascade,
+    author uuid references users(id) on delete set null,
+    content text not null,
+    email_message_id text not null,
+    created_at timestamp with time zone not null
+);
+
+create index contact_message_replies_message_id_idx on contact_message_replies (message_id, created_at);
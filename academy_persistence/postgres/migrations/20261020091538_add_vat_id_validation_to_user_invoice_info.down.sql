@@ -0,0 +1,3 @@
+alter table user_invoice_info drop column vat_id_status;
+alter table user_invoice_info drop column vat_id_consultation_number;
+alter table user_invoice_info drop column vat_id_validated_at;
@@ -0,0 +1,3 @@
+alter table user_invoice_info add column vat_id_status text;
+alter table user_invoice_info add column vat_id_consultation_number text;
+alter table user_invoice_info add column vat_id_validated_at timestamp with time zone;
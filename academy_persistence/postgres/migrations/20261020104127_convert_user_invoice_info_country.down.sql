@@ -0,0 +1,3 @@
+update user_invoice_info set country = unconverted_country where unconverted_country is not null;
+
+alter table user_invoice_info drop column unconverted_country;
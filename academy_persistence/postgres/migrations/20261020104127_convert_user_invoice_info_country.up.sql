@@ -0,0 +1,1101 @@
+alter table user_invoice_info add column unconverted_country text;
+
+update user_invoice_info set unconverted_country = country, country = null where country is not null;
+
+update user_invoice_info i set country = c.code, unconverted_country = null
+from (values
+    ('ad', 'AD'),
+    ('and', 'AD'),
+    ('andorra', 'AD'),
+    ('ae', 'AE'),
+    ('are', 'AE'),
+    ('united arab emirates', 'AE'),
+    ('vereinigte arabische emirate', 'AE'),
+    ('af', 'AF'),
+    ('afg', 'AF'),
+    ('afghanistan', 'AF'),
+    ('ag', 'AG'),
+    ('atg', 'AG'),
+    ('antigua and barbuda', 'AG'),
+    ('antigua und barbuda', 'AG'),
+    ('ai', 'AI'),
+    ('aia', 'AI'),
+    ('anguilla', 'AI'),
+    ('al', 'AL'),
+    ('alb', 'AL'),
+    ('albania', 'AL'),
+    ('albanien', 'AL'),
+    ('am', 'AM'),
+    ('arm', 'AM'),
+    ('armenia', 'AM'),
+    ('armenien', 'AM'),
+    ('ao', 'AO'),
+    ('ago', 'AO'),
+    ('angola', 'AO'),
+    ('aq', 'AQ'),
+    ('ata', 'AQ'),
+    ('antarctica', 'AQ'),
+    ('antarktis', 'AQ'),
+    ('ar', 'AR'),
+    ('arg', 'AR'),
+    ('argentina', 'AR'),
+    ('argentinien', 'AR'),
+    ('as', 'AS'),
+    ('asm', 'AS'),
+    ('american samoa', 'AS'),
+    ('amerikanisch-samoa', 'AS'),
+    ('at', 'AT'),
+    ('aut', 'AT'),
+    ('austria', 'AT'),
+    ('Österreich', 'AT'),
+    ('österreich', 'AT'),
+    ('au', 'AU'),
+    ('aus', 'AU'),
+    ('australia', 'AU'),
+    ('australien', 'AU'),
+    ('aw', 'AW'),
+    ('abw', 'AW'),
+    ('aruba', 'AW'),
+    ('ax', 'AX'),
+    ('ala', 'AX'),
+    ('Åland islands', 'AX'),
+    ('åland islands', 'AX'),
+    ('Åland-inseln', 'AX'),
+    ('åland-inseln', 'AX'),
+    ('az', 'AZ'),
+    ('aze', 'AZ'),
+    ('azerbaijan', 'AZ'),
+    ('aserbaidschan', 'AZ'),
+    ('ba', 'BA'),
+    ('bih', 'BA'),
+    ('bosnia and herzegovina', 'BA'),
+    ('bosnien und herzegowina', 'BA'),
+    ('bb', 'BB'),
+    ('brb', 'BB'),
+    ('barbados', 'BB'),
+    ('bd', 'BD'),
+    ('bgd', 'BD'),
+    ('bangladesh', 'BD'),
+    ('bangladesch', 'BD'),
+    ('be', 'BE'),
+    ('bel', 'BE'),
+    ('belgium', 'BE'),
+    ('belgien', 'BE'),
+    ('bf', 'BF'),
+    ('bfa', 'BF'),
+    ('burkina faso', 'BF'),
+    ('bg', 'BG'),
+    ('bgr', 'BG'),
+    ('bulgaria', 'BG'),
+    ('bulgarien', 'BG'),
+    ('bh', 'BH'),
+    ('bhr', 'BH'),
+    ('bahrain', 'BH'),
+    ('bi', 'BI'),
+    ('bdi', 'BI'),
+    ('burundi', 'BI'),
+    ('bj', 'BJ'),
+    ('ben', 'BJ'),
+    ('benin', 'BJ'),
+    ('bl', 'BL'),
+    ('blm', 'BL'),
+    ('saint barthélemy', 'BL'),
+    ('saint-barthélemy', 'BL'),
+    ('bm', 'BM'),
+    ('bmu', 'BM'),
+    ('bermuda', 'BM'),
+    ('bn', 'BN'),
+    ('brn', 'BN'),
+    ('brunei darussalam', 'BN'),
+    ('bo', 'BO'),
+    ('bol', 'BO'),
+    ('bolivia', 'BO'),
+    ('bolivien', 'BO'),
+    ('bq', 'BQ'),
+    ('bes', 'BQ'),
+    ('bonaire, sint eustatius and saba', 'BQ'),
+    ('bonaire, sint eustatius und saba', 'BQ'),
+    ('br', 'BR'),
+    ('bra', 'BR'),
+    ('brazil', 'BR'),
+    ('brasilien', 'BR'),
+    ('bs', 'BS'),
+    ('bhs', 'BS'),
+    ('bahamas', 'BS'),
+    ('bt', 'BT'),
+    ('btn', 'BT'),
+    ('bhutan', 'BT'),
+    ('bv', 'BV'),
+    ('bvt', 'BV'),
+    ('bouvet island', 'BV'),
+    ('bouvet-insel', 'BV'),
+    ('bw', 'BW'),
+    ('bwa', 'BW'),
+    ('botswana', 'BW'),
+    ('botsuana', 'BW'),
+    ('by', 'BY'),
+    ('blr', 'BY'),
+    ('belarus', 'BY'),
+    ('bz', 'BZ'),
+    ('blz', 'BZ'),
+    ('belize', 'BZ'),
+    ('ca', 'CA'),
+    ('can', 'CA'),
+    ('canada', 'CA'),
+    ('kanada', 'CA'),
+    ('cc', 'CC'),
+    ('cck', 'CC'),
+    ('cocos (keeling) islands', 'CC'),
+    ('kokos-(keeling-)inseln', 'CC'),
+    ('cd', 'CD'),
+    ('cod', 'CD'),
+    ('congo, the democratic republic of the', 'CD'),
+    ('demokratische republik kongo', 'CD'),
+    ('cf', 'CF'),
+    ('caf', 'CF'),
+    ('central african republic', 'CF'),
+    ('zentralafrikanische republik', 'CF'),
+    ('cg', 'CG'),
+    ('cog', 'CG'),
+    ('congo', 'CG'),
+    ('kongo', 'CG'),
+    ('ch', 'CH'),
+    ('che', 'CH'),
+    ('switzerland', 'CH'),
+    ('schweiz', 'CH'),
+    ('ci', 'CI'),
+    ('civ', 'CI'),
+    ('côte d''ivoire', 'CI'),
+    ('ck', 'CK'),
+    ('cok', 'CK'),
+    ('cook islands', 'CK'),
+    ('cookinseln', 'CK'),
+    ('cl', 'CL'),
+    ('chl', 'CL'),
+    ('chile', 'CL'),
+    ('cm', 'CM'),
+    ('cmr', 'CM'),
+    ('cameroon', 'CM'),
+    ('kamerun', 'CM'),
+    ('cn', 'CN'),
+    ('chn', 'CN'),
+    ('china', 'CN'),
+    ('co', 'CO'),
+    ('col', 'CO'),
+    ('colombia', 'CO'),
+    ('kolumbien', 'CO'),
+    ('cr', 'CR'),
+    ('cri', 'CR'),
+    ('costa rica', 'CR'),
+    ('cu', 'CU'),
+    ('cub', 'CU'),
+    ('cuba', 'CU'),
+    ('kuba', 'CU'),
+    ('cv', 'CV'),
+    ('cpv', 'CV'),
+    ('cabo verde', 'CV'),
+    ('kap verde', 'CV'),
+    ('cw', 'CW'),
+    ('cuw', 'CW'),
+    ('curaçao', 'CW'),
+    ('cx', 'CX'),
+    ('cxr', 'CX'),
+    ('christmas island', 'CX'),
+    ('weihnachtsinseln', 'CX'),
+    ('cy', 'CY'),
+    ('cyp', 'CY'),
+    ('cyprus', 'CY'),
+    ('zypern', 'CY'),
+    ('cz', 'CZ'),
+    ('cze', 'CZ'),
+    ('czechia', 'CZ'),
+    ('tschechien', 'CZ'),
+    ('de', 'DE'),
+    ('deu', 'DE'),
+    ('germany', 'DE'),
+    ('deutschland', 'DE'),
+    ('dj', 'DJ'),
+    ('dji', 'DJ'),
+    ('djibouti', 'DJ'),
+    ('dschibuti', 'DJ'),
+    ('dk', 'DK'),
+    ('dnk', 'DK'),
+    ('denmark', 'DK'),
+    ('dänemark', 'DK'),
+    ('dm', 'DM'),
+    ('dma', 'DM'),
+    ('dominica', 'DM'),
+    ('do', 'DO'),
+    ('dom', 'DO'),
+    ('dominican republic', 'DO'),
+    ('dominikanische republik', 'DO'),
+    ('dz', 'DZ'),
+    ('dza', 'DZ'),
+    ('algeria', 'DZ'),
+    ('algerien', 'DZ'),
+    ('ec', 'EC'),
+    ('ecu', 'EC'),
+    ('ecuador', 'EC'),
+    ('ee', 'EE'),
+    ('est', 'EE'),
+    ('estonia', 'EE'),
+    ('estland', 'EE'),
+    ('eg', 'EG'),
+    ('egy', 'EG'),
+    ('egypt', 'EG'),
+    ('ägypten', 'EG'),
+    ('Ägypten', 'EG'),
+    ('eh', 'EH'),
+    ('esh', 'EH'),
+    ('western sahara', 'EH'),
+    ('westsahara', 'EH'),
+    ('er', 'ER'),
+    ('eri', 'ER'),
+    ('eritrea', 'ER'),
+    ('es', 'ES'),
+    ('esp', 'ES'),
+    ('spain', 'ES'),
+    ('spanien', 'ES'),
+    ('et', 'ET'),
+    ('eth', 'ET'),
+    ('ethiopia', 'ET'),
+    ('Äthiopien', 'ET'),
+    ('äthiopien', 'ET'),
+    ('fi', 'FI'),
+    ('fin', 'FI'),
+    ('finland', 'FI'),
+    ('finnland', 'FI'),
+    ('fj', 'FJ'),
+    ('fji', 'FJ'),
+    ('fiji', 'FJ'),
+    ('fidschi', 'FJ'),
+    ('fk', 'FK'),
+    ('flk', 'FK'),
+    ('falkland islands (malvinas)', 'FK'),
+    ('falklandinseln (malwinen)', 'FK'),
+    ('fm', 'FM'),
+    ('fsm', 'FM'),
+    ('micronesia, federated states of', 'FM'),
+    ('mikronesien, föderierte staaten von', 'FM'),
+    ('fo', 'FO'),
+    ('fro', 'FO'),
+    ('faroe islands', 'FO'),
+    ('färöer-inseln', 'FO'),
+    ('fr', 'FR'),
+    ('fra', 'FR'),
+    ('france', 'FR'),
+    ('frankreich', 'FR'),
+    ('ga', 'GA'),
+    ('gab', 'GA'),
+    ('gabon', 'GA'),
+    ('gabun', 'GA'),
+    ('gb', 'GB'),
+    ('gbr', 'GB'),
+    ('united kingdom', 'GB'),
+    ('vereinigtes königreich', 'GB'),
+    ('gd', 'GD'),
+    ('grd', 'GD'),
+    ('grenada', 'GD'),
+    ('ge', 'GE'),
+    ('geo', 'GE'),
+    ('georgia', 'GE'),
+    ('georgien', 'GE'),
+    ('gf', 'GF'),
+    ('guf', 'GF'),
+    ('french guiana', 'GF'),
+    ('französisch-guyana', 'GF'),
+    ('gg', 'GG'),
+    ('ggy', 'GG'),
+    ('guernsey', 'GG'),
+    ('gh', 'GH'),
+    ('gha', 'GH'),
+    ('ghana', 'GH'),
+    ('gi', 'GI'),
+    ('gib', 'GI'),
+    ('gibraltar', 'GI'),
+    ('gl', 'GL'),
+    ('grl', 'GL'),
+    ('greenland', 'GL'),
+    ('grönland', 'GL'),
+    ('gm', 'GM'),
+    ('gmb', 'GM'),
+    ('gambia', 'GM'),
+    ('gn', 'GN'),
+    ('gin', 'GN'),
+    ('guinea', 'GN'),
+    ('gp', 'GP'),
+    ('glp', 'GP'),
+    ('guadeloupe', 'GP'),
+    ('gq', 'GQ'),
+    ('gnq', 'GQ'),
+    ('equatorial guinea', 'GQ'),
+    ('äquatorialguinea', 'GQ'),
+    ('Äquatorialguinea', 'GQ'),
+    ('gr', 'GR'),
+    ('grc', 'GR'),
+    ('greece', 'GR'),
+    ('griechenland', 'GR'),
+    ('gs', 'GS'),
+    ('sgs', 'GS'),
+    ('south georgia and the south sandwich islands', 'GS'),
+    ('south georgia und die südlichen sandwichinseln', 'GS'),
+    ('gt', 'GT'),
+    ('gtm', 'GT'),
+    ('guatemala', 'GT'),
+    ('gu', 'GU'),
+    ('gum', 'GU'),
+    ('guam', 'GU'),
+    ('gw', 'GW'),
+    ('gnb', 'GW'),
+    ('guinea-bissau', 'GW'),
+    ('gy', 'GY'),
+    ('guy', 'GY'),
+    ('guyana', 'GY'),
+    ('hk', 'HK'),
+    ('hkg', 'HK'),
+    ('hong kong', 'HK'),
+    ('hongkong', 'HK'),
+    ('hm', 'HM'),
+    ('hmd', 'HM'),
+    ('heard island and mcdonald islands', 'HM'),
+    ('heard und mcdonaldinseln', 'HM'),
+    ('hn', 'HN'),
+    ('hnd', 'HN'),
+    ('honduras', 'HN'),
+    ('hr', 'HR'),
+    ('hrv', 'HR'),
+    ('croatia', 'HR'),
+    ('kroatien', 'HR'),
+    ('ht', 'HT'),
+    ('hti', 'HT'),
+    ('haiti', 'HT'),
+    ('hu', 'HU'),
+    ('hun', 'HU'),
+    ('hungary', 'HU'),
+    ('ungarn', 'HU'),
+    ('id', 'ID'),
+    ('idn', 'ID'),
+    ('indonesia', 'ID'),
+    ('indonesien', 'ID'),
+    ('ie', 'IE'),
+    ('irl', 'IE'),
+    ('ireland', 'IE'),
+    ('irland', 'IE'),
+    ('il', 'IL'),
+    ('isr', 'IL'),
+    ('israel', 'IL'),
+    ('im', 'IM'),
+    ('imn', 'IM'),
+    ('isle of man', 'IM'),
+    ('insel man', 'IM'),
+    ('in', 'IN'),
+    ('ind', 'IN'),
+    ('india', 'IN'),
+    ('indien', 'IN'),
+    ('io', 'IO'),
+    ('iot', 'IO'),
+    ('british indian ocean territory', 'IO'),
+    ('britisches territorium im indischen ozean', 'IO'),
+    ('iq', 'IQ'),
+    ('irq', 'IQ'),
+    ('iraq', 'IQ'),
+    ('irak', 'IQ'),
+    ('ir', 'IR'),
+    ('irn', 'IR'),
+    ('iran', 'IR'),
+    ('iran, islamische republik', 'IR'),
+    ('is', 'IS'),
+    ('isl', 'IS'),
+    ('iceland', 'IS'),
+    ('island', 'IS'),
+    ('it', 'IT'),
+    ('ita', 'IT'),
+    ('italy', 'IT'),
+    ('italien', 'IT'),
+    ('je', 'JE'),
+    ('jey', 'JE'),
+    ('jersey', 'JE'),
+    ('jm', 'JM'),
+    ('jam', 'JM'),
+    ('jamaica', 'JM'),
+    ('jamaika', 'JM'),
+    ('jo', 'JO'),
+    ('jor', 'JO'),
+    ('jordan', 'JO'),
+    ('jordanien', 'JO'),
+    ('jp', 'JP'),
+    ('jpn', 'JP'),
+    ('japan', 'JP'),
+    ('ke', 'KE'),
+    ('ken', 'KE'),
+    ('kenya', 'KE'),
+    ('kenia', 'KE'),
+    ('kg', 'KG'),
+    ('kgz', 'KG'),
+    ('kyrgyzstan', 'KG'),
+    ('kirgisistan', 'KG'),
+    ('kh', 'KH'),
+    ('khm', 'KH'),
+    ('cambodia', 'KH'),
+    ('kambodscha', 'KH'),
+    ('ki', 'KI'),
+    ('kir', 'KI'),
+    ('kiribati', 'KI'),
+    ('km', 'KM'),
+    ('com', 'KM'),
+    ('comoros', 'KM'),
+    ('komoren', 'KM'),
+    ('kn', 'KN'),
+    ('kna', 'KN'),
+    ('saint kitts and nevis', 'KN'),
+    ('st. kitts und nevis', 'KN'),
+    ('kp', 'KP'),
+    ('prk', 'KP'),
+    ('north korea', 'KP'),
+    ('nordkorea', 'KP'),
+    ('kr', 'KR'),
+    ('kor', 'KR'),
+    ('south korea', 'KR'),
+    ('südkorea', 'KR'),
+    ('kw', 'KW'),
+    ('kwt', 'KW'),
+    ('kuwait', 'KW'),
+    ('ky', 'KY'),
+    ('cym', 'KY'),
+    ('cayman islands', 'KY'),
+    ('cayman-inseln', 'KY'),
+    ('kz', 'KZ'),
+    ('kaz', 'KZ'),
+    ('kazakhstan', 'KZ'),
+    ('kasachstan', 'KZ'),
+    ('la', 'LA'),
+    ('lao', 'LA'),
+    ('laos', 'LA'),
+    ('laos, demokratische volksrepublik', 'LA'),
+    ('lb', 'LB'),
+    ('lbn', 'LB'),
+    ('lebanon', 'LB'),
+    ('libanon', 'LB'),
+    ('lc', 'LC'),
+    ('lca', 'LC'),
+    ('saint lucia', 'LC'),
+    ('st. lucia', 'LC'),
+    ('li', 'LI'),
+    ('lie', 'LI'),
+    ('liechtenstein', 'LI'),
+    ('lk', 'LK'),
+    ('lka', 'LK'),
+    ('sri lanka', 'LK'),
+    ('lr', 'LR'),
+    ('lbr', 'LR'),
+    ('liberia', 'LR'),
+    ('ls', 'LS'),
+    ('lso', 'LS'),
+    ('lesotho', 'LS'),
+    ('lt', 'LT'),
+    ('ltu', 'LT'),
+    ('lithuania', 'LT'),
+    ('litauen', 'LT'),
+    ('lu', 'LU'),
+    ('lux', 'LU'),
+    ('luxembourg', 'LU'),
+    ('luxemburg', 'LU'),
+    ('lv', 'LV'),
+    ('lva', 'LV'),
+    ('latvia', 'LV'),
+    ('lettland', 'LV'),
+    ('ly', 'LY'),
+    ('lby', 'LY'),
+    ('libya', 'LY'),
+    ('libyen', 'LY'),
+    ('ma', 'MA'),
+    ('mar', 'MA'),
+    ('morocco', 'MA'),
+    ('marokko', 'MA'),
+    ('mc', 'MC'),
+    ('mco', 'MC'),
+    ('monaco', 'MC'),
+    ('md', 'MD'),
+    ('mda', 'MD'),
+    ('moldova', 'MD'),
+    ('moldau', 'MD'),
+    ('me', 'ME'),
+    ('mne', 'ME'),
+    ('montenegro', 'ME'),
+    ('mf', 'MF'),
+    ('maf', 'MF'),
+    ('saint martin (french part)', 'MF'),
+    ('saint martin (französischer teil)', 'MF'),
+    ('mg', 'MG'),
+    ('mdg', 'MG'),
+    ('madagascar', 'MG'),
+    ('madagaskar', 'MG'),
+    ('mh', 'MH'),
+    ('mhl', 'MH'),
+    ('marshall islands', 'MH'),
+    ('marshallinseln', 'MH'),
+    ('mk', 'MK'),
+    ('mkd', 'MK'),
+    ('north macedonia', 'MK'),
+    ('nordmazedonien', 'MK'),
+    ('ml', 'ML'),
+    ('mli', 'ML'),
+    ('mali', 'ML'),
+    ('mm', 'MM'),
+    ('mmr', 'MM'),
+    ('myanmar', 'MM'),
+    ('mn', 'MN'),
+    ('mng', 'MN'),
+    ('mongolia', 'MN'),
+    ('mongolei', 'MN'),
+    ('mo', 'MO'),
+    ('mac', 'MO'),
+    ('macao', 'MO'),
+    ('mp', 'MP'),
+    ('mnp', 'MP'),
+    ('northern mariana islands', 'MP'),
+    ('nördliche marianen', 'MP'),
+    ('mq', 'MQ'),
+    ('mtq', 'MQ'),
+    ('martinique', 'MQ'),
+    ('mr', 'MR'),
+    ('mrt', 'MR'),
+    ('mauritania', 'MR'),
+    ('mauretanien', 'MR'),
+    ('ms', 'MS'),
+    ('msr', 'MS'),
+    ('montserrat', 'MS'),
+    ('mt', 'MT'),
+    ('mlt', 'MT'),
+    ('malta', 'MT'),
+    ('mu', 'MU'),
+    ('mus', 'MU'),
+    ('mauritius', 'MU'),
+    ('mv', 'MV'),
+    ('mdv', 'MV'),
+    ('maldives', 'MV'),
+    ('malediven', 'MV'),
+    ('mw', 'MW'),
+    ('mwi', 'MW'),
+    ('malawi', 'MW'),
+    ('mx', 'MX'),
+    ('mex', 'MX'),
+    ('mexico', 'MX'),
+    ('mexiko', 'MX'),
+    ('my', 'MY'),
+    ('mys', 'MY'),
+    ('malaysia', 'MY'),
+    ('mz', 'MZ'),
+    ('moz', 'MZ'),
+    ('mozambique', 'MZ'),
+    ('mosambik', 'MZ'),
+    ('na', 'NA'),
+    ('nam', 'NA'),
+    ('namibia', 'NA'),
+    ('nc', 'NC'),
+    ('ncl', 'NC'),
+    ('new caledonia', 'NC'),
+    ('neukaledonien', 'NC'),
+    ('ne', 'NE'),
+    ('ner', 'NE'),
+    ('niger', 'NE'),
+    ('nf', 'NF'),
+    ('nfk', 'NF'),
+    ('norfolk island', 'NF'),
+    ('norfolkinsel', 'NF'),
+    ('ng', 'NG'),
+    ('nga', 'NG'),
+    ('nigeria', 'NG'),
+    ('ni', 'NI'),
+    ('nic', 'NI'),
+    ('nicaragua', 'NI'),
+    ('nl', 'NL'),
+    ('nld', 'NL'),
+    ('netherlands', 'NL'),
+    ('niederlande', 'NL'),
+    ('no', 'NO'),
+    ('nor', 'NO'),
+    ('norway', 'NO'),
+    ('norwegen', 'NO'),
+    ('np', 'NP'),
+    ('npl', 'NP'),
+    ('nepal', 'NP'),
+    ('nr', 'NR'),
+    ('nru', 'NR'),
+    ('nauru', 'NR'),
+    ('nu', 'NU'),
+    ('niu', 'NU'),
+    ('niue', 'NU'),
+    ('nz', 'NZ'),
+    ('nzl', 'NZ'),
+    ('new zealand', 'NZ'),
+    ('neuseeland', 'NZ'),
+    ('om', 'OM'),
+    ('omn', 'OM'),
+    ('oman', 'OM'),
+    ('pa', 'PA'),
+    ('pan', 'PA'),
+    ('panama', 'PA'),
+    ('pe', 'PE'),
+    ('per', 'PE'),
+    ('peru', 'PE'),
+    ('pf', 'PF'),
+    ('pyf', 'PF'),
+    ('french polynesia', 'PF'),
+    ('französisch-polynesien', 'PF'),
+    ('pg', 'PG'),
+    ('png', 'PG'),
+    ('papua new guinea', 'PG'),
+    ('papua-neuguinea', 'PG'),
+    ('ph', 'PH'),
+    ('phl', 'PH'),
+    ('philippines', 'PH'),
+    ('philippinen', 'PH'),
+    ('pk', 'PK'),
+    ('pak', 'PK'),
+    ('pakistan', 'PK'),
+    ('pl', 'PL'),
+    ('pol', 'PL'),
+    ('poland', 'PL'),
+    ('polen', 'PL'),
+    ('pm', 'PM'),
+    ('spm', 'PM'),
+    ('saint pierre and miquelon', 'PM'),
+    ('st. pierre und miquelon', 'PM'),
+    ('pn', 'PN'),
+    ('pcn', 'PN'),
+    ('pitcairn', 'PN'),
+    ('pr', 'PR'),
+    ('pri', 'PR'),
+    ('puerto rico', 'PR'),
+    ('ps', 'PS'),
+    ('pse', 'PS'),
+    ('palestine, state of', 'PS'),
+    ('palästina, staat', 'PS'),
+    ('pt', 'PT'),
+    ('prt', 'PT'),
+    ('portugal', 'PT'),
+    ('pw', 'PW'),
+    ('plw', 'PW'),
+    ('palau', 'PW'),
+    ('py', 'PY'),
+    ('pry', 'PY'),
+    ('paraguay', 'PY'),
+    ('qa', 'QA'),
+    ('qat', 'QA'),
+    ('qatar', 'QA'),
+    ('katar', 'QA'),
+    ('re', 'RE'),
+    ('reu', 'RE'),
+    ('réunion', 'RE'),
+    ('ro', 'RO'),
+    ('rou', 'RO'),
+    ('romania', 'RO'),
+    ('rumänien', 'RO'),
+    ('rs', 'RS'),
+    ('srb', 'RS'),
+    ('serbia', 'RS'),
+    ('serbien', 'RS'),
+    ('ru', 'RU'),
+    ('rus', 'RU'),
+    ('russian federation', 'RU'),
+    ('russische föderation', 'RU'),
+    ('rw', 'RW'),
+    ('rwa', 'RW'),
+    ('rwanda', 'RW'),
+    ('ruanda', 'RW'),
+    ('sa', 'SA'),
+    ('sau', 'SA'),
+    ('saudi arabia', 'SA'),
+    ('saudi-arabien', 'SA'),
+    ('sb', 'SB'),
+    ('slb', 'SB'),
+    ('solomon islands', 'SB'),
+    ('salomoninseln', 'SB'),
+    ('sc', 'SC'),
+    ('syc', 'SC'),
+    ('seychelles', 'SC'),
+    ('seychellen', 'SC'),
+    ('sd', 'SD'),
+    ('sdn', 'SD'),
+    ('sudan', 'SD'),
+    ('se', 'SE'),
+    ('swe', 'SE'),
+    ('sweden', 'SE'),
+    ('schweden', 'SE'),
+    ('sg', 'SG'),
+    ('sgp', 'SG'),
+    ('singapore', 'SG'),
+    ('singapur', 'SG'),
+    ('sh', 'SH'),
+    ('shn', 'SH'),
+    ('saint helena, ascension and tristan da cunha', 'SH'),
+    ('st. helena, ascension und tristan da cunha', 'SH'),
+    ('si', 'SI'),
+    ('svn', 'SI'),
+    ('slovenia', 'SI'),
+    ('slowenien', 'SI'),
+    ('sj', 'SJ'),
+    ('sjm', 'SJ'),
+    ('svalbard and jan mayen', 'SJ'),
+    ('svalbard und jan mayen', 'SJ'),
+    ('sk', 'SK'),
+    ('svk', 'SK'),
+    ('slovakia', 'SK'),
+    ('slowakei', 'SK'),
+    ('sl', 'SL'),
+    ('sle', 'SL'),
+    ('sierra leone', 'SL'),
+    ('sm', 'SM'),
+    ('smr', 'SM'),
+    ('san marino', 'SM'),
+    ('sn', 'SN'),
+    ('sen', 'SN'),
+    ('senegal', 'SN'),
+    ('so', 'SO'),
+    ('som', 'SO'),
+    ('somalia', 'SO'),
+    ('sr', 'SR'),
+    ('sur', 'SR'),
+    ('suriname', 'SR'),
+    ('ss', 'SS'),
+    ('ssd', 'SS'),
+    ('south sudan', 'SS'),
+    ('südsudan', 'SS'),
+    ('st', 'ST'),
+    ('stp', 'ST'),
+    ('sao tome and principe', 'ST'),
+    ('são tomé und príncipe', 'ST'),
+    ('sv', 'SV'),
+    ('slv', 'SV'),
+    ('el salvador', 'SV'),
+    ('sx', 'SX'),
+    ('sxm', 'SX'),
+    ('sint maarten (dutch part)', 'SX'),
+    ('saint-martin (niederländischer teil)', 'SX'),
+    ('sy', 'SY'),
+    ('syr', 'SY'),
+    ('syria', 'SY'),
+    ('syrien', 'SY'),
+    ('sz', 'SZ'),
+    ('swz', 'SZ'),
+    ('eswatini', 'SZ'),
+    ('tc', 'TC'),
+    ('tca', 'TC'),
+    ('turks and caicos islands', 'TC'),
+    ('turks- und caicosinseln', 'TC'),
+    ('td', 'TD'),
+    ('tcd', 'TD'),
+    ('chad', 'TD'),
+    ('tschad', 'TD'),
+    ('tf', 'TF'),
+    ('atf', 'TF'),
+    ('french southern territories', 'TF'),
+    ('französische süd- und antarktisgebiete', 'TF'),
+    ('tg', 'TG'),
+    ('tgo', 'TG'),
+    ('togo', 'TG'),
+    ('th', 'TH'),
+    ('tha', 'TH'),
+    ('thailand', 'TH'),
+    ('tj', 'TJ'),
+    ('tjk', 'TJ'),
+    ('tajikistan', 'TJ'),
+    ('tadschikistan', 'TJ'),
+    ('tk', 'TK'),
+    ('tkl', 'TK'),
+    ('tokelau', 'TK'),
+    ('tl', 'TL'),
+    ('tls', 'TL'),
+    ('timor-leste', 'TL'),
+    ('tm', 'TM'),
+    ('tkm', 'TM'),
+    ('turkmenistan', 'TM'),
+    ('tn', 'TN'),
+    ('tun', 'TN'),
+    ('tunisia', 'TN'),
+    ('tunesien', 'TN'),
+    ('to', 'TO'),
+    ('ton', 'TO'),
+    ('tonga', 'TO'),
+    ('tr', 'TR'),
+    ('tur', 'TR'),
+    ('türkiye', 'TR'),
+    ('türkei', 'TR'),
+    ('tt', 'TT'),
+    ('tto', 'TT'),
+    ('trinidad and tobago', 'TT'),
+    ('trinidad und tobago', 'TT'),
+    ('tv', 'TV'),
+    ('tuv', 'TV'),
+    ('tuvalu', 'TV'),
+    ('tw', 'TW'),
+    ('twn', 'TW'),
+    ('taiwan', 'TW'),
+    ('taiwan, chinesische provinz', 'TW'),
+    ('tz', 'TZ'),
+    ('tza', 'TZ'),
+    ('tanzania', 'TZ'),
+    ('tansania', 'TZ'),
+    ('ua', 'UA'),
+    ('ukr', 'UA'),
+    ('ukraine', 'UA'),
+    ('ug', 'UG'),
+    ('uga', 'UG'),
+    ('uganda', 'UG'),
+    ('um', 'UM'),
+    ('umi', 'UM'),
+    ('united states minor outlying islands', 'UM'),
+    ('us', 'US'),
+    ('usa', 'US'),
+    ('united states', 'US'),
+    ('vereinigte staaten', 'US'),
+    ('uy', 'UY'),
+    ('ury', 'UY'),
+    ('uruguay', 'UY'),
+    ('uz', 'UZ'),
+    ('uzb', 'UZ'),
+    ('uzbekistan', 'UZ'),
+    ('usbekistan', 'UZ'),
+    ('va', 'VA'),
+    ('vat', 'VA'),
+    ('holy see (vatican city state)', 'VA'),
+    ('heiliger stuhl (staat vatikanstadt)', 'VA'),
+    ('vc', 'VC'),
+    ('vct', 'VC'),
+    ('saint vincent and the grenadines', 'VC'),
+    ('st. vincent und die grenadinen', 'VC'),
+    ('ve', 'VE'),
+    ('ven', 'VE'),
+    ('venezuela', 'VE'),
+    ('venezuela, bolivarische republik', 'VE'),
+    ('vg', 'VG'),
+    ('vgb', 'VG'),
+    ('virgin islands, british', 'VG'),
+    ('britische jungferninseln', 'VG'),
+    ('vi', 'VI'),
+    ('vir', 'VI'),
+    ('virgin islands, u.s.', 'VI'),
+    ('amerikanische jungferninseln', 'VI'),
+    ('vn', 'VN'),
+    ('vnm', 'VN'),
+    ('vietnam', 'VN'),
+    ('vu', 'VU'),
+    ('vut', 'VU'),
+    ('vanuatu', 'VU'),
+    ('wf', 'WF'),
+    ('wlf', 'WF'),
+    ('wallis and futuna', 'WF'),
+    ('wallis und futuna', 'WF'),
+    ('ws', 'WS'),
+    ('wsm', 'WS'),
+    ('samoa', 'WS'),
+    ('ye', 'YE'),
+    ('yem', 'YE'),
+    ('yemen', 'YE'),
+    ('jemen', 'YE'),
+    ('yt', 'YT'),
+    ('myt', 'YT'),
+    ('mayotte', 'YT'),
+    ('za', 'ZA'),
+    ('zaf', 'ZA'),
+    ('south africa', 'ZA'),
+    ('südafrika', 'ZA'),
+    ('zm', 'ZM'),
+    ('zmb', 'ZM'),
+    ('zambia', 'ZM'),
+    ('sambia', 'ZM'),
+    ('zw', 'ZW'),
+    ('zwe', 'ZW'),
+    ('zimbabwe', 'ZW'),
+    ('simbabwe', 'ZW'),
+    ('principality of andorra', 'AD'),
+    ('islamic republic of afghanistan', 'AF'),
+    ('republic of albania', 'AL'),
+    ('republic of armenia', 'AM'),
+    ('republic of angola', 'AO'),
+    ('argentine republic', 'AR'),
+    ('republic of austria', 'AT'),
+    ('republic of azerbaijan', 'AZ'),
+    ('republic of bosnia and herzegovina', 'BA'),
+    ('people''s republic of bangladesh', 'BD'),
+    ('kingdom of belgium', 'BE'),
+    ('republic of bulgaria', 'BG'),
+    ('kingdom of bahrain', 'BH'),
+    ('republic of burundi', 'BI'),
+    ('republic of benin', 'BJ'),
+    ('bolivia, plurinational state of', 'BO'),
+    ('plurinational state of bolivia', 'BO'),
+    ('federative republic of brazil', 'BR'),
+    ('commonwealth of the bahamas', 'BS'),
+    ('kingdom of bhutan', 'BT'),
+    ('republic of botswana', 'BW'),
+    ('republic of belarus', 'BY'),
+    ('republic of the congo', 'CG'),
+    ('swiss confederation', 'CH'),
+    ('republic of côte d''ivoire', 'CI'),
+    ('republic of chile', 'CL'),
+    ('republic of cameroon', 'CM'),
+    ('people''s republic of china', 'CN'),
+    ('republic of colombia', 'CO'),
+    ('republic of costa rica', 'CR'),
+    ('republic of cuba', 'CU'),
+    ('republic of cabo verde', 'CV'),
+    ('republic of cyprus', 'CY'),
+    ('czech republic', 'CZ'),
+    ('federal republic of germany', 'DE'),
+    ('republic of djibouti', 'DJ'),
+    ('kingdom of denmark', 'DK'),
+    ('commonwealth of dominica', 'DM'),
+    ('people''s democratic republic of algeria', 'DZ'),
+    ('republic of ecuador', 'EC'),
+    ('republic of estonia', 'EE'),
+    ('arab republic of egypt', 'EG'),
+    ('the state of eritrea', 'ER'),
+    ('kingdom of spain', 'ES'),
+    ('federal democratic republic of ethiopia', 'ET'),
+    ('republic of finland', 'FI'),
+    ('republic of fiji', 'FJ'),
+    ('federated states of micronesia', 'FM'),
+    ('french republic', 'FR'),
+    ('gabonese republic', 'GA'),
+    ('united kingdom of great britain and northern ireland', 'GB'),
+    ('republic of ghana', 'GH'),
+    ('republic of the gambia', 'GM'),
+    ('republic of guinea', 'GN'),
+    ('republic of equatorial guinea', 'GQ'),
+    ('hellenic republic', 'GR'),
+    ('republic of guatemala', 'GT'),
+    ('republic of guinea-bissau', 'GW'),
+    ('republic of guyana', 'GY'),
+    ('hong kong special administrative region of china', 'HK'),
+    ('republic of honduras', 'HN'),
+    ('republic of croatia', 'HR'),
+    ('republic of haiti', 'HT'),
+    ('republic of indonesia', 'ID'),
+    ('state of israel', 'IL'),
+    ('republic of india', 'IN'),
+    ('republic of iraq', 'IQ'),
+    ('iran, islamic republic of', 'IR'),
+    ('islamic republic of iran', 'IR'),
+    ('republic of iceland', 'IS'),
+    ('italian republic', 'IT'),
+    ('hashemite kingdom of jordan', 'JO'),
+    ('republic of kenya', 'KE'),
+    ('kyrgyz republic', 'KG'),
+    ('kingdom of cambodia', 'KH'),
+    ('republic of kiribati', 'KI'),
+    ('union of the comoros', 'KM'),
+    ('korea, democratic people''s republic of', 'KP'),
+    ('democratic people''s republic of korea', 'KP'),
+    ('korea, republic of', 'KR'),
+    ('state of kuwait', 'KW'),
+    ('republic of kazakhstan', 'KZ'),
+    ('lao people''s democratic republic', 'LA'),
+    ('lebanese republic', 'LB'),
+    ('principality of liechtenstein', 'LI'),
+    ('democratic socialist republic of sri lanka', 'LK'),
+    ('republic of liberia', 'LR'),
+    ('kingdom of lesotho', 'LS'),
+    ('republic of lithuania', 'LT'),
+    ('grand duchy of luxembourg', 'LU'),
+    ('republic of latvia', 'LV'),
+    ('kingdom of morocco', 'MA'),
+    ('principality of monaco', 'MC'),
+    ('moldova, republic of', 'MD'),
+    ('republic of moldova', 'MD'),
+    ('republic of madagascar', 'MG'),
+    ('republic of the marshall islands', 'MH'),
+    ('republic of north macedonia', 'MK'),
+    ('republic of mali', 'ML'),
+    ('republic of myanmar', 'MM'),
+    ('macao special administrative region of china', 'MO'),
+    ('commonwealth of the northern mariana islands', 'MP'),
+    ('islamic republic of mauritania', 'MR'),
+    ('republic of malta', 'MT'),
+    ('republic of mauritius', 'MU'),
+    ('republic of maldives', 'MV'),
+    ('republic of malawi', 'MW'),
+    ('united mexican states', 'MX'),
+    ('republic of mozambique', 'MZ'),
+    ('republic of namibia', 'NA'),
+    ('republic of the niger', 'NE'),
+    ('federal republic of nigeria', 'NG'),
+    ('republic of nicaragua', 'NI'),
+    ('kingdom of the netherlands', 'NL'),
+    ('kingdom of norway', 'NO'),
+    ('federal democratic republic of nepal', 'NP'),
+    ('republic of nauru', 'NR'),
+    ('sultanate of oman', 'OM'),
+    ('republic of panama', 'PA'),
+    ('republic of peru', 'PE'),
+    ('independent state of papua new guinea', 'PG'),
+    ('republic of the philippines', 'PH'),
+    ('islamic republic of pakistan', 'PK'),
+    ('republic of poland', 'PL'),
+    ('the state of palestine', 'PS'),
+    ('portuguese republic', 'PT'),
+    ('republic of palau', 'PW'),
+    ('republic of paraguay', 'PY'),
+    ('state of qatar', 'QA'),
+    ('republic of serbia', 'RS'),
+    ('rwandese republic', 'RW'),
+    ('kingdom of saudi arabia', 'SA'),
+    ('republic of seychelles', 'SC'),
+    ('republic of the sudan', 'SD'),
+    ('kingdom of sweden', 'SE'),
+    ('republic of singapore', 'SG'),
+    ('republic of slovenia', 'SI'),
+    ('slovak republic', 'SK'),
+    ('republic of sierra leone', 'SL'),
+    ('republic of san marino', 'SM'),
+    ('republic of senegal', 'SN'),
+    ('federal republic of somalia', 'SO'),
+    ('republic of suriname', 'SR'),
+    ('republic of south sudan', 'SS'),
+    ('democratic republic of sao tome and principe', 'ST'),
+    ('republic of el salvador', 'SV'),
+    ('syrian arab republic', 'SY'),
+    ('kingdom of eswatini', 'SZ'),
+    ('republic of chad', 'TD'),
+    ('togolese republic', 'TG'),
+    ('kingdom of thailand', 'TH'),
+    ('republic of tajikistan', 'TJ'),
+    ('democratic republic of timor-leste', 'TL'),
+    ('republic of tunisia', 'TN'),
+    ('kingdom of tonga', 'TO'),
+    ('republic of türkiye', 'TR'),
+    ('republic of trinidad and tobago', 'TT'),
+    ('taiwan, province of china', 'TW'),
+    ('tanzania, united republic of', 'TZ'),
+    ('united republic of tanzania', 'TZ'),
+    ('republic of uganda', 'UG'),
+    ('united states of america', 'US'),
+    ('eastern republic of uruguay', 'UY'),
+    ('republic of uzbekistan', 'UZ'),
+    ('venezuela, bolivarian republic of', 'VE'),
+    ('bolivarian republic of venezuela', 'VE'),
+    ('british virgin islands', 'VG'),
+    ('virgin islands of the united states', 'VI'),
+    ('viet nam', 'VN'),
+    ('socialist republic of viet nam', 'VN'),
+    ('republic of vanuatu', 'VU'),
+    ('independent state of samoa', 'WS'),
+    ('republic of yemen', 'YE'),
+    ('republic of south africa', 'ZA'),
+    ('republic of zambia', 'ZM'),
+    ('republic of zimbabwe', 'ZW'),
+    ('tschechische republik', 'CZ'),
+    ('uk', 'GB'),
+    ('great britain', 'GB'),
+    ('großbritannien', 'GB'),
+    ('england', 'GB'),
+    ('holland', 'NL'),
+    ('russia', 'RU'),
+    ('russland', 'RU'),
+    ('turkey', 'TR'),
+    ('vereinigte staaten von amerika', 'US'),
+    ('vatican', 'VA'),
+    ('democratic republic of the congo', 'CD')
+) c(name, code)
+where lower(trim(i.unconverted_country)) = c.name;
@@ -0,0 +1,2 @@
+drop table coin_transactions;
+drop table coin_balances;
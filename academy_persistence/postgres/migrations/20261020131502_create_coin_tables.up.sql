@@ -0,0 +1,18 @@
+create table coin_balances (
+    user_id uuid primary key references users(id) on delete cascade,
+    coins bigint not null check (coins >= 0),
+    withheld_coins bigint not null check (withheld_coins >= 0)
+);
+
+create table coin_transactions (
+    id uuid primary key,
+    user_id uuid not null references users(id) on delete cascade,
+    kind text not null,
+    source text not null,
+    destination text not null check (destination <> source),
+    coins bigint not null check (coins > 0),
+    description text,
+    created_at timestamp with time zone not null
+);
+
+create index coin_transactions_user_id_idx on coin_transactions (user_id, created_at);
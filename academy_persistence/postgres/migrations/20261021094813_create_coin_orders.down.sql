@@ -0,0 +1 @@
+drop table coin_orders;
@@ -0,0 +1,13 @@
+create table coin_orders (
+    id uuid primary key,
+    user_id uuid not null references users(id) on delete cascade,
+    package text not null,
+    coins bigint not null check (coins > 0),
+    price bigint not null check (price >= 0),
+    status text not null,
+    payment_id text not null unique,
+    created_at timestamp with time zone not null,
+    updated_at timestamp with time zone not null
+);
+
+create index coin_orders_user_id_idx on coin_orders (user_id, created_at);
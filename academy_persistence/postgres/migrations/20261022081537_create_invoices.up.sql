@@ -0,0 +1,32 @@
+-- last issued invoice number per year
+create table invoice_numbers (
+    year integer primary key,
+    last_number bigint not null check (last_number > 0)
+);
+
+-- invoices have to be retained after the user has been deleted, so user_id
+-- intentionally does not reference the users table
+create table invoices (
+    id uuid primary key,
+    user_id uuid not null,
+    number text not null unique,
+    kind text not null,
+    business boolean not null,
+    first_name text,
+    last_name text,
+    street text,
+    zip_code text,
+    city text,
+    country text not null,
+    vat_id text,
+    vat_id_valid boolean not null,
+    description text not null,
+    net bigint not null check (net >= 0),
+    vat bigint not null check (vat >= 0),
+    gross bigint not null check (gross = net + vat),
+    vat_rate integer not null check (vat_rate >= 0),
+    vat_treatment text not null,
+    created_at timestamp with time zone not null
+);
+
+create index invoices_user_id_idx on invoices (user_id, created_at);
@@ -0,0 +1,6 @@
+drop table lecture_completions;
+drop table lectures;
+drop table course_sections;
+drop table courses;
+drop table skill_prerequisites;
+drop table skills;
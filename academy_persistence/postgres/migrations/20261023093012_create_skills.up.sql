@@ -0,0 +1,52 @@
+create table skills (
+    id uuid primary key,
+    title text not null,
+    description text not null,
+    created_at timestamp with time zone not null
+);
+
+create table skill_prerequisites (
+    skill_id uuid not null references skills(id) on delete cascade,
+    prerequisite_id uuid not null references skills(id) on delete cascade,
+    primary key (skill_id, prerequisite_id)
+);
+
+create index skill_prerequisites_prerequisite_id_idx on skill_prerequisites (prerequisite_id);
+
+create table courses (
+    id uuid primary key,
+    skill_id uuid not null references skills(id) on delete cascade,
+    title text not null,
+    description text not null,
+    created_at timestamp with time zone not null
+);
+
+create index courses_skill_id_idx on courses (skill_id);
+
+create table course_sections (
+    id uuid primary key,
+    course_id uuid not null references courses(id) on delete cascade,
+    title text not null,
+    position integer not null
+);
+
+create index course_sections_course_id_idx on course_sections (course_id);
+
+create table lectures (
+    id uuid primary key,
+    section_id uuid not null references course_sections(id) on delete cascade,
+    title text not null,
+    description text not null,
+    position integer not null
+);
+
+create index lectures_section_id_idx on lectures (section_id);
+
+create table lecture_completions (
+    user_id uuid not null references users(id) on delete cascade,
+    lecture_id uuid not null references lectures(id) on delete cascade,
+    completed_at timestamp with time zone not null,
+    primary key (user_id, lecture_id)
+);
+
+create index lecture_completions_lecture_id_idx on lecture_completions (lecture_id);
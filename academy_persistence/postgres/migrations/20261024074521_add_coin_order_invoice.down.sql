@@ -0,0 +1 @@
+alter table coin_orders drop column invoice_id;
@@ -0,0 +1,5 @@
+-- the invoice of a completed order is issued after the coins have been
+-- credited, orders without invoice are picked up again by a background task
+alter table coin_orders add column invoice_id uuid references invoices(id);
+
+create index coin_orders_missing_invoice_idx on coin_orders (updated_at) where status = 'completed' and invoice_id is null;
@@ -0,0 +1,3 @@
+drop index coin_transactions_reference_idx;
+
+alter table coin_transactions drop column reference;
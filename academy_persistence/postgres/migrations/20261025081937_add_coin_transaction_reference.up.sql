@@ -0,0 +1,4 @@
+alter table coin_transactions add column reference text;
+
+-- withheld coins have been earned, so both kinds share their references
+create unique index coin_transactions_reference_idx on coin_transactions (user_id, (case kind when 'withheld' then 'earn' else kind end), reference) where reference is not null;
@@ -0,0 +1,229 @@
+use academy_di::Build;
+use academy_models::{
+    checkout::{CoinOrder, CoinOrderId, CoinOrderStatus, PaymentId},
+    invoice::InvoiceId,
+    pagination::PaginationSlice,
+    user::UserId,
+};
+use academy_persistence_contracts::checkout::CheckoutRepository;
+use academy_utils::trace_instrument;
+use anyhow::anyhow;
+use bb8_postgres::tokio_postgres::Row;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
+
+#[derive(Debug, Clone, Build)]
+pub struct PostgresCheckoutRepository;
+
+columns!(order as "o": "id", "user_id", "package", "coins", "price", "status", "payment_id", "created_at", "updated_at");
+
+impl CheckoutRepository<PostgresTransaction> for PostgresCheckoutRepository {
+    #[trace_instrument(skip(self, txn))]
+    async fn create_order(
+        &self,
+        txn: &mut PostgresTransaction,
+        order: &CoinOrder,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into coin_orders ({ORDER_COL_NAMES}) values ({})",
+                    arg_indices(1..=ORDER_CNT)
+                ),
+                &[
+                    &*order.id,
+                    &*order.user_id,
+                    &order.package.as_str(),
+                    &(*order.coins as i64),
+                    &(order.price as i64),
+                    &encode_status(order.status),
+                    &order.payment_id.as_str(),
+                    &order.created_at,
+                    &order.updated_at,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_order(
+        &self,
+        txn: &mut PostgresTransaction,
+        order_id: CoinOrderId,
+    ) -> anyhow::Result<Option<CoinOrder>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {ORDER_COLS} from coin_orders o where o.id=$1"),
+                &[&*order_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_order(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_order_by_payment_id(
+        &self,
+        txn: &mut PostgresTransaction,
+        payment_id: &PaymentId,
+    ) -> anyhow::Result<Option<CoinOrder>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {ORDER_COLS} from coin_orders o where o.payment_id=$1"),
+                &[&payment_id.as_str()],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_order(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_orders(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        pagination: PaginationSlice,
+    ) -> anyhow::Result<Vec<CoinOrder>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {ORDER_COLS} from coin_orders o where o.user_id=$1 order by \
+                     o.created_at desc, o.id asc limit $2 offset $3"
+                ),
+                &[
+                    &*user_id,
+                    &(*pagination.limit as i64),
+                    &(pagination.offset as i64),
+                ],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_order(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn count_orders(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+    ) -> anyhow::Result<u64> {
+        txn.txn()
+            .query_one(
+                "select count(*) from coin_orders where user_id=$1",
+                &[&*user_id],
+            )
+            .await
+            .map(|row| row.get::<_, i64>(0) as _)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_order_status(
+        &self,
+        txn: &mut PostgresTransaction,
+        order_id: CoinOrderId,
+        expected: CoinOrderStatus,
+        status: CoinOrderStatus,
+        updated_at: DateTime<Utc>,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "update coin_orders set status=$3, updated_at=$4 where id=$1 and status=$2",
+                &[
+                    &*order_id,
+                    &encode_status(expected),
+                    &encode_status(status),
+                    &updated_at,
+                ],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_orders_without_invoice(
+        &self,
+        txn: &mut PostgresTransaction,
+    ) -> anyhow::Result<Vec<CoinOrder>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {ORDER_COLS} from coin_orders o where o.status=$1 and o.invoice_id is \
+                     null order by o.updated_at"
+                ),
+                &[&encode_status(CoinOrderStatus::Completed)],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_order(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn set_order_invoice(
+        &self,
+        txn: &mut PostgresTransaction,
+        order_id: CoinOrderId,
+        invoice_id: InvoiceId,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "update coin_orders set invoice_id=$2 where id=$1 and invoice_id is null",
+                &[&*order_id, &*invoice_id],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+}
+
+fn decode_order(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<CoinOrder> {
+    Ok(CoinOrder {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        user_id: row.get::<_, Uuid>(cnt.idx()).into(),
+        package: row.get::<_, String>(cnt.idx()).try_into()?,
+        coins: (row.get::<_, i64>(cnt.idx()) as u64).try_into()?,
+        price: row.get::<_, i64>(cnt.idx()) as _,
+        status: decode_status(row.get(cnt.idx()))?,
+        payment_id: row.get::<_, String>(cnt.idx()).try_into()?,
+        created_at: row.get(cnt.idx()),
+        updated_at: row.get(cnt.idx()),
+    })
+}
+
+fn encode_status(status: CoinOrderStatus) -> &'static str {
+    match status {
+        CoinOrderStatus::Pending => "pending",
+        CoinOrderStatus::Completed => "completed",
+        CoinOrderStatus::Failed => "failed",
+        CoinOrderStatus::Cancelled => "cancelled",
+    }
+}
+
+fn decode_status(status: &str) -> anyhow::Result<CoinOrderStatus> {
+    match status {
+        "pending" => Ok(CoinOrderStatus::Pending),
+        "completed" => Ok(CoinOrderStatus::Completed),
+        "failed" => Ok(CoinOrderStatus::Failed),
+        "cancelled" => Ok(CoinOrderStatus::Cancelled),
+        _ => Err(anyhow!("Invalid coin order status: {status}")),
+    }
+}
@@ -0,0 +1,276 @@
+use academy_di::Build;
+use academy_models::{
+    coin::{
+        CoinAccount, CoinBalance, CoinTransaction, CoinTransactionKind, CoinTransactionReference,
+    },
+    pagination::PaginationSlice,
+    user::UserId,
+};
+use academy_persistence_contracts::coin::{CoinRepository, CoinTransactionCreateResult};
+use academy_utils::trace_instrument;
+use anyhow::anyhow;
+use bb8_postgres::tokio_postgres::Row;
+use uuid::Uuid;
+
+use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
+
+#[derive(Debug, Clone, Build)]
+pub struct PostgresCoinRepository;
+
+columns!(transaction as "t": "id", "user_id", "kind", "source", "destination", "coins", "description", "reference", "created_at");
+
+impl CoinRepository<PostgresTransaction> for PostgresCoinRepository {
+    #[trace_instrument(skip(self, txn))]
+    async fn get_balance(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+    ) -> anyhow::Result<CoinBalance> {
+        txn.txn()
+            .query_opt(
+                "select coins, withheld_coins from coin_balances where user_id=$1",
+                &[&*user_id],
+            )
+            .await
+            .map(|row| row.map(|row| decode_balance(&row)).unwrap_or_default())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create_transaction(
+        &self,
+        txn: &mut PostgresTransaction,
+        transaction: &CoinTransaction,
+    ) -> anyhow::Result<CoinTransactionCreateResult> {
+        let delta = |account| {
+            let coins = *transaction.coins as i64;
+            if transaction.kind.destination() == account {
+                coins
+            } else if transaction.kind.source() == account {
+                -coins
+            } else {
+                0
+            }
+        };
+
+        // The transaction is inserted before the balance is updated, so that a concurrent
+        // transaction with the same reference waits for this one to finish and is then skipped
+        // instead of failing with a unique violation.
+        let inserted = txn
+            .txn()
+            .execute(
+                &format!(
+                    "insert into coin_transactions ({TRANSACTION_COL_NAMES}) values ({}) on \
+                     conflict do nothing",
+                    arg_indices(1..=TRANSACTION_CNT)
+                ),
+                &[
+                    &*transaction.id,
+                    &*transaction.user_id,
+                    &encode_kind(transaction.kind),
+                    &encode_account(transaction.kind.source()),
+                    &encode_account(transaction.kind.destination()),
+                    &(*transaction.coins as i64),
+                    &transaction.description.as_deref(),
+                    &transaction.reference.as_deref(),
+                    &transaction.created_at,
+                ],
+            )
+            .await?
+            != 0;
+
+        if !inserted {
+            let existing = match &transaction.reference {
+                Some(reference) => {
+                    self.get_transaction_by_reference(
+                        txn,
+                        transaction.user_id,
+                        transaction.kind,
+                        reference,
+                    )
+                    .await?
+                }
+                None => None,
+            };
+            return existing
+                .map(CoinTransactionCreateResult::Duplicate)
+                .ok_or_else(|| anyhow!("Coin transaction {} already exists", *transaction.id));
+        }
+
+        txn.txn()
+            .execute(
+                "insert into coin_balances (user_id, coins, withheld_coins) values ($1, 0, 0) on \
+                 conflict (user_id) do nothing",
+                &[&*transaction.user_id],
+            )
+            .await?;
+
+        let balance = txn
+            .txn()
+            .query_opt(
+                "update coin_balances set coins=coins+$2, withheld_coins=withheld_coins+$3 where \
+                 user_id=$1 and coins+$2>=0 and withheld_coins+$3>=0 returning coins, \
+                 withheld_coins",
+                &[
+                    &*transaction.user_id,
+                    &delta(CoinAccount::Available),
+                    &delta(CoinAccount::Withheld),
+                ],
+            )
+            .await?
+            .map(|row| decode_balance(&row));
+
+        match balance {
+            Some(balance) => Ok(CoinTransactionCreateResult::Created(balance)),
+            None => {
+                txn.txn()
+                    .execute(
+                        "delete from coin_transactions where id=$1",
+                        &[&*transaction.id],
+                    )
+                    .await?;
+                Ok(CoinTransactionCreateResult::Overdrawn)
+            }
+        }
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_transaction_by_reference(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        kind: CoinTransactionKind,
+        reference: &CoinTransactionReference,
+    ) -> anyhow::Result<Option<CoinTransaction>> {
+        txn.txn()
+            .query_opt(
+                &format!(
+                    "select {TRANSACTION_COLS} from coin_transactions t where t.user_id=$1 and \
+                     (case t.kind when 'withheld' then 'earn' else t.kind end)=$2 and \
+                     t.reference=$3"
+                ),
+                &[&*user_id, &encode_reference_kind(kind), &**reference],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_transaction(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_transactions(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        pagination: PaginationSlice,
+    ) -> anyhow::Result<Vec<CoinTransaction>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {TRANSACTION_COLS} from coin_transactions t where t.user_id=$1 order \
+                     by t.created_at desc, t.id asc limit $2 offset $3"
+                ),
+                &[
+                    &*user_id,
+                    &(*pagination.limit as i64),
+                    &(pagination.offset as i64),
+                ],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_transaction(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn count_transactions(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+    ) -> anyhow::Result<u64> {
+        txn.txn()
+            .query_one(
+                "select count(*) from coin_transactions where user_id=$1",
+                &[&*user_id],
+            )
+            .await
+            .map(|row| row.get::<_, i64>(0) as _)
+            .map_err(Into::into)
+    }
+}
+
+fn decode_balance(row: &Row) -> CoinBalance {
+    CoinBalance {
+        coins: row.get::<_, i64>(0) as _,
+        withheld_coins: row.get::<_, i64>(1) as _,
+    }
+}
+
+fn decode_transaction(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<CoinTransaction> {
+    let id = row.get::<_, Uuid>(cnt.idx()).into();
+    let user_id = row.get::<_, Uuid>(cnt.idx()).into();
+    let kind = decode_kind(row.get(cnt.idx()))?;
+    // source and destination are implied by the kind of the transaction
+    cnt.idx();
+    cnt.idx();
+    Ok(CoinTransaction {
+        id,
+        user_id,
+        kind,
+        coins: (row.get::<_, i64>(cnt.idx()) as u64).try_into()?,
+        description: row
+            .get::<_, Option<String>>(cnt.idx())
+            .map(TryInto::try_into)
+            .transpose()?,
+        reference: row
+            .get::<_, Option<String>>(cnt.idx())
+            .map(TryInto::try_into)
+            .transpose()?,
+        created_at: row.get(cnt.idx()),
+    })
+}
+
+fn encode_kind(kind: CoinTransactionKind) -> &'static str {
+    match kind {
+        CoinTransactionKind::Earn => "earn",
+        CoinTransactionKind::Spend => "spend",
+        CoinTransactionKind::Withheld => "withheld",
+        CoinTransactionKind::Released => "released",
+        CoinTransactionKind::Refund => "refund",
+        CoinTransactionKind::Purchase => "purchase",
+    }
+}
+
+/// Return the kind under which the references of transactions of the given
+/// kind must be unique, see `coin_transactions_reference_idx`.
+fn encode_reference_kind(kind: CoinTransactionKind) -> &'static str {
+    match kind {
+        CoinTransactionKind::Withheld => encode_kind(CoinTransactionKind::Earn),
+        kind => encode_kind(kind),
+    }
+}
+
+fn decode_kind(kind: &str) -> anyhow::Result<CoinTransactionKind> {
+    match kind {
+        "earn" => Ok(CoinTransactionKind::Earn),
+        "spend" => Ok(CoinTransactionKind::Spend),
+        "withheld" => Ok(CoinTransactionKind::Withheld),
+        "released" => Ok(CoinTransactionKind::Released),
+        "refund" => Ok(CoinTransactionKind::Refund),
+        "purchase" => Ok(CoinTransactionKind::Purchase),
+        _ => Err(anyhow!("Invalid coin transaction kind: {kind}")),
+    }
+}
+
+fn encode_account(account: CoinAccount) -> &'static str {
+    match account {
+        CoinAccount::External => "external",
+        CoinAccount::Available => "available",
+        CoinAccount::Withheld => "withheld",
+    }
+}
@@ -0,0 +1,252 @@
+use academy_di::Build;
+use academy_models::{
+    contact::{
+        ContactInboxMessage, ContactMessage, ContactMessageAuthor, ContactMessageFilter,
+        ContactMessageId, ContactMessageReply, ContactMessageStatus,
+    },
+    pagination::PaginationSlice,
+    user::UserId,
+};
+use academy_persistence_contracts::contact::ContactRepository;
+use academy_utils::trace_instrument;
+use anyhow::anyhow;
+use bb8_postgres::tokio_postgres::Row;
+use uuid::Uuid;
+
+use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
+
+#[derive(Debug, Clone, Build)]
+pub struct PostgresContactRepository;
+
+columns!(message as "m": "id", "author_name", "author_email", "subject", "content", "status", "assignee", "email_message_id", "created_at");
+columns!(reply as "r": "id", "message_id", "author", "content", "email_message_id", "created_at");
+
+const FILTER: &str = "($1::text is null or m.status=$1) and ($2::uuid is null or m.assignee=$2)";
+
+impl ContactRepository<PostgresTransaction> for PostgresContactRepository {
+    #[trace_instrument(skip(self, txn))]
+    async fn list_messages(
+        &self,
+        txn: &mut PostgresTransaction,
+        filter: ContactMessageFilter,
+        pagination: PaginationSlice,
+    ) -> anyhow::Result<Vec<ContactInboxMessage>> {
+        let status = filter.status.map(encode_status);
+        let assignee = filter.assignee.map(|x| *x);
+        txn.txn()
+            .query(
+                &format!(
+                    "select {MESSAGE_COLS} from contact_messages m where {FILTER} order by \
+                     m.created_at desc, m.id asc limit $3 offset $4"
+                ),
+                &[
+                    &status,
+                    &assignee,
+                    &(*pagination.limit as i64),
+                    &(pagination.offset as i64),
+                ],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_message(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn count_messages(
+        &self,
+        txn: &mut PostgresTransaction,
+        filter: ContactMessageFilter,
+    ) -> anyhow::Result<u64> {
+        let status = filter.status.map(encode_status);
+        let assignee = filter.assignee.map(|x| *x);
+        txn.txn()
+            .query_one(
+                &format!("select count(*) from contact_messages m where {FILTER}"),
+                &[&status, &assignee],
+            )
+            .await
+            .map(|row| row.get::<_, i64>(0) as _)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_message(
+        &self,
+        txn: &mut PostgresTransaction,
+        message_id: ContactMessageId,
+    ) -> anyhow::Result<Option<ContactInboxMessage>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {MESSAGE_COLS} from contact_messages m where m.id=$1"),
+                &[&*message_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_message(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create_message(
+        &self,
+        txn: &mut PostgresTransaction,
+        message: &ContactInboxMessage,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into contact_messages ({MESSAGE_COL_NAMES}) values ({})",
+                    arg_indices(1..=MESSAGE_CNT)
+                ),
+                &[
+                    &*message.id,
+                    &*message.message.author.name,
+                    &message.message.author.email.as_str(),
+                    &*message.message.subject,
+                    &*message.message.content,
+                    &encode_status(message.status),
+                    &message.assignee.map(|x| *x),
+                    &message.email_message_id,
+                    &message.created_at,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_message_assignee(
+        &self,
+        txn: &mut PostgresTransaction,
+        message_id: ContactMessageId,
+        assignee: Option<UserId>,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "update contact_messages set assignee=$2 where id=$1",
+                &[&*message_id, &assignee.map(|x| *x)],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_message_status(
+        &self,
+        txn: &mut PostgresTransaction,
+        message_id: ContactMessageId,
+        status: ContactMessageStatus,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "update contact_messages set status=$2 where id=$1",
+                &[&*message_id, &encode_status(status)],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_replies(
+        &self,
+        txn: &mut PostgresTransaction,
+        message_id: ContactMessageId,
+    ) -> anyhow::Result<Vec<ContactMessageReply>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {REPLY_COLS} from contact_message_replies r where r.message_id=$1 \
+                     order by r.created_at asc, r.id asc"
+                ),
+                &[&*message_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_reply(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create_reply(
+        &self,
+        txn: &mut PostgresTransaction,
+        reply: &ContactMessageReply,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into contact_message_replies ({REPLY_COL_NAMES}) values ({})",
+                    arg_indices(1..=REPLY_CNT)
+                ),
+                &[
+                    &*reply.id,
+                    &*reply.message_id,
+                    &reply.author.map(|x| *x),
+                    &*reply.content,
+                    &reply.email_message_id,
+                    &reply.created_at,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+}
+
+fn decode_message(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<ContactInboxMessage> {
+    Ok(ContactInboxMessage {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        message: ContactMessage {
+            author: ContactMessageAuthor {
+                name: row.get::<_, String>(cnt.idx()).try_into()?,
+                email: row.get::<_, &str>(cnt.idx()).parse()?,
+            },
+            subject: row.get::<_, String>(cnt.idx()).try_into()?,
+            content: row.get::<_, String>(cnt.idx()).try_into()?,
+        },
+        status: decode_status(row.get(cnt.idx()))?,
+        assignee: row.get::<_, Option<Uuid>>(cnt.idx()).map(Into::into),
+        email_message_id: row.get(cnt.idx()),
+        created_at: row.get(cnt.idx()),
+    })
+}
+
+fn decode_reply(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<ContactMessageReply> {
+    Ok(ContactMessageReply {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        message_id: row.get::<_, Uuid>(cnt.idx()).into(),
+        author: row.get::<_, Option<Uuid>>(cnt.idx()).map(Into::into),
+        content: row.get::<_, String>(cnt.idx()).try_into()?,
+        email_message_id: row.get(cnt.idx()),
+        created_at: row.get(cnt.idx()),
+    })
+}
+
+fn encode_status(status: ContactMessageStatus) -> &'static str {
+    match status {
+        ContactMessageStatus::Open => "open",
+        ContactMessageStatus::Resolved => "resolved",
+        ContactMessageStatus::Quarantined => "quarantined",
+    }
+}
+
+fn decode_status(status: &str) -> anyhow::Result<ContactMessageStatus> {
+    match status {
+        "open" => Ok(ContactMessageStatus::Open),
+        "resolved" => Ok(ContactMessageStatus::Resolved),
+        "quarantined" => Ok(ContactMessageStatus::Quarantined),
+        _ => Err(anyhow!("Invalid contact message status: {status}")),
+    }
+}
@@ -0,0 +1,330 @@
+use academy_di::Build;
+use academy_models::{
+    email::{Email, EmailBody, EmailHeader, EmailInlineImage},
+    email_outbox::{EmailOutboxEntry, EmailOutboxId, EmailOutboxStatus},
+    pagination::PaginationSlice,
+};
+use academy_persistence_contracts::email_outbox::EmailOutboxRepository;
+use academy_utils::trace_instrument;
+use anyhow::anyhow;
+use bb8_postgres::tokio_postgres::Row;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
+
+#[derive(Debug, Clone, Build)]
+pub struct PostgresEmailOutboxRepository;
+
+columns!(email as "e": "id", "recipient", "subject", "body", "body_text", "content_type", "reply_to", "header_names", "header_values", "inline_image_ids", "inline_image_content_types", "inline_image_data", "status", "attempts", "created_at", "next_attempt_at", "sent_at", "last_error");
+
+impl EmailOutboxRepository<PostgresTransaction> for PostgresEmailOutboxRepository {
+    #[trace_instrument(skip(self, txn))]
+    async fn list(
+        &self,
+        txn: &mut PostgresTransaction,
+        status: Option<EmailOutboxStatus>,
+        pagination: PaginationSlice,
+    ) -> anyhow::Result<Vec<EmailOutboxEntry>> {
+        let status = status.map(encode_status);
+        txn.txn()
+            .query(
+                &format!(
+                    "select {EMAIL_COLS} from email_outbox e where $1::text is null or \
+                     e.status=$1 order by e.created_at desc, e.id asc limit $2 offset $3"
+                ),
+                &[
+                    &status,
+                    &(*pagination.limit as i64),
+                    &(pagination.offset as i64),
+                ],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_email(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn count(
+        &self,
+        txn: &mut PostgresTransaction,
+        status: Option<EmailOutboxStatus>,
+    ) -> anyhow::Result<u64> {
+        let status = status.map(encode_status);
+        txn.txn()
+            .query_one(
+                "select count(*) from email_outbox where $1::text is null or status=$1",
+                &[&status],
+            )
+            .await
+            .map(|row| row.get::<_, i64>(0) as _)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get(
+        &self,
+        txn: &mut PostgresTransaction,
+        email_id: EmailOutboxId,
+    ) -> anyhow::Result<Option<EmailOutboxEntry>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {EMAIL_COLS} from email_outbox e where e.id=$1"),
+                &[&*email_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_email(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create(
+        &self,
+        txn: &mut PostgresTransaction,
+        entry: &EmailOutboxEntry,
+    ) -> anyhow::Result<()> {
+        let (header_names, header_values) = entry
+            .email
+            .headers
+            .iter()
+            .map(|header| (header.name.as_str(), header.value.as_str()))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        let (body, body_text, content_type) = encode_body(&entry.email.body);
+        let inline_images = &entry.email.inline_images;
+
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into email_outbox ({EMAIL_COL_NAMES}) values ({})",
+                    arg_indices(1..=EMAIL_CNT)
+                ),
+                &[
+                    &*entry.id,
+                    &entry.email.recipient.0.to_string(),
+                    &entry.email.subject,
+                    &body,
+                    &body_text,
+                    &content_type,
+                    &entry.email.reply_to.as_ref().map(|x| x.0.to_string()),
+                    &header_names,
+                    &header_values,
+                    &inline_images
+                        .iter()
+                        .map(|x| x.content_id.as_str())
+                        .collect::<Vec<_>>(),
+                    &inline_images
+                        .iter()
+                        .map(|x| x.content_type.as_str())
+                        .collect::<Vec<_>>(),
+                    &inline_images
+                        .iter()
+                        .map(|x| x.data.as_slice())
+                        .collect::<Vec<_>>(),
+                    &encode_status(entry.status),
+                    &(entry.attempts as i32),
+                    &entry.created_at,
+                    &entry.next_attempt_at,
+                    &entry.sent_at,
+                    &entry.last_error,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn lock_due(
+        &self,
+        txn: &mut PostgresTransaction,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> anyhow::Result<Vec<EmailOutboxEntry>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {EMAIL_COLS} from email_outbox e where e.status='pending' and \
+                     e.next_attempt_at<=$1 order by e.next_attempt_at asc, e.id asc limit $2 for \
+                     update skip locked"
+                ),
+                &[&now, &(limit as i64)],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_email(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn mark_sent(
+        &self,
+        txn: &mut PostgresTransaction,
+        email_id: EmailOutboxId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                "update email_outbox set status='sent', sent_at=$2, next_attempt_at=null where \
+                 id=$1",
+                &[&*email_id, &now],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn record_failed_attempt(
+        &self,
+        txn: &mut PostgresTransaction,
+        email_id: EmailOutboxId,
+        error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                "update email_outbox set attempts=attempts+1, last_error=$2, next_attempt_at=$3, \
+                 status=case when $3::timestamptz is null then 'failed' else 'pending' end where \
+                 id=$1",
+                &[&*email_id, &error, &next_attempt_at],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn retry(
+        &self,
+        txn: &mut PostgresTransaction,
+        email_id: EmailOutboxId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "update email_outbox set status='pending', attempts=0, next_attempt_at=$2 where \
+                 id=$1 and status!='sent'",
+                &[&*email_id, &now],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn delete_sent_before(
+        &self,
+        txn: &mut PostgresTransaction,
+        sent_before: DateTime<Utc>,
+    ) -> anyhow::Result<u64> {
+        txn.txn()
+            .execute(
+                "delete from email_outbox where status='sent' and sent_at<$1",
+                &[&sent_before],
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
+
+fn decode_email(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<EmailOutboxEntry> {
+    let id = row.get::<_, Uuid>(cnt.idx()).into();
+    let recipient = row.get::<_, &str>(cnt.idx()).parse()?;
+    let subject = row.get(cnt.idx());
+    let body = row.get(cnt.idx());
+    let body_text = row.get(cnt.idx());
+    let body = decode_body(body, body_text, row.get(cnt.idx()))?;
+    let reply_to = row
+        .get::<_, Option<&str>>(cnt.idx())
+        .map(str::parse)
+        .transpose()?;
+    let header_names = row.get::<_, Vec<String>>(cnt.idx());
+    let header_values = row.get::<_, Vec<String>>(cnt.idx());
+    let inline_image_ids = row.get::<_, Vec<String>>(cnt.idx());
+    let inline_image_content_types = row.get::<_, Vec<String>>(cnt.idx());
+    let inline_image_data = row.get::<_, Vec<Vec<u8>>>(cnt.idx());
+
+    Ok(EmailOutboxEntry {
+        id,
+        email: Email {
+            recipient,
+            subject,
+            body,
+            reply_to,
+            headers: header_names
+                .into_iter()
+                .zip(header_values)
+                .map(|(name, value)| EmailHeader { name, value })
+                .collect(),
+            inline_images: inline_image_ids
+                .into_iter()
+                .zip(inline_image_content_types)
+                .zip(inline_image_data)
+                .map(|((content_id, content_type), data)| EmailInlineImage {
+                    content_id,
+                    content_type,
+                    data,
+                })
+                .collect(),
+        },
+        status: decode_status(row.get(cnt.idx()))?,
+        attempts: row.get::<_, i32>(cnt.idx()) as _,
+        created_at: row.get(cnt.idx()),
+        next_attempt_at: row.get(cnt.idx()),
+        sent_at: row.get(cnt.idx()),
+        last_error: row.get(cnt.idx()),
+    })
+}
+
+fn encode_status(status: EmailOutboxStatus) -> &'static str {
+    match status {
+        EmailOutboxStatus::Pending => "pending",
+        EmailOutboxStatus::Sent => "sent",
+        EmailOutboxStatus::Failed => "failed",
+    }
+}
+
+fn decode_status(status: &str) -> anyhow::Result<EmailOutboxStatus> {
+    match status {
+        "pending" => Ok(EmailOutboxStatus::Pending),
+        "sent" => Ok(EmailOutboxStatus::Sent),
+        "failed" => Ok(EmailOutboxStatus::Failed),
+        _ => Err(anyhow!("Invalid email outbox status: {status}")),
+    }
+}
+
+/// Encode the body of an email as `(body, body_text, content_type)`.
+///
+/// For `multipart/alternative` bodies, `body` contains the HTML version and
+/// `body_text` the plain text version.
+fn encode_body(body: &EmailBody) -> (&str, Option<&str>, &'static str) {
+    match body {
+        EmailBody::Text(text) => (text, None, "text"),
+        EmailBody::Html(html) => (html, None, "html"),
+        EmailBody::Alternative { text, html } => (html, Some(text), "alternative"),
+    }
+}
+
+fn decode_body(
+    body: String,
+    body_text: Option<String>,
+    content_type: &str,
+) -> anyhow::Result<EmailBody> {
+    match (content_type, body_text) {
+        ("text", _) => Ok(EmailBody::Text(body)),
+        ("html", _) => Ok(EmailBody::Html(body)),
+        ("alternative", Some(text)) => Ok(EmailBody::Alternative { text, html: body }),
+        ("alternative", None) => Err(anyhow!("Missing plain text body of email")),
+        _ => Err(anyhow!("Invalid email content type: {content_type}")),
+    }
+}
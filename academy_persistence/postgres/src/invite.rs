@@ -0,0 +1,207 @@
+use academy_di::Build;
+use academy_models::{
+    invite::{Invite, InviteCode, InviteId},
+    user::UserId,
+};
+use academy_persistence_contracts::invite::InviteRepository;
+use academy_utils::trace_instrument;
+use bb8_postgres::tokio_postgres::Row;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
+
+#[derive(Debug, Clone, Build)]
+pub struct PostgresInviteRepository;
+
+columns!(invite as "i": "id", "code", "created_by", "created_at", "expires_at", "max_uses", "uses", "admin", "cohort");
+
+const VALID: &str = "(i.expires_at is null or i.expires_at > $2) and (i.max_uses is null or \
+                     i.uses < i.max_uses)";
+
+impl InviteRepository<PostgresTransaction> for PostgresInviteRepository {
+    #[trace_instrument(skip(self, txn))]
+    async fn list(
+        &self,
+        txn: &mut PostgresTransaction,
+        created_by: Option<UserId>,
+    ) -> anyhow::Result<Vec<Invite>> {
+        let created_by = created_by.map(|x| *x);
+        txn.txn()
+            .query(
+                &format!(
+                    "select {INVITE_COLS} from invites i where $1::uuid is null or \
+                     i.created_by=$1 order by i.created_at asc, i.code asc"
+                ),
+                &[&created_by],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_invite(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn count_valid_by_creator(
+        &self,
+        txn: &mut PostgresTransaction,
+        created_by: UserId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<u64> {
+        txn.txn()
+            .query_one(
+                &format!("select count(*) from invites i where i.created_by=$1 and {VALID}"),
+                &[&*created_by, &now],
+            )
+            .await
+            .map(|row| row.get::<_, i64>(0) as _)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get(
+        &self,
+        txn: &mut PostgresTransaction,
+        invite_id: InviteId,
+    ) -> anyhow::Result<Option<Invite>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {INVITE_COLS} from invites i where i.id=$1"),
+                &[&*invite_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_invite(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_by_code(
+        &self,
+        txn: &mut PostgresTransaction,
+        code: &InviteCode,
+    ) -> anyhow::Result<Option<Invite>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {INVITE_COLS} from invites i where i.code=$1"),
+                &[&**code],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_invite(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create(&self, txn: &mut PostgresTransaction, invite: &Invite) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into invites ({INVITE_COL_NAMES}) values ({})",
+                    arg_indices(1..=INVITE_CNT)
+                ),
+                &[
+                    &*invite.id,
+                    &*invite.code,
+                    &invite.created_by.map(|x| *x),
+                    &invite.created_at,
+                    &invite.expires_at,
+                    &invite.max_uses.map(|x| *x as i64),
+                    &(invite.uses as i64),
+                    &invite.admin,
+                    &invite.cohort.as_deref(),
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn delete(
+        &self,
+        txn: &mut PostgresTransaction,
+        invite_id: InviteId,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute("delete from invites where id=$1", &[&*invite_id])
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn redeem(
+        &self,
+        txn: &mut PostgresTransaction,
+        invite_id: InviteId,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<bool> {
+        let updated = txn
+            .txn()
+            .execute(
+                &format!("update invites i set uses=uses+1 where i.id=$1 and {VALID}"),
+                &[&*invite_id, &now],
+            )
+            .await?;
+        if updated == 0 {
+            return Ok(false);
+        }
+
+        txn.txn()
+            .execute(
+                "insert into user_invites (user_id, invite_id, redeemed_at) values ($1, $2, $3)",
+                &[&*user_id, &*invite_id, &now],
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_invite_id_by_user(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+    ) -> anyhow::Result<Option<InviteId>> {
+        txn.txn()
+            .query_opt(
+                "select invite_id from user_invites where user_id=$1",
+                &[&*user_id],
+            )
+            .await
+            .map(|row| {
+                row.and_then(|row| row.get::<_, Option<Uuid>>(0))
+                    .map(Into::into)
+            })
+            .map_err(Into::into)
+    }
+}
+
+fn decode_invite(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<Invite> {
+    Ok(Invite {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        code: row.get::<_, String>(cnt.idx()).try_into()?,
+        created_by: row.get::<_, Option<Uuid>>(cnt.idx()).map(Into::into),
+        created_at: row.get(cnt.idx()),
+        expires_at: row.get(cnt.idx()),
+        max_uses: row
+            .get::<_, Option<i64>>(cnt.idx())
+            .map(|x| anyhow::Ok(u32::try_from(x)?.try_into()?))
+            .transpose()?,
+        uses: row.get::<_, i64>(cnt.idx()).try_into()?,
+        admin: row.get(cnt.idx()),
+        cohort: row
+            .get::<_, Option<String>>(cnt.idx())
+            .map(TryInto::try_into)
+            .transpose()?,
+    })
+}
@@ -0,0 +1,211 @@
+use academy_di::Build;
+use academy_models::{
+    country::Country,
+    invoice::{Invoice, InvoiceId, InvoiceKind, InvoiceRecipient, InvoiceVatTreatment},
+    pagination::PaginationSlice,
+    user::UserId,
+};
+use academy_persistence_contracts::invoice::InvoiceRepository;
+use academy_utils::trace_instrument;
+use anyhow::anyhow;
+use bb8_postgres::tokio_postgres::Row;
+use uuid::Uuid;
+
+use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
+
+#[derive(Debug, Clone, Build)]
+pub struct PostgresInvoiceRepository;
+
+columns!(invoice as "i": "id", "user_id", "number", "kind", "business", "first_name", "last_name", "street", "zip_code", "city", "country", "vat_id", "vat_id_valid", "description", "net", "vat", "gross", "vat_rate", "vat_treatment", "created_at");
+
+impl InvoiceRepository<PostgresTransaction> for PostgresInvoiceRepository {
+    #[trace_instrument(skip(self, txn))]
+    async fn next_number(&self, txn: &mut PostgresTransaction, year: i32) -> anyhow::Result<u64> {
+        txn.txn()
+            .query_one(
+                "insert into invoice_numbers (year, last_number) values ($1, 1) on conflict \
+                 (year) do update set last_number=invoice_numbers.last_number+1 returning \
+                 last_number",
+                &[&year],
+            )
+            .await
+            .map(|row| row.get::<_, i64>(0) as _)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create(&self, txn: &mut PostgresTransaction, invoice: &Invoice) -> anyhow::Result<()> {
+        let recipient = &invoice.recipient;
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into invoices ({INVOICE_COL_NAMES}) values ({})",
+                    arg_indices(1..=INVOICE_CNT)
+                ),
+                &[
+                    &*invoice.id,
+                    &*invoice.user_id,
+                    &invoice.number.as_str(),
+                    &encode_kind(invoice.kind),
+                    &recipient.business,
+                    &recipient.first_name.as_ref().map(|x| x.as_str()),
+                    &recipient.last_name.as_ref().map(|x| x.as_str()),
+                    &recipient.street.as_ref().map(|x| x.as_str()),
+                    &recipient.zip_code.as_ref().map(|x| x.as_str()),
+                    &recipient.city.as_ref().map(|x| x.as_str()),
+                    &recipient.country.code(),
+                    &recipient.vat_id.as_ref().map(|x| x.as_str()),
+                    &recipient.vat_id_valid,
+                    &invoice.description.as_str(),
+                    &(invoice.net as i64),
+                    &(invoice.vat as i64),
+                    &(invoice.gross as i64),
+                    &(invoice.vat_rate as i32),
+                    &encode_vat_treatment(invoice.vat_treatment),
+                    &invoice.created_at,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get(
+        &self,
+        txn: &mut PostgresTransaction,
+        invoice_id: InvoiceId,
+    ) -> anyhow::Result<Option<Invoice>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {INVOICE_COLS} from invoices i where i.id=$1"),
+                &[&*invoice_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_invoice(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        pagination: PaginationSlice,
+    ) -> anyhow::Result<Vec<Invoice>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {INVOICE_COLS} from invoices i where i.user_id=$1 order by \
+                     i.created_at desc, i.id asc limit $2 offset $3"
+                ),
+                &[
+                    &*user_id,
+                    &(*pagination.limit as i64),
+                    &(pagination.offset as i64),
+                ],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_invoice(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn count(&self, txn: &mut PostgresTransaction, user_id: UserId) -> anyhow::Result<u64> {
+        txn.txn()
+            .query_one(
+                "select count(*) from invoices where user_id=$1",
+                &[&*user_id],
+            )
+            .await
+            .map(|row| row.get::<_, i64>(0) as _)
+            .map_err(Into::into)
+    }
+}
+
+fn decode_invoice(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<Invoice> {
+    Ok(Invoice {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        user_id: row.get::<_, Uuid>(cnt.idx()).into(),
+        number: row.get::<_, String>(cnt.idx()).try_into()?,
+        kind: decode_kind(row.get(cnt.idx()))?,
+        recipient: InvoiceRecipient {
+            business: row.get(cnt.idx()),
+            first_name: row
+                .get::<_, Option<String>>(cnt.idx())
+                .map(TryInto::try_into)
+                .transpose()?,
+            last_name: row
+                .get::<_, Option<String>>(cnt.idx())
+                .map(TryInto::try_into)
+                .transpose()?,
+            street: row
+                .get::<_, Option<String>>(cnt.idx())
+                .map(TryInto::try_into)
+                .transpose()?,
+            zip_code: row
+                .get::<_, Option<String>>(cnt.idx())
+                .map(TryInto::try_into)
+                .transpose()?,
+            city: row
+                .get::<_, Option<String>>(cnt.idx())
+                .map(TryInto::try_into)
+                .transpose()?,
+            country: {
+                let country = row.get::<_, &str>(cnt.idx());
+                Country::from_code(country).ok_or_else(|| anyhow!("Invalid country: {country}"))?
+            },
+            vat_id: row
+                .get::<_, Option<String>>(cnt.idx())
+                .map(TryInto::try_into)
+                .transpose()?,
+            vat_id_valid: row.get(cnt.idx()),
+        },
+        description: row.get::<_, String>(cnt.idx()).try_into()?,
+        net: row.get::<_, i64>(cnt.idx()) as _,
+        vat: row.get::<_, i64>(cnt.idx()) as _,
+        gross: row.get::<_, i64>(cnt.idx()) as _,
+        vat_rate: row.get::<_, i32>(cnt.idx()) as _,
+        vat_treatment: decode_vat_treatment(row.get(cnt.idx()))?,
+        created_at: row.get(cnt.idx()),
+    })
+}
+
+fn encode_kind(kind: InvoiceKind) -> &'static str {
+    match kind {
+        InvoiceKind::Purchase => "purchase",
+        InvoiceKind::Payout => "payout",
+    }
+}
+
+fn decode_kind(kind: &str) -> anyhow::Result<InvoiceKind> {
+    match kind {
+        "purchase" => Ok(InvoiceKind::Purchase),
+        "payout" => Ok(InvoiceKind::Payout),
+        _ => Err(anyhow!("Invalid invoice kind: {kind}")),
+    }
+}
+
+fn encode_vat_treatment(vat_treatment: InvoiceVatTreatment) -> &'static str {
+    match vat_treatment {
+        InvoiceVatTreatment::Standard => "standard",
+        InvoiceVatTreatment::ReverseCharge => "reverse_charge",
+        InvoiceVatTreatment::NotTaxable => "not_taxable",
+    }
+}
+
+fn decode_vat_treatment(vat_treatment: &str) -> anyhow::Result<InvoiceVatTreatment> {
+    match vat_treatment {
+        "standard" => Ok(InvoiceVatTreatment::Standard),
+        "reverse_charge" => Ok(InvoiceVatTreatment::ReverseCharge),
+        "not_taxable" => Ok(InvoiceVatTreatment::NotTaxable),
+        _ => Err(anyhow!("Invalid invoice vat treatment: {vat_treatment}")),
+    }
+}
@@ -12,9 +12,17 @@ use bb8_postgres::{
 use ouroboros::self_referencing;
 use tracing::trace;
 
+pub mod checkout;
+pub mod coin;
+pub mod contact;
+pub mod email_outbox;
+pub mod invite;
+pub mod invoice;
 pub mod mfa;
+pub mod newsletter;
 pub mod oauth2;
 pub mod session;
+pub mod skill;
 pub mod user;
 
 type PgClient = tokio_postgres::Client;
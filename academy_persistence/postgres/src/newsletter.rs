@@ -0,0 +1,336 @@
+use std::fmt::Write;
+
+use academy_di::Build;
+use academy_models::{
+    email_address::EmailAddress,
+    newsletter::{
+        NewsletterCampaign, NewsletterCampaignId, NewsletterCampaignPatchRef,
+        NewsletterCampaignProgress, NewsletterCampaignStatus, NewsletterRecipient,
+        NewsletterRecipientStatus,
+    },
+    user::UserId,
+};
+use academy_persistence_contracts::newsletter::NewsletterRepository;
+use academy_utils::{patch::PatchValue, trace_instrument};
+use anyhow::anyhow;
+use bb8_postgres::tokio_postgres::{types::ToSql, Row};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
+
+#[derive(Debug, Clone, Build)]
+pub struct PostgresNewsletterRepository;
+
+columns!(campaign as "c": "id", "subject", "body_html", "body_text", "status", "created_at", "started_at", "completed_at");
+
+const PROGRESS_COLS: &str = "pr.pending, pr.sent, pr.failed, pr.skipped";
+const JOIN_PROGRESS: &str = "cross join lateral (select count(*) filter (where \
+                             r.status='pending') pending, count(*) filter (where \
+                             r.status='sent') sent, count(*) filter (where r.status='failed') \
+                             failed, count(*) filter (where r.status='skipped') skipped from \
+                             newsletter_recipients r where r.campaign_id=c.id) pr";
+
+/// Must match the conditions under which users are added as recipients in
+/// [`PostgresNewsletterRepository::start_campaign`].
+const SUBSCRIBED: &str = "u.enabled and u.newsletter and u.email_verified and u.email is not null";
+
+impl NewsletterRepository<PostgresTransaction> for PostgresNewsletterRepository {
+    #[trace_instrument(skip(self, txn))]
+    async fn list_campaigns(
+        &self,
+        txn: &mut PostgresTransaction,
+        status: Option<NewsletterCampaignStatus>,
+    ) -> anyhow::Result<Vec<NewsletterCampaign>> {
+        let status = status.map(encode_campaign_status);
+        txn.txn()
+            .query(
+                &format!(
+                    "select {CAMPAIGN_COLS}, {PROGRESS_COLS} from newsletter_campaigns c \
+                     {JOIN_PROGRESS} where $1::text is null or c.status=$1 order by \
+                     c.created_at desc, c.id asc"
+                ),
+                &[&status],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_campaign(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_campaign(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign_id: NewsletterCampaignId,
+    ) -> anyhow::Result<Option<NewsletterCampaign>> {
+        txn.txn()
+            .query_opt(
+                &format!(
+                    "select {CAMPAIGN_COLS}, {PROGRESS_COLS} from newsletter_campaigns c \
+                     {JOIN_PROGRESS} where c.id=$1"
+                ),
+                &[&*campaign_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_campaign(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create_campaign(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign: &NewsletterCampaign,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into newsletter_campaigns ({CAMPAIGN_COL_NAMES}) values ({})",
+                    arg_indices(1..=CAMPAIGN_CNT)
+                ),
+                &[
+                    &*campaign.id,
+                    &*campaign.subject,
+                    &*campaign.body_html,
+                    &*campaign.body_text,
+                    &encode_campaign_status(campaign.status),
+                    &campaign.created_at,
+                    &campaign.started_at,
+                    &campaign.completed_at,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_campaign<'a>(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign_id: NewsletterCampaignId,
+        NewsletterCampaignPatchRef {
+            subject,
+            body_html,
+            body_text,
+        }: NewsletterCampaignPatchRef<'a>,
+    ) -> anyhow::Result<bool> {
+        let mut query = "update newsletter_campaigns set id=id".to_owned();
+        let mut params: Vec<&(dyn ToSql + Sync)> = vec![&*campaign_id];
+
+        let updates = [
+            ("subject", subject.map(|x| x.as_str())),
+            ("body_html", body_html.map(|x| x.as_str())),
+            ("body_text", body_text.map(|x| x.as_str())),
+        ];
+        for (col, value) in &updates {
+            if let PatchValue::Update(value) = value {
+                params.push(value);
+                write!(&mut query, ", {col}=${}", params.len()).unwrap();
+            }
+        }
+
+        query.push_str(" where id=$1");
+
+        txn.txn()
+            .execute(&query, &params)
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn delete_campaign(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign_id: NewsletterCampaignId,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "delete from newsletter_campaigns where id=$1",
+                &[&*campaign_id],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn start_campaign(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Option<u64>> {
+        let updated = txn
+            .txn()
+            .execute(
+                "update newsletter_campaigns set status='sending', started_at=$2 where id=$1 and \
+                 status='draft'",
+                &[&*campaign_id, &now],
+            )
+            .await?;
+        if updated == 0 {
+            return Ok(None);
+        }
+
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into newsletter_recipients (campaign_id, user_id, status, \
+                     updated_at) select $1, u.id, 'pending', $2 from users u where {SUBSCRIBED}"
+                ),
+                &[&*campaign_id, &now],
+            )
+            .await
+            .map(Some)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn complete_campaign(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "update newsletter_campaigns set status='sent', completed_at=$2 where id=$1 and \
+                 status='sending'",
+                &[&*campaign_id, &now],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn skip_unsubscribed_recipients(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign_id: NewsletterCampaignId,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<u64> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "update newsletter_recipients r set status='skipped', updated_at=$2 from \
+                     users u where r.campaign_id=$1 and r.status='pending' and u.id=r.user_id \
+                     and not ({SUBSCRIBED})"
+                ),
+                &[&*campaign_id, &now],
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn lock_pending_recipients(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign_id: NewsletterCampaignId,
+        limit: u64,
+    ) -> anyhow::Result<Vec<NewsletterRecipient>> {
+        txn.txn()
+            .query(
+                "select r.user_id, u.email, p.display_name from newsletter_recipients r inner \
+                 join users u on u.id=r.user_id inner join user_profiles p on \
+                 p.user_id=r.user_id where r.campaign_id=$1 and r.status='pending' and u.email \
+                 is not null order by r.user_id limit $2 for update of r skip locked",
+                &[&*campaign_id, &(limit as i64)],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| {
+                        Ok(NewsletterRecipient {
+                            user_id: row.get::<_, Uuid>(0).into(),
+                            email: row
+                                .get::<_, &str>(1)
+                                .parse::<EmailAddress>()?
+                                .with_name(row.get(2)),
+                        })
+                    })
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_recipient_status(
+        &self,
+        txn: &mut PostgresTransaction,
+        campaign_id: NewsletterCampaignId,
+        user_id: UserId,
+        status: NewsletterRecipientStatus,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                "update newsletter_recipients set status=$3, updated_at=$4 where campaign_id=$1 \
+                 and user_id=$2",
+                &[
+                    &*campaign_id,
+                    &*user_id,
+                    &encode_recipient_status(status),
+                    &now,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+}
+
+fn decode_campaign(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<NewsletterCampaign> {
+    Ok(NewsletterCampaign {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        subject: row.get::<_, String>(cnt.idx()).try_into()?,
+        body_html: row.get::<_, String>(cnt.idx()).try_into()?,
+        body_text: row.get::<_, String>(cnt.idx()).try_into()?,
+        status: decode_campaign_status(row.get(cnt.idx()))?,
+        created_at: row.get(cnt.idx()),
+        started_at: row.get(cnt.idx()),
+        completed_at: row.get(cnt.idx()),
+        progress: NewsletterCampaignProgress {
+            pending: row.get::<_, i64>(cnt.idx()) as _,
+            sent: row.get::<_, i64>(cnt.idx()) as _,
+            failed: row.get::<_, i64>(cnt.idx()) as _,
+            skipped: row.get::<_, i64>(cnt.idx()) as _,
+        },
+    })
+}
+
+fn encode_campaign_status(status: NewsletterCampaignStatus) -> &'static str {
+    match status {
+        NewsletterCampaignStatus::Draft => "draft",
+        NewsletterCampaignStatus::Sending => "sending",
+        NewsletterCampaignStatus::Sent => "sent",
+    }
+}
+
+fn decode_campaign_status(status: &str) -> anyhow::Result<NewsletterCampaignStatus> {
+    match status {
+        "draft" => Ok(NewsletterCampaignStatus::Draft),
+        "sending" => Ok(NewsletterCampaignStatus::Sending),
+        "sent" => Ok(NewsletterCampaignStatus::Sent),
+        _ => Err(anyhow!("Invalid newsletter campaign status: {status}")),
+    }
+}
+
+fn encode_recipient_status(status: NewsletterRecipientStatus) -> &'static str {
+    match status {
+        NewsletterRecipientStatus::Pending => "pending",
+        NewsletterRecipientStatus::Sent => "sent",
+        NewsletterRecipientStatus::Failed => "failed",
+        NewsletterRecipientStatus::Skipped => "skipped",
+    }
+}
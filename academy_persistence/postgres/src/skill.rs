@@ -0,0 +1,629 @@
+use std::fmt::Write;
+
+use academy_di::Build;
+use academy_models::{
+    skill::{
+        Course, CourseId, CoursePatchRef, CourseSection, CourseSectionId, CourseSectionPatchRef,
+        LearningProgress, Lecture, LectureId, LecturePatchRef, Skill, SkillId, SkillPatchRef,
+        SkillPrerequisite,
+    },
+    user::UserId,
+};
+use academy_persistence_contracts::skill::SkillRepository;
+use academy_utils::{patch::PatchValue, trace_instrument};
+use bb8_postgres::tokio_postgres::{types::ToSql, Row};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
+
+#[derive(Debug, Clone, Build)]
+pub struct PostgresSkillRepository;
+
+columns!(skill as "s": "id", "title", "description", "created_at");
+columns!(course as "c": "id", "skill_id", "title", "description", "created_at");
+columns!(section as "cs": "id", "course_id", "title", "position");
+columns!(lecture as "l": "id", "section_id", "title", "description", "position");
+
+impl SkillRepository<PostgresTransaction> for PostgresSkillRepository {
+    #[trace_instrument(skip(self, txn))]
+    async fn list_skills(&self, txn: &mut PostgresTransaction) -> anyhow::Result<Vec<Skill>> {
+        txn.txn()
+            .query(
+                &format!("select {SKILL_COLS} from skills s order by s.created_at, s.id"),
+                &[],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_skill(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_skill(
+        &self,
+        txn: &mut PostgresTransaction,
+        skill_id: SkillId,
+    ) -> anyhow::Result<Option<Skill>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {SKILL_COLS} from skills s where s.id=$1"),
+                &[&*skill_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_skill(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create_skill(
+        &self,
+        txn: &mut PostgresTransaction,
+        skill: &Skill,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into skills ({SKILL_COL_NAMES}) values ({})",
+                    arg_indices(1..=SKILL_CNT)
+                ),
+                &[
+                    &*skill.id,
+                    &skill.title.as_str(),
+                    &skill.description.as_str(),
+                    &skill.created_at,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_skill<'a>(
+        &self,
+        txn: &mut PostgresTransaction,
+        skill_id: SkillId,
+        SkillPatchRef { title, description }: SkillPatchRef<'a>,
+    ) -> anyhow::Result<bool> {
+        update(
+            txn,
+            "skills",
+            &skill_id,
+            &[
+                ("title", title.map(|x| x.as_str())),
+                ("description", description.map(|x| x.as_str())),
+            ],
+        )
+        .await
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn delete_skill(
+        &self,
+        txn: &mut PostgresTransaction,
+        skill_id: SkillId,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute("delete from skills where id=$1", &[&*skill_id])
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_prerequisites(
+        &self,
+        txn: &mut PostgresTransaction,
+    ) -> anyhow::Result<Vec<SkillPrerequisite>> {
+        txn.txn()
+            .query(
+                "select skill_id, prerequisite_id from skill_prerequisites order by skill_id, \
+                 prerequisite_id",
+                &[],
+            )
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| SkillPrerequisite {
+                        skill_id: row.get::<_, Uuid>(0).into(),
+                        prerequisite_id: row.get::<_, Uuid>(1).into(),
+                    })
+                    .collect()
+            })
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn add_prerequisite(
+        &self,
+        txn: &mut PostgresTransaction,
+        SkillPrerequisite {
+            skill_id,
+            prerequisite_id,
+        }: SkillPrerequisite,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "insert into skill_prerequisites (skill_id, prerequisite_id) values ($1, $2) on \
+                 conflict do nothing",
+                &[&*skill_id, &*prerequisite_id],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn remove_prerequisite(
+        &self,
+        txn: &mut PostgresTransaction,
+        SkillPrerequisite {
+            skill_id,
+            prerequisite_id,
+        }: SkillPrerequisite,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "delete from skill_prerequisites where skill_id=$1 and prerequisite_id=$2",
+                &[&*skill_id, &*prerequisite_id],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_courses(
+        &self,
+        txn: &mut PostgresTransaction,
+        skill_id: Option<SkillId>,
+    ) -> anyhow::Result<Vec<Course>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {COURSE_COLS} from courses c where $1::uuid is null or \
+                     c.skill_id=$1 order by c.created_at, c.id"
+                ),
+                &[&skill_id.map(|x| *x)],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_course(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_course(
+        &self,
+        txn: &mut PostgresTransaction,
+        course_id: CourseId,
+    ) -> anyhow::Result<Option<Course>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {COURSE_COLS} from courses c where c.id=$1"),
+                &[&*course_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_course(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create_course(
+        &self,
+        txn: &mut PostgresTransaction,
+        course: &Course,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into courses ({COURSE_COL_NAMES}) values ({})",
+                    arg_indices(1..=COURSE_CNT)
+                ),
+                &[
+                    &*course.id,
+                    &*course.skill_id,
+                    &course.title.as_str(),
+                    &course.description.as_str(),
+                    &course.created_at,
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_course<'a>(
+        &self,
+        txn: &mut PostgresTransaction,
+        course_id: CourseId,
+        CoursePatchRef { title, description }: CoursePatchRef<'a>,
+    ) -> anyhow::Result<bool> {
+        update(
+            txn,
+            "courses",
+            &course_id,
+            &[
+                ("title", title.map(|x| x.as_str())),
+                ("description", description.map(|x| x.as_str())),
+            ],
+        )
+        .await
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn delete_course(
+        &self,
+        txn: &mut PostgresTransaction,
+        course_id: CourseId,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute("delete from courses where id=$1", &[&*course_id])
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_sections(
+        &self,
+        txn: &mut PostgresTransaction,
+        course_id: CourseId,
+    ) -> anyhow::Result<Vec<CourseSection>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {SECTION_COLS} from course_sections cs where cs.course_id=$1 order \
+                     by cs.position, cs.id"
+                ),
+                &[&*course_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_section(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_section(
+        &self,
+        txn: &mut PostgresTransaction,
+        section_id: CourseSectionId,
+    ) -> anyhow::Result<Option<CourseSection>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {SECTION_COLS} from course_sections cs where cs.id=$1"),
+                &[&*section_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_section(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create_section(
+        &self,
+        txn: &mut PostgresTransaction,
+        section: &CourseSection,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into course_sections ({SECTION_COL_NAMES}) values ({})",
+                    arg_indices(1..=SECTION_CNT)
+                ),
+                &[
+                    &*section.id,
+                    &*section.course_id,
+                    &section.title.as_str(),
+                    &(section.position as i32),
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_section<'a>(
+        &self,
+        txn: &mut PostgresTransaction,
+        section_id: CourseSectionId,
+        CourseSectionPatchRef { title }: CourseSectionPatchRef<'a>,
+    ) -> anyhow::Result<bool> {
+        update(
+            txn,
+            "course_sections",
+            &section_id,
+            &[("title", title.map(|x| x.as_str()))],
+        )
+        .await
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn delete_section(
+        &self,
+        txn: &mut PostgresTransaction,
+        section_id: CourseSectionId,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute("delete from course_sections where id=$1", &[&*section_id])
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_lectures(
+        &self,
+        txn: &mut PostgresTransaction,
+        course_id: CourseId,
+    ) -> anyhow::Result<Vec<Lecture>> {
+        txn.txn()
+            .query(
+                &format!(
+                    "select {LECTURE_COLS} from lectures l inner join course_sections cs on \
+                     cs.id=l.section_id where cs.course_id=$1 order by cs.position, cs.id, \
+                     l.position, l.id"
+                ),
+                &[&*course_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|rows| {
+                rows.into_iter()
+                    .map(|row| decode_lecture(&row, &mut Default::default()))
+                    .collect()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_lecture(
+        &self,
+        txn: &mut PostgresTransaction,
+        lecture_id: LectureId,
+    ) -> anyhow::Result<Option<Lecture>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {LECTURE_COLS} from lectures l where l.id=$1"),
+                &[&*lecture_id],
+            )
+            .await
+            .map_err(Into::into)
+            .and_then(|row| {
+                row.map(|row| decode_lecture(&row, &mut Default::default()))
+                    .transpose()
+            })
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn create_lecture(
+        &self,
+        txn: &mut PostgresTransaction,
+        lecture: &Lecture,
+    ) -> anyhow::Result<()> {
+        txn.txn()
+            .execute(
+                &format!(
+                    "insert into lectures ({LECTURE_COL_NAMES}) values ({})",
+                    arg_indices(1..=LECTURE_CNT)
+                ),
+                &[
+                    &*lecture.id,
+                    &*lecture.section_id,
+                    &lecture.title.as_str(),
+                    &lecture.description.as_str(),
+                    &(lecture.position as i32),
+                ],
+            )
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_lecture<'a>(
+        &self,
+        txn: &mut PostgresTransaction,
+        lecture_id: LectureId,
+        LecturePatchRef { title, description }: LecturePatchRef<'a>,
+    ) -> anyhow::Result<bool> {
+        update(
+            txn,
+            "lectures",
+            &lecture_id,
+            &[
+                ("title", title.map(|x| x.as_str())),
+                ("description", description.map(|x| x.as_str())),
+            ],
+        )
+        .await
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn delete_lecture(
+        &self,
+        txn: &mut PostgresTransaction,
+        lecture_id: LectureId,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute("delete from lectures where id=$1", &[&*lecture_id])
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_course_progress(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+    ) -> anyhow::Result<Vec<(CourseId, LearningProgress)>> {
+        txn.txn()
+            .query(
+                "select cs.course_id, count(lc.lecture_id), count(l.id) from course_sections \
+                 cs inner join lectures l on l.section_id=cs.id left join lecture_completions \
+                 lc on lc.lecture_id=l.id and lc.user_id=$1 group by cs.course_id order by \
+                 cs.course_id",
+                &[&*user_id],
+            )
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| {
+                        (
+                            row.get::<_, Uuid>(0).into(),
+                            LearningProgress {
+                                completed_lectures: row.get::<_, i64>(1) as _,
+                                total_lectures: row.get::<_, i64>(2) as _,
+                            },
+                        )
+                    })
+                    .collect()
+            })
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_completed_lectures(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        course_id: CourseId,
+    ) -> anyhow::Result<Vec<LectureId>> {
+        txn.txn()
+            .query(
+                "select l.id from lecture_completions lc inner join lectures l on \
+                 l.id=lc.lecture_id inner join course_sections cs on cs.id=l.section_id where \
+                 lc.user_id=$1 and cs.course_id=$2 order by cs.position, cs.id, l.position, \
+                 l.id",
+                &[&*user_id, &*course_id],
+            )
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| row.get::<_, Uuid>(0).into())
+                    .collect()
+            })
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn complete_lecture(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        lecture_id: LectureId,
+        completed_at: DateTime<Utc>,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "insert into lecture_completions (user_id, lecture_id, completed_at) values ($1, \
+                 $2, $3) on conflict do nothing",
+                &[&*user_id, &*lecture_id, &completed_at],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn reset_lecture(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        lecture_id: LectureId,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "delete from lecture_completions where user_id=$1 and lecture_id=$2",
+                &[&*user_id, &*lecture_id],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+}
+
+async fn update(
+    txn: &mut PostgresTransaction,
+    table: &str,
+    id: &Uuid,
+    updates: &[(&str, PatchValue<&str>)],
+) -> anyhow::Result<bool> {
+    let mut query = format!("update {table} set id=id");
+    let mut params: Vec<&(dyn ToSql + Sync)> = vec![id];
+
+    for (col, value) in updates {
+        if let PatchValue::Update(value) = value {
+            params.push(value);
+            write!(&mut query, ", {col}=${}", params.len()).unwrap();
+        }
+    }
+
+    query.push_str(" where id=$1");
+
+    txn.txn()
+        .execute(&query, &params)
+        .await
+        .map(|n| n != 0)
+        .map_err(Into::into)
+}
+
+fn decode_skill(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<Skill> {
+    Ok(Skill {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        title: row.get::<_, String>(cnt.idx()).try_into()?,
+        description: row.get::<_, String>(cnt.idx()).try_into()?,
+        created_at: row.get(cnt.idx()),
+    })
+}
+
+fn decode_course(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<Course> {
+    Ok(Course {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        skill_id: row.get::<_, Uuid>(cnt.idx()).into(),
+        title: row.get::<_, String>(cnt.idx()).try_into()?,
+        description: row.get::<_, String>(cnt.idx()).try_into()?,
+        created_at: row.get(cnt.idx()),
+    })
+}
+
+fn decode_section(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<CourseSection> {
+    Ok(CourseSection {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        course_id: row.get::<_, Uuid>(cnt.idx()).into(),
+        title: row.get::<_, String>(cnt.idx()).try_into()?,
+        position: row.get::<_, i32>(cnt.idx()).try_into()?,
+    })
+}
+
+fn decode_lecture(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<Lecture> {
+    Ok(Lecture {
+        id: row.get::<_, Uuid>(cnt.idx()).into(),
+        section_id: row.get::<_, Uuid>(cnt.idx()).into(),
+        title: row.get::<_, String>(cnt.idx()).try_into()?,
+        description: row.get::<_, String>(cnt.idx()).try_into()?,
+        position: row.get::<_, i32>(cnt.idx()).try_into()?,
+    })
+}
@@ -2,17 +2,23 @@ use std::fmt::Write;
 
 use academy_di::Build;
 use academy_models::{
+    country::Country,
     email_address::EmailAddress,
     oauth2::{OAuth2ProviderId, OAuth2RemoteUserId},
-    pagination::PaginationSlice,
+    pagination::{Pagination, SortDirection},
+    url::Url,
     user::{
         User, UserComposite, UserDetails, UserFilter, UserId, UserInvoiceInfo,
-        UserInvoiceInfoPatchRef, UserName, UserPatchRef, UserProfile, UserProfilePatchRef,
+        UserInvoiceInfoPatchRef, UserListKey, UserName, UserPatchRef, UserPrivacy,
+        UserPrivacyPatchRef, UserProfile, UserProfilePatchRef, UserProfileVisibility,
+        UserSortField, UserSorting, UserVatId, UserVatIdStatus, UserVatIdValidation,
     },
 };
 use academy_persistence_contracts::user::{UserRepoError, UserRepository};
 use academy_utils::{patch::PatchValue, trace_instrument};
+use anyhow::anyhow;
 use bb8_postgres::tokio_postgres::{self, types::ToSql, Row};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
@@ -20,15 +26,19 @@ use crate::{arg_indices, columns, ColumnCounter, PostgresTransaction};
 #[derive(Debug, Clone, Copy, Default, Build)]
 pub struct PostgresUserRepository;
 
-columns!(user as "u": "id", "name", "email", "email_verified", "created_at", "last_login", "last_name_change", "enabled", "admin", "newsletter");
-columns!(profile as "p": "user_id", "display_name", "bio", "tags");
+columns!(user as "u": "id", "name", "email", "email_verified", "created_at", "last_login", "last_name_change", "enabled", "admin", "newsletter", "locale");
+columns!(profile as "p": "user_id", "display_name", "bio", "tags", "avatar_url");
+columns!(privacy as "p": "display_name_visibility", "bio_visibility", "tags_visibility", "registration_visibility", "avatar_visibility");
 columns!(details as "d": "user_id", "mfa_enabled", "password_login", "oauth2_login");
-columns!(invoice_info as "i": "user_id", "business", "first_name", "last_name", "street", "zip_code", "city", "country", "vat_id");
+columns!(invoice_info as "i": "user_id", "business", "first_name", "last_name", "street", "zip_code", "city", "country", "vat_id", "vat_id_status", "vat_id_consultation_number", "vat_id_validated_at");
 
 const JOIN_PROFILE: &str = "inner join user_profiles p on u.id=p.user_id";
 const JOIN_DETAILS: &str = "inner join user_details d on u.id=d.user_id";
 const JOIN_INVOICE_INFO: &str = "inner join user_invoice_info i on u.id=i.user_id";
 
+/// Must match the expression of the `user_invoice_info_name_trgm_idx` index.
+const INVOICE_NAME: &str = "lower(coalesce(i.first_name, '') || ' ' || coalesce(i.last_name, ''))";
+
 impl UserRepository<PostgresTransaction> for PostgresUserRepository {
     #[trace_instrument(skip(self, txn))]
     async fn count(
@@ -36,12 +46,18 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
         txn: &mut PostgresTransaction,
         filter: &UserFilter,
     ) -> anyhow::Result<u64> {
-        let mut query = "select count(*) from users u ".to_owned();
-        if filter.name.is_some() {
-            query.push_str(JOIN_PROFILE)
+        let mut query = "select count(*) from users u".to_owned();
+        if filter.name.is_some() || filter.query.is_some() {
+            query.push(' ');
+            query.push_str(JOIN_PROFILE);
         }
         if filter.mfa_enabled.is_some() {
-            query.push_str(JOIN_DETAILS)
+            query.push(' ');
+            query.push_str(JOIN_DETAILS);
+        }
+        if filter.query.is_some() {
+            query.push(' ');
+            query.push_str(JOIN_INVOICE_INFO);
         }
         query.push_str(" where true");
 
@@ -60,7 +76,8 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
         &self,
         txn: &mut PostgresTransaction,
         filter: &UserFilter,
-        pagination: PaginationSlice,
+        sorting: UserSorting,
+        pagination: Pagination<UserListKey>,
     ) -> anyhow::Result<Vec<UserComposite>> {
         let mut query = format!(
             "select {USER_COLS}, {PROFILE_COLS}, {DETAILS_COLS}, {INVOICE_INFO_COLS} from users u \
@@ -68,9 +85,27 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
         );
         let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
         make_filter(filter, &mut query, &mut params);
+
+        let sort_column = match sorting.field {
+            UserSortField::CreatedAt => "u.created_at",
+            UserSortField::LastLogin => "u.last_login",
+            UserSortField::Name => "lower(u.name)",
+        };
+        let offset = match &pagination {
+            Pagination::Offset(slice) => slice.offset,
+            Pagination::After { key, .. } => {
+                make_keyset_filter(sorting, sort_column, key, &mut query, &mut params);
+                0
+            }
+        };
+
+        let sort_direction = match sorting.direction {
+            SortDirection::Asc => "asc nulls first",
+            SortDirection::Desc => "desc nulls last",
+        };
         query.push_str(&format!(
-            " order by u.created_at asc limit {} offset {}",
-            *pagination.limit, pagination.offset
+            " order by {sort_column} {sort_direction}, lower(u.name) asc limit {} offset {offset}",
+            *pagination.limit(),
         ));
 
         txn.txn()
@@ -211,6 +246,7 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
                     &user.enabled,
                     &user.admin,
                     &user.newsletter,
+                    &user.locale.as_str(),
                 ],
             )
             .await
@@ -227,11 +263,13 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
                     &profile.display_name.as_str(),
                     &profile.bio.as_str(),
                     &profile.tags.iter().map(|x| x.as_str()).collect::<Vec<_>>(),
+                    &profile.avatar_url.as_ref().map(|x| x.as_str()),
                 ],
             )
             .await
             .map_err(|err| UserRepoError::Other(err.into()))?;
 
+        let vat_id_validation = invoice_info.vat_id_validation.as_ref();
         txn.txn()
             .execute(
                 &format!(
@@ -246,8 +284,11 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
                     &invoice_info.street.as_deref(),
                     &invoice_info.zip_code.as_deref(),
                     &invoice_info.city.as_deref(),
-                    &invoice_info.country.as_deref(),
+                    &invoice_info.country.map(Country::code),
                     &invoice_info.vat_id.as_deref(),
+                    &vat_id_validation.map(|x| encode_vat_id_status(x.status)),
+                    &vat_id_validation.and_then(|x| x.consultation_number.as_deref()),
+                    &vat_id_validation.map(|x| x.validated_at),
                 ],
             )
             .await
@@ -270,6 +311,7 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
             enabled,
             admin,
             newsletter,
+            locale,
         }: UserPatchRef<'a>,
     ) -> Result<bool, UserRepoError> {
         let mut query = "update users set id=id".to_owned();
@@ -309,6 +351,10 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
             params.push(newsletter);
             write!(&mut query, ", newsletter=${}", params.len()).unwrap();
         }
+        if let PatchValue::Update(locale) = locale {
+            params.push(&**locale);
+            write!(&mut query, ", locale=${}", params.len()).unwrap();
+        }
 
         query.push_str(" where id=$1");
 
@@ -357,6 +403,84 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
             .map_err(Into::into)
     }
 
+    #[trace_instrument(skip(self, txn))]
+    async fn update_avatar_url(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        avatar_url: &Option<Url>,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "update user_profiles set avatar_url=$2 where user_id=$1",
+                &[&*user_id, &avatar_url.as_ref().map(|x| x.as_str())],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn get_privacy(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+    ) -> anyhow::Result<Option<UserPrivacy>> {
+        txn.txn()
+            .query_opt(
+                &format!("select {PRIVACY_COLS} from user_profiles p where user_id=$1"),
+                &[&*user_id],
+            )
+            .await?
+            .map(|row| decode_privacy(&row, &mut Default::default()))
+            .transpose()
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn update_privacy<'a>(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        UserPrivacyPatchRef {
+            display_name,
+            bio,
+            tags,
+            registration,
+            avatar,
+        }: UserPrivacyPatchRef<'a>,
+    ) -> anyhow::Result<bool> {
+        let mut query = "update user_profiles set user_id=user_id".to_owned();
+        let mut params: Vec<&(dyn ToSql + Sync)> = vec![&*user_id];
+
+        let updates = [
+            (
+                "display_name_visibility",
+                display_name.map(encode_visibility),
+            ),
+            ("bio_visibility", bio.map(encode_visibility)),
+            ("tags_visibility", tags.map(encode_visibility)),
+            (
+                "registration_visibility",
+                registration.map(encode_visibility),
+            ),
+            ("avatar_visibility", avatar.map(encode_visibility)),
+        ];
+        for (col, value) in &updates {
+            if let PatchValue::Update(value) = value {
+                params.push(value);
+                write!(&mut query, ", {col}=${}", params.len()).unwrap();
+            }
+        }
+
+        query.push_str(" where user_id=$1");
+
+        txn.txn()
+            .execute(&query, &params)
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
     #[trace_instrument(skip(self, txn))]
     async fn update_invoice_info<'a>(
         &self,
@@ -381,7 +505,7 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
         let street = street.map(|x| x.as_deref());
         let zip_code = zip_code.map(|x| x.as_deref());
         let city = city.map(|x| x.as_deref());
-        let country = country.map(|x| x.as_deref());
+        let country = country.map(|x| x.map(Country::code));
         let vat_id = vat_id.map(|x| x.as_deref());
 
         if let PatchValue::Update(business) = &business {
@@ -410,11 +534,19 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
         }
         if let PatchValue::Update(country) = &country {
             params.push(country);
-            write!(&mut query, ", country=${}", params.len()).unwrap();
+            write!(
+                &mut query,
+                ", country=${}, unconverted_country=null",
+                params.len()
+            )
+            .unwrap();
         }
         if let PatchValue::Update(vat_id) = &vat_id {
             params.push(vat_id);
             write!(&mut query, ", vat_id=${}", params.len()).unwrap();
+            query.push_str(
+                ", vat_id_status=null, vat_id_consultation_number=null, vat_id_validated_at=null",
+            );
         }
 
         query.push_str(" where user_id=$1");
@@ -426,6 +558,78 @@ impl UserRepository<PostgresTransaction> for PostgresUserRepository {
             .map_err(Into::into)
     }
 
+    #[trace_instrument(skip(self, txn))]
+    async fn update_vat_id_validation(
+        &self,
+        txn: &mut PostgresTransaction,
+        user_id: UserId,
+        vat_id: &UserVatId,
+        validation: &UserVatIdValidation,
+    ) -> anyhow::Result<bool> {
+        txn.txn()
+            .execute(
+                "update user_invoice_info set vat_id_status=$3, vat_id_consultation_number=$4, \
+                 vat_id_validated_at=$5 where user_id=$1 and vat_id=$2",
+                &[
+                    &*user_id,
+                    &vat_id.as_str(),
+                    &encode_vat_id_status(validation.status),
+                    &validation.consultation_number,
+                    &validation.validated_at,
+                ],
+            )
+            .await
+            .map(|n| n != 0)
+            .map_err(Into::into)
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_vat_ids_to_revalidate(
+        &self,
+        txn: &mut PostgresTransaction,
+        validated_before: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<(UserId, UserVatId)>> {
+        txn.txn()
+            .query(
+                "select user_id, vat_id from user_invoice_info where vat_id is not null and \
+                 (vat_id_validated_at is null or vat_id_validated_at<$1 or vat_id_status=$2) \
+                 order by user_id",
+                &[
+                    &validated_before,
+                    &encode_vat_id_status(UserVatIdStatus::Pending),
+                ],
+            )
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok((
+                    row.get::<_, Uuid>(0).into(),
+                    row.get::<_, String>(1).try_into()?,
+                ))
+            })
+            .collect()
+    }
+
+    #[trace_instrument(skip(self, txn))]
+    async fn list_unconverted_countries(
+        &self,
+        txn: &mut PostgresTransaction,
+    ) -> anyhow::Result<Vec<(UserId, String)>> {
+        txn.txn()
+            .query(
+                "select user_id, unconverted_country from user_invoice_info where \
+                 unconverted_country is not null order by user_id",
+                &[],
+            )
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| (row.get::<_, Uuid>(0).into(), row.get(1)))
+                    .collect()
+            })
+            .map_err(Into::into)
+    }
+
     #[trace_instrument(skip(self, txn))]
     async fn delete(&self, txn: &mut PostgresTransaction, user_id: UserId) -> anyhow::Result<bool> {
         txn.txn()
@@ -487,6 +691,27 @@ fn make_filter<'a>(
     query: &mut String,
     params: &mut Vec<&'a (dyn ToSql + Sync)>,
 ) {
+    if let Some(search) = &filter.query {
+        params.push(&**search);
+        let n = params.len();
+        // `like` matches substrings (e.g. parts of an email address), while `<%`
+        // (word similarity) tolerates typos. Both are backed by trigram indexes.
+        let pattern = format!(
+            r"'%' || replace(replace(replace(lower(${n}), '\', '\\'), '%', '\%'), '_', '\_') || '%'"
+        );
+        query.push_str(" and (false");
+        for column in [
+            "lower(u.name)",
+            "lower(p.display_name)",
+            "lower(u.email)",
+            INVOICE_NAME,
+        ] {
+            query.push_str(&format!(
+                " or {column} like {pattern} or lower(${n}) <% {column}"
+            ));
+        }
+        query.push(')');
+    }
     if let Some(name) = &filter.name {
         params.push(&**name);
         query.push_str(&format!(
@@ -518,6 +743,75 @@ fn make_filter<'a>(
         params.push(newsletter);
         query.push_str(&format!(" and newsletter=${}", params.len()));
     }
+    if let Some(created_after) = &filter.created_after {
+        params.push(created_after);
+        query.push_str(&format!(" and u.created_at>=${}", params.len()));
+    }
+    if let Some(created_before) = &filter.created_before {
+        params.push(created_before);
+        query.push_str(&format!(" and u.created_at<${}", params.len()));
+    }
+    if let Some(last_login_after) = &filter.last_login_after {
+        params.push(last_login_after);
+        query.push_str(&format!(" and u.last_login>=${}", params.len()));
+    }
+    if let Some(last_login_before) = &filter.last_login_before {
+        params.push(last_login_before);
+        query.push_str(&format!(" and u.last_login<${}", params.len()));
+    }
+    if let Some(oauth2_provider) = &filter.oauth2_provider {
+        params.push(&**oauth2_provider);
+        query.push_str(&format!(
+            " and exists (select 1 from oauth2_links l where l.user_id=u.id and \
+             l.provider_id=${})",
+            params.len()
+        ));
+    }
+}
+
+/// Select only rows after the given key, i.e. rows that would be ordered after
+/// the row of the key by `order by {sort_column} {direction}, lower(u.name)`.
+fn make_keyset_filter<'a>(
+    sorting: UserSorting,
+    sort_column: &str,
+    key: &'a UserListKey,
+    query: &mut String,
+    params: &mut Vec<&'a (dyn ToSql + Sync)>,
+) {
+    params.push(&*key.name);
+    let name = format!("lower(${})", params.len());
+
+    let cmp = match sorting.direction {
+        SortDirection::Asc => ">",
+        SortDirection::Desc => "<",
+    };
+
+    if sorting.field == UserSortField::Name {
+        query.push_str(&format!(" and lower(u.name){cmp}{name}"));
+        return;
+    }
+
+    // nulls are ordered first in ascending and last in descending order
+    let condition = match (&key.value, sorting.direction) {
+        (Some(value), direction) => {
+            params.push(value);
+            let value = format!("${}", params.len());
+            let mut condition = format!(
+                "{sort_column}{cmp}{value} or ({sort_column}={value} and lower(u.name)>{name})"
+            );
+            if direction == SortDirection::Desc {
+                condition.push_str(&format!(" or {sort_column} is null"));
+            }
+            condition
+        }
+        (None, SortDirection::Asc) => {
+            format!("{sort_column} is not null or lower(u.name)>{name}")
+        }
+        (None, SortDirection::Desc) => {
+            format!("{sort_column} is null and lower(u.name)>{name}")
+        }
+    };
+    query.push_str(&format!(" and ({condition})"));
 }
 
 fn decode_user(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<User> {
@@ -536,6 +830,7 @@ fn decode_user(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<User> {
         enabled: row.get(cnt.idx()),
         admin: row.get(cnt.idx()),
         newsletter: row.get(cnt.idx()),
+        locale: row.get::<_, String>(cnt.idx()).try_into()?,
     })
 }
 
@@ -550,9 +845,41 @@ fn decode_profile(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<UserProf
             .map(TryInto::try_into)
             .collect::<Result<Vec<_>, _>>()?
             .try_into()?,
+        avatar_url: row
+            .get::<_, Option<String>>(cnt.idx())
+            .as_deref()
+            .map(str::parse)
+            .transpose()?,
     })
 }
 
+fn decode_privacy(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<UserPrivacy> {
+    Ok(UserPrivacy {
+        display_name: decode_visibility(row.get(cnt.idx()))?,
+        bio: decode_visibility(row.get(cnt.idx()))?,
+        tags: decode_visibility(row.get(cnt.idx()))?,
+        registration: decode_visibility(row.get(cnt.idx()))?,
+        avatar: decode_visibility(row.get(cnt.idx()))?,
+    })
+}
+
+fn encode_visibility(visibility: &UserProfileVisibility) -> &'static str {
+    match visibility {
+        UserProfileVisibility::Public => "public",
+        UserProfileVisibility::Users => "users",
+        UserProfileVisibility::Private => "private",
+    }
+}
+
+fn decode_visibility(visibility: &str) -> anyhow::Result<UserProfileVisibility> {
+    match visibility {
+        "public" => Ok(UserProfileVisibility::Public),
+        "users" => Ok(UserProfileVisibility::Users),
+        "private" => Ok(UserProfileVisibility::Private),
+        _ => Err(anyhow!("Invalid profile visibility: {visibility}")),
+    }
+}
+
 fn decode_details(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<UserDetails> {
     cnt.idx(); // user_id
     Ok(UserDetails {
@@ -588,15 +915,54 @@ fn decode_invoice_info(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<Use
             .transpose()?,
         country: row
             .get::<_, Option<String>>(cnt.idx())
-            .map(TryInto::try_into)
+            .map(|country| {
+                Country::from_code(&country).ok_or_else(|| anyhow!("Invalid country: {country}"))
+            })
             .transpose()?,
         vat_id: row
             .get::<_, Option<String>>(cnt.idx())
             .map(TryInto::try_into)
             .transpose()?,
+        vat_id_validation: decode_vat_id_validation(row, cnt)?,
     })
 }
 
+fn decode_vat_id_validation(
+    row: &Row,
+    cnt: &mut ColumnCounter,
+) -> anyhow::Result<Option<UserVatIdValidation>> {
+    let status = row.get::<_, Option<String>>(cnt.idx());
+    let consultation_number = row.get(cnt.idx());
+    let validated_at = row.get::<_, Option<DateTime<Utc>>>(cnt.idx());
+    status
+        .zip(validated_at)
+        .map(|(status, validated_at)| {
+            Ok(UserVatIdValidation {
+                status: decode_vat_id_status(&status)?,
+                consultation_number,
+                validated_at,
+            })
+        })
+        .transpose()
+}
+
+fn encode_vat_id_status(status: UserVatIdStatus) -> &'static str {
+    match status {
+        UserVatIdStatus::Valid => "valid",
+        UserVatIdStatus::Pending => "pending",
+        UserVatIdStatus::Invalid => "invalid",
+    }
+}
+
+fn decode_vat_id_status(status: &str) -> anyhow::Result<UserVatIdStatus> {
+    match status {
+        "valid" => Ok(UserVatIdStatus::Valid),
+        "pending" => Ok(UserVatIdStatus::Pending),
+        "invalid" => Ok(UserVatIdStatus::Invalid),
+        _ => Err(anyhow!("Invalid vat id status: {status}")),
+    }
+}
+
 fn decode_composite(row: &Row, cnt: &mut ColumnCounter) -> anyhow::Result<UserComposite> {
     Ok(UserComposite {
         user: decode_user(row, cnt)?,
@@ -1,7 +1,11 @@
 use academy_persistence_contracts::{Database, Transaction};
 use academy_persistence_postgres::{
-    mfa::PostgresMfaRepository, oauth2::PostgresOAuth2Repository,
-    session::PostgresSessionRepository, user::PostgresUserRepository, PostgresDatabase,
+    checkout::PostgresCheckoutRepository, coin::PostgresCoinRepository,
+    contact::PostgresContactRepository, email_outbox::PostgresEmailOutboxRepository,
+    invite::PostgresInviteRepository, invoice::PostgresInvoiceRepository,
+    mfa::PostgresMfaRepository, newsletter::PostgresNewsletterRepository,
+    oauth2::PostgresOAuth2Repository, session::PostgresSessionRepository,
+    skill::PostgresSkillRepository, user::PostgresUserRepository, PostgresDatabase,
     PostgresDatabaseConfig,
 };
 
@@ -20,6 +24,14 @@ pub async fn setup() -> Db {
         PostgresSessionRepository,
         PostgresMfaRepository,
         PostgresOAuth2Repository,
+        PostgresInviteRepository,
+        PostgresNewsletterRepository,
+        PostgresEmailOutboxRepository,
+        PostgresContactRepository,
+        PostgresCoinRepository,
+        PostgresCheckoutRepository,
+        PostgresInvoiceRepository,
+        PostgresSkillRepository,
     )
     .await
     .unwrap();
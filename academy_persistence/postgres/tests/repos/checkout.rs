@@ -0,0 +1,255 @@
+use academy_demo::{
+    checkout::{ALL_ORDERS, FOO_ORDER_CANCELLED, FOO_ORDER_PENDING},
+    invoice::{FOO_INVOICE_PAYOUT, FOO_INVOICE_PURCHASE},
+    user::{BAR, FOO},
+    UUID1,
+};
+use academy_models::checkout::{CoinOrder, CoinOrderStatus};
+use academy_persistence_contracts::{checkout::CheckoutRepository, Database, Transaction};
+use academy_persistence_postgres::checkout::PostgresCheckoutRepository;
+
+use crate::{
+    common::setup,
+    repos::{make_slice, sliced},
+};
+
+const REPO: PostgresCheckoutRepository = PostgresCheckoutRepository;
+
+#[tokio::test]
+async fn create_order() {
+    let db = setup().await;
+
+    let order = CoinOrder {
+        id: UUID1.into(),
+        user_id: BAR.user.id,
+        package: "medium".try_into().unwrap(),
+        coins: 500.try_into().unwrap(),
+        price: 475,
+        status: CoinOrderStatus::Pending,
+        payment_id: "cs_1234".try_into().unwrap(),
+        created_at: BAR.user.created_at,
+        updated_at: BAR.user.created_at,
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create_order(&mut txn, &order).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    assert_eq!(
+        REPO.get_order(&mut txn, order.id).await.unwrap(),
+        Some(order)
+    );
+}
+
+#[tokio::test]
+async fn create_order_payment_id_conflict() {
+    let db = setup().await;
+
+    let order = CoinOrder {
+        id: UUID1.into(),
+        user_id: BAR.user.id,
+        ..FOO_ORDER_PENDING.clone()
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create_order(&mut txn, &order).await.unwrap_err();
+}
+
+#[tokio::test]
+async fn get_order() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    for &order in &*ALL_ORDERS {
+        assert_eq!(
+            REPO.get_order(&mut txn, order.id).await.unwrap().as_ref(),
+            Some(order)
+        );
+    }
+    assert_eq!(REPO.get_order(&mut txn, UUID1.into()).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn get_order_by_payment_id() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    for &order in &*ALL_ORDERS {
+        assert_eq!(
+            REPO.get_order_by_payment_id(&mut txn, &order.payment_id)
+                .await
+                .unwrap()
+                .as_ref(),
+            Some(order)
+        );
+    }
+    assert_eq!(
+        REPO.get_order_by_payment_id(&mut txn, &"cs_unknown".try_into().unwrap())
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn list_orders() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let expected = ALL_ORDERS
+        .iter()
+        .filter(|o| o.user_id == FOO.user.id)
+        .rev()
+        .copied()
+        .cloned()
+        .collect::<Vec<_>>();
+    for limit in 1..=3 {
+        for offset in 0..=3 {
+            let slice = make_slice(limit, offset);
+            let result = REPO
+                .list_orders(&mut txn, FOO.user.id, slice)
+                .await
+                .unwrap();
+            assert_eq!(result, sliced(&expected, slice));
+        }
+    }
+
+    let result = REPO
+        .list_orders(&mut txn, BAR.user.id, make_slice(10, 0))
+        .await
+        .unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn count_orders() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    assert_eq!(REPO.count_orders(&mut txn, FOO.user.id).await.unwrap(), 2);
+    assert_eq!(REPO.count_orders(&mut txn, BAR.user.id).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn update_order_status() {
+    let db = setup().await;
+
+    let expected = CoinOrder {
+        status: CoinOrderStatus::Completed,
+        updated_at: FOO_ORDER_PENDING.updated_at + chrono::Duration::seconds(60),
+        ..FOO_ORDER_PENDING.clone()
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_order_status(
+            &mut txn,
+            expected.id,
+            CoinOrderStatus::Pending,
+            expected.status,
+            expected.updated_at,
+        )
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    assert_eq!(
+        REPO.get_order(&mut txn, expected.id).await.unwrap(),
+        Some(expected)
+    );
+}
+
+#[tokio::test]
+async fn update_order_status_unexpected_status() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO
+        .update_order_status(
+            &mut txn,
+            FOO_ORDER_CANCELLED.id,
+            CoinOrderStatus::Pending,
+            CoinOrderStatus::Completed,
+            FOO_ORDER_CANCELLED.updated_at,
+        )
+        .await
+        .unwrap();
+    assert!(!result);
+
+    let result = REPO
+        .update_order_status(
+            &mut txn,
+            UUID1.into(),
+            CoinOrderStatus::Pending,
+            CoinOrderStatus::Completed,
+            FOO_ORDER_CANCELLED.updated_at,
+        )
+        .await
+        .unwrap();
+    assert!(!result);
+
+    assert_eq!(
+        REPO.get_order(&mut txn, FOO_ORDER_CANCELLED.id)
+            .await
+            .unwrap()
+            .as_ref(),
+        Some(&*FOO_ORDER_CANCELLED)
+    );
+}
+
+#[tokio::test]
+async fn orders_without_invoice() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    assert_eq!(
+        REPO.list_orders_without_invoice(&mut txn).await.unwrap(),
+        []
+    );
+
+    let completed = CoinOrder {
+        status: CoinOrderStatus::Completed,
+        updated_at: FOO_ORDER_PENDING.updated_at + chrono::Duration::seconds(60),
+        ..FOO_ORDER_PENDING.clone()
+    };
+    REPO.update_order_status(
+        &mut txn,
+        completed.id,
+        CoinOrderStatus::Pending,
+        completed.status,
+        completed.updated_at,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        REPO.list_orders_without_invoice(&mut txn).await.unwrap(),
+        vec![completed.clone()]
+    );
+
+    let result = REPO
+        .set_order_invoice(&mut txn, completed.id, FOO_INVOICE_PURCHASE.id)
+        .await
+        .unwrap();
+    assert!(result);
+
+    assert_eq!(
+        REPO.list_orders_without_invoice(&mut txn).await.unwrap(),
+        []
+    );
+
+    let result = REPO
+        .set_order_invoice(&mut txn, completed.id, FOO_INVOICE_PAYOUT.id)
+        .await
+        .unwrap();
+    assert!(!result);
+
+    let result = REPO
+        .set_order_invoice(&mut txn, UUID1.into(), FOO_INVOICE_PAYOUT.id)
+        .await
+        .unwrap();
+    assert!(!result);
+}
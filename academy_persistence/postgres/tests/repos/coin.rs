@@ -0,0 +1,313 @@
+use academy_demo::{
+    coin::{ALL_TRANSACTIONS, BAR_BALANCE, BAR_WITHHELD, FOO_BALANCE, FOO_EARN},
+    user::{ADMIN, BAR, FOO},
+    UUID1, UUID2,
+};
+use academy_models::coin::{CoinBalance, CoinTransaction, CoinTransactionKind};
+use academy_persistence_contracts::{
+    coin::{CoinRepository, CoinTransactionCreateResult},
+    Database, Transaction,
+};
+use academy_persistence_postgres::coin::PostgresCoinRepository;
+
+use crate::{
+    common::setup,
+    repos::{make_slice, sliced},
+};
+
+const REPO: PostgresCoinRepository = PostgresCoinRepository;
+
+#[tokio::test]
+async fn get_balance() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    assert_eq!(
+        REPO.get_balance(&mut txn, FOO.user.id).await.unwrap(),
+        FOO_BALANCE
+    );
+    assert_eq!(
+        REPO.get_balance(&mut txn, BAR.user.id).await.unwrap(),
+        BAR_BALANCE
+    );
+    assert_eq!(
+        REPO.get_balance(&mut txn, ADMIN.user.id).await.unwrap(),
+        CoinBalance::default()
+    );
+}
+
+#[tokio::test]
+async fn create_transaction() {
+    let db = setup().await;
+
+    let transaction = CoinTransaction {
+        id: UUID1.into(),
+        user_id: BAR.user.id,
+        kind: CoinTransactionKind::Released,
+        coins: 50.try_into().unwrap(),
+        description: None,
+        reference: None,
+        created_at: BAR_WITHHELD.created_at + chrono::Duration::seconds(1),
+    };
+    let expected = CoinBalance {
+        coins: 50,
+        withheld_coins: 0,
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .create_transaction(&mut txn, &transaction)
+        .await
+        .unwrap();
+    assert_eq!(result, CoinTransactionCreateResult::Created(expected));
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    assert_eq!(
+        REPO.get_balance(&mut txn, BAR.user.id).await.unwrap(),
+        expected
+    );
+    assert_eq!(
+        REPO.list_transactions(&mut txn, BAR.user.id, make_slice(10, 0))
+            .await
+            .unwrap(),
+        [transaction, BAR_WITHHELD.clone()]
+    );
+}
+
+#[tokio::test]
+async fn create_transaction_insufficient_balance() {
+    let db = setup().await;
+
+    let transaction = CoinTransaction {
+        id: UUID1.into(),
+        user_id: FOO.user.id,
+        kind: CoinTransactionKind::Spend,
+        coins: (FOO_BALANCE.coins + 1).try_into().unwrap(),
+        description: None,
+        reference: None,
+        created_at: FOO.user.created_at,
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .create_transaction(&mut txn, &transaction)
+        .await
+        .unwrap();
+    assert_eq!(result, CoinTransactionCreateResult::Overdrawn);
+
+    assert_eq!(
+        REPO.get_balance(&mut txn, FOO.user.id).await.unwrap(),
+        FOO_BALANCE
+    );
+    assert_eq!(
+        REPO.count_transactions(&mut txn, FOO.user.id)
+            .await
+            .unwrap(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn create_transaction_duplicate_reference() {
+    let db = setup().await;
+
+    let transaction = CoinTransaction {
+        id: UUID1.into(),
+        user_id: BAR.user.id,
+        kind: CoinTransactionKind::Earn,
+        coins: 50.try_into().unwrap(),
+        description: None,
+        reference: BAR_WITHHELD.reference.clone(),
+        created_at: BAR_WITHHELD.created_at + chrono::Duration::seconds(1),
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .create_transaction(&mut txn, &transaction)
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        CoinTransactionCreateResult::Duplicate(BAR_WITHHELD.clone())
+    );
+
+    assert_eq!(
+        REPO.get_balance(&mut txn, BAR.user.id).await.unwrap(),
+        BAR_BALANCE
+    );
+    assert_eq!(
+        REPO.count_transactions(&mut txn, BAR.user.id)
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn create_transaction_duplicate_reference_concurrent() {
+    let db = setup().await;
+
+    let transaction = CoinTransaction {
+        id: UUID1.into(),
+        user_id: FOO.user.id,
+        kind: CoinTransactionKind::Spend,
+        coins: 100.try_into().unwrap(),
+        description: None,
+        reference: Some("order:1337".try_into().unwrap()),
+        created_at: FOO.user.created_at,
+    };
+    let expected = CoinBalance {
+        coins: FOO_BALANCE.coins - 100,
+        ..FOO_BALANCE
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .create_transaction(&mut txn, &transaction)
+        .await
+        .unwrap();
+    assert_eq!(result, CoinTransactionCreateResult::Created(expected));
+
+    // the duplicate waits for the first transaction to be committed
+    let mut other_txn = db.begin_transaction().await.unwrap();
+    let duplicate = CoinTransaction {
+        id: UUID2.into(),
+        ..transaction.clone()
+    };
+    let other = tokio::spawn(async move {
+        let result = REPO.create_transaction(&mut other_txn, &duplicate).await;
+        (other_txn, result)
+    });
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    txn.commit().await.unwrap();
+
+    let (mut other_txn, result) = other.await.unwrap();
+    assert_eq!(
+        result.unwrap(),
+        CoinTransactionCreateResult::Duplicate(transaction)
+    );
+    assert_eq!(
+        REPO.get_balance(&mut other_txn, FOO.user.id).await.unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn get_transaction_by_reference() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let challenge_1 = FOO_EARN.reference.clone().unwrap();
+    let challenge_2 = BAR_WITHHELD.reference.clone().unwrap();
+
+    for (user_id, kind, reference, expected) in [
+        (
+            FOO.user.id,
+            CoinTransactionKind::Earn,
+            &challenge_1,
+            Some(&*FOO_EARN),
+        ),
+        (
+            FOO.user.id,
+            CoinTransactionKind::Withheld,
+            &challenge_1,
+            Some(&*FOO_EARN),
+        ),
+        (
+            BAR.user.id,
+            CoinTransactionKind::Earn,
+            &challenge_2,
+            Some(&*BAR_WITHHELD),
+        ),
+        (FOO.user.id, CoinTransactionKind::Refund, &challenge_1, None),
+        (FOO.user.id, CoinTransactionKind::Earn, &challenge_2, None),
+        (BAR.user.id, CoinTransactionKind::Earn, &challenge_1, None),
+    ] {
+        let result = REPO
+            .get_transaction_by_reference(&mut txn, user_id, kind, reference)
+            .await
+            .unwrap();
+        assert_eq!(result.as_ref(), expected);
+    }
+
+    let transaction = CoinTransaction {
+        id: UUID1.into(),
+        user_id: FOO.user.id,
+        kind: CoinTransactionKind::Refund,
+        coins: 50.try_into().unwrap(),
+        description: None,
+        reference: Some(challenge_1.clone()),
+        created_at: FOO.user.created_at,
+    };
+    REPO.create_transaction(&mut txn, &transaction)
+        .await
+        .unwrap();
+
+    let result = REPO
+        .get_transaction_by_reference(
+            &mut txn,
+            FOO.user.id,
+            CoinTransactionKind::Refund,
+            &challenge_1,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result, Some(transaction));
+}
+
+#[tokio::test]
+async fn list_transactions() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let expected = ALL_TRANSACTIONS
+        .iter()
+        .filter(|t| t.user_id == FOO.user.id)
+        .rev()
+        .copied()
+        .cloned()
+        .collect::<Vec<_>>();
+    for limit in 1..=3 {
+        for offset in 0..=3 {
+            let slice = make_slice(limit, offset);
+            let result = REPO
+                .list_transactions(&mut txn, FOO.user.id, slice)
+                .await
+                .unwrap();
+            assert_eq!(result, sliced(&expected, slice));
+        }
+    }
+
+    let result = REPO
+        .list_transactions(&mut txn, ADMIN.user.id, make_slice(10, 0))
+        .await
+        .unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn count_transactions() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    assert_eq!(
+        REPO.count_transactions(&mut txn, FOO.user.id)
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        REPO.count_transactions(&mut txn, BAR.user.id)
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        REPO.count_transactions(&mut txn, ADMIN.user.id)
+            .await
+            .unwrap(),
+        0
+    );
+}
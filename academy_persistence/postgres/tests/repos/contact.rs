@@ -0,0 +1,267 @@
+use std::time::Duration;
+
+use academy_demo::{
+    contact::{ALL_MESSAGES, OPEN_MESSAGE, RESOLVED_MESSAGE, RESOLVED_MESSAGE_REPLY},
+    user::{ADMIN, ADMIN2},
+    UUID1,
+};
+use academy_models::contact::{
+    ContactInboxMessage, ContactMessageFilter, ContactMessageReply, ContactMessageStatus,
+};
+use academy_persistence_contracts::{
+    contact::ContactRepository, user::UserRepository, Database, Transaction,
+};
+use academy_persistence_postgres::{
+    contact::PostgresContactRepository, user::PostgresUserRepository,
+};
+use academy_utils::Apply;
+
+use crate::{
+    common::setup,
+    repos::{make_slice, sliced},
+};
+
+const REPO: PostgresContactRepository = PostgresContactRepository;
+
+#[tokio::test]
+async fn list_messages() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let expected = ALL_MESSAGES.iter().copied().cloned().collect::<Vec<_>>();
+    for limit in 1..=3 {
+        for offset in 0..=3 {
+            let slice = make_slice(limit, offset);
+            let result = REPO
+                .list_messages(&mut txn, Default::default(), slice)
+                .await
+                .unwrap();
+            assert_eq!(result, sliced(&expected, slice));
+        }
+    }
+
+    let filter = ContactMessageFilter {
+        status: Some(ContactMessageStatus::Open),
+        assignee: None,
+    };
+    let result = REPO
+        .list_messages(&mut txn, filter, make_slice(10, 0))
+        .await
+        .unwrap();
+    assert_eq!(result, vec![OPEN_MESSAGE.clone()]);
+
+    let filter = ContactMessageFilter {
+        status: None,
+        assignee: Some(ADMIN.user.id),
+    };
+    let result = REPO
+        .list_messages(&mut txn, filter, make_slice(10, 0))
+        .await
+        .unwrap();
+    assert_eq!(result, vec![RESOLVED_MESSAGE.clone()]);
+
+    let filter = ContactMessageFilter {
+        status: Some(ContactMessageStatus::Open),
+        assignee: Some(ADMIN.user.id),
+    };
+    let result = REPO
+        .list_messages(&mut txn, filter, make_slice(10, 0))
+        .await
+        .unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn count_messages() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO
+        .count_messages(&mut txn, Default::default())
+        .await
+        .unwrap();
+    assert_eq!(result, ALL_MESSAGES.len() as u64);
+
+    let filter = ContactMessageFilter {
+        status: Some(ContactMessageStatus::Resolved),
+        assignee: None,
+    };
+    let result = REPO.count_messages(&mut txn, filter).await.unwrap();
+    assert_eq!(result, 1);
+
+    let filter = ContactMessageFilter {
+        status: None,
+        assignee: Some(ADMIN2.user.id),
+    };
+    let result = REPO.count_messages(&mut txn, filter).await.unwrap();
+    assert_eq!(result, 0);
+}
+
+#[tokio::test]
+async fn get_message() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.get_message(&mut txn, OPEN_MESSAGE.id).await.unwrap();
+    assert_eq!(result.as_ref(), Some(&*OPEN_MESSAGE));
+
+    let result = REPO.get_message(&mut txn, UUID1.into()).await.unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn create_message() {
+    let expected = ContactInboxMessage {
+        id: UUID1.into(),
+        email_message_id: format!("<{UUID1}@bootstrap.academy>"),
+        created_at: OPEN_MESSAGE.created_at + Duration::from_secs(60),
+        ..OPEN_MESSAGE.clone()
+    };
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create_message(&mut txn, &expected).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get_message(&mut txn, expected.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+}
+
+#[tokio::test]
+async fn update_message_assignee() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_message_assignee(&mut txn, OPEN_MESSAGE.id, Some(ADMIN2.user.id))
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get_message(&mut txn, OPEN_MESSAGE.id).await.unwrap();
+    assert_eq!(
+        result,
+        Some(
+            OPEN_MESSAGE
+                .clone()
+                .with(|x| x.assignee = Some(ADMIN2.user.id))
+        )
+    );
+
+    let result = REPO
+        .update_message_assignee(&mut txn, OPEN_MESSAGE.id, None)
+        .await
+        .unwrap();
+    assert!(result);
+    let result = REPO.get_message(&mut txn, OPEN_MESSAGE.id).await.unwrap();
+    assert_eq!(result.as_ref(), Some(&*OPEN_MESSAGE));
+
+    let result = REPO
+        .update_message_assignee(&mut txn, UUID1.into(), None)
+        .await
+        .unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn update_message_status() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_message_status(&mut txn, OPEN_MESSAGE.id, ContactMessageStatus::Resolved)
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get_message(&mut txn, OPEN_MESSAGE.id).await.unwrap();
+    assert_eq!(
+        result,
+        Some(
+            OPEN_MESSAGE
+                .clone()
+                .with(|x| x.status = ContactMessageStatus::Resolved)
+        )
+    );
+
+    let result = REPO
+        .update_message_status(&mut txn, UUID1.into(), ContactMessageStatus::Open)
+        .await
+        .unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn list_replies() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO
+        .list_replies(&mut txn, RESOLVED_MESSAGE.id)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![RESOLVED_MESSAGE_REPLY.clone()]);
+
+    let result = REPO.list_replies(&mut txn, OPEN_MESSAGE.id).await.unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn create_reply() {
+    let expected = ContactMessageReply {
+        id: UUID1.into(),
+        email_message_id: format!("<{UUID1}@bootstrap.academy>"),
+        created_at: RESOLVED_MESSAGE_REPLY.created_at + Duration::from_secs(60),
+        ..RESOLVED_MESSAGE_REPLY.clone()
+    };
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create_reply(&mut txn, &expected).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .list_replies(&mut txn, RESOLVED_MESSAGE.id)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![RESOLVED_MESSAGE_REPLY.clone(), expected]);
+}
+
+#[tokio::test]
+async fn delete_assignee() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    PostgresUserRepository
+        .delete(&mut txn, ADMIN.user.id)
+        .await
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .get_message(&mut txn, RESOLVED_MESSAGE.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        Some(RESOLVED_MESSAGE.clone().with(|x| x.assignee = None))
+    );
+
+    let result = REPO
+        .list_replies(&mut txn, RESOLVED_MESSAGE.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        vec![RESOLVED_MESSAGE_REPLY.clone().with(|x| x.author = None)]
+    );
+}
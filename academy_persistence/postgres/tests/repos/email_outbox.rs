@@ -0,0 +1,268 @@
+use std::time::Duration;
+
+use academy_demo::{
+    email_outbox::{ALL_EMAILS, FAILED_EMAIL, SENT_EMAIL},
+    user::FOO,
+    UUID1, UUID2,
+};
+use academy_models::{
+    email::{Email, EmailBody, EmailHeader, EmailInlineImage},
+    email_outbox::{EmailOutboxEntry, EmailOutboxStatus},
+};
+use academy_persistence_contracts::{email_outbox::EmailOutboxRepository, Database, Transaction};
+use academy_persistence_postgres::email_outbox::PostgresEmailOutboxRepository;
+use academy_utils::Apply;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    common::setup,
+    repos::{make_slice, sliced},
+};
+
+const REPO: PostgresEmailOutboxRepository = PostgresEmailOutboxRepository;
+
+fn make_entry(id: Uuid, next_attempt_at: DateTime<Utc>) -> EmailOutboxEntry {
+    EmailOutboxEntry {
+        id: id.into(),
+        email: Email {
+            recipient: FOO.user.email.clone().unwrap().into(),
+            subject: "Test".into(),
+            body: EmailBody::Alternative {
+                text: "Hello World!".into(),
+                html: r#"<p>Hello World!</p><img src="cid:logo">"#.into(),
+            },
+            reply_to: Some("admin@example.com".parse().unwrap()),
+            headers: vec![EmailHeader {
+                name: "X-Test".into(),
+                value: "42".into(),
+            }],
+            inline_images: vec![EmailInlineImage {
+                content_id: "logo".into(),
+                content_type: "image/png".into(),
+                data: vec![0x89, b'P', b'N', b'G'],
+            }],
+        },
+        status: EmailOutboxStatus::Pending,
+        attempts: 0,
+        created_at: SENT_EMAIL.created_at + Duration::from_secs(3600),
+        next_attempt_at: Some(next_attempt_at),
+        sent_at: None,
+        last_error: None,
+    }
+}
+
+#[tokio::test]
+async fn list() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let expected = ALL_EMAILS.iter().copied().cloned().collect::<Vec<_>>();
+    for limit in 1..=3 {
+        for offset in 0..=3 {
+            let slice = make_slice(limit, offset);
+            let result = REPO.list(&mut txn, None, slice).await.unwrap();
+            assert_eq!(result, sliced(&expected, slice));
+        }
+    }
+
+    let result = REPO
+        .list(&mut txn, Some(EmailOutboxStatus::Failed), make_slice(10, 0))
+        .await
+        .unwrap();
+    assert_eq!(result, vec![FAILED_EMAIL.clone()]);
+
+    let result = REPO
+        .list(
+            &mut txn,
+            Some(EmailOutboxStatus::Pending),
+            make_slice(10, 0),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn count() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.count(&mut txn, None).await.unwrap();
+    assert_eq!(result, ALL_EMAILS.len() as u64);
+
+    let result = REPO
+        .count(&mut txn, Some(EmailOutboxStatus::Sent))
+        .await
+        .unwrap();
+    assert_eq!(result, 1);
+
+    let result = REPO
+        .count(&mut txn, Some(EmailOutboxStatus::Pending))
+        .await
+        .unwrap();
+    assert_eq!(result, 0);
+}
+
+#[tokio::test]
+async fn get() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.get(&mut txn, SENT_EMAIL.id).await.unwrap();
+    assert_eq!(result.as_ref(), Some(&*SENT_EMAIL));
+
+    let result = REPO.get(&mut txn, UUID1.into()).await.unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn create() {
+    let expected = make_entry(UUID1, SENT_EMAIL.created_at);
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create(&mut txn, &expected).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get(&mut txn, expected.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+}
+
+#[tokio::test]
+async fn lock_due() {
+    let now = SENT_EMAIL.created_at + Duration::from_secs(7200);
+    let due = make_entry(UUID1, now - Duration::from_secs(60));
+    let not_due = make_entry(UUID2, now + Duration::from_secs(60));
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create(&mut txn, &due).await.unwrap();
+    REPO.create(&mut txn, &not_due).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.lock_due(&mut txn, now, 10).await.unwrap();
+    assert_eq!(result, vec![due.clone()]);
+
+    let result = REPO.lock_due(&mut txn, now, 0).await.unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn mark_sent() {
+    let now = SENT_EMAIL.created_at + Duration::from_secs(7200);
+    let entry = make_entry(UUID1, now);
+    let expected = entry.clone().with(|x| {
+        x.status = EmailOutboxStatus::Sent;
+        x.next_attempt_at = None;
+        x.sent_at = Some(now);
+    });
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create(&mut txn, &entry).await.unwrap();
+    REPO.mark_sent(&mut txn, entry.id, now).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get(&mut txn, entry.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+}
+
+#[tokio::test]
+async fn record_failed_attempt() {
+    let now = SENT_EMAIL.created_at + Duration::from_secs(7200);
+    let entry = make_entry(UUID1, now);
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create(&mut txn, &entry).await.unwrap();
+    REPO.record_failed_attempt(
+        &mut txn,
+        entry.id,
+        "connection refused",
+        Some(now + Duration::from_secs(60)),
+    )
+    .await
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get(&mut txn, entry.id).await.unwrap();
+    assert_eq!(
+        result,
+        Some(entry.clone().with(|x| {
+            x.attempts = 1;
+            x.next_attempt_at = Some(now + Duration::from_secs(60));
+            x.last_error = Some("connection refused".into());
+        }))
+    );
+
+    REPO.record_failed_attempt(&mut txn, entry.id, "timeout", None)
+        .await
+        .unwrap();
+    let result = REPO.get(&mut txn, entry.id).await.unwrap();
+    assert_eq!(
+        result,
+        Some(entry.with(|x| {
+            x.status = EmailOutboxStatus::Failed;
+            x.attempts = 2;
+            x.next_attempt_at = None;
+            x.last_error = Some("timeout".into());
+        }))
+    );
+}
+
+#[tokio::test]
+async fn retry() {
+    let now = FAILED_EMAIL.created_at + Duration::from_secs(7200);
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.retry(&mut txn, FAILED_EMAIL.id, now).await.unwrap();
+    assert!(result);
+    let result = REPO.get(&mut txn, FAILED_EMAIL.id).await.unwrap();
+    assert_eq!(
+        result,
+        Some(FAILED_EMAIL.clone().with(|x| {
+            x.status = EmailOutboxStatus::Pending;
+            x.attempts = 0;
+            x.next_attempt_at = Some(now);
+        }))
+    );
+
+    let result = REPO.retry(&mut txn, SENT_EMAIL.id, now).await.unwrap();
+    assert!(!result);
+
+    let result = REPO.retry(&mut txn, UUID1.into(), now).await.unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn delete_sent_before() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let sent_at = SENT_EMAIL.sent_at.unwrap();
+
+    let result = REPO.delete_sent_before(&mut txn, sent_at).await.unwrap();
+    assert_eq!(result, 0);
+
+    let result = REPO
+        .delete_sent_before(&mut txn, sent_at + Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(result, 1);
+
+    let result = REPO.count(&mut txn, None).await.unwrap();
+    assert_eq!(result, 1);
+    let result = REPO.get(&mut txn, FAILED_EMAIL.id).await.unwrap();
+    assert_eq!(result.as_ref(), Some(&*FAILED_EMAIL));
+}
@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use academy_demo::{
+    invite::{ADMIN_INVITE_1, FOO_INVITE_1},
+    user::{ADMIN, BAR, FOO},
+    UUID1,
+};
+use academy_models::invite::Invite;
+use academy_persistence_contracts::{
+    invite::InviteRepository, user::UserRepository, Database, Transaction,
+};
+use academy_persistence_postgres::{
+    invite::PostgresInviteRepository, user::PostgresUserRepository,
+};
+use academy_utils::Apply;
+
+use crate::common::setup;
+
+const REPO: PostgresInviteRepository = PostgresInviteRepository;
+
+#[tokio::test]
+async fn list() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.list(&mut txn, None).await.unwrap();
+    assert_eq!(result, [ADMIN_INVITE_1.clone(), FOO_INVITE_1.clone()]);
+
+    let result = REPO.list(&mut txn, Some(FOO.user.id)).await.unwrap();
+    assert_eq!(result, vec![FOO_INVITE_1.clone()]);
+
+    let result = REPO.list(&mut txn, Some(BAR.user.id)).await.unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn count_valid_by_creator() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let now = FOO_INVITE_1.created_at;
+    let result = REPO
+        .count_valid_by_creator(&mut txn, FOO.user.id, now)
+        .await
+        .unwrap();
+    assert_eq!(result, 1);
+
+    let now = FOO_INVITE_1.expires_at.unwrap();
+    let result = REPO
+        .count_valid_by_creator(&mut txn, FOO.user.id, now)
+        .await
+        .unwrap();
+    assert_eq!(result, 0);
+}
+
+#[tokio::test]
+async fn get() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.get(&mut txn, FOO_INVITE_1.id).await.unwrap();
+    assert_eq!(result.as_ref(), Some(&*FOO_INVITE_1));
+
+    let result = REPO.get(&mut txn, UUID1.into()).await.unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn get_by_code() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO
+        .get_by_code(&mut txn, &ADMIN_INVITE_1.code)
+        .await
+        .unwrap();
+    assert_eq!(result.as_ref(), Some(&*ADMIN_INVITE_1));
+
+    let result = REPO
+        .get_by_code(&mut txn, &"AAAA-BBBB-CCCC".try_into().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn create() {
+    let expected = Invite {
+        id: UUID1.into(),
+        code: "AAAA-BBBB-CCCC".try_into().unwrap(),
+        created_by: None,
+        created_at: BAR.user.created_at,
+        expires_at: None,
+        max_uses: None,
+        uses: 0,
+        admin: true,
+        cohort: None,
+    };
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create(&mut txn, &expected).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get(&mut txn, expected.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+}
+
+#[tokio::test]
+async fn delete() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.delete(&mut txn, FOO_INVITE_1.id).await.unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get(&mut txn, FOO_INVITE_1.id).await.unwrap();
+    assert_eq!(result, None);
+
+    let result = REPO.delete(&mut txn, FOO_INVITE_1.id).await.unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn redeem() {
+    let now = FOO_INVITE_1.created_at + Duration::from_secs(60);
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .redeem(&mut txn, FOO_INVITE_1.id, BAR.user.id, now)
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get(&mut txn, FOO_INVITE_1.id).await.unwrap();
+    assert_eq!(result, Some(FOO_INVITE_1.clone().with(|x| x.uses = 1)));
+
+    let result = REPO
+        .get_invite_id_by_user(&mut txn, BAR.user.id)
+        .await
+        .unwrap();
+    assert_eq!(result, Some(FOO_INVITE_1.id));
+
+    // usage limit reached
+    let result = REPO
+        .redeem(&mut txn, FOO_INVITE_1.id, ADMIN.user.id, now)
+        .await
+        .unwrap();
+    assert!(!result);
+
+    let result = REPO
+        .get_invite_id_by_user(&mut txn, ADMIN.user.id)
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn redeem_expired() {
+    let now = FOO_INVITE_1.expires_at.unwrap();
+
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO
+        .redeem(&mut txn, FOO_INVITE_1.id, BAR.user.id, now)
+        .await
+        .unwrap();
+    assert!(!result);
+
+    let result = REPO.get(&mut txn, FOO_INVITE_1.id).await.unwrap();
+    assert_eq!(result.as_ref(), Some(&*FOO_INVITE_1));
+}
+
+#[tokio::test]
+async fn delete_creator() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    PostgresUserRepository
+        .delete(&mut txn, FOO.user.id)
+        .await
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get(&mut txn, FOO_INVITE_1.id).await.unwrap();
+    assert_eq!(
+        result,
+        Some(FOO_INVITE_1.clone().with(|x| x.created_by = None))
+    );
+}
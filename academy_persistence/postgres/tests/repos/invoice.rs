@@ -0,0 +1,147 @@
+use academy_demo::{
+    invoice::{ALL_INVOICES, FOO_INVOICE_PURCHASE},
+    user::{BAR, FOO},
+    UUID1,
+};
+use academy_models::{
+    country::Country,
+    invoice::{Invoice, InvoiceKind, InvoiceRecipient, InvoiceVatTreatment},
+};
+use academy_persistence_contracts::{invoice::InvoiceRepository, Database, Transaction};
+use academy_persistence_postgres::invoice::PostgresInvoiceRepository;
+
+use crate::{
+    common::setup,
+    repos::{make_slice, sliced},
+};
+
+const REPO: PostgresInvoiceRepository = PostgresInvoiceRepository;
+
+#[tokio::test]
+async fn next_number() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    assert_eq!(REPO.next_number(&mut txn, 2024).await.unwrap(), 3);
+    assert_eq!(REPO.next_number(&mut txn, 2024).await.unwrap(), 4);
+    assert_eq!(REPO.next_number(&mut txn, 2025).await.unwrap(), 1);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    assert_eq!(REPO.next_number(&mut txn, 2024).await.unwrap(), 5);
+    assert_eq!(REPO.next_number(&mut txn, 2025).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn next_number_rollback() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    assert_eq!(REPO.next_number(&mut txn, 2024).await.unwrap(), 3);
+    assert_eq!(REPO.next_number(&mut txn, 2026).await.unwrap(), 1);
+    txn.rollback().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    assert_eq!(REPO.next_number(&mut txn, 2024).await.unwrap(), 3);
+    assert_eq!(REPO.next_number(&mut txn, 2026).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn create() {
+    let db = setup().await;
+
+    let invoice = Invoice {
+        id: UUID1.into(),
+        user_id: BAR.user.id,
+        number: "BA-2024-000003".try_into().unwrap(),
+        kind: InvoiceKind::Purchase,
+        recipient: InvoiceRecipient {
+            business: false,
+            first_name: None,
+            last_name: None,
+            street: None,
+            zip_code: None,
+            city: None,
+            country: Country::AT,
+            vat_id: None,
+            vat_id_valid: false,
+        },
+        description: "500 Coins".try_into().unwrap(),
+        net: 417,
+        vat: 83,
+        gross: 500,
+        vat_rate: 2000,
+        vat_treatment: InvoiceVatTreatment::Standard,
+        created_at: BAR.user.created_at,
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create(&mut txn, &invoice).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    assert_eq!(REPO.get(&mut txn, invoice.id).await.unwrap(), Some(invoice));
+}
+
+#[tokio::test]
+async fn create_number_conflict() {
+    let db = setup().await;
+
+    let invoice = Invoice {
+        id: UUID1.into(),
+        ..FOO_INVOICE_PURCHASE.clone()
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create(&mut txn, &invoice).await.unwrap_err();
+}
+
+#[tokio::test]
+async fn get() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    for &invoice in &*ALL_INVOICES {
+        assert_eq!(
+            REPO.get(&mut txn, invoice.id).await.unwrap().as_ref(),
+            Some(invoice)
+        );
+    }
+    assert_eq!(REPO.get(&mut txn, UUID1.into()).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn list() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let expected = ALL_INVOICES
+        .iter()
+        .filter(|i| i.user_id == FOO.user.id)
+        .rev()
+        .copied()
+        .cloned()
+        .collect::<Vec<_>>();
+    for limit in 1..=3 {
+        for offset in 0..=3 {
+            let slice = make_slice(limit, offset);
+            let result = REPO.list(&mut txn, FOO.user.id, slice).await.unwrap();
+            assert_eq!(result, sliced(&expected, slice));
+        }
+    }
+
+    let result = REPO
+        .list(&mut txn, BAR.user.id, make_slice(10, 0))
+        .await
+        .unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn count() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    assert_eq!(REPO.count(&mut txn, FOO.user.id).await.unwrap(), 2);
+    assert_eq!(REPO.count(&mut txn, BAR.user.id).await.unwrap(), 0);
+}
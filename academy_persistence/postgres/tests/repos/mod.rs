@@ -1,8 +1,16 @@
 use academy_models::pagination::PaginationSlice;
 
+mod checkout;
+mod coin;
+mod contact;
+mod email_outbox;
+mod invite;
+mod invoice;
 mod mfa;
+mod newsletter;
 mod oauth2;
 mod session;
+mod skill;
 mod user;
 
 pub fn make_slice(limit: u64, offset: u64) -> PaginationSlice {
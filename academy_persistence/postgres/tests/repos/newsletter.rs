@@ -0,0 +1,310 @@
+use std::time::Duration;
+
+use academy_demo::{
+    newsletter::{DRAFT_CAMPAIGN, SENT_CAMPAIGN},
+    user::{ADMIN2, FOO},
+    UUID1,
+};
+use academy_models::{
+    newsletter::{
+        NewsletterCampaign, NewsletterCampaignPatchRef, NewsletterCampaignProgress,
+        NewsletterCampaignStatus, NewsletterRecipient, NewsletterRecipientStatus,
+    },
+    user::{User, UserComposite},
+};
+use academy_persistence_contracts::{
+    newsletter::NewsletterRepository, user::UserRepository, Database, Transaction,
+};
+use academy_persistence_postgres::{
+    newsletter::PostgresNewsletterRepository, user::PostgresUserRepository,
+};
+use academy_utils::{patch::Patch, Apply};
+
+use crate::common::setup;
+
+const REPO: PostgresNewsletterRepository = PostgresNewsletterRepository;
+
+#[tokio::test]
+async fn list_campaigns() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.list_campaigns(&mut txn, None).await.unwrap();
+    assert_eq!(result, [DRAFT_CAMPAIGN.clone(), SENT_CAMPAIGN.clone()]);
+
+    let result = REPO
+        .list_campaigns(&mut txn, Some(NewsletterCampaignStatus::Sent))
+        .await
+        .unwrap();
+    assert_eq!(result, vec![SENT_CAMPAIGN.clone()]);
+
+    let result = REPO
+        .list_campaigns(&mut txn, Some(NewsletterCampaignStatus::Sending))
+        .await
+        .unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn get_campaign() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO
+        .get_campaign(&mut txn, DRAFT_CAMPAIGN.id)
+        .await
+        .unwrap();
+    assert_eq!(result.as_ref(), Some(&*DRAFT_CAMPAIGN));
+
+    let result = REPO.get_campaign(&mut txn, UUID1.into()).await.unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn create_campaign() {
+    let expected = NewsletterCampaign {
+        id: UUID1.into(),
+        subject: "Test".try_into().unwrap(),
+        body_html: "<p>Test</p>".try_into().unwrap(),
+        body_text: "Test".try_into().unwrap(),
+        status: NewsletterCampaignStatus::Draft,
+        created_at: DRAFT_CAMPAIGN.created_at + Duration::from_secs(60),
+        started_at: None,
+        completed_at: None,
+        progress: Default::default(),
+    };
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create_campaign(&mut txn, &expected).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get_campaign(&mut txn, expected.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+}
+
+#[tokio::test]
+async fn update_campaign() {
+    let expected = DRAFT_CAMPAIGN.clone().with(|x| {
+        x.subject = "Updated subject".try_into().unwrap();
+        x.body_text = "Updated text".try_into().unwrap();
+    });
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_campaign(
+            &mut txn,
+            DRAFT_CAMPAIGN.id,
+            NewsletterCampaignPatchRef::new()
+                .update_subject(&expected.subject)
+                .update_body_text(&expected.body_text),
+        )
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .get_campaign(&mut txn, DRAFT_CAMPAIGN.id)
+        .await
+        .unwrap();
+    assert_eq!(result, Some(expected));
+
+    let result = REPO
+        .update_campaign(&mut txn, UUID1.into(), NewsletterCampaignPatchRef::new())
+        .await
+        .unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn delete_campaign() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .delete_campaign(&mut txn, DRAFT_CAMPAIGN.id)
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .get_campaign(&mut txn, DRAFT_CAMPAIGN.id)
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+
+    let result = REPO
+        .delete_campaign(&mut txn, DRAFT_CAMPAIGN.id)
+        .await
+        .unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn start_campaign() {
+    let now = DRAFT_CAMPAIGN.created_at + Duration::from_secs(60);
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .start_campaign(&mut txn, DRAFT_CAMPAIGN.id, now)
+        .await
+        .unwrap();
+    assert_eq!(result, Some(2));
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .get_campaign(&mut txn, DRAFT_CAMPAIGN.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        Some(DRAFT_CAMPAIGN.clone().with(|x| {
+            x.status = NewsletterCampaignStatus::Sending;
+            x.started_at = Some(now);
+            x.progress.pending = 2;
+        }))
+    );
+
+    // already started
+    let result = REPO
+        .start_campaign(&mut txn, DRAFT_CAMPAIGN.id, now)
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+
+    let result = REPO
+        .start_campaign(&mut txn, SENT_CAMPAIGN.id, now)
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn send_campaign() {
+    let now = DRAFT_CAMPAIGN.created_at + Duration::from_secs(60);
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.start_campaign(&mut txn, DRAFT_CAMPAIGN.id, now)
+        .await
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut expected = [make_recipient(&FOO), make_recipient(&ADMIN2)];
+    expected.sort_by_key(|x| x.user_id);
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .lock_pending_recipients(&mut txn, DRAFT_CAMPAIGN.id, 1)
+        .await
+        .unwrap();
+    assert_eq!(result, expected[..1]);
+
+    // locked recipients are skipped by other transactions
+    let mut txn2 = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .lock_pending_recipients(&mut txn2, DRAFT_CAMPAIGN.id, 10)
+        .await
+        .unwrap();
+    assert_eq!(result, expected[1..]);
+    txn2.rollback().await.unwrap();
+
+    REPO.update_recipient_status(
+        &mut txn,
+        DRAFT_CAMPAIGN.id,
+        expected[0].user_id,
+        NewsletterRecipientStatus::Sent,
+        now,
+    )
+    .await
+    .unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let unsubscribed = [&*FOO, &*ADMIN2]
+        .into_iter()
+        .find(|x| x.user.id == expected[1].user_id)
+        .unwrap();
+    PostgresUserRepository
+        .update(
+            &mut txn,
+            unsubscribed.user.id,
+            User {
+                newsletter: false,
+                ..unsubscribed.user.clone()
+            }
+            .as_patch_ref(),
+        )
+        .await
+        .unwrap();
+
+    let result = REPO
+        .skip_unsubscribed_recipients(&mut txn, DRAFT_CAMPAIGN.id, now)
+        .await
+        .unwrap();
+    assert_eq!(result, 1);
+
+    let result = REPO
+        .lock_pending_recipients(&mut txn, DRAFT_CAMPAIGN.id, 10)
+        .await
+        .unwrap();
+    assert_eq!(result, []);
+
+    let result = REPO
+        .complete_campaign(&mut txn, DRAFT_CAMPAIGN.id, now)
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .get_campaign(&mut txn, DRAFT_CAMPAIGN.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        Some(DRAFT_CAMPAIGN.clone().with(|x| {
+            x.status = NewsletterCampaignStatus::Sent;
+            x.started_at = Some(now);
+            x.completed_at = Some(now);
+            x.progress = NewsletterCampaignProgress {
+                pending: 0,
+                sent: 1,
+                failed: 0,
+                skipped: 1,
+            };
+        }))
+    );
+
+    // already completed
+    let result = REPO
+        .complete_campaign(&mut txn, DRAFT_CAMPAIGN.id, now)
+        .await
+        .unwrap();
+    assert!(!result);
+}
+
+fn make_recipient(user: &UserComposite) -> NewsletterRecipient {
+    NewsletterRecipient {
+        user_id: user.user.id,
+        email: user
+            .user
+            .email
+            .clone()
+            .unwrap()
+            .with_name(user.profile.display_name.clone().into_inner()),
+    }
+}
@@ -0,0 +1,390 @@
+use std::time::Duration;
+
+use academy_demo::{
+    skill::{
+        ADVANCED_PYTHON_COURSE, ADVANCED_PYTHON_LECTURE, ALL_COURSES, ALL_PREREQUISITES,
+        ALL_SKILLS, GIT, PYTHON_ADVANCED, PYTHON_BASICS, PYTHON_COURSE, PYTHON_LECTURE_1,
+        PYTHON_LECTURE_2, PYTHON_LECTURE_3, PYTHON_SECTION_1, PYTHON_SECTION_2,
+    },
+    user::{BAR, FOO},
+    UUID1,
+};
+use academy_models::skill::{
+    CoursePatchRef, CourseSection, CourseSectionPatchRef, LearningProgress, Lecture,
+    LecturePatchRef, Skill, SkillPatchRef, SkillPrerequisite,
+};
+use academy_persistence_contracts::{skill::SkillRepository, Database, Transaction};
+use academy_persistence_postgres::skill::PostgresSkillRepository;
+use academy_utils::Apply;
+
+use crate::common::setup;
+
+const REPO: PostgresSkillRepository = PostgresSkillRepository;
+
+#[tokio::test]
+async fn list_skills() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.list_skills(&mut txn).await.unwrap();
+    assert_eq!(
+        result,
+        ALL_SKILLS.iter().copied().cloned().collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn get_skill() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.get_skill(&mut txn, GIT.id).await.unwrap();
+    assert_eq!(result.as_ref(), Some(&*GIT));
+
+    let result = REPO.get_skill(&mut txn, UUID1.into()).await.unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn create_skill() {
+    let expected = Skill {
+        id: UUID1.into(),
+        title: "Rust".try_into().unwrap(),
+        description: "Ownership and borrowing".try_into().unwrap(),
+        created_at: GIT.created_at + Duration::from_secs(60),
+    };
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    REPO.create_skill(&mut txn, &expected).await.unwrap();
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get_skill(&mut txn, expected.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+}
+
+#[tokio::test]
+async fn update_skill() {
+    let expected = GIT.clone().with(|x| {
+        x.description = "Version control".try_into().unwrap();
+    });
+
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_skill(
+            &mut txn,
+            GIT.id,
+            SkillPatchRef::new().update_description(&expected.description),
+        )
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get_skill(&mut txn, GIT.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+
+    let result = REPO
+        .update_skill(&mut txn, UUID1.into(), SkillPatchRef::new())
+        .await
+        .unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn delete_skill() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.delete_skill(&mut txn, PYTHON_BASICS.id).await.unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get_skill(&mut txn, PYTHON_BASICS.id).await.unwrap();
+    assert_eq!(result, None);
+
+    let result = REPO.list_prerequisites(&mut txn).await.unwrap();
+    assert_eq!(result, []);
+
+    let result = REPO.get_course(&mut txn, PYTHON_COURSE.id).await.unwrap();
+    assert_eq!(result, None);
+
+    let result = REPO
+        .get_lecture(&mut txn, PYTHON_LECTURE_1.id)
+        .await
+        .unwrap();
+    assert_eq!(result, None);
+
+    let result = REPO.delete_skill(&mut txn, UUID1.into()).await.unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn prerequisites() {
+    let edge = SkillPrerequisite {
+        skill_id: PYTHON_ADVANCED.id,
+        prerequisite_id: GIT.id,
+    };
+
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.list_prerequisites(&mut txn).await.unwrap();
+    assert_eq!(result, *ALL_PREREQUISITES);
+
+    assert!(REPO.add_prerequisite(&mut txn, edge).await.unwrap());
+    assert!(!REPO.add_prerequisite(&mut txn, edge).await.unwrap());
+
+    let mut result = REPO.list_prerequisites(&mut txn).await.unwrap();
+    result.sort_by_key(|x| (x.skill_id, x.prerequisite_id));
+    let mut expected = vec![ALL_PREREQUISITES[0], edge];
+    expected.sort_by_key(|x| (x.skill_id, x.prerequisite_id));
+    assert_eq!(result, expected);
+
+    assert!(REPO.remove_prerequisite(&mut txn, edge).await.unwrap());
+    assert!(!REPO.remove_prerequisite(&mut txn, edge).await.unwrap());
+
+    let result = REPO.list_prerequisites(&mut txn).await.unwrap();
+    assert_eq!(result, *ALL_PREREQUISITES);
+}
+
+#[tokio::test]
+async fn list_courses() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.list_courses(&mut txn, None).await.unwrap();
+    assert_eq!(
+        result,
+        ALL_COURSES.iter().copied().cloned().collect::<Vec<_>>()
+    );
+
+    let result = REPO
+        .list_courses(&mut txn, Some(PYTHON_ADVANCED.id))
+        .await
+        .unwrap();
+    assert_eq!(result, vec![ADVANCED_PYTHON_COURSE.clone()]);
+
+    let result = REPO.list_courses(&mut txn, Some(GIT.id)).await.unwrap();
+    assert_eq!(result, []);
+}
+
+#[tokio::test]
+async fn update_course() {
+    let expected = PYTHON_COURSE.clone().with(|x| {
+        x.title = "Python for Beginners".try_into().unwrap();
+    });
+
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO
+        .update_course(
+            &mut txn,
+            PYTHON_COURSE.id,
+            CoursePatchRef::new().update_title(&expected.title),
+        )
+        .await
+        .unwrap();
+    assert!(result);
+
+    let result = REPO.get_course(&mut txn, PYTHON_COURSE.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+}
+
+#[tokio::test]
+async fn sections() {
+    let section = CourseSection {
+        id: UUID1.into(),
+        course_id: PYTHON_COURSE.id,
+        title: "Classes".try_into().unwrap(),
+        position: 2,
+    };
+
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    REPO.create_section(&mut txn, &section).await.unwrap();
+
+    let result = REPO
+        .list_sections(&mut txn, PYTHON_COURSE.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        [
+            PYTHON_SECTION_1.clone(),
+            PYTHON_SECTION_2.clone(),
+            section.clone()
+        ]
+    );
+
+    let expected = section.clone().with(|x| {
+        x.title = "Objects".try_into().unwrap();
+    });
+    let result = REPO
+        .update_section(
+            &mut txn,
+            section.id,
+            CourseSectionPatchRef::new().update_title(&expected.title),
+        )
+        .await
+        .unwrap();
+    assert!(result);
+
+    let result = REPO.get_section(&mut txn, section.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+
+    let result = REPO
+        .delete_section(&mut txn, PYTHON_SECTION_1.id)
+        .await
+        .unwrap();
+    assert!(result);
+
+    let result = REPO
+        .list_lectures(&mut txn, PYTHON_COURSE.id)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![PYTHON_LECTURE_3.clone()]);
+}
+
+#[tokio::test]
+async fn lectures() {
+    let lecture = Lecture {
+        id: UUID1.into(),
+        section_id: PYTHON_SECTION_1.id,
+        title: "Comments".try_into().unwrap(),
+        description: "".try_into().unwrap(),
+        position: 2,
+    };
+
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    REPO.create_lecture(&mut txn, &lecture).await.unwrap();
+
+    let result = REPO
+        .list_lectures(&mut txn, PYTHON_COURSE.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        [
+            PYTHON_LECTURE_1.clone(),
+            PYTHON_LECTURE_2.clone(),
+            lecture.clone(),
+            PYTHON_LECTURE_3.clone()
+        ]
+    );
+
+    let expected = lecture.clone().with(|x| {
+        x.description = "# like this".try_into().unwrap();
+    });
+    let result = REPO
+        .update_lecture(
+            &mut txn,
+            lecture.id,
+            LecturePatchRef::new().update_description(&expected.description),
+        )
+        .await
+        .unwrap();
+    assert!(result);
+
+    let result = REPO.get_lecture(&mut txn, lecture.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+
+    assert!(REPO.delete_lecture(&mut txn, lecture.id).await.unwrap());
+    assert!(!REPO.delete_lecture(&mut txn, lecture.id).await.unwrap());
+}
+
+#[tokio::test]
+async fn list_course_progress() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let mut expected = vec![
+        (
+            PYTHON_COURSE.id,
+            LearningProgress {
+                completed_lectures: 1,
+                total_lectures: 3,
+            },
+        ),
+        (
+            ADVANCED_PYTHON_COURSE.id,
+            LearningProgress {
+                completed_lectures: 0,
+                total_lectures: 1,
+            },
+        ),
+    ];
+    expected.sort_by_key(|x| x.0);
+
+    let result = REPO
+        .list_course_progress(&mut txn, FOO.user.id)
+        .await
+        .unwrap();
+    assert_eq!(result, expected);
+
+    let result = REPO
+        .list_course_progress(&mut txn, BAR.user.id)
+        .await
+        .unwrap();
+    assert!(result.iter().all(|(_, p)| p.completed_lectures == 0));
+}
+
+#[tokio::test]
+async fn complete_and_reset_lecture() {
+    let now = FOO.user.created_at + Duration::from_secs(7 * 24 * 3600);
+
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO
+        .list_completed_lectures(&mut txn, FOO.user.id, PYTHON_COURSE.id)
+        .await
+        .unwrap();
+    assert_eq!(result, [PYTHON_LECTURE_1.id]);
+
+    let result = REPO
+        .complete_lecture(&mut txn, FOO.user.id, PYTHON_LECTURE_1.id, now)
+        .await
+        .unwrap();
+    assert!(!result);
+
+    let result = REPO
+        .complete_lecture(&mut txn, FOO.user.id, PYTHON_LECTURE_3.id, now)
+        .await
+        .unwrap();
+    assert!(result);
+
+    let result = REPO
+        .list_completed_lectures(&mut txn, FOO.user.id, PYTHON_COURSE.id)
+        .await
+        .unwrap();
+    assert_eq!(result, [PYTHON_LECTURE_1.id, PYTHON_LECTURE_3.id]);
+
+    let result = REPO
+        .reset_lecture(&mut txn, FOO.user.id, PYTHON_LECTURE_1.id)
+        .await
+        .unwrap();
+    assert!(result);
+
+    let result = REPO
+        .reset_lecture(&mut txn, FOO.user.id, ADVANCED_PYTHON_LECTURE.id)
+        .await
+        .unwrap();
+    assert!(!result);
+
+    let result = REPO
+        .list_completed_lectures(&mut txn, FOO.user.id, PYTHON_COURSE.id)
+        .await
+        .unwrap();
+    assert_eq!(result, [PYTHON_LECTURE_3.id]);
+}
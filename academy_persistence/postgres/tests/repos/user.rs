@@ -1,17 +1,26 @@
 use std::sync::LazyLock;
 
 use academy_demo::{
-    oauth2::FOO_OAUTH2_LINK_1,
+    oauth2::{FOO_OAUTH2_LINK_1, TEST_OAUTH2_PROVIDER_ID},
     user::{ADMIN, ADMIN2, ALL_USERS, BAR, FOO},
     UUID1,
 };
-use academy_models::user::{User, UserComposite, UserDetails, UserFilter};
+use academy_models::{
+    pagination::{Pagination, SortDirection},
+    url::Url,
+    user::{
+        User, UserComposite, UserDetails, UserFilter, UserInvoiceInfo, UserInvoiceInfoPatch,
+        UserListKey, UserPrivacy, UserProfileVisibility, UserSortField, UserSorting,
+        UserVatIdStatus, UserVatIdValidation,
+    },
+};
 use academy_persistence_contracts::{
     user::{UserRepoError, UserRepository},
     Database, Transaction,
 };
 use academy_persistence_postgres::user::PostgresUserRepository;
-use academy_utils::{assert_matches, patch::Patch};
+use academy_utils::{assert_matches, patch::Patch, Apply};
+use chrono::{TimeZone, Utc};
 
 use crate::{
     common::setup,
@@ -52,6 +61,39 @@ static FILTER_TESTS: LazyLock<Vec<(UserFilter, Vec<&UserComposite>)>> = LazyLock
         (filter!(newsletter: false), vec![&ADMIN, &BAR]),
         (filter!(admin: false, enabled: true), vec![&FOO]),
         (filter!(name: "2", admin: true), vec![&ADMIN2]),
+        (filter!(query: ""), ALL_USERS.clone()),
+        (filter!(query: "ADMINISTRATOR"), vec![&ADMIN, &ADMIN2]),
+        (filter!(query: "admn"), vec![&ADMIN, &ADMIN2]),
+        (filter!(query: "example.com"), vec![&ADMIN, &ADMIN2, &FOO]),
+        (filter!(query: "foo 42"), vec![&FOO]),
+        (filter!(query: "x y"), vec![&FOO]),
+        (filter!(query: "%"), vec![]),
+        (filter!(query: "does not exist"), vec![]),
+        (
+            filter!(created_after: Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()),
+            vec![&FOO, &BAR],
+        ),
+        (
+            filter!(created_before: Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()),
+            vec![&ADMIN, &ADMIN2],
+        ),
+        (
+            filter!(created_after: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(), created_before: Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap()),
+            vec![&ADMIN, &ADMIN2, &FOO],
+        ),
+        (
+            filter!(last_login_after: Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()),
+            vec![&ADMIN, &ADMIN2],
+        ),
+        (
+            filter!(last_login_before: Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()),
+            vec![&FOO],
+        ),
+        (
+            filter!(oauth2_provider: TEST_OAUTH2_PROVIDER_ID.clone()),
+            vec![&FOO],
+        ),
+        (filter!(oauth2_provider: "other"), vec![]),
     ]
 });
 
@@ -73,19 +115,124 @@ async fn list_composites() {
 
     for (filter, expected) in &*FILTER_TESTS {
         let slice = make_slice(100, 0);
-        let result = REPO.list_composites(&mut txn, filter, slice).await.unwrap();
+        let result = REPO
+            .list_composites(&mut txn, filter, Default::default(), slice.into())
+            .await
+            .unwrap();
         assert_eq!(&result.iter().collect::<Vec<_>>(), sliced(expected, slice));
 
         let slice = make_slice(2, 0);
-        let result = REPO.list_composites(&mut txn, filter, slice).await.unwrap();
+        let result = REPO
+            .list_composites(&mut txn, filter, Default::default(), slice.into())
+            .await
+            .unwrap();
         assert_eq!(&result.iter().collect::<Vec<_>>(), sliced(expected, slice));
 
         let slice = make_slice(100, 1);
-        let result = REPO.list_composites(&mut txn, filter, slice).await.unwrap();
+        let result = REPO
+            .list_composites(&mut txn, filter, Default::default(), slice.into())
+            .await
+            .unwrap();
         assert_eq!(&result.iter().collect::<Vec<_>>(), sliced(expected, slice));
     }
 }
 
+#[tokio::test]
+async fn list_composites_sorted() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    for (field, direction, expected) in [
+        (
+            UserSortField::CreatedAt,
+            SortDirection::Asc,
+            [&*ADMIN, &ADMIN2, &FOO, &BAR],
+        ),
+        (
+            UserSortField::CreatedAt,
+            SortDirection::Desc,
+            [&*BAR, &FOO, &ADMIN, &ADMIN2],
+        ),
+        (
+            UserSortField::LastLogin,
+            SortDirection::Asc,
+            [&*BAR, &FOO, &ADMIN, &ADMIN2],
+        ),
+        (
+            UserSortField::LastLogin,
+            SortDirection::Desc,
+            [&*ADMIN, &ADMIN2, &FOO, &BAR],
+        ),
+        (
+            UserSortField::Name,
+            SortDirection::Asc,
+            [&*ADMIN, &ADMIN2, &BAR, &FOO],
+        ),
+        (
+            UserSortField::Name,
+            SortDirection::Desc,
+            [&*FOO, &BAR, &ADMIN2, &ADMIN],
+        ),
+    ] {
+        let result = REPO
+            .list_composites(
+                &mut txn,
+                &Default::default(),
+                UserSorting { field, direction },
+                make_slice(100, 0).into(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+    }
+}
+
+#[tokio::test]
+async fn list_composites_keyset() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    for field in [
+        UserSortField::CreatedAt,
+        UserSortField::LastLogin,
+        UserSortField::Name,
+    ] {
+        for direction in [SortDirection::Asc, SortDirection::Desc] {
+            let sorting = UserSorting { field, direction };
+            for filter in [filter!(), filter!(admin: false), filter!(name: "admin")] {
+                let expected = REPO
+                    .list_composites(&mut txn, &filter, sorting, make_slice(100, 0).into())
+                    .await
+                    .unwrap();
+
+                let limit = 1.try_into().unwrap();
+                let mut result = REPO
+                    .list_composites(&mut txn, &filter, sorting, make_slice(1, 0).into())
+                    .await
+                    .unwrap();
+                while let Some(last) = result.last() {
+                    let key = UserListKey::new(&last.user, field);
+                    let page = REPO
+                        .list_composites(
+                            &mut txn,
+                            &filter,
+                            sorting,
+                            Pagination::After { limit, key },
+                        )
+                        .await
+                        .unwrap();
+                    if page.is_empty() {
+                        break;
+                    }
+                    result.extend(page);
+                }
+
+                assert_eq!(result, expected, "{sorting:?} {filter:?}");
+            }
+        }
+    }
+}
+
 #[tokio::test]
 async fn exists() {
     let db = setup().await;
@@ -380,12 +527,105 @@ async fn update_profile() {
     assert_eq!(result, expected);
 }
 
+#[tokio::test]
+async fn update_avatar_url() {
+    let db = setup().await;
+
+    let avatar_url = "https://example.com/avatars/bar.png"
+        .parse::<Url>()
+        .unwrap();
+    let mut expected = BAR.clone();
+    expected.profile.avatar_url = Some(avatar_url.clone());
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_avatar_url(&mut txn, BAR.user.id, &Some(avatar_url))
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .get_composite(&mut txn, BAR.user.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(result, expected);
+
+    let result = REPO
+        .update_avatar_url(&mut txn, BAR.user.id, &None)
+        .await
+        .unwrap();
+    assert!(result);
+
+    let result = REPO
+        .get_composite(&mut txn, BAR.user.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(result, *BAR);
+}
+
+#[tokio::test]
+async fn get_privacy() {
+    let db = setup().await;
+    let mut txn = db.begin_transaction().await.unwrap();
+
+    let result = REPO.get_privacy(&mut txn, FOO.user.id).await.unwrap();
+    assert_eq!(result, Some(UserPrivacy::default()));
+
+    let result = REPO.get_privacy(&mut txn, UUID1.into()).await.unwrap();
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn update_privacy() {
+    let db = setup().await;
+
+    let expected = UserPrivacy {
+        display_name: UserProfileVisibility::Public,
+        bio: UserProfileVisibility::Users,
+        tags: UserProfileVisibility::Private,
+        registration: UserProfileVisibility::Users,
+        avatar: UserProfileVisibility::Private,
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_privacy(&mut txn, BAR.user.id, expected.as_patch_ref())
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.get_privacy(&mut txn, BAR.user.id).await.unwrap();
+    assert_eq!(result, Some(expected));
+
+    let result = REPO.get_privacy(&mut txn, FOO.user.id).await.unwrap();
+    assert_eq!(result, Some(UserPrivacy::default()));
+
+    let result = REPO
+        .update_privacy(
+            &mut txn,
+            UUID1.into(),
+            UserPrivacy::default().as_patch_ref(),
+        )
+        .await
+        .unwrap();
+    assert!(!result);
+}
+
 #[tokio::test]
 async fn update_invoice_info() {
     let db = setup().await;
 
     let expected = UserComposite {
-        invoice_info: FOO.invoice_info.clone(),
+        invoice_info: UserInvoiceInfo {
+            vat_id_validation: None,
+            ..FOO.invoice_info.clone()
+        },
         ..BAR.clone()
     };
 
@@ -406,6 +646,131 @@ async fn update_invoice_info() {
     assert_eq!(result, expected);
 }
 
+#[tokio::test]
+async fn update_invoice_info_resets_vat_id_validation() {
+    let db = setup().await;
+
+    let expected = UserComposite {
+        invoice_info: UserInvoiceInfo {
+            vat_id: Some("DE1234567890".try_into().unwrap()),
+            vat_id_validation: None,
+            ..FOO.invoice_info.clone()
+        },
+        ..FOO.clone()
+    };
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_invoice_info(
+            &mut txn,
+            FOO.user.id,
+            UserInvoiceInfoPatch::new()
+                .update_vat_id(expected.invoice_info.vat_id.clone())
+                .as_ref(),
+        )
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .get_composite(&mut txn, FOO.user.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(result, expected);
+}
+
+#[tokio::test]
+async fn update_vat_id_validation() {
+    let db = setup().await;
+
+    let validation = UserVatIdValidation {
+        status: UserVatIdStatus::Invalid,
+        consultation_number: None,
+        validated_at: Utc.with_ymd_and_hms(2024, 8, 1, 12, 0, 0).unwrap(),
+    };
+    let expected = FOO
+        .clone()
+        .with(|u| u.invoice_info.vat_id_validation = Some(validation.clone()));
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_vat_id_validation(
+            &mut txn,
+            FOO.user.id,
+            FOO.invoice_info.vat_id.as_ref().unwrap(),
+            &validation,
+        )
+        .await
+        .unwrap();
+    assert!(result);
+    txn.commit().await.unwrap();
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .get_composite(&mut txn, FOO.user.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(result, expected);
+}
+
+#[tokio::test]
+async fn update_vat_id_validation_vat_id_changed() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .update_vat_id_validation(
+            &mut txn,
+            FOO.user.id,
+            &"DE1234567890".try_into().unwrap(),
+            FOO.invoice_info.vat_id_validation.as_ref().unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(!result);
+}
+
+#[tokio::test]
+async fn list_vat_ids_to_revalidate() {
+    let db = setup().await;
+
+    let validated_at = FOO
+        .invoice_info
+        .vat_id_validation
+        .as_ref()
+        .unwrap()
+        .validated_at;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO
+        .list_vat_ids_to_revalidate(&mut txn, validated_at)
+        .await
+        .unwrap();
+    assert_eq!(result, []);
+
+    let result = REPO
+        .list_vat_ids_to_revalidate(&mut txn, validated_at + chrono::Duration::seconds(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        [(FOO.user.id, FOO.invoice_info.vat_id.clone().unwrap())]
+    );
+}
+
+#[tokio::test]
+async fn list_unconverted_countries() {
+    let db = setup().await;
+
+    let mut txn = db.begin_transaction().await.unwrap();
+    let result = REPO.list_unconverted_countries(&mut txn).await.unwrap();
+    assert_eq!(result, []);
+}
+
 #[tokio::test]
 async fn delete() {
     let db = setup().await;
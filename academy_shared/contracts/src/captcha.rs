@@ -1,23 +1,35 @@
 use std::future::Future;
 
+use academy_models::captcha::{CaptchaChallenge, CaptchaConfig};
 use thiserror::Error;
 
 #[cfg_attr(feature = "mock", mockall::automock)]
 pub trait CaptchaService: Send + Sync + 'static {
-    /// Return the public reCAPTCHA sitekey if reCAPTCHA is enabled.
-    #[allow(
-        clippy::needless_lifetimes,
-        reason = "explicit lifetime needed for automock"
-    )]
-    fn get_recaptcha_sitekey<'a>(&'a self) -> Option<&'a str>;
-
-    /// Verify the given reCAPTCHA response.
+    /// Return the public configuration of the captcha provider.
+    fn get_config(&self) -> CaptchaConfig;
+
+    /// Create a new proof-of-work challenge.
+    ///
+    /// Only available if the proof-of-work captcha is enabled.
+    fn create_challenge(
+        &self,
+    ) -> impl Future<Output = Result<CaptchaChallenge, CaptchaCreateChallengeError>> + Send;
+
+    /// Verify the given captcha response.
     fn check<'a>(
         &self,
         response: Option<&'a str>,
     ) -> impl Future<Output = Result<(), CaptchaCheckError>> + Send;
 }
 
+#[derive(Debug, Error)]
+pub enum CaptchaCreateChallengeError {
+    #[error("The proof-of-work captcha is not enabled.")]
+    NotAvailable,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
 #[derive(Debug, Error)]
 pub enum CaptchaCheckError {
     #[error("The response is invalid or the user is probably not human.")]
@@ -28,11 +40,22 @@ pub enum CaptchaCheckError {
 
 #[cfg(feature = "mock")]
 impl MockCaptchaService {
-    pub fn with_get_recaptcha_sitekey(mut self, sitekey: Option<&'static str>) -> Self {
-        self.expect_get_recaptcha_sitekey()
+    pub fn with_get_config(mut self, config: CaptchaConfig) -> Self {
+        self.expect_get_config()
+            .once()
+            .with()
+            .return_once(move || config);
+        self
+    }
+
+    pub fn with_create_challenge(
+        mut self,
+        result: Result<CaptchaChallenge, CaptchaCreateChallengeError>,
+    ) -> Self {
+        self.expect_create_challenge()
             .once()
             .with()
-            .return_once(move || sitekey);
+            .return_once(|| Box::pin(std::future::ready(result)));
         self
     }
 
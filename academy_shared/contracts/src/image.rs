@@ -0,0 +1,41 @@
+use std::future::Future;
+
+use thiserror::Error;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait ImageService: Send + Sync + 'static {
+    /// Crop the given image to a square and scale it to each of the given
+    /// sizes. The resulting thumbnails are encoded as PNG and returned in the
+    /// same order as `sizes`.
+    fn make_square_thumbnails(
+        &self,
+        data: Vec<u8>,
+        sizes: Vec<u32>,
+    ) -> impl Future<Output = Result<Vec<Vec<u8>>, ImageError>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ImageError {
+    #[error("The image format is not supported.")]
+    UnsupportedFormat,
+    #[error("The image could not be decoded.")]
+    Invalid,
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+#[cfg(feature = "mock")]
+impl MockImageService {
+    pub fn with_make_square_thumbnails(
+        mut self,
+        data: Vec<u8>,
+        sizes: Vec<u32>,
+        result: Result<Vec<Vec<u8>>, ImageError>,
+    ) -> Self {
+        self.expect_make_square_thumbnails()
+            .once()
+            .with(mockall::predicate::eq(data), mockall::predicate::eq(sizes))
+            .return_once(|_, _| Box::pin(std::future::ready(result)));
+        self
+    }
+}
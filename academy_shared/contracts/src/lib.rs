@@ -1,8 +1,10 @@
 pub mod captcha;
 pub mod hash;
 pub mod id;
+pub mod image;
 pub mod jwt;
 pub mod password;
+pub mod pdf;
 pub mod secret;
 pub mod time;
 pub mod totp;
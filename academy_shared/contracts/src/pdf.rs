@@ -0,0 +1,21 @@
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait PdfService: Send + Sync + 'static {
+    /// Render the given plain text as an A4 PDF document using a monospace
+    /// font.
+    ///
+    /// Long lines are wrapped and new pages are started automatically, a form
+    /// feed (`\x0c`) starts a new page explicitly. Characters which are not
+    /// supported by the font are replaced by `?`.
+    fn render_text(&self, title: &str, text: &str) -> anyhow::Result<Vec<u8>>;
+}
+
+#[cfg(feature = "mock")]
+impl MockPdfService {
+    pub fn with_render_text(mut self, title: String, text: String, result: Vec<u8>) -> Self {
+        self.expect_render_text()
+            .once()
+            .with(mockall::predicate::eq(title), mockall::predicate::eq(text))
+            .return_once(|_, _| Ok(result));
+        self
+    }
+}
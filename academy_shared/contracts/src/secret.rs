@@ -1,4 +1,4 @@
-use academy_models::{mfa::MfaRecoveryCode, Sensitive, VerificationCode};
+use academy_models::{invite::InviteCode, mfa::MfaRecoveryCode, Sensitive, VerificationCode};
 
 #[cfg_attr(feature = "mock", mockall::automock)]
 pub trait SecretService: Send + Sync + 'static {
@@ -13,6 +13,9 @@ pub trait SecretService: Send + Sync + 'static {
 
     /// Generate a new random mfa recovery code.
     fn generate_mfa_recovery_code(&self) -> MfaRecoveryCode;
+
+    /// Generate a new random invite code.
+    fn generate_invite_code(&self) -> InviteCode;
 }
 
 #[cfg(feature = "mock")]
@@ -48,4 +51,12 @@ impl MockSecretService {
             .return_once(|| result);
         self
     }
+
+    pub fn with_generate_invite_code(mut self, result: InviteCode) -> Self {
+        self.expect_generate_invite_code()
+            .once()
+            .with()
+            .return_once(|| result);
+        self
+    }
 }
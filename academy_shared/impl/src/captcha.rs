@@ -1,15 +1,29 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
+use academy_cache_contracts::CacheService;
 use academy_di::Build;
-use academy_extern_contracts::recaptcha::RecaptchaApiService;
-use academy_shared_contracts::captcha::{CaptchaCheckError, CaptchaService};
+use academy_extern_contracts::{
+    hcaptcha::HcaptchaApiService, recaptcha::RecaptchaApiService, turnstile::TurnstileApiService,
+};
+use academy_models::captcha::{CaptchaChallenge, CaptchaConfig};
+use academy_shared_contracts::{
+    captcha::{CaptchaCheckError, CaptchaCreateChallengeError, CaptchaService},
+    secret::SecretService,
+};
 use academy_utils::trace_instrument;
 use anyhow::Context;
+use sha2::{Digest, Sha256};
+
+const CHALLENGE_LENGTH: usize = 32;
 
 #[derive(Debug, Clone, Build)]
 #[cfg_attr(test, derive(Default))]
-pub struct CaptchaServiceImpl<RecaptchaApi> {
+pub struct CaptchaServiceImpl<RecaptchaApi, HcaptchaApi, TurnstileApi, Secret, Cache> {
     recaptcha_api: RecaptchaApi,
+    hcaptcha_api: HcaptchaApi,
+    turnstile_api: TurnstileApi,
+    secret: Secret,
+    cache: Cache,
     config: CaptchaServiceConfig,
 }
 
@@ -17,6 +31,9 @@ pub struct CaptchaServiceImpl<RecaptchaApi> {
 pub enum CaptchaServiceConfig {
     Disabled,
     Recaptcha(RecaptchaCaptchaServiceConfig),
+    Hcaptcha(SiteverifyCaptchaServiceConfig),
+    Turnstile(SiteverifyCaptchaServiceConfig),
+    ProofOfWork(ProofOfWorkCaptchaServiceConfig),
 }
 
 #[derive(Debug, Clone)]
@@ -26,63 +43,228 @@ pub struct RecaptchaCaptchaServiceConfig {
     pub min_score: f64,
 }
 
-impl<RecaptchaApi> CaptchaService for CaptchaServiceImpl<RecaptchaApi>
+#[derive(Debug, Clone)]
+pub struct SiteverifyCaptchaServiceConfig {
+    pub sitekey: Arc<str>,
+    pub secret: Arc<str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProofOfWorkCaptchaServiceConfig {
+    pub difficulty: u8,
+    pub challenge_ttl: Duration,
+}
+
+impl<RecaptchaApi, HcaptchaApi, TurnstileApi, Secret, Cache> CaptchaService
+    for CaptchaServiceImpl<RecaptchaApi, HcaptchaApi, TurnstileApi, Secret, Cache>
 where
     RecaptchaApi: RecaptchaApiService,
+    HcaptchaApi: HcaptchaApiService,
+    TurnstileApi: TurnstileApiService,
+    Secret: SecretService,
+    Cache: CacheService,
 {
     #[trace_instrument(skip(self))]
-    fn get_recaptcha_sitekey(&self) -> Option<&str> {
+    fn get_config(&self) -> CaptchaConfig {
         match &self.config {
-            CaptchaServiceConfig::Recaptcha(RecaptchaCaptchaServiceConfig { sitekey, .. }) => {
-                Some(sitekey)
-            }
-            CaptchaServiceConfig::Disabled => None,
+            CaptchaServiceConfig::Disabled => CaptchaConfig::Disabled,
+            CaptchaServiceConfig::Recaptcha(config) => CaptchaConfig::Recaptcha {
+                sitekey: config.sitekey.to_string(),
+            },
+            CaptchaServiceConfig::Hcaptcha(config) => CaptchaConfig::Hcaptcha {
+                sitekey: config.sitekey.to_string(),
+            },
+            CaptchaServiceConfig::Turnstile(config) => CaptchaConfig::Turnstile {
+                sitekey: config.sitekey.to_string(),
+            },
+            CaptchaServiceConfig::ProofOfWork(config) => CaptchaConfig::ProofOfWork {
+                difficulty: config.difficulty,
+            },
         }
     }
 
     #[trace_instrument(skip(self))]
-    async fn check(&self, response: Option<&str>) -> Result<(), CaptchaCheckError> {
-        let config = match &self.config {
-            CaptchaServiceConfig::Recaptcha(config) => config,
-            CaptchaServiceConfig::Disabled => return Ok(()),
+    async fn create_challenge(&self) -> Result<CaptchaChallenge, CaptchaCreateChallengeError> {
+        let CaptchaServiceConfig::ProofOfWork(config) = &self.config else {
+            return Err(CaptchaCreateChallengeError::NotAvailable);
         };
 
-        let response = response.ok_or(CaptchaCheckError::Failed)?;
-        let response = self
-            .recaptcha_api
-            .siteverify(response, &config.secret)
+        let challenge = self.secret.generate(CHALLENGE_LENGTH).0;
+
+        self.cache
+            .set(
+                &challenge_cache_key(&challenge),
+                &config.difficulty,
+                Some(config.challenge_ttl),
+            )
             .await
-            .context("Failed to verify reCAPTCHA response")?;
-        let ok = response.success && response.score.unwrap_or(0.0) >= config.min_score;
+            .context("Failed to save captcha challenge in cache")?;
+
+        Ok(CaptchaChallenge {
+            challenge,
+            difficulty: config.difficulty,
+        })
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn check(&self, response: Option<&str>) -> Result<(), CaptchaCheckError> {
+        let ok = match (&self.config, response) {
+            (CaptchaServiceConfig::Disabled, _) => true,
+            (_, None) => false,
+            (CaptchaServiceConfig::Recaptcha(config), Some(response)) => {
+                let response = self
+                    .recaptcha_api
+                    .siteverify(response, &config.secret)
+                    .await
+                    .context("Failed to verify reCAPTCHA response")?;
+                response.success && response.score.unwrap_or(0.0) >= config.min_score
+            }
+            (CaptchaServiceConfig::Hcaptcha(config), Some(response)) => {
+                self.hcaptcha_api
+                    .siteverify(response, &config.secret)
+                    .await
+                    .context("Failed to verify hCaptcha response")?
+                    .success
+            }
+            (CaptchaServiceConfig::Turnstile(config), Some(response)) => {
+                self.turnstile_api
+                    .siteverify(response, &config.secret)
+                    .await
+                    .context("Failed to verify Turnstile response")?
+                    .success
+            }
+            (CaptchaServiceConfig::ProofOfWork(_), Some(response)) => {
+                self.check_proof_of_work(response).await?
+            }
+        };
+
         ok.then_some(()).ok_or(CaptchaCheckError::Failed)
     }
 }
 
+impl<RecaptchaApi, HcaptchaApi, TurnstileApi, Secret, Cache>
+    CaptchaServiceImpl<RecaptchaApi, HcaptchaApi, TurnstileApi, Secret, Cache>
+where
+    Cache: CacheService,
+{
+    /// Verify a proof-of-work captcha response of the form `{challenge}:{nonce}`.
+    ///
+    /// Every challenge can only be used once, even if the response is invalid.
+    async fn check_proof_of_work(&self, response: &str) -> anyhow::Result<bool> {
+        let Some((challenge, _)) = response.split_once(':') else {
+            return Ok(false);
+        };
+
+        let cache_key = challenge_cache_key(challenge);
+        let Some(difficulty) = self
+            .cache
+            .take::<u8>(&cache_key)
+            .await
+            .context("Failed to take captcha challenge from cache")?
+        else {
+            return Ok(false);
+        };
+
+        Ok(leading_zero_bits(&Sha256::digest(response)) >= difficulty.into())
+    }
+}
+
+fn challenge_cache_key(challenge: &str) -> String {
+    format!("captcha_challenge:{challenge}")
+}
+
+fn leading_zero_bits(hash: &[u8]) -> u32 {
+    let mut bits = 0;
+    for &byte in hash {
+        bits += byte.leading_zeros();
+        if byte != 0 {
+            break;
+        }
+    }
+    bits
+}
+
 #[cfg(test)]
 mod tests {
-    use academy_extern_contracts::recaptcha::{
-        MockRecaptchaApiService, RecaptchaSiteverifyResponse,
+    use academy_cache_contracts::MockCacheService;
+    use academy_extern_contracts::{
+        hcaptcha::{HcaptchaSiteverifyResponse, MockHcaptchaApiService},
+        recaptcha::{MockRecaptchaApiService, RecaptchaSiteverifyResponse},
+        turnstile::{MockTurnstileApiService, TurnstileSiteverifyResponse},
     };
+    use academy_shared_contracts::secret::MockSecretService;
     use academy_utils::assert_matches;
 
     use super::*;
 
-    type Sut = CaptchaServiceImpl<MockRecaptchaApiService>;
+    type Sut = CaptchaServiceImpl<
+        MockRecaptchaApiService,
+        MockHcaptchaApiService,
+        MockTurnstileApiService,
+        MockSecretService,
+        MockCacheService,
+    >;
+
+    const CHALLENGE: &str = "3JRgcg0dbsTfW91PjkQb8wJoWxJGazRs";
 
     #[test]
-    fn get_recaptcha_sitekey_enabled() {
+    fn get_config_recaptcha() {
         // Arrange
         let sut = Sut::default();
 
         // Act
-        let result = sut.get_recaptcha_sitekey();
+        let result = sut.get_config();
+
+        // Assert
+        assert_eq!(
+            result,
+            CaptchaConfig::Recaptcha {
+                sitekey: "sitekey".into()
+            }
+        );
+    }
 
+    #[test]
+    fn get_config_hcaptcha() {
         // Arrange
-        assert_eq!(result, Some("sitekey"));
+        let config = CaptchaServiceConfig::Hcaptcha(Default::default());
+
+        let sut = CaptchaServiceImpl {
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.get_config();
+
+        // Assert
+        assert_eq!(
+            result,
+            CaptchaConfig::Hcaptcha {
+                sitekey: "sitekey".into()
+            }
+        );
+    }
+
+    #[test]
+    fn get_config_proof_of_work() {
+        // Arrange
+        let config = CaptchaServiceConfig::ProofOfWork(Default::default());
+
+        let sut = CaptchaServiceImpl {
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.get_config();
+
+        // Assert
+        assert_eq!(result, CaptchaConfig::ProofOfWork { difficulty: 8 });
     }
 
     #[test]
-    fn get_recaptcha_sitekey_disabled() {
+    fn get_config_disabled() {
         // Arrange
         let config = CaptchaServiceConfig::Disabled;
 
@@ -92,10 +274,55 @@ mod tests {
         };
 
         // Act
-        let result = sut.get_recaptcha_sitekey();
+        let result = sut.get_config();
+
+        // Assert
+        assert_eq!(result, CaptchaConfig::Disabled);
+    }
 
+    #[tokio::test]
+    async fn create_challenge_ok() {
         // Arrange
-        assert_eq!(result, None);
+        let config = CaptchaServiceConfig::ProofOfWork(Default::default());
+
+        let secret = MockSecretService::new().with_generate(CHALLENGE_LENGTH, CHALLENGE.into());
+
+        let cache = MockCacheService::new().with_set(
+            format!("captcha_challenge:{CHALLENGE}"),
+            8u8,
+            Some(Duration::from_secs(600)),
+        );
+
+        let sut = CaptchaServiceImpl {
+            secret,
+            cache,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.create_challenge().await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            CaptchaChallenge {
+                challenge: CHALLENGE.into(),
+                difficulty: 8
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn create_challenge_not_available() {
+        // Arrange
+        let sut = Sut::default();
+
+        // Act
+        let result = sut.create_challenge().await;
+
+        // Assert
+        assert_matches!(result, Err(CaptchaCreateChallengeError::NotAvailable));
     }
 
     #[tokio::test]
@@ -142,6 +369,7 @@ mod tests {
         let sut = CaptchaServiceImpl {
             recaptcha_api,
             config,
+            ..Sut::default()
         };
 
         // Act
@@ -269,6 +497,157 @@ mod tests {
         assert_matches!(result, Err(CaptchaCheckError::Failed));
     }
 
+    #[tokio::test]
+    async fn check_hcaptcha() {
+        // Arrange
+        let config = CaptchaServiceConfig::Hcaptcha(Default::default());
+
+        let hcaptcha_api = MockHcaptchaApiService::new().with_siteverify(
+            "captcha response".into(),
+            "secret".into(),
+            HcaptchaSiteverifyResponse { success: true },
+        );
+
+        let sut = CaptchaServiceImpl {
+            hcaptcha_api,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(Some("captcha response")).await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_turnstile_failed() {
+        // Arrange
+        let config = CaptchaServiceConfig::Turnstile(Default::default());
+
+        let turnstile_api = MockTurnstileApiService::new().with_siteverify(
+            "captcha response".into(),
+            "secret".into(),
+            TurnstileSiteverifyResponse { success: false },
+        );
+
+        let sut = CaptchaServiceImpl {
+            turnstile_api,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(Some("captcha response")).await;
+
+        // Assert
+        assert_matches!(result, Err(CaptchaCheckError::Failed));
+    }
+
+    #[tokio::test]
+    async fn check_proof_of_work() {
+        // Arrange
+        let config = CaptchaServiceConfig::ProofOfWork(Default::default());
+
+        let response = solve_challenge(8);
+
+        let cache_key = format!("captcha_challenge:{CHALLENGE}");
+        let cache = MockCacheService::new().with_take(cache_key, Some(8u8));
+
+        let sut = CaptchaServiceImpl {
+            cache,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(Some(&response)).await;
+
+        // Assert
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_proof_of_work_insufficient_difficulty() {
+        // Arrange
+        let config = CaptchaServiceConfig::ProofOfWork(Default::default());
+
+        let response = (0..)
+            .map(|nonce| format!("{CHALLENGE}:{nonce}"))
+            .find(|response| leading_zero_bits(&Sha256::digest(response)) == 0)
+            .unwrap();
+
+        let cache_key = format!("captcha_challenge:{CHALLENGE}");
+        let cache = MockCacheService::new().with_take(cache_key, Some(8u8));
+
+        let sut = CaptchaServiceImpl {
+            cache,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(Some(&response)).await;
+
+        // Assert
+        assert_matches!(result, Err(CaptchaCheckError::Failed));
+    }
+
+    #[tokio::test]
+    async fn check_proof_of_work_unknown_challenge() {
+        // Arrange
+        let config = CaptchaServiceConfig::ProofOfWork(Default::default());
+
+        let response = solve_challenge(8);
+
+        let cache =
+            MockCacheService::new().with_take::<u8>(format!("captcha_challenge:{CHALLENGE}"), None);
+
+        let sut = CaptchaServiceImpl {
+            cache,
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(Some(&response)).await;
+
+        // Assert
+        assert_matches!(result, Err(CaptchaCheckError::Failed));
+    }
+
+    #[tokio::test]
+    async fn check_proof_of_work_invalid_format() {
+        // Arrange
+        let config = CaptchaServiceConfig::ProofOfWork(Default::default());
+
+        let sut = CaptchaServiceImpl {
+            config,
+            ..Sut::default()
+        };
+
+        // Act
+        let result = sut.check(Some(CHALLENGE)).await;
+
+        // Assert
+        assert_matches!(result, Err(CaptchaCheckError::Failed));
+    }
+
+    #[test]
+    fn count_leading_zero_bits() {
+        assert_eq!(leading_zero_bits(&[0xff, 0x00]), 0);
+        assert_eq!(leading_zero_bits(&[0x00, 0x00, 0x10]), 19);
+        assert_eq!(leading_zero_bits(&[0x00, 0x00]), 16);
+    }
+
+    fn solve_challenge(difficulty: u32) -> String {
+        (0..)
+            .map(|nonce| format!("{CHALLENGE}:{nonce}"))
+            .find(|response| leading_zero_bits(&Sha256::digest(response)) >= difficulty)
+            .unwrap()
+    }
+
     impl Default for CaptchaServiceConfig {
         fn default() -> Self {
             Self::Recaptcha(Default::default())
@@ -284,4 +663,22 @@ mod tests {
             }
         }
     }
+
+    impl Default for SiteverifyCaptchaServiceConfig {
+        fn default() -> Self {
+            Self {
+                sitekey: "sitekey".into(),
+                secret: "secret".into(),
+            }
+        }
+    }
+
+    impl Default for ProofOfWorkCaptchaServiceConfig {
+        fn default() -> Self {
+            Self {
+                difficulty: 8,
+                challenge_ttl: Duration::from_secs(600),
+            }
+        }
+    }
 }
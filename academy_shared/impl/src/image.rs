@@ -0,0 +1,126 @@
+use std::io::Cursor;
+
+use academy_di::Build;
+use academy_shared_contracts::image::{ImageError, ImageService};
+use academy_utils::trace_instrument;
+use anyhow::{anyhow, Context};
+use image::{imageops::FilterType, ImageFormat, ImageReader, Limits};
+
+/// Maximum width and height of images that are accepted for decoding.
+const MAX_DIMENSION: u32 = 8192;
+
+#[derive(Debug, Clone, Copy, Build)]
+pub struct ImageServiceImpl;
+
+impl ImageService for ImageServiceImpl {
+    #[trace_instrument(skip(self, data))]
+    async fn make_square_thumbnails(
+        &self,
+        data: Vec<u8>,
+        sizes: Vec<u32>,
+    ) -> Result<Vec<Vec<u8>>, ImageError> {
+        tokio::task::spawn_blocking(move || make_square_thumbnails(&data, &sizes))
+            .await
+            .map_err(|err| anyhow!(err).context("Failed to create thumbnails"))?
+    }
+}
+
+fn make_square_thumbnails(data: &[u8], sizes: &[u32]) -> Result<Vec<Vec<u8>>, ImageError> {
+    let format = image::guess_format(data).map_err(|_| ImageError::UnsupportedFormat)?;
+    if !matches!(
+        format,
+        ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP
+    ) {
+        return Err(ImageError::UnsupportedFormat);
+    }
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+
+    let mut reader = ImageReader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|_| ImageError::Invalid)?;
+
+    let side = image.width().min(image.height());
+    let image = image.crop_imm(
+        (image.width() - side) / 2,
+        (image.height() - side) / 2,
+        side,
+        side,
+    );
+
+    sizes
+        .iter()
+        .map(|&size| {
+            let mut out = Vec::new();
+            image
+                .resize_exact(size, size, FilterType::Lanczos3)
+                .write_to(&mut Cursor::new(&mut out), ImageFormat::Png)
+                .context("Failed to encode thumbnail")?;
+            Ok(out)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use academy_utils::assert_matches;
+    use image::{DynamicImage, GenericImageView};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn ok() {
+        // Arrange
+        let mut data = Vec::new();
+        DynamicImage::new_rgb8(300, 200)
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .unwrap();
+
+        let sut = ImageServiceImpl;
+
+        // Act
+        let result = sut.make_square_thumbnails(data, vec![128, 32]).await;
+
+        // Assert
+        let result = result.unwrap();
+        assert_eq!(result.len(), 2);
+        for (data, size) in result.iter().zip([128, 32]) {
+            let image = image::load_from_memory_with_format(data, ImageFormat::Png).unwrap();
+            assert_eq!(image.dimensions(), (size, size));
+        }
+    }
+
+    #[tokio::test]
+    async fn unsupported_format() {
+        // Arrange
+        let sut = ImageServiceImpl;
+
+        // Act
+        let result = sut
+            .make_square_thumbnails(b"GIF89a".to_vec(), vec![128])
+            .await;
+
+        // Assert
+        assert_matches!(result, Err(ImageError::UnsupportedFormat));
+    }
+
+    #[tokio::test]
+    async fn invalid() {
+        // Arrange
+        let mut data = Vec::new();
+        DynamicImage::new_rgb8(16, 16)
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .unwrap();
+        data.truncate(data.len() / 2);
+
+        let sut = ImageServiceImpl;
+
+        // Act
+        let result = sut.make_square_thumbnails(data, vec![128]).await;
+
+        // Assert
+        assert_matches!(result, Err(ImageError::Invalid));
+    }
+}
@@ -0,0 +1,175 @@
+use academy_di::Build;
+use academy_shared_contracts::pdf::PdfService;
+use academy_utils::trace_instrument;
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+
+/// Width and height of an A4 page in points.
+const PAGE_SIZE: (f32, f32) = (595.0, 842.0);
+const MARGIN: f32 = 56.0;
+const FONT_SIZE: f32 = 10.0;
+const LEADING: f32 = 12.0;
+
+/// Width of each character of the Courier font in units of the font size.
+const CHAR_WIDTH: f32 = 0.6;
+
+const FONT_NAME: Name = Name(b"F1");
+
+#[derive(Debug, Clone, Copy, Build)]
+pub struct PdfServiceImpl;
+
+impl PdfService for PdfServiceImpl {
+    #[trace_instrument(skip(self, text))]
+    fn render_text(&self, title: &str, text: &str) -> anyhow::Result<Vec<u8>> {
+        Ok(render_text(title, text))
+    }
+}
+
+fn render_text(title: &str, text: &str) -> Vec<u8> {
+    let pages = layout(text);
+
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let font_id = Ref::new(3);
+    let info_id = Ref::new(4);
+    let page_ids = (0..pages.len() as i32)
+        .map(|i| (Ref::new(5 + 2 * i), Ref::new(6 + 2 * i)))
+        .collect::<Vec<_>>();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().map(|&(page_id, _)| page_id))
+        .count(pages.len() as i32);
+    pdf.type1_font(font_id)
+        .base_font(Name(b"Courier"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+    pdf.document_info(info_id)
+        .title(TextStr(title))
+        .producer(TextStr("Bootstrap Academy"));
+
+    for (lines, &(page_id, content_id)) in pages.iter().zip(&page_ids) {
+        let mut page = pdf.page(page_id);
+        page.media_box(Rect::new(0.0, 0.0, PAGE_SIZE.0, PAGE_SIZE.1))
+            .parent(page_tree_id)
+            .contents(content_id);
+        page.resources().fonts().pair(FONT_NAME, font_id);
+        page.finish();
+
+        let mut content = Content::new();
+        content
+            .begin_text()
+            .set_font(FONT_NAME, FONT_SIZE)
+            .set_leading(LEADING)
+            .next_line(MARGIN, PAGE_SIZE.1 - MARGIN - FONT_SIZE);
+        for line in lines {
+            content
+                .show(Str(&encode_win_ansi(line)))
+                .next_line_using_leading();
+        }
+        content.end_text();
+        pdf.stream(content_id, &content.finish());
+    }
+
+    pdf.finish()
+}
+
+/// Split the given text into pages of wrapped lines.
+fn layout(text: &str) -> Vec<Vec<String>> {
+    let line_width = ((PAGE_SIZE.0 - 2.0 * MARGIN) / (FONT_SIZE * CHAR_WIDTH)) as usize;
+    let page_height = ((PAGE_SIZE.1 - 2.0 * MARGIN) / LEADING) as usize;
+
+    text.split('\x0c')
+        .flat_map(|page| {
+            let lines = page
+                .trim_end_matches('\n')
+                .lines()
+                .flat_map(|line| {
+                    let chars = line.replace('\t', "    ").chars().collect::<Vec<_>>();
+                    if chars.is_empty() {
+                        return vec![String::new()];
+                    }
+                    chars
+                        .chunks(line_width)
+                        .map(|chunk| chunk.iter().collect::<String>().trim_end().into())
+                        .collect()
+                })
+                .collect::<Vec<String>>();
+
+            if lines.is_empty() {
+                return vec![Vec::new()];
+            }
+            lines
+                .chunks(page_height)
+                .map(<[_]>::to_vec)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Encode the given string using the Windows-1252 character set, which is
+/// used by the standard PDF fonts.
+fn encode_win_ansi(line: &str) -> Vec<u8> {
+    line.chars()
+        .map(|c| match c {
+            ' '..='~' | '\u{a0}'..='\u{ff}' => c as u8,
+            '€' => 0x80,
+            '‚' => 0x82,
+            '„' => 0x84,
+            '…' => 0x85,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            _ => b'?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_text() {
+        let pdf = PdfServiceImpl
+            .render_text("Rechnung", "Rechnung\n\nGesamtbetrag: 9,50\n")
+            .unwrap();
+        let contains = |needle: &[u8]| pdf.windows(needle.len()).any(|w| w == needle);
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(contains(b"/BaseFont /Courier"));
+        assert!(contains(b"(Rechnung) Tj"));
+        assert!(contains(b"(Gesamtbetrag: 9,50) Tj"));
+    }
+
+    #[test]
+    fn layout_wrap() {
+        let text = format!("{}\n\nfoo\tbar", "x".repeat(100));
+        assert_eq!(
+            layout(&text),
+            [[
+                "x".repeat(80),
+                "x".repeat(20),
+                String::new(),
+                "foo    bar".into()
+            ]]
+        );
+    }
+
+    #[test]
+    fn layout_pages() {
+        let text = (0..70).map(|i| format!("{i}\n")).collect::<String>() + "\x0cfoo";
+        let pages = layout(&text);
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0].len(), 60);
+        assert_eq!(pages[1].len(), 10);
+        assert_eq!(pages[2], ["foo"]);
+    }
+
+    #[test]
+    fn encode() {
+        assert_eq!(encode_win_ansi("Müller 5 € → x"), b"M\xfcller 5 \x80 ? x");
+    }
+}
@@ -1,5 +1,5 @@
 use academy_di::Build;
-use academy_models::{mfa::MfaRecoveryCode, Sensitive, VerificationCode};
+use academy_models::{invite::InviteCode, mfa::MfaRecoveryCode, Sensitive, VerificationCode};
 use academy_shared_contracts::secret::SecretService;
 use academy_utils::trace_instrument;
 use rand::{
@@ -47,6 +47,18 @@ impl SecretService for SecretServiceImpl {
         .try_into()
         .unwrap()
     }
+
+    #[trace_instrument(skip(self))]
+    fn generate_invite_code(&self) -> InviteCode {
+        generate_hyphenated_code(
+            csprng(),
+            uppercase_digits(),
+            InviteCode::CHUNK_COUNT,
+            InviteCode::CHUNK_SIZE,
+        )
+        .try_into()
+        .unwrap()
+    }
 }
 
 fn generate_hyphenated_code(
@@ -138,6 +150,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn generate_invite_code() {
+        // Arrange
+        let sut = SecretServiceImpl;
+
+        // Act + Assert
+        for _ in 0..4096 {
+            sut.generate_invite_code();
+        }
+    }
+
     #[test]
     fn uppercase_digits() {
         // Arrange
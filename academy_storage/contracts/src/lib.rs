@@ -0,0 +1,42 @@
+use std::future::Future;
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+pub trait StorageService: Send + Sync + 'static {
+    /// Read the object with the given key.
+    fn get(&self, key: &str) -> impl Future<Output = anyhow::Result<Option<Vec<u8>>>> + Send;
+
+    /// Create a new or replace an existing object.
+    fn put(&self, key: &str, data: Vec<u8>) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Remove an existing object.
+    ///
+    /// Does nothing if the object does not exist.
+    fn remove(&self, key: &str) -> impl Future<Output = anyhow::Result<()>> + Send;
+}
+
+#[cfg(feature = "mock")]
+impl MockStorageService {
+    pub fn with_get(mut self, key: String, result: Option<Vec<u8>>) -> Self {
+        self.expect_get()
+            .once()
+            .with(mockall::predicate::eq(key))
+            .return_once(|_| Box::pin(std::future::ready(Ok(result))));
+        self
+    }
+
+    pub fn with_put(mut self, key: String, data: Vec<u8>) -> Self {
+        self.expect_put()
+            .once()
+            .with(mockall::predicate::eq(key), mockall::predicate::eq(data))
+            .return_once(|_, _| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+
+    pub fn with_remove(mut self, key: String) -> Self {
+        self.expect_remove()
+            .once()
+            .with(mockall::predicate::eq(key))
+            .return_once(|_| Box::pin(std::future::ready(Ok(()))));
+        self
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use academy_di::Build;
+use academy_storage_contracts::StorageService;
+use academy_utils::trace_instrument;
+use anyhow::{anyhow, Context};
+
+/// Storage backend that keeps all objects as files in a local directory.
+#[derive(Debug, Clone, Build)]
+pub struct LocalStorage {
+    config: LocalStorageConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalStorageConfig {
+    pub path: Arc<Path>,
+}
+
+impl LocalStorage {
+    /// Resolve the path of the file for the given key.
+    ///
+    /// Keys are `/`-separated relative paths which may not contain `.` or `..`
+    /// components to ensure that objects never escape the storage directory.
+    fn path(&self, key: &str) -> anyhow::Result<PathBuf> {
+        let path = Path::new(key);
+        if key.is_empty()
+            || !path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(anyhow!("Invalid storage key {key:?}"));
+        }
+
+        Ok(self.config.path.join(path))
+    }
+}
+
+impl StorageService for LocalStorage {
+    #[trace_instrument(skip(self))]
+    async fn get(&self, key: &str) -> anyhow::Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.path(key)?).await {
+            Ok(data) => Ok(Some(data)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(anyhow!(err).context("Failed to read object from storage")),
+        }
+    }
+
+    #[trace_instrument(skip(self, data))]
+    async fn put(&self, key: &str, data: Vec<u8>) -> anyhow::Result<()> {
+        let path = self.path(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .context("Failed to create storage directory")?;
+        }
+
+        // Write to a temporary file first and then rename it, so readers never
+        // observe partially written objects.
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        tokio::fs::write(&tmp, data)
+            .await
+            .context("Failed to write object to storage")?;
+        tokio::fs::rename(&tmp, &path)
+            .await
+            .context("Failed to move object into place")
+    }
+
+    #[trace_instrument(skip(self))]
+    async fn remove(&self, key: &str) -> anyhow::Result<()> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(anyhow!(err).context("Failed to remove object from storage")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn put_get_remove() {
+        let (storage, root) = setup();
+
+        assert_eq!(storage.get("foo/bar.txt").await.unwrap(), None);
+
+        storage
+            .put("foo/bar.txt", b"hello world".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            storage.get("foo/bar.txt").await.unwrap().unwrap(),
+            b"hello world"
+        );
+
+        storage.put("foo/bar.txt", b"42".to_vec()).await.unwrap();
+        assert_eq!(storage.get("foo/bar.txt").await.unwrap().unwrap(), b"42");
+
+        storage.remove("foo/bar.txt").await.unwrap();
+        assert_eq!(storage.get("foo/bar.txt").await.unwrap(), None);
+
+        storage.remove("foo/bar.txt").await.unwrap();
+
+        tokio::fs::remove_dir_all(root).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn invalid_keys() {
+        let (storage, root) = setup();
+
+        for key in ["", "/etc/passwd", "../foo", "foo/../../bar", "./foo"] {
+            assert!(storage.get(key).await.is_err());
+            assert!(storage.put(key, vec![]).await.is_err());
+            assert!(storage.remove(key).await.is_err());
+        }
+
+        tokio::fs::remove_dir_all(root).await.unwrap();
+    }
+
+    fn setup() -> (LocalStorage, PathBuf) {
+        let path = std::env::temp_dir().join(format!("academy-storage-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&path).unwrap();
+        let storage = LocalStorage {
+            config: LocalStorageConfig {
+                path: path.as_path().into(),
+            },
+        };
+        (storage, path)
+    }
+}
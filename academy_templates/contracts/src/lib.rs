@@ -1,11 +1,33 @@
 use std::fmt::Debug;
 
+use academy_models::{
+    invoice::{InvoiceKind, InvoiceVatTreatment},
+    locale::Locale,
+};
 use serde::Serialize;
 
 #[cfg_attr(feature = "mock", mockall::automock)]
 pub trait TemplateService: Send + Sync + 'static {
-    /// Render the given template.
-    fn render<T: Template + 'static>(&self, template: &T) -> anyhow::Result<String>;
+    /// Render the given template in the given locale.
+    ///
+    /// Falls back to less specific locales (see [`Locale::fallbacks`]) if the
+    /// template has not been translated to the given locale.
+    fn render<T: Template + 'static>(
+        &self,
+        template: &T,
+        locale: &Locale,
+    ) -> anyhow::Result<String>;
+
+    /// Render the plain text version of the given template in the given
+    /// locale.
+    ///
+    /// The rendered HTML is converted to plain text, so this should only be
+    /// used for HTML templates without a dedicated text template.
+    fn render_text<T: Template + 'static>(
+        &self,
+        template: &T,
+        locale: &Locale,
+    ) -> anyhow::Result<String>;
 }
 
 #[cfg(feature = "mock")]
@@ -13,42 +35,152 @@ impl MockTemplateService {
     pub fn with_render<T: Template + Send + PartialEq + std::fmt::Debug + 'static>(
         mut self,
         template: T,
+        locale: Locale,
         result: String,
     ) -> Self {
         self.expect_render()
             .once()
-            .with(mockall::predicate::eq(template))
-            .return_once(|_| Ok(result));
+            .with(
+                mockall::predicate::eq(template),
+                mockall::predicate::eq(locale),
+            )
+            .return_once(|_, _| Ok(result));
+        self
+    }
+
+    pub fn with_render_text<T: Template + Send + PartialEq + std::fmt::Debug + 'static>(
+        mut self,
+        template: T,
+        locale: Locale,
+        result: String,
+    ) -> Self {
+        self.expect_render_text()
+            .once()
+            .with(
+                mockall::predicate::eq(template),
+                mockall::predicate::eq(locale),
+            )
+            .return_once(|_, _| Ok(result));
         self
     }
 }
 
 pub trait Template: Serialize + Debug {
     const NAME: &'static str;
-    const TEMPLATE: &'static str;
 }
 
-pub const BASE_TEMPLATE: &str = include_str!("../templates/base.html");
+/// A template which is rendered as the body of an email.
+pub trait EmailTemplate: Template {
+    /// The translated subject lines of the email by locale.
+    const SUBJECTS: &'static [(&'static str, &'static str)];
+
+    /// Return the subject line of the email in the given locale, falling back
+    /// to less specific locales if no translation is available.
+    fn subject(locale: &Locale) -> &'static str {
+        locale
+            .fallbacks()
+            .find_map(|locale| Self::SUBJECTS.iter().find(|&&(l, _)| l == locale))
+            .map(|&(_, subject)| subject)
+            .unwrap_or_default()
+    }
+}
+
+/// The base templates by locale.
+pub const BASE_TEMPLATES: &[(&str, &str)] = &[
+    ("de", include_str!("../templates/de/base.html")),
+    ("en", include_str!("../templates/en/base.html")),
+];
 
 macro_rules! templates {
-    ($( $ident:ident ( $path:literal ), )* ) => {
+    ($( $ident:ident ( $path:literal, [$($locale:ident),* $(,)?] ), )* ) => {
         $(
             impl Template for $ident {
                 const NAME: &'static str = stringify!($ident);
-                const TEMPLATE: &'static str = include_str!(concat!("../templates/", $path));
             }
         )*
 
-        pub const TEMPLATES: &[(&str, &str)] = &[
-            $( ($ident::NAME, $ident::TEMPLATE) ),*
+        /// All templates as `(locale, name, template)` tuples.
+        pub const TEMPLATES: &[(&str, &str, &str)] = &[
+            $($(
+                (
+                    stringify!($locale),
+                    $ident::NAME,
+                    include_str!(concat!("../templates/", stringify!($locale), "/", $path)),
+                ),
+            )*)*
         ];
     };
 }
 
+macro_rules! subjects {
+    ($( $ident:ident { $($locale:ident : $subject:literal),* $(,)? }, )*) => {
+        $(
+            impl EmailTemplate for $ident {
+                const SUBJECTS: &'static [(&'static str, &'static str)] = &[
+                    $( (stringify!($locale), $subject) ),*
+                ];
+            }
+        )*
+    };
+}
+
 templates! {
-    ResetPasswordTemplate("reset_password.html"),
-    VerifyEmailTemplate("verify_email.html"),
-    SubscribeNewsletterTemplate("subscribe_newsletter.html"),
+    ResetPasswordTemplate("reset_password.html", [de, en]),
+    VerifyEmailTemplate("verify_email.html", [de, en]),
+    SubscribeNewsletterTemplate("subscribe_newsletter.html", [de, en]),
+    NewsletterTemplate("newsletter.html", [de, en]),
+    NewsletterTextTemplate("newsletter.txt", [de, en]),
+    PasswordChangedTemplate("password_changed.html", [de, en]),
+    EmailChangedTemplate("email_changed.html", [de, en]),
+    AccountDisabledTemplate("account_disabled.html", [de, en]),
+    MfaEnabledTemplate("mfa_enabled.html", [de, en]),
+    MfaDisabledTemplate("mfa_disabled.html", [de, en]),
+    OAuth2LinkCreatedTemplate("oauth2_link_created.html", [de, en]),
+    OAuth2LinkDeletedTemplate("oauth2_link_deleted.html", [de, en]),
+    InvoiceTemplate("invoice.txt", [de, en]),
+}
+
+subjects! {
+    ResetPasswordTemplate {
+        de: "Passwort zurücksetzen - Bootstrap Academy",
+        en: "Reset your password - Bootstrap Academy",
+    },
+    VerifyEmailTemplate {
+        de: "Willkommen bei der Bootstrap Academy!",
+        en: "Welcome to Bootstrap Academy!",
+    },
+    SubscribeNewsletterTemplate {
+        de: "Newsletter abonnieren - Bootstrap Academy",
+        en: "Subscribe to the newsletter - Bootstrap Academy",
+    },
+    PasswordChangedTemplate {
+        de: "Dein Passwort wurde geändert - Bootstrap Academy",
+        en: "Your password has been changed - Bootstrap Academy",
+    },
+    EmailChangedTemplate {
+        de: "Deine E-Mail-Adresse wurde geändert - Bootstrap Academy",
+        en: "Your email address has been changed - Bootstrap Academy",
+    },
+    AccountDisabledTemplate {
+        de: "Dein Konto wurde deaktiviert - Bootstrap Academy",
+        en: "Your account has been disabled - Bootstrap Academy",
+    },
+    MfaEnabledTemplate {
+        de: "Zwei-Faktor-Authentifizierung aktiviert - Bootstrap Academy",
+        en: "Two-factor authentication enabled - Bootstrap Academy",
+    },
+    MfaDisabledTemplate {
+        de: "Zwei-Faktor-Authentifizierung deaktiviert - Bootstrap Academy",
+        en: "Two-factor authentication disabled - Bootstrap Academy",
+    },
+    OAuth2LinkCreatedTemplate {
+        de: "Neue Anmeldemethode verknüpft - Bootstrap Academy",
+        en: "New login method linked - Bootstrap Academy",
+    },
+    OAuth2LinkDeletedTemplate {
+        de: "Anmeldemethode entfernt - Bootstrap Academy",
+        en: "Login method removed - Bootstrap Academy",
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -67,4 +199,84 @@ pub struct VerifyEmailTemplate {
 pub struct SubscribeNewsletterTemplate {
     pub code: String,
     pub url: String,
+    pub unsubscribe_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NewsletterTemplate {
+    pub subject: String,
+    /// Raw HTML content of the newsletter campaign.
+    pub content: String,
+    pub unsubscribe_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NewsletterTextTemplate {
+    pub content: String,
+    pub unsubscribe_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PasswordChangedTemplate {
+    /// Url of the page to start account recovery if the change was not made
+    /// by the user.
+    pub recovery_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EmailChangedTemplate {
+    pub new_email: String,
+    pub recovery_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AccountDisabledTemplate {
+    pub recovery_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MfaEnabledTemplate {
+    pub recovery_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MfaDisabledTemplate {
+    pub recovery_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OAuth2LinkCreatedTemplate {
+    pub provider_name: String,
+    pub remote_user_name: String,
+    pub recovery_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OAuth2LinkDeletedTemplate {
+    pub provider_name: String,
+    pub remote_user_name: String,
+    pub recovery_url: String,
+}
+
+/// Plain text template of invoices, which is rendered as a PDF document.
+///
+/// Amounts, rates and dates are expected to be formatted according to the
+/// locale of the template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvoiceTemplate {
+    pub kind: InvoiceKind,
+    pub number: String,
+    pub date: String,
+    /// Name and address of the academy, one line each
+    pub issuer: Vec<String>,
+    pub issuer_vat_id: Option<String>,
+    /// Name and address of the user, one line each
+    pub recipient: Vec<String>,
+    pub recipient_vat_id: Option<String>,
+    pub description: String,
+    pub net: String,
+    pub vat: String,
+    pub vat_rate: String,
+    pub gross: String,
+    pub vat_treatment: InvoiceVatTreatment,
 }
@@ -1,50 +1,136 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 use academy_di::Build;
-use academy_templates_contracts::{Template, TemplateService, BASE_TEMPLATE, TEMPLATES};
+use academy_models::locale::Locale;
+use academy_templates_contracts::{Template, TemplateService, BASE_TEMPLATES, TEMPLATES};
 use academy_utils::trace_instrument;
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use tera::Tera;
 
+/// Line width of plain text versions of HTML templates.
+const TEXT_WIDTH: usize = 80;
+
 #[derive(Debug, Clone, Build)]
 pub struct TemplateServiceImpl {
+    config: TemplateServiceConfig,
     #[di(default)]
     state: State,
 }
 
 #[derive(Debug, Clone)]
-struct State(Arc<Tera>);
+pub struct TemplateServiceConfig {
+    /// Source of the logo image in the base template, e.g. a public url or a
+    /// `cid:` url referencing an inline image.
+    pub logo_src: Arc<str>,
+}
+
+/// One tera instance per locale, so that localized templates can extend the
+/// base template of the same locale.
+#[derive(Debug, Clone)]
+struct State(Arc<HashMap<&'static str, Tera>>);
 
 impl Default for State {
     fn default() -> Self {
-        let mut tera = Tera::default();
+        let mut teras = HashMap::<_, Tera>::new();
 
-        tera.add_raw_template("base", BASE_TEMPLATE).unwrap();
+        for &(locale, template) in BASE_TEMPLATES {
+            let tera = teras.entry(locale).or_default();
+            tera.add_raw_template("base", template).unwrap();
+        }
 
-        for &(name, template) in TEMPLATES {
+        for &(locale, name, template) in TEMPLATES {
+            let tera = teras.entry(locale).or_default();
             tera.add_raw_template(name, template).unwrap();
         }
 
-        Self(tera.into())
+        Self(teras.into())
     }
 }
 
 impl TemplateService for TemplateServiceImpl {
     #[trace_instrument(skip(self))]
-    fn render<T: Template>(&self, template: &T) -> anyhow::Result<String> {
+    fn render<T: Template + 'static>(
+        &self,
+        template: &T,
+        locale: &Locale,
+    ) -> anyhow::Result<String> {
         let context = tera::Context::from_serialize(template)
             .with_context(|| format!("Failed to build tera context for template {}", T::NAME))?;
+        self.render_context(T::NAME, context, locale)
+    }
 
-        self.state
-            .0
-            .render(T::NAME, &context)
-            .with_context(|| format!("Failed to render template {}", T::NAME))
+    #[trace_instrument(skip(self))]
+    fn render_text<T: Template + 'static>(
+        &self,
+        template: &T,
+        locale: &Locale,
+    ) -> anyhow::Result<String> {
+        let html = self.render(template, locale)?;
+        html_to_text(T::NAME, &html, locale)
     }
 }
 
+impl TemplateServiceImpl {
+    /// Render the template with the given name using arbitrary json data, e.g.
+    /// to preview templates without going through the actual flows.
+    pub fn render_by_name(
+        &self,
+        name: &str,
+        data: serde_json::Value,
+        locale: &Locale,
+    ) -> anyhow::Result<String> {
+        let context = tera::Context::from_value(data)
+            .with_context(|| format!("Failed to build tera context for template {name}"))?;
+        self.render_context(name, context, locale)
+    }
+
+    /// Render the plain text version of the template with the given name
+    /// using arbitrary json data.
+    pub fn render_text_by_name(
+        &self,
+        name: &str,
+        data: serde_json::Value,
+        locale: &Locale,
+    ) -> anyhow::Result<String> {
+        let html = self.render_by_name(name, data, locale)?;
+        html_to_text(name, &html, locale)
+    }
+
+    fn render_context(
+        &self,
+        name: &str,
+        mut context: tera::Context,
+        locale: &Locale,
+    ) -> anyhow::Result<String> {
+        context.insert("logo_src", &*self.config.logo_src);
+
+        let tera = locale
+            .fallbacks()
+            .filter_map(|locale| self.state.0.get(locale))
+            .find(|tera| tera.get_template_names().any(|n| n == name))
+            .ok_or_else(|| anyhow!("Template {name} does not exist in locale {}", **locale))?;
+
+        tera.render(name, &context)
+            .with_context(|| format!("Failed to render template {name} in locale {}", **locale))
+    }
+}
+
+fn html_to_text(name: &str, html: &str, locale: &Locale) -> anyhow::Result<String> {
+    html2text::from_read(html.as_bytes(), TEXT_WIDTH).with_context(|| {
+        format!(
+            "Failed to convert template {name} in locale {} to plain text",
+            **locale
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
+    use academy_models::invoice::{InvoiceKind, InvoiceVatTreatment};
     use academy_templates_contracts::{
+        AccountDisabledTemplate, EmailChangedTemplate, EmailTemplate, InvoiceTemplate,
+        MfaDisabledTemplate, MfaEnabledTemplate, NewsletterTemplate, NewsletterTextTemplate,
+        OAuth2LinkCreatedTemplate, OAuth2LinkDeletedTemplate, PasswordChangedTemplate,
         ResetPasswordTemplate, SubscribeNewsletterTemplate, VerifyEmailTemplate,
     };
 
@@ -71,19 +157,277 @@ mod tests {
         test_template(SubscribeNewsletterTemplate {
             code: "code".into(),
             url: "https://bootstrap.academy/".into(),
+            unsubscribe_url: "https://bootstrap.academy/unsubscribe?token=token".into(),
         });
     }
 
-    fn test_template<T: Template + 'static>(template: T) {
+    #[test]
+    fn newsletter() {
+        test_template(NewsletterTemplate {
+            subject: "Subject".into(),
+            content: "<p>Hello World!</p>".into(),
+            unsubscribe_url: "https://bootstrap.academy/unsubscribe?token=token".into(),
+        });
+    }
+
+    #[test]
+    fn newsletter_text() {
+        test_template(NewsletterTextTemplate {
+            content: "Hello World!".into(),
+            unsubscribe_url: "https://bootstrap.academy/unsubscribe?token=token".into(),
+        });
+    }
+
+    #[test]
+    fn password_changed() {
+        test_template(PasswordChangedTemplate {
+            recovery_url: "https://bootstrap.academy/".into(),
+        });
+    }
+
+    #[test]
+    fn email_changed() {
+        test_template(EmailChangedTemplate {
+            new_email: "foo@example.com".into(),
+            recovery_url: "https://bootstrap.academy/".into(),
+        });
+    }
+
+    #[test]
+    fn account_disabled() {
+        test_template(AccountDisabledTemplate {
+            recovery_url: "https://bootstrap.academy/".into(),
+        });
+    }
+
+    #[test]
+    fn mfa_enabled() {
+        test_template(MfaEnabledTemplate {
+            recovery_url: "https://bootstrap.academy/".into(),
+        });
+    }
+
+    #[test]
+    fn mfa_disabled() {
+        test_template(MfaDisabledTemplate {
+            recovery_url: "https://bootstrap.academy/".into(),
+        });
+    }
+
+    #[test]
+    fn oauth2_link_created() {
+        test_template(OAuth2LinkCreatedTemplate {
+            provider_name: "GitHub".into(),
+            remote_user_name: "foo".into(),
+            recovery_url: "https://bootstrap.academy/".into(),
+        });
+    }
+
+    #[test]
+    fn oauth2_link_deleted() {
+        test_template(OAuth2LinkDeletedTemplate {
+            provider_name: "GitHub".into(),
+            remote_user_name: "foo".into(),
+            recovery_url: "https://bootstrap.academy/".into(),
+        });
+    }
+
+    #[test]
+    fn invoice() {
         // Arrange
-        let sut = TemplateServiceImpl {
-            state: Default::default(),
+        let template = InvoiceTemplate {
+            kind: InvoiceKind::Purchase,
+            number: "BA-2026-000042".into(),
+            date: "21.10.2026".into(),
+            issuer: vec!["Bootstrap Academy GmbH".into(), "Berlin".into()],
+            issuer_vat_id: Some("DE0123456789".into()),
+            recipient: vec!["Max Mustermann".into(), "Österreich".into()],
+            recipient_vat_id: Some("ATU12345678".into()),
+            description: "1000 Coins".into(),
+            net: "9,50 €".into(),
+            vat: "0,00 €".into(),
+            vat_rate: "0".into(),
+            gross: "9,50 €".into(),
+            vat_treatment: InvoiceVatTreatment::ReverseCharge,
+        };
+
+        let sut = make_sut();
+
+        // Act
+        let result = sut.render(&template, &make_locale("de"));
+
+        // Assert
+        let result = result.unwrap();
+        assert!(result.starts_with("Bootstrap Academy GmbH\nBerlin\nUSt-IdNr.: DE0123456789\n"));
+        assert!(result.contains("\nRECHNUNG\n"));
+        assert!(result.contains("Nummer:           BA-2026-000042\n"));
+        assert!(result.contains("Umsatzsteuer:     0,00 € (0 %)\n"));
+        assert!(result.contains("(Reverse Charge)"));
+        assert!(!result.contains('<'));
+
+        for kind in [InvoiceKind::Purchase, InvoiceKind::Payout] {
+            for vat_treatment in [
+                InvoiceVatTreatment::Standard,
+                InvoiceVatTreatment::ReverseCharge,
+                InvoiceVatTreatment::NotTaxable,
+            ] {
+                test_template(InvoiceTemplate {
+                    kind,
+                    vat_treatment,
+                    issuer_vat_id: None,
+                    recipient_vat_id: None,
+                    ..template.clone()
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn fallback() {
+        // Arrange
+        let template = VerifyEmailTemplate {
+            code: "code".into(),
+            url: "https://bootstrap.academy/".into(),
+        };
+
+        let sut = make_sut();
+
+        for (locale, expected) in [("de-AT", "de"), ("en-US", "en"), ("fr", "en")] {
+            let expected = sut.render(&template, &make_locale(expected)).unwrap();
+
+            // Act
+            let result = sut.render(&template, &make_locale(locale));
+
+            // Assert
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn logo_src() {
+        // Arrange
+        let template = VerifyEmailTemplate {
+            code: "code".into(),
+            url: "https://bootstrap.academy/".into(),
+        };
+
+        let sut = make_sut();
+
+        // Act
+        let result = sut.render(&template, &make_locale("en"));
+
+        // Assert
+        assert!(result.unwrap().contains(r#"<img src="cid:logo""#));
+    }
+
+    #[test]
+    fn render_text() {
+        // Arrange
+        let template = VerifyEmailTemplate {
+            code: "the verification code".into(),
+            url: "https://bootstrap.academy/auth/verify-account".into(),
+        };
+
+        let sut = make_sut();
+
+        for locale in LOCALES {
+            // Act
+            let result = sut.render_text(&template, &make_locale(locale));
+
+            // Assert
+            let result = result.unwrap();
+            assert!(!result.contains('<'));
+            assert!(result.contains("the verification code"));
+            assert!(result.contains("https://bootstrap.academy/auth/verify-account"));
+        }
+    }
+
+    #[test]
+    fn render_by_name() {
+        // Arrange
+        let template = VerifyEmailTemplate {
+            code: "code".into(),
+            url: "https://bootstrap.academy/".into(),
         };
+        let data = serde_json::to_value(&template).unwrap();
+
+        let sut = make_sut();
+
+        for locale in LOCALES {
+            let locale = make_locale(locale);
+            let expected = sut.render(&template, &locale).unwrap();
+
+            // Act
+            let result = sut.render_by_name(VerifyEmailTemplate::NAME, data.clone(), &locale);
+
+            // Assert
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn render_by_name_unknown() {
+        // Arrange
+        let sut = make_sut();
 
         // Act
-        let result = sut.render(&template);
+        let result = sut.render_by_name("Foo", serde_json::json!({}), &make_locale("en"));
 
         // Assert
-        result.unwrap();
+        result.unwrap_err();
+    }
+
+    #[test]
+    fn subjects() {
+        for locale in LOCALES {
+            let locale = make_locale(locale);
+            assert_ne!(ResetPasswordTemplate::subject(&locale), "");
+            assert_ne!(VerifyEmailTemplate::subject(&locale), "");
+            assert_ne!(SubscribeNewsletterTemplate::subject(&locale), "");
+            assert_ne!(PasswordChangedTemplate::subject(&locale), "");
+            assert_ne!(EmailChangedTemplate::subject(&locale), "");
+            assert_ne!(AccountDisabledTemplate::subject(&locale), "");
+            assert_ne!(MfaEnabledTemplate::subject(&locale), "");
+            assert_ne!(MfaDisabledTemplate::subject(&locale), "");
+            assert_ne!(OAuth2LinkCreatedTemplate::subject(&locale), "");
+            assert_ne!(OAuth2LinkDeletedTemplate::subject(&locale), "");
+        }
+
+        assert_eq!(
+            VerifyEmailTemplate::subject(&make_locale("de-CH")),
+            VerifyEmailTemplate::subject(&make_locale("de"))
+        );
+        assert_eq!(
+            VerifyEmailTemplate::subject(&make_locale("fr")),
+            VerifyEmailTemplate::subject(&make_locale("en"))
+        );
+    }
+
+    const LOCALES: [&str; 2] = ["de", "en"];
+
+    fn make_sut() -> TemplateServiceImpl {
+        TemplateServiceImpl {
+            config: TemplateServiceConfig {
+                logo_src: "cid:logo".into(),
+            },
+            state: Default::default(),
+        }
+    }
+
+    fn make_locale(locale: &str) -> Locale {
+        locale.try_into().unwrap()
+    }
+
+    fn test_template<T: Template + 'static>(template: T) {
+        // Arrange
+        let sut = make_sut();
+
+        for locale in LOCALES {
+            // Act
+            let result = sut.render(&template, &make_locale(locale));
+
+            // Assert
+            result.unwrap();
+        }
     }
 }
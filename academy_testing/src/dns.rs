@@ -0,0 +1,82 @@
+use std::net::IpAddr;
+
+use anyhow::Context;
+use tokio::net::UdpSocket;
+use tracing::{info, warn};
+
+const TYPE_MX: u16 = 15;
+
+pub async fn start_server(host: IpAddr, port: u16) -> anyhow::Result<()> {
+    info!("Starting dns testing server on udp://{host}:{port}");
+    info!("Domains ending with .invalid do not exist.");
+    info!("Domains starting with no-mx. do not have any MX records.");
+    info!("All other domains have exactly one MX record.");
+
+    let socket = UdpSocket::bind((host, port))
+        .await
+        .with_context(|| format!("Failed to bind to {host}:{port}"))?;
+
+    let mut buf = [0; 512];
+    loop {
+        let (n, addr) = socket
+            .recv_from(&mut buf)
+            .await
+            .context("Failed to receive query")?;
+
+        let Some(response) = handle_query(&buf[..n]) else {
+            warn!("Received invalid query from {addr}");
+            continue;
+        };
+
+        socket
+            .send_to(&response, addr)
+            .await
+            .context("Failed to send response")?;
+    }
+}
+
+fn handle_query(query: &[u8]) -> Option<Vec<u8>> {
+    let header = query.get(..12)?;
+
+    // parse the name of the first question
+    let mut labels = Vec::new();
+    let mut pos = 12;
+    loop {
+        let len = *query.get(pos)? as usize;
+        pos += 1;
+        if len == 0 {
+            break;
+        }
+        labels.push(std::str::from_utf8(query.get(pos..pos + len)?).ok()?);
+        pos += len;
+    }
+    let question = query.get(12..pos + 4)?;
+    let qtype = u16::from_be_bytes([question[question.len() - 4], question[question.len() - 3]]);
+
+    let domain = labels.join(".").to_lowercase();
+    let (rcode, answer) = if domain == "invalid" || domain.ends_with(".invalid") {
+        (3, false)
+    } else {
+        (0, qtype == TYPE_MX && !domain.starts_with("no-mx."))
+    };
+
+    let mut response = Vec::with_capacity(512);
+    response.extend(&header[..2]); // id
+    response.extend((0x8180u16 | rcode).to_be_bytes()); // response, recursion desired/available
+    response.extend(1u16.to_be_bytes()); // qdcount
+    response.extend(u16::from(answer).to_be_bytes()); // ancount
+    response.extend([0; 4]); // nscount, arcount
+    response.extend(question);
+
+    if answer {
+        response.extend([0xc0, 0x0c]); // pointer to the question name
+        response.extend(TYPE_MX.to_be_bytes());
+        response.extend(1u16.to_be_bytes()); // class IN
+        response.extend(3600u32.to_be_bytes()); // ttl
+        response.extend(9u16.to_be_bytes()); // rdlength
+        response.extend(10u16.to_be_bytes()); // preference
+        response.extend(b"\x04mail\xc0\x0c"); // exchange
+    }
+
+    Some(response)
+}
@@ -1,6 +1,6 @@
 use std::net::IpAddr;
 
-use academy_testing::{internal, oauth2, recaptcha, vat};
+use academy_testing::{dns, hcaptcha, oauth2, payment, recaptcha, turnstile, vat};
 use clap::{CommandFactory, Parser, Subcommand};
 use clap_complete::Shell;
 use url::Url;
@@ -20,6 +20,12 @@ async fn main() -> anyhow::Result<()> {
         Command::Recaptcha { host, port, secret } => {
             recaptcha::start_server(host, port, secret).await?
         }
+        Command::Hcaptcha { host, port, secret } => {
+            hcaptcha::start_server(host, port, secret).await?
+        }
+        Command::Turnstile { host, port, secret } => {
+            turnstile::start_server(host, port, secret).await?
+        }
         Command::OAuth2 {
             host,
             port,
@@ -28,7 +34,14 @@ async fn main() -> anyhow::Result<()> {
             redirect_url,
         } => oauth2::start_server(host, port, client_id, client_secret, redirect_url).await?,
         Command::Vat { host, port } => vat::start_server(host, port).await?,
-        Command::Internal { host, port } => internal::start_server(host, port).await?,
+        Command::Payment {
+            host,
+            port,
+            secret_key,
+            webhook_secret,
+            webhook_url,
+        } => payment::start_server(host, port, secret_key, webhook_secret, webhook_url).await?,
+        Command::Dns { host, port } => dns::start_server(host, port).await?,
         Command::Completion { shell } => {
             clap_complete::generate(
                 shell,
@@ -81,12 +94,43 @@ enum Command {
         #[arg(long, default_value = "8003")]
         port: u16,
     },
-    /// Start the internal api testing server
-    Internal {
+    /// Start the payment provider testing server
+    Payment {
         #[arg(long, default_value = "127.0.0.1")]
         host: IpAddr,
         #[arg(long, default_value = "8004")]
         port: u16,
+        #[arg(long, default_value = "test-secret")]
+        secret_key: String,
+        #[arg(long, default_value = "test-webhook-secret")]
+        webhook_secret: String,
+        #[arg(long, default_value = "http://127.0.0.1:8000/auth/coins/webhook")]
+        webhook_url: Url,
+    },
+    /// Start the dns testing server
+    Dns {
+        #[arg(long, default_value = "127.0.0.1")]
+        host: IpAddr,
+        #[arg(long, default_value = "8005")]
+        port: u16,
+    },
+    /// Start the hcaptcha testing server
+    Hcaptcha {
+        #[arg(long, default_value = "127.0.0.1")]
+        host: IpAddr,
+        #[arg(long, default_value = "8006")]
+        port: u16,
+        #[arg(long, default_value = "test-secret")]
+        secret: String,
+    },
+    /// Start the turnstile testing server
+    Turnstile {
+        #[arg(long, default_value = "127.0.0.1")]
+        host: IpAddr,
+        #[arg(long, default_value = "8007")]
+        port: u16,
+        #[arg(long, default_value = "test-secret")]
+        secret: String,
     },
     /// Generate shell completions
     Completion {
@@ -0,0 +1,160 @@
+use std::{collections::HashMap, net::IpAddr, sync::Arc};
+
+use anyhow::Context;
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
+    routing, Json, Router,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tokio::{net::TcpListener, sync::Mutex};
+use tracing::{info, warn};
+use url::Url;
+use uuid::Uuid;
+
+const CHECKOUTS_ROUTE: &str = "/payment/v1/checkouts";
+const CHECKOUT_ROUTE: &str = "/payment/checkout/:id";
+
+pub async fn start_server(
+    host: IpAddr,
+    port: u16,
+    secret_key: String,
+    webhook_secret: String,
+    webhook_url: Url,
+) -> anyhow::Result<()> {
+    info!("Starting payment testing server on {host}:{port}");
+    info!("API base url: http://{host}:{port}/payment/v1/");
+    info!("Secret key: {secret_key:?}");
+    info!("Webhook secret: {webhook_secret:?}");
+    info!("Webhook url: {webhook_url}");
+    info!("Open the checkout url to pay, append ?status=failed or ?status=cancelled to simulate unsuccessful payments.");
+
+    let router = Router::new()
+        .route(CHECKOUTS_ROUTE, routing::post(create_checkout))
+        .route(CHECKOUT_ROUTE, routing::get(pay))
+        .with_state(Arc::new(StateInner {
+            base_url: format!("http://{host}:{port}"),
+            secret_key,
+            webhook_secret,
+            webhook_url,
+            http: reqwest::Client::new(),
+            checkouts: Default::default(),
+        }));
+
+    let listener = TcpListener::bind((host, port))
+        .await
+        .with_context(|| format!("Failed to bind to {host}:{port}"))?;
+    axum::serve(listener, router)
+        .await
+        .context("Failed to start HTTP server")
+}
+
+type State = axum::extract::State<Arc<StateInner>>;
+struct StateInner {
+    base_url: String,
+    secret_key: String,
+    webhook_secret: String,
+    webhook_url: Url,
+    http: reqwest::Client,
+    checkouts: Mutex<HashMap<String, Checkout>>,
+}
+
+#[derive(Deserialize)]
+struct Checkout {
+    success_url: Url,
+    cancel_url: Url,
+}
+
+#[derive(Serialize)]
+struct CreateCheckoutResponse {
+    id: String,
+    url: String,
+}
+
+async fn create_checkout(
+    state: State,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(checkout): Json<Checkout>,
+) -> Response {
+    if auth.token() != state.secret_key {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let id = format!("cs_{}", Uuid::new_v4().simple());
+    state.checkouts.lock().await.insert(id.clone(), checkout);
+
+    Json(CreateCheckoutResponse {
+        url: format!("{}/payment/checkout/{id}", state.base_url),
+        id,
+    })
+    .into_response()
+}
+
+#[derive(Deserialize)]
+struct PayQuery {
+    #[serde(default)]
+    status: WebhookEventStatus,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum WebhookEventStatus {
+    #[default]
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Serialize)]
+struct WebhookEvent<'a> {
+    checkout_id: &'a str,
+    status: WebhookEventStatus,
+}
+
+async fn pay(
+    state: State,
+    Path(id): Path<String>,
+    Query(PayQuery { status }): Query<PayQuery>,
+) -> Response {
+    let Some(checkout) = state.checkouts.lock().await.remove(&id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let payload = serde_json::to_vec(&WebhookEvent {
+        checkout_id: &id,
+        status,
+    })
+    .unwrap();
+    let mut mac = Hmac::<Sha256>::new_from_slice(state.webhook_secret.as_bytes()).unwrap();
+    mac.update(&payload);
+    let signature = hex::encode(mac.finalize().into_bytes());
+
+    match state
+        .http
+        .post(state.webhook_url.clone())
+        .header("Payment-Signature", signature)
+        .header("Content-Type", "application/json")
+        .body(payload)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+    {
+        Ok(_) => info!(id, ?status, "webhook delivered"),
+        Err(err) => warn!(id, ?status, %err, "failed to deliver webhook"),
+    }
+
+    match status {
+        WebhookEventStatus::Succeeded => Redirect::to(checkout.success_url.as_str()),
+        WebhookEventStatus::Failed | WebhookEventStatus::Cancelled => {
+            Redirect::to(checkout.cancel_url.as_str())
+        }
+    }
+    .into_response()
+}
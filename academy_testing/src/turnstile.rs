@@ -0,0 +1,47 @@
+use std::{net::IpAddr, sync::Arc};
+
+use anyhow::Context;
+use axum::{extract::State, routing, Form, Json, Router};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+use tracing::info;
+
+const SITEVERIFY_ROUTE: &str = "/turnstile/v0/siteverify";
+
+pub async fn start_server(host: IpAddr, port: u16, secret: String) -> anyhow::Result<()> {
+    info!("Starting turnstile testing server on {host}:{port}");
+    info!("Turnstile siteverify endpoint: http://{host}:{port}{SITEVERIFY_ROUTE}");
+    info!("Secret: {secret:?}");
+    info!("The only valid turnstile response is \"success\"");
+
+    let router = Router::new()
+        .route(SITEVERIFY_ROUTE, routing::post(siteverify))
+        .with_state(secret.into());
+
+    let listener = TcpListener::bind((host, port))
+        .await
+        .with_context(|| format!("Failed to bind to {host}:{port}"))?;
+    axum::serve(listener, router)
+        .await
+        .context("Failed to start HTTP server")
+}
+
+#[derive(Deserialize)]
+struct SiteverifyRequest {
+    secret: String,
+    response: String,
+}
+
+#[derive(Serialize)]
+struct SiteverifyResponse {
+    success: bool,
+}
+
+async fn siteverify(
+    state: State<Arc<str>>,
+    Form(SiteverifyRequest { secret, response }): Form<SiteverifyRequest>,
+) -> Json<SiteverifyResponse> {
+    Json(SiteverifyResponse {
+        success: *secret == **state && response == "success",
+    })
+}
@@ -2,11 +2,11 @@ use std::net::IpAddr;
 
 use anyhow::Context;
 use axum::{
-    extract::Path,
+    extract::{Path, Query},
     response::{IntoResponse, Response},
     routing, Json, Router,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tokio::net::TcpListener;
 use tracing::info;
 
@@ -16,6 +16,7 @@ pub async fn start_server(host: IpAddr, port: u16) -> anyhow::Result<()> {
     info!("Starting vat api testing server on {host}:{port}");
     info!("Validate endpoint: http://{host}:{port}{VALIDATE_ROUTE}");
     info!("The only valid vat id is DE0123456789, all other vat ids are rejected.");
+    info!("For DE9999999999 the service of the member state is reported as unavailable.");
 
     let router = Router::new().route(VALIDATE_ROUTE, routing::get(validate));
 
@@ -27,13 +28,45 @@ pub async fn start_server(host: IpAddr, port: u16) -> anyhow::Result<()> {
         .context("Failed to start HTTP server")
 }
 
-async fn validate(Path((country, id)): Path<(String, String)>) -> Response {
+async fn validate(
+    Path((country, id)): Path<(String, String)>,
+    Query(requester): Query<ValidateQuery>,
+) -> Response {
+    if country == "DE" && id == "9999999999" {
+        return Json(ValidateResponse {
+            is_valid: false,
+            user_error: "MS_UNAVAILABLE",
+            request_identifier: String::new(),
+        })
+        .into_response();
+    }
+
     let is_valid = country == "DE" && id == "0123456789";
-    Json(ValidateResponse { is_valid }).into_response()
+    let request_identifier = if is_valid && requester.requester_number.is_some() {
+        "WAPIAAAAZ0123456".into()
+    } else {
+        String::new()
+    };
+    Json(ValidateResponse {
+        is_valid,
+        user_error: if is_valid { "VALID" } else { "INVALID" },
+        request_identifier,
+    })
+    .into_response()
+}
+
+#[derive(Deserialize)]
+struct ValidateQuery {
+    #[serde(rename = "requesterNumber")]
+    requester_number: Option<String>,
 }
 
 #[derive(Serialize)]
 struct ValidateResponse {
     #[serde(rename = "isValid")]
     is_valid: bool,
+    #[serde(rename = "userError")]
+    user_error: &'static str,
+    #[serde(rename = "requestIdentifier")]
+    request_identifier: String,
 }
@@ -44,6 +44,14 @@
     ${testing}/bin/academy-testing recaptcha
   '';
 
+  processes.testing-hcaptcha.exec = ''
+    ${testing}/bin/academy-testing hcaptcha
+  '';
+
+  processes.testing-turnstile.exec = ''
+    ${testing}/bin/academy-testing turnstile
+  '';
+
   processes.testing-oauth2.exec = ''
     ${testing}/bin/academy-testing oauth2
   '';
@@ -52,8 +60,12 @@
     ${testing}/bin/academy-testing vat
   '';
 
-  processes.testing-internal.exec = ''
-    ${testing}/bin/academy-testing internal
+  processes.testing-dns.exec = ''
+    ${testing}/bin/academy-testing dns
+  '';
+
+  processes.testing-payment.exec = ''
+    ${testing}/bin/academy-testing payment
   '';
 
   env = {
@@ -39,7 +39,7 @@ in {
       default = {};
     };
 
-    tasks = lib.genAttrs ["prune-database"] (task: {
+    tasks = lib.genAttrs ["prune-database" "deliver-emails" "send-newsletters" "revalidate-vat-ids" "create-invoices"] (task: {
       schedule = lib.mkOption {
         type = lib.types.either lib.types.str (lib.types.listOf lib.types.str);
         default = [];
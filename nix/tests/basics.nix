@@ -41,6 +41,9 @@ testers.runNixOSTest {
     machine.wait_until_succeeds("test -e /var/mail/root/new/*", 20)
     machine.succeed("grep 'Email deliverability seems to be working!' /var/mail/root/new/*")
 
+    rendered = machine.succeed("""academy email render VerifyEmailTemplate --text --data '{"code": "ABCD-EFGH", "url": "https://example.com/verify"}'""")
+    assert "ABCD-EFGH" in rendered
+
     status = json.loads(machine.succeed("curl -s http://127.0.0.1:8000/health"))
     assert status == {
       "database": True,
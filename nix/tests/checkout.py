@@ -0,0 +1,110 @@
+import hashlib
+import hmac
+import json
+import os
+
+import httpx
+from utils import c, create_account, discard_auth, save_auth
+
+packages = [
+    {"id": "small", "coins": 500, "price": 500},
+    {"id": "medium", "coins": 1000, "price": 950},
+    {"id": "large", "coins": 5000, "price": 4500},
+]
+
+
+def send_webhook(payload, secret="test-webhook-secret"):
+    payload = json.dumps(payload).encode()
+    signature = hmac.new(secret.encode(), payload, hashlib.sha256).hexdigest()
+    return c.post("/auth/coins/webhook", content=payload, headers={"Payment-Signature": signature})
+
+
+def pay(redirect_url, status=None):
+    resp = httpx.get(redirect_url, params={"status": status} if status else None)
+    assert resp.status_code == 303
+    return resp.headers["Location"]
+
+
+resp = c.get("/auth/coins/packages")
+assert resp.status_code == 200
+assert resp.json() == packages
+
+# users without invoice info cannot buy coins
+create_account("checkout", "checkout@example.com", "checkout")
+resp = c.post("/auth/users/me/coins/orders", json={"package": "small"})
+assert resp.status_code == 403
+assert resp.json() == {"detail": "Cannot buy coins"}
+
+os.system("academy admin user create --verified buyer buyer@example.com buyer")
+resp = c.post("/auth/sessions", json={"name_or_email": "buyer", "password": "buyer"})
+assert resp.status_code == 200
+save_auth(resp.json())
+
+resp = c.patch("/auth/users/me", json={"business": False, "country": "DE"})
+assert resp.status_code == 200
+assert resp.json()["can_buy_coins"] is True
+
+resp = c.post("/auth/users/me/coins/orders", json={"package": "huge"})
+assert resp.status_code == 404
+assert resp.json() == {"detail": "Package not found"}
+
+# successful payment
+resp = c.post("/auth/users/me/coins/orders", json={"package": "medium"})
+assert resp.status_code == 200
+order = resp.json()["order"]
+redirect_url = resp.json()["redirect_url"]
+assert order["package"] == "medium"
+assert order["coins"] == 1000
+assert order["price"] == 950
+assert order["status"] == "pending"
+
+resp = c.get(f"/auth/users/me/coins/orders/{order['id']}")
+assert resp.status_code == 200
+assert resp.json() == order
+
+assert pay(redirect_url) == f"https://bootstrap.academy/shop/orders/{order['id']}"
+
+resp = c.get(f"/auth/users/me/coins/orders/{order['id']}")
+assert resp.status_code == 200
+assert resp.json()["status"] == "completed"
+assert c.get("/auth/users/me/coins").json() == {"coins": 1000, "withheld_coins": 0}
+
+resp = c.get("/auth/users/me/coins/transactions")
+assert resp.json()["total"] == 1
+assert [(t["kind"], t["coins"]) for t in resp.json()["transactions"]] == [("purchase", 1000)]
+
+# cancelled payment
+resp = c.post("/auth/users/me/coins/orders", json={"package": "small"})
+assert resp.status_code == 200
+cancelled = resp.json()["order"]
+assert pay(resp.json()["redirect_url"], "cancelled") == f"https://bootstrap.academy/shop?cancelled={cancelled['id']}"
+assert c.get(f"/auth/users/me/coins/orders/{cancelled['id']}").json()["status"] == "cancelled"
+assert c.get("/auth/users/me/coins").json() == {"coins": 1000, "withheld_coins": 0}
+
+# webhooks are idempotent
+payment_id = redirect_url.rsplit("/", 1)[1]
+resp = send_webhook({"checkout_id": payment_id, "status": "succeeded"})
+assert resp.status_code == 200
+resp = send_webhook({"checkout_id": payment_id, "status": "failed"})
+assert resp.status_code == 200
+assert c.get(f"/auth/users/me/coins/orders/{order['id']}").json()["status"] == "completed"
+assert c.get("/auth/users/me/coins").json() == {"coins": 1000, "withheld_coins": 0}
+
+resp = send_webhook({"checkout_id": payment_id, "status": "succeeded"}, "invalid")
+assert resp.status_code == 401
+assert resp.json() == {"detail": "Invalid signature"}
+
+resp = c.get("/auth/users/me/coins/orders")
+assert resp.status_code == 200
+assert resp.json()["total"] == 2
+assert [o["id"] for o in resp.json()["orders"]] == [cancelled["id"], order["id"]]
+
+resp = c.get("/auth/users/me/coins/orders/3b5cf9a1-1c8e-4d52-8a43-5e0e9f2d7c61")
+assert resp.status_code == 404
+assert resp.json() == {"detail": "Order not found"}
+
+discard_auth()
+resp = c.get("/auth/coins/packages")
+assert resp.status_code == 200
+resp = c.get("/auth/users/me/coins/orders")
+assert resp.status_code == 401
@@ -0,0 +1,88 @@
+import subprocess
+
+from utils import c, create_account, make_client
+
+login = create_account("coins", "coins@example.com", "coins")
+user_id = login["user"]["id"]
+
+resp = c.get("/auth/users/me/coins")
+assert resp.status_code == 200
+assert resp.json() == {"coins": 0, "withheld_coins": 0}
+
+resp = c.get("/auth/users/a8d95e0f-71ae-4c49-995e-695b7c93848c/coins")
+assert resp.status_code == 403
+
+status, jwt = subprocess.getstatusoutput('academy jwt sign \'{"aud":"auth"}\'')
+assert status == 0
+internal = make_client()
+internal.headers["Authorization"] = jwt.strip()
+
+# earned coins are withheld until the invoice info is complete
+resp = internal.post(f"/auth/_internal/coins/{user_id}/credit", json={"kind": "earn", "coins": 42, "description": "a"})
+assert resp.status_code == 200
+assert resp.json()["transaction"]["kind"] == "withheld"
+assert resp.json()["transaction"]["coins"] == 42
+assert resp.json()["transaction"]["description"] == "a"
+assert resp.json()["balance"] == {"coins": 0, "withheld_coins": 42}
+
+resp = internal.post(f"/auth/_internal/coins/{user_id}/credit", json={"kind": "refund", "coins": 10})
+assert resp.status_code == 200
+assert resp.json()["transaction"]["kind"] == "refund"
+assert resp.json()["balance"] == {"coins": 10, "withheld_coins": 42}
+
+resp = internal.post(f"/auth/_internal/coins/{user_id}/debit", json={"coins": 11})
+assert resp.status_code == 412
+assert resp.json() == {"detail": "Not enough coins"}
+
+resp = internal.post(f"/auth/_internal/coins/{user_id}/debit", json={"coins": 7, "description": "b"})
+assert resp.status_code == 200
+assert resp.json()["transaction"]["kind"] == "spend"
+assert resp.json()["balance"] == {"coins": 3, "withheld_coins": 42}
+
+resp = internal.post(f"/auth/_internal/coins/{user_id}/debit", json={"coins": 0})
+assert resp.status_code == 422
+
+resp = internal.get(f"/auth/_internal/coins/{user_id}")
+assert resp.status_code == 200
+assert resp.json() == {"coins": 3, "withheld_coins": 42}
+assert c.get("/auth/users/me/coins").json() == resp.json()
+
+resp = c.get("/auth/users/me/coins/transactions", params={"limit": 2})
+assert resp.status_code == 200
+assert resp.json()["total"] == 3
+assert [(t["kind"], t["coins"]) for t in resp.json()["transactions"]] == [("spend", 7), ("refund", 10)]
+
+# withheld coins are released as soon as the invoice info is complete
+resp = c.patch(
+    "/auth/users/me",
+    json={
+        "business": False,
+        "first_name": "a",
+        "last_name": "b",
+        "street": "c",
+        "zip_code": "12345",
+        "city": "e",
+        "country": "DE",
+    },
+)
+assert resp.status_code == 200
+assert resp.json()["can_receive_coins"] is True
+assert c.get("/auth/users/me/coins").json() == {"coins": 45, "withheld_coins": 0}
+
+resp = c.get("/auth/users/me/coins/transactions", params={"limit": 1})
+assert resp.json()["total"] == 4
+assert [(t["kind"], t["coins"]) for t in resp.json()["transactions"]] == [("released", 42)]
+
+resp = internal.post(f"/auth/_internal/coins/{user_id}/credit", json={"kind": "earn", "coins": 5})
+assert resp.status_code == 200
+assert resp.json()["transaction"]["kind"] == "earn"
+assert resp.json()["balance"] == {"coins": 50, "withheld_coins": 0}
+
+resp = internal.get("/auth/_internal/coins/85bae8d0-5419-48ba-9018-88df147a0eb2")
+assert resp.status_code == 404
+assert resp.json() == {"detail": "User not found"}
+
+internal.headers["Authorization"] = "blubb"
+resp = internal.get(f"/auth/_internal/coins/{user_id}")
+assert resp.status_code == 401
+assert resp.json() == {"detail": "Invalid token"}
@@ -15,6 +15,16 @@ testers.runNixOSTest {
       recaptcha.enable = false;
     };
   };
+  nodes.proof_of_work = {
+    imports = [defaultModule];
+    services.academy.backend.settings = {
+      recaptcha.enable = false;
+      proof_of_work = {
+        enable = true;
+        difficulty = 8;
+      };
+    };
+  };
 
   testScript = ''
     import json
@@ -24,9 +34,20 @@ testers.runNixOSTest {
     default.wait_for_unit("academy-backend.service")
     default.wait_for_open_port(8000)
     assert json.loads(default.succeed("curl -s http://127.0.0.1:8000/auth/recaptcha")) == "test-sitekey"
+    assert json.loads(default.succeed("curl -s http://127.0.0.1:8000/auth/captcha")) == {"provider": "recaptcha", "sitekey": "test-sitekey"}
+    assert json.loads(default.succeed("curl -s -X POST http://127.0.0.1:8000/auth/captcha/challenge")) == {"detail": "Proof of work disabled"}
 
     no_recaptcha.wait_for_unit("academy-backend.service")
     no_recaptcha.wait_for_open_port(8000)
     assert json.loads(no_recaptcha.succeed("curl -s http://127.0.0.1:8000/auth/recaptcha")) is None
+    assert json.loads(no_recaptcha.succeed("curl -s http://127.0.0.1:8000/auth/captcha")) == {"provider": "disabled"}
+
+    proof_of_work.wait_for_unit("academy-backend.service")
+    proof_of_work.wait_for_open_port(8000)
+    assert json.loads(proof_of_work.succeed("curl -s http://127.0.0.1:8000/auth/recaptcha")) is None
+    assert json.loads(proof_of_work.succeed("curl -s http://127.0.0.1:8000/auth/captcha")) == {"provider": "proof_of_work", "difficulty": 8}
+    challenge = json.loads(proof_of_work.succeed("curl -s -X POST http://127.0.0.1:8000/auth/captcha/challenge"))
+    assert challenge["difficulty"] == 8
+    assert len(challenge["challenge"]) == 32
   '';
 }
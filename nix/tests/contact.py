@@ -23,3 +23,7 @@ for resp in ["success-0.3", "failure"]:
     resp = c.post("/auth/contact", json={**msg, "recaptcha_response": resp})
     assert resp.status_code == 412
     assert resp.json() == {"detail": "Recaptcha failed"}
+
+resp = c.post("/auth/contact", json={**msg, "recaptcha_response": "success-0.7", "honeypot": "https://example.com"})
+assert resp.status_code == 403
+assert resp.json() == {"detail": "Message rejected"}
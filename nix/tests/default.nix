@@ -48,12 +48,15 @@
           smtp_url = "smtp://127.0.0.1:25";
           from = "test@bootstrap.academy";
         };
-        internal.shop_url = "http://127.0.0.1:8004/shop/";
         health = {
           database_cache_ttl = "2s";
           cache_cache_ttl = "2s";
           email_cache_ttl = "2s";
         };
+        user = {
+          avatar_url = "http://127.0.0.1:8000/auth/avatars/";
+          newsletter_unsubscribe_url = "http://127.0.0.1:8000/auth/newsletter/unsubscribe";
+        };
         contact.email = "contact@academy";
         recaptcha = {
           enable = lib.mkDefault true;
@@ -63,6 +66,8 @@
           min_score = 0.5;
         };
         vat.validate_endpoint_override = "http://127.0.0.1:8003/validate/";
+        dns.nameserver_override = "127.0.0.1:8005";
+        payment.api_url = "http://127.0.0.1:8004/payment/v1/";
         oauth2 = {
           enable = true;
           providers = let
@@ -115,11 +120,19 @@
       '';
     };
 
-    systemd.services."academy-testing-internal" = {
+    systemd.services."academy-testing-dns" = {
+      wantedBy = ["academy-backend.service"];
+      before = ["academy-backend.service"];
+      script = ''
+        ${self.packages.${system}.testing.unwrapped}/bin/academy-testing dns
+      '';
+    };
+
+    systemd.services."academy-testing-payment" = {
       wantedBy = ["academy-backend.service"];
       before = ["academy-backend.service"];
       script = ''
-        ${self.packages.${system}.testing.unwrapped}/bin/academy-testing internal
+        ${self.packages.${system}.testing.unwrapped}/bin/academy-testing payment
       '';
     };
 
@@ -135,6 +148,8 @@
       mode = "0400";
       argument = ''
         jwt.secret = "changeme"
+        payment.secret_key = "test-secret"
+        payment.webhook_secret = "test-webhook-secret"
       '';
     };
   };
@@ -29,10 +29,11 @@ FOO = {
     "first_name": "x",
     "last_name": "y",
     "street": "asdf",
-    "zip_code": "1234",
+    "zip_code": "12345",
     "city": "xyz",
-    "country": "asdf",
-    "vat_id": "1234",
+    "country": "DE",
+    "vat_id": "DE0123456789",
+    "vat_id_status": "valid",
     "can_buy_coins": True,
     "can_receive_coins": True,
     "avatar_url": "https://gravatar.com/avatar/321ba197033e81286fedb719d60d4ed5cecaed170733cb4a92013811afc0e3b6",
@@ -0,0 +1,98 @@
+import datetime
+import os
+import subprocess
+
+import httpx
+from utils import c, create_account, make_client, save_auth
+
+year = datetime.datetime.now(datetime.timezone.utc).year
+
+os.system("academy admin user create --verified invoice invoice@example.com invoice")
+resp = c.post("/auth/sessions", json={"name_or_email": "invoice", "password": "invoice"})
+assert resp.status_code == 200
+save_auth(resp.json())
+user_id = resp.json()["user"]["id"]
+
+resp = c.patch(
+    "/auth/users/me",
+    json={
+        "business": False,
+        "first_name": "a",
+        "last_name": "b",
+        "street": "c",
+        "zip_code": "12345",
+        "city": "e",
+        "country": "DE",
+    },
+)
+assert resp.status_code == 200
+assert resp.json()["can_receive_coins"] is True
+
+resp = c.get("/auth/users/me/invoices")
+assert resp.status_code == 200
+assert resp.json() == {"total": 0, "invoices": []}
+
+# an invoice is issued for every completed coin order
+resp = c.post("/auth/users/me/coins/orders", json={"package": "medium"})
+assert resp.status_code == 200
+resp = httpx.get(resp.json()["redirect_url"])
+assert resp.status_code == 303
+
+resp = c.get("/auth/users/me/invoices")
+assert resp.status_code == 200
+assert resp.json()["total"] == 1
+purchase = resp.json()["invoices"][0]
+assert purchase["number"] == f"BA-{year}-000001"
+assert purchase["kind"] == "purchase"
+assert purchase["description"] == "1000 Coins"
+assert (purchase["net"], purchase["vat"], purchase["gross"]) == (798, 152, 950)
+assert purchase["vat_rate"] == 1900
+assert purchase["vat_treatment"] == "standard"
+assert purchase["recipient"]["country"] == "DE"
+
+resp = c.get(f"/auth/users/me/invoices/{purchase['id']}")
+assert resp.status_code == 200
+assert resp.json() == purchase
+
+resp = c.get(f"/auth/users/me/invoices/{purchase['id']}/pdf")
+assert resp.status_code == 200
+assert resp.headers["Content-Type"] == "application/pdf"
+assert resp.headers["Content-Disposition"] == f'attachment; filename="BA-{year}-000001.pdf"'
+assert resp.content.startswith(b"%PDF")
+
+resp = c.get("/auth/users/me/invoices/3b5cf9a1-1c8e-4d52-8a43-5e0e9f2d7c61")
+assert resp.status_code == 404
+assert resp.json() == {"detail": "Invoice not found"}
+
+# self-billed invoices for payouts
+status, jwt = subprocess.getstatusoutput('academy jwt sign \'{"aud":"auth"}\'')
+assert status == 0
+internal = make_client()
+internal.headers["Authorization"] = jwt.strip()
+
+resp = internal.post(f"/auth/_internal/invoices/{user_id}/payout", json={"description": "Payout", "amount": 2000})
+assert resp.status_code == 200
+payout = resp.json()
+assert payout["number"] == f"BA-{year}-000002"
+assert payout["kind"] == "payout"
+assert (payout["net"], payout["vat"], payout["gross"]) == (2000, 0, 2000)
+assert payout["vat_treatment"] == "not_taxable"
+
+resp = c.get("/auth/users/me/invoices")
+assert resp.json()["total"] == 2
+assert [i["id"] for i in resp.json()["invoices"]] == [payout["id"], purchase["id"]]
+
+resp = internal.post(
+    "/auth/_internal/invoices/3b5cf9a1-1c8e-4d52-8a43-5e0e9f2d7c61/payout", json={"description": "Payout", "amount": 1}
+)
+assert resp.status_code == 404
+assert resp.json() == {"detail": "User not found"}
+
+other = create_account("other", "other@example.com", "other", make_client())
+resp = internal.post(f"/auth/_internal/invoices/{other['user']['id']}/payout", json={"description": "Payout", "amount": 1})
+assert resp.status_code == 403
+assert resp.json() == {"detail": "Invoice info incomplete"}
+
+# users can only access their own invoices
+resp = c.get(f"/auth/users/{other['user']['id']}/invoices")
+assert resp.status_code == 403
@@ -1,7 +1,9 @@
 import os
+import time
+from pathlib import Path
 
 import pyotp
-from utils import c, create_account, discard_auth, get_self, save_auth
+from utils import c, create_account, discard_auth, fetch_mail, get_self, save_auth
 
 login = create_account("a", "a@a", "a")
 assert login["user"]["mfa_enabled"] is False
@@ -107,6 +109,10 @@ assert resp.status_code == 412
 assert resp.json() == {"detail": "Invalid code"}
 
 # recovery code
+time.sleep(10)  # wait for notifications about previous changes to be delivered
+for mail in Path("/var/mail/root/new").glob("*"):
+    mail.unlink()
+
 resp = c.post(
     "/auth/sessions",
     json={"name_or_email": "a", "password": "a", "recovery_code": recovery_code, "recaptcha_response": "success-1.0"},
@@ -116,3 +122,7 @@ login = resp.json()
 assert login["user"]["mfa_enabled"] is False
 save_auth(login)
 assert get_self()["mfa_enabled"] is False
+
+mail = fetch_mail()
+assert mail["X-Original-To"] == "a@a"
+assert mail["Subject"] == "Zwei-Faktor-Authentifizierung deaktiviert - Bootstrap Academy"
@@ -0,0 +1,157 @@
+import os
+
+from utils import c, create_account, make_client, save_auth
+
+os.system("academy admin user create --admin admin admin@admin admin")
+resp = c.post("/auth/sessions", json={"name_or_email": "admin", "password": "admin"})
+assert resp.status_code == 200
+save_auth(resp.json())
+
+resp = c.get("/auth/skills")
+assert resp.status_code == 200
+assert resp.json() == {"skills": [], "prerequisites": []}
+
+resp = c.post("/auth/skills", json={"title": "Python", "description": ""})
+assert resp.status_code == 200
+python = resp.json()
+
+resp = c.post("/auth/skills", json={"title": "Django", "description": "Web framework"})
+assert resp.status_code == 200
+django = resp.json()
+
+resp = c.patch(f"/auth/skills/{python['id']}", json={"description": "The basics"})
+assert resp.status_code == 200
+assert resp.json() == {**python, "description": "The basics"}
+python = resp.json()
+
+resp = c.put(f"/auth/skills/{django['id']}/prerequisites/{python['id']}")
+assert resp.status_code == 200
+
+resp = c.put(f"/auth/skills/{python['id']}/prerequisites/{django['id']}")
+assert resp.status_code == 409
+assert resp.json() == {"detail": "Skill prerequisite cycle"}
+
+resp = c.put(f"/auth/skills/{python['id']}/prerequisites/{python['id']}")
+assert resp.status_code == 409
+
+resp = c.get("/auth/skills")
+assert resp.status_code == 200
+assert resp.json() == {
+    "skills": [python, django],
+    "prerequisites": [{"skill_id": django["id"], "prerequisite_id": python["id"]}],
+}
+
+# courses, sections and lectures
+resp = c.post(f"/auth/skills/{python['id']}/courses", json={"title": "Intro", "description": ""})
+assert resp.status_code == 200
+course = resp.json()
+assert course["skill_id"] == python["id"]
+
+resp = c.post(f"/auth/courses/{course['id']}/sections", json={"title": "Setup"})
+assert resp.status_code == 200
+section1 = resp.json()
+assert section1["position"] == 0
+
+resp = c.post(f"/auth/courses/{course['id']}/sections", json={"title": "Basics"})
+assert resp.status_code == 200
+section2 = resp.json()
+assert section2["position"] == 1
+
+lectures = []
+for section, title in [(section1, "Install"), (section2, "Variables"), (section2, "Loops")]:
+    resp = c.post(f"/auth/sections/{section['id']}/lectures", json={"title": title, "description": ""})
+    assert resp.status_code == 200
+    lectures.append(resp.json())
+assert [lecture["position"] for lecture in lectures] == [0, 0, 1]
+
+resp = c.post(f"/auth/skills/{django['id']}/courses", json={"title": "Django", "description": ""})
+assert resp.status_code == 200
+django_course = resp.json()
+resp = c.post(f"/auth/courses/{django_course['id']}/sections", json={"title": "Models"})
+assert resp.status_code == 200
+resp = c.post(f"/auth/sections/{resp.json()['id']}/lectures", json={"title": "ORM", "description": ""})
+assert resp.status_code == 200
+django_lecture = resp.json()
+
+resp = c.get("/auth/courses", params={"skill_id": python["id"]})
+assert resp.status_code == 200
+assert resp.json() == [course]
+
+# course outlines are public
+anon = make_client()
+resp = anon.get(f"/auth/courses/{course['id']}")
+assert resp.status_code == 200
+assert resp.json() == {
+    "course": course,
+    "sections": [
+        {"section": section1, "lectures": lectures[:1]},
+        {"section": section2, "lectures": lectures[1:]},
+    ],
+}
+
+# progress
+create_account("learner", "learner@example.com", "learner")
+
+resp = c.get("/auth/users/me/skills/progress")
+assert resp.status_code == 200
+assert resp.json() == [
+    {
+        "skill_id": python["id"],
+        "progress": {"completed_lectures": 0, "total_lectures": 3, "percent": 0},
+        "unlocked": True,
+    },
+    {
+        "skill_id": django["id"],
+        "progress": {"completed_lectures": 0, "total_lectures": 1, "percent": 0},
+        "unlocked": False,
+    },
+]
+
+resp = c.put(f"/auth/users/me/completed_lectures/{django_lecture['id']}")
+assert resp.status_code == 403
+assert resp.json() == {"detail": "Skill locked"}
+
+for lecture in lectures[:2]:
+    resp = c.put(f"/auth/users/me/completed_lectures/{lecture['id']}")
+    assert resp.status_code == 200
+
+resp = c.get(f"/auth/users/me/courses/{course['id']}/progress")
+assert resp.status_code == 200
+assert resp.json() == {
+    "course_id": course["id"],
+    "progress": {"completed_lectures": 2, "total_lectures": 3, "percent": 66},
+    "completed_lectures": [lecture["id"] for lecture in lectures[:2]],
+}
+
+resp = c.delete(f"/auth/users/me/completed_lectures/{lectures[1]['id']}")
+assert resp.status_code == 200
+resp = c.delete(f"/auth/users/me/completed_lectures/{lectures[1]['id']}")
+assert resp.status_code == 404
+
+for lecture in lectures[1:]:
+    resp = c.put(f"/auth/users/me/completed_lectures/{lecture['id']}")
+    assert resp.status_code == 200
+
+resp = c.get("/auth/users/me/skills/progress")
+assert resp.status_code == 200
+assert resp.json()[0]["progress"]["percent"] == 100
+assert resp.json()[1]["unlocked"] is True
+
+resp = c.put(f"/auth/users/me/completed_lectures/{django_lecture['id']}")
+assert resp.status_code == 200
+
+# only admins can edit the catalog
+resp = c.post("/auth/skills", json={"title": "Rust", "description": ""})
+assert resp.status_code == 403
+
+# deleting a skill removes its courses
+resp = c.post("/auth/sessions", json={"name_or_email": "admin", "password": "admin"})
+assert resp.status_code == 200
+save_auth(resp.json())
+
+resp = c.delete(f"/auth/skills/{django['id']}")
+assert resp.status_code == 200
+resp = c.get(f"/auth/courses/{django_course['id']}")
+assert resp.status_code == 404
+resp = c.get("/auth/skills")
+assert resp.json() == {"skills": [python], "prerequisites": []}
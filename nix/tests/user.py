@@ -9,6 +9,7 @@ from utils import (
     decode_mail_payload,
     discard_auth,
     fetch_mail,
+    make_client,
     refresh_session,
     save_auth,
 )
@@ -55,6 +56,7 @@ assert login == {
         "city": None,
         "country": None,
         "vat_id": None,
+        "vat_id_status": None,
         "can_buy_coins": False,
         "can_receive_coins": False,
         "avatar_url": "https://gravatar.com/avatar/b4c9a289323b21a01c3e940f150eb9b8c542587f1abfd8f0e1cc1ffc5e475514",
@@ -146,51 +148,83 @@ assert resp.json() == user
 assert c.get("/auth/users/me").json() == user
 
 ## invoice info
-resp = c.patch("/auth/users/me", json={"business": False, "country": "Germany"})
+countries = c.get("/auth/countries", headers={"Accept-Language": "de"}).json()
+assert len(countries) == 249
+assert {"code": "DE", "name": "Deutschland", "vat_prefix": "DE", "zip_code_pattern": "^[0-9]{5}$"} in countries
+
+resp = c.patch("/auth/users/me", json={"country": "Germany"})
+assert resp.status_code == 422
+
+resp = c.patch("/auth/users/me", json={"business": False, "country": "DE"})
 assert resp.status_code == 200
 user = resp.json()
 user["business"] = False
-user["country"] = "Germany"
+user["country"] = "DE"
 user["can_buy_coins"] = True
 assert resp.json() == user
 assert c.get("/auth/users/me").json() == user
-assert c.get(f"http://127.0.0.1:8004/shop/_internal/coins/{user['id']}/withheld").json() == 0
+status, jwt = subprocess.getstatusoutput('academy jwt sign \'{"aud":"auth"}\'')
+assert status == 0
+internal = make_client()
+internal.headers["Authorization"] = jwt.strip()
+resp = internal.post(f"/auth/_internal/coins/{user['id']}/credit", json={"kind": "earn", "coins": 10})
+assert resp.status_code == 200
+assert resp.json()["transaction"]["kind"] == "withheld"
+assert resp.json()["balance"] == {"coins": 0, "withheld_coins": 10}
 
 resp = c.patch("/auth/users/me", json={"business": True, "vat_id": "DE0192837465"})
 assert resp.status_code == 404
 assert resp.json() == {"detail": "Invalid VAT ID"}
 assert c.get("/auth/users/me").json() == user
 
-resp = c.patch("/auth/users/me", json={"business": True, "vat_id": "DE0123456789"})
+resp = c.patch("/auth/users/me", json={"business": True, "vat_id": "DE9999999999"})
 assert resp.status_code == 200
 user["business"] = True
-user["vat_id"] = "DE0123456789"
+user["vat_id"] = "DE9999999999"
+user["vat_id_status"] = "pending"
 user["can_buy_coins"] = False
 assert resp.json() == user
 assert c.get("/auth/users/me").json() == user
 
-assert c.get(f"http://127.0.0.1:8004/shop/_internal/coins/{user['id']}/withheld").json() == 0
+resp = c.patch("/auth/users/me", json={"vat_id": "DE0123456789"})
+assert resp.status_code == 200
+user["vat_id"] = "DE0123456789"
+user["vat_id_status"] = "valid"
+assert resp.json() == user
+assert c.get("/auth/users/me").json() == user
+
+resp = c.patch("/auth/users/me", json={"vat_id": "ATU12345678"})
+assert resp.status_code == 400
+assert resp.json() == {"detail": "VAT ID country mismatch"}
+
+resp = c.patch("/auth/users/me", json={"zip_code": "1234"})
+assert resp.status_code == 400
+assert resp.json() == {"detail": "Invalid zip code"}
+assert c.get("/auth/users/me").json() == user
+
+assert c.get("/auth/users/me/coins").json() == {"coins": 0, "withheld_coins": 10}
 resp = c.patch(
-    "/auth/users/me", json={"first_name": "a", "last_name": "b", "street": "c", "zip_code": "d", "city": "e"}
+    "/auth/users/me", json={"first_name": "a", "last_name": "b", "street": "c", "zip_code": "12345", "city": "e"}
 )
 assert resp.status_code == 200
-assert c.get(f"http://127.0.0.1:8004/shop/_internal/coins/{user['id']}/withheld").json() == 1
+assert c.get("/auth/users/me/coins").json() == {"coins": 10, "withheld_coins": 0}
 user["first_name"] = "a"
 user["last_name"] = "b"
 user["street"] = "c"
-user["zip_code"] = "d"
+user["zip_code"] = "12345"
 user["city"] = "e"
 user["can_buy_coins"] = True
 user["can_receive_coins"] = True
 assert resp.json() == user
 assert c.get("/auth/users/me").json() == user
 
-assert c.get(f"http://127.0.0.1:8004/shop/_internal/coins/{user['id']}/withheld").json() == 1
+assert c.get("/auth/users/me/coins").json() == {"coins": 10, "withheld_coins": 0}
 resp = c.patch("/auth/users/me", json={"business": False})
 assert resp.status_code == 200
-assert c.get(f"http://127.0.0.1:8004/shop/_internal/coins/{user['id']}/withheld").json() == 2
+assert c.get("/auth/users/me/coins").json() == {"coins": 10, "withheld_coins": 0}
 user["business"] = False
 user["vat_id"] = None
+user["vat_id_status"] = None
 assert resp.json() == user
 assert c.get("/auth/users/me").json() == user
 
@@ -201,7 +235,7 @@ user["can_buy_coins"] = False
 user["can_receive_coins"] = False
 assert resp.json() == user
 assert c.get("/auth/users/me").json() == user
-assert c.get(f"http://127.0.0.1:8004/shop/_internal/coins/{user['id']}/withheld").json() == 2
+assert c.get("/auth/users/me/coins").json() == {"coins": 10, "withheld_coins": 0}
 
 ## name
 start = time.time() - 1